@@ -1,7 +1,7 @@
 import { useCallback, useRef } from 'react';
 import { Routes, Route } from 'react-router-dom';
 import { Header } from './components/layout/Header';
-import { AlertBanner } from './components/layout/AlertBanner';
+import { AlertBanner, SnapshotWriteBanner } from './components/layout/AlertBanner';
 import { LiveView } from './components/live/LiveView';
 import { HourlyView } from './components/reports/HourlyView';
 import { DailyView } from './components/reports/DailyView';
@@ -29,6 +29,7 @@ export default function App() {
     <div className="flex flex-col h-screen">
       <Header status={data?.status ?? null} connected={connected} />
       {data?.health && <AlertBanner health={data.health} />}
+      {data?.health && <SnapshotWriteBanner health={data.health} />}
       <main className="flex-1 overflow-hidden">
         <Routes>
           <Route
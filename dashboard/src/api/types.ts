@@ -34,9 +34,20 @@ export interface HealthV2 {
     ml: boolean;
     fleet: boolean;
     storage: boolean;
+    kb_snapshots: SnapshotWriteStatus | null;
   };
 }
 
+export interface SnapshotWriteStatus {
+  pending: number;
+  written: number;
+  recovered: number;
+  dropped: number;
+  consecutive_failures: number;
+  last_error: string | null;
+  last_failure_at: number | null;
+}
+
 export interface StatusV2 {
   system_status: string;
   rig_state: string;
@@ -99,6 +110,8 @@ export interface BaselineSummaryV2 {
   locked_count: number;
   learning_count: number;
   total_metrics: number;
+  operational_since: number | null;
+  banner: string | null;
 }
 
 export interface MLSummaryV2 {
@@ -1,5 +1,5 @@
 import type { HealthV2 } from '../../api/types';
-import { severityColor } from '../../theme/colors';
+import { colors, severityColor } from '../../theme/colors';
 
 interface AlertBannerProps {
   health: HealthV2;
@@ -46,3 +46,28 @@ export function AlertBanner({ health }: AlertBannerProps) {
     </div>
   );
 }
+
+/** Shown while knowledge-base snapshot writes are failing and queued. */
+export function SnapshotWriteBanner({ health }: AlertBannerProps) {
+  const kb = health.components.kb_snapshots;
+  if (!kb || kb.pending === 0) return null;
+
+  return (
+    <div
+      className="border-l-4 px-4 py-2"
+      style={{ borderColor: colors.yellow, backgroundColor: `${colors.yellow}15` }}
+    >
+      <p className="text-sm text-text-primary leading-snug">
+        <span
+          className="text-xs font-bold uppercase tracking-wider mr-2"
+          style={{ color: colors.yellow }}
+        >
+          KB snapshots
+        </span>
+        {kb.pending} snapshot{kb.pending === 1 ? '' : 's'} not yet written
+        {kb.dropped > 0 && `, ${kb.dropped} dropped`}
+        {kb.last_error && <span className="text-text-secondary"> — {kb.last_error}</span>}
+      </p>
+    </div>
+  );
+}
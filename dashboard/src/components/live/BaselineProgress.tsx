@@ -15,6 +15,11 @@ export function BaselineProgress({ baseline }: Props) {
       <h3 className="text-text-secondary text-xs uppercase tracking-wider font-medium">
         Baseline Learning
       </h3>
+      {baseline.banner && (
+        <div className="rounded-lg px-3 py-2 text-xs font-medium border border-[#3fb950] text-[#3fb950]">
+          {baseline.banner}
+        </div>
+      )}
       <div className="bg-bg-card rounded-lg p-3 border border-border space-y-2">
         <div className="flex justify-between text-xs">
           <span className="text-text-secondary">{baseline.overall_status}</span>
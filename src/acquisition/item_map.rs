@@ -0,0 +1,256 @@
+//! WITS Level 0 item code → packet field mapping
+//!
+//! The built-in map covers the Record 01 items in [`wits_items`]. Service
+//! companies often send further records (record 08 MWD, record 12
+//! cementing, ...), so an optional TOML file at
+//! `acquisition.wits_item_map_path` overlays extra or replacement codes:
+//!
+//! ```toml
+//! [items]
+//! "8:13"  = "annular_pressure"
+//! "12:05" = "choke_position_pct"
+//! "0141"  = "gas_units"
+//! ```
+//!
+//! Codes are `record:item` (1-2 digits each) or the raw 4-digit `RRII` form.
+//! Codes in a frame with no mapping are logged once and ignored.
+
+use super::wits_parser::wits_items;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::{info, warn};
+
+/// Channels a WITS item can be mapped onto.
+///
+/// All are `WitsPacket` fields except `block_position`, which only feeds
+/// rig-state classification.
+pub const MAPPABLE_FIELDS: [&str; 28] = [
+    "bit_depth",
+    "hole_depth",
+    "rop",
+    "hook_load",
+    "wob",
+    "rpm",
+    "torque",
+    "spp",
+    "pump_spm",
+    "flow_in",
+    "flow_out",
+    "pit_volume",
+    "mud_weight_in",
+    "mud_weight_out",
+    "ecd",
+    "mud_temp_in",
+    "mud_temp_out",
+    "gas_units",
+    "background_gas",
+    "connection_gas",
+    "h2s",
+    "co2",
+    "casing_pressure",
+    "annular_pressure",
+    "choke_position_pct",
+    "pore_pressure",
+    "fracture_gradient",
+    "block_position",
+];
+
+/// Built-in Record 01 mapping.
+const BUILTIN_ITEMS: [(&str, &str); 21] = [
+    (wits_items::BIT_DEPTH, "bit_depth"),
+    (wits_items::HOLE_DEPTH, "hole_depth"),
+    (wits_items::ROP, "rop"),
+    (wits_items::HOOK_LOAD, "hook_load"),
+    (wits_items::WOB, "wob"),
+    (wits_items::RPM, "rpm"),
+    (wits_items::TORQUE, "torque"),
+    (wits_items::SPP, "spp"),
+    (wits_items::PUMP_SPM_1, "pump_spm"),
+    (wits_items::FLOW_IN, "flow_in"),
+    (wits_items::FLOW_OUT, "flow_out"),
+    (wits_items::PIT_VOLUME, "pit_volume"),
+    (wits_items::MUD_WEIGHT_IN, "mud_weight_in"),
+    (wits_items::MUD_WEIGHT_OUT, "mud_weight_out"),
+    (wits_items::MUD_TEMP_IN, "mud_temp_in"),
+    (wits_items::MUD_TEMP_OUT, "mud_temp_out"),
+    (wits_items::CASING_PRESSURE, "casing_pressure"),
+    (wits_items::GAS_UNITS, "gas_units"),
+    (wits_items::H2S, "h2s"),
+    (wits_items::CO2, "co2"),
+    (wits_items::ECD, "ecd"),
+];
+
+/// On-disk layout of the item map TOML.
+#[derive(Debug, Deserialize)]
+struct ItemMapFile {
+    #[serde(default)]
+    items: HashMap<String, String>,
+}
+
+/// Maps 4-digit WITS item codes (`RRII`) to packet field names.
+#[derive(Debug)]
+pub struct WitsItemMap {
+    fields: HashMap<String, String>,
+    /// Unmapped codes already logged, so each is reported once.
+    warned: Mutex<HashSet<String>>,
+}
+
+impl Default for WitsItemMap {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl WitsItemMap {
+    /// The built-in Record 01 mapping.
+    pub fn builtin() -> Self {
+        let mut fields: HashMap<String, String> = BUILTIN_ITEMS
+            .iter()
+            .map(|(code, field)| (code.to_string(), field.to_string()))
+            .collect();
+        fields.insert(
+            wits_items::BLOCK_POSITION.to_string(),
+            "block_position".to_string(),
+        );
+        Self {
+            fields,
+            warned: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Built-in mapping overlaid with the `[items]` table of a TOML string.
+    pub fn from_toml(contents: &str) -> Result<Self, String> {
+        let file: ItemMapFile =
+            toml::from_str(contents).map_err(|e| format!("invalid item map TOML: {}", e))?;
+        let mut map = Self::builtin();
+        for (code, field) in file.items {
+            let normalized = normalize_code(&code)?;
+            if !MAPPABLE_FIELDS.contains(&field.as_str()) {
+                return Err(format!(
+                    "item {} maps to unknown field {:?} (expected one of {:?})",
+                    code, field, MAPPABLE_FIELDS
+                ));
+            }
+            map.fields.insert(normalized, field);
+        }
+        Ok(map)
+    }
+
+    /// Load an item map file, overlaying the built-in mapping.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        Self::from_toml(&contents)
+    }
+
+    /// Map from `acquisition.wits_item_map_path`, or the built-in one.
+    ///
+    /// A missing or invalid file is logged and falls back to the built-ins,
+    /// so a bad overlay never stops acquisition.
+    pub fn from_config() -> Self {
+        let path = if crate::config::is_initialized() {
+            crate::config::get().acquisition.wits_item_map_path.clone()
+        } else {
+            None
+        };
+        let Some(path) = path else {
+            return Self::builtin();
+        };
+        match Self::load(Path::new(&path)) {
+            Ok(map) => {
+                info!(path = %path, items = map.fields.len(), "Loaded WITS item map");
+                map
+            }
+            Err(e) => {
+                warn!(path = %path, error = %e, "WITS item map not loaded — using built-in items");
+                Self::builtin()
+            }
+        }
+    }
+
+    /// Process-wide map, loaded from config on first use.
+    pub fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<WitsItemMap>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(|| Arc::new(Self::from_config())))
+    }
+
+    /// Field a code maps to; unmapped codes are logged the first time seen.
+    pub fn field_for(&self, code: &str) -> Option<&str> {
+        if let Some(field) = self.fields.get(code) {
+            return Some(field);
+        }
+        if let Ok(mut warned) = self.warned.lock() {
+            if warned.insert(code.to_string()) {
+                warn!(code = %code, "Unmapped WITS item ignored (add it to the WITS item map)");
+            }
+        }
+        None
+    }
+
+    /// Resolve raw item values to field values, dropping unmapped codes.
+    pub fn resolve(&self, items: &HashMap<String, f64>) -> HashMap<String, f64> {
+        items
+            .iter()
+            .filter_map(|(code, value)| {
+                self.field_for(code)
+                    .map(|field| (field.to_string(), *value))
+            })
+            .collect()
+    }
+}
+
+/// Normalize `"8:13"` / `"08:13"` / `"0813"` to the 4-digit frame code.
+fn normalize_code(code: &str) -> Result<String, String> {
+    let code = code.trim();
+    let (record, item) = match code.split_once(':') {
+        Some((r, i)) => (r.trim(), i.trim()),
+        None if code.len() == 4 && code.is_ascii() => (&code[0..2], &code[2..4]),
+        None => return Err(format!("item code {:?} must be RR:II or RRII", code)),
+    };
+    let parse = |part: &str| -> Result<u8, String> {
+        part.parse::<u8>()
+            .ok()
+            .filter(|n| (1..=99).contains(n))
+            .ok_or_else(|| format!("item code {:?} must use numbers 1-99", code))
+    };
+    Ok(format!("{:02}{:02}", parse(record)?, parse(item)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_record_item_codes() {
+        assert_eq!(normalize_code("8:13").unwrap(), "0813");
+        assert_eq!(normalize_code("12:05").unwrap(), "1205");
+        assert_eq!(normalize_code("0108").unwrap(), "0108");
+        assert!(normalize_code("108").is_err());
+        assert!(normalize_code("0:13").is_err());
+    }
+
+    #[test]
+    fn overlay_adds_and_replaces_items() {
+        let map = WitsItemMap::from_toml(
+            r#"
+            [items]
+            "8:13" = "annular_pressure"
+            "0140" = "background_gas"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(map.field_for("0813"), Some("annular_pressure"));
+        assert_eq!(map.field_for("0140"), Some("background_gas"));
+        // Built-ins not overridden are kept
+        assert_eq!(map.field_for(wits_items::BIT_DEPTH), Some("bit_depth"));
+        assert_eq!(map.field_for("9999"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_field_names() {
+        let err = WitsItemMap::from_toml("[items]\n\"8:13\" = \"not_a_field\"\n").unwrap_err();
+        assert!(err.contains("not_a_field"));
+    }
+}
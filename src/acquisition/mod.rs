@@ -2,7 +2,9 @@
 //!
 //! Handles data ingestion from WITS data sources.
 
+pub mod item_map;
+pub mod probe;
 pub mod scanner;
 pub mod wits_parser;
 
-pub use wits_parser::{WitsClient, WitsError};
+pub use wits_parser::WitsClient;
@@ -0,0 +1,332 @@
+//! WITS source probe
+//!
+//! The scanner only proves that an endpoint speaks WITS framing. Before the
+//! setup wizard writes a config pointing at it, `probe` samples the stream
+//! for a few seconds through the normal `WitsClient` parser and summarises
+//! what actually arrived — packet count, channels, depth and ROP ranges and
+//! any silences — graded green / amber / red for the wizard's data-quality
+//! badge.
+//!
+//! The probe is time-boxed end to end (connect included) and always closes
+//! its connection before returning.
+
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use super::wits_parser::{WitsClient, WitsError};
+
+/// Sampling window when the caller does not choose one.
+pub const DEFAULT_PROBE_SECS: u64 = 10;
+
+/// Longest sampling window a caller may request.
+pub const MAX_PROBE_SECS: u64 = 30;
+
+/// Silence between records long enough to count as a gap.
+const GAP_THRESHOLD: Duration = Duration::from_secs(3);
+
+/// Channels the analysis pipeline cannot do without; any missing is amber.
+const CORE_CHANNELS: [&str; 7] = [
+    "bit_depth",
+    "hole_depth",
+    "rop",
+    "wob",
+    "rpm",
+    "spp",
+    "flow_in",
+];
+
+/// Data-quality badge for a probed source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProbeQuality {
+    /// Streaming steadily with every core channel
+    Green,
+    /// Streaming, but with gaps or missing core channels
+    Amber,
+    /// No usable drilling data (no connection, no packets or no depth)
+    Red,
+}
+
+/// Observed min/max of one channel
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ValueRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ValueRange {
+    fn include(range: &mut Option<ValueRange>, value: f64) {
+        match range {
+            Some(r) => {
+                r.min = r.min.min(value);
+                r.max = r.max.max(value);
+            }
+            None => {
+                *range = Some(ValueRange {
+                    min: value,
+                    max: value,
+                })
+            }
+        }
+    }
+}
+
+/// What a probe saw on the stream
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeSummary {
+    pub host: String,
+    pub port: u16,
+    /// Whether the TCP connection was established
+    pub connected: bool,
+    /// Complete records parsed during the window
+    pub packets: u64,
+    pub duration_secs: f64,
+    /// Packet fields seen in at least one record, sorted
+    pub channels: Vec<String>,
+    /// Core channels never seen
+    pub missing_channels: Vec<String>,
+    pub bit_depth_ft: Option<ValueRange>,
+    pub hole_depth_ft: Option<ValueRange>,
+    pub rop_ft_hr: Option<ValueRange>,
+    /// Silences between records (or at the end of the window) of at least
+    /// `GAP_THRESHOLD`
+    pub gaps: u32,
+    pub longest_gap_secs: f64,
+    /// Human-readable findings behind the grade
+    pub issues: Vec<String>,
+    pub quality: ProbeQuality,
+}
+
+/// Running tallies while sampling
+struct ProbeTally {
+    connected: bool,
+    packets: u64,
+    channels: BTreeSet<String>,
+    bit_depth: Option<ValueRange>,
+    hole_depth: Option<ValueRange>,
+    rop: Option<ValueRange>,
+    last_seen: Instant,
+    gaps: u32,
+    longest_gap: Duration,
+    stop_reason: Option<String>,
+}
+
+impl ProbeTally {
+    fn new(started: Instant) -> Self {
+        Self {
+            connected: false,
+            packets: 0,
+            channels: BTreeSet::new(),
+            bit_depth: None,
+            hole_depth: None,
+            rop: None,
+            last_seen: started,
+            gaps: 0,
+            longest_gap: Duration::ZERO,
+            stop_reason: None,
+        }
+    }
+
+    fn note_silence(&mut self, now: Instant) {
+        let silence = now.duration_since(self.last_seen);
+        if silence >= GAP_THRESHOLD {
+            self.gaps += 1;
+        }
+        self.longest_gap = self.longest_gap.max(silence);
+    }
+
+    fn record(&mut self, client: &WitsClient, packet: &crate::types::WitsPacket) {
+        let now = Instant::now();
+        if self.packets > 0 {
+            self.note_silence(now);
+        }
+        self.last_seen = now;
+        self.packets += 1;
+
+        let channels = client.last_record_channels();
+        for (name, value, range) in [
+            ("bit_depth", packet.bit_depth, &mut self.bit_depth),
+            ("hole_depth", packet.hole_depth, &mut self.hole_depth),
+            ("rop", packet.rop, &mut self.rop),
+        ] {
+            if channels.contains(&name) {
+                ValueRange::include(range, value);
+            }
+        }
+        self.channels
+            .extend(channels.into_iter().map(str::to_string));
+    }
+
+    /// Sample until the stream fails; the caller bounds this with a deadline.
+    async fn sample(&mut self, client: &mut WitsClient) {
+        if let Err(e) = client.connect().await {
+            self.stop_reason = Some(format!("Connection failed: {e}"));
+            return;
+        }
+        self.connected = true;
+        self.last_seen = Instant::now();
+
+        loop {
+            match client.read_packet_once().await {
+                Ok(packet) => self.record(client, &packet),
+                Err(WitsError::ConnectionClosed) => {
+                    self.stop_reason = Some(format!(
+                        "Source closed the connection after {} packets",
+                        self.packets
+                    ));
+                    return;
+                }
+                Err(e) => {
+                    self.stop_reason = Some(format!("Read failed: {e}"));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn finish(mut self, host: &str, port: u16, started: Instant) -> ProbeSummary {
+        let now = Instant::now();
+        if self.connected && self.stop_reason.is_none() {
+            // Trailing silence up to the end of the window
+            self.note_silence(now);
+        }
+
+        let missing_channels: Vec<String> = CORE_CHANNELS
+            .iter()
+            .filter(|c| !self.channels.contains(**c))
+            .map(|c| c.to_string())
+            .collect();
+
+        let mut issues = Vec::new();
+        issues.extend(self.stop_reason.take());
+        if self.connected && self.packets == 0 {
+            issues.push("No complete WITS records received".to_string());
+        }
+        let has_depth = self.bit_depth.is_some() || self.hole_depth.is_some();
+        if self.packets > 0 && !has_depth {
+            issues.push("No bit or hole depth channel".to_string());
+        }
+        if !missing_channels.is_empty() && self.packets > 0 {
+            issues.push(format!(
+                "Missing core channels: {}",
+                missing_channels.join(", ")
+            ));
+        }
+        if self.gaps > 0 {
+            issues.push(format!(
+                "{} gap(s) of {}s or more, longest {:.1}s",
+                self.gaps,
+                GAP_THRESHOLD.as_secs(),
+                self.longest_gap.as_secs_f64()
+            ));
+        }
+
+        let quality = if self.packets == 0 || !has_depth {
+            ProbeQuality::Red
+        } else if issues.is_empty() {
+            ProbeQuality::Green
+        } else {
+            ProbeQuality::Amber
+        };
+
+        ProbeSummary {
+            host: host.to_string(),
+            port,
+            connected: self.connected,
+            packets: self.packets,
+            duration_secs: now.duration_since(started).as_secs_f64(),
+            channels: self.channels.into_iter().collect(),
+            missing_channels,
+            bit_depth_ft: self.bit_depth,
+            hole_depth_ft: self.hole_depth,
+            rop_ft_hr: self.rop,
+            gaps: self.gaps,
+            longest_gap_secs: self.longest_gap.as_secs_f64(),
+            issues,
+            quality,
+        }
+    }
+}
+
+/// Sample `host:port` for `window` and summarise the data quality.
+///
+/// Never takes longer than `window` plus connection teardown.
+pub async fn probe(host: &str, port: u16, window: Duration) -> ProbeSummary {
+    let started = Instant::now();
+    let mut client = WitsClient::new(host, port).with_read_timeout(window.as_secs() + 1);
+    let mut tally = ProbeTally::new(started);
+
+    let _ = tokio::time::timeout_at(started + window, tally.sample(&mut client)).await;
+    let _ = client.disconnect().await;
+
+    tally.finish(host, port, started)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acquisition::wits_parser::{wits_items, WitsFrameBuilder};
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_probe_summarises_mock_stream() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // Five records of a drilling feed without flow-in, then hold the
+        // connection open silently
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            for i in 0..5 {
+                let frame = WitsFrameBuilder::new()
+                    .add_item(wits_items::BIT_DEPTH, 9_000.0 + i as f64)
+                    .add_item(wits_items::HOLE_DEPTH, 9_010.0)
+                    .add_item(wits_items::ROP, 40.0 + 10.0 * i as f64)
+                    .add_item(wits_items::WOB, 25.0)
+                    .add_item(wits_items::RPM, 120.0)
+                    .add_item(wits_items::SPP, 3_000.0)
+                    .build();
+                socket.write_all(frame.as_bytes()).await.unwrap();
+            }
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let summary = probe("127.0.0.1", port, Duration::from_millis(800)).await;
+
+        assert!(summary.connected);
+        assert_eq!(summary.packets, 5);
+        assert!(summary.channels.iter().any(|c| c == "bit_depth"));
+        assert!(summary.channels.iter().any(|c| c == "rop"));
+        assert_eq!(summary.missing_channels, vec!["flow_in".to_string()]);
+        assert_eq!(
+            summary.bit_depth_ft,
+            Some(ValueRange {
+                min: 9_000.0,
+                max: 9_004.0
+            })
+        );
+        assert_eq!(
+            summary.rop_ft_hr,
+            Some(ValueRange {
+                min: 40.0,
+                max: 80.0
+            })
+        );
+        assert_eq!(summary.gaps, 0);
+        assert_eq!(summary.quality, ProbeQuality::Amber);
+        assert!(summary.duration_secs < 2.0);
+
+        // Nothing listening: red, with the reason
+        let unused = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let closed = probe("127.0.0.1", unused, Duration::from_millis(500)).await;
+        assert!(!closed.connected);
+        assert_eq!(closed.quality, ProbeQuality::Red);
+        assert!(!closed.issues.is_empty());
+    }
+}
@@ -10,15 +10,25 @@ use tokio::net::TcpStream;
 use tokio::sync::Semaphore;
 use tracing::{debug, info, warn};
 
+/// How sure the scanner is that an endpoint speaks WITS Level 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScanConfidence {
+    /// A complete `&&` ... `!!` record with item lines was read.
+    High,
+    /// Only a record delimiter was seen (e.g. joined mid-record).
+    Low,
+}
+
 /// A discovered WITS TCP endpoint.
 #[derive(Debug, Clone, serde::Serialize)]
-pub struct WitsDiscovery {
-    /// IP address of the WITS server
-    pub host: String,
-    /// TCP port
-    pub port: u16,
-    /// True if a WITS Level 0 frame header (`&&\r\n`) was observed
-    pub validated: bool,
+pub struct ScanResult {
+    /// Address of the WITS server
+    pub addr: SocketAddr,
+    /// How strongly the probed bytes matched WITS Level 0 framing
+    pub confidence: ScanConfidence,
+    /// First complete record read from the stream, for the setup wizard
+    pub sample_record: Option<String>,
 }
 
 /// Default port ranges to scan for WITS Level 0 streams.
@@ -27,6 +37,12 @@ pub const DEFAULT_PORT_RANGES: &[(u16, u16)] = &[(5000, 5010), (10001, 10010)];
 /// Maximum concurrent TCP probe connections.
 const MAX_CONCURRENT_PROBES: usize = 64;
 
+/// Bytes read from a connected port before classifying it.
+const PROBE_WINDOW_BYTES: usize = 1024;
+
+/// How long to wait for the probe window to fill.
+const PROBE_READ_TIMEOUT: Duration = Duration::from_secs(3);
+
 /// Detect the local machine's IPv4 address by briefly connecting a UDP socket.
 ///
 /// This does not send any data — the OS simply selects the appropriate
@@ -44,45 +60,93 @@ fn detect_local_ip() -> Option<Ipv4Addr> {
 
 /// Probe a single host:port for a WITS TCP stream.
 ///
-/// Returns `Some(WitsDiscovery)` if TCP connects, `None` if unreachable.
-async fn probe_endpoint(addr: SocketAddr, connect_timeout: Duration) -> Option<WitsDiscovery> {
+/// Returns `Some(ScanResult)` only if WITS framing was seen; unreachable
+/// ports and open ports speaking anything else return `None`.
+async fn probe_endpoint(addr: SocketAddr, connect_timeout: Duration) -> Option<ScanResult> {
     let stream = tokio::time::timeout(connect_timeout, TcpStream::connect(addr))
         .await
         .ok()?
         .ok()?;
 
-    // TCP connected — try to read first bytes to validate WITS header
-    let validated = match try_read_wits_header(stream).await {
-        Ok(v) => v,
-        Err(_) => false,
-    };
-
-    Some(WitsDiscovery {
-        host: addr.ip().to_string(),
-        port: addr.port(),
-        validated,
+    let sample = read_probe_window(stream).await;
+    let (confidence, sample_record) = classify_wits_sample(&sample)?;
+    Some(ScanResult {
+        addr,
+        confidence,
+        sample_record,
     })
 }
 
-/// Attempt to read the first bytes from a connected TCP stream and check
-/// for the WITS Level 0 frame start marker `&&\r\n`.
-async fn try_read_wits_header(mut stream: TcpStream) -> std::io::Result<bool> {
+/// Read up to [`PROBE_WINDOW_BYTES`] from a connected stream, stopping
+/// early once a complete record has arrived.
+async fn read_probe_window(mut stream: TcpStream) -> Vec<u8> {
+    let mut data = Vec::with_capacity(PROBE_WINDOW_BYTES);
     let mut buf = [0u8; 256];
-    let read_timeout = Duration::from_secs(3);
+    let deadline = tokio::time::Instant::now() + PROBE_READ_TIMEOUT;
 
-    match tokio::time::timeout(read_timeout, stream.read(&mut buf)).await {
-        Ok(Ok(n)) if n >= 4 => {
-            // Look for && followed by \r\n anywhere in the first bytes
-            let data = &buf[..n];
-            Ok(data.windows(4).any(|w| w == b"&&\r\n"))
+    while data.len() < PROBE_WINDOW_BYTES {
+        match tokio::time::timeout_at(deadline, stream.read(&mut buf)).await {
+            Ok(Ok(n)) if n > 0 => {
+                let take = n.min(PROBE_WINDOW_BYTES - data.len());
+                data.extend_from_slice(&buf[..take]);
+                if matches!(classify_wits_sample(&data), Some((ScanConfidence::High, _))) {
+                    break;
+                }
+            }
+            _ => break,
         }
-        Ok(Ok(n)) if n > 0 => {
-            // Got some data but too short for a full WITS header
-            let data = &buf[..n];
-            Ok(data.windows(2).any(|w| w == b"&&"))
+    }
+    data
+}
+
+/// Classify bytes read from a port by their WITS Level 0 framing.
+///
+/// Delimiters must sit on their own line, so `&&` inside an HTTP body or
+/// script does not count. A complete record with at least one `RRII<value>`
+/// item line is high confidence; a lone delimiter line is low; no
+/// delimiter at all means the port is not a WITS source.
+pub fn classify_wits_sample(data: &[u8]) -> Option<(ScanConfidence, Option<String>)> {
+    let text = String::from_utf8_lossy(data);
+    let mut record: Option<Vec<&str>> = None;
+    let mut saw_delimiter = false;
+
+    for line in text.lines().map(|l| l.trim()) {
+        match line {
+            "&&" => {
+                saw_delimiter = true;
+                record = Some(vec![line]);
+            }
+            "!!" => {
+                saw_delimiter = true;
+                if let Some(mut lines) = record.take() {
+                    if lines.len() > 1 {
+                        lines.push(line);
+                        return Some((ScanConfidence::High, Some(lines.join("\r\n"))));
+                    }
+                }
+            }
+            _ => {
+                if let Some(ref mut lines) = record {
+                    if is_wits_item_line(line) {
+                        lines.push(line);
+                    } else if !line.is_empty() {
+                        // Not WITS content — abandon this record
+                        record = None;
+                    }
+                }
+            }
         }
-        _ => Ok(false),
     }
+
+    saw_delimiter.then_some((ScanConfidence::Low, None))
+}
+
+/// `RRII<value>`: a 4-digit item code followed by a numeric value.
+fn is_wits_item_line(line: &str) -> bool {
+    line.len() > 4
+        && line.is_char_boundary(4)
+        && line[..4].bytes().all(|b| b.is_ascii_digit())
+        && line[4..].trim().parse::<f64>().is_ok()
 }
 
 /// Expand port ranges into a flat list of ports.
@@ -98,12 +162,12 @@ fn expand_port_ranges(ranges: &[(u16, u16)]) -> Vec<u16> {
 
 /// Scan the local /24 subnet on given port ranges for WITS TCP streams.
 ///
-/// Returns discovered streams sorted by IP then port.
+/// Returns ports showing WITS framing, sorted by IP then port.
 ///
 /// # Arguments
 /// * `port_ranges` — Port ranges to scan, e.g. `&[(5000, 5010), (10001, 10010)]`
 /// * `timeout_ms` — Per-connection timeout in milliseconds (default: 2000)
-pub async fn scan_subnet(port_ranges: &[(u16, u16)], timeout_ms: u64) -> Vec<WitsDiscovery> {
+pub async fn scan_subnet(port_ranges: &[(u16, u16)], timeout_ms: u64) -> Vec<ScanResult> {
     let local_ip = match detect_local_ip() {
         Some(ip) => ip,
         None => {
@@ -148,15 +212,15 @@ pub async fn scan_subnet(port_ranges: &[(u16, u16)], timeout_ms: u64) -> Vec<Wit
     for handle in handles {
         if let Ok(Some(discovery)) = handle.await {
             info!(
-                "Found WITS stream at {}:{} (validated: {})",
-                discovery.host, discovery.port, discovery.validated
+                "Found WITS stream at {} (confidence: {:?})",
+                discovery.addr, discovery.confidence
             );
             discoveries.push(discovery);
         }
     }
 
     // Sort by IP then port
-    discoveries.sort_by(|a, b| a.host.cmp(&b.host).then(a.port.cmp(&b.port)));
+    discoveries.sort_by_key(|d| d.addr);
     discoveries
 }
 
@@ -219,4 +283,57 @@ mod tests {
             assert!(!ip.is_loopback());
         }
     }
+
+    #[test]
+    fn test_classify_wits_framed_stream() {
+        // Joined mid-record: the partial first record is skipped
+        let data = b"0116 25.0\r\n!!\r\n&&\r\n0108 10500.0\r\n0113 45.5\r\n!!\r\n&&\r\n";
+        let (confidence, sample) = classify_wits_sample(data).expect("WITS stream");
+        assert_eq!(confidence, ScanConfidence::High);
+        assert_eq!(
+            sample.as_deref(),
+            Some("&&\r\n0108 10500.0\r\n0113 45.5\r\n!!")
+        );
+    }
+
+    #[test]
+    fn test_classify_partial_record_is_low_confidence() {
+        let (confidence, sample) = classify_wits_sample(b"&&\r\n0108 10500.0\r\n").unwrap();
+        assert_eq!(confidence, ScanConfidence::Low);
+        assert!(sample.is_none());
+    }
+
+    #[test]
+    fn test_classify_rejects_http_response() {
+        let data = b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n\
+            <script>if (a && b) { go(); }</script>\r\n";
+        assert!(classify_wits_sample(data).is_none());
+        assert!(classify_wits_sample(b"").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_probe_endpoint_rejects_http_and_accepts_wits() {
+        use tokio::io::AsyncWriteExt;
+
+        async fn serve(payload: &'static [u8]) -> SocketAddr {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let _ = stream.write_all(payload).await;
+            });
+            addr
+        }
+
+        let http = serve(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await;
+        assert!(probe_endpoint(http, Duration::from_secs(1)).await.is_none());
+
+        let wits = serve(b"&&\r\n0108 10500.0\r\n!!\r\n").await;
+        let result = probe_endpoint(wits, Duration::from_secs(1))
+            .await
+            .expect("WITS endpoint");
+        assert_eq!(result.addr, wits);
+        assert_eq!(result.confidence, ScanConfidence::High);
+        assert!(result.sample_record.is_some());
+    }
 }
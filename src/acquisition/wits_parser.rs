@@ -19,9 +19,11 @@
 //! - 0120: Pump SPM 1
 //! - 0121: Flow In (gpm)
 
+use super::item_map::WitsItemMap;
 use crate::types::{RigState, WitsPacket};
 use anyhow::{Context, Result};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
@@ -111,6 +113,10 @@ pub struct WitsClient {
     reconnections: u64,
     /// Total timeouts encountered
     timeouts: u64,
+    /// Item code → packet field mapping (built-ins + optional overlay)
+    item_map: Arc<WitsItemMap>,
+    /// Raw items of the most recent complete record
+    last_record: HashMap<String, f64>,
 }
 
 impl WitsClient {
@@ -128,6 +134,8 @@ impl WitsClient {
             packets_received: 0,
             reconnections: 0,
             timeouts: 0,
+            item_map: WitsItemMap::shared(),
+            last_record: HashMap::new(),
         }
     }
 
@@ -138,6 +146,13 @@ impl WitsClient {
         self
     }
 
+    /// Use a specific item map instead of the one from config.
+    #[cfg(test)]
+    pub fn with_item_map(mut self, item_map: Arc<WitsItemMap>) -> Self {
+        self.item_map = item_map;
+        self
+    }
+
     /// Connect to WITS server with timeout
     pub async fn connect(&mut self) -> Result<(), WitsError> {
         if self.connected {
@@ -183,6 +198,7 @@ impl WitsClient {
     /// Reconnect with exponential backoff.
     ///
     /// Returns Ok(()) when reconnected, Err if max attempts exhausted.
+    #[allow(dead_code)]
     pub async fn reconnect(&mut self) -> Result<(), WitsError> {
         // Disconnect first
         let _ = self.disconnect().await;
@@ -231,6 +247,7 @@ impl WitsClient {
     /// Read next WITS packet with timeout and stale connection detection.
     ///
     /// Automatically reconnects on timeout or connection drop.
+    #[allow(dead_code)]
     pub async fn read_packet(&mut self) -> Result<WitsPacket, WitsError> {
         // Check for stale connection
         let now = current_unix_secs();
@@ -278,6 +295,31 @@ impl WitsClient {
         }
     }
 
+    /// Read next WITS packet with timeout, leaving reconnection to the caller.
+    ///
+    /// Connects first if not connected. On error the connection is dropped,
+    /// so the next `connect()` opens a fresh stream.
+    pub async fn read_packet_once(&mut self) -> Result<WitsPacket, WitsError> {
+        if !self.connected {
+            self.connect().await?;
+        }
+
+        match self.read_packet_inner().await {
+            Ok(packet) => {
+                self.last_data_time = current_unix_secs();
+                self.packets_received += 1;
+                Ok(packet)
+            }
+            Err(e) => {
+                if matches!(e, WitsError::Timeout) {
+                    self.timeouts += 1;
+                }
+                let _ = self.disconnect().await;
+                Err(e)
+            }
+        }
+    }
+
     /// Inner packet read with timeout — does NOT auto-reconnect.
     async fn read_packet_inner(&mut self) -> Result<WitsPacket, WitsError> {
         let reader = self
@@ -317,7 +359,9 @@ impl WitsClient {
             // End of record
             if line == "!!" {
                 if in_record && !items.is_empty() {
-                    return Ok(Self::items_to_packet(&items));
+                    let packet = Self::items_to_packet(&items, &self.item_map);
+                    self.last_record = items;
+                    return Ok(packet);
                 }
                 in_record = false;
                 continue;
@@ -337,6 +381,17 @@ impl WitsClient {
         }
     }
 
+    /// Packet fields carried by the most recent record (mapped codes only).
+    ///
+    /// A `WitsPacket` defaults absent channels to 0, so this is how a caller
+    /// tells "not sent" from "reading zero".
+    pub fn last_record_channels(&self) -> Vec<&str> {
+        self.last_record
+            .keys()
+            .filter_map(|code| self.item_map.field_for(code))
+            .collect()
+    }
+
     /// Get connection health statistics
     #[allow(dead_code)]
     pub fn stats(&self) -> WitsClientStats {
@@ -353,45 +408,38 @@ impl WitsClient {
         }
     }
 
-    /// Convert parsed items to WitsPacket
-    fn items_to_packet(items: &HashMap<String, f64>) -> WitsPacket {
+    /// Convert parsed items to WitsPacket via the item map
+    fn items_to_packet(items: &HashMap<String, f64>, item_map: &WitsItemMap) -> WitsPacket {
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs();
 
-        // Extract all WITS items with defaults
-        let bit_depth = items.get(wits_items::BIT_DEPTH).copied().unwrap_or(0.0);
-        let hole_depth = items.get(wits_items::HOLE_DEPTH).copied().unwrap_or(0.0);
-        let rop = items.get(wits_items::ROP).copied().unwrap_or(0.0);
-        let hook_load = items.get(wits_items::HOOK_LOAD).copied().unwrap_or(0.0);
-        let wob = items.get(wits_items::WOB).copied().unwrap_or(0.0);
-        let rpm = items.get(wits_items::RPM).copied().unwrap_or(0.0);
-        let torque = items.get(wits_items::TORQUE).copied().unwrap_or(0.0);
-        let spp = items.get(wits_items::SPP).copied().unwrap_or(0.0);
-        let pump_spm = items.get(wits_items::PUMP_SPM_1).copied().unwrap_or(0.0);
-        let flow_in = items.get(wits_items::FLOW_IN).copied().unwrap_or(0.0);
-        let flow_out = items.get(wits_items::FLOW_OUT).copied().unwrap_or(0.0);
-        let pit_volume = items.get(wits_items::PIT_VOLUME).copied().unwrap_or(0.0);
-        let mud_weight_in = items.get(wits_items::MUD_WEIGHT_IN).copied().unwrap_or(0.0);
-        let mud_weight_out = items
-            .get(wits_items::MUD_WEIGHT_OUT)
-            .copied()
-            .unwrap_or(0.0);
-        let mud_temp_in = items.get(wits_items::MUD_TEMP_IN).copied().unwrap_or(0.0);
-        let mud_temp_out = items.get(wits_items::MUD_TEMP_OUT).copied().unwrap_or(0.0);
-        let casing_pressure = items
-            .get(wits_items::CASING_PRESSURE)
-            .copied()
-            .unwrap_or(0.0);
-        let gas_units = items.get(wits_items::GAS_UNITS).copied().unwrap_or(0.0);
-        let h2s = items.get(wits_items::H2S).copied().unwrap_or(0.0);
-        let co2 = items.get(wits_items::CO2).copied().unwrap_or(0.0);
-        let ecd = items.get(wits_items::ECD).copied().unwrap_or(0.0);
-        let block_position = items
-            .get(wits_items::BLOCK_POSITION)
-            .copied()
-            .unwrap_or(0.0);
+        // Resolve item codes to field names; unmapped channels default to 0
+        let fields = item_map.resolve(items);
+        let field = |name: &str| fields.get(name).copied().unwrap_or(0.0);
+        let bit_depth = field("bit_depth");
+        let hole_depth = field("hole_depth");
+        let rop = field("rop");
+        let hook_load = field("hook_load");
+        let wob = field("wob");
+        let rpm = field("rpm");
+        let torque = field("torque");
+        let spp = field("spp");
+        let pump_spm = field("pump_spm");
+        let flow_in = field("flow_in");
+        let flow_out = field("flow_out");
+        let pit_volume = field("pit_volume");
+        let mud_weight_in = field("mud_weight_in");
+        let mud_weight_out = field("mud_weight_out");
+        let mud_temp_in = field("mud_temp_in");
+        let mud_temp_out = field("mud_temp_out");
+        let casing_pressure = field("casing_pressure");
+        let gas_units = field("gas_units");
+        let h2s = field("h2s");
+        let co2 = field("co2");
+        let ecd = field("ecd");
+        let block_position = field("block_position");
 
         // Classify rig state based on parameters
         let rig_state = classify_rig_state(rpm, wob, hook_load, rop, block_position);
@@ -426,15 +474,16 @@ impl WitsClient {
             mud_temp_out,
             // Well control / Gas
             gas_units,
-            background_gas: 0.0,
-            connection_gas: 0.0,
+            background_gas: field("background_gas"),
+            connection_gas: field("connection_gas"),
             h2s,
             co2,
             casing_pressure,
-            annular_pressure: 0.0,
+            annular_pressure: field("annular_pressure"),
+            choke_position_pct: field("choke_position_pct"),
             // Formation
-            pore_pressure: 0.0,
-            fracture_gradient: 0.0,
+            pore_pressure: field("pore_pressure"),
+            fracture_gradient: field("fracture_gradient"),
             // Derived (calculated elsewhere)
             mse: 0.0,
             d_exponent: 0.0,
@@ -964,11 +1013,137 @@ impl Default for DepthContinuityTracker {
     }
 }
 
+// ============================================================================
+// Rate-of-Change Filtering
+// ============================================================================
+
+/// Per-channel state for the rate-of-change filter
+#[derive(Debug, Clone, Copy, Default)]
+struct ChannelRate {
+    /// Last accepted value and its timestamp
+    last_good: Option<(f64, u64)>,
+    /// Previous out-of-limit sample (value, timestamp), awaiting confirmation
+    suspect: Option<(f64, u64)>,
+}
+
+/// Stateful filter for physically impossible single-sample jumps.
+///
+/// Range checks catch values no rig can produce; this catches values that
+/// are plausible on their own but got there impossibly fast (WOB jumping
+/// 50 klbs in one second). A sample exceeding its channel's max rate is
+/// flagged and, when `hold_glitches` is set, replaced with the last good
+/// value. If the next sample stays near the new level the jump is accepted
+/// as a genuine transient, so a real step change costs one sample of lag.
+#[derive(Debug, Clone, Default)]
+pub struct RateOfChangeFilter {
+    channels: [ChannelRate; 6],
+    /// Samples held at their previous value (for diagnostics)
+    pub held_count: u64,
+}
+
+impl RateOfChangeFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check (and optionally repair) a packet against the per-channel limits.
+    ///
+    /// Returns one `Warning` issue per glitched channel.
+    pub fn apply(
+        &mut self,
+        packet: &mut WitsPacket,
+        cfg: &crate::config::SensorRateLimitConfig,
+    ) -> Vec<DataQualityIssue> {
+        let mut issues = Vec::new();
+        if !cfg.enabled {
+            return issues;
+        }
+
+        let ts = packet.timestamp;
+        let fields: [(&str, &mut f64); 6] = [
+            ("wob", &mut packet.wob),
+            ("rpm", &mut packet.rpm),
+            ("torque", &mut packet.torque),
+            ("spp", &mut packet.spp),
+            ("hook_load", &mut packet.hook_load),
+            ("flow_in", &mut packet.flow_in),
+        ];
+
+        for (((name, value), (_, max_rate)), state) in fields
+            .into_iter()
+            .zip(cfg.channel_limits())
+            .zip(self.channels.iter_mut())
+        {
+            let current = *value;
+            let Some((prev, prev_ts)) = state.last_good else {
+                state.last_good = Some((current, ts));
+                continue;
+            };
+            if max_rate <= 0.0 {
+                state.last_good = Some((current, ts));
+                continue;
+            }
+
+            let dt = ts.saturating_sub(prev_ts).max(1) as f64;
+            let within_limit = (current - prev).abs() <= max_rate * dt;
+            // A persisting new level is a real transient, not a glitch
+            let confirmed = state.suspect.is_some_and(|(s, s_ts)| {
+                (current - s).abs() <= max_rate * ts.saturating_sub(s_ts).max(1) as f64
+            });
+
+            if within_limit || confirmed {
+                state.last_good = Some((current, ts));
+                state.suspect = None;
+                continue;
+            }
+
+            state.suspect = Some((current, ts));
+            let rate = (current - prev).abs() / dt;
+            let message = if cfg.hold_glitches {
+                *value = prev;
+                self.held_count += 1;
+                format!(
+                    "{} jumped {:.1} -> {:.1} ({:.1}/s, max {:.1}/s) — held at previous value",
+                    name, prev, current, rate, max_rate
+                )
+            } else {
+                format!(
+                    "{} jumped {:.1} -> {:.1} ({:.1}/s, max {:.1}/s)",
+                    name, prev, current, rate, max_rate
+                )
+            };
+            issues.push(DataQualityIssue {
+                field: name.to_string(),
+                severity: QualitySeverity::Warning,
+                message,
+            });
+        }
+
+        issues
+    }
+}
+
 /// Parse WITS JSON format (for testing with wits_simulator.py)
+///
+/// Accepts a full `WitsPacket` object, or an object of raw WITS item codes
+/// (`{"0108": 10500.0, "0813": 310.0}`) resolved through the shared item map.
 #[allow(dead_code)]
 pub fn parse_wits_json(json_str: &str) -> Result<WitsPacket> {
-    let packet: WitsPacket = serde_json::from_str(json_str).context("Failed to parse WITS JSON")?;
-    Ok(packet)
+    parse_wits_json_with_map(json_str, &WitsItemMap::shared())
+}
+
+/// [`parse_wits_json`] with an explicit item map for raw item-code objects.
+fn parse_wits_json_with_map(json_str: &str, item_map: &WitsItemMap) -> Result<WitsPacket> {
+    if let Ok(packet) = serde_json::from_str::<WitsPacket>(json_str) {
+        return Ok(packet);
+    }
+    let items: HashMap<String, f64> =
+        serde_json::from_str(json_str).context("Failed to parse WITS JSON")?;
+    let items = items
+        .into_iter()
+        .filter(|(_, value)| value.is_finite())
+        .collect();
+    Ok(WitsClient::items_to_packet(&items, item_map))
 }
 
 /// WITS Level 0 frame builder (for testing/simulation)
@@ -1020,7 +1195,7 @@ mod tests {
         items.insert(wits_items::FLOW_IN.to_string(), 500.0);
         items.insert(wits_items::FLOW_OUT.to_string(), 505.0);
 
-        let packet = WitsClient::items_to_packet(&items);
+        let packet = WitsClient::items_to_packet(&items, &WitsItemMap::builtin());
 
         assert_eq!(packet.bit_depth, 10500.0);
         assert_eq!(packet.rop, 45.5);
@@ -1031,6 +1206,48 @@ mod tests {
         assert_eq!(packet.rig_state, RigState::Drilling);
     }
 
+    #[tokio::test]
+    async fn test_custom_item_map_frame_parsing() {
+        use tokio::io::AsyncWriteExt;
+
+        let item_map = WitsItemMap::from_toml(
+            "[items]\n\"8:13\" = \"annular_pressure\"\n\"12:05\" = \"choke_position_pct\"\n",
+        )
+        .unwrap();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let frame = WitsFrameBuilder::new()
+                .add_item(wits_items::BIT_DEPTH, 10500.0)
+                .add_item("0813", 310.0)
+                .add_item("1205", 42.5)
+                .add_item("9901", 7.0)
+                .build();
+            stream.write_all(frame.as_bytes()).await.unwrap();
+        });
+
+        let mut client = WitsClient::new("127.0.0.1", port).with_item_map(Arc::new(item_map));
+        let packet = client.read_packet_once().await.unwrap();
+        server.await.unwrap();
+
+        assert_eq!(packet.bit_depth, 10500.0);
+        assert_eq!(packet.annular_pressure, 310.0);
+        assert_eq!(packet.choke_position_pct, 42.5);
+    }
+
+    #[test]
+    fn test_parse_wits_json_item_codes() {
+        let item_map =
+            WitsItemMap::from_toml("[items]\n\"08:13\" = \"annular_pressure\"\n").unwrap();
+        let json = r#"{"0108": 10500.0, "0116": 25.0, "0813": 310.0, "9901": 7.0}"#;
+
+        let packet = parse_wits_json_with_map(json, &item_map).unwrap();
+        assert_eq!(packet.bit_depth, 10500.0);
+        assert_eq!(packet.wob, 25.0);
+        assert_eq!(packet.annular_pressure, 310.0);
+    }
+
     #[test]
     fn test_rig_state_classification() {
         // Drilling
@@ -1207,4 +1424,57 @@ mod tests {
             "Should compare against last good packet"
         );
     }
+
+    // ── RateOfChangeFilter tests ──────────────────────────────────
+
+    fn wob_packet(timestamp: u64, wob: f64) -> WitsPacket {
+        WitsPacket {
+            timestamp,
+            wob,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rate_filter_holds_single_sample_wob_glitch() {
+        let cfg = crate::config::SensorRateLimitConfig::default();
+        let mut filter = RateOfChangeFilter::new();
+
+        let mut p = wob_packet(1, 20.0);
+        assert!(filter.apply(&mut p, &cfg).is_empty());
+
+        // 50 klbs in one second, gone on the next sample
+        let mut glitch = wob_packet(2, 70.0);
+        let issues = filter.apply(&mut glitch, &cfg);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "wob");
+        assert_eq!(glitch.wob, 20.0, "glitch should be held at previous value");
+
+        let mut p = wob_packet(3, 20.5);
+        assert!(filter.apply(&mut p, &cfg).is_empty());
+        assert_eq!(p.wob, 20.5);
+        assert_eq!(filter.held_count, 1);
+    }
+
+    #[test]
+    fn rate_filter_passes_sustained_fast_wob_increase() {
+        let cfg = crate::config::SensorRateLimitConfig::default();
+        let mut filter = RateOfChangeFilter::new();
+
+        // Fast ramp within the limit passes untouched
+        for (i, wob) in [5.0, 20.0, 35.0].into_iter().enumerate() {
+            let mut p = wob_packet(i as u64, wob);
+            assert!(filter.apply(&mut p, &cfg).is_empty());
+            assert_eq!(p.wob, wob);
+        }
+
+        // A step beyond the limit that persists: held for one sample, then accepted
+        let mut step = wob_packet(3, 60.0);
+        assert_eq!(filter.apply(&mut step, &cfg).len(), 1);
+        for (i, wob) in [61.0, 62.0].into_iter().enumerate() {
+            let mut p = wob_packet(4 + i as u64, wob);
+            assert!(filter.apply(&mut p, &cfg).is_empty());
+            assert_eq!(p.wob, wob);
+        }
+    }
 }
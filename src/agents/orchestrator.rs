@@ -6,10 +6,14 @@
 //! ## Specialists and Weights
 //!
 //! Each specialist implements the `Specialist` trait (see `agents::specialists`):
-//! 1. **MSE Specialist** (25%): Drilling efficiency analysis
-//! 2. **Hydraulic Specialist** (25%): SPP, flow, ECD margin
+//! 1. **MSE Specialist** (20%): Drilling efficiency analysis
+//! 2. **Hydraulic Specialist** (20%): SPP, flow, ECD margin
 //! 3. **WellControl Specialist** (30%): Kick/loss, gas, pit volume (safety-critical)
-//! 4. **Formation Specialist** (20%): D-exponent, torque trends
+//! 4. **Formation Specialist** (15%): D-exponent, torque trends
+//! 5. **StuckPipe Specialist** (15%): Mechanical sticking risk
+//!
+//! Weights are read from `[orchestrator]` (or `[ensemble_weights]`) at vote
+//! time and normalised to 1.0. A specialist with weight 0 is not consulted.
 //!
 //! ## Voting Rules
 //!
@@ -31,6 +35,7 @@
 //! | 3      | Unstable/kick      | WellControl ×1.5, MSE ×0.7, Formation ×0.8       |
 
 use super::specialists::{self, Specialist};
+use crate::config::WellConfig;
 use crate::strategic::advisory::{self, VotingResult};
 use crate::types::{
     AdvisoryTicket, DrillingPhysicsReport, FinalSeverity, SpecialistVote, TicketSeverity,
@@ -136,6 +141,54 @@ fn apply_regime_weights(votes: &mut [SpecialistVote], regime_id: u8) -> &'static
     profile.label
 }
 
+// ============================================================================
+// Configured specialist weights
+// ============================================================================
+
+/// `[orchestrator]` / `[ensemble_weights]` key for a specialist name.
+fn weight_key(specialist: &str) -> &str {
+    match specialist {
+        "MSE" => "mse",
+        "Hydraulic" => "hydraulic",
+        "WellControl" => "well_control",
+        "Formation" => "formation",
+        "StuckPipe" => "stuck_pipe",
+        other => other,
+    }
+}
+
+/// Configured base weight per specialist (in order), normalised to sum to 1.0.
+///
+/// Zero-weight specialists stay at 0 so the caller can skip them. If no
+/// specialist has a positive weight, all get equal weights.
+fn effective_weights(specialists: &[Box<dyn Specialist>], cfg: &WellConfig) -> Vec<f64> {
+    let raw: Vec<f64> = specialists
+        .iter()
+        .map(|s| cfg.specialist_weight(weight_key(s.name())))
+        .collect();
+    let total: f64 = raw.iter().sum();
+    if total > 1e-10 {
+        raw.iter().map(|w| w / total).collect()
+    } else if !raw.is_empty() {
+        vec![1.0 / raw.len() as f64; raw.len()]
+    } else {
+        raw
+    }
+}
+
+/// Effective weighting as "MSE 20%, Hydraulic 20%, ...", skipped specialists omitted.
+pub fn weighting_summary(cfg: &WellConfig) -> String {
+    let specialists = specialists::default_specialists();
+    let weights = effective_weights(&specialists, cfg);
+    specialists
+        .iter()
+        .zip(weights)
+        .filter(|(_, w)| *w > 0.0)
+        .map(|(s, w)| format!("{} {:.0}%", s.name(), w * 100.0))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 // ============================================================================
 // Orchestrator
 // ============================================================================
@@ -149,7 +202,7 @@ pub struct Orchestrator {
 }
 
 impl Orchestrator {
-    /// Create a new orchestrator with the default 4 drilling specialists
+    /// Create a new orchestrator with the default 5 drilling specialists
     pub fn new() -> Self {
         Self {
             specialists: specialists::default_specialists(),
@@ -171,14 +224,32 @@ impl Orchestrator {
         ticket: &AdvisoryTicket,
         physics: &DrillingPhysicsReport,
         regime_id: u8,
+    ) -> VotingResult {
+        let cfg = crate::config::get();
+        self.vote_with(ticket, physics, regime_id, &cfg)
+    }
+
+    /// `vote` against an explicit config's specialist weights.
+    fn vote_with(
+        &mut self,
+        ticket: &AdvisoryTicket,
+        physics: &DrillingPhysicsReport,
+        regime_id: u8,
+        cfg: &WellConfig,
     ) -> VotingResult {
         self.reports_generated += 1;
 
-        // Collect votes from all specialists via the trait
+        // Collect votes from the weighted specialists; zero-weight ones are skipped
+        let weights = effective_weights(&self.specialists, cfg);
         let mut votes: Vec<SpecialistVote> = self
             .specialists
             .iter()
-            .map(|s| s.evaluate(ticket, physics))
+            .zip(weights)
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(s, weight)| SpecialistVote {
+                weight,
+                ..s.evaluate(ticket, physics)
+            })
             .collect();
 
         // Apply regime-aware weight scaling (Phase 6)
@@ -353,6 +424,7 @@ mod tests {
             founder_detected: false,
             founder_severity: 0.0,
             optimal_wob_estimate: 0.0,
+            bit_wear: crate::types::BitWearEstimate::default(),
         }
     }
 
@@ -416,6 +488,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_zero_weight_specialist_is_skipped() {
+        ensure_config();
+        let mut orchestrator = Orchestrator::new();
+        let mut cfg = WellConfig::default();
+        cfg.ensemble_weights.formation = 0.0;
+
+        let result = orchestrator.vote_with(&create_test_ticket(), &create_test_physics(), 0, &cfg);
+
+        assert_eq!(result.votes.len(), 4);
+        assert!(result.votes.iter().all(|v| v.specialist != "Formation"));
+        let total_weight: f64 = result.votes.iter().map(|v| v.weight).sum();
+        assert!((total_weight - 1.0).abs() < 1e-9, "got {total_weight}");
+    }
+
+    #[test]
+    fn test_orchestrator_table_overrides_listed_specialists() {
+        let specialists = specialists::default_specialists();
+        let mut cfg = WellConfig::default();
+        cfg.orchestrator.mse = Some(0.4);
+        cfg.orchestrator.well_control = Some(0.6);
+        cfg.orchestrator.formation = Some(0.0);
+
+        // Listed weights replace the ensemble ones, zeroed specialists drop
+        // out, and the rest renormalise to 1.0
+        let weights = effective_weights(&specialists, &cfg);
+        let total = 0.4 + 0.20 + 0.6 + 0.15;
+        let expected = [0.4 / total, 0.20 / total, 0.6 / total, 0.0, 0.15 / total];
+        for (w, e) in weights.iter().zip(expected) {
+            assert!((w - e).abs() < 1e-9, "{weights:?}");
+        }
+        assert!(!weighting_summary(&cfg).contains("Formation"));
+
+        // Default config: ensemble weights, already summing to 1.0
+        let weights = effective_weights(&specialists, &WellConfig::default());
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!((weights[2] - 0.30).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_partial_orchestrator_table_keeps_unlisted_ensemble_weights() {
+        let specialists = specialists::default_specialists();
+        let mut cfg = WellConfig::default();
+        cfg.orchestrator.formation = Some(0.1);
+        assert!(cfg.validate().is_ok());
+
+        // Only Formation is listed; every other specialist still votes
+        let weights = effective_weights(&specialists, &cfg);
+        let total = 0.20 + 0.20 + 0.30 + 0.1 + 0.15;
+        let expected = [
+            0.20 / total,
+            0.20 / total,
+            0.30 / total,
+            0.1 / total,
+            0.15 / total,
+        ];
+        for (w, e) in weights.iter().zip(expected) {
+            assert!((w - e).abs() < 1e-9, "{weights:?}");
+        }
+        assert_eq!(
+            weighting_summary(&cfg),
+            "MSE 21%, Hydraulic 21%, WellControl 32%, Formation 11%, StuckPipe 16%"
+        );
+    }
+
     #[test]
     fn test_efficiency_score() {
         ensure_config();
@@ -10,6 +10,8 @@
 //! - CONFIRMED if: well control indicators persistent
 //! - REJECTED if: transient spike, returned to baseline
 //! - UNCERTAIN if: insufficient history or conflicting signals
+//! - SUPPRESSED (rejected) if: operators have rejected most of the category's
+//!   advisories and this one is below High severity
 //!
 //! ## LLM Integration
 //!
@@ -18,7 +20,9 @@
 //! and ticket details to generate actionable drilling recommendations.
 
 use crate::baseline::ThresholdManager;
+use crate::config::FeedbackConfig;
 use crate::physics_engine;
+use crate::storage::suggestions::{self, CategoryStats};
 use crate::types::{
     AdvisoryTicket, AnomalyCategory, CheckStatus, EnhancedPhysicsReport, FinalSeverity,
     HistoryEntry, TicketEvent, TicketStage, VerificationResult, VerificationStatus,
@@ -43,6 +47,38 @@ pub struct StrategicAgent {
     threshold_manager: Option<Arc<RwLock<ThresholdManager>>>,
     /// Equipment ID for baseline lookups
     equipment_id: String,
+    /// Operator feedback stats behind category suppression
+    feedback_stats: FeedbackStatsCache,
+    /// Suppression settings and stats pinned by tests in place of the
+    /// global config and the feedback store
+    #[cfg(test)]
+    feedback_override: Option<(FeedbackConfig, Vec<CategoryStats>)>,
+}
+
+/// Per-category feedback stats for one well, rebuilt only when a feedback
+/// record has been persisted since the last load (see
+/// `storage::feedback::generation`).
+#[derive(Debug, Default)]
+struct FeedbackStatsCache {
+    generation: Option<u64>,
+    well_id: String,
+    stats: Vec<CategoryStats>,
+}
+
+impl FeedbackStatsCache {
+    fn current(&mut self, well_id: &str) -> &[CategoryStats] {
+        let generation = crate::storage::feedback::generation();
+        if self.generation != Some(generation) || self.well_id != well_id {
+            let records: Vec<_> = crate::storage::feedback::load_all()
+                .into_iter()
+                .filter(|r| r.well_id == well_id)
+                .collect();
+            self.stats = suggestions::compute_stats(&records);
+            self.generation = Some(generation);
+            self.well_id = well_id.to_string();
+        }
+        &self.stats
+    }
 }
 
 impl std::fmt::Debug for StrategicAgent {
@@ -64,6 +100,9 @@ impl StrategicAgent {
             verbose: false,
             threshold_manager: None,
             equipment_id: "RIG".to_string(),
+            feedback_stats: FeedbackStatsCache::default(),
+            #[cfg(test)]
+            feedback_override: None,
         }
     }
 
@@ -77,6 +116,9 @@ impl StrategicAgent {
             verbose: false,
             threshold_manager: Some(threshold_manager),
             equipment_id: equipment_id.to_string(),
+            feedback_stats: FeedbackStatsCache::default(),
+            #[cfg(test)]
+            feedback_override: None,
         }
     }
 
@@ -140,9 +182,19 @@ impl StrategicAgent {
         }
 
         // Apply verification logic based on anomaly category
-        let (status, reasoning, final_severity, send_to_dashboard) =
+        let (mut status, mut reasoning, final_severity, mut send_to_dashboard) =
             self.apply_verification_logic(&mut traced_ticket, &enhanced_physics, history);
 
+        // Hold back chronic false-positive categories unless the advisory is severe
+        if status == VerificationStatus::Confirmed && final_severity < FinalSeverity::High {
+            if let Some(why) = self.suppression_reason(ticket.category) {
+                traced_ticket.log_failed(TicketStage::FinalDecision, why.clone());
+                status = VerificationStatus::Rejected;
+                reasoning = format!("{} ({})", why, reasoning);
+                send_to_dashboard = false;
+            }
+        }
+
         // Log the final decision
         let final_status = match status {
             VerificationStatus::Confirmed => CheckStatus::Passed,
@@ -177,6 +229,22 @@ impl StrategicAgent {
         }
     }
 
+    /// Why `category` is held back by operator feedback on the current well,
+    /// if it is. Stats come from the cache, so the feedback store is only
+    /// scanned after new feedback arrives.
+    fn suppression_reason(&mut self, category: AnomalyCategory) -> Option<String> {
+        #[cfg(test)]
+        if let Some((cfg, stats)) = &self.feedback_override {
+            return feedback_suppression(category, stats, cfg);
+        }
+        let cfg = crate::config::get();
+        if !cfg.feedback.suppression_enabled {
+            return None;
+        }
+        let stats = self.feedback_stats.current(&cfg.well.name);
+        feedback_suppression(category, stats, &cfg.feedback)
+    }
+
     /// Apply verification decision logic based on anomaly category
     fn apply_verification_logic(
         &self,
@@ -198,6 +266,13 @@ impl StrategicAgent {
                 self.verify_drilling_efficiency(ticket, physics, history)
             }
             AnomalyCategory::Formation => self.verify_formation(ticket, physics, history),
+            // Equipment advisories come from deterministic surface checks
+            AnomalyCategory::Equipment => (
+                VerificationStatus::Confirmed,
+                "Surface equipment condition confirmed by deterministic check".to_string(),
+                FinalSeverity::Medium,
+                true,
+            ),
             AnomalyCategory::None => (
                 VerificationStatus::Rejected,
                 "No anomaly category specified".to_string(),
@@ -536,6 +611,12 @@ impl StrategicAgent {
             .iter()
             .any(|d| d.contains("Pack-off"));
 
+        let has_cuttings_loading = physics
+            .base
+            .detected_dysfunctions
+            .iter()
+            .any(|d| d.contains("Cuttings loading"));
+
         // Check for founder condition from physics report (uses full history for reliable detection)
         let has_founder = physics.base.founder_detected;
         let founder_severity = physics.base.founder_severity;
@@ -633,6 +714,29 @@ impl StrategicAgent {
             );
         }
 
+        // === CUTTINGS LOADING (gradual rise with ECD climbing) ===
+        if has_cuttings_loading && !has_packoff {
+            ticket.log_passed(
+                TicketStage::MseAnalysis,
+                format!(
+                    "Cuttings loading: gradual SPP/torque rise with ECD trending up, torque_delta={:.1}%",
+                    avg_torque_delta * 100.0
+                ),
+            );
+            return (
+                VerificationStatus::Confirmed,
+                format!(
+                    "CONFIRMED: Cuttings loading — hole cleaning inadequate{formation_label}. \
+                     Torque increase: {:.1}%, ECD {:.2} ppg and rising. \
+                     Increase flow rate and rotation, pump a sweep, and circulate before drilling ahead.",
+                    avg_torque_delta * 100.0,
+                    physics.base.current_ecd
+                ),
+                FinalSeverity::Medium,
+                true,
+            );
+        }
+
         // === PACK-OFF DETECTION ===
         if has_packoff
             || (avg_torque_delta > cfg.thresholds.mechanical.torque_increase_critical
@@ -939,6 +1043,39 @@ impl Default for StrategicAgent {
     }
 }
 
+/// Why a category's below-High advisories are held back by operator feedback.
+///
+/// A category is suppressed once at least `suppression_min_rated` of its
+/// advisories were rated confirmed or false positive and the confirmation
+/// rate sits below `suppression_confirmation_floor`. Well control is never
+/// suppressed. Stats are refreshed whenever feedback is persisted, so new
+/// confirmations that lift the rate above the floor end the suppression.
+pub fn feedback_suppression(
+    category: AnomalyCategory,
+    stats: &[CategoryStats],
+    cfg: &FeedbackConfig,
+) -> Option<String> {
+    if !cfg.suppression_enabled || category == AnomalyCategory::WellControl {
+        return None;
+    }
+    let s = stats.iter().find(|s| s.category == category)?;
+    let rated = s.confirmed + s.false_positives;
+    if rated < cfg.suppression_min_rated.max(1)
+        || s.confirmation_rate >= cfg.suppression_confirmation_floor
+    {
+        return None;
+    }
+    Some(format!(
+        "Suppressed: operators confirmed {}/{} rated {} advisories ({:.0}% < {:.0}% floor); \
+         only HIGH or CRITICAL pass",
+        s.confirmed,
+        rated,
+        category,
+        s.confirmation_rate * 100.0,
+        cfg.suppression_confirmation_floor * 100.0,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1041,6 +1178,112 @@ mod tests {
         assert!(result.send_to_dashboard);
     }
 
+    fn rated(
+        category: AnomalyCategory,
+        confirmed: usize,
+        false_positives: usize,
+    ) -> Vec<crate::storage::feedback::FeedbackRecord> {
+        use crate::storage::feedback::{FeedbackOutcome, FeedbackRecord};
+        (0..confirmed + false_positives)
+            .map(|i| FeedbackRecord {
+                advisory_timestamp: i as u64,
+                well_id: String::new(),
+                outcome: if i < confirmed {
+                    FeedbackOutcome::Confirmed
+                } else {
+                    FeedbackOutcome::FalsePositive
+                },
+                category,
+                trigger_parameter: String::new(),
+                trigger_value: 0.0,
+                threshold_value: 0.0,
+                submitted_by: "driller".to_string(),
+                source: crate::storage::feedback::FeedbackSource::Operator,
+                submitted_at: 0,
+                notes: String::new(),
+                actual_cause: None,
+                action_taken: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_chronic_false_positive_category_suppressed() {
+        let cfg = FeedbackConfig {
+            suppression_enabled: true,
+            ..FeedbackConfig::default()
+        };
+        let stats = suggestions::compute_stats(&rated(AnomalyCategory::Mechanical, 0, 20));
+
+        let why = feedback_suppression(AnomalyCategory::Mechanical, &stats, &cfg)
+            .expect("0/20 confirmations should suppress");
+        assert!(why.contains("0/20"));
+        // A category with no feedback history is unaffected
+        assert!(feedback_suppression(AnomalyCategory::Hydraulics, &stats, &cfg).is_none());
+
+        // Well control is never suppressed
+        let stats = suggestions::compute_stats(&rated(AnomalyCategory::WellControl, 0, 20));
+        assert!(feedback_suppression(AnomalyCategory::WellControl, &stats, &cfg).is_none());
+    }
+
+    #[test]
+    fn test_suppression_lifts_as_confirmations_arrive() {
+        let cfg = FeedbackConfig {
+            suppression_enabled: true,
+            ..FeedbackConfig::default()
+        };
+        // Too few rated advisories to judge
+        let stats = suggestions::compute_stats(&rated(AnomalyCategory::Mechanical, 0, 9));
+        assert!(feedback_suppression(AnomalyCategory::Mechanical, &stats, &cfg).is_none());
+
+        // 5/20 confirmed = 25%, above the 20% floor
+        let stats = suggestions::compute_stats(&rated(AnomalyCategory::Mechanical, 5, 15));
+        assert!(feedback_suppression(AnomalyCategory::Mechanical, &stats, &cfg).is_none());
+
+        // Off by default
+        let stats = suggestions::compute_stats(&rated(AnomalyCategory::Mechanical, 0, 20));
+        let cfg = FeedbackConfig::default();
+        assert!(feedback_suppression(AnomalyCategory::Mechanical, &stats, &cfg).is_none());
+    }
+
+    #[test]
+    fn test_verify_ticket_suppresses_chronic_category_below_high() {
+        ensure_config();
+        let history = create_test_history();
+        let mut stats = suggestions::compute_stats(&rated(AnomalyCategory::Equipment, 0, 20));
+        stats.extend(suggestions::compute_stats(&rated(
+            AnomalyCategory::WellControl,
+            0,
+            20,
+        )));
+        let enabled = FeedbackConfig {
+            suppression_enabled: true,
+            ..FeedbackConfig::default()
+        };
+
+        // Equipment advisories confirm at Medium; 0/20 confirmations holds them back
+        let mut agent = StrategicAgent::new();
+        agent.feedback_override = Some((enabled, stats.clone()));
+        let ticket = create_test_ticket(AnomalyCategory::Equipment, TicketSeverity::Medium);
+        let result = agent.verify_ticket(&ticket, &history);
+        assert_eq!(result.status, VerificationStatus::Rejected);
+        assert!(!result.send_to_dashboard);
+        assert!(result.reasoning.starts_with("Suppressed"));
+
+        // Well control passes whatever the feedback says
+        let ticket = create_test_ticket(AnomalyCategory::WellControl, TicketSeverity::High);
+        let result = agent.verify_ticket(&ticket, &history);
+        assert_eq!(result.status, VerificationStatus::Confirmed);
+
+        // Off by default: the same stats change nothing
+        let mut agent = StrategicAgent::new();
+        agent.feedback_override = Some((FeedbackConfig::default(), stats));
+        let ticket = create_test_ticket(AnomalyCategory::Equipment, TicketSeverity::Medium);
+        let result = agent.verify_ticket(&ticket, &history);
+        assert_eq!(result.status, VerificationStatus::Confirmed);
+        assert!(result.send_to_dashboard);
+    }
+
     #[test]
     fn test_verification_count() {
         ensure_config();
@@ -23,12 +23,12 @@
 //! - State filter: Only during Drilling or Reaming
 //! - Cooldown: 60 seconds (CRITICAL bypasses)
 
-use crate::baseline::{wits_metrics, BaselineOverrides, ThresholdManager};
+use crate::baseline::{wits_metrics, BaselineOverrides, OverrideSigmas, ThresholdManager};
 use crate::physics_engine;
 use crate::types::{
     AdvisoryTicket, AnomalyCategory, Campaign, CfcFeatureSurpriseInfo, DrillingMetrics,
-    HistoryEntry, Operation, RigState, ThresholdBreach, TicketContext, TicketSeverity, TicketStage,
-    TicketType, WitsPacket,
+    HistoryEntry, ModeTransitionEvent, Operation, RigState, ThresholdBreach, TicketContext,
+    TicketSeverity, TicketStage, TicketType, WitsPacket,
 };
 
 // ============================================================================
@@ -60,6 +60,9 @@ use std::sync::{Arc, RwLock};
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+/// Samples a WITS metric needs before the agent locks its baseline
+const AUTO_LOCK_MIN_SAMPLES: usize = 100;
+
 // ============================================================================
 // Baseline Management
 // ============================================================================
@@ -317,6 +320,8 @@ pub struct TacticalAgent {
     threshold_manager: Option<Arc<RwLock<ThresholdManager>>>,
     /// Sigma-derived overrides computed after baselines lock
     baseline_overrides: Option<BaselineOverrides>,
+    /// Sigmas `baseline_overrides` were derived with (`None` = restored from disk)
+    override_sigmas: Option<OverrideSigmas>,
     /// ACI conformal interval tracker — second opinion on anomaly severity
     aci_tracker: crate::aci::AciTracker,
     /// Latest ACI result (only during drilling/reaming)
@@ -325,6 +330,8 @@ pub struct TacticalAgent {
     cfc_network: crate::cfc::DualCfcNetwork,
     /// Latest CfC result (only during drilling/reaming)
     cfc_result: Option<crate::cfc::DualCfcResult>,
+    /// Wall time of the latest dual CfC update (only during drilling/reaming)
+    cfc_update_time: Option<std::time::Duration>,
     /// CfC-based formation transition detector
     cfc_formation_detector: crate::cfc::formation_detector::FormationTransitionDetector,
     /// Latest CfC formation transition event (if any)
@@ -349,16 +356,36 @@ pub struct TacticalAgent {
     pending_operation: Option<Operation>,
     /// Count of consecutive packets in pending operation state
     pending_operation_count: u32,
+    /// Debounced rig state (hysteresis over `classify_rig_state`)
+    rig_state_classifier: crate::physics_engine::RigStateClassifier,
     /// Count of consecutive founder-positive packets (debounce counter)
     founder_consecutive_count: u32,
     /// Count of consecutive pit-rate-anomaly packets (debounce counter)
     pit_rate_consecutive_count: u32,
+    /// Pump trip / changeover detector (suppresses flow alarms, RULE 6c)
+    pump_events: crate::physics_engine::pump_events::PumpEventTracker,
+    /// MPD choke adjustments (contextualizes SPP changes)
+    choke: crate::physics_engine::choke::ChokeAdjustmentTracker,
+    /// Drillability coefficients learned from ML reports (formation hardness)
+    hardness_calibration: crate::physics_engine::hardness_calibration::HardnessCalibration,
+    /// Drift / degradation monitor for the dual CfC networks
+    cfc_health: crate::cfc::health::CfcHealthMonitor,
     /// Depth-ahead CfC network for formation transition forecasting
     depth_ahead: Option<crate::cfc::depth_ahead::DepthAheadNetwork>,
     /// Latest depth-ahead result (only during drilling/reaming)
     depth_ahead_result: Option<crate::cfc::depth_ahead::DepthAheadResult>,
     /// Current formation name (set by coordinator for formation-aware baselines)
     current_formation_name: Option<String>,
+    /// Learning-to-operational transition (set once enough baselines lock)
+    operational_since: Option<ModeTransitionEvent>,
+    /// Transition event not yet picked up by the processing loop
+    pending_mode_transition: Option<ModeTransitionEvent>,
+    /// Warns when baseline learning stops progressing toward locking
+    learning_watchdog: crate::baseline::watchdog::LearningStallWatchdog,
+    /// Packet timestamp drifted baselines were last saved
+    last_drift_save: Option<u64>,
+    /// Create (provisional) tickets while baselines are still learning
+    learning_preview: bool,
 }
 
 impl std::fmt::Debug for TacticalAgent {
@@ -385,10 +412,12 @@ impl TacticalAgent {
             equipment_id: "RIG".to_string(),
             threshold_manager: None,
             baseline_overrides: None,
+            override_sigmas: None,
             aci_tracker: crate::aci::AciTracker::new(crate::aci::AciConfig::default()),
             aci_result: None,
-            cfc_network: crate::cfc::DualCfcNetwork::new(42),
+            cfc_network: crate::cfc::DualCfcNetwork::from_well_config(42),
             cfc_result: None,
+            cfc_update_time: None,
             cfc_formation_detector:
                 crate::cfc::formation_detector::FormationTransitionDetector::new(),
             latest_formation_transition: None,
@@ -402,11 +431,22 @@ impl TacticalAgent {
             previous_operation: Operation::Static,
             pending_operation: None,
             pending_operation_count: 0,
+            rig_state_classifier: crate::physics_engine::RigStateClassifier::new(),
             founder_consecutive_count: 0,
             pit_rate_consecutive_count: 0,
+            pump_events: crate::physics_engine::pump_events::PumpEventTracker::new(),
+            choke: crate::physics_engine::choke::ChokeAdjustmentTracker::new(),
+            hardness_calibration:
+                crate::physics_engine::hardness_calibration::HardnessCalibration::new(),
+            cfc_health: crate::cfc::health::CfcHealthMonitor::new(),
             depth_ahead: Some(crate::cfc::depth_ahead::DepthAheadNetwork::new(1042)),
             depth_ahead_result: None,
             current_formation_name: None,
+            operational_since: None,
+            pending_mode_transition: None,
+            learning_watchdog: crate::baseline::watchdog::LearningStallWatchdog::new(),
+            last_drift_save: None,
+            learning_preview: learning_preview_from_config(),
         }
     }
 
@@ -422,10 +462,12 @@ impl TacticalAgent {
             equipment_id: "RIG".to_string(),
             threshold_manager: None,
             baseline_overrides: None,
+            override_sigmas: None,
             aci_tracker: crate::aci::AciTracker::new(crate::aci::AciConfig::default()),
             aci_result: None,
-            cfc_network: crate::cfc::DualCfcNetwork::new(42),
+            cfc_network: crate::cfc::DualCfcNetwork::from_well_config(42),
             cfc_result: None,
+            cfc_update_time: None,
             cfc_formation_detector:
                 crate::cfc::formation_detector::FormationTransitionDetector::new(),
             latest_formation_transition: None,
@@ -439,11 +481,22 @@ impl TacticalAgent {
             previous_operation: Operation::Static,
             pending_operation: None,
             pending_operation_count: 0,
+            rig_state_classifier: crate::physics_engine::RigStateClassifier::new(),
             founder_consecutive_count: 0,
             pit_rate_consecutive_count: 0,
+            pump_events: crate::physics_engine::pump_events::PumpEventTracker::new(),
+            choke: crate::physics_engine::choke::ChokeAdjustmentTracker::new(),
+            hardness_calibration:
+                crate::physics_engine::hardness_calibration::HardnessCalibration::new(),
+            cfc_health: crate::cfc::health::CfcHealthMonitor::new(),
             depth_ahead: Some(crate::cfc::depth_ahead::DepthAheadNetwork::new(1042)),
             depth_ahead_result: None,
             current_formation_name: None,
+            operational_since: None,
+            pending_mode_transition: None,
+            learning_watchdog: crate::baseline::watchdog::LearningStallWatchdog::new(),
+            last_drift_save: None,
+            learning_preview: learning_preview_from_config(),
         }
     }
 
@@ -468,20 +521,29 @@ impl TacticalAgent {
         start_in_learning_mode: bool,
         campaign: Campaign,
     ) -> Self {
-        let (mode, restored_overrides) = if start_in_learning_mode {
-            (TacticalMode::BaselineLearning, None)
+        let (mode, restored_overrides, operational_since) = if start_in_learning_mode {
+            (TacticalMode::BaselineLearning, None, None)
         } else {
             match threshold_manager.read() {
                 Ok(manager) => {
                     if manager.all_wits_locked(equipment_id) {
                         // Restore overrides from persisted state if available
                         let overrides = manager.overrides.clone();
-                        (TacticalMode::DynamicThresholds, overrides)
+                        // Already live from a previous run — record when, but
+                        // don't re-announce the transition.
+                        let (locked, total, locked_at) = manager.wits_lock_progress(equipment_id);
+                        let since = ModeTransitionEvent {
+                            timestamp: locked_at.unwrap_or(0),
+                            locked_metrics: locked,
+                            total_metrics: total,
+                            message: ModeTransitionEvent::BANNER.to_string(),
+                        };
+                        (TacticalMode::DynamicThresholds, overrides, Some(since))
                     } else {
-                        (TacticalMode::BaselineLearning, None)
+                        (TacticalMode::BaselineLearning, None, None)
                     }
                 }
-                Err(_) => (TacticalMode::BaselineLearning, None),
+                Err(_) => (TacticalMode::BaselineLearning, None, None),
             }
         };
 
@@ -503,10 +565,12 @@ impl TacticalAgent {
             equipment_id: equipment_id.to_string(),
             threshold_manager: Some(threshold_manager),
             baseline_overrides: restored_overrides,
+            override_sigmas: None,
             aci_tracker: crate::aci::AciTracker::new(crate::aci::AciConfig::default()),
             aci_result: None,
-            cfc_network: crate::cfc::DualCfcNetwork::new(42),
+            cfc_network: crate::cfc::DualCfcNetwork::from_well_config(42),
             cfc_result: None,
+            cfc_update_time: None,
             cfc_formation_detector:
                 crate::cfc::formation_detector::FormationTransitionDetector::new(),
             latest_formation_transition: None,
@@ -520,11 +584,22 @@ impl TacticalAgent {
             previous_operation: Operation::Static,
             pending_operation: None,
             pending_operation_count: 0,
+            rig_state_classifier: crate::physics_engine::RigStateClassifier::new(),
             founder_consecutive_count: 0,
             pit_rate_consecutive_count: 0,
+            pump_events: crate::physics_engine::pump_events::PumpEventTracker::new(),
+            choke: crate::physics_engine::choke::ChokeAdjustmentTracker::new(),
+            hardness_calibration:
+                crate::physics_engine::hardness_calibration::HardnessCalibration::new(),
+            cfc_health: crate::cfc::health::CfcHealthMonitor::new(),
             depth_ahead: Some(crate::cfc::depth_ahead::DepthAheadNetwork::new(1042)),
             depth_ahead_result: None,
             current_formation_name: None,
+            operational_since,
+            pending_mode_transition: None,
+            learning_watchdog: crate::baseline::watchdog::LearningStallWatchdog::new(),
+            last_drift_save: None,
+            learning_preview: learning_preview_from_config(),
         }
     }
 
@@ -561,6 +636,55 @@ impl TacticalAgent {
             mgr.compute_overrides(&self.equipment_id)
         };
         self.baseline_overrides = Some(overrides);
+        self.override_sigmas = Some(OverrideSigmas::current());
+    }
+
+    /// Re-derive the overrides from the locked baselines when `sigmas` differ
+    /// from the ones they were derived with, e.g. after a config hot-reload.
+    ///
+    /// Updates the live and persisted overrides. Returns true when they changed.
+    fn refresh_overrides_for_sigmas(&mut self, sigmas: OverrideSigmas) -> bool {
+        if self.baseline_overrides.is_none() || self.override_sigmas == Some(sigmas) {
+            return false;
+        }
+        let Some(ref manager) = self.threshold_manager else {
+            return false;
+        };
+        let mut mgr = match manager.write() {
+            Ok(m) => m,
+            Err(e) => {
+                warn!(error = %e, "Failed to lock ThresholdManager for override re-derivation");
+                return false;
+            }
+        };
+        self.override_sigmas = Some(sigmas);
+        let overrides = mgr.derive_overrides(
+            &self.equipment_id,
+            self.current_formation_name.as_deref(),
+            sigmas,
+        );
+        if self.baseline_overrides.as_ref() == Some(&overrides) {
+            return false;
+        }
+
+        info!(
+            equipment_id = %self.equipment_id,
+            sigmas = ?sigmas,
+            flow_imbalance_override = ?overrides.flow_imbalance_warning_gpm,
+            spp_warning_override = ?overrides.spp_deviation_warning_psi,
+            torque_warning_override = ?overrides.torque_warning_fraction,
+            "Override sigmas changed — re-derived baseline overrides"
+        );
+        // Persisted overrides are the global (formation-independent) set
+        let global = mgr.derive_overrides(&self.equipment_id, None, sigmas);
+        mgr.overrides = Some(global);
+        if let Err(e) = mgr.save_to_file(std::path::Path::new(crate::baseline::DEFAULT_STATE_PATH))
+        {
+            warn!(error = %e, "Failed to persist re-derived baseline overrides");
+        }
+        drop(mgr);
+        self.baseline_overrides = Some(overrides);
+        true
     }
 
     /// Get current operating mode
@@ -568,6 +692,17 @@ impl TacticalAgent {
         self.mode
     }
 
+    /// Enable or disable preview detection during baseline learning.
+    pub fn set_learning_preview(&mut self, enabled: bool) {
+        self.learning_preview = enabled;
+    }
+
+    /// True while baselines are learning and preview detection is on, i.e.
+    /// any ticket raised now comes from static thresholds and is provisional.
+    pub fn in_learning_preview(&self) -> bool {
+        self.learning_preview && self.mode == TacticalMode::BaselineLearning
+    }
+
     /// Get equipment ID
     pub fn equipment_id(&self) -> &str {
         &self.equipment_id
@@ -597,21 +732,44 @@ impl TacticalAgent {
         let start = Instant::now();
         self.packets_processed += 1;
 
+        // Debounced rig state: raw classification flaps near thresholds
+        let rig_state = self.rig_state_classifier.classify(packet);
+
         // ====================================================================
         // PHASE 1.5: Baseline Learning (if in learning mode)
         // ====================================================================
         if self.mode == TacticalMode::BaselineLearning {
-            self.feed_baseline_samples(packet);
+            self.feed_baseline_samples(packet, rig_state);
             self.try_auto_lock_baselines(packet.timestamp);
+            self.check_learning_stall(packet.timestamp);
+        } else {
+            self.drift_baselines(packet, rig_state);
+            // Metrics that had not locked when the mode switched keep
+            // learning until the operational fraction is reached.
+            if self.operational_since.is_none() {
+                self.feed_baseline_samples(packet, rig_state);
+                self.lock_remaining_baselines(packet.timestamp);
+            }
+        }
+        if self.operational_since.is_none() {
+            self.check_mode_transition(packet.timestamp);
+        }
+        if self.mode == TacticalMode::DynamicThresholds && crate::config::is_initialized() {
+            let bl = &crate::config::get().baseline_learning;
+            if bl.rederive_overrides_on_reload {
+                self.refresh_overrides_for_sigmas(OverrideSigmas::from_config(bl));
+            }
         }
 
         // ====================================================================
         // PHASE 2: Basic Drilling Physics Calculations (target: < 15ms)
         // ====================================================================
-        let mut metrics = physics_engine::tactical_update(
+        let mut metrics = physics_engine::tactical_update_with_state(
             packet,
+            rig_state,
             self.prev_active_packet.as_ref(),
             self.baseline_overrides.as_ref(),
+            self.drillability_coefficient(),
         );
 
         // Update metrics with baseline deltas
@@ -619,6 +777,11 @@ impl TacticalAgent {
         metrics.torque_delta_percent = self.baseline.torque_delta_percent(packet.torque);
         metrics.spp_delta = self.baseline.spp_delta(packet.spp);
 
+        // An SPP step during a deliberate MPD choke move is operator-induced,
+        // not a formation or hole problem.
+        self.update_choke(packet);
+        self.contextualize_choke_adjustment(&mut metrics, packet);
+
         // ====================================================================
         // PHASE 2.5: Operation Classification
         // ====================================================================
@@ -682,6 +845,8 @@ impl TacticalAgent {
         // ====================================================================
         // PHASE 2.8: Dual CfC Neural Network Update (shadow mode, drilling/reaming)
         // ====================================================================
+        self.apply_cfc_feature_set();
+        let cfc_start = Instant::now();
         self.cfc_result =
             if metrics.state == RigState::Drilling || metrics.state == RigState::Reaming {
                 Some(crate::cfc::update_dual_from_drilling(
@@ -693,6 +858,8 @@ impl TacticalAgent {
             } else {
                 None
             };
+        self.cfc_update_time = self.cfc_result.as_ref().map(|_| cfc_start.elapsed());
+        self.update_cfc_health();
 
         // ====================================================================
         // PHASE 2.8.1: Regime clustering from CfC motor outputs
@@ -773,7 +940,7 @@ impl TacticalAgent {
         // to avoid false positives from Idle→Drilling state transitions
         if matches!(
             metrics.state,
-            RigState::Drilling | RigState::Reaming | RigState::Circulating
+            RigState::Drilling | RigState::Sliding | RigState::Reaming | RigState::Circulating
         ) {
             self.prev_active_packet = Some(packet.clone());
         }
@@ -807,6 +974,10 @@ impl TacticalAgent {
             self.pit_rate_consecutive_count = 0;
         }
 
+        // Track pump trips / changeovers so the flow-balance transient they
+        // cause is not mistaken for a loss or a kick (RULE 6c).
+        self.update_pump_events(packet);
+
         // Update sustained anomaly throttle normal-packet counters.
         // Only during drilling/reaming states — non-drilling packets (Idle,
         // Circulating, etc.) must not erode the throttle since tickets can
@@ -819,8 +990,8 @@ impl TacticalAgent {
         // PHASE 3: Advisory Ticket Decision
         // ====================================================================
 
-        // During baseline learning, never create tickets
-        if self.mode == TacticalMode::BaselineLearning {
+        // During baseline learning, never create tickets (unless previewing)
+        if self.mode == TacticalMode::BaselineLearning && !self.learning_preview {
             return (None, metrics, history_entry);
         }
 
@@ -840,16 +1011,226 @@ impl TacticalAgent {
         (advisory_ticket, metrics, history_entry)
     }
 
+    /// Fold an ML analysis report into the formation-hardness calibration.
+    /// `formation` is the prognosis formation the report's depth range falls in.
+    pub fn ingest_ml_report(
+        &mut self,
+        report: &crate::types::MLInsightsReport,
+        formation: Option<&str>,
+    ) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let cfg = &crate::config::get().hardness_calibration;
+        if !cfg.enabled {
+            return;
+        }
+        if let Some(observed) = self.hardness_calibration.ingest(report, formation, cfg) {
+            debug!(
+                formation = ?formation,
+                observed_coefficient = observed,
+                calibrated = ?self.hardness_calibration.coefficient(formation, cfg),
+                "Hardness calibration updated from ML report"
+            );
+        }
+    }
+
+    /// Calibrated expected-ROP coefficient for the current formation
+    /// (`None` = fixed model).
+    fn drillability_coefficient(&self) -> Option<f64> {
+        if !crate::config::is_initialized() {
+            return None;
+        }
+        let cfg = &crate::config::get().hardness_calibration;
+        if !cfg.enabled {
+            return None;
+        }
+        self.hardness_calibration
+            .coefficient(self.current_formation_name.as_deref(), cfg)
+    }
+
+    /// Feed the CfC health monitor and, with `auto_reset`, re-initialise a
+    /// network that has drifted into a degraded state.
+    fn update_cfc_health(&mut self) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let cfg = &crate::config::get().cfc_health;
+        if !cfg.enabled {
+            return;
+        }
+        let Some(ref result) = self.cfc_result else {
+            return;
+        };
+        self.cfc_health.observe(result, cfg);
+
+        let report = self.cfc_health.report(cfg);
+        for (name, health) in [("fast", &report.fast), ("slow", &report.slow)] {
+            if health.status != crate::cfc::health::NetworkHealthStatus::Degraded {
+                continue;
+            }
+            if !cfg.auto_reset {
+                debug!(network = name, reasons = ?health.reasons, "CfC network degraded");
+                continue;
+            }
+            warn!(
+                network = name,
+                reasons = ?health.reasons,
+                loss_ratio = ?health.loss_ratio,
+                saturated_fraction = health.saturated_fraction,
+                "CfC network degraded — resetting to recalibrate"
+            );
+            if name == "fast" {
+                self.cfc_network.fast.reset();
+                self.cfc_health.fast.note_reset();
+            } else {
+                self.cfc_network.slow.reset();
+                self.cfc_health.slow.note_reset();
+            }
+        }
+    }
+
+    /// Feed the pump event tracker and log pump trips / changeovers.
+    fn update_pump_events(&mut self, packet: &WitsPacket) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let cfg = &crate::config::get().pump_events;
+        if !cfg.enabled {
+            return;
+        }
+        let was_active = self.pump_events.is_active();
+        let event = self.pump_events.update(
+            packet.timestamp,
+            packet.flow_in,
+            packet.pump_spm,
+            packet.spp,
+            cfg,
+        );
+        if !was_active && self.pump_events.is_active() {
+            warn!(
+                depth = packet.bit_depth,
+                flow_in = packet.flow_in,
+                pump_spm = packet.pump_spm,
+                spp = packet.spp,
+                "Pump trip/changeover detected — suppressing flow alarms until flow recovers"
+            );
+        }
+        if let Some(event) = event {
+            info!(
+                kind = ?event.kind,
+                duration_secs = event.duration_secs,
+                pre_flow_gpm = event.pre_flow_gpm,
+                min_flow_gpm = event.min_flow_gpm,
+                pre_spm = event.pre_spm,
+                min_spm = event.min_spm,
+                depth = packet.bit_depth,
+                "Pump event ended"
+            );
+        }
+    }
+
+    /// True while flow-based alarms are suppressed by a pump trip/changeover
+    pub fn pump_event_suppressing(&self) -> bool {
+        self.pump_events.is_suppressing()
+    }
+
+    /// Feed the choke tracker and log detected choke moves.
+    fn update_choke(&mut self, packet: &WitsPacket) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let cfg = &crate::config::get().mpd;
+        if !cfg.enabled {
+            return;
+        }
+        if let Some(adj) = self
+            .choke
+            .update(packet.timestamp, packet.choke_position_pct, cfg)
+        {
+            info!(
+                depth = packet.bit_depth,
+                from_pct = ?adj.from_pct,
+                to_pct = ?adj.to_pct,
+                spp = packet.spp,
+                "MPD choke adjustment detected — attributing pressure changes to the choke"
+            );
+        }
+    }
+
+    /// Record an operator-declared MPD choke adjustment at `timestamp`.
+    pub fn mark_choke_adjustment(&mut self, timestamp: u64) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let cfg = &crate::config::get().mpd;
+        if !cfg.enabled {
+            return;
+        }
+        let adj = self.choke.declare(timestamp, cfg);
+        info!(
+            timestamp = adj.timestamp,
+            context_secs = cfg.context_secs,
+            "MPD choke adjustment declared — attributing pressure changes to the choke"
+        );
+    }
+
+    /// True while pressure changes are attributed to an MPD choke move
+    pub fn choke_adjustment_active(&self) -> bool {
+        self.choke.is_contextualizing()
+    }
+
+    /// Clear an SPP-deviation anomaly raised during a choke move. The ECD
+    /// margin alarm is left alone: a choke move can push ECD toward the
+    /// fracture gradient, and that still needs attention.
+    fn contextualize_choke_adjustment(&self, metrics: &mut DrillingMetrics, packet: &WitsPacket) {
+        if !self.choke.is_contextualizing()
+            || !metrics.is_anomaly
+            || metrics.anomaly_category != AnomalyCategory::Hydraulics
+        {
+            return;
+        }
+        let Some(description) = metrics
+            .anomaly_description
+            .as_ref()
+            .filter(|d| d.contains("SPP"))
+        else {
+            return;
+        };
+        info!(
+            depth = packet.bit_depth,
+            spp = packet.spp,
+            choke_position_pct = packet.choke_position_pct,
+            description = %description,
+            "Pressure change contextualized as MPD choke adjustment — not alarmed"
+        );
+        metrics.anomaly_description = Some(format!("{} (MPD choke adjustment)", description));
+        metrics.is_anomaly = false;
+        metrics.anomaly_category = AnomalyCategory::None;
+    }
+
+    /// Handle a packet thinned out by input decimation.
+    ///
+    /// Skips physics, CfC, and ticket generation, but keeps the rig state
+    /// classifier's debounce current and still feeds baseline learning so
+    /// decimation does not slow down the learning phase.
+    pub fn observe_decimated(&mut self, packet: &WitsPacket) {
+        let rig_state = self.rig_state_classifier.classify(packet);
+        if self.mode == TacticalMode::BaselineLearning {
+            self.feed_baseline_samples(packet, rig_state);
+            self.try_auto_lock_baselines(packet.timestamp);
+        }
+    }
+
     /// Feed samples to the baseline accumulator during learning phase.
     /// Only feeds during active drilling/reaming states so baselines represent
     /// actual drilling conditions, not idle/connection noise.
     ///
     /// When `current_formation_name` is set, also feeds formation-specific
     /// accumulators via `add_sample_with_formation()`.
-    fn feed_baseline_samples(&mut self, packet: &WitsPacket) {
+    fn feed_baseline_samples(&mut self, packet: &WitsPacket, state: RigState) {
         // Only learn from drilling/reaming states — baselines trained on idle data
         // (zero WOB, zero torque, zero ROP) produce meaningless thresholds.
-        let state = crate::physics_engine::classify_rig_state(packet);
         if state != RigState::Drilling && state != RigState::Reaming {
             return;
         }
@@ -868,32 +1249,45 @@ impl TacticalAgent {
             let timestamp = packet.timestamp;
             let formation = self.current_formation_name.as_deref();
 
-            // Helper macro: feed global + formation-specific when available
-            macro_rules! feed_metric {
-                ($metric:expr, $value:expr) => {
-                    if let Some(fm) = formation {
-                        mgr.add_sample_with_formation(
-                            &self.equipment_id, $metric, $value, fm, timestamp,
-                        );
-                    } else {
-                        mgr.add_sample(&self.equipment_id, $metric, $value, timestamp);
-                    }
-                };
+            // Feed all WITS metrics for baseline learning (global + formation-specific)
+            for (metric, value) in wits_metric_values(packet) {
+                mgr.add_sample_in(&self.equipment_id, metric, value, formation, timestamp);
             }
+        }
+    }
+
+    /// Let locked baselines drift toward normal drilling samples when
+    /// `baseline_learning.drift_alpha` is set, persisting them periodically.
+    fn drift_baselines(&mut self, packet: &WitsPacket, state: RigState) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let bl = &crate::config::get().baseline_learning;
+        if bl.drift_alpha <= 0.0 {
+            return;
+        }
+        // Same drilling/reaming filter as learning
+        if state != RigState::Drilling && state != RigState::Reaming {
+            return;
+        }
+        let Some(ref manager) = self.threshold_manager else {
+            return;
+        };
+        let Ok(mut mgr) = manager.write() else {
+            return;
+        };
+        for (metric, value) in wits_metric_values(packet) {
+            mgr.observe(&self.equipment_id, metric, value);
+        }
 
-            // Feed all WITS metrics for baseline learning
-            feed_metric!(wits_metrics::MSE, packet.mse);
-            feed_metric!(wits_metrics::D_EXPONENT, packet.d_exponent);
-            feed_metric!(wits_metrics::DXC, packet.dxc);
-            feed_metric!(wits_metrics::FLOW_BALANCE, packet.flow_balance());
-            feed_metric!(wits_metrics::SPP, packet.spp);
-            feed_metric!(wits_metrics::TORQUE, packet.torque);
-            feed_metric!(wits_metrics::ROP, packet.rop);
-            feed_metric!(wits_metrics::WOB, packet.wob);
-            feed_metric!(wits_metrics::RPM, packet.rpm);
-            feed_metric!(wits_metrics::ECD, packet.ecd);
-            feed_metric!(wits_metrics::PIT_VOLUME, packet.pit_volume);
-            feed_metric!(wits_metrics::GAS_UNITS, packet.gas_units);
+        let last_save = *self.last_drift_save.get_or_insert(packet.timestamp);
+        if packet.timestamp.saturating_sub(last_save) >= bl.drift_persist_interval_secs {
+            self.last_drift_save = Some(packet.timestamp);
+            if let Err(e) =
+                mgr.save_to_file(std::path::Path::new(crate::baseline::DEFAULT_STATE_PATH))
+            {
+                warn!(error = %e, "Failed to persist drifted baselines");
+            }
         }
     }
 
@@ -916,11 +1310,13 @@ impl TacticalAgent {
             let should_lock = match status {
                 Some(crate::baseline::LearningStatus::Learning {
                     samples_collected, ..
-                }) => samples_collected >= 100,
+                }) => samples_collected >= AUTO_LOCK_MIN_SAMPLES,
                 _ => false,
             };
 
-            if should_lock {
+            if !should_lock {
+                self.maybe_save_accumulators(&mgr, status);
+            } else {
                 let locked = mgr.try_lock_all_wits(&self.equipment_id, timestamp);
                 if !locked.is_empty() {
                     // Compute sigma-derived overrides from the newly locked baselines
@@ -942,6 +1338,7 @@ impl TacticalAgent {
                     }
                     drop(mgr);
                     self.baseline_overrides = Some(overrides);
+                    self.override_sigmas = Some(OverrideSigmas::current());
                     self.mode = TacticalMode::DynamicThresholds;
 
                     // Reset ACI windows — discard noisy learning-phase samples
@@ -953,6 +1350,170 @@ impl TacticalAgent {
         }
     }
 
+    /// Lock metrics that were still learning when the mode switched, once
+    /// they have collected as many samples as the first lock required, and
+    /// re-derive the sigma overrides to include them.
+    fn lock_remaining_baselines(&mut self, timestamp: u64) {
+        let Some(ref manager) = self.threshold_manager else {
+            return;
+        };
+        let Ok(mut mgr) = manager.write() else {
+            return;
+        };
+        let mut locked = Vec::new();
+        for metric in wits_metrics::ALL {
+            let ready = matches!(
+                mgr.get_status(&self.equipment_id, metric),
+                Some(crate::baseline::LearningStatus::Learning {
+                    samples_collected, ..
+                }) if samples_collected >= AUTO_LOCK_MIN_SAMPLES
+            );
+            if ready
+                && mgr
+                    .lock_baseline(&self.equipment_id, metric, timestamp)
+                    .is_ok()
+            {
+                locked.push(metric);
+            }
+        }
+        if locked.is_empty() {
+            return;
+        }
+
+        let overrides = mgr.compute_overrides(&self.equipment_id);
+        info!(
+            equipment_id = %self.equipment_id,
+            locked_metrics = ?locked,
+            "Locked remaining baselines after switching to DynamicThresholds"
+        );
+        mgr.overrides = Some(overrides.clone());
+        if let Err(e) = mgr.save_to_file(std::path::Path::new(crate::baseline::DEFAULT_STATE_PATH))
+        {
+            warn!(error = %e, "Failed to persist baseline overrides");
+        }
+        drop(mgr);
+        self.baseline_overrides = Some(overrides);
+        self.override_sigmas = Some(OverrideSigmas::current());
+    }
+
+    /// Periodically persist in-progress accumulators so a restart resumes
+    /// learning rather than starting over.
+    fn maybe_save_accumulators(
+        &self,
+        mgr: &crate::baseline::ThresholdManager,
+        status: Option<crate::baseline::LearningStatus>,
+    ) {
+        if !mgr.persists_accumulators() || !crate::config::is_initialized() {
+            return;
+        }
+        let interval = crate::config::get()
+            .baseline_learning
+            .accumulator_save_interval_samples
+            .max(1);
+        if let Some(crate::baseline::LearningStatus::Learning {
+            samples_collected, ..
+        }) = status
+        {
+            if samples_collected > 0 && samples_collected % interval == 0 {
+                if let Err(e) =
+                    mgr.save_to_file(std::path::Path::new(crate::baseline::DEFAULT_STATE_PATH))
+                {
+                    warn!(error = %e, "Failed to persist in-progress baseline accumulators");
+                }
+            }
+        }
+    }
+
+    /// Emit the learning-to-operational event the first time the configured
+    /// fraction of WITS baselines is locked. Checked on every packet until it
+    /// fires, not just on the packet that switched the mode.
+    fn check_mode_transition(&mut self, timestamp: u64) {
+        let Some(ref manager) = self.threshold_manager else {
+            return;
+        };
+        let (locked, total) = match manager.read() {
+            Ok(mgr) => {
+                let (locked, total, _) = mgr.wits_lock_progress(&self.equipment_id);
+                (locked, total)
+            }
+            Err(_) => return,
+        };
+        let required = if crate::config::is_initialized() {
+            crate::config::get().baseline_learning.operational_lock_fraction
+        } else {
+            1.0
+        };
+        if total == 0 || (locked as f64) < required * total as f64 {
+            return;
+        }
+
+        let event = ModeTransitionEvent {
+            timestamp,
+            locked_metrics: locked,
+            total_metrics: total,
+            message: ModeTransitionEvent::BANNER.to_string(),
+        };
+        info!(
+            equipment_id = %self.equipment_id,
+            locked_metrics = locked,
+            total_metrics = total,
+            "{}",
+            ModeTransitionEvent::BANNER
+        );
+        self.operational_since = Some(event.clone());
+        self.pending_mode_transition = Some(event);
+    }
+
+    /// Warn once when baseline learning has gone `stall_warning_secs` without
+    /// progress toward locking.
+    fn check_learning_stall(&mut self, timestamp: u64) {
+        if self.mode != TacticalMode::BaselineLearning || !crate::config::is_initialized() {
+            return;
+        }
+        let bl = &crate::config::get().baseline_learning;
+        if !bl.stall_watchdog_enabled {
+            return;
+        }
+        let Some(ref manager) = self.threshold_manager else {
+            return;
+        };
+        let Ok(mgr) = manager.read() else {
+            return;
+        };
+        if let Some(event) = self.learning_watchdog.update(
+            &mgr,
+            &self.equipment_id,
+            timestamp,
+            bl.stall_warning_secs,
+        ) {
+            warn!(
+                equipment_id = %self.equipment_id,
+                stalled_secs = event.stalled_secs,
+                stuck_metrics = event.metrics.len(),
+                "{}",
+                event.message
+            );
+        }
+    }
+
+    /// Current baseline learning stall, while still in learning mode.
+    pub fn learning_stall(&self) -> Option<&crate::baseline::watchdog::LearningStallEvent> {
+        if self.mode != TacticalMode::BaselineLearning {
+            return None;
+        }
+        self.learning_watchdog.active()
+    }
+
+    /// When the agent went live on dynamic anomaly detection (if it has).
+    pub fn operational_since(&self) -> Option<&ModeTransitionEvent> {
+        self.operational_since.as_ref()
+    }
+
+    /// Take the learning-to-operational event, if one fired since the last call.
+    pub fn take_mode_transition(&mut self) -> Option<ModeTransitionEvent> {
+        self.pending_mode_transition.take()
+    }
+
     /// Update sustained throttle normal counts.
     /// Categories that see enough consecutive non-anomalous packets get reset.
     fn update_sustained_normal_counts(&mut self, is_anomaly: bool, category: AnomalyCategory) {
@@ -1149,6 +1710,27 @@ impl TacticalAgent {
         // Determine trigger parameter and value
         let (trigger_parameter, trigger_value, threshold_value) = self.determine_trigger(metrics);
 
+        // RULE 6c: Pump event suppression — when a tripped pump comes back,
+        // flow-in recovers before the returns do and the pits draw down until
+        // the annulus settles. Only the loss side of flow balance / pit rate
+        // is held; gains (kicks, pit gain), gas and H2S always pass.
+        if self.pump_events.is_suppressing()
+            && is_pump_transient_loss(
+                metrics.anomaly_category,
+                &trigger_parameter,
+                trigger_value,
+                threshold_value,
+                packet,
+            )
+        {
+            debug!(
+                trigger = %trigger_parameter,
+                value = trigger_value,
+                "Ticket suppressed — pump trip/changeover transient (RULE 6c)"
+            );
+            return None;
+        }
+
         // Build description
         let description = metrics
             .anomaly_description
@@ -1227,10 +1809,13 @@ impl TacticalAgent {
             ticket.log_info(
                 TicketStage::TacticalCreation,
                 format!(
-                    "CfC: fast={:.3} slow={:.3} combined={:.3} calibrated={} surprises={}",
+                    "CfC: fast={:.3} slow={:.3} combined={:.3} divergence={:.3} ({}) \
+                     calibrated={} surprises={}",
                     cfc.fast.anomaly_score,
                     cfc.slow.anomaly_score,
                     cfc.anomaly_score,
+                    cfc.divergence,
+                    cfc.interpretation(),
                     cfc.is_calibrated,
                     cfc.feature_surprises.len()
                 ),
@@ -1284,6 +1869,7 @@ impl TacticalAgent {
                 }
             }
             AnomalyCategory::Formation => (TicketSeverity::Low, TicketType::Optimization),
+            AnomalyCategory::Equipment => (TicketSeverity::Medium, TicketType::RiskWarning),
             AnomalyCategory::None => (TicketSeverity::Low, TicketType::Optimization),
         };
 
@@ -1576,6 +2162,11 @@ impl TacticalAgent {
                 metrics.d_exponent,
                 0.0, // No fixed threshold for formation changes
             ),
+            AnomalyCategory::Equipment => (
+                "flow_balance".to_string(),
+                metrics.flow_balance,
+                cfg.thresholds.well_control.flow_imbalance_warning_gpm,
+            ),
             AnomalyCategory::None => ("unknown".to_string(), 0.0, 0.0),
         }
     }
@@ -1802,6 +2393,7 @@ impl TacticalAgent {
                 }
             }
             AnomalyCategory::Formation => "Formation Change".into(),
+            AnomalyCategory::Equipment => "Surface Equipment".into(),
             AnomalyCategory::None => "Unknown".into(),
         }
     }
@@ -1817,6 +2409,11 @@ impl TacticalAgent {
         &self.aci_tracker
     }
 
+    /// Wall time of the latest dual CfC update (None when it did not run)
+    pub fn cfc_update_time(&self) -> Option<std::time::Duration> {
+        self.cfc_update_time
+    }
+
     /// Get the latest CfC result (shadow mode, only during drilling/reaming)
     pub fn cfc_result(&self) -> Option<&crate::cfc::DualCfcResult> {
         self.cfc_result.as_ref()
@@ -1832,6 +2429,15 @@ impl TacticalAgent {
         self.latest_formation_transition.as_ref()
     }
 
+    /// Health of the dual CfC networks (`None` when monitoring is disabled)
+    pub fn cfc_health_report(&self) -> Option<crate::cfc::health::CfcHealthReport> {
+        if !crate::config::is_initialized() {
+            return None;
+        }
+        let cfg = &crate::config::get().cfc_health;
+        cfg.enabled.then(|| self.cfc_health.report(cfg))
+    }
+
     /// Get a reference to the dual CfC network for diagnostics
     pub fn cfc_network(&self) -> &crate::cfc::DualCfcNetwork {
         &self.cfc_network
@@ -1852,6 +2458,21 @@ impl TacticalAgent {
         self.regime_centroids
     }
 
+    /// Fraction of recent packets that stayed in the most common regime
+    pub fn regime_stability(&self) -> f64 {
+        self.regime_clusterer.stability()
+    }
+
+    /// Collapse near-duplicate regime centroids; returns how many were removed
+    pub fn merge_regimes(&mut self, min_distance: f64) -> usize {
+        let merged = self.regime_clusterer.merge_close_centroids(min_distance);
+        if merged > 0 {
+            self.latest_regime_id = self.regime_clusterer.latest_regime_id();
+            self.regime_centroids = self.regime_clusterer.centroids();
+        }
+        merged
+    }
+
     pub fn stats(&self) -> AgentStats {
         AgentStats {
             packets_processed: self.packets_processed,
@@ -1885,8 +2506,12 @@ impl TacticalAgent {
         self.previous_operation = Operation::Static;
         self.pending_operation = None;
         self.pending_operation_count = 0;
+        self.rig_state_classifier = crate::physics_engine::RigStateClassifier::new();
         self.founder_consecutive_count = 0;
         self.pit_rate_consecutive_count = 0;
+        self.pump_events = crate::physics_engine::pump_events::PumpEventTracker::new();
+        self.choke = crate::physics_engine::choke::ChokeAdjustmentTracker::new();
+        self.cfc_health = crate::cfc::health::CfcHealthMonitor::new();
         self.sustained_throttle.clear();
         self.depth_ahead = Some(crate::cfc::depth_ahead::DepthAheadNetwork::new(1042));
         self.depth_ahead_result = None;
@@ -1922,6 +2547,22 @@ impl TacticalAgent {
         }
     }
 
+    /// Weight the CfC training loss with the active campaign's feature set.
+    fn apply_cfc_feature_set(&mut self) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let weights = crate::config::get()
+            .cfc_feature_sets
+            .weights_for(self.campaign);
+        if self.cfc_network.set_feature_weights(weights) {
+            info!(
+                campaign = %self.campaign.short_code(),
+                "CfC feature set switched to campaign weighting"
+            );
+        }
+    }
+
     /// Check if baseline learning is complete
     pub fn is_baseline_locked(&self) -> bool {
         match self.mode {
@@ -1931,6 +2572,49 @@ impl TacticalAgent {
     }
 }
 
+/// WITS metric values fed to baseline learning and drift.
+fn wits_metric_values(packet: &WitsPacket) -> [(&'static str, f64); 12] {
+    [
+        (wits_metrics::MSE, packet.mse),
+        (wits_metrics::D_EXPONENT, packet.d_exponent),
+        (wits_metrics::DXC, packet.dxc),
+        (wits_metrics::FLOW_BALANCE, packet.flow_balance()),
+        (wits_metrics::SPP, packet.spp),
+        (wits_metrics::TORQUE, packet.torque),
+        (wits_metrics::ROP, packet.rop),
+        (wits_metrics::WOB, packet.wob),
+        (wits_metrics::RPM, packet.rpm),
+        (wits_metrics::ECD, packet.ecd),
+        (wits_metrics::PIT_VOLUME, packet.pit_volume),
+        (wits_metrics::GAS_UNITS, packet.gas_units),
+    ]
+}
+
+/// True for a WellControl ticket whose trigger is the loss side of flow
+/// balance or pit rate with no gas/H2S alarm — what a pump coming back on
+/// line looks like (RULE 6c). Kicks and pit gains are never matched.
+fn is_pump_transient_loss(
+    category: AnomalyCategory,
+    trigger_parameter: &str,
+    trigger_value: f64,
+    threshold_value: f64,
+    packet: &WitsPacket,
+) -> bool {
+    if category != AnomalyCategory::WellControl {
+        return false;
+    }
+    let wc = &crate::config::get().thresholds.well_control;
+    if packet.gas_units > wc.gas_units_warning || packet.h2s > wc.h2s_warning_ppm {
+        return false;
+    }
+    matches!(trigger_parameter, "flow_balance" | "pit_rate") && trigger_value < -threshold_value
+}
+
+/// `[baseline_learning] preview_mode` (off when config is not loaded).
+fn learning_preview_from_config() -> bool {
+    crate::config::is_initialized() && crate::config::get().baseline_learning.preview_mode
+}
+
 impl Default for TacticalAgent {
     fn default() -> Self {
         Self::new()
@@ -2020,6 +2704,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pa_campaign_switches_cfc_feature_weighting() {
+        ensure_config();
+        let sets = &crate::config::get().cfc_feature_sets;
+        let mut agent = TacticalAgent::new();
+        let packet = create_normal_drilling_packet();
+
+        agent.process(&packet, false, None);
+        let drilling = *agent.cfc_network().fast.feature_weights();
+        assert_eq!(drilling, sets.weights_for(Campaign::Production));
+
+        agent.set_campaign(Campaign::PlugAbandonment);
+        agent.process(&packet, false, None);
+        let pa = *agent.cfc_network().fast.feature_weights();
+        assert_ne!(pa, drilling);
+        assert_eq!(pa, *agent.cfc_network().slow.feature_weights());
+        // P&A focuses on pressure and flow rather than ROP / MSE
+        let idx = |name: &str| {
+            crate::cfc::normalizer::FEATURE_NAMES
+                .iter()
+                .position(|n| *n == name)
+                .unwrap()
+        };
+        assert!(pa[idx("spp")] > drilling[idx("spp")]);
+        assert!(pa[idx("flow_balance")] > drilling[idx("flow_balance")]);
+        assert!(pa[idx("rop")] < drilling[idx("rop")]);
+        assert!(pa[idx("mse")] < drilling[idx("mse")]);
+    }
+
     #[test]
     fn test_kick_generates_ticket() {
         ensure_config();
@@ -2038,6 +2751,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pump_changeover_suppresses_flow_alarms() {
+        ensure_config();
+        let mut agent = TacticalAgent::new();
+        let settle = crate::config::get().pump_events.settle_secs;
+
+        let steady = |ts: u64| {
+            let mut p = create_normal_drilling_packet();
+            p.timestamp = ts;
+            p.pump_spm = 120.0;
+            p
+        };
+        for ts in 1000..1010 {
+            agent.process(&steady(ts), false, None);
+        }
+
+        // One pump trips: flow-in, strokes and SPP drop together
+        let mut tripped = steady(1010);
+        tripped.flow_in = 250.0;
+        tripped.flow_out = 250.0;
+        tripped.pump_spm = 60.0;
+        tripped.spp = 1100.0;
+        agent.process(&tripped, false, None);
+        assert!(agent.pump_event_suppressing());
+
+        // Standby pump on line: flow-in is back but returns lag and the pits
+        // draw down — a loss signature that is the pumps, not the hole.
+        let mut recovering = steady(1030);
+        recovering.flow_out = 380.0;
+        recovering.pit_volume_change = -8.0;
+        let (ticket, metrics, _) = agent.process(&recovering, false, None);
+        assert!(metrics.is_anomaly);
+        assert_eq!(metrics.anomaly_category, AnomalyCategory::WellControl);
+        assert!(agent.pump_event_suppressing());
+        assert!(ticket.is_none(), "loss transient suppressed while settling");
+
+        // A kick while still settling is never suppressed
+        let mut kick = create_kick_packet();
+        kick.timestamp = 1031;
+        kick.pump_spm = 120.0;
+        kick.gas_units = 20.0;
+        kick.pit_volume_change = 8.0;
+        assert!(agent.pump_event_suppressing());
+        let (ticket, metrics, _) = agent.process(&kick, false, None);
+        assert_eq!(metrics.anomaly_category, AnomalyCategory::WellControl);
+        assert!(ticket.is_some(), "kick raised during pump event settle");
+
+        agent.process(&steady(1030 + settle), false, None);
+        assert!(!agent.pump_event_suppressing());
+    }
+
+    #[test]
+    fn test_spp_change_during_choke_move_is_contextualized() {
+        ensure_config();
+        let mut agent = TacticalAgent::new();
+        let context = crate::config::get().mpd.context_secs;
+
+        let mpd = |ts: u64, choke: f64, spp: f64| {
+            let mut p = create_normal_drilling_packet();
+            p.timestamp = ts;
+            p.choke_position_pct = choke;
+            p.spp = spp;
+            p
+        };
+        for ts in 1000..1010 {
+            agent.process(&mpd(ts, 40.0, 3000.0), false, None);
+        }
+
+        // Crew closes the choke: back-pressure steps SPP up
+        let (ticket, metrics, _) = agent.process(&mpd(1010, 25.0, 3250.0), false, None);
+        assert!(agent.choke_adjustment_active());
+        assert!(!metrics.is_anomaly, "SPP step attributed to the choke");
+        assert!(metrics
+            .anomaly_description
+            .as_deref()
+            .is_some_and(|d| d.contains("MPD choke adjustment")));
+        assert!(ticket.is_none());
+
+        // Context expires; the same SPP step with the choke held still fires
+        agent.process(&mpd(1010 + context, 25.0, 3250.0), false, None);
+        assert!(!agent.choke_adjustment_active());
+        let (_, metrics, _) = agent.process(&mpd(1011 + context, 25.0, 3500.0), false, None);
+        assert!(metrics.is_anomaly);
+        assert_eq!(metrics.anomaly_category, AnomalyCategory::Hydraulics);
+        assert!(metrics
+            .anomaly_description
+            .as_deref()
+            .is_some_and(|d| d.contains("SPP increase")));
+
+        // A declared adjustment contextualizes without choke data
+        agent.mark_choke_adjustment(1012 + context);
+        let (_, metrics, _) = agent.process(&mpd(1013 + context, 0.0, 3750.0), false, None);
+        assert!(!metrics.is_anomaly);
+    }
+
     #[test]
     fn test_history_entry_always_created() {
         ensure_config();
@@ -2343,4 +3151,178 @@ mod tests {
             "Overrides should fall back to global when formation cleared"
         );
     }
+
+    #[test]
+    fn test_sigma_change_rederives_flow_imbalance_override() {
+        ensure_config();
+        use crate::baseline::wits_metrics;
+        use crate::config::BaselineLearningConfig;
+
+        let equip = "sigma-rig";
+        let mut manager = ThresholdManager::new();
+        let all_sensors = [
+            wits_metrics::MSE,
+            wits_metrics::D_EXPONENT,
+            wits_metrics::DXC,
+            wits_metrics::FLOW_BALANCE,
+            wits_metrics::SPP,
+            wits_metrics::TORQUE,
+            wits_metrics::ROP,
+            wits_metrics::WOB,
+            wits_metrics::RPM,
+            wits_metrics::ECD,
+            wits_metrics::PIT_VOLUME,
+            wits_metrics::GAS_UNITS,
+        ];
+        for sensor in all_sensors {
+            for i in 0..200 {
+                manager.add_sample(equip, sensor, 100.0 + (i as f64 * 0.01), i as u64);
+            }
+            manager
+                .lock_baseline(equip, sensor, 200)
+                .expect("should lock baseline");
+        }
+        let flow_std = manager
+            .get_threshold(equip, wits_metrics::FLOW_BALANCE)
+            .expect("flow_balance locked")
+            .effective_std();
+
+        let tm = Arc::new(RwLock::new(manager));
+        let mut agent = TacticalAgent::new_with_thresholds(equip, tm.clone(), false);
+        assert_eq!(agent.mode(), TacticalMode::DynamicThresholds);
+        let initial = BaselineLearningConfig::default();
+        agent.set_current_formation(Some("Hugin".to_string()));
+        let before = agent
+            .baseline_overrides
+            .as_ref()
+            .and_then(|o| o.flow_imbalance_warning_gpm)
+            .expect("flow imbalance override");
+        assert!((before - initial.override_flow_imbalance_sigma * flow_std).abs() < 1e-9);
+
+        // Reloaded config with a wider flow imbalance sigma
+        let reloaded = BaselineLearningConfig {
+            override_flow_imbalance_sigma: 4.5,
+            ..initial
+        };
+        assert!(agent.refresh_overrides_for_sigmas(OverrideSigmas::from_config(&reloaded)));
+        let after = agent
+            .baseline_overrides
+            .as_ref()
+            .and_then(|o| o.flow_imbalance_warning_gpm)
+            .expect("flow imbalance override");
+        assert!((after - 4.5 * flow_std).abs() < 1e-9);
+        let persisted = tm
+            .read()
+            .expect("manager")
+            .overrides
+            .as_ref()
+            .and_then(|o| o.flow_imbalance_warning_gpm);
+        assert_eq!(persisted, Some(after));
+
+        // Same sigmas again: nothing to re-derive
+        assert!(!agent.refresh_overrides_for_sigmas(OverrideSigmas::from_config(&reloaded)));
+    }
+
+    #[test]
+    fn test_metrics_locking_one_at_a_time_emit_mode_transition_at_fraction() {
+        ensure_config();
+        use crate::baseline::wits_metrics;
+
+        let equip = "staggered-lock-rig";
+        let mut manager = ThresholdManager::new();
+        for i in 0..150 {
+            manager.add_sample(equip, wits_metrics::MSE, 20_000.0 + (i as f64), i as u64);
+        }
+        let tm = Arc::new(RwLock::new(manager));
+        let mut agent = TacticalAgent::new_with_thresholds(equip, tm.clone(), true);
+
+        // MSE locks first and switches the mode; 1 of 12 is not operational
+        agent.try_auto_lock_baselines(200);
+        assert_eq!(agent.mode(), TacticalMode::DynamicThresholds);
+        agent.check_mode_transition(200);
+        assert!(agent.take_mode_transition().is_none());
+
+        // The rest become ready one per packet while already in dynamic mode
+        let others: Vec<&str> = wits_metrics::ALL
+            .into_iter()
+            .filter(|m| *m != wits_metrics::MSE)
+            .collect();
+        for (n, sensor) in others.iter().enumerate() {
+            {
+                let mut mgr = tm.write().unwrap();
+                for i in 0..150 {
+                    mgr.add_sample(equip, sensor, 100.0 + (i as f64 * 0.01), i as u64);
+                }
+            }
+            let mut packet = create_normal_drilling_packet();
+            packet.timestamp = 300 + n as u64;
+            agent.process(&packet, false, None);
+
+            let (locked, total, _) = tm.read().unwrap().wits_lock_progress(equip);
+            assert_eq!(locked, n + 2, "{sensor} locked on its own packet");
+            let event = agent.take_mode_transition();
+            if locked < total {
+                assert!(event.is_none(), "not operational at {locked}/{total}");
+            } else {
+                let event = event.expect("transition once the last metric locks");
+                assert_eq!(event.locked_metrics, 12);
+                assert_eq!(event.timestamp, packet.timestamp);
+            }
+        }
+        assert!(agent.operational_since().is_some());
+    }
+
+    #[test]
+    fn test_locking_last_metric_emits_single_mode_transition() {
+        ensure_config();
+        use crate::baseline::wits_metrics;
+
+        let equip = "transition-rig";
+        let mut manager = ThresholdManager::new();
+        let others = [
+            wits_metrics::D_EXPONENT,
+            wits_metrics::DXC,
+            wits_metrics::FLOW_BALANCE,
+            wits_metrics::SPP,
+            wits_metrics::TORQUE,
+            wits_metrics::ROP,
+            wits_metrics::WOB,
+            wits_metrics::RPM,
+            wits_metrics::ECD,
+            wits_metrics::PIT_VOLUME,
+            wits_metrics::GAS_UNITS,
+        ];
+        for sensor in &others {
+            for i in 0..150 {
+                manager.add_sample(equip, sensor, 100.0 + (i as f64 * 0.01), i as u64);
+            }
+            manager.lock_baseline(equip, sensor, 150).expect("should lock");
+        }
+        // MSE is the last metric still learning, with enough samples to lock
+        for i in 0..150 {
+            manager.add_sample(equip, wits_metrics::MSE, 20_000.0 + (i as f64), i as u64);
+        }
+
+        let tm = Arc::new(RwLock::new(manager));
+        let mut agent = TacticalAgent::new_with_thresholds(equip, tm, true);
+        assert_eq!(agent.mode(), TacticalMode::BaselineLearning);
+        assert!(agent.operational_since().is_none());
+
+        agent.try_auto_lock_baselines(200);
+        agent.check_mode_transition(200);
+
+        let event = agent.take_mode_transition().expect("transition emitted");
+        assert_eq!(event.timestamp, 200);
+        assert_eq!(event.locked_metrics, 12);
+        assert_eq!(event.total_metrics, 12);
+        assert_eq!(agent.mode(), TacticalMode::DynamicThresholds);
+        assert_eq!(agent.operational_since().map(|e| e.timestamp), Some(200));
+
+        // Further packets never re-emit
+        let packet = create_normal_drilling_packet();
+        for _ in 0..5 {
+            agent.process(&packet, false, None);
+        }
+        assert!(agent.take_mode_transition().is_none());
+    }
 }
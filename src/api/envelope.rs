@@ -39,6 +39,14 @@ impl<T: Serialize> ApiResponse<T> {
         };
         (StatusCode::OK, axum::Json(body)).into_response()
     }
+
+    /// Envelope as a JSON string, for transports other than an HTTP body.
+    pub fn json(data: T) -> serde_json::Result<String> {
+        serde_json::to_string(&Self {
+            data,
+            meta: ResponseMeta::default(),
+        })
+    }
 }
 
 /// Error detail inside [`ApiErrorResponse`].
@@ -127,6 +127,38 @@ pub struct AcknowledgeRequest {
     pub action_taken: String,
 }
 
+/// Structured reason for an acknowledgment.
+///
+/// `FalseAlarm` and `Handled` also record operator feedback on the advisory
+/// (false positive / confirmed); `Deferred` does not rate it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AckReasonCode {
+    FalseAlarm,
+    Handled,
+    Deferred,
+}
+
+impl AckReasonCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AckReasonCode::FalseAlarm => "false_alarm",
+            AckReasonCode::Handled => "handled",
+            AckReasonCode::Deferred => "deferred",
+        }
+    }
+
+    /// Feedback outcome implied by this reason, if any
+    pub fn feedback_outcome(self) -> Option<crate::storage::feedback::FeedbackOutcome> {
+        use crate::storage::feedback::FeedbackOutcome;
+        match self {
+            AckReasonCode::FalseAlarm => Some(FeedbackOutcome::FalsePositive),
+            AckReasonCode::Handled => Some(FeedbackOutcome::Confirmed),
+            AckReasonCode::Deferred => None,
+        }
+    }
+}
+
 /// Stored acknowledgment record
 #[derive(Debug, Clone, Serialize, serde::Deserialize)]
 pub struct AcknowledgmentRecord {
@@ -135,6 +167,15 @@ pub struct AcknowledgmentRecord {
     pub acknowledged_at: u64,
     pub notes: String,
     pub action_taken: String,
+    /// Category of the acknowledged advisory, when it could be resolved
+    #[serde(default)]
+    pub category: Option<crate::types::AnomalyCategory>,
+    /// Acknowledged advisory was CRITICAL (expires on the shorter TTL)
+    #[serde(default)]
+    pub critical: bool,
+    /// Structured reason (bulk acknowledgments)
+    #[serde(default)]
+    pub reason_code: Option<AckReasonCode>,
 }
 
 /// Response after acknowledging an advisory
@@ -158,17 +199,26 @@ pub async fn acknowledge_advisory(
         .unwrap_or_default()
         .as_secs();
 
+    let (category, critical) = crate::pipeline::acknowledgments::ack_subject(
+        &*state.app_state.read().await,
+        request.ticket_timestamp,
+    );
     let record = AcknowledgmentRecord {
         ticket_timestamp: request.ticket_timestamp,
         acknowledged_by: request.acknowledged_by,
         acknowledged_at: now,
         notes: request.notes,
         action_taken: request.action_taken,
+        category,
+        critical,
+        reason_code: None,
     };
 
     // Persist to sled before touching the in-memory list so the record
     // survives even if the process is killed immediately after this write.
-    if let Err(e) = crate::storage::acks::persist(record.acknowledged_at, &record) {
+    if let Err(e) =
+        crate::storage::acks::persist(record.acknowledged_at, record.ticket_timestamp, &record)
+    {
         warn!("Failed to persist acknowledgment: {}", e);
     }
 
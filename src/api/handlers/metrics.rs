@@ -21,6 +21,7 @@ use super::DashboardState;
 /// - `sairen_tickets_created_total` — advisory tickets generated
 /// - `sairen_tickets_verified_total` — tickets confirmed by strategic agent
 /// - `sairen_tickets_rejected_total` — tickets rejected as transient
+/// - `sairen_sensor_glitches_total` — WITS samples flagged by the glitch filter
 /// - `sairen_uptime_seconds`       — process uptime in seconds
 /// - `sairen_avg_mse_efficiency`   — current rolling MSE efficiency (gauge)
 pub async fn get_metrics(State(state): State<DashboardState>) -> impl IntoResponse {
@@ -56,6 +57,13 @@ pub async fn get_metrics(State(state): State<DashboardState>) -> impl IntoRespon
         app_state.tickets_rejected
     ));
 
+    body.push_str("# HELP sairen_sensor_glitches_total WITS samples flagged as glitches\n");
+    body.push_str("# TYPE sairen_sensor_glitches_total counter\n");
+    body.push_str(&format!(
+        "sairen_sensor_glitches_total {}\n",
+        app_state.sensor_glitches
+    ));
+
     body.push_str("# HELP sairen_uptime_seconds Process uptime in seconds\n");
     body.push_str("# TYPE sairen_uptime_seconds gauge\n");
     body.push_str(&format!(
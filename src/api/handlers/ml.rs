@@ -81,6 +81,7 @@ pub struct MLCorrelation {
     pub y_param: String,
     pub r_value: f64,
     pub p_value: f64,
+    pub sample_count: usize,
 }
 
 impl From<&crate::types::MLInsightsReport> for MLReportSummary {
@@ -120,6 +121,7 @@ impl From<&crate::types::MLInsightsReport> for MLReportSummary {
                             y_param: c.y_param.clone(),
                             r_value: c.r_value,
                             p_value: c.p_value,
+                            sample_count: c.sample_count,
                         })
                         .collect(),
                 ),
@@ -227,7 +229,15 @@ pub async fn get_ml_optimal(
     });
 
     if let Some(storage) = &state.ml_storage {
-        match storage.find_by_depth(&app_state.field_name, &app_state.well_id, depth, 500.0, 5) {
+        let wellbore = app_state.active_wellbore.as_ref().map(|w| w.branch.as_str());
+        match storage.find_by_depth(
+            &app_state.field_name,
+            &app_state.well_id,
+            wellbore,
+            depth,
+            500.0,
+            5,
+        ) {
             Ok(reports) if !reports.is_empty() => {
                 // Find first successful report
                 for report in &reports {
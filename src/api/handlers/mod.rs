@@ -43,6 +43,8 @@ pub struct DashboardState {
     pub equipment_id: String,
     /// ML insights storage (V2.1)
     pub ml_storage: Option<Arc<MLInsightsStorage>>,
+    /// Live tickets/advisories/rig state published by the processing loop
+    pub live_events: crate::pipeline::live_events::LiveEventBus,
 }
 
 impl DashboardState {
@@ -59,6 +61,7 @@ impl DashboardState {
             threshold_manager: Some(threshold_manager),
             equipment_id: equipment_id.to_string(),
             ml_storage: None,
+            live_events: Default::default(),
         }
     }
 }
@@ -74,6 +77,7 @@ mod tests {
             threshold_manager: None,
             equipment_id: "RIG".to_string(),
             ml_storage: None,
+            live_events: Default::default(),
         }
     }
 
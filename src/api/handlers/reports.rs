@@ -423,6 +423,10 @@ pub async fn create_test_critical_report() -> Json<serde_json::Value> {
         trigger_parameter: "flow_balance".to_string(),
         trigger_value: 85.0,
         threshold_value: 20.0,
+        provisional_during_learning: false,
+        sparkline: None,
+        gas_lag: None,
+        causal_leads: Vec::new(),
     };
 
     // Store the test report
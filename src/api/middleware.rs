@@ -1,29 +1,115 @@
 //! API middleware layers.
 //!
-//! Currently provides deprecation headers for the v1 API surface.
+//! Currently enforces the lifecycle of the deprecated v1 API surface: RFC 8594
+//! deprecation headers until the configured sunset (`api.v1_sunset`), then
+//! `410 Gone` pointing at the v2 equivalent.
 
 use axum::http::header::HeaderName;
-use axum::http::HeaderValue;
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
 use axum::middleware::Next;
-use axum::response::Response;
+use axum::response::{IntoResponse, Response};
+use chrono::NaiveDate;
+use serde::Serialize;
 
-/// Axum middleware that adds RFC 8594 deprecation headers to v1 responses.
-///
-/// - `Deprecation: true`
-/// - `Sunset: 2026-09-01`
-pub async fn add_v1_deprecation_headers(request: axum::extract::Request, next: Next) -> Response {
-    let mut response = next.run(request).await;
+use super::envelope::{ErrorDetail, ResponseMeta};
+
+fn configured_sunset() -> NaiveDate {
+    if crate::config::is_initialized() {
+        crate::config::get().api.v1_sunset
+    } else {
+        crate::config::ApiConfig::default().v1_sunset
+    }
+}
+
+/// v2 endpoint serving the same data as a v1 path (relative to `/api/v1`).
+fn v2_equivalent(v1_path: &str) -> Option<&'static str> {
+    let v2 = match v1_path.trim_end_matches('/') {
+        "/health" => "/api/v2/system/health",
+        "/status" | "/verification" | "/diagnosis" => "/api/v2/live",
+        "/drilling" => "/api/v2/drilling",
+        "/strategic/hourly" => "/api/v2/reports/hourly",
+        "/strategic/daily" => "/api/v2/reports/daily",
+        "/reports/critical" => "/api/v2/reports/critical",
+        "/baseline" => "/api/v2/debug/baseline",
+        "/campaign" => "/api/v2/campaign",
+        "/ml/latest" => "/api/v2/ml/latest",
+        "/ml/history" => "/api/v2/debug/ml/history",
+        "/ml/optimal" => "/api/v2/ml/optimal",
+        "/config" => "/api/v2/config",
+        "/config/validate" => "/api/v2/config/validate",
+        "/advisory/acknowledge" => "/api/v2/advisory/acknowledge",
+        "/advisory/acknowledgments" => "/api/v2/advisory/acknowledgments",
+        "/shift/summary" => "/api/v2/shift/summary",
+        "/metrics" => "/api/v2/metrics",
+        "/fleet/intelligence" => "/api/v2/debug/fleet/intelligence",
+        _ => return None,
+    };
+    Some(v2)
+}
+
+/// `410 Gone` body for a retired v1 route
+#[derive(Debug, Serialize)]
+struct V1GoneBody {
+    error: ErrorDetail,
+    /// v2 endpoint to use instead, when there is one
+    replacement: Option<&'static str>,
+    sunset: String,
+    meta: ResponseMeta,
+}
+
+fn v1_gone(v1_path: &str, sunset: NaiveDate) -> Response {
+    let replacement = v2_equivalent(v1_path);
+    let message = match replacement {
+        Some(v2) => format!("API v1 was retired on {sunset}; use {v2}"),
+        None => format!("API v1 was retired on {sunset}; this endpoint has no v2 equivalent"),
+    };
+    let body = V1GoneBody {
+        error: ErrorDetail {
+            code: "GONE".to_string(),
+            message,
+        },
+        replacement,
+        sunset: sunset.to_string(),
+        meta: ResponseMeta::default(),
+    };
+    (StatusCode::GONE, axum::Json(body)).into_response()
+}
 
-    let headers = response.headers_mut();
+fn insert_deprecation_headers(headers: &mut HeaderMap, sunset: NaiveDate) {
     headers.insert(
         HeaderName::from_static("deprecation"),
         HeaderValue::from_static("true"),
     );
-    headers.insert(
-        HeaderName::from_static("sunset"),
-        HeaderValue::from_static("2026-09-01"),
-    );
+    if let Ok(value) = HeaderValue::from_str(&sunset.to_string()) {
+        headers.insert(HeaderName::from_static("sunset"), value);
+    }
+}
+
+/// Axum middleware for the v1 API, keyed on `api.v1_sunset`.
+///
+/// Through the sunset date (UTC) the handler runs and the response gets
+/// - `Deprecation: true`
+/// - `Sunset: <api.v1_sunset>`
+///
+/// After it, the handler is not called and the client gets `410 Gone` with
+/// the v2 replacement path in the JSON body.
+pub async fn enforce_v1_sunset(request: axum::extract::Request, next: Next) -> Response {
+    let today = chrono::Utc::now().date_naive();
+    v1_lifecycle(configured_sunset(), today, request, next).await
+}
 
+async fn v1_lifecycle(
+    sunset: NaiveDate,
+    today: NaiveDate,
+    request: axum::extract::Request,
+    next: Next,
+) -> Response {
+    if today > sunset {
+        return v1_gone(request.uri().path(), sunset);
+    }
+
+    let mut response = next.run(request).await;
+    insert_deprecation_headers(response.headers_mut(), sunset);
     response
 }
 
@@ -37,18 +123,68 @@ mod tests {
     use axum::Router;
     use tower::ServiceExt;
 
+    fn sunset() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, 1).unwrap()
+    }
+
+    fn before_sunset() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 8, 31).unwrap()
+    }
+
+    fn after_sunset() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, 2).unwrap()
+    }
+
+    fn v1_app(sunset: NaiveDate, today: NaiveDate) -> Router {
+        Router::new()
+            .route("/status", get(|| async { "ok" }))
+            .layer(middleware::from_fn(move |request, next| {
+                v1_lifecycle(sunset, today, request, next)
+            }))
+    }
+
     #[tokio::test]
     async fn test_deprecation_headers_present() {
-        let app = Router::new()
-            .route("/test", get(|| async { "ok" }))
-            .layer(middleware::from_fn(add_v1_deprecation_headers));
-
-        let resp = app
-            .oneshot(Request::get("/test").body(Body::empty()).unwrap())
+        let resp = v1_app(sunset(), before_sunset())
+            .oneshot(Request::get("/status").body(Body::empty()).unwrap())
             .await
             .unwrap();
 
+        assert_eq!(resp.status(), StatusCode::OK);
         assert_eq!(resp.headers().get("deprecation").unwrap(), "true");
         assert_eq!(resp.headers().get("sunset").unwrap(), "2026-09-01");
     }
+
+    #[tokio::test]
+    async fn test_gone_after_sunset() {
+        let resp = v1_app(sunset(), after_sunset())
+            .oneshot(Request::get("/status").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+        let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let v: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(v["error"]["code"], "GONE");
+        assert_eq!(v["replacement"], "/api/v2/live");
+        assert_eq!(v["sunset"], "2026-09-01");
+    }
+
+    #[tokio::test]
+    async fn test_default_sunset_is_announced() {
+        let default = crate::config::ApiConfig::default().v1_sunset;
+        let resp = v1_app(default, default)
+            .oneshot(Request::get("/status").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        // The sunset day itself is still served
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get("sunset").unwrap(),
+            default.to_string().as_str()
+        );
+    }
 }
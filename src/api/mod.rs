@@ -2,7 +2,7 @@
 //!
 //! Provides HTTP endpoints for the SAIREN-OS drilling intelligence dashboard:
 //! - v2 API with consistent envelope and consolidated live endpoint
-//! - v1 API (deprecated, sunset 2026-09-01) for backward compatibility
+//! - v1 API (deprecated; `410 Gone` after `api.v1_sunset`) for backward compatibility
 //! - React SPA served via `rust-embed` (compiled into the binary)
 
 pub mod envelope;
@@ -97,11 +97,11 @@ pub fn create_app(state: DashboardState) -> Router {
     Router::new()
         // v2 API (primary)
         .nest("/api/v2", v2_routes::v2_api_routes(state.clone()))
-        // v1 API (deprecated — adds Deprecation + Sunset headers)
+        // v1 API (deprecated — Deprecation + Sunset headers, 410 after sunset)
         .nest(
             "/api/v1",
             routes::api_routes(state.clone())
-                .layer(axum_mw::from_fn(middleware::add_v1_deprecation_headers)),
+                .layer(axum_mw::from_fn(middleware::enforce_v1_sunset)),
         )
         // Legacy health endpoint at /health
         .merge(routes::legacy_routes(state))
@@ -88,6 +88,7 @@ mod tests {
             threshold_manager: None,
             equipment_id: "RIG".to_string(),
             ml_storage: None,
+            live_events: Default::default(),
         }
     }
 
@@ -2,6 +2,7 @@
 //!
 //! Served when `sairen-os setup` is run. Provides endpoints for:
 //! - WITS subnet scanning and connection testing
+//! - Sampling a chosen WITS source to grade its data quality
 //! - Well identity configuration
 //! - Fleet Hub pairing via 6-digit codes
 //! - Config file generation
@@ -15,7 +16,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 
-use crate::acquisition::scanner;
+use crate::acquisition::{probe, scanner};
 
 /// Setup wizard HTML (embedded at compile time)
 const SETUP_HTML: &str = include_str!("../../static/setup.html");
@@ -72,7 +73,7 @@ impl SetupState {
 pub struct ScanResponse {
     pub status: String,
     pub elapsed_ms: u64,
-    pub streams: Vec<scanner::WitsDiscovery>,
+    pub streams: Vec<scanner::ScanResult>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -88,6 +89,14 @@ pub struct ConnectResponse {
     pub message: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ProbeRequest {
+    pub host: String,
+    pub port: u16,
+    /// Sampling window in seconds (default 10, capped at 30)
+    pub seconds: Option<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SaveRequest {
     pub wits_host: String,
@@ -248,6 +257,31 @@ pub async fn test_connect(Json(req): Json<ConnectRequest>) -> Json<ConnectRespon
     }
 }
 
+/// POST /api/setup/probe — Sample a WITS source and grade its data
+///
+/// Blocks for the sampling window, then returns packet count, channels,
+/// depth/ROP ranges, gaps and a green/amber/red quality badge.
+pub async fn probe_source(Json(req): Json<ProbeRequest>) -> Json<probe::ProbeSummary> {
+    let secs = req
+        .seconds
+        .unwrap_or(probe::DEFAULT_PROBE_SECS)
+        .clamp(1, probe::MAX_PROBE_SECS);
+    info!(
+        "Probing WITS source {}:{} for {}s",
+        req.host, req.port, secs
+    );
+
+    let summary = probe::probe(&req.host, req.port, std::time::Duration::from_secs(secs)).await;
+    info!(
+        packets = summary.packets,
+        quality = ?summary.quality,
+        "WITS probe of {}:{} complete",
+        req.host,
+        req.port
+    );
+    Json(summary)
+}
+
 /// POST /api/setup/save — Write config files
 pub async fn save_config(
     State(state): State<SetupState>,
@@ -507,6 +541,7 @@ pub fn setup_router(state: SetupState) -> axum::Router {
         .route("/api/setup/scan", get(start_scan))
         .route("/api/setup/scan/status", get(scan_status))
         .route("/api/setup/connect", post(test_connect))
+        .route("/api/setup/probe", post(probe_source))
         .route("/api/setup/save", post(save_config))
         .route("/api/setup/fleet/pair", post(initiate_pair))
         .route("/api/setup/fleet/status", get(pair_status))
@@ -29,6 +29,8 @@ pub struct HealthV2 {
     pub confidence: f64,
     pub timestamp: DateTime<Utc>,
     pub components: ComponentHealth,
+    /// What the operator should assess when rating the latest advisory
+    pub feedback_prompt: Option<crate::storage::feedback::FeedbackPrompt>,
 }
 
 #[derive(Debug, Serialize)]
@@ -38,6 +40,10 @@ pub struct ComponentHealth {
     pub ml: bool,
     pub fleet: bool,
     pub storage: bool,
+    /// Mid-well snapshot writes; `pending > 0` means writes are failing
+    pub kb_snapshots: Option<crate::knowledge_base::snapshot_queue::SnapshotWriteStatus>,
+    /// Fleet events evicted by the mesh store size cap (absent when mesh is off)
+    pub mesh_events_dropped: Option<u64>,
 }
 
 /// System status fields for the consolidated live endpoint.
@@ -50,8 +56,12 @@ pub struct StatusV2 {
     pub uptime_secs: u64,
     pub total_analyses: u64,
     pub packets_processed: u64,
+    /// WITS channel samples flagged as single-sample glitches
+    pub sensor_glitches: u64,
     pub campaign: String,
     pub campaign_code: String,
+    /// Fraction of recent packets in one CfC regime (1.0 = steady state)
+    pub regime_stability: f64,
 }
 
 /// Drilling metrics for v2.
@@ -111,6 +121,13 @@ pub struct BaselineSummaryV2 {
     pub locked_count: usize,
     pub learning_count: usize,
     pub total_metrics: usize,
+    /// Unix timestamp when dynamic anomaly detection went live
+    pub operational_since: Option<u64>,
+    /// Banner shown once baseline learning completes
+    pub banner: Option<String>,
+    /// Warning when learning has stalled without progress toward locking
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stall_warning: Option<String>,
 }
 
 /// ML summary for live endpoint.
@@ -166,6 +183,24 @@ pub struct DepthQuery {
     pub depth: Option<f64>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DepthRangeQuery {
+    #[serde(default)]
+    pub min_ft: Option<f64>,
+    #[serde(default)]
+    pub max_ft: Option<f64>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    #[serde(default)]
+    pub q: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ShiftQuery {
     #[serde(default)]
@@ -216,6 +251,10 @@ fn build_health(state: &crate::pipeline::AppState, dashboard: &DashboardState) -
 
     let has_ml = state.latest_ml_report.is_some();
     let has_fleet = std::env::var("FLEET_HUB_URL").is_ok();
+    let feedback_prompt = state
+        .latest_advisory
+        .as_ref()
+        .and_then(|adv| crate::storage::feedback::feedback_prompt(adv.category));
 
     HealthV2 {
         overall_score: score,
@@ -230,7 +269,13 @@ fn build_health(state: &crate::pipeline::AppState, dashboard: &DashboardState) -
             ml: has_ml,
             fleet: has_fleet,
             storage: true,
+            kb_snapshots: state.kb_snapshot_status.clone(),
+            mesh_events_dropped: state
+                .mesh_evictions
+                .as_ref()
+                .map(crate::gossip::store::EvictionLog::dropped_events),
         },
+        feedback_prompt,
     }
 }
 
@@ -262,8 +307,10 @@ fn build_status(state: &crate::pipeline::AppState) -> StatusV2 {
         uptime_secs: state.uptime_secs(),
         total_analyses: state.total_analyses,
         packets_processed: state.packets_processed,
+        sensor_glitches: state.sensor_glitches,
         campaign: state.campaign.display_name().to_string(),
         campaign_code: state.campaign.short_code().to_string(),
+        regime_stability: state.regime_stability,
     }
 }
 
@@ -434,7 +481,10 @@ fn build_verification(state: &crate::pipeline::AppState) -> VerificationV2 {
     }
 }
 
-fn build_baseline_summary(dashboard: &DashboardState) -> BaselineSummaryV2 {
+fn build_baseline_summary(
+    app: &crate::pipeline::AppState,
+    dashboard: &DashboardState,
+) -> BaselineSummaryV2 {
     let metrics_to_check = [
         wits_metrics::MSE,
         wits_metrics::D_EXPONENT,
@@ -483,6 +533,9 @@ fn build_baseline_summary(dashboard: &DashboardState) -> BaselineSummaryV2 {
         locked_count: locked,
         learning_count: learning,
         total_metrics: metrics_to_check.len(),
+        operational_since: app.mode_transition.as_ref().map(|e| e.timestamp),
+        banner: app.mode_transition.as_ref().map(|e| e.message.clone()),
+        stall_warning: app.learning_stall.as_ref().map(|e| e.message.clone()),
     }
 }
 
@@ -533,19 +586,152 @@ pub async fn system_health(State(state): State<DashboardState>) -> Response {
     ApiResponse::ok(build_health(&app, &state))
 }
 
+fn build_live(state: &crate::pipeline::AppState, dashboard: &DashboardState) -> LiveDataResponse {
+    LiveDataResponse {
+        health: build_health(state, dashboard),
+        status: build_status(state),
+        drilling: build_drilling(state, dashboard),
+        verification: build_verification(state),
+        baseline_summary: build_baseline_summary(state, dashboard),
+        ml_latest: build_ml_summary(state),
+        shift: build_shift(state),
+    }
+}
+
 /// GET /api/v2/live — consolidated endpoint replacing 7 v1 polls.
 pub async fn live_data(State(state): State<DashboardState>) -> Response {
     let app = state.app_state.read().await;
-    let response = LiveDataResponse {
-        health: build_health(&app, &state),
-        status: build_status(&app),
-        drilling: build_drilling(&app, &state),
-        verification: build_verification(&app),
-        baseline_summary: build_baseline_summary(&state),
-        ml_latest: build_ml_summary(&app),
-        shift: build_shift(&app),
-    };
-    ApiResponse::ok(response)
+    ApiResponse::ok(build_live(&app, &state))
+}
+
+/// Interval between SSE heartbeat comments on `/api/v2/stream`
+const STREAM_HEARTBEAT: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// GET /api/v2/stream — Server-Sent Events push feed for wall displays.
+///
+/// Emits named `ticket`, `advisory` and `rig_state` events with JSON data;
+/// per-packet metrics are only on `/api/v2/ws`.
+/// A client that falls too far behind skips the events it missed; a
+/// `heartbeat` comment keeps proxies from closing an idle connection.
+pub async fn live_stream(State(state): State<DashboardState>) -> Response {
+    use axum::response::sse::{Event, KeepAlive, Sse};
+    use tokio::sync::broadcast::error::RecvError;
+
+    let rx = state.live_events.subscribe();
+    let events = futures::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    let sse = Event::default().event(event.name()).json_data(&event);
+                    return Some((sse, rx));
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::debug!(skipped, "SSE client lagging — dropped live events");
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+    let heartbeat = KeepAlive::new()
+        .interval(STREAM_HEARTBEAT)
+        .text("heartbeat");
+    Sse::new(events).keep_alive(heartbeat).into_response()
+}
+
+/// One WebSocket message: the `data` of a v2 envelope.
+#[derive(Serialize)]
+struct WsFrame<'a, T: Serialize> {
+    /// `snapshot` for the initial live snapshot, else the live event name
+    event: &'a str,
+    payload: &'a T,
+}
+
+async fn send_ws_frame<T: Serialize>(
+    socket: &mut axum::extract::ws::WebSocket,
+    event: &str,
+    payload: &T,
+) -> Result<(), axum::Error> {
+    let text = ApiResponse::json(WsFrame { event, payload }).map_err(axum::Error::new)?;
+    socket.send(axum::extract::ws::Message::Text(text)).await
+}
+
+/// GET /api/v2/ws — WebSocket mirror of `/api/v2/live`.
+///
+/// Sends the current live snapshot on connect, then each live event
+/// (`ticket`, `advisory`, `rig_state`, `metrics`) as it happens. Every
+/// message is a v2 envelope whose `data` is `{ "event", "payload" }`. The
+/// server pings every heartbeat interval and closes a client that has not
+/// answered by the next one.
+pub async fn live_ws(
+    ws: axum::extract::ws::WebSocketUpgrade,
+    State(state): State<DashboardState>,
+) -> Response {
+    ws.on_upgrade(move |socket| live_socket(socket, state))
+}
+
+async fn live_socket(mut socket: axum::extract::ws::WebSocket, state: DashboardState) {
+    use axum::extract::ws::Message;
+    use tokio::sync::broadcast::error::RecvError;
+
+    // Subscribe before taking the snapshot so nothing in between is missed
+    let mut rx = state.live_events.subscribe();
+    let mut metrics_rx = state.live_events.subscribe_metrics();
+    let snapshot = build_live(&*state.app_state.read().await, &state);
+    if let Err(e) = send_ws_frame(&mut socket, "snapshot", &snapshot).await {
+        tracing::debug!(error = %e, "WebSocket client gone before snapshot");
+        return;
+    }
+
+    let mut ping = tokio::time::interval(STREAM_HEARTBEAT);
+    ping.tick().await;
+    let mut awaiting_pong = false;
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Ok(event) => {
+                    if send_ws_frame(&mut socket, event.name(), &event).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::debug!(skipped, "WebSocket client lagging — dropped live events");
+                }
+                Err(RecvError::Closed) => {
+                    let _ = socket.send(Message::Close(None)).await;
+                    return;
+                }
+            },
+            metrics = metrics_rx.recv() => match metrics {
+                Ok(event) => {
+                    if send_ws_frame(&mut socket, event.name(), &event).await.is_err() {
+                        return;
+                    }
+                }
+                // Stale metrics are superseded by the next packet
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => {
+                    let _ = socket.send(Message::Close(None)).await;
+                    return;
+                }
+            },
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                // Pong or any other traffic shows the client is alive
+                Some(Ok(_)) => awaiting_pong = false,
+            },
+            _ = ping.tick() => {
+                if awaiting_pong {
+                    tracing::debug!("WebSocket client missed a ping — closing");
+                    let _ = socket.send(Message::Close(None)).await;
+                    return;
+                }
+                if socket.send(Message::Ping(Vec::new())).await.is_err() {
+                    return;
+                }
+                awaiting_pong = true;
+            }
+        }
+    }
 }
 
 /// GET /api/v2/drilling
@@ -605,78 +791,132 @@ pub async fn reports_critical(
         .min(1000);
     let reports = crate::storage::history::get_critical_reports(limit);
 
-    let entries: Vec<super::handlers::CriticalReportEntry> = reports
-        .into_iter()
-        .map(|report| {
-            let physics = &report.physics_report;
-            let sig_content = format!(
-                "{}:{}:{}:{}:{}",
-                report.timestamp,
-                report.efficiency_score,
-                report.recommendation,
-                physics.current_depth,
-                physics.current_rop
-            );
-            let digital_signature = format!("MD5-{:x}", md5::compute(sig_content.as_bytes()));
+    let entries: Vec<super::handlers::CriticalReportEntry> =
+        reports.into_iter().map(report_entry).collect();
 
-            let dt =
-                chrono::DateTime::from_timestamp(report.timestamp as i64, 0).unwrap_or_default();
-            let timestamp_formatted = dt.to_rfc3339();
-            let signature_timestamp = Utc::now().to_rfc3339();
-            let report_id = format!("RPT-{}", report.timestamp);
+    ApiResponse::ok(entries)
+}
 
-            let (trigger_param, trigger_val, threshold_val) =
-                super::handlers::extract_trigger_info(&report);
+/// GET /api/v2/reports/by-depth?min_ft=3200&max_ft=3600&limit=200
+///
+/// The newest `limit` reports whose bit depth falls in the window, oldest
+/// first. Depth is not monotonic, so this scans recent history (see
+/// `storage::history::MAX_DEPTH_SCAN_REPORTS`) rather than seeking a range;
+/// the scan runs on the blocking pool.
+pub async fn reports_by_depth(Query(q): Query<DepthRangeQuery>) -> Response {
+    let (Some(min_ft), Some(max_ft)) = (q.min_ft, q.max_ft) else {
+        return ApiErrorResponse::bad_request("min_ft and max_ft are required");
+    };
+    if !min_ft.is_finite() || !max_ft.is_finite() || min_ft > max_ft {
+        return ApiErrorResponse::bad_request("min_ft must be <= max_ft");
+    }
 
-            let votes_summary: Vec<String> = report
-                .votes
-                .iter()
-                .map(|v| {
-                    format!(
-                        "{} ({}%): {:?}",
-                        v.specialist,
-                        (v.weight * 100.0).clamp(0.0, 100.0) as u8,
-                        v.vote
-                    )
-                })
-                .collect();
-
-            super::handlers::CriticalReportEntry {
-                report_id,
-                timestamp: report.timestamp,
-                timestamp_formatted,
-                efficiency_score: report.efficiency_score,
-                risk_level: format!("{:?}", report.risk_level),
-                recommendation: report.recommendation,
-                expected_benefit: report.expected_benefit,
-                reasoning: report.reasoning,
-                trigger_parameter: trigger_param,
-                trigger_value: trigger_val,
-                threshold_value: threshold_val,
-                drilling_params: super::handlers::CriticalDrillingParams {
-                    bit_depth: physics.current_depth,
-                    rop: physics.current_rop,
-                    wob: physics.current_wob,
-                    rpm: physics.current_rpm,
-                    torque: physics.current_torque,
-                    flow_in: physics.current_flow_in,
-                    flow_out: physics.current_flow_out,
-                    flow_balance: physics.current_flow_out - physics.current_flow_in,
-                    spp: physics.current_spp,
-                    mud_weight: physics.current_mud_weight,
-                    ecd: physics.current_ecd,
-                    pit_volume: physics.current_pit_volume,
-                    mse: physics.avg_mse,
-                    mse_efficiency: physics.mse_efficiency,
-                },
-                votes_summary,
-                digital_signature,
-                signature_timestamp,
-            }
+    let limit = q
+        .limit
+        .unwrap_or(200)
+        .min(crate::storage::history::MAX_DEPTH_RESULTS);
+
+    let scan = tokio::task::spawn_blocking(move || {
+        crate::storage::history::query_by_depth(min_ft, max_ft, limit)
+    })
+    .await;
+    match scan {
+        Ok(Ok(reports)) => {
+            let entries: Vec<super::handlers::CriticalReportEntry> =
+                reports.into_iter().map(report_entry).collect();
+            ApiResponse::ok(entries)
+        }
+        Ok(Err(e)) => ApiErrorResponse::service_unavailable(format!("History storage: {e}")),
+        Err(e) => ApiErrorResponse::internal(format!("Depth query failed: {e}")),
+    }
+}
+
+/// GET /api/v2/advisories/search?q=pack-off&limit=50
+///
+/// Case-insensitive substring match on advisory recommendations, newest
+/// first. A linear scan over stored history, not an index.
+pub async fn search_advisories(Query(q): Query<SearchQuery>) -> Response {
+    let query = q.q.unwrap_or_default();
+    if query.trim().is_empty() {
+        return ApiErrorResponse::bad_request("q is required");
+    }
+    let limit = q
+        .limit
+        .unwrap_or(50)
+        .min(crate::storage::history::MAX_SEARCH_RESULTS);
+
+    match crate::storage::history::search_recommendations(&query, limit) {
+        Ok(advisories) => ApiResponse::ok(advisories),
+        Err(e) => ApiErrorResponse::service_unavailable(format!("History storage: {e}")),
+    }
+}
+
+/// Build the signed report export entry shared by the report endpoints
+fn report_entry(report: crate::types::StrategicReport) -> super::handlers::CriticalReportEntry {
+    let physics = &report.physics_report;
+    let sig_content = format!(
+        "{}:{}:{}:{}:{}",
+        report.timestamp,
+        report.efficiency_score,
+        report.recommendation,
+        physics.current_depth,
+        physics.current_rop
+    );
+    let digital_signature = format!("MD5-{:x}", md5::compute(sig_content.as_bytes()));
+
+    let dt = chrono::DateTime::from_timestamp(report.timestamp as i64, 0).unwrap_or_default();
+    let timestamp_formatted = dt.to_rfc3339();
+    let signature_timestamp = Utc::now().to_rfc3339();
+    let report_id = format!("RPT-{}", report.timestamp);
+
+    let (trigger_param, trigger_val, threshold_val) =
+        super::handlers::extract_trigger_info(&report);
+
+    let votes_summary: Vec<String> = report
+        .votes
+        .iter()
+        .map(|v| {
+            format!(
+                "{} ({}%): {:?}",
+                v.specialist,
+                (v.weight * 100.0).clamp(0.0, 100.0) as u8,
+                v.vote
+            )
         })
         .collect();
 
-    ApiResponse::ok(entries)
+    super::handlers::CriticalReportEntry {
+        report_id,
+        timestamp: report.timestamp,
+        timestamp_formatted,
+        efficiency_score: report.efficiency_score,
+        risk_level: format!("{:?}", report.risk_level),
+        recommendation: report.recommendation,
+        expected_benefit: report.expected_benefit,
+        reasoning: report.reasoning,
+        trigger_parameter: trigger_param,
+        trigger_value: trigger_val,
+        threshold_value: threshold_val,
+        drilling_params: super::handlers::CriticalDrillingParams {
+            bit_depth: physics.current_depth,
+            rop: physics.current_rop,
+            wob: physics.current_wob,
+            rpm: physics.current_rpm,
+            torque: physics.current_torque,
+            flow_in: physics.current_flow_in,
+            flow_out: physics.current_flow_out,
+            flow_balance: physics.current_flow_out - physics.current_flow_in,
+            spp: physics.current_spp,
+            mud_weight: physics.current_mud_weight,
+            ecd: physics.current_ecd,
+            pit_volume: physics.current_pit_volume,
+            mse: physics.avg_mse,
+            mse_efficiency: physics.mse_efficiency,
+        },
+        votes_summary,
+        digital_signature,
+        signature_timestamp,
+    }
 }
 
 /// GET /api/v2/ml/latest
@@ -735,7 +975,8 @@ pub async fn ml_optimal(
         return ApiErrorResponse::service_unavailable("ML storage not available");
     };
 
-    match storage.find_by_depth(&app.field_name, &app.well_id, depth, 500.0, 5) {
+    let wellbore = app.active_wellbore.as_ref().map(|w| w.branch.as_str());
+    match storage.find_by_depth(&app.field_name, &app.well_id, wellbore, depth, 500.0, 5) {
         Ok(reports) if !reports.is_empty() => {
             for report in &reports {
                 if let crate::types::AnalysisResult::Success(insights) = &report.result {
@@ -882,15 +1123,24 @@ pub async fn acknowledge_advisory(
         .unwrap_or_default()
         .as_secs();
 
+    let (category, critical) = crate::pipeline::acknowledgments::ack_subject(
+        &*state.app_state.read().await,
+        request.ticket_timestamp,
+    );
     let record = super::handlers::AcknowledgmentRecord {
         ticket_timestamp: request.ticket_timestamp,
         acknowledged_by: request.acknowledged_by,
         acknowledged_at: now,
         notes: request.notes,
         action_taken: request.action_taken,
+        category,
+        critical,
+        reason_code: None,
     };
 
-    if let Err(e) = crate::storage::acks::persist(record.acknowledged_at, &record) {
+    if let Err(e) =
+        crate::storage::acks::persist(record.acknowledged_at, record.ticket_timestamp, &record)
+    {
         tracing::warn!("Failed to persist acknowledgment: {}", e);
     }
 
@@ -903,6 +1153,79 @@ pub async fn acknowledge_advisory(
     ApiResponse::ok(record)
 }
 
+/// POST /api/v2/advisories/ack — acknowledge several advisories at once.
+///
+/// Returns one result per requested timestamp so unknown advisories are
+/// visible. Records for the advisories that exist are written in a single
+/// sled batch; a `false_alarm` / `handled` reason code also records operator
+/// feedback on each advisory.
+pub async fn bulk_acknowledge(
+    State(state): State<DashboardState>,
+    axum::Json(request): axum::Json<crate::pipeline::acknowledgments::BulkAckRequest>,
+) -> Response {
+    use crate::pipeline::acknowledgments::{self, MAX_BULK_ACKS};
+
+    if request.advisory_timestamps.is_empty() {
+        return ApiErrorResponse::bad_request("advisory_timestamps must not be empty");
+    }
+    if request.advisory_timestamps.len() > MAX_BULK_ACKS {
+        return ApiErrorResponse::bad_request(format!(
+            "At most {MAX_BULK_ACKS} advisories per request"
+        ));
+    }
+    if request.submitted_by.trim().is_empty() {
+        return ApiErrorResponse::bad_request("submitted_by is required");
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let well_id = crate::config::get().well.name.clone();
+    let outcome = acknowledgments::bulk_acknowledge(
+        request,
+        &well_id,
+        now,
+        crate::storage::history::get_by_timestamp,
+    );
+
+    if !outcome.records.is_empty() {
+        let entries = outcome
+            .records
+            .iter()
+            .map(|r| (r.acknowledged_at, r.ticket_timestamp, r));
+        if let Err(e) = crate::storage::acks::persist_batch(entries) {
+            return ApiErrorResponse::internal(format!("Failed to persist acknowledgments: {e}"));
+        }
+    }
+    for record in &outcome.feedback {
+        if let Err(e) = crate::storage::feedback::persist(record) {
+            tracing::warn!("Failed to persist acknowledgment feedback: {}", e);
+        }
+    }
+
+    let acknowledged = outcome.records.len();
+    {
+        let mut app = state.app_state.write().await;
+        for record in outcome.records {
+            if app.acknowledgments.len() >= crate::pipeline::MAX_ACKNOWLEDGMENTS {
+                app.acknowledgments.pop_front();
+            }
+            app.acknowledgments.push_back(record);
+        }
+    }
+    tracing::info!(
+        acknowledged,
+        requested = outcome.items.len(),
+        "Bulk advisory acknowledgment"
+    );
+
+    ApiResponse::ok(serde_json::json!({
+        "acknowledged": acknowledged,
+        "results": outcome.items,
+    }))
+}
+
 /// GET /api/v2/advisory/acknowledgments
 pub async fn get_acknowledgments(State(state): State<DashboardState>) -> Response {
     let app = state.app_state.read().await;
@@ -972,14 +1295,7 @@ pub async fn reload_config() -> Response {
 // ============================================================================
 
 /// Request body for submitting feedback on an advisory.
-#[derive(Debug, Deserialize)]
-pub struct SubmitFeedbackRequest {
-    pub outcome: crate::storage::feedback::FeedbackOutcome,
-    #[serde(default)]
-    pub submitted_by: String,
-    #[serde(default)]
-    pub notes: String,
-}
+pub type SubmitFeedbackRequest = crate::storage::feedback::FeedbackSubmission;
 
 /// POST /api/v2/advisory/feedback/:timestamp — submit operator feedback on an advisory.
 pub async fn submit_feedback(
@@ -1005,21 +1321,9 @@ pub async fn submit_feedback(
         .unwrap_or_default()
         .as_secs();
 
-    let record = crate::storage::feedback::FeedbackRecord {
-        advisory_timestamp: timestamp,
-        outcome: body.outcome,
-        category: report.category,
-        trigger_parameter: report.trigger_parameter.clone(),
-        trigger_value: report.trigger_value,
-        threshold_value: report.threshold_value,
-        submitted_by: if body.submitted_by.is_empty() {
-            "anonymous".to_string()
-        } else {
-            body.submitted_by
-        },
-        submitted_at: now,
-        notes: body.notes,
-    };
+    let well_id = crate::config::get().well.name.clone();
+    let record =
+        crate::storage::feedback::FeedbackRecord::from_submission(&report, body, &well_id, now);
 
     if let Err(e) = crate::storage::feedback::persist(&record) {
         return ApiErrorResponse::internal(format!("Failed to persist feedback: {}", e));
@@ -1035,6 +1339,15 @@ pub async fn feedback_stats() -> Response {
     ApiResponse::ok(stats)
 }
 
+/// GET /api/v2/feedback/false-positive-sources — trigger parameters ranked by
+/// false-positive rate, with threshold suggestions for the worst offenders.
+pub async fn false_positive_sources() -> Response {
+    let records = crate::storage::feedback::load_all();
+    let config = crate::config::get();
+    let sources = crate::storage::suggestions::compute_false_positive_sources(&records, &config);
+    ApiResponse::ok(sources)
+}
+
 /// GET /api/v2/config/suggestions — threshold adjustment suggestions based on feedback.
 pub async fn config_suggestions() -> Response {
     let records = crate::storage::feedback::load_all();
@@ -1043,6 +1356,114 @@ pub async fn config_suggestions() -> Response {
     ApiResponse::ok(suggestions)
 }
 
+/// GET /api/v2/config/sanity — does the config match the well being drilled?
+pub async fn config_sanity(State(state): State<DashboardState>) -> Response {
+    let baseline_means = match &state.threshold_manager {
+        Some(manager) => {
+            let mgr = match manager.read() {
+                Ok(g) => g,
+                Err(e) => e.into_inner(),
+            };
+            crate::config::sanity::locked_baseline_means(&mgr, &state.equipment_id)
+        }
+        None => Vec::new(),
+    };
+    let config = crate::config::get();
+    let app = state.app_state.read().await;
+    ApiResponse::ok(app.config_sanity.report(&config, &baseline_means))
+}
+
+// ============================================================================
+// Bit-run endpoints
+// ============================================================================
+
+/// GET /api/v2/bit-runs — archived bit runs with their dull-grade correlation.
+pub async fn list_bit_runs() -> Response {
+    ApiResponse::ok(crate::storage::bit_runs::load_all())
+}
+
+/// POST /api/v2/bit-runs/:id/dull — enter the post-run dull grade.
+///
+/// Cross-references the run's advisories with the reported outcome and
+/// records the result as feedback for confidence calibration.
+pub async fn submit_dull_grade(
+    Path(id): Path<String>,
+    axum::Json(body): axum::Json<crate::storage::bit_runs::DullGradeSubmission>,
+) -> Response {
+    use crate::storage::bit_runs;
+
+    if id.trim().is_empty() {
+        return ApiErrorResponse::bad_request("Bit run id must not be empty");
+    }
+    if body.end_ts <= body.start_ts {
+        return ApiErrorResponse::bad_request("end_ts must be after start_ts");
+    }
+    if body.dull.inner > 8 || body.dull.outer > 8 {
+        return ApiErrorResponse::bad_request("IADC inner/outer wear must be 0-8");
+    }
+
+    let advisories: Vec<_> = crate::storage::history::get_all_reports()
+        .into_iter()
+        .filter(|a| a.timestamp >= body.start_ts && a.timestamp <= body.end_ts)
+        .collect();
+    // Operator feedback takes precedence over the dull-grade inference
+    let already_rated: std::collections::HashSet<u64> = crate::storage::feedback::load_all()
+        .into_iter()
+        .filter(|r| !r.is_automatic())
+        .map(|r| r.advisory_timestamp)
+        .collect();
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let config = crate::config::get();
+    let (correlation, records) = bit_runs::correlate(
+        &id,
+        &config.well.name,
+        &body.dull,
+        &advisories,
+        &already_rated,
+        &config.bit_runs,
+        now,
+    );
+
+    for record in &records {
+        if let Err(e) = crate::storage::feedback::persist(record) {
+            return ApiErrorResponse::internal(format!("Failed to persist feedback: {}", e));
+        }
+    }
+
+    let run = bit_runs::BitRunRecord {
+        id,
+        start_ts: body.start_ts,
+        end_ts: body.end_ts,
+        depth_in_ft: body.depth_in_ft,
+        depth_out_ft: body.depth_out_ft,
+        dull: body.dull,
+        submitted_by: if body.submitted_by.is_empty() {
+            "anonymous".to_string()
+        } else {
+            body.submitted_by
+        },
+        submitted_at: now,
+        notes: body.notes,
+        correlation,
+    };
+    if let Err(e) = bit_runs::persist(&run) {
+        return ApiErrorResponse::internal(format!("Failed to persist bit run: {}", e));
+    }
+
+    tracing::info!(
+        run = %run.id,
+        dull = %run.dull.iadc(),
+        correlated = run.correlation.correlated,
+        confirmed = run.correlation.confirmed,
+        "Dull grade recorded"
+    );
+    ApiResponse::ok(run)
+}
+
 // ============================================================================
 // Lookahead endpoint
 // ============================================================================
@@ -1077,6 +1498,7 @@ pub async fn lookahead_status(State(state): State<DashboardState>) -> Response {
     }
 
     let app = state.app_state.read().await;
+    let branch = app.active_wellbore.as_ref().map(|w| w.branch.clone());
     let (bit_depth, rop) = match &app.latest_wits_packet {
         Some(pkt) => (pkt.bit_depth, pkt.rop),
         None => {
@@ -1093,13 +1515,7 @@ pub async fn lookahead_status(State(state): State<DashboardState>) -> Response {
     };
     drop(app);
 
-    // Load prognosis (same path as coordinator)
-    let kb = crate::knowledge_base::KnowledgeBase::init();
-    let prognosis = if let Some(ref kb) = kb {
-        kb.prognosis()
-    } else {
-        crate::types::FormationPrognosis::load()
-    };
+    let prognosis = load_branch_prognosis(branch.as_deref());
 
     let Some(prognosis) = prognosis else {
         return ApiResponse::ok(LookaheadStatus {
@@ -1338,7 +1754,7 @@ pub async fn damping_recipes(State(_state): State<DashboardState>) -> Response {
 // ============================================================================
 
 /// POST /api/v2/well/debrief — generate and persist a post-well debrief.
-pub async fn generate_debrief_handler(State(_state): State<DashboardState>) -> Response {
+pub async fn generate_debrief_handler(State(state): State<DashboardState>) -> Response {
     // Init knowledge base from env vars
     let kb = match crate::knowledge_base::KnowledgeBase::init() {
         Some(kb) => kb,
@@ -1349,59 +1765,22 @@ pub async fn generate_debrief_handler(State(_state): State<DashboardState>) -> R
         }
     };
 
-    // Generate post-well summary
-    let post_well = match kb.complete_well() {
-        Ok(summary) => summary,
-        Err(e) => {
-            return ApiErrorResponse::internal(format!(
-                "Failed to generate post-well summary: {}",
-                e
-            ));
-        }
+    let (rig_states, data_loss) = {
+        let app = state.app_state.read().await;
+        let data_loss = app
+            .mesh_evictions
+            .as_ref()
+            .map(crate::gossip::store::EvictionLog::windows)
+            .unwrap_or_default();
+        (app.rig_state_log.clone(), data_loss)
     };
-
-    // Get advisory history from global history DB
-    let advisories = crate::storage::history::get_all_reports();
-
-    // Get operator feedback
-    let feedback_records = crate::storage::feedback::load_all();
-
-    // Get prognosis
-    let prognosis = kb.prognosis();
-
-    // Determine well start timestamp from first advisory
-    let well_start_ts = advisories.first().map(|a| a.timestamp).unwrap_or(0);
-
-    // Generate debrief
-    let debrief = crate::debrief::generate_debrief(
-        &post_well,
-        &advisories,
-        &feedback_records,
-        prognosis.as_ref(),
-        well_start_ts,
-    );
-
-    // Persist to KB post-well directory
-    let post_well_dir = kb.config().post_well_dir(&kb.config().well);
-    if let Err(e) = std::fs::create_dir_all(&post_well_dir) {
-        tracing::warn!("Failed to create post-well dir: {}", e);
-    }
-
-    let debrief_path = post_well_dir.join("debrief.json");
-    match serde_json::to_string_pretty(&debrief) {
-        Ok(json) => {
-            if let Err(e) = std::fs::write(&debrief_path, &json) {
-                tracing::warn!("Failed to write debrief.json: {}", e);
-            } else {
-                tracing::info!(path = ?debrief_path, "Wrote post-well debrief");
-            }
-        }
-        Err(e) => {
-            tracing::warn!("Failed to serialize debrief: {}", e);
-        }
+    match crate::debrief::complete_well(&kb, &rig_states, &data_loss) {
+        Ok(debrief) => ApiResponse::ok(debrief),
+        Err(e) => ApiErrorResponse::internal(format!(
+            "Failed to generate post-well summary: {}",
+            e
+        )),
     }
-
-    ApiResponse::ok(debrief)
 }
 
 /// GET /api/v2/well/debrief — read persisted debrief.
@@ -1435,6 +1814,223 @@ pub async fn get_debrief_handler(State(_state): State<DashboardState>) -> Respon
     }
 }
 
+// ============================================================================
+// Well export
+// ============================================================================
+
+/// Bytes buffered per chunk of the export download
+const EXPORT_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Chunks queued between the export writer and the response body
+const EXPORT_CHUNK_QUEUE: usize = 4;
+
+/// `Write` adapter that hands the export writer's output to the response
+/// body in chunks. Runs on a blocking thread; a full queue blocks the writer
+/// until the client catches up.
+struct ExportChunkSender {
+    tx: tokio::sync::mpsc::Sender<std::io::Result<axum::body::Bytes>>,
+    buf: Vec<u8>,
+}
+
+impl ExportChunkSender {
+    fn send_buffered(&mut self) -> std::io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = axum::body::Bytes::from(std::mem::take(&mut self.buf));
+        self.tx.blocking_send(Ok(chunk)).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "export client disconnected")
+        })
+    }
+}
+
+impl std::io::Write for ExportChunkSender {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        if self.buf.len() >= EXPORT_CHUNK_BYTES {
+            self.send_buffered()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.send_buffered()
+    }
+}
+
+/// Download name for a well's export bundle. The well name is operator
+/// input, so anything outside `[A-Za-z0-9._-]` becomes `_` before it goes
+/// into the `Content-Disposition` header.
+fn export_filename(well_id: &str) -> String {
+    let stem: String = well_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let stem = stem.trim_start_matches('.');
+    let stem = if stem.is_empty() { "well" } else { stem };
+    format!("{stem}-export.json")
+}
+
+/// GET /api/v2/export/well — download the whole well as a signed JSON bundle.
+///
+/// See `storage::export` for the layout. The bundle is signed with the fleet
+/// passphrase (`FLEET_PASSPHRASE`) when one is set, else carries only the
+/// content hash. Storage is read on the blocking writer thread and advisory
+/// history is streamed from it, so the download starts immediately and memory
+/// stays bounded; a storage error aborts the download.
+pub async fn export_well(State(state): State<DashboardState>) -> Response {
+    use crate::storage::export::{self, ExportSources};
+    use axum::http::header;
+
+    let config = if crate::config::is_initialized() {
+        crate::config::get().as_ref().clone()
+    } else {
+        crate::config::WellConfig::default()
+    };
+    let debrief = state.app_state.read().await.well_debrief.clone();
+    let baselines = state
+        .threshold_manager
+        .as_ref()
+        .and_then(|mgr| mgr.read().ok().map(|mgr| mgr.clone()));
+    let strategic_storage = state.strategic_storage.clone();
+
+    let mut sources = ExportSources {
+        exported_at: chrono::Utc::now().timestamp() as u64,
+        well_id: config.well.name.clone(),
+        field: config.well.field.clone(),
+        config,
+        baselines,
+        hourly_reports: Vec::new(),
+        daily_reports: Vec::new(),
+        feedback: Vec::new(),
+        debrief,
+    };
+    let filename = export_filename(&sources.well_id);
+    let key = std::env::var("FLEET_PASSPHRASE")
+        .ok()
+        .filter(|p| !p.is_empty());
+
+    let (tx, rx) = tokio::sync::mpsc::channel(EXPORT_CHUNK_QUEUE);
+    tokio::task::spawn_blocking(move || {
+        let sink = ExportChunkSender {
+            tx: tx.clone(),
+            buf: Vec::with_capacity(EXPORT_CHUNK_BYTES),
+        };
+        let reports = match &strategic_storage {
+            Some(storage) => storage
+                .count_hourly()
+                .and_then(|n| storage.get_hourly(n))
+                .and_then(|hourly| {
+                    let daily = storage.count_daily().and_then(|n| storage.get_daily(n))?;
+                    Ok((hourly, daily))
+                }),
+            None => Ok((Vec::new(), Vec::new())),
+        };
+        let key = key.as_deref().map(str::as_bytes);
+        let written = reports
+            .map_err(|e| std::io::Error::other(format!("strategic reports: {e}")))
+            .and_then(|(hourly, daily)| {
+                sources.hourly_reports = hourly;
+                sources.daily_reports = daily;
+                sources.feedback = crate::storage::feedback::load_all();
+                let advisories = crate::storage::history::iter_reports();
+                export::write_bundle(sink, &sources, advisories, key)
+            });
+        match written {
+            Ok(digest) => tracing::info!(
+                advisories = digest.advisories,
+                sha256 = %digest.sha256,
+                signed = digest.hmac_sha256.is_some(),
+                "Well export complete"
+            ),
+            Err(e) => {
+                tracing::warn!("Well export aborted: {}", e);
+                let _ = tx.blocking_send(Err(e));
+            }
+        }
+    });
+    let chunks = futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    });
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        axum::body::Body::from_stream(chunks),
+    )
+        .into_response()
+}
+
+/// Largest bundle `verify_export` accepts
+pub const MAX_IMPORT_BUNDLE_BYTES: usize = 128 * 1024 * 1024;
+
+/// What a verified export bundle contains
+#[derive(Debug, Serialize)]
+pub struct ExportVerification {
+    pub well_id: String,
+    pub field: String,
+    /// Rig and campaign from the bundle's well config
+    pub rig: String,
+    pub campaign: String,
+    pub exported_at: u64,
+    pub version: u32,
+    /// Whether the fleet signature was checked (a passphrase is configured)
+    pub signature_verified: bool,
+    pub advisories: usize,
+    pub feedback: usize,
+    pub hourly_reports: usize,
+    pub daily_reports: usize,
+    pub has_baselines: bool,
+    pub has_debrief: bool,
+}
+
+/// POST /api/v2/export/verify — check an export bundle before importing it.
+///
+/// Verifies the content hash, and the fleet signature when `FLEET_PASSPHRASE`
+/// is set, then summarises the payload. A bundle that fails either check is
+/// rejected with 400.
+pub async fn verify_export(body: axum::body::Bytes) -> Response {
+    let key = std::env::var("FLEET_PASSPHRASE")
+        .ok()
+        .filter(|p| !p.is_empty());
+    let signature_verified = key.is_some();
+
+    let verified = tokio::task::spawn_blocking(move || {
+        crate::storage::export::verify_bundle(&body, key.as_deref().map(str::as_bytes))
+    })
+    .await;
+    match verified {
+        Ok(Ok(export)) => ApiResponse::ok(ExportVerification {
+            well_id: export.well_id,
+            field: export.field,
+            rig: export.config.well.rig,
+            campaign: export.config.well.campaign,
+            exported_at: export.exported_at,
+            version: export.version,
+            signature_verified,
+            advisories: export.advisories.len(),
+            feedback: export.feedback.len(),
+            hourly_reports: export.strategic_reports.hourly.len(),
+            daily_reports: export.strategic_reports.daily.len(),
+            has_baselines: export.baselines.is_some(),
+            has_debrief: export.debrief.is_some(),
+        }),
+        Ok(Err(e)) => ApiErrorResponse::bad_request(e.to_string()),
+        Err(e) => ApiErrorResponse::internal(format!("Bundle verification failed: {e}")),
+    }
+}
+
 // ============================================================================
 // Debug endpoints
 // ============================================================================
@@ -1499,6 +2095,145 @@ pub async fn debug_fleet_intelligence(
     ApiResponse::ok(filtered)
 }
 
+// ============================================================================
+// Sensor diagnostics endpoint
+// ============================================================================
+
+/// Response for `GET /api/v2/sensors/diagnostics`.
+#[derive(Debug, Serialize)]
+pub struct SensorDiagnostics {
+    /// Per-channel feed resolution and quantization status
+    pub channels: Vec<crate::baseline::ChannelResolution>,
+    /// Sensor IDs of channels flagged as heavily quantized
+    pub quantized_channels: Vec<String>,
+}
+
+/// GET /api/v2/sensors/diagnostics — WITS feed resolution/quantization check.
+pub async fn sensor_diagnostics(State(state): State<DashboardState>) -> Response {
+    let Some(ref manager) = state.threshold_manager else {
+        return ApiErrorResponse::service_unavailable("Baseline learning not available");
+    };
+    let mgr = match manager.read() {
+        Ok(g) => g,
+        Err(e) => e.into_inner(),
+    };
+
+    let channels = mgr.resolution_diagnostics(&state.equipment_id);
+    let quantized_channels = channels
+        .iter()
+        .filter(|c| c.quantized)
+        .map(|c| c.sensor_id.clone())
+        .collect();
+
+    ApiResponse::ok(SensorDiagnostics {
+        channels,
+        quantized_channels,
+    })
+}
+
+/// GET /api/v2/pipeline/metrics — ingestion lag, processing time, throughput.
+pub async fn pipeline_metrics(State(state): State<DashboardState>) -> Response {
+    let app = state.app_state.read().await;
+    ApiResponse::ok(app.pipeline_metrics.clone())
+}
+
+/// GET /api/v2/pipeline/stats — p50/p95/p99 latency per processing stage.
+pub async fn pipeline_stats(State(state): State<DashboardState>) -> Response {
+    let latency = std::sync::Arc::clone(&state.app_state.read().await.stage_latency);
+    ApiResponse::ok(latency.snapshot())
+}
+
+/// GET /api/v2/benchmark/detection — detection latency per injected fault.
+///
+/// Only available when running on synthetic data with injected faults.
+pub async fn detection_benchmark(State(state): State<DashboardState>) -> Response {
+    let app = state.app_state.read().await;
+    match &app.detection_benchmark {
+        Some(benchmark) => {
+            ApiResponse::ok(benchmark.report(&crate::config::get().detection_benchmark))
+        }
+        None => ApiErrorResponse::service_unavailable(
+            "No injected faults — the detection benchmark runs on synthetic data only",
+        ),
+    }
+}
+
+// ============================================================================
+// Sidetrack endpoints
+// ============================================================================
+
+/// Response for `GET /api/v2/sidetrack`.
+#[derive(Debug, Serialize)]
+pub struct SidetrackStatus {
+    /// Active wellbore branch (`None` while on the original hole)
+    pub active: Option<crate::types::SidetrackEvent>,
+    /// Operator marker not yet applied by the pipeline
+    pub pending: Option<crate::types::SidetrackMarker>,
+}
+
+/// GET /api/v2/sidetrack — active wellbore branch.
+pub async fn get_sidetrack(State(state): State<DashboardState>) -> Response {
+    let app = state.app_state.read().await;
+    ApiResponse::ok(SidetrackStatus {
+        active: app.active_wellbore.clone(),
+        pending: app.pending_sidetrack.clone(),
+    })
+}
+
+/// POST /api/v2/sidetrack — operator marker starting a new wellbore branch.
+///
+/// Applied by the pipeline on the next packet. Kickoff defaults to the
+/// current bit depth.
+pub async fn mark_sidetrack(
+    State(state): State<DashboardState>,
+    axum::Json(marker): axum::Json<crate::types::SidetrackMarker>,
+) -> Response {
+    if let Some(kickoff) = marker.kickoff_depth_ft {
+        if !kickoff.is_finite() || kickoff <= 0.0 {
+            return ApiErrorResponse::bad_request("kickoff_depth_ft must be > 0");
+        }
+    }
+
+    let mut app = state.app_state.write().await;
+    if marker.kickoff_depth_ft.is_none() && app.latest_wits_packet.is_none() {
+        return ApiErrorResponse::bad_request(
+            "No WITS data yet — provide kickoff_depth_ft explicitly.",
+        );
+    }
+    app.pending_sidetrack = Some(marker.clone());
+
+    ApiResponse::ok(serde_json::json!({
+        "pending": marker,
+        "message": "Sidetrack marker accepted; the new branch starts on the next packet."
+    }))
+}
+
+/// POST /api/v2/mpd/choke-adjustment — operator declaration of an MPD choke
+/// move. SPP changes from the next packet on are attributed to the choke for
+/// `mpd.context_secs`.
+pub async fn declare_choke_adjustment(
+    State(state): State<DashboardState>,
+    body: Option<axum::Json<crate::physics_engine::choke::ChokeAdjustmentDeclaration>>,
+) -> Response {
+    let cfg = &crate::config::get().mpd;
+    if !cfg.enabled {
+        return ApiErrorResponse::bad_request(
+            "MPD choke adjustments are disabled ([mpd] enabled = false)",
+        );
+    }
+    let declaration = body.map(|b| b.0).unwrap_or_default();
+
+    let mut app = state.app_state.write().await;
+    app.pending_choke_adjustment = Some(declaration.clone());
+
+    ApiResponse::ok(serde_json::json!({
+        "pending": declaration,
+        "context_secs": cfg.context_secs,
+        "message": "Choke adjustment accepted; pressure changes from the next packet on \
+                    are attributed to the choke."
+    }))
+}
+
 // ============================================================================
 // Formation context endpoint
 // ============================================================================
@@ -1571,6 +2306,68 @@ pub struct BitWearStatus {
     pub buckets_tracked: usize,
 }
 
+/// Load the prognosis (same path as the coordinator) as seen from the active
+/// wellbore branch.
+fn load_branch_prognosis(branch: Option<&str>) -> Option<crate::types::FormationPrognosis> {
+    let kb = crate::knowledge_base::KnowledgeBase::init();
+    let prognosis = if let Some(ref kb) = kb {
+        kb.prognosis()
+    } else {
+        crate::types::FormationPrognosis::load()
+    }?;
+    match branch {
+        Some(b) => Some(prognosis.for_branch(b).unwrap_or(prognosis)),
+        None => Some(prognosis),
+    }
+}
+
+/// Response for `GET /api/v2/formations/candidate-tops`.
+#[derive(Debug, Serialize)]
+pub struct CandidateTopsResponse {
+    /// Candidate tops ordered by depth
+    pub candidates: Vec<crate::cfc::candidate_tops::CandidateTop>,
+    /// Candidates matched to a prognosis top
+    pub matched_count: usize,
+    /// Mean absolute picked-vs-predicted error over matched candidates (ft)
+    pub mean_abs_depth_error_ft: Option<f64>,
+}
+
+/// GET /api/v2/formations/candidate-tops — formation tops picked from detected transitions.
+pub async fn candidate_tops(State(state): State<DashboardState>) -> Response {
+    let app = state.app_state.read().await;
+    let tracker = app.candidate_tops.clone();
+    let branch = app.active_wellbore.as_ref().map(|w| w.branch.clone());
+    drop(app);
+
+    let config = crate::config::get();
+    let prognosis = if config.candidate_tops.match_prognosis {
+        load_branch_prognosis(branch.as_deref())
+    } else {
+        None
+    };
+    let candidates = tracker.candidates(
+        prognosis.as_ref().map(|p| p.formations.as_slice()),
+        &config.candidate_tops,
+    );
+
+    let errors: Vec<f64> = candidates
+        .iter()
+        .filter_map(|c| c.depth_error_ft)
+        .map(f64::abs)
+        .collect();
+    let mean_abs_depth_error_ft = if errors.is_empty() {
+        None
+    } else {
+        Some(errors.iter().sum::<f64>() / errors.len() as f64)
+    };
+
+    ApiResponse::ok(CandidateTopsResponse {
+        matched_count: errors.len(),
+        mean_abs_depth_error_ft,
+        candidates,
+    })
+}
+
 /// GET /api/v2/formation/context — current formation, next boundary, upcoming formations.
 pub async fn formation_context(State(state): State<DashboardState>) -> Response {
     let app = state.app_state.read().await;
@@ -1592,6 +2389,7 @@ pub async fn formation_context(State(state): State<DashboardState>) -> Response
     // Connection gas events
     let connection_gas: Vec<_> = app.connection_gas_tracker.latest_events().iter().cloned().collect();
     let connection_gas_trending_up = app.connection_gas_tracker.is_trending_up();
+    let branch = app.active_wellbore.as_ref().map(|w| w.branch.clone());
     // Bit wear status
     let bit_wear = {
         let tracker = &app.bit_wear_tracker;
@@ -1608,13 +2406,7 @@ pub async fn formation_context(State(state): State<DashboardState>) -> Response
     };
     drop(app);
 
-    // Load prognosis (same path as lookahead_status)
-    let kb = crate::knowledge_base::KnowledgeBase::init();
-    let prognosis = if let Some(ref kb) = kb {
-        kb.prognosis()
-    } else {
-        crate::types::FormationPrognosis::load()
-    };
+    let prognosis = load_branch_prognosis(branch.as_deref());
 
     let Some(prognosis) = prognosis else {
         return ApiResponse::ok(FormationContext {
@@ -1790,6 +2582,95 @@ pub async fn swab_surge_status(State(state): State<DashboardState>) -> Response
     }
 }
 
+/// GET /api/v2/connections/scorecard — connection-practices scorecard.
+///
+/// Scores the connections in the last `hours` (default: all retained
+/// connections) on duration consistency, gas control and pressure management.
+pub async fn connection_scorecard(
+    State(state): State<DashboardState>,
+    Query(q): Query<ShiftQuery>,
+) -> Response {
+    let cfg = &crate::config::get().connection_scorecard;
+    if !cfg.enabled {
+        return ApiErrorResponse::service_unavailable("Connection scorecard is disabled");
+    }
+
+    let from_ts = q.hours.map(|hours| {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        now.saturating_sub((hours.max(0.0) * 3600.0) as u64)
+    });
+
+    let app = state.app_state.read().await;
+    let events: Vec<_> = app
+        .connection_log
+        .iter()
+        .filter(|e| from_ts.is_none_or(|from| e.timestamp >= from))
+        .cloned()
+        .collect();
+
+    ApiResponse::ok(crate::physics_engine::connection_scorecard::build_scorecard(
+        &events, cfg,
+    ))
+}
+
+/// GET /api/v2/charts/time-depth — whole-well time vs depth series.
+pub async fn time_depth_chart(State(state): State<DashboardState>) -> Response {
+    let cfg = &crate::config::get().charts;
+    if !cfg.enabled {
+        return ApiErrorResponse::service_unavailable("Drilling charts are disabled");
+    }
+    let app = state.app_state.read().await;
+    ApiResponse::ok(app.chart_log.time_depth(cfg))
+}
+
+/// GET /api/v2/charts/rop-depth — whole-well mean ROP by depth interval.
+pub async fn rop_depth_chart(State(state): State<DashboardState>) -> Response {
+    let cfg = &crate::config::get().charts;
+    if !cfg.enabled {
+        return ApiErrorResponse::service_unavailable("Drilling charts are disabled");
+    }
+    let app = state.app_state.read().await;
+    ApiResponse::ok(app.chart_log.rop_depth(cfg))
+}
+
+/// GET /api/v2/logs/mse — whole-well MSE-depth log (gaps where MSE was invalid).
+pub async fn mse_log(State(state): State<DashboardState>) -> Response {
+    let cfg = &crate::config::get().charts;
+    if !cfg.enabled || !cfg.mse_log {
+        return ApiErrorResponse::service_unavailable("MSE-depth log is disabled");
+    }
+    let app = state.app_state.read().await;
+    ApiResponse::ok(app.chart_log.mse_log(cfg))
+}
+
+/// GET /api/v2/drilling/directional — slide / rotate footage, slide ROP vs
+/// expectation, and detected survey stations.
+pub async fn directional_status(State(state): State<DashboardState>) -> Response {
+    if !crate::config::get().directional.enabled {
+        return ApiErrorResponse::service_unavailable("Directional tracking is disabled");
+    }
+    let app = state.app_state.read().await;
+    match &app.directional {
+        Some(summary) => ApiResponse::ok(summary.clone()),
+        None => ApiErrorResponse::service_unavailable("No directional data yet"),
+    }
+}
+
+/// GET /api/v2/cfc/health — drift / degradation status of the CfC networks.
+pub async fn cfc_health(State(state): State<DashboardState>) -> Response {
+    if !crate::config::get().cfc_health.enabled {
+        return ApiErrorResponse::service_unavailable("CfC health monitoring is disabled");
+    }
+    let app = state.app_state.read().await;
+    match &app.cfc_health {
+        Some(report) => ApiResponse::ok(report.clone()),
+        None => ApiErrorResponse::service_unavailable("No CfC health data yet"),
+    }
+}
+
 /// GET /api/v2/shift/handover — structured shift handover report.
 pub async fn shift_handover(
     State(state): State<DashboardState>,
@@ -1935,3 +2816,106 @@ pub async fn metrics(State(state): State<DashboardState>) -> Response {
         .await
         .into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::AppState;
+    use crate::storage::export::{verify_bundle, ExportError};
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+    use tower::ServiceExt;
+
+    fn create_test_state() -> DashboardState {
+        DashboardState {
+            app_state: Arc::new(RwLock::new(AppState::default())),
+            strategic_storage: None,
+            threshold_manager: None,
+            equipment_id: "RIG".to_string(),
+            ml_storage: None,
+            live_events: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_export_filename_keeps_only_safe_characters() {
+        assert_eq!(export_filename("Well-A_1.2"), "Well-A_1.2-export.json");
+        assert_eq!(
+            export_filename("Well \"A\"\r\nSet-Cookie: x"),
+            "Well__A___Set-Cookie__x-export.json"
+        );
+        assert_eq!(
+            export_filename("../../etc/passwd"),
+            "_.._etc_passwd-export.json"
+        );
+        assert_eq!(export_filename(""), "well-export.json");
+    }
+
+    #[tokio::test]
+    async fn test_export_well_streams_signed_bundle() {
+        // Only the export handlers read the passphrase
+        std::env::set_var("FLEET_PASSPHRASE", "fleet-secret");
+        let app = super::super::v2_routes::v2_api_routes(create_test_state());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/export/well")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let disposition = response.headers()[header::CONTENT_DISPOSITION]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(disposition.starts_with("attachment; filename=\""));
+        assert!(disposition.ends_with("-export.json\""));
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let export = verify_bundle(&body, Some(b"fleet-secret")).unwrap();
+        assert_eq!(
+            export.well_id,
+            crate::config::WellConfig::default().well.name
+        );
+        assert!(matches!(
+            verify_bundle(&body, Some(b"other-fleet")),
+            Err(ExportError::SignatureMismatch)
+        ));
+
+        // The verify endpoint accepts the bundle and rejects a tampered copy
+        let verify = |bundle: Vec<u8>| {
+            app.clone().oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/export/verify")
+                    .body(Body::from(bundle))
+                    .unwrap(),
+            )
+        };
+        let verified = verify(body.to_vec()).await.unwrap();
+        assert_eq!(verified.status(), StatusCode::OK);
+        let summary: serde_json::Value = serde_json::from_slice(
+            &axum::body::to_bytes(verified.into_body(), usize::MAX)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(summary["data"]["signature_verified"], true);
+        assert_eq!(summary["data"]["version"], 1);
+
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        let tampered = text.replacen("\"field\":\"", "\"field\":\"X", 1);
+        let rejected = verify(tampered.into_bytes()).await.unwrap();
+        std::env::remove_var("FLEET_PASSPHRASE");
+        assert_eq!(rejected.status(), StatusCode::BAD_REQUEST);
+    }
+}
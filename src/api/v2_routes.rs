@@ -1,5 +1,6 @@
 //! v2 API route table.
 
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{get, post};
 use axum::Router;
 
@@ -12,11 +13,15 @@ pub fn v2_api_routes(state: DashboardState) -> Router {
         // Core endpoints
         .route("/system/health", get(v2_handlers::system_health))
         .route("/live", get(v2_handlers::live_data))
+        .route("/stream", get(v2_handlers::live_stream))
+        .route("/ws", get(v2_handlers::live_ws))
         .route("/drilling", get(v2_handlers::drilling))
+        .route("/drilling/directional", get(v2_handlers::directional_status))
         // Reports
         .route("/reports/hourly", get(v2_handlers::reports_hourly))
         .route("/reports/daily", get(v2_handlers::reports_daily))
         .route("/reports/critical", get(v2_handlers::reports_critical))
+        .route("/reports/by-depth", get(v2_handlers::reports_by_depth))
         // ML
         .route("/ml/latest", get(v2_handlers::ml_latest))
         .route("/ml/optimal", get(v2_handlers::ml_optimal))
@@ -26,10 +31,13 @@ pub fn v2_api_routes(state: DashboardState) -> Router {
         .route("/config/validate", post(v2_handlers::validate_config))
         .route("/config/reload", post(v2_handlers::reload_config))
         .route("/config/suggestions", get(v2_handlers::config_suggestions))
+        .route("/config/sanity", get(v2_handlers::config_sanity))
         // Campaign
         .route("/campaign", get(v2_handlers::get_campaign))
         .route("/campaign", post(v2_handlers::set_campaign))
         // Advisory
+        .route("/advisories/search", get(v2_handlers::search_advisories))
+        .route("/advisories/ack", post(v2_handlers::bulk_acknowledge))
         .route(
             "/advisory/acknowledge",
             post(v2_handlers::acknowledge_advisory),
@@ -44,6 +52,13 @@ pub fn v2_api_routes(state: DashboardState) -> Router {
             "/advisory/feedback/:timestamp",
             post(v2_handlers::submit_feedback),
         )
+        .route(
+            "/feedback/false-positive-sources",
+            get(v2_handlers::false_positive_sources),
+        )
+        // Bit runs
+        .route("/bit-runs", get(v2_handlers::list_bit_runs))
+        .route("/bit-runs/:id/dull", post(v2_handlers::submit_dull_grade))
         // Lookahead
         .route("/lookahead/status", get(v2_handlers::lookahead_status))
         // Damping
@@ -52,13 +67,53 @@ pub fn v2_api_routes(state: DashboardState) -> Router {
         // Well debrief
         .route("/well/debrief", get(v2_handlers::get_debrief_handler))
         .route("/well/debrief", post(v2_handlers::generate_debrief_handler))
+        // Well export
+        .route("/export/well", get(v2_handlers::export_well))
+        .route(
+            "/export/verify",
+            post(v2_handlers::verify_export)
+                .layer(DefaultBodyLimit::max(v2_handlers::MAX_IMPORT_BUNDLE_BYTES)),
+        )
         // Formation context
         .route(
             "/formation/context",
             get(v2_handlers::formation_context),
         )
+        .route(
+            "/formations/candidate-tops",
+            get(v2_handlers::candidate_tops),
+        )
+        // Sidetrack / wellbore branch
+        .route("/sidetrack", get(v2_handlers::get_sidetrack))
+        .route("/sidetrack", post(v2_handlers::mark_sidetrack))
+        // MPD choke adjustments
+        .route(
+            "/mpd/choke-adjustment",
+            post(v2_handlers::declare_choke_adjustment),
+        )
+        // Sensor diagnostics
+        .route("/sensors/diagnostics", get(v2_handlers::sensor_diagnostics))
+        // Pipeline ingestion metrics
+        .route("/pipeline/metrics", get(v2_handlers::pipeline_metrics))
+        .route("/pipeline/stats", get(v2_handlers::pipeline_stats))
+        // Detection latency against injected faults
+        .route(
+            "/benchmark/detection",
+            get(v2_handlers::detection_benchmark),
+        )
         // Trip / swab-surge
         .route("/trip/swab-surge", get(v2_handlers::swab_surge_status))
+        // Connection practices
+        .route(
+            "/connections/scorecard",
+            get(v2_handlers::connection_scorecard),
+        )
+        // Drilling performance charts
+        .route("/charts/time-depth", get(v2_handlers::time_depth_chart))
+        .route("/charts/rop-depth", get(v2_handlers::rop_depth_chart))
+        .route("/logs/mse", get(v2_handlers::mse_log))
+        // CfC network health
+        .route("/cfc/health", get(v2_handlers::cfc_health))
         // Shift handover
         .route("/shift/handover", get(v2_handlers::shift_handover))
         // Shift
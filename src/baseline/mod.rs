@@ -9,6 +9,7 @@
 //! - `DynamicThresholds`: Per-metric thresholds learned from baseline data
 //! - `BaselineAccumulator`: Accumulates samples during learning phase
 //! - `ThresholdManager`: Manages thresholds for all equipment/sensors
+//! - `watchdog::LearningStallWatchdog`: Warns when learning stops progressing
 //!
 //! ## Key Features
 //!
@@ -46,6 +47,8 @@ use std::path::Path;
 use thiserror::Error;
 use tracing::{debug, info, warn};
 
+pub mod watchdog;
+
 // ============================================================================
 // Configuration Constants (defaults — overridden by well_config.toml)
 // ============================================================================
@@ -68,8 +71,14 @@ pub const MAX_OUTLIER_PERCENTAGE: f64 = 0.05; // 5%
 /// Sigma threshold for outlier detection during learning
 pub const OUTLIER_SIGMA_THRESHOLD: f64 = 3.0;
 
+/// Resolution-to-std ratio at or above which a channel is flagged as quantized
+pub const DEFAULT_QUANTIZATION_RATIO: f64 = 0.5;
+
+/// Deltas smaller than this are treated as repeated values, not a resolution step
+const RESOLUTION_EPSILON: f64 = 1e-9;
+
 /// Schema version for persistence compatibility
-pub const SCHEMA_VERSION: u32 = 3; // v3: formation-aware baselines
+pub const SCHEMA_VERSION: u32 = 4; // v4: optional in-progress accumulators
 
 // ============================================================================
 // Config-aware accessors (read from well_config.toml when available)
@@ -127,6 +136,38 @@ fn cfg_outlier_sigma() -> f64 {
     }
 }
 
+/// Returns `(quantization_ratio, widen_floor)`, or `None` when the check is disabled.
+fn cfg_quantization() -> Option<(f64, bool)> {
+    if crate::config::is_initialized() {
+        let bl = &crate::config::get().baseline_learning;
+        bl.quantization_check_enabled
+            .then_some((bl.quantization_ratio, bl.widen_quantized_std_floor))
+    } else {
+        Some((DEFAULT_QUANTIZATION_RATIO, true))
+    }
+}
+
+fn cfg_drift_mode() -> DriftMode {
+    if crate::config::is_initialized() {
+        let bl = &crate::config::get().baseline_learning;
+        DriftMode::from_alpha(bl.drift_alpha, bl.drift_widen_std)
+    } else {
+        DriftMode::Frozen
+    }
+}
+
+/// Classify a channel's resolution against its learned std dev.
+///
+/// Returns `(quantized, widened_std_floor)`.
+fn assess_quantization(resolution: Option<f64>, std_dev: f64) -> (bool, Option<f64>) {
+    let (Some(step), Some((ratio, widen))) = (resolution, cfg_quantization()) else {
+        return (false, None);
+    };
+    let quantized = step >= ratio * std_dev;
+    let floor = (quantized && widen).then_some(step);
+    (quantized, floor)
+}
+
 // ============================================================================
 // Baseline Overrides — sigma-derived thresholds for anomaly detection
 // ============================================================================
@@ -139,7 +180,7 @@ fn cfg_outlier_sigma() -> f64 {
 ///
 /// Each field is `Option<f64>` — `None` means the metric wasn't locked or the
 /// baseline data was insufficient to derive a meaningful threshold.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct BaselineOverrides {
     /// Flow imbalance warning: 3σ of flow_balance std (gpm).
     /// Uses 3σ for warning because kick detection must avoid false alarms.
@@ -154,6 +195,39 @@ pub struct BaselineOverrides {
     pub torque_critical_fraction: Option<f64>,
 }
 
+/// Sigma multipliers used to derive [`BaselineOverrides`].
+///
+/// Read from `[baseline_learning]`; a hot-reload that changes them
+/// re-derives the live overrides from the locked baselines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverrideSigmas {
+    /// Flow imbalance warning (σ of flow_balance)
+    pub flow_imbalance: f64,
+    /// SPP / torque warning
+    pub warning: f64,
+    /// SPP / torque critical
+    pub critical: f64,
+}
+
+impl OverrideSigmas {
+    pub fn from_config(cfg: &crate::config::BaselineLearningConfig) -> Self {
+        Self {
+            flow_imbalance: cfg.override_flow_imbalance_sigma,
+            warning: cfg.override_warning_sigma,
+            critical: cfg.override_critical_sigma,
+        }
+    }
+
+    /// Sigmas from the live config, or the defaults before it is loaded.
+    pub fn current() -> Self {
+        if crate::config::is_initialized() {
+            Self::from_config(&crate::config::get().baseline_learning)
+        } else {
+            Self::from_config(&crate::config::BaselineLearningConfig::default())
+        }
+    }
+}
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -240,6 +314,29 @@ fn make_composite_id(equipment_id: &str, sensor_id: &str) -> String {
     format!("{}:{}", equipment_id, sensor_id)
 }
 
+/// How a locked baseline follows slow changes in "normal" after lock
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum DriftMode {
+    /// Baseline stays exactly as learned
+    #[default]
+    Frozen,
+    /// Normal samples nudge the mean: `mean += alpha * (value - mean)`.
+    /// With `widen_std`, the std may grow (never shrink) the same way.
+    Ewma { alpha: f64, widen_std: bool },
+}
+
+impl DriftMode {
+    /// `Ewma` for a positive alpha, `Frozen` otherwise.
+    pub fn from_alpha(alpha: f64, widen_std: bool) -> Self {
+        if alpha > 0.0 && alpha.is_finite() {
+            Self::Ewma { alpha, widen_std }
+        } else {
+            Self::Frozen
+        }
+    }
+}
+
 /// Dynamic thresholds learned from baseline data for a single metric
 ///
 /// Each metric (e.g., "RIG:mse", "RIG:flow_balance", "RIG:torque")
@@ -282,6 +379,22 @@ pub struct DynamicThresholds {
 
     /// Maximum value seen during baseline
     pub max_value: f64,
+
+    /// Smallest non-zero step between consecutive learning samples
+    #[serde(default)]
+    pub resolution: Option<f64>,
+
+    /// Whether the channel looked heavily quantized at lock time
+    #[serde(default)]
+    pub quantized: bool,
+
+    /// Std floor raised to one resolution step for quantized channels
+    #[serde(default)]
+    pub quantized_std_floor: Option<f64>,
+
+    /// Post-lock drift of the baseline (frozen unless configured)
+    #[serde(default)]
+    pub drift: DriftMode,
 }
 
 impl DynamicThresholds {
@@ -300,6 +413,10 @@ impl DynamicThresholds {
             sample_count: 0,
             min_value: f64::MAX,
             max_value: f64::MIN,
+            resolution: None,
+            quantized: false,
+            quantized_std_floor: None,
+            drift: DriftMode::Frozen,
         }
     }
 
@@ -325,6 +442,7 @@ impl DynamicThresholds {
             return floor;
         }
         let min_std = (self.baseline_mean.abs() * floor).max(floor);
+        let min_std = min_std.max(self.quantized_std_floor.unwrap_or(0.0));
         self.baseline_std.max(min_std)
     }
 
@@ -382,6 +500,31 @@ impl DynamicThresholds {
         }
     }
 
+    /// Check a value and, if it is normal in both directions, let the
+    /// baseline drift toward it according to [`DriftMode`].
+    ///
+    /// Warning and critical samples never move the baseline, so an anomaly
+    /// cannot drag the mean toward itself.
+    pub fn observe(&mut self, value: f64) -> AnomalyCheckResult {
+        let result = self.check(value);
+        if value.is_finite() && result.z_score.abs() < self.warning_sigma {
+            self.apply_drift(value);
+        }
+        result
+    }
+
+    fn apply_drift(&mut self, value: f64) {
+        let DriftMode::Ewma { alpha, widen_std } = self.drift else {
+            return;
+        };
+        let deviation = value - self.baseline_mean;
+        self.baseline_mean += alpha * deviation;
+        if widen_std {
+            let variance = (1.0 - alpha) * self.baseline_std.powi(2) + alpha * deviation.powi(2);
+            self.baseline_std = self.baseline_std.max(variance.sqrt());
+        }
+    }
+
     /// Check if a value exceeds warning threshold
     pub fn is_warning(&self, value: f64) -> bool {
         self.z_score(value) >= self.warning_sigma
@@ -432,6 +575,14 @@ pub struct BaselineAccumulator {
 
     /// Timestamp when learning started
     pub started_at: u64,
+
+    /// Previous sample (for resolution tracking)
+    #[serde(default)]
+    pub last_value: Option<f64>,
+
+    /// Smallest non-zero delta between consecutive samples
+    #[serde(default)]
+    pub min_nonzero_delta: Option<f64>,
 }
 
 impl BaselineAccumulator {
@@ -448,6 +599,8 @@ impl BaselineAccumulator {
             max_value: f64::MIN,
             outlier_count: 0,
             started_at,
+            last_value: None,
+            min_nonzero_delta: None,
         }
     }
 
@@ -471,6 +624,16 @@ impl BaselineAccumulator {
         self.min_value = self.min_value.min(value);
         self.max_value = self.max_value.max(value);
 
+        // Track observed resolution (smallest non-zero step)
+        if let Some(prev) = self.last_value {
+            let step = (value - prev).abs();
+            if step > RESOLUTION_EPSILON {
+                self.min_nonzero_delta =
+                    Some(self.min_nonzero_delta.map_or(step, |d| d.min(step)));
+            }
+        }
+        self.last_value = Some(value);
+
         // Welford's online algorithm for mean and variance
         let delta = value - self.mean;
         self.mean += delta / self.count as f64;
@@ -521,6 +684,16 @@ impl BaselineAccumulator {
         }
     }
 
+    /// Observed value resolution (smallest non-zero step), if any
+    pub fn resolution(&self) -> Option<f64> {
+        self.min_nonzero_delta
+    }
+
+    /// Whether the channel currently looks heavily quantized
+    pub fn is_quantized(&self) -> bool {
+        assess_quantization(self.resolution(), self.std_dev()).0
+    }
+
     /// Check if baseline is contaminated
     pub fn is_contaminated(&self) -> bool {
         self.outlier_percentage() > cfg_max_outlier_pct()
@@ -531,6 +704,27 @@ impl BaselineAccumulator {
         self.count >= cfg_min_samples()
     }
 
+    /// Sanity-check Welford state restored from disk.
+    ///
+    /// A truncated or hand-edited entry must not seed the mean, so anything
+    /// that `add_sample` could never have produced is rejected.
+    pub fn is_consistent(&self) -> bool {
+        let finite = self.mean.is_finite()
+            && self.m2.is_finite()
+            && self.min_value.is_finite()
+            && self.max_value.is_finite();
+        let tolerance = 1e-9 * self.mean.abs().max(1.0);
+        self.count > 0
+            && !self.equipment_id.is_empty()
+            && !self.sensor_id.is_empty()
+            && finite
+            && self.m2 >= 0.0
+            && self.min_value <= self.max_value
+            && self.mean >= self.min_value - tolerance
+            && self.mean <= self.max_value + tolerance
+            && self.outlier_count <= self.count
+    }
+
     /// Finalize into DynamicThresholds
     ///
     /// Returns error if contaminated or insufficient samples.
@@ -556,6 +750,17 @@ impl BaselineAccumulator {
 
         // Compute std_dev before moving values
         let std_dev = self.std_dev();
+        let resolution = self.resolution();
+        let (quantized, quantized_std_floor) = assess_quantization(resolution, std_dev);
+        if quantized {
+            warn!(
+                metric = %self.composite_id,
+                resolution = ?resolution,
+                std = std_dev,
+                widened_floor = ?quantized_std_floor,
+                "Channel appears heavily quantized (coarse feed resolution)"
+            );
+        }
 
         Ok(DynamicThresholds {
             composite_id: self.composite_id,
@@ -570,6 +775,10 @@ impl BaselineAccumulator {
             sample_count: self.count,
             min_value: self.min_value,
             max_value: self.max_value,
+            resolution,
+            quantized,
+            quantized_std_floor,
+            drift: cfg_drift_mode(),
         })
     }
 
@@ -577,6 +786,8 @@ impl BaselineAccumulator {
     pub fn force_finalize(self, timestamp: u64) -> DynamicThresholds {
         let outlier_pct = self.outlier_percentage() * 100.0;
         let std_dev = self.std_dev();
+        let resolution = self.resolution();
+        let (quantized, quantized_std_floor) = assess_quantization(resolution, std_dev);
 
         warn!(
             metric = %self.composite_id,
@@ -597,6 +808,10 @@ impl BaselineAccumulator {
             sample_count: self.count,
             min_value: self.min_value,
             max_value: self.max_value,
+            resolution,
+            quantized,
+            quantized_std_floor,
+            drift: cfg_drift_mode(),
         }
     }
 }
@@ -632,6 +847,27 @@ pub enum LearningStatus {
     },
 }
 
+/// Feed resolution diagnostics for a single channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelResolution {
+    /// Metric identifier (e.g., "wob")
+    pub sensor_id: String,
+    /// Smallest non-zero step observed between samples
+    pub resolution: Option<f64>,
+    /// Learned (or in-progress) standard deviation
+    pub std_dev: f64,
+    /// Whether the channel appears heavily quantized
+    pub quantized: bool,
+    /// Widened std floor applied to the channel, if any
+    pub quantized_std_floor: Option<f64>,
+    /// Effective std used for z-scores (locked channels only)
+    pub effective_std: Option<f64>,
+    /// Whether the baseline is locked
+    pub locked: bool,
+    /// Samples observed
+    pub sample_count: usize,
+}
+
 // ============================================================================
 // Baseline State Persistence
 // ============================================================================
@@ -641,9 +877,9 @@ pub const DEFAULT_STATE_PATH: &str = "data/baseline_state.json";
 
 /// Serializable baseline state for crash-safe persistence.
 ///
-/// Only locked thresholds are persisted.  In-progress accumulators are
-/// intentionally excluded so that learning restarts cleanly after a reboot
-/// rather than resuming from potentially stale partial data.
+/// Locked thresholds are always persisted.  In-progress accumulators are
+/// only included when `persist_accumulators` is set (v4+); otherwise learning
+/// restarts cleanly after a reboot.
 #[derive(Serialize, Deserialize)]
 struct BaselineState {
     schema_version: u32,
@@ -654,6 +890,13 @@ struct BaselineState {
     /// Per-formation locked thresholds (v3+). Key: "equipment:metric:formation".
     #[serde(default)]
     formation_thresholds: HashMap<String, DynamicThresholds>,
+    /// Whether `accumulators` was written (v4+).
+    #[serde(default)]
+    persist_accumulators: bool,
+    /// Welford state for metrics still learning (v4+). Kept as raw JSON so a
+    /// single corrupt entry is dropped on load instead of failing the file.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    accumulators: HashMap<String, serde_json::Value>,
 }
 
 // ============================================================================
@@ -684,6 +927,10 @@ pub struct ThresholdManager {
 
     /// Per-formation accumulators for metrics still learning.
     formation_accumulators: HashMap<String, BaselineAccumulator>,
+
+    /// Persist in-progress accumulators so learning resumes after a restart.
+    #[serde(default)]
+    persist_accumulators: bool,
 }
 
 impl Default for ThresholdManager {
@@ -702,9 +949,20 @@ impl ThresholdManager {
             overrides: None,
             formation_thresholds: HashMap::new(),
             formation_accumulators: HashMap::new(),
+            persist_accumulators: false,
         }
     }
 
+    /// Enable or disable persisting in-progress accumulators on save.
+    pub fn set_persist_accumulators(&mut self, enabled: bool) {
+        self.persist_accumulators = enabled;
+    }
+
+    /// Whether in-progress accumulators are persisted on save.
+    pub fn persists_accumulators(&self) -> bool {
+        self.persist_accumulators
+    }
+
     /// Start learning baseline for a metric
     pub fn start_learning(&mut self, equipment_id: &str, sensor_id: &str, timestamp: u64) {
         let composite_id = format!("{}:{}", equipment_id, sensor_id);
@@ -857,6 +1115,32 @@ impl ThresholdManager {
         self.thresholds.get(&composite_id).map(|t| t.check(value))
     }
 
+    /// Check a value against a locked baseline and let the baseline drift
+    /// toward it when normal (see [`DynamicThresholds::observe`]).
+    ///
+    /// Returns None if baseline is not yet locked.
+    pub fn observe(
+        &mut self,
+        equipment_id: &str,
+        sensor_id: &str,
+        value: f64,
+    ) -> Option<AnomalyCheckResult> {
+        let composite_id = format!("{}:{}", equipment_id, sensor_id);
+        self.thresholds.get_mut(&composite_id).map(|t| t.observe(value))
+    }
+
+    /// Set the drift mode of every locked baseline (e.g. from config on
+    /// startup, for baselines locked before drift was configured).
+    pub fn set_drift_mode(&mut self, drift: DriftMode) {
+        for t in self
+            .thresholds
+            .values_mut()
+            .chain(self.formation_thresholds.values_mut())
+        {
+            t.drift = drift;
+        }
+    }
+
     /// Add a sample to both global and formation-specific accumulators.
     ///
     /// The global accumulator always receives the sample (via `add_sample()`).
@@ -951,6 +1235,56 @@ impl ThresholdManager {
         self.check_anomaly(equipment_id, sensor_id, value)
     }
 
+    /// Add a sample, tagged with the current formation when one is known.
+    ///
+    /// `None` behaves exactly like [`Self::add_sample`].
+    pub fn add_sample_in(
+        &mut self,
+        equipment_id: &str,
+        sensor_id: &str,
+        value: f64,
+        formation_name: Option<&str>,
+        timestamp: u64,
+    ) -> Option<bool> {
+        match formation_name {
+            Some(formation) => {
+                self.add_sample_with_formation(equipment_id, sensor_id, value, formation, timestamp)
+            }
+            None => self.add_sample(equipment_id, sensor_id, value, timestamp),
+        }
+    }
+
+    /// Check a value against the formation baseline when one is known and
+    /// locked, otherwise against the formation-agnostic baseline.
+    pub fn check_anomaly_in(
+        &self,
+        equipment_id: &str,
+        sensor_id: &str,
+        value: f64,
+        formation_name: Option<&str>,
+    ) -> Option<AnomalyCheckResult> {
+        match formation_name {
+            Some(formation) => {
+                self.check_anomaly_with_formation(equipment_id, sensor_id, value, formation)
+            }
+            None => self.check_anomaly(equipment_id, sensor_id, value),
+        }
+    }
+
+    /// Formations with at least one locked baseline for this equipment.
+    pub fn locked_formations(&self, equipment_id: &str) -> Vec<String> {
+        let prefix = format!("{}:", equipment_id);
+        let mut formations: Vec<String> = self
+            .formation_thresholds
+            .keys()
+            .filter_map(|key| key.strip_prefix(&prefix))
+            .filter_map(|rest| rest.split_once(':').map(|(_, fm)| fm.to_string()))
+            .collect();
+        formations.sort();
+        formations.dedup();
+        formations
+    }
+
     /// Get learning status for a metric
     pub fn get_status(&self, equipment_id: &str, sensor_id: &str) -> Option<LearningStatus> {
         let composite_id = format!("{}:{}", equipment_id, sensor_id);
@@ -1033,42 +1367,7 @@ impl ThresholdManager {
     /// Called after baselines lock to derive anomaly detection thresholds
     /// from the learned mean/std of each WITS metric.
     pub fn compute_overrides(&self, equipment_id: &str) -> BaselineOverrides {
-        let mut overrides = BaselineOverrides::default();
-
-        // Flow imbalance: 3σ of flow_balance std (absolute deviation from zero)
-        if let Some(t) = self.get_threshold(equipment_id, wits_metrics::FLOW_BALANCE) {
-            let sigma3 = 3.0 * t.effective_std();
-            if sigma3 > 0.0 {
-                overrides.flow_imbalance_warning_gpm = Some(sigma3);
-            }
-        }
-
-        // SPP deviation: 2σ for warning, 3σ for critical
-        if let Some(t) = self.get_threshold(equipment_id, wits_metrics::SPP) {
-            let sigma2 = 2.0 * t.effective_std();
-            let sigma3 = 3.0 * t.effective_std();
-            if sigma2 > 0.0 {
-                overrides.spp_deviation_warning_psi = Some(sigma2.max(30.0));
-                overrides.spp_deviation_critical_psi = Some(sigma3.max(50.0));
-            }
-        }
-
-        // Torque: fraction-based increase thresholds
-        // Anomaly detection checks torque_delta_percent (fractional change).
-        // We derive threshold from (std / mean), scaled by 2σ and 3σ.
-        if let Some(t) = self.get_threshold(equipment_id, wits_metrics::TORQUE) {
-            if t.baseline_mean > 0.0 {
-                let cv = (t.effective_std() / t.baseline_mean).clamp(0.0, 10.0);
-                let warn = (2.0 * cv).max(0.10); // Never below 10%
-                let crit = (3.0 * cv).max(0.20); // Never below 20%
-                if warn > 0.0 {
-                    overrides.torque_warning_fraction = Some(warn);
-                    overrides.torque_critical_fraction = Some(crit);
-                }
-            }
-        }
-
-        overrides
+        self.derive_overrides(equipment_id, None, OverrideSigmas::current())
     }
 
     /// Compute sigma-derived overrides using formation-specific baselines where
@@ -1081,41 +1380,74 @@ impl ThresholdManager {
         &self,
         equipment_id: &str,
         formation_name: &str,
+    ) -> BaselineOverrides {
+        self.derive_overrides(equipment_id, Some(formation_name), OverrideSigmas::current())
+    }
+
+    /// Overrides for every formation with a locked baseline, keyed by
+    /// formation name. Metrics not yet locked in a formation fall back to the
+    /// global baseline, as in [`Self::compute_overrides_with_formation`].
+    pub fn compute_overrides_by_formation(
+        &self,
+        equipment_id: &str,
+    ) -> HashMap<String, BaselineOverrides> {
+        self.locked_formations(equipment_id)
+            .into_iter()
+            .map(|formation| {
+                let overrides = self.compute_overrides_with_formation(equipment_id, &formation);
+                (formation, overrides)
+            })
+            .collect()
+    }
+
+    /// Derive overrides with explicit sigma multipliers.
+    ///
+    /// With a formation, formation-specific baselines are preferred and the
+    /// global baseline is the fallback.
+    pub fn derive_overrides(
+        &self,
+        equipment_id: &str,
+        formation_name: Option<&str>,
+        sigmas: OverrideSigmas,
     ) -> BaselineOverrides {
         let mut overrides = BaselineOverrides::default();
 
         // Helper: look up formation-specific threshold, fall back to global
         let get_t = |metric: &str| -> Option<&DynamicThresholds> {
-            let fkey = format!("{}:{}:{}", equipment_id, metric, formation_name);
-            self.formation_thresholds
-                .get(&fkey)
+            formation_name
+                .and_then(|formation| {
+                    let fkey = format!("{}:{}:{}", equipment_id, metric, formation);
+                    self.formation_thresholds.get(&fkey)
+                })
                 .or_else(|| self.get_threshold(equipment_id, metric))
         };
 
-        // Flow imbalance: 3σ of flow_balance std
+        // Flow imbalance: absolute deviation from zero of flow_balance
         if let Some(t) = get_t(wits_metrics::FLOW_BALANCE) {
-            let sigma3 = 3.0 * t.effective_std();
-            if sigma3 > 0.0 {
-                overrides.flow_imbalance_warning_gpm = Some(sigma3);
+            let gpm = sigmas.flow_imbalance * t.effective_std();
+            if gpm > 0.0 {
+                overrides.flow_imbalance_warning_gpm = Some(gpm);
             }
         }
 
-        // SPP deviation: 2σ for warning, 3σ for critical
+        // SPP deviation: warning and critical sigma
         if let Some(t) = get_t(wits_metrics::SPP) {
-            let sigma2 = 2.0 * t.effective_std();
-            let sigma3 = 3.0 * t.effective_std();
-            if sigma2 > 0.0 {
-                overrides.spp_deviation_warning_psi = Some(sigma2.max(30.0));
-                overrides.spp_deviation_critical_psi = Some(sigma3.max(50.0));
+            let warn = sigmas.warning * t.effective_std();
+            let crit = sigmas.critical * t.effective_std();
+            if warn > 0.0 {
+                overrides.spp_deviation_warning_psi = Some(warn.max(30.0));
+                overrides.spp_deviation_critical_psi = Some(crit.max(50.0));
             }
         }
 
         // Torque: fraction-based increase thresholds
+        // Anomaly detection checks torque_delta_percent (fractional change).
+        // We derive threshold from (std / mean), scaled by the sigmas.
         if let Some(t) = get_t(wits_metrics::TORQUE) {
             if t.baseline_mean > 0.0 {
                 let cv = (t.effective_std() / t.baseline_mean).clamp(0.0, 10.0);
-                let warn = (2.0 * cv).max(0.10);
-                let crit = (3.0 * cv).max(0.20);
+                let warn = (sigmas.warning * cv).max(0.10); // Never below 10%
+                let crit = (sigmas.critical * cv).max(0.20); // Never below 20%
                 if warn > 0.0 {
                     overrides.torque_warning_fraction = Some(warn);
                     overrides.torque_critical_fraction = Some(crit);
@@ -1142,15 +1474,24 @@ impl ThresholdManager {
 
     /// Save locked thresholds to a JSON file via [`BaselineState`].
     ///
-    /// Only locked thresholds are persisted; in-progress accumulators are
-    /// intentionally omitted so that learning restarts cleanly after a reboot.
+    /// In-progress accumulators are included only when persistence is enabled
+    /// with [`Self::set_persist_accumulators`]; otherwise learning restarts
+    /// cleanly after a reboot.
     /// Parent directories are created automatically if they do not exist.
     pub fn save_to_file(&self, path: &Path) -> Result<(), BaselineError> {
+        let mut accumulators = HashMap::new();
+        if self.persist_accumulators {
+            for (key, acc) in self.accumulators.iter().filter(|(_, a)| a.count > 0) {
+                accumulators.insert(key.clone(), serde_json::to_value(acc)?);
+            }
+        }
         let state = BaselineState {
             schema_version: SCHEMA_VERSION,
             thresholds: self.thresholds.clone(),
             overrides: self.overrides.clone(),
             formation_thresholds: self.formation_thresholds.clone(),
+            persist_accumulators: self.persist_accumulators,
+            accumulators,
         };
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -1160,6 +1501,7 @@ impl ThresholdManager {
         info!(
             path = %path.display(),
             locked = self.thresholds.len(),
+            learning = state.accumulators.len(),
             "Baseline state saved"
         );
         Ok(())
@@ -1168,9 +1510,12 @@ impl ThresholdManager {
     /// Load locked thresholds from a JSON file.
     ///
     /// Returns `None` when the file is missing, corrupt, or has an
-    /// incompatible schema version.  Accumulators are always empty on load
-    /// so learning restarts from scratch after a crash.
-    pub fn load_from_file(path: &Path) -> Option<Self> {
+    /// incompatible schema version.  Accumulators saved with persistence
+    /// enabled are resumed only when `resume_accumulators` is set (the
+    /// current `baseline_learning.persist_accumulators`); entries that fail
+    /// to parse or are internally inconsistent are dropped so those metrics
+    /// restart learning.
+    pub fn load_from_file(path: &Path, resume_accumulators: bool) -> Option<Self> {
         let json = match std::fs::read_to_string(path) {
             Ok(j) => j,
             Err(e) => {
@@ -1185,8 +1530,8 @@ impl ThresholdManager {
                 return None;
             }
         };
-        // Accept schema v2 (pre-formation) and v3 (formation-aware)
-        if state.schema_version != SCHEMA_VERSION && state.schema_version != 2 {
+        // Accept schema v2 (pre-formation), v3 (formation-aware) and v4
+        if !(2..=SCHEMA_VERSION).contains(&state.schema_version) {
             warn!(
                 file_version = state.schema_version,
                 expected = SCHEMA_VERSION,
@@ -1195,7 +1540,7 @@ impl ThresholdManager {
             return None;
         }
         if state.schema_version == 2 {
-            info!("Migrating baseline state from schema v2 → v3 (empty formation baselines)");
+            info!("Migrating baseline state from schema v2 → v4 (empty formation baselines)");
         }
         let locked = state.thresholds.len();
         let formation_locked = state.formation_thresholds.len();
@@ -1215,19 +1560,46 @@ impl ThresholdManager {
             })
             .collect();
 
+        let resume = resume_accumulators && state.persist_accumulators;
+        let mut accumulators = HashMap::new();
+        if resume {
+            for (key, value) in state.accumulators {
+                let mut acc: BaselineAccumulator = match serde_json::from_value(value) {
+                    Ok(a) => a,
+                    Err(e) => {
+                        warn!(metric = %key, error = %e, "Dropping unreadable baseline accumulator");
+                        continue;
+                    }
+                };
+                acc.composite_id = make_composite_id(&acc.equipment_id, &acc.sensor_id);
+                if !acc.is_consistent() || acc.composite_id != key {
+                    warn!(metric = %key, "Dropping inconsistent baseline accumulator");
+                    continue;
+                }
+                if thresholds.contains_key(&key) {
+                    continue;
+                }
+                accumulators.insert(key, acc);
+            }
+            if !accumulators.is_empty() {
+                info!(learning = accumulators.len(), "Resumed in-progress baseline learning");
+            }
+        }
+
         Some(Self {
             thresholds,
-            accumulators: HashMap::new(),
+            accumulators,
             schema_version: SCHEMA_VERSION,
             overrides: state.overrides,
             formation_thresholds: state.formation_thresholds,
             formation_accumulators: HashMap::new(),
+            persist_accumulators: resume,
         })
     }
 
     /// Load from file if it exists and is valid, otherwise create new.
-    pub fn load_or_new(path: &Path) -> Self {
-        Self::load_from_file(path).unwrap_or_else(|| {
+    pub fn load_or_new(path: &Path, resume_accumulators: bool) -> Self {
+        Self::load_from_file(path, resume_accumulators).unwrap_or_else(|| {
             debug!("No valid baseline state found, starting fresh");
             Self::new()
         })
@@ -1264,6 +1636,22 @@ pub mod wits_metrics {
     pub const PIT_VOLUME: &str = "pit_volume";
     /// Gas units (total gas)
     pub const GAS_UNITS: &str = "gas_units";
+
+    /// Every WITS metric learned for baselines
+    pub const ALL: [&str; 12] = [
+        MSE,
+        D_EXPONENT,
+        DXC,
+        FLOW_BALANCE,
+        SPP,
+        TORQUE,
+        ROP,
+        WOB,
+        RPM,
+        ECD,
+        PIT_VOLUME,
+        GAS_UNITS,
+    ];
 }
 
 /// Legacy TDS metric IDs (for backward compatibility)
@@ -1344,6 +1732,86 @@ impl ThresholdManager {
             .all(|sensor_id| self.is_locked(equipment_id, sensor_id))
     }
 
+    /// Lock progress across WITS metrics: `(locked, total, latest_lock_timestamp)`.
+    pub fn wits_lock_progress(&self, equipment_id: &str) -> (usize, usize, Option<u64>) {
+        let metrics = [
+            wits_metrics::MSE,
+            wits_metrics::D_EXPONENT,
+            wits_metrics::DXC,
+            wits_metrics::FLOW_BALANCE,
+            wits_metrics::SPP,
+            wits_metrics::TORQUE,
+            wits_metrics::ROP,
+            wits_metrics::WOB,
+            wits_metrics::RPM,
+            wits_metrics::ECD,
+            wits_metrics::PIT_VOLUME,
+            wits_metrics::GAS_UNITS,
+        ];
+
+        let locked: Vec<&DynamicThresholds> = metrics
+            .iter()
+            .filter_map(|sensor_id| self.get_threshold(equipment_id, sensor_id))
+            .filter(|t| t.locked)
+            .collect();
+        let latest = locked.iter().filter_map(|t| t.locked_timestamp).max();
+        (locked.len(), metrics.len(), latest)
+    }
+
+    /// Per-channel feed resolution diagnostics for all WITS metrics.
+    ///
+    /// Locked channels report the resolution captured at lock time; channels
+    /// still learning report the resolution observed so far.
+    pub fn resolution_diagnostics(&self, equipment_id: &str) -> Vec<ChannelResolution> {
+        let metrics = [
+            wits_metrics::MSE,
+            wits_metrics::D_EXPONENT,
+            wits_metrics::DXC,
+            wits_metrics::FLOW_BALANCE,
+            wits_metrics::SPP,
+            wits_metrics::TORQUE,
+            wits_metrics::ROP,
+            wits_metrics::WOB,
+            wits_metrics::RPM,
+            wits_metrics::ECD,
+            wits_metrics::PIT_VOLUME,
+            wits_metrics::GAS_UNITS,
+        ];
+
+        metrics
+            .iter()
+            .filter_map(|sensor_id| {
+                if let Some(t) = self.get_threshold(equipment_id, sensor_id) {
+                    Some(ChannelResolution {
+                        sensor_id: sensor_id.to_string(),
+                        resolution: t.resolution,
+                        std_dev: t.baseline_std,
+                        quantized: t.quantized,
+                        quantized_std_floor: t.quantized_std_floor,
+                        effective_std: Some(t.effective_std()),
+                        locked: true,
+                        sample_count: t.sample_count,
+                    })
+                } else {
+                    self.get_accumulator(equipment_id, sensor_id).map(|acc| {
+                        let (quantized, floor) =
+                            assess_quantization(acc.resolution(), acc.std_dev());
+                        ChannelResolution {
+                            sensor_id: sensor_id.to_string(),
+                            resolution: acc.resolution(),
+                            std_dev: acc.std_dev(),
+                            quantized,
+                            quantized_std_floor: floor,
+                            effective_std: None,
+                            locked: false,
+                            sample_count: acc.count,
+                        }
+                    })
+                }
+            })
+            .collect()
+    }
+
     /// Legacy: Add all standard TDS metrics for learning
     pub fn start_tds_learning(&mut self, equipment_id: &str, timestamp: u64) {
         self.start_learning(equipment_id, tds_metrics::VIBRATION_RMS, timestamp);
@@ -1436,6 +1904,10 @@ mod tests {
             sample_count: 100,
             min_value: 25000.0,
             max_value: 45000.0,
+            resolution: None,
+            quantized: false,
+            quantized_std_floor: None,
+            drift: DriftMode::Frozen,
         };
 
         // Normal value (z = 1)
@@ -1452,6 +1924,58 @@ mod tests {
         assert_eq!(result.level, AnomalyLevel::Critical);
     }
 
+    /// MSE baseline locked at 20 000 psi, std ≈ 290
+    fn locked_mse(drift: DriftMode) -> DynamicThresholds {
+        let mut acc = BaselineAccumulator::new("RIG", "mse", 0);
+        for i in 0..150 {
+            acc.add_sample(20_000.0 + (i % 10) as f64 * 100.0 - 450.0);
+        }
+        let mut t = acc.finalize(150).unwrap();
+        t.drift = drift;
+        t
+    }
+
+    #[test]
+    fn test_drifting_baseline_follows_slow_ramp() {
+        let drift = DriftMode::from_alpha(0.001, false);
+        let mut drifting = locked_mse(drift);
+        let frozen = locked_mse(DriftMode::Frozen);
+        let std = drifting.baseline_std;
+
+        // +3000 psi (~10σ) over 30 000 samples, e.g. a dulling bit
+        let mut frozen_flagged = false;
+        for i in 0..30_000 {
+            let noise = (i % 10) as f64 * 100.0 - 450.0;
+            let value = 20_000.0 + i as f64 * 0.1 + noise;
+            let result = drifting.observe(value);
+            assert_eq!(result.level, AnomalyLevel::Normal, "sample {i}: z {}", result.z_score);
+            frozen_flagged |= frozen.check(value).level != AnomalyLevel::Normal;
+        }
+        assert!(frozen_flagged, "frozen baseline over-triggers on the ramp");
+        assert!((drifting.baseline_mean - 23_000.0).abs() < 200.0, "{}", drifting.baseline_mean);
+        assert_eq!(drifting.baseline_std, std, "std held without widen_std");
+
+        // A sudden step is still critical
+        let step = drifting.baseline_mean + 8.0 * std;
+        assert_eq!(drifting.observe(step).level, AnomalyLevel::Critical);
+    }
+
+    #[test]
+    fn test_anomalous_samples_do_not_drag_drifting_baseline() {
+        let mut t = locked_mse(DriftMode::from_alpha(0.05, true));
+        let (mean, std) = (t.baseline_mean, t.baseline_std);
+        for _ in 0..100 {
+            assert_ne!(t.observe(mean + 4.0 * std).level, AnomalyLevel::Normal);
+            t.observe(mean - 4.0 * std);
+        }
+        assert_eq!((t.baseline_mean, t.baseline_std), (mean, std));
+
+        // Frozen baselines never move
+        let mut frozen = locked_mse(DriftMode::Frozen);
+        frozen.observe(mean + std);
+        assert_eq!(frozen.baseline_mean, mean);
+    }
+
     #[test]
     fn test_threshold_manager_workflow() {
         let mut manager = ThresholdManager::new();
@@ -1507,6 +2031,10 @@ mod tests {
             sample_count: 100,
             min_value: 0.0,
             max_value: 0.0,
+            resolution: None,
+            quantized: false,
+            quantized_std_floor: None,
+            drift: DriftMode::Frozen,
         };
 
         // Should use floor instead of zero
@@ -1674,6 +2202,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_two_formations_lock_independent_mse_means() {
+        let mut manager = ThresholdManager::new();
+        for i in 0..120u64 {
+            let step = (i % 10) as f64;
+            let mut feed = |metric, value, formation| {
+                manager.add_sample_in("RIG", metric, value, Some(formation), i);
+            };
+            feed(wits_metrics::MSE, 20_000.0 + step * 50.0, "Shale");
+            feed(wits_metrics::MSE, 60_000.0 + step * 150.0, "Chalk");
+            feed(wits_metrics::SPP, 2500.0 + step * 20.0, "Shale");
+            feed(wits_metrics::SPP, 2500.0 + step * 60.0, "Chalk");
+        }
+
+        let shale = &manager.formation_thresholds["RIG:mse:Shale"];
+        let chalk = &manager.formation_thresholds["RIG:mse:Chalk"];
+        assert!((shale.baseline_mean - 20_225.0).abs() < 1.0, "{}", shale.baseline_mean);
+        assert!((chalk.baseline_mean - 60_675.0).abs() < 1.0, "{}", chalk.baseline_mean);
+
+        // Chalk-normal MSE is critical against the shale baseline
+        let check = |formation| manager.check_anomaly_in("RIG", wits_metrics::MSE, 60_500.0, formation);
+        assert_eq!(check(Some("Shale")).unwrap().level, AnomalyLevel::Critical);
+        assert_eq!(check(Some("Chalk")).unwrap().level, AnomalyLevel::Normal);
+        // No formation: the mixed global baseline has not been locked
+        assert!(check(None).is_none());
+
+        assert_eq!(manager.locked_formations("RIG"), vec!["Chalk", "Shale"]);
+        let by_formation = manager.compute_overrides_by_formation("RIG");
+        let shale_spp = by_formation["Shale"].spp_deviation_warning_psi.unwrap();
+        let chalk_spp = by_formation["Chalk"].spp_deviation_warning_psi.unwrap();
+        assert!(chalk_spp > 2.5 * shale_spp, "shale {shale_spp}, chalk {chalk_spp}");
+    }
+
     #[test]
     fn test_compute_overrides_with_formation_falls_back_to_global() {
         let mut manager = ThresholdManager::new();
@@ -1727,7 +2288,7 @@ mod tests {
         let tmp = std::env::temp_dir().join("test_baseline_v2_migration.json");
         std::fs::write(&tmp, serde_json::to_string_pretty(&v2_state).unwrap()).unwrap();
 
-        let manager = ThresholdManager::load_from_file(&tmp);
+        let manager = ThresholdManager::load_from_file(&tmp, false);
         assert!(manager.is_some(), "Should load v2 state file");
         let manager = manager.unwrap();
         assert_eq!(manager.schema_version, SCHEMA_VERSION);
@@ -1739,4 +2300,91 @@ mod tests {
 
         std::fs::remove_file(&tmp).ok();
     }
+
+    #[test]
+    fn test_accumulators_resume_across_save_and_load() {
+        let value = |i: usize| 35000.0 + (i % 10) as f64 * 10.0;
+
+        let mut manager = ThresholdManager::new();
+        manager.set_persist_accumulators(true);
+        manager.start_learning("RIG", "mse", 0);
+        manager.start_learning("RIG", "torque", 0);
+        for i in 0..90 {
+            manager.add_sample("RIG", "mse", value(i), i as u64);
+        }
+
+        let tmp = std::env::temp_dir().join("test_baseline_accumulator_resume.json");
+        manager.save_to_file(&tmp).unwrap();
+
+        // Corrupt a second, hand-written entry: mean outside [min, max]
+        let mut json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&tmp).unwrap()).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        let mut bad = json["accumulators"]["RIG:mse"].clone();
+        bad["sensor_id"] = "spp".into();
+        bad["mean"] = 1.0e9.into();
+        json["accumulators"]["RIG:spp"] = bad;
+        std::fs::write(&tmp, serde_json::to_string(&json).unwrap()).unwrap();
+
+        // Persistence since switched off: the saved accumulators are ignored
+        let fresh = ThresholdManager::load_from_file(&tmp, false).expect("state loads");
+        assert!(!fresh.persists_accumulators());
+        assert_eq!(fresh.learning_count(), 0);
+
+        let mut loaded = ThresholdManager::load_from_file(&tmp, true).expect("state loads");
+        std::fs::remove_file(&tmp).ok();
+        assert!(loaded.persists_accumulators());
+        assert!(loaded.is_learning("RIG", "mse"));
+        assert!(!loaded.is_learning("RIG", "spp"), "corrupt entry dropped");
+        assert!(!loaded.is_learning("RIG", "torque"), "empty accumulator not saved");
+
+        for i in 90..105 {
+            loaded.add_sample("RIG", "mse", value(i), i as u64);
+        }
+        let expected_mean = (0..105).map(value).sum::<f64>() / 105.0;
+        let locked = loaded.lock_baseline("RIG", "mse", 2000).expect("lock succeeds");
+        assert_eq!(locked.sample_count, 105);
+        assert!((locked.baseline_mean - expected_mean).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quantized_channel_detected_and_floor_widened() {
+        let mut manager = ThresholdManager::new();
+
+        // WOB reported as whole klbs: a ±0.4 klb wobble around 25.5 only ever
+        // shows up as 25 or 26. ROP is a fine-grained continuous signal.
+        for i in 0..150 {
+            let t = i as f64;
+            let wob = (25.5 + 0.4 * (t * 0.7).sin()).round();
+            let rop = 60.0 + 5.0 * (t * 0.3).sin() + 0.013 * t;
+            manager.add_sample("RIG", wits_metrics::WOB, wob, i);
+            manager.add_sample("RIG", wits_metrics::ROP, rop, i);
+        }
+
+        let learning = manager.resolution_diagnostics("RIG");
+        let wob = learning
+            .iter()
+            .find(|c| c.sensor_id == wits_metrics::WOB)
+            .expect("wob diagnostics");
+        assert!(wob.quantized, "integer WOB should be flagged while learning");
+        assert!(!wob.locked);
+
+        manager.lock_baseline("RIG", wits_metrics::WOB, 1000).unwrap();
+        manager.lock_baseline("RIG", wits_metrics::ROP, 1000).unwrap();
+
+        let wob = manager.get_threshold("RIG", wits_metrics::WOB).unwrap();
+        assert_eq!(wob.resolution, Some(1.0));
+        assert!(wob.quantized);
+        assert!(wob.baseline_std < 1.0);
+        assert_eq!(wob.quantized_std_floor, Some(1.0));
+        assert!((wob.effective_std() - 1.0).abs() < 1e-9);
+
+        let rop = manager.get_threshold("RIG", wits_metrics::ROP).unwrap();
+        assert!(!rop.quantized, "continuous ROP should not be flagged");
+        assert!(rop.quantized_std_floor.is_none());
+
+        let diag = manager.resolution_diagnostics("RIG");
+        assert_eq!(diag.len(), 2);
+        assert!(diag.iter().all(|c| c.locked));
+    }
 }
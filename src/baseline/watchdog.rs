@@ -0,0 +1,255 @@
+//! Learning Stall Watchdog
+//!
+//! A rig that rarely drills (workover, P&A, long completions) may never feed
+//! enough drilling samples to lock its baselines, and sits in learning mode
+//! with no dynamic detection and no indication anything is wrong.
+//!
+//! `LearningStallWatchdog` tracks progress toward locking — new samples on an
+//! uncontaminated metric, or a new lock — and raises a warning once no
+//! progress has been made for the configured time. The warning lists each
+//! stuck WITS metric and why it is stuck, so the operator can either
+//! force-lock or lower `baseline_learning.min_samples_for_lock`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{wits_metrics, LearningStatus, ThresholdManager};
+
+/// Why a metric has not locked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StallReason {
+    /// Fewer samples than `min_samples_for_lock`
+    InsufficientSamples,
+    /// Too many outliers for a clean baseline
+    Contaminated,
+}
+
+/// A WITS metric stuck in learning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StalledMetric {
+    pub metric: String,
+    pub reason: StallReason,
+    pub samples_collected: usize,
+    pub samples_needed: usize,
+    /// Outlier share of collected samples (%)
+    pub outlier_percentage: f64,
+}
+
+/// Raised when baseline learning has made no progress for too long
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningStallEvent {
+    /// Unix timestamp the stall was detected
+    pub timestamp: u64,
+    /// Seconds since learning last made progress
+    pub stalled_secs: u64,
+    /// Metrics still unlocked, with the reason each is stuck
+    pub metrics: Vec<StalledMetric>,
+    /// Operator-facing warning text
+    pub message: String,
+}
+
+/// Detects baseline learning that has stopped progressing
+#[derive(Debug, Clone, Default)]
+pub struct LearningStallWatchdog {
+    /// Last packet timestamp at which learning progressed
+    last_progress_at: Option<u64>,
+    /// Samples per metric at the last update
+    sample_counts: HashMap<String, usize>,
+    /// Locked metrics at the last update
+    locked: usize,
+    /// Current stall, cleared when learning progresses again
+    active: Option<LearningStallEvent>,
+}
+
+impl LearningStallWatchdog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update with the manager's current state.
+    ///
+    /// Returns the stall event the first time learning has gone `stall_secs`
+    /// without progress. Any further progress clears the stall and re-arms.
+    pub fn update(
+        &mut self,
+        mgr: &ThresholdManager,
+        equipment_id: &str,
+        timestamp: u64,
+        stall_secs: u64,
+    ) -> Option<&LearningStallEvent> {
+        let mut progressed = false;
+        let mut locked = 0;
+        let mut stuck = Vec::new();
+
+        for metric in wits_metrics::ALL {
+            let (collected, needed, outlier_pct, reason) =
+                match mgr.get_status(equipment_id, metric) {
+                    Some(LearningStatus::Locked { .. }) => {
+                        locked += 1;
+                        continue;
+                    }
+                    Some(LearningStatus::Learning {
+                        samples_collected,
+                        samples_needed,
+                        outlier_percentage,
+                        ..
+                    }) => {
+                        let previous = self
+                            .sample_counts
+                            .insert(metric.to_string(), samples_collected);
+                        progressed |= samples_collected > previous.unwrap_or(0);
+                        if samples_collected >= samples_needed {
+                            // Enough samples; waiting on the lock trigger, not stuck
+                            continue;
+                        }
+                        (
+                            samples_collected,
+                            samples_needed,
+                            outlier_percentage,
+                            StallReason::InsufficientSamples,
+                        )
+                    }
+                    Some(LearningStatus::Contaminated {
+                        outlier_percentage,
+                        samples_collected,
+                    }) => {
+                        self.sample_counts
+                            .insert(metric.to_string(), samples_collected);
+                        (
+                            samples_collected,
+                            super::cfg_min_samples(),
+                            outlier_percentage,
+                            StallReason::Contaminated,
+                        )
+                    }
+                    None => continue,
+                };
+            stuck.push(StalledMetric {
+                metric: metric.to_string(),
+                reason,
+                samples_collected: collected,
+                samples_needed: needed,
+                outlier_percentage: outlier_pct,
+            });
+        }
+
+        progressed |= locked > self.locked;
+        self.locked = locked;
+
+        let since = *self.last_progress_at.get_or_insert(timestamp);
+        if progressed || stuck.is_empty() {
+            self.last_progress_at = Some(timestamp);
+            self.active = None;
+            return None;
+        }
+
+        let stalled_secs = timestamp.saturating_sub(since);
+        if self.active.is_some() || stalled_secs < stall_secs {
+            return None;
+        }
+
+        self.active = Some(LearningStallEvent {
+            timestamp,
+            stalled_secs,
+            message: stall_message(&stuck, stalled_secs),
+            metrics: stuck,
+        });
+        self.active.as_ref()
+    }
+
+    /// The current stall, if learning is stalled.
+    pub fn active(&self) -> Option<&LearningStallEvent> {
+        self.active.as_ref()
+    }
+}
+
+fn stall_message(stuck: &[StalledMetric], stalled_secs: u64) -> String {
+    let details: Vec<String> = stuck
+        .iter()
+        .map(|m| match m.reason {
+            StallReason::InsufficientSamples => format!(
+                "{} ({}/{} samples)",
+                m.metric, m.samples_collected, m.samples_needed
+            ),
+            StallReason::Contaminated => format!(
+                "{} (contaminated, {:.1}% outliers)",
+                m.metric, m.outlier_percentage
+            ),
+        })
+        .collect();
+    let advice = if stuck.iter().all(|m| m.reason == StallReason::Contaminated) {
+        "Force-lock the baselines or restart learning on cleaner data"
+    } else {
+        "Force-lock the baselines or lower baseline_learning.min_samples_for_lock"
+    };
+    format!(
+        "Baseline learning stalled for {:.1} h with no progress toward locking: {}. {}.",
+        stalled_secs as f64 / 3600.0,
+        details.join(", "),
+        advice
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stalled_learning_warns_with_stuck_metrics() {
+        let mut mgr = ThresholdManager::new();
+        mgr.start_wits_learning("RIG", 0);
+
+        // A short drilling burst: every metric collects 40 samples
+        for (i, ts) in (0..40u64).enumerate() {
+            for metric in wits_metrics::ALL {
+                mgr.add_sample("RIG", metric, 100.0 + (i % 2) as f64, ts);
+            }
+        }
+        // Gas units spike repeatedly: contaminated
+        for ts in 40..50u64 {
+            mgr.add_sample("RIG", wits_metrics::GAS_UNITS, 100.0 + ts as f64 * 50.0, ts);
+        }
+        assert!(matches!(
+            mgr.get_status("RIG", wits_metrics::GAS_UNITS),
+            Some(LearningStatus::Contaminated { .. })
+        ));
+
+        let stall_secs = 6 * 3600;
+        let mut watchdog = LearningStallWatchdog::new();
+        assert!(watchdog.update(&mgr, "RIG", 100, stall_secs).is_none());
+
+        // Rig stops drilling: sample counts stay where they are
+        for ts in (160..stall_secs + 100).step_by(60) {
+            assert!(watchdog.update(&mgr, "RIG", ts, stall_secs).is_none());
+        }
+        let event = watchdog
+            .update(&mgr, "RIG", stall_secs + 100, stall_secs)
+            .expect("stall detected")
+            .clone();
+        assert_eq!(event.stalled_secs, stall_secs);
+        assert_eq!(event.metrics.len(), wits_metrics::ALL.len());
+        for m in &event.metrics {
+            if m.metric == wits_metrics::GAS_UNITS {
+                assert_eq!(m.reason, StallReason::Contaminated);
+            } else {
+                assert_eq!(m.reason, StallReason::InsufficientSamples);
+                assert_eq!((m.samples_collected, m.samples_needed), (40, 100));
+            }
+        }
+        assert!(event.message.contains("mse (40/100 samples)"));
+        assert!(event.message.contains("gas_units (contaminated"));
+        assert!(event.message.contains("min_samples_for_lock"));
+
+        // Reported once; drilling resumes and the stall clears
+        assert!(watchdog
+            .update(&mgr, "RIG", stall_secs + 200, stall_secs)
+            .is_none());
+        assert!(watchdog.active().is_some());
+        mgr.add_sample("RIG", wits_metrics::MSE, 100.0, stall_secs + 300);
+        assert!(watchdog
+            .update(&mgr, "RIG", stall_secs + 300, stall_secs)
+            .is_none());
+        assert!(watchdog.active().is_none());
+    }
+}
@@ -434,6 +434,7 @@ impl SimulationState {
                 self.rng.gen_range(0.0..10.0)
             },
             annular_pressure: self.rng.gen_range(0.0..20.0),
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.0,
             mse: self.mse,
@@ -7,6 +7,9 @@
 //! Usage:
 //!   cargo run --bin volve-replay
 //!   cargo run --bin volve-replay -- --file data/volve/some_other_well.csv
+//!   cargo run --bin volve-replay -- --quality-filters   # interpolate short gaps
+//!   cargo run --bin volve-replay -- --depth-window 3200:3600   # replay one interval
+//!   cargo run --bin volve-replay -- --interpolate-gaps   # fill missing channel samples
 
 use sairen_os::aci::{self, ConformalInterval};
 use sairen_os::acquisition::wits_parser::{sanitize_packet, DepthContinuityTracker};
@@ -17,7 +20,7 @@ use sairen_os::strategic::templates::template_advisory;
 use sairen_os::types::{
     AnomalyCategory, Campaign, DrillingMetrics, HistoryEntry, RigState, VerificationStatus,
 };
-use sairen_os::volve::{VolveConfig, VolveReplay};
+use sairen_os::volve::{parse_depth_window, VolveConfig, VolveReplay};
 use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
 
@@ -126,6 +129,9 @@ fn main() {
     let args: Vec<String> = std::env::args().collect();
     let mut csv_path = "data/volve/Norway-NA-15_47_9-F-9 A time.csv".to_string();
     let mut json_summary = false;
+    let mut quality_filters = false;
+    let mut depth_window = None;
+    let mut interpolate_gaps = false;
 
     let mut i = 1;
     while i < args.len() {
@@ -137,6 +143,20 @@ fn main() {
                 }
             }
             "--json-summary" => json_summary = true,
+            "--quality-filters" => quality_filters = true,
+            "--interpolate-gaps" => interpolate_gaps = true,
+            "--depth-window" => {
+                i += 1;
+                if i < args.len() {
+                    match parse_depth_window(&args[i]) {
+                        Ok(w) => depth_window = Some(w),
+                        Err(e) => {
+                            eprintln!("ERROR: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
             _ => {}
         }
         i += 1;
@@ -170,6 +190,9 @@ fn main() {
     let volve_config = VolveConfig {
         skip_null_rows: true,
         nan_to_zero: true,
+        quality_filters,
+        depth_window,
+        interpolate_gaps,
         ..Default::default()
     };
 
@@ -420,7 +443,7 @@ fn main() {
                 AnomalyCategory::Mechanical => stats.mechanical_events += 1,
                 AnomalyCategory::Hydraulics => stats.hydraulics_events += 1,
                 AnomalyCategory::Formation => stats.formation_events += 1,
-                AnomalyCategory::None => {}
+                AnomalyCategory::Equipment | AnomalyCategory::None => {}
             }
 
             if stats.depth_at_first_ticket.is_none() {
@@ -1,17 +1,23 @@
 //! Causal Inference — lightweight Granger-causality on edge
 //!
 //! Detects which drilling parameters (WOB, RPM, torque, SPP, ROP) causally
-//! precede MSE spikes in the 60-packet history buffer.
+//! precede MSE spikes in the 60-packet history buffer. [`CausalTarget`]
+//! selects SPP (pack-off), flow balance (kick/loss), torque or ROP as the
+//! target instead; those targets also consider pump SPM and flow-in.
 //!
 //! ## Algorithm
 //!
-//! For each candidate parameter X and target series Y (MSE):
+//! For each candidate parameter X (other than the target and the series it is
+//! computed from) and target series Y:
 //!   - Compute cross-correlation at lags 1..=`MAX_LAG_SECS`
 //!   - Record the lag with highest |Pearson r|
 //!   - If |r| ≥ `MIN_CORRELATION`, emit a `CausalLead`
 //!
 //! Cross-correlation at lag k: r(X[0..n-k], Y[k..n])
-//! This answers: "does X at time t-k help predict MSE at time t?"
+//! This answers: "does X at time t-k help predict Y at time t?"
+//!
+//! [`detect_leads_partial`] additionally partials each candidate against the
+//! stronger leads, so collinear confounders (WOB moving with RPM) are dropped.
 //!
 //! Runs in < 1 ms on a 60-sample buffer — no external crates required.
 
@@ -29,40 +35,120 @@ const MIN_HISTORY: usize = 20;
 /// Maximum causal leads to return (sorted by |r| descending).
 const MAX_LEADS: usize = 3;
 
+/// Effect series a causal analysis explains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CausalTarget {
+    /// Mechanical specific energy (drilling efficiency)
+    #[default]
+    Mse,
+    /// Standpipe pressure (pack-off, washout)
+    Spp,
+    /// Flow out minus flow in (kick / loss)
+    FlowBalance,
+    /// Surface torque
+    Torque,
+    /// Rate of penetration
+    Rop,
+}
+
+impl CausalTarget {
+    /// Whether candidate `name` may lead this target. A target is never its
+    /// own lead, nor led by a series it is computed from.
+    fn is_candidate(self, name: &str) -> bool {
+        match self {
+            // The original MSE candidate set: WOB, RPM, torque, SPP, ROP
+            CausalTarget::Mse => !matches!(name, "Pump SPM" | "Flow In"),
+            CausalTarget::Spp => name != "SPP",
+            // Flow balance is flow out minus flow in
+            CausalTarget::FlowBalance => name != "Flow In",
+            CausalTarget::Torque => name != "Torque",
+            CausalTarget::Rop => name != "ROP",
+        }
+    }
+
+    fn series(self, history: &[HistoryEntry]) -> Vec<f64> {
+        history
+            .iter()
+            .map(|e| match self {
+                CausalTarget::Mse => e.metrics.mse,
+                CausalTarget::Spp => e.packet.spp,
+                CausalTarget::FlowBalance => e.metrics.flow_balance,
+                CausalTarget::Torque => e.packet.torque,
+                CausalTarget::Rop => e.packet.rop,
+            })
+            .collect()
+    }
+}
+
 /// Detect leading indicators for MSE spikes from the drilling history buffer.
 ///
-/// Returns up to [`MAX_LEADS`] parameters that most strongly precede MSE
-/// changes, sorted by correlation strength descending. Returns an empty
-/// `Vec` when the history buffer is too short to compute reliable statistics.
+/// Equivalent to [`detect_leads_for`] with [`CausalTarget::Mse`].
 pub fn detect_leads(history: &[HistoryEntry]) -> Vec<CausalLead> {
+    detect_leads_for(history, CausalTarget::Mse)
+}
+
+/// Detect leading indicators for changes in `target` from the history buffer.
+///
+/// Returns up to [`MAX_LEADS`] parameters that most strongly precede target
+/// changes, sorted by correlation strength descending. The target itself and
+/// the series it is computed from are never candidates. Returns an empty `Vec` when the history buffer is too
+/// short to compute reliable statistics.
+pub fn detect_leads_for(history: &[HistoryEntry], target: CausalTarget) -> Vec<CausalLead> {
+    rank_leads(history, target, false)
+}
+
+/// Like [`detect_leads_for`], but suppresses collinear confounders.
+///
+/// WOB and RPM often move together, so both correlate with MSE even when only
+/// one drives it. Candidates are visited strongest first and each is partialled
+/// against the leads already accepted (at their own lags); a series that only
+/// echoes a stronger lead falls below the reporting threshold. Each returned
+/// lead carries its `partial_r`.
+pub fn detect_leads_partial(history: &[HistoryEntry], target: CausalTarget) -> Vec<CausalLead> {
+    rank_leads(history, target, true)
+}
+
+fn rank_leads(history: &[HistoryEntry], target: CausalTarget, partial: bool) -> Vec<CausalLead> {
     if history.len() < MIN_HISTORY {
         return Vec::new();
     }
 
     let max_lag = MAX_LAG_SECS.min(history.len() / 3);
 
-    // Target series: MSE (the metric we want to predict)
-    let mse: Vec<f64> = history.iter().map(|e| e.metrics.mse).collect();
+    // Target series (the metric we want to predict)
+    let effect = target.series(history);
 
     // Candidate input series — each is a (name, values) pair
-    let candidates: [(&str, Vec<f64>); 5] = [
+    let candidates: [(&str, Vec<f64>); 7] = [
         ("WOB", history.iter().map(|e| e.packet.wob).collect()),
         ("RPM", history.iter().map(|e| e.packet.rpm).collect()),
         ("Torque", history.iter().map(|e| e.packet.torque).collect()),
         ("SPP", history.iter().map(|e| e.packet.spp).collect()),
         ("ROP", history.iter().map(|e| e.packet.rop).collect()),
+        (
+            "Pump SPM",
+            history.iter().map(|e| e.packet.pump_spm).collect(),
+        ),
+        (
+            "Flow In",
+            history.iter().map(|e| e.packet.flow_in).collect(),
+        ),
     ];
 
     let mut leads: Vec<CausalLead> = Vec::new();
 
     for (name, series) in &candidates {
-        let (best_lag, best_r) = best_lagged_correlation(series, &mse, max_lag);
+        if !target.is_candidate(name) {
+            continue;
+        }
+        let (best_lag, best_r) = best_lagged_correlation(series, &effect, max_lag);
         if best_r.abs() >= MIN_CORRELATION {
             leads.push(CausalLead {
                 parameter: name.to_string(),
                 lag_seconds: best_lag as u32,
                 pearson_r: best_r,
                 correlation_sign: if best_r > 0.0 { 1 } else { -1 },
+                partial_r: None,
             });
         }
     }
@@ -74,15 +160,100 @@ pub fn detect_leads(history: &[HistoryEntry]) -> Vec<CausalLead> {
             .partial_cmp(&a.pearson_r.abs())
             .unwrap_or(std::cmp::Ordering::Equal)
     });
+
+    if partial {
+        let series_of = |name: &str| {
+            candidates
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, s)| s.as_slice())
+                .unwrap_or_default()
+        };
+        let mut accepted: Vec<CausalLead> = Vec::new();
+        for mut lead in leads {
+            let controls: Vec<(&[f64], usize)> = accepted
+                .iter()
+                .map(|a| (series_of(&a.parameter), a.lag_seconds as usize))
+                .collect();
+            let r = partial_lagged_correlation(
+                series_of(&lead.parameter),
+                lead.lag_seconds as usize,
+                &effect,
+                &controls,
+            );
+            if r.abs() >= MIN_CORRELATION {
+                lead.partial_r = Some(r);
+                accepted.push(lead);
+            }
+        }
+        leads = accepted;
+    }
+
     leads.truncate(MAX_LEADS);
     leads
 }
 
-/// Find the lag (1..=`max_lag`) that maximises |Pearson r| between `x` and `mse`.
+/// Partial correlation of `x` (at `lag`) with `y`, controlling for each
+/// `(series, lag)` in `controls`.
+///
+/// All series are aligned on the target index and the controls are
+/// Gram-Schmidt orthogonalized, so `x` and `y` can be residualized one
+/// control at a time without a matrix inverse. A control that duplicates an
+/// earlier one contributes nothing; an `x` fully explained by the controls
+/// gives 0.0.
+fn partial_lagged_correlation(
+    x: &[f64],
+    lag: usize,
+    y: &[f64],
+    controls: &[(&[f64], usize)],
+) -> f64 {
+    let n = y.len();
+    let start = controls.iter().map(|(_, l)| *l).fold(lag, usize::max);
+    if start >= n || x.len() < n || controls.iter().any(|(s, _)| s.len() < n) {
+        return 0.0;
+    }
+
+    let centered = |v: Vec<f64>| {
+        let mean = v.iter().sum::<f64>() / v.len() as f64;
+        v.into_iter().map(|e| e - mean).collect::<Vec<f64>>()
+    };
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(p, q)| p * q).sum::<f64>();
+    let remove = |v: &mut Vec<f64>, unit: &[f64]| {
+        let proj = dot(v, unit);
+        v.iter_mut().zip(unit).for_each(|(e, u)| *e -= proj * u);
+    };
+
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    for (series, control_lag) in controls {
+        let mut z = centered((start..n).map(|t| series[t - control_lag]).collect());
+        for unit in &basis {
+            remove(&mut z, unit);
+        }
+        let norm = dot(&z, &z).sqrt();
+        if norm > 1e-10 {
+            basis.push(z.into_iter().map(|e| e / norm).collect());
+        }
+    }
+
+    let mut x_res = centered((start..n).map(|t| x[t - lag]).collect());
+    let mut y_res = centered(y[start..].to_vec());
+    let x_norm = dot(&x_res, &x_res).sqrt();
+    for unit in &basis {
+        remove(&mut x_res, unit);
+        remove(&mut y_res, unit);
+    }
+    // Nothing left of x once the controls are removed (rounding noise only)
+    if dot(&x_res, &x_res).sqrt() <= x_norm * 1e-9 {
+        return 0.0;
+    }
+    pearson_r(&x_res, &y_res)
+}
+
+/// Find the lag (1..=`max_lag`) that maximises |Pearson r| between `x` and `y`.
 ///
 /// Returns `(best_lag, best_r)`. If `max_lag` is 0 or `x` is too short, returns
 /// `(0, 0.0)`.
-fn best_lagged_correlation(x: &[f64], mse: &[f64], max_lag: usize) -> (usize, f64) {
+fn best_lagged_correlation(x: &[f64], y: &[f64], max_lag: usize) -> (usize, f64) {
     let mut best_lag = 0usize;
     let mut best_r = 0.0f64;
 
@@ -91,9 +262,9 @@ fn best_lagged_correlation(x: &[f64], mse: &[f64], max_lag: usize) -> (usize, f6
             break;
         }
         // cause = x[0..n-lag]  (what happened lag seconds ago)
-        // effect = mse[lag..n]  (what MSE is now)
+        // effect = y[lag..n]  (what the target is now)
         let cause = &x[..x.len() - lag];
-        let effect = &mse[lag..];
+        let effect = &y[lag..];
         let r = pearson_r(cause, effect);
         if r.abs() > best_r.abs() {
             best_r = r;
@@ -221,6 +392,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_leads_for_spp_surfaces_pump_leads() {
+        // Pumps brought up at packet 20; SPP builds 10 packets later
+        let entries: Vec<HistoryEntry> = (0..60)
+            .map(|i| {
+                let spp = if i < 30 { 2000.0 } else { 2800.0 };
+                let mut entry = make_entry(25.0, 120.0, 15.0, spp, 50.0, 30_000.0);
+                if i >= 20 {
+                    entry.packet.pump_spm = 140.0;
+                    entry.packet.flow_in = 580.0;
+                }
+                entry
+            })
+            .collect();
+        let leads = detect_leads_for(&entries, CausalTarget::Spp);
+        let params: Vec<&str> = leads.iter().map(|l| l.parameter.as_str()).collect();
+        assert!(params.contains(&"Pump SPM"), "leads: {params:?}");
+        assert!(params.contains(&"Flow In"), "leads: {params:?}");
+        assert!(leads.iter().all(|l| l.pearson_r > 0.0 && l.lag_seconds > 0));
+    }
+
+    #[test]
+    fn detect_leads_for_never_returns_target_as_cause() {
+        // Every series ramps together, so each would otherwise lead itself
+        let entries: Vec<HistoryEntry> = (0..60)
+            .map(|i| {
+                let v = i as f64;
+                make_entry(v, v * 5.0, v * 0.5, v * 20.0, v, v * 500.0)
+            })
+            .collect();
+        for (target, name) in [
+            (CausalTarget::Spp, "SPP"),
+            (CausalTarget::Torque, "Torque"),
+            (CausalTarget::Rop, "ROP"),
+        ] {
+            let leads = detect_leads_for(&entries, target);
+            assert!(!leads.is_empty());
+            assert!(
+                leads.iter().all(|l| l.parameter != name),
+                "{name} reported as its own lead"
+            );
+        }
+        // Flow balance is not led by its own flow-in term
+        let mut flow = entries.clone();
+        for (i, e) in flow.iter_mut().enumerate() {
+            e.packet.flow_in = i as f64 * 10.0;
+            e.metrics.flow_balance = if i < 30 { 0.0 } else { -(i as f64) };
+        }
+        assert!(detect_leads_for(&flow, CausalTarget::FlowBalance)
+            .iter()
+            .all(|l| l.parameter != "Flow In"));
+
+        // MSE keeps its original candidates, without the pump series
+        let mut pumps = entries.clone();
+        for (i, e) in pumps.iter_mut().enumerate() {
+            e.packet.pump_spm = i as f64;
+            e.packet.flow_in = i as f64 * 4.0;
+        }
+        assert!(detect_leads_for(&pumps, CausalTarget::Mse)
+            .iter()
+            .all(|l| !matches!(l.parameter.as_str(), "Pump SPM" | "Flow In")));
+
+        // Default wrapper is the MSE target
+        let names = |leads: Vec<CausalLead>| -> Vec<String> {
+            leads.into_iter().map(|l| l.parameter).collect()
+        };
+        assert_eq!(
+            names(detect_leads(&entries)),
+            names(detect_leads_for(&entries, CausalTarget::Mse))
+        );
+    }
+
+    #[test]
+    fn detect_leads_partial_collapses_collinear_inputs() {
+        // WOB drives MSE; RPM is locked to WOB (perfectly collinear)
+        let entries: Vec<HistoryEntry> = (0..60)
+            .map(|i| {
+                let wob = if i < 20 { 20.0 } else { 30.0 };
+                let mse = if i < 30 { 20_000.0 } else { 40_000.0 };
+                make_entry(wob, wob * 5.0, 15.0, 2800.0, 50.0, mse)
+            })
+            .collect();
+
+        let raw = detect_leads_for(&entries, CausalTarget::Mse);
+        let raw_params: Vec<&str> = raw.iter().map(|l| l.parameter.as_str()).collect();
+        assert!(raw_params.contains(&"WOB") && raw_params.contains(&"RPM"));
+        assert!(raw.iter().all(|l| l.partial_r.is_none()));
+
+        let partial = detect_leads_partial(&entries, CausalTarget::Mse);
+        assert_eq!(
+            partial.len(),
+            1,
+            "collinear pair should collapse: {partial:?}"
+        );
+        assert!(partial[0].parameter == "WOB" || partial[0].parameter == "RPM");
+        let r = partial[0].partial_r.expect("partial_r populated");
+        assert!(
+            (r - partial[0].pearson_r).abs() < 1e-9,
+            "first lead has no controls"
+        );
+    }
+
+    #[test]
+    fn partial_correlation_removes_shared_driver() {
+        // y follows z; x is z plus independent noise → partial r(x, y | z) ≈ 0
+        let z: Vec<f64> = (0..40).map(|i| (i as f64 * 0.7).sin() * 10.0).collect();
+        let noise: Vec<f64> = (0..40).map(|i| (i as f64 * 2.3).cos()).collect();
+        let x: Vec<f64> = z.iter().zip(&noise).map(|(a, b)| a + b).collect();
+        let y: Vec<f64> = std::iter::once(0.0)
+            .chain(z.iter().copied())
+            .take(40)
+            .collect();
+
+        let raw = pearson_r(&x[..39], &y[1..]);
+        assert!(raw > 0.9, "raw r {raw}");
+        let partial = partial_lagged_correlation(&x, 1, &y, &[(&z, 1)]);
+        assert!(partial.abs() < 0.1, "partial r {partial}");
+    }
+
     // ── helpers ──────────────────────────────────────────────────────────────
 
     fn make_entry(wob: f64, rpm: f64, torque: f64, spp: f64, rop: f64, mse: f64) -> HistoryEntry {
@@ -254,6 +544,7 @@ mod tests {
             co2: 0.1,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.0,
             mse,
@@ -0,0 +1,295 @@
+//! Candidate Formation Tops
+//!
+//! Accumulates CfC formation transition events into candidate formation tops
+//! for real-time geosteering. Transitions firing at nearly the same depth are
+//! clustered into one candidate; repeated, broad (many-feature) detections
+//! earn high confidence while an isolated narrow detection stays low.
+//!
+//! Candidates can be matched against the prognosis tops to report the
+//! picked-vs-predicted depth error.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::CandidateTopsConfig;
+use crate::types::{FormationInterval, FormationTransitionEvent};
+
+/// Detections at which persistence stops adding confidence.
+const FULL_PERSISTENCE_DETECTIONS: f64 = 3.0;
+
+/// Surprised-feature count at which breadth stops adding confidence.
+const FULL_BREADTH_FEATURES: f64 = 6.0;
+
+/// Confidence weight of persistence (repeat detections); breadth gets the rest.
+const PERSISTENCE_WEIGHT: f64 = 0.6;
+
+/// A candidate formation top built from one or more detected transitions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateTop {
+    /// Mean depth of the clustered detections (ft)
+    pub depth_ft: f64,
+    /// Unix timestamp of the first detection
+    pub first_detected: u64,
+    /// Number of transitions clustered into this candidate
+    pub detections: usize,
+    /// 0.0-1.0 from detection persistence and feature breadth
+    pub confidence: f64,
+    /// Features that drove the detections, most frequent first
+    pub driving_features: Vec<String>,
+    /// Nearest prognosis top within the match tolerance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_formation: Option<String>,
+    /// Prognosis depth of the matched top (ft)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predicted_depth_ft: Option<f64>,
+    /// Picked minus predicted depth (ft); positive = came in deep
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_error_ft: Option<f64>,
+}
+
+/// Clustered detections backing one candidate
+#[derive(Debug, Clone)]
+struct Cluster {
+    depth_sum: f64,
+    first_detected: u64,
+    detections: usize,
+    feature_total: usize,
+    /// (feature, hits)
+    features: Vec<(String, usize)>,
+}
+
+impl Cluster {
+    fn depth(&self) -> f64 {
+        self.depth_sum / self.detections as f64
+    }
+
+    fn add(&mut self, event: &FormationTransitionEvent) {
+        self.depth_sum += event.bit_depth;
+        self.detections += 1;
+        self.feature_total += event.surprised_features.len();
+        for name in &event.surprised_features {
+            match self.features.iter_mut().find(|(n, _)| n == name) {
+                Some((_, hits)) => *hits += 1,
+                None => self.features.push((name.clone(), 1)),
+            }
+        }
+    }
+
+    fn confidence(&self) -> f64 {
+        let persistence = (self.detections as f64 / FULL_PERSISTENCE_DETECTIONS).min(1.0);
+        let avg_features = self.feature_total as f64 / self.detections as f64;
+        let breadth = (avg_features / FULL_BREADTH_FEATURES).min(1.0);
+        PERSISTENCE_WEIGHT * persistence + (1.0 - PERSISTENCE_WEIGHT) * breadth
+    }
+}
+
+/// Accumulates formation transitions into candidate tops
+#[derive(Debug, Clone, Default)]
+pub struct CandidateTopTracker {
+    clusters: Vec<Cluster>,
+}
+
+impl CandidateTopTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a detected formation transition.
+    pub fn record(&mut self, event: &FormationTransitionEvent, cfg: &CandidateTopsConfig) {
+        if !cfg.enabled || event.bit_depth <= 0.0 {
+            return;
+        }
+
+        let nearest = self
+            .clusters
+            .iter_mut()
+            .map(|c| ((c.depth() - event.bit_depth).abs(), c))
+            .filter(|(dist, _)| *dist <= cfg.cluster_window_ft)
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        match nearest {
+            Some((_, cluster)) => cluster.add(event),
+            None => {
+                let mut cluster = Cluster {
+                    depth_sum: 0.0,
+                    first_detected: event.timestamp,
+                    detections: 0,
+                    feature_total: 0,
+                    features: Vec::new(),
+                };
+                cluster.add(event);
+                self.clusters.push(cluster);
+                if self.clusters.len() > cfg.max_candidates.max(1) {
+                    self.clusters.remove(0);
+                }
+            }
+        }
+    }
+
+    /// Candidate tops ordered by depth, matched against the prognosis
+    /// formations when given and `match_prognosis` is enabled.
+    pub fn candidates(
+        &self,
+        prognosis: Option<&[FormationInterval]>,
+        cfg: &CandidateTopsConfig,
+    ) -> Vec<CandidateTop> {
+        let mut tops: Vec<CandidateTop> = self
+            .clusters
+            .iter()
+            .map(|c| {
+                let mut features = c.features.clone();
+                features.sort_by(|a, b| b.1.cmp(&a.1));
+                let depth_ft = c.depth();
+
+                let matched = prognosis
+                    .filter(|_| cfg.match_prognosis)
+                    .and_then(|formations| {
+                        nearest_top(formations, depth_ft, cfg.match_tolerance_ft)
+                    });
+
+                CandidateTop {
+                    depth_ft,
+                    first_detected: c.first_detected,
+                    detections: c.detections,
+                    confidence: c.confidence(),
+                    driving_features: features.into_iter().map(|(n, _)| n).collect(),
+                    matched_formation: matched.map(|f| f.name.clone()),
+                    predicted_depth_ft: matched.map(|f| f.depth_top_ft),
+                    depth_error_ft: matched.map(|f| depth_ft - f.depth_top_ft),
+                }
+            })
+            .collect();
+        tops.sort_by(|a, b| {
+            a.depth_ft
+                .partial_cmp(&b.depth_ft)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        tops
+    }
+}
+
+/// Prognosis formation whose top is nearest `depth_ft`, within `tolerance_ft`.
+fn nearest_top(
+    formations: &[FormationInterval],
+    depth_ft: f64,
+    tolerance_ft: f64,
+) -> Option<&FormationInterval> {
+    formations
+        .iter()
+        // A top at surface is the start of the prognosis, not a boundary
+        .filter(|f| f.depth_top_ft > 0.0)
+        .map(|f| ((f.depth_top_ft - depth_ft).abs(), f))
+        .filter(|(dist, _)| *dist <= tolerance_ft)
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, f)| f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BestParams, FormationParameters, OffsetPerformance, ParameterRange};
+
+    fn event(ts: u64, depth: f64, features: &[&str]) -> FormationTransitionEvent {
+        FormationTransitionEvent {
+            timestamp: ts,
+            bit_depth: depth,
+            surprised_features: features.iter().map(|s| s.to_string()).collect(),
+            packet_index: ts,
+        }
+    }
+
+    fn formation(name: &str, top: f64, base: f64) -> FormationInterval {
+        let range = ParameterRange {
+            min: 1.0,
+            optimal: 2.0,
+            max: 3.0,
+        };
+        FormationInterval {
+            name: name.to_string(),
+            depth_top_ft: top,
+            depth_base_ft: base,
+            lithology: "Shale".to_string(),
+            hardness: 4.0,
+            drillability: "Medium".to_string(),
+            pore_pressure_ppg: 9.0,
+            fracture_gradient_ppg: 14.0,
+            hazards: Vec::new(),
+            parameters: FormationParameters {
+                wob_klbs: range.clone(),
+                rpm: range.clone(),
+                flow_gpm: range,
+                mud_weight_ppg: 9.5,
+                bit_type: "PDC".to_string(),
+            },
+            offset_performance: OffsetPerformance {
+                wells: Vec::new(),
+                avg_rop_ft_hr: 50.0,
+                best_rop_ft_hr: 80.0,
+                avg_mse_psi: 15000.0,
+                best_params: BestParams {
+                    wob_klbs: 20.0,
+                    rpm: 120.0,
+                },
+                notes: String::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_transitions_near_prognosis_tops_match_with_small_error() {
+        let cfg = CandidateTopsConfig::default();
+        let mut tracker = CandidateTopTracker::new();
+        let broad = ["mse", "d_exp", "rop", "torque", "wob"];
+
+        // Repeated broad detections around the 5000 ft top
+        tracker.record(&event(100, 5010.0, &broad), &cfg);
+        tracker.record(&event(160, 5018.0, &broad), &cfg);
+        tracker.record(&event(220, 5020.0, &broad[..4]), &cfg);
+        // ...and the 8000 ft top
+        tracker.record(&event(900, 7990.0, &broad), &cfg);
+        tracker.record(&event(960, 7996.0, &broad), &cfg);
+        tracker.record(&event(1020, 8002.0, &broad), &cfg);
+        // Isolated, narrow detection mid-formation
+        tracker.record(
+            &event(600, 6500.0, &["gas_units", "pit_volume", "ecd"]),
+            &cfg,
+        );
+
+        let prognosis = vec![
+            formation("Overburden", 0.0, 5000.0),
+            formation("Chalk", 5000.0, 8000.0),
+            formation("Reservoir", 8000.0, 10000.0),
+        ];
+        let tops = tracker.candidates(Some(&prognosis), &cfg);
+        assert_eq!(tops.len(), 3);
+
+        let chalk = &tops[0];
+        assert_eq!(chalk.detections, 3);
+        assert_eq!(chalk.matched_formation.as_deref(), Some("Chalk"));
+        assert!(chalk.depth_error_ft.unwrap().abs() < 20.0);
+        assert!(chalk.confidence > 0.8);
+        assert_eq!(chalk.driving_features[0], "mse");
+
+        let reservoir = &tops[2];
+        assert_eq!(reservoir.matched_formation.as_deref(), Some("Reservoir"));
+        assert!(reservoir.depth_error_ft.unwrap().abs() < 10.0);
+
+        let spurious = &tops[1];
+        assert_eq!(spurious.detections, 1);
+        assert!(spurious.matched_formation.is_none());
+        assert!(spurious.confidence < 0.5);
+    }
+
+    #[test]
+    fn test_matching_can_be_disabled() {
+        let cfg = CandidateTopsConfig {
+            match_prognosis: false,
+            ..CandidateTopsConfig::default()
+        };
+        let mut tracker = CandidateTopTracker::new();
+        tracker.record(&event(1, 5000.0, &["mse", "rop", "wob"]), &cfg);
+
+        let prognosis = vec![formation("Chalk", 5000.0, 8000.0)];
+        let tops = tracker.candidates(Some(&prognosis), &cfg);
+        assert!(tops[0].matched_formation.is_none());
+    }
+}
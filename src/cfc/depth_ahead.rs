@@ -7,12 +7,12 @@
 //! ## Feature mapping
 //!
 //! Slots 0-7 map to depth-ahead features (2x training weight via existing
-//! FEATURE_WEIGHTS). Slots 8-15 are zero-filled — the network trivially
+//! DEFAULT_FEATURE_WEIGHTS). Slots 8-15 are zero-filled — the network trivially
 //! learns to predict zero for those outputs.
 
 use crate::cfc::network::{CfcNetwork, CfcNetworkConfig};
 use crate::cfc::normalizer::NUM_FEATURES;
-use crate::cfc::training::TrainingConfig;
+use crate::cfc::training::{TrainingConfig, DEFAULT_FEATURE_WEIGHTS};
 use crate::cfc::wiring::NcpConfig;
 use crate::types::{DrillingMetrics, WitsPacket};
 
@@ -68,6 +68,7 @@ impl DepthAheadNetwork {
                 initial_lr: 0.0005,
                 lr_decay: 0.9999,
                 lr_floor: 0.00005,
+                feature_weights: DEFAULT_FEATURE_WEIGHTS,
             },
             error_ema_alpha: 0.008,
             calibration_window: 500,
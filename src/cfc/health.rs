@@ -0,0 +1,307 @@
+//! CfC Network Health / Drift Monitoring
+//!
+//! Online training keeps the CfC networks adapting for the whole well, which
+//! also lets them drift into degenerate states that look like normal output:
+//!
+//! - **Loss diverging**: the recent mean training loss has grown past
+//!   `loss_divergence_ratio` × the best window mean seen, or gone non-finite.
+//! - **Score saturated**: at least `saturation_fraction` of the recent
+//!   anomaly scores are at or above `saturation_score` — everything looks
+//!   anomalous, so the score carries no information.
+//! - **Score stuck**: the score has effectively stopped moving
+//!   (std below `stuck_score_std`) at either extreme.
+//!
+//! `CfcHealthMonitor` watches both networks over a rolling window of
+//! `window_packets` calibrated packets and reports which are degraded
+//! (`GET /api/v2/cfc/health`). With `auto_reset`, the tactical agent
+//! re-initialises a degraded network so it can recalibrate.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+use crate::config::CfcHealthConfig;
+
+/// Health of one CfC network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkHealthStatus {
+    /// Not yet calibrated, or too few calibrated packets to judge
+    Warming,
+    Healthy,
+    Degraded,
+}
+
+/// Why a network was judged degraded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DegradationReason {
+    LossDiverging,
+    LossNonFinite,
+    ScoreSaturated,
+    ScoreStuck,
+}
+
+/// Health snapshot for one network
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkHealth {
+    pub status: NetworkHealthStatus,
+    pub reasons: Vec<DegradationReason>,
+    pub is_calibrated: bool,
+    /// Calibrated packets in the window
+    pub window_packets: usize,
+    /// Mean training loss over the window
+    pub recent_loss: f64,
+    /// Lowest window mean loss seen since the last reset
+    pub best_loss: Option<f64>,
+    /// recent_loss / best_loss
+    pub loss_ratio: Option<f64>,
+    pub score_mean: f64,
+    pub score_std: f64,
+    /// Share of window scores at or above `saturation_score`
+    pub saturated_fraction: f64,
+    /// Times this network has been auto-reset
+    pub resets: u32,
+}
+
+/// Rolling health monitor for one network
+#[derive(Debug, Clone, Default)]
+pub struct NetworkHealthMonitor {
+    losses: VecDeque<f64>,
+    scores: VecDeque<f64>,
+    best_loss: Option<f64>,
+    non_finite_loss: bool,
+    is_calibrated: bool,
+    resets: u32,
+}
+
+fn mean_std(values: &VecDeque<f64>) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+impl NetworkHealthMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Observe one packet's output from the network.
+    pub fn observe(
+        &mut self,
+        anomaly_score: f64,
+        training_loss: Option<f64>,
+        is_calibrated: bool,
+        cfg: &CfcHealthConfig,
+    ) {
+        self.is_calibrated = is_calibrated;
+        if !is_calibrated {
+            return;
+        }
+        let window = cfg.window_packets.max(2);
+
+        if let Some(loss) = training_loss {
+            if loss.is_finite() {
+                self.losses.push_back(loss);
+                while self.losses.len() > window {
+                    self.losses.pop_front();
+                }
+            } else {
+                self.non_finite_loss = true;
+            }
+        }
+        self.scores.push_back(anomaly_score);
+        while self.scores.len() > window {
+            self.scores.pop_front();
+        }
+
+        if self.losses.len() >= window {
+            let (recent, _) = mean_std(&self.losses);
+            self.best_loss = Some(self.best_loss.map_or(recent, |b| b.min(recent)));
+        }
+    }
+
+    /// Current health. Judged only once a full window has been seen.
+    pub fn health(&self, cfg: &CfcHealthConfig) -> NetworkHealth {
+        let (recent_loss, _) = mean_std(&self.losses);
+        let (score_mean, score_std) = mean_std(&self.scores);
+        let saturated_fraction = if self.scores.is_empty() {
+            0.0
+        } else {
+            self.scores
+                .iter()
+                .filter(|s| **s >= cfg.saturation_score)
+                .count() as f64
+                / self.scores.len() as f64
+        };
+        let loss_ratio = self.best_loss.filter(|b| *b > 0.0).map(|b| recent_loss / b);
+
+        let mut reasons = Vec::new();
+        if self.non_finite_loss {
+            reasons.push(DegradationReason::LossNonFinite);
+        }
+        let full = self.scores.len() >= cfg.window_packets.max(2);
+        if full {
+            if loss_ratio.is_some_and(|r| r > cfg.loss_divergence_ratio) {
+                reasons.push(DegradationReason::LossDiverging);
+            }
+            if saturated_fraction >= cfg.saturation_fraction {
+                reasons.push(DegradationReason::ScoreSaturated);
+            } else if score_std < cfg.stuck_score_std
+                && (score_mean <= 1.0 - cfg.saturation_score || score_mean >= cfg.saturation_score)
+            {
+                reasons.push(DegradationReason::ScoreStuck);
+            }
+        }
+
+        let status = if !reasons.is_empty() {
+            NetworkHealthStatus::Degraded
+        } else if !self.is_calibrated || !full {
+            NetworkHealthStatus::Warming
+        } else {
+            NetworkHealthStatus::Healthy
+        };
+
+        NetworkHealth {
+            status,
+            reasons,
+            is_calibrated: self.is_calibrated,
+            window_packets: self.scores.len(),
+            recent_loss,
+            best_loss: self.best_loss,
+            loss_ratio,
+            score_mean,
+            score_std,
+            saturated_fraction,
+            resets: self.resets,
+        }
+    }
+
+    /// Forget the window after the network has been reset.
+    pub fn note_reset(&mut self) {
+        let resets = self.resets + 1;
+        *self = Self {
+            resets,
+            ..Self::default()
+        };
+    }
+}
+
+/// Health of the dual CfC network
+#[derive(Debug, Clone, Serialize)]
+pub struct CfcHealthReport {
+    pub fast: NetworkHealth,
+    pub slow: NetworkHealth,
+    /// Either network degraded
+    pub degraded: bool,
+    pub auto_reset: bool,
+}
+
+/// Health monitor for the fast and slow networks
+#[derive(Debug, Clone, Default)]
+pub struct CfcHealthMonitor {
+    pub fast: NetworkHealthMonitor,
+    pub slow: NetworkHealthMonitor,
+}
+
+impl CfcHealthMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Observe one dual-network result.
+    pub fn observe(&mut self, result: &super::DualCfcResult, cfg: &CfcHealthConfig) {
+        self.fast.observe(
+            result.fast.anomaly_score,
+            result.fast.training_loss,
+            result.fast.is_calibrated,
+            cfg,
+        );
+        self.slow.observe(
+            result.slow.anomaly_score,
+            result.slow.training_loss,
+            result.slow.is_calibrated,
+            cfg,
+        );
+    }
+
+    pub fn report(&self, cfg: &CfcHealthConfig) -> CfcHealthReport {
+        let fast = self.fast.health(cfg);
+        let slow = self.slow.health(cfg);
+        CfcHealthReport {
+            degraded: fast.status == NetworkHealthStatus::Degraded
+                || slow.status == NetworkHealthStatus::Degraded,
+            fast,
+            slow,
+            auto_reset: cfg.auto_reset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturated_diverging_network_is_flagged_degraded() {
+        let cfg = CfcHealthConfig {
+            window_packets: 50,
+            ..CfcHealthConfig::default()
+        };
+        let mut monitor = NetworkHealthMonitor::new();
+
+        // Uncalibrated: warming, never judged
+        for _ in 0..100 {
+            monitor.observe(0.0, Some(1.0), false, &cfg);
+        }
+        assert_eq!(monitor.health(&cfg).status, NetworkHealthStatus::Warming);
+
+        // Healthy: loss settling, scores varying at the low end
+        for i in 0..200 {
+            let score = 0.1 + 0.2 * ((i % 7) as f64 / 7.0);
+            monitor.observe(score, Some(0.05 + 0.01 * (i % 3) as f64), true, &cfg);
+        }
+        let healthy = monitor.health(&cfg);
+        assert_eq!(healthy.status, NetworkHealthStatus::Healthy);
+        assert!(healthy.reasons.is_empty());
+
+        // Driven into divergence: loss climbs 20x and every packet scores ~1
+        for i in 0..100 {
+            monitor.observe(0.99, Some(0.06 * (1.0 + i as f64 * 0.4)), true, &cfg);
+        }
+        let degraded = monitor.health(&cfg);
+        assert_eq!(degraded.status, NetworkHealthStatus::Degraded);
+        assert!(degraded.reasons.contains(&DegradationReason::LossDiverging));
+        assert!(degraded
+            .reasons
+            .contains(&DegradationReason::ScoreSaturated));
+        assert!(degraded
+            .loss_ratio
+            .is_some_and(|r| r > cfg.loss_divergence_ratio));
+
+        // A reset clears the window and counts the reset
+        monitor.note_reset();
+        let after = monitor.health(&cfg);
+        assert_eq!(after.status, NetworkHealthStatus::Warming);
+        assert_eq!(after.resets, 1);
+    }
+
+    #[test]
+    fn test_score_stuck_at_zero_is_flagged() {
+        let cfg = CfcHealthConfig {
+            window_packets: 20,
+            ..CfcHealthConfig::default()
+        };
+        let mut monitor = NetworkHealthMonitor::new();
+        for _ in 0..40 {
+            monitor.observe(0.0, Some(0.05), true, &cfg);
+        }
+        let health = monitor.health(&cfg);
+        assert_eq!(health.status, NetworkHealthStatus::Degraded);
+        assert_eq!(health.reasons, vec![DegradationReason::ScoreStuck]);
+    }
+}
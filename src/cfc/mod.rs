@@ -12,6 +12,12 @@
 //! trigger detection. Two 64-neuron networks cost ~77% of a single 128-neuron
 //! network while providing fundamentally better coverage.
 //!
+//! Network sizes are set per deployment with `[cfc] fast_neurons` /
+//! `slow_neurons` (see `CfcConfig`). Only the inter and command groups
+//! scale; per-packet cost grows roughly with the square of the hidden
+//! neuron count, so weak edge boxes can drop to ~48 neurons and hub
+//! instances run 128+.
+//!
 //! The networks are **self-supervised** — they predict next-timestep sensor
 //! values and treat prediction error as an anomaly signal. No labeled
 //! training data is needed.
@@ -25,10 +31,12 @@
 //! - Outputs: 16 next-step predictions, anomaly score (0-1), health score (0-1)
 //! - Online training: forward → predict → compare → backprop → Adam, every packet
 
+pub mod candidate_tops;
 pub mod cell;
 pub mod checkpoint;
 pub mod depth_ahead;
 pub mod formation_detector;
+pub mod health;
 pub mod network;
 pub mod normalizer;
 pub mod regime_clusterer;
@@ -68,6 +76,16 @@ pub struct CfcDrillingResult {
     pub motor_outputs: Vec<f64>,
 }
 
+/// Which of the dual networks a result refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkKind {
+    Fast,
+    Slow,
+}
+
+/// Below this fast/slow divergence the networks are considered to agree.
+const DIVERGENCE_AGREEMENT: f64 = 0.1;
+
 /// Combined result from the dual CfC network architecture.
 #[derive(Debug, Clone)]
 pub struct DualCfcResult {
@@ -75,6 +93,10 @@ pub struct DualCfcResult {
     pub anomaly_score: f64,
     /// Combined health score: 1.0 - anomaly_score.
     pub health_score: f64,
+    /// |fast_score - slow_score|: how much the networks disagree.
+    pub divergence: f64,
+    /// Network with the higher anomaly score (fast on ties).
+    pub dominant: NetworkKind,
     /// Per-network results.
     pub fast: CfcDrillingResult,
     pub slow: CfcDrillingResult,
@@ -92,6 +114,68 @@ pub struct DualCfcResult {
     pub packets_processed: u64,
 }
 
+impl DualCfcResult {
+    /// Combine the per-network results for one packet.
+    pub fn combine(
+        fast: CfcDrillingResult,
+        slow: CfcDrillingResult,
+        learning_rate: f64,
+        packets_processed: u64,
+    ) -> Self {
+        let combined_anomaly = fast.anomaly_score.max(slow.anomaly_score);
+        let dominant = if fast.anomaly_score >= slow.anomaly_score {
+            NetworkKind::Fast
+        } else {
+            NetworkKind::Slow
+        };
+
+        // Feature surprises from whichever network scored higher
+        let feature_surprises = match dominant {
+            NetworkKind::Fast => fast.feature_surprises.clone(),
+            NetworkKind::Slow => slow.feature_surprises.clone(),
+        };
+
+        // Feature sigmas from slow network (stable baseline for formation detection)
+        let feature_sigmas = slow.feature_sigmas.clone();
+
+        // Motor outputs from fast network (responsive for regime clustering)
+        let motor_outputs = fast.motor_outputs.clone();
+
+        let is_calibrated = fast.is_calibrated || slow.is_calibrated;
+
+        Self {
+            anomaly_score: combined_anomaly,
+            health_score: 1.0 - combined_anomaly,
+            divergence: (fast.anomaly_score - slow.anomaly_score).abs(),
+            dominant,
+            fast,
+            slow,
+            is_calibrated,
+            feature_surprises,
+            feature_sigmas,
+            motor_outputs,
+            learning_rate,
+            packets_processed,
+        }
+    }
+
+    /// What the fast/slow split says about the anomaly.
+    ///
+    /// The fast network adapts to step changes, the slow one holds a stable
+    /// baseline — so whichever scores higher hints at an acute event versus
+    /// a gradual trend.
+    pub fn interpretation(&self) -> &'static str {
+        if self.divergence < DIVERGENCE_AGREEMENT {
+            "networks agree (no fast/slow split)"
+        } else {
+            match self.dominant {
+                NetworkKind::Fast => "acute event (fast-dominant)",
+                NetworkKind::Slow => "gradual trend (slow-dominant)",
+            }
+        }
+    }
+}
+
 /// Dual CfC network: fast + slow running in parallel.
 #[derive(Debug, Clone)]
 pub struct DualCfcNetwork {
@@ -109,6 +193,42 @@ impl DualCfcNetwork {
         }
     }
 
+    /// Create a dual network with the neuron counts from a `[cfc]` section.
+    pub fn with_sizes(seed: u64, sizes: &crate::config::CfcConfig) -> Self {
+        Self {
+            fast: CfcNetwork::with_config(
+                seed,
+                CfcNetworkConfig::fast().with_neurons(sizes.fast_neurons, sizes.inter_fraction),
+            ),
+            slow: CfcNetwork::with_config(
+                seed + 100,
+                CfcNetworkConfig::slow().with_neurons(sizes.slow_neurons, sizes.inter_fraction),
+            ),
+        }
+    }
+
+    /// Create a dual network sized from the loaded well config, or the
+    /// default 64/64 layout when no config is loaded.
+    pub fn from_well_config(seed: u64) -> Self {
+        if crate::config::is_initialized() {
+            Self::with_sizes(seed, &crate::config::get().cfc)
+        } else {
+            Self::new(seed)
+        }
+    }
+
+    /// Apply campaign feature-set weights to both networks' training loss.
+    ///
+    /// Returns `true` if the weighting changed.
+    pub fn set_feature_weights(&mut self, weights: [f64; NUM_FEATURES]) -> bool {
+        if *self.fast.feature_weights() == weights && *self.slow.feature_weights() == weights {
+            return false;
+        }
+        self.fast.set_feature_weights(weights);
+        self.slow.set_feature_weights(weights);
+        true
+    }
+
     /// Reset both networks from scratch.
     pub fn reset(&mut self) {
         self.fast.reset();
@@ -229,35 +349,12 @@ pub fn update_dual_from_drilling(
         || update_from_drilling(&mut dual.slow, packet, metrics, dt),
     );
 
-    let combined_anomaly = fast_result.anomaly_score.max(slow_result.anomaly_score);
-
-    // Feature surprises from whichever network scored higher
-    let feature_surprises = if fast_result.anomaly_score >= slow_result.anomaly_score {
-        fast_result.feature_surprises.clone()
-    } else {
-        slow_result.feature_surprises.clone()
-    };
-
-    // Feature sigmas from slow network (stable baseline for formation detection)
-    let feature_sigmas = slow_result.feature_sigmas.clone();
-
-    // Motor outputs from fast network (responsive for regime clustering)
-    let motor_outputs = fast_result.motor_outputs.clone();
-
-    let is_calibrated = fast_result.is_calibrated || slow_result.is_calibrated;
-
-    DualCfcResult {
-        anomaly_score: combined_anomaly,
-        health_score: 1.0 - combined_anomaly,
-        fast: fast_result,
-        slow: slow_result,
-        is_calibrated,
-        feature_surprises,
-        feature_sigmas,
-        motor_outputs,
-        learning_rate: dual.fast.learning_rate(),
-        packets_processed: dual.fast.packets_processed(),
-    }
+    DualCfcResult::combine(
+        fast_result,
+        slow_result,
+        dual.fast.learning_rate(),
+        dual.fast.packets_processed(),
+    )
 }
 
 #[cfg(test)]
@@ -419,4 +516,67 @@ pub(crate) mod tests {
         assert_eq!(dual.fast.packets_processed(), 0);
         assert_eq!(dual.slow.packets_processed(), 0);
     }
+
+    #[test]
+    fn test_dual_network_non_default_size_produces_valid_scores() {
+        let sizes = crate::config::CfcConfig {
+            fast_neurons: 96,
+            slow_neurons: 48,
+            ..crate::config::CfcConfig::default()
+        };
+        let mut dual = DualCfcNetwork::with_sizes(42, &sizes);
+        assert_eq!(dual.fast.config().ncp.num_neurons, 96);
+        assert_eq!(dual.slow.config().ncp.num_neurons, 48);
+
+        let packet = make_test_packet();
+        let metrics = make_test_metrics();
+        for _ in 0..20 {
+            let result = update_dual_from_drilling(&mut dual, &packet, &metrics, 1.0);
+            assert!(result.anomaly_score >= 0.0 && result.anomaly_score <= 1.0);
+            assert!(result.health_score >= 0.0 && result.health_score <= 1.0);
+            assert_eq!(result.motor_outputs.len(), 8);
+        }
+        assert!(dual.fast.avg_loss().is_finite());
+        assert!(dual.slow.avg_loss().is_finite());
+    }
+
+    fn synthetic_result(anomaly_score: f64) -> CfcDrillingResult {
+        CfcDrillingResult {
+            anomaly_score,
+            health_score: 1.0 - anomaly_score,
+            training_loss: None,
+            is_calibrated: true,
+            learning_rate: 0.001,
+            packets_processed: 1000,
+            avg_loss: 0.1,
+            feature_surprises: Vec::new(),
+            feature_sigmas: Vec::new(),
+            motor_outputs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_dual_result_attributes_dominant_network() {
+        let acute =
+            DualCfcResult::combine(synthetic_result(0.8), synthetic_result(0.2), 0.001, 1000);
+        assert_eq!(acute.dominant, NetworkKind::Fast);
+        assert!((acute.divergence - 0.6).abs() < 1e-9);
+        assert!((acute.anomaly_score - 0.8).abs() < 1e-9);
+        assert_eq!(acute.interpretation(), "acute event (fast-dominant)");
+
+        let trend =
+            DualCfcResult::combine(synthetic_result(0.1), synthetic_result(0.7), 0.001, 1000);
+        assert_eq!(trend.dominant, NetworkKind::Slow);
+        assert!((trend.divergence - 0.6).abs() < 1e-9);
+        assert!((trend.anomaly_score - 0.7).abs() < 1e-9);
+        assert_eq!(trend.interpretation(), "gradual trend (slow-dominant)");
+
+        let agree =
+            DualCfcResult::combine(synthetic_result(0.5), synthetic_result(0.45), 0.001, 1000);
+        assert!((agree.divergence - 0.05).abs() < 1e-9);
+        assert_eq!(
+            agree.interpretation(),
+            "networks agree (no fast/slow split)"
+        );
+    }
 }
@@ -65,6 +65,13 @@ impl CfcNetworkConfig {
             calibration_window: 500,
         }
     }
+
+    /// Resize the NCP topology (see [`NcpConfig::scaled`]), keeping the
+    /// training and scoring settings.
+    pub fn with_neurons(mut self, num_neurons: usize, inter_fraction: f64) -> Self {
+        self.ncp = NcpConfig::scaled(num_neurons, inter_fraction);
+        self
+    }
 }
 
 /// CfC neural network for drilling anomaly detection.
@@ -390,6 +397,16 @@ impl CfcNetwork {
         self.last_feature_errors = [0.0; NUM_FEATURES];
     }
 
+    /// Per-feature training loss weights currently in use.
+    pub fn feature_weights(&self) -> &[f64; NUM_FEATURES] {
+        &self.config.training.feature_weights
+    }
+
+    /// Replace the per-feature training loss weights (campaign feature set).
+    pub fn set_feature_weights(&mut self, weights: [f64; NUM_FEATURES]) {
+        self.config.training.feature_weights = weights;
+    }
+
     /// Full reset (new network from scratch with stored config).
     pub fn reset(&mut self) {
         *self = Self::with_config(self.seed, self.config.clone());
@@ -3,6 +3,12 @@
 //! Clusters the 8-dimensional motor output vectors from the CfC NCP network
 //! into k=4 drilling regimes using online k-means with a fixed learning rate.
 //! Centroids are initialised lazily from the first 4 distinct motor output vectors.
+//!
+//! Over a long run two centroids can drift onto the same regime and fragment
+//! the regime timeline; `merge_close_centroids` collapses such pairs and frees
+//! the slot for the next genuinely new regime.
+
+use std::collections::VecDeque;
 
 /// Number of regime clusters.
 const K: usize = 4;
@@ -12,6 +18,8 @@ const DIM: usize = 8;
 const LEARNING_RATE: f64 = 0.01;
 /// Minimum squared Euclidean distance to consider two points "distinct" during init.
 const DISTINCT_THRESHOLD_SQ: f64 = 1e-12;
+/// Number of recent assignments used for the stability metric.
+const STABILITY_WINDOW: usize = 120;
 
 /// Online k-means clusterer for CfC motor neuron outputs.
 #[derive(Debug, Clone)]
@@ -20,6 +28,13 @@ pub struct RegimeClusterer {
     init_buffer: Vec<[f64; DIM]>,
     initialized: bool,
     latest_regime_id: u8,
+    /// Slots still in use; a merged-away slot is inactive until reseeded.
+    active: [bool; K],
+    /// Squared distance a point must exceed from every active centroid to
+    /// reseed a freed slot. Set by the last merge.
+    reseed_dist_sq: Option<f64>,
+    /// Recent regime assignments (post-initialisation only).
+    recent: VecDeque<u8>,
 }
 
 impl RegimeClusterer {
@@ -30,6 +45,9 @@ impl RegimeClusterer {
             init_buffer: Vec::with_capacity(K),
             initialized: false,
             latest_regime_id: 0,
+            active: [true; K],
+            reseed_dist_sq: None,
+            recent: VecDeque::with_capacity(STABILITY_WINDOW),
         }
     }
 
@@ -45,13 +63,19 @@ impl RegimeClusterer {
 
         if !self.initialized {
             self.try_init(point);
+            if self.initialized {
+                self.record(self.latest_regime_id);
+            }
             return self.latest_regime_id;
         }
 
-        // Find nearest centroid
+        // Find nearest active centroid
         let mut best_k = 0usize;
         let mut best_dist = f64::MAX;
         for (i, centroid) in self.centroids.iter().enumerate() {
+            if !self.active[i] {
+                continue;
+            }
             let dist = sq_dist(&point, centroid);
             if dist < best_dist {
                 best_dist = dist;
@@ -59,12 +83,87 @@ impl RegimeClusterer {
             }
         }
 
-        // Nudge centroid towards point
-        for d in 0..DIM {
-            self.centroids[best_k][d] += LEARNING_RATE * (point[d] - self.centroids[best_k][d]);
+        // A point far from every active regime reseeds a slot freed by a merge
+        let free_slot = self.active.iter().position(|a| !a);
+        match (free_slot, self.reseed_dist_sq) {
+            (Some(slot), Some(reseed)) if best_dist > reseed => {
+                self.centroids[slot] = point;
+                self.active[slot] = true;
+                best_k = slot;
+            }
+            _ => {
+                // Nudge centroid towards point
+                for d in 0..DIM {
+                    self.centroids[best_k][d] +=
+                        LEARNING_RATE * (point[d] - self.centroids[best_k][d]);
+                }
+            }
         }
 
         self.latest_regime_id = best_k as u8;
+        self.record(self.latest_regime_id);
+        self.latest_regime_id
+    }
+
+    /// Collapse active centroids closer than `min_distance` (Euclidean).
+    ///
+    /// Each merged pair is replaced by its midpoint in the lower slot; the
+    /// higher slot is freed and every recent assignment to it is remapped to
+    /// the surviving id. Returns the number of centroids removed.
+    pub fn merge_close_centroids(&mut self, min_distance: f64) -> usize {
+        if !self.initialized || min_distance <= 0.0 {
+            return 0;
+        }
+        let min_dist_sq = min_distance * min_distance;
+        let mut merged = 0;
+
+        for i in 0..K {
+            for j in (i + 1)..K {
+                if !self.active[i]
+                    || !self.active[j]
+                    || sq_dist(&self.centroids[i], &self.centroids[j]) >= min_dist_sq
+                {
+                    continue;
+                }
+                for d in 0..DIM {
+                    self.centroids[i][d] = 0.5 * (self.centroids[i][d] + self.centroids[j][d]);
+                }
+                self.active[j] = false;
+                self.remap(j as u8, i as u8);
+                merged += 1;
+            }
+        }
+
+        if merged > 0 {
+            // Reseed only for points clearly outside the merge radius
+            self.reseed_dist_sq = Some(4.0 * min_dist_sq);
+        }
+        merged
+    }
+
+    /// Fraction of recent packets assigned to the most common regime.
+    ///
+    /// 1.0 means the rig sat in a single regime across the window (steady
+    /// state); 0.0 until the clusterer has initialised.
+    pub fn stability(&self) -> f64 {
+        if self.recent.is_empty() {
+            return 0.0;
+        }
+        let mut counts = [0usize; K];
+        for &id in &self.recent {
+            counts[id as usize] += 1;
+        }
+        let modal = counts.iter().copied().max().unwrap_or(0);
+        modal as f64 / self.recent.len() as f64
+    }
+
+    /// Number of centroids currently in use.
+    pub fn active_regimes(&self) -> usize {
+        self.active.iter().filter(|a| **a).count()
+    }
+
+    /// Latest assigned regime id.
+    pub fn latest_regime_id(&self) -> u8 {
         self.latest_regime_id
     }
 
@@ -83,6 +182,22 @@ impl RegimeClusterer {
         *self = Self::new();
     }
 
+    fn record(&mut self, regime_id: u8) {
+        if self.recent.len() >= STABILITY_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(regime_id);
+    }
+
+    fn remap(&mut self, from: u8, to: u8) {
+        for id in self.recent.iter_mut().filter(|id| **id == from) {
+            *id = to;
+        }
+        if self.latest_regime_id == from {
+            self.latest_regime_id = to;
+        }
+    }
+
     /// Try to add a point to the init buffer. If we reach K distinct points, seed centroids.
     fn try_init(&mut self, point: [f64; DIM]) {
         // Check if this point is distinct from all buffered points
@@ -245,4 +360,71 @@ mod tests {
         assert!(!rc.is_initialized());
         assert!(rc.init_buffer.is_empty());
     }
+
+    #[test]
+    fn test_merge_near_duplicate_centroids() {
+        let mut rc = RegimeClusterer::new();
+        // Seeds 0 and 1 are near-identical
+        let seeds: [[f64; 8]; 4] = [
+            [10.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [10.01, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 0.0],
+        ];
+        for s in &seeds {
+            rc.assign(s);
+        }
+        // The same regime fragments across both ids
+        let a = [9.99, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let b = [10.02, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        for _ in 0..10 {
+            assert_eq!(rc.assign(&a), 0);
+            assert_eq!(rc.assign(&b), 1);
+        }
+        let stability_before = rc.stability();
+        assert_eq!(rc.latest_regime_id(), 1);
+
+        assert_eq!(rc.merge_close_centroids(0.1), 1);
+        assert_eq!(rc.active_regimes(), 3);
+        // Latest id and recent history remapped onto the surviving slot
+        assert_eq!(rc.latest_regime_id(), 0);
+        assert!(rc.recent.iter().all(|&id| id != 1));
+        assert!(rc.stability() > stability_before);
+        let merged = rc.centroids()[0][0];
+        assert!((9.99..=10.02).contains(&merged));
+
+        // Both sides of the old split now land in regime 0
+        assert_eq!(rc.assign(&a), 0);
+        assert_eq!(rc.assign(&b), 0);
+        // Well-separated centroids are left alone
+        assert_eq!(rc.merge_close_centroids(0.1), 0);
+
+        // A genuinely new regime reseeds the freed slot
+        assert_eq!(rc.assign(&[0.0, 10.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]), 1);
+        assert_eq!(rc.active_regimes(), 4);
+    }
+
+    #[test]
+    fn test_stability_steady_vs_alternating() {
+        let mut rc = RegimeClusterer::new();
+        assert_eq!(rc.stability(), 0.0);
+        let seeds: [[f64; 8]; 4] = [
+            [10.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 10.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 0.0],
+        ];
+        for s in &seeds {
+            rc.assign(s);
+        }
+        for i in 0..STABILITY_WINDOW {
+            rc.assign(&seeds[i % 2]);
+        }
+        assert!((rc.stability() - 0.5).abs() < 1e-9);
+
+        for _ in 0..STABILITY_WINDOW {
+            rc.assign(&seeds[2]);
+        }
+        assert!((rc.stability() - 1.0).abs() < 1e-9);
+    }
 }
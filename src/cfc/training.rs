@@ -5,7 +5,8 @@
 //! older steps is decayed by 0.7^k to stabilize training.
 //!
 //! The loss is feature-weighted MSE between predicted and actual next-step values.
-//! Primary drilling features (WOB, ROP, torque, SPP) are weighted 2x.
+//! Primary drilling features (WOB, ROP, torque, SPP) are weighted 2x by default;
+//! `TrainingConfig::feature_weights` replaces the weighting per campaign.
 
 use crate::cfc::cell::{sigmoid, CfcWeights, ForwardCache};
 use crate::cfc::wiring::{NcpWiring, NUM_OUTPUTS};
@@ -31,6 +32,13 @@ pub struct TrainingConfig {
     pub initial_lr: f64,
     pub lr_decay: f64,
     pub lr_floor: f64,
+    /// Per-output loss weights (campaign feature set)
+    #[serde(default = "default_feature_weights")]
+    pub feature_weights: [f64; NUM_OUTPUTS],
+}
+
+fn default_feature_weights() -> [f64; NUM_OUTPUTS] {
+    DEFAULT_FEATURE_WEIGHTS
 }
 
 impl TrainingConfig {
@@ -43,6 +51,7 @@ impl TrainingConfig {
             initial_lr: 0.001,
             lr_decay: 0.9999,
             lr_floor: 0.0001,
+            feature_weights: DEFAULT_FEATURE_WEIGHTS,
         }
     }
 
@@ -55,13 +64,14 @@ impl TrainingConfig {
             initial_lr: 0.0001,
             lr_decay: 0.9999,
             lr_floor: 0.00001,
+            feature_weights: DEFAULT_FEATURE_WEIGHTS,
         }
     }
 }
 
-/// Per-output feature weights for loss computation.
+/// Default per-output feature weights for loss computation.
 /// Primary features (0-7) get weight 2.0, supplementary (8-15) get weight 1.0.
-const FEATURE_WEIGHTS: [f64; NUM_OUTPUTS] = [
+pub const DEFAULT_FEATURE_WEIGHTS: [f64; NUM_OUTPUTS] = [
     2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0,
     2.0, // WOB, ROP, RPM, torque, MSE, SPP, d-exp, hookload
     1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
@@ -318,6 +328,7 @@ pub fn train_step_with_config(
     let bptt_depth = config.map_or(BPTT_DEPTH, |c| c.bptt_depth);
     let bptt_decay = config.map_or(BPTT_DECAY, |c| c.bptt_decay);
     let max_grad_norm = config.map_or(MAX_GRAD_NORM, |c| c.max_grad_norm);
+    let feature_weights = config.map_or(&DEFAULT_FEATURE_WEIGHTS, |c| &c.feature_weights);
     let n = wiring.config.num_neurons;
     let num_motor = wiring.config.num_motor();
     let motor_start = wiring.config.command_end;
@@ -329,12 +340,12 @@ pub fn train_step_with_config(
     // ========================================================================
     // 1. Compute feature-weighted output loss
     // ========================================================================
-    let weight_sum: f64 = FEATURE_WEIGHTS.iter().sum();
+    let weight_sum: f64 = feature_weights.iter().sum();
     let mut loss = 0.0;
     let mut d_output = vec![0.0; NUM_OUTPUTS];
     for o in 0..NUM_OUTPUTS {
         let err = most_recent.output[o] - target[o];
-        let w = FEATURE_WEIGHTS[o];
+        let w = feature_weights[o];
         d_output[o] = 2.0 * w * err / weight_sum;
         loss += w * err * err;
     }
@@ -689,7 +700,7 @@ mod tests {
 
     #[test]
     fn test_feature_weights_sum() {
-        let sum: f64 = FEATURE_WEIGHTS.iter().sum();
+        let sum: f64 = DEFAULT_FEATURE_WEIGHTS.iter().sum();
         assert!((sum - 24.0).abs() < 1e-10); // 8*2 + 8*1 = 24
     }
 
@@ -41,6 +41,12 @@ pub const NUM_OUTPUTS: usize = NUM_FEATURES;
 /// Number of primary features (get 2 sensory neurons each).
 const NUM_PRIMARY: usize = 8;
 
+/// Sensory neurons required by the input mapping (primary ×2, supplementary ×1).
+pub const NUM_SENSORY: usize = 2 * NUM_PRIMARY + (NUM_FEATURES - NUM_PRIMARY);
+
+/// Smallest inter / command group a scaled layout may use.
+const MIN_HIDDEN_GROUP: usize = 2;
+
 /// Configuration for NCP neuron topology — group boundaries and sizes.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NcpConfig {
@@ -75,6 +81,58 @@ impl NcpConfig {
         }
     }
 
+    /// Layout with `num_neurons` in total for a given hardware tier.
+    ///
+    /// Sensory (24, fixed by the input mapping) and motor (8, fixed by the
+    /// regime clusterer) groups keep their size; the remaining neurons are
+    /// split between inter and command by `inter_fraction`. `dual_64()` is
+    /// `scaled(64, 0.625)`.
+    pub fn scaled(num_neurons: usize, inter_fraction: f64) -> Self {
+        let hidden = num_neurons.saturating_sub(NUM_SENSORY + NUM_MOTOR);
+        let inter = ((hidden as f64 * inter_fraction).round() as usize).min(hidden);
+        Self {
+            num_neurons,
+            sensory_end: NUM_SENSORY,
+            inter_end: NUM_SENSORY + inter,
+            command_end: NUM_SENSORY + hidden,
+        }
+    }
+
+    /// Validate boundaries plus the NCP funnel proportions a scaled layout
+    /// must keep: full-size sensory and motor groups, and an inter group at
+    /// least as large as a command group of at least 2 neurons.
+    pub fn validate_proportions(&self) -> Result<(), String> {
+        self.validate()?;
+        if self.sensory_end != NUM_SENSORY {
+            return Err(format!(
+                "sensory group has {} neurons, input mapping needs {}",
+                self.sensory_end, NUM_SENSORY,
+            ));
+        }
+        if self.num_motor() != NUM_MOTOR {
+            return Err(format!(
+                "motor group has {} neurons, regime clustering needs {}",
+                self.num_motor(),
+                NUM_MOTOR,
+            ));
+        }
+        let inter = self.inter_end - self.sensory_end;
+        let command = self.command_end - self.inter_end;
+        if command < MIN_HIDDEN_GROUP {
+            return Err(format!(
+                "command group has {} neurons, need at least {}",
+                command, MIN_HIDDEN_GROUP,
+            ));
+        }
+        if inter < command {
+            return Err(format!(
+                "inter group ({}) smaller than command group ({})",
+                inter, command,
+            ));
+        }
+        Ok(())
+    }
+
     /// Number of motor neurons.
     #[inline]
     pub fn num_motor(&self) -> usize {
@@ -497,4 +555,22 @@ mod tests {
         };
         NcpWiring::generate_with_config(42, &cfg);
     }
+
+    #[test]
+    fn test_scaled_layouts() {
+        let cfg = NcpConfig::scaled(64, 0.625);
+        assert_eq!(cfg.inter_end, NcpConfig::dual_64().inter_end);
+        assert_eq!(cfg.command_end, NcpConfig::dual_64().command_end);
+        assert!(cfg.validate_proportions().is_ok());
+
+        let big = NcpConfig::scaled(256, 0.625);
+        assert_eq!(big.num_motor(), 8);
+        assert_eq!(big.inter_end - big.sensory_end, 140);
+        assert!(big.validate_proportions().is_ok());
+        assert!(NcpConfig::default_128().validate_proportions().is_ok());
+
+        // Too small to hold a command group, and an inverted funnel
+        assert!(NcpConfig::scaled(34, 0.625).validate_proportions().is_err());
+        assert!(NcpConfig::scaled(64, 0.3).validate_proportions().is_err());
+    }
 }
@@ -8,36 +8,76 @@
 //!
 //! - `normal_mud_weight_ppg`: Median of `mud_weight_in` from first 30 packets
 //!
+//! Opt-in (`thresholds.hydraulics.auto_pore_fracture`):
+//!
+//! - `pore_pressure_ppg`: Eaton's method on the corrected d-exponent (dxc)
+//!   over steady on-bottom drilling. The normal compaction trend is fitted
+//!   (log dxc vs depth) over the shallow half of at least
+//!   `MIN_TREND_INTERVAL_FT` drilled, which is assumed normally pressured,
+//!   and extrapolated to the deep half
+//! - `fracture_gradient_ppg`: Detected mud weight plus a configurable offset
+//!   (`thresholds.hydraulics.auto_fracture_offset_ppg`), only once the pore
+//!   pressure could be estimated
+//!
+//! Detected values are cached per well and re-validated after applying; a
+//! combination the config rejects is rolled back.
+//!
 //! ## Usage
 //!
 //! ```ignore
-//! let mut detector = AutoDetector::new();
-//! for packet in first_30_packets {
+//! let mut detector = AutoDetector::new()
+//!     .with_pore_fracture_estimate(true)
+//!     .with_fracture_offset(3.0);
+//! for packet in first_packets {
 //!     detector.observe(&packet);
 //! }
 //! if detector.ready() {
 //!     let detected = detector.detect();
-//!     // Apply detected.normal_mud_weight_ppg if not user-set...
+//!     // Apply each detected value only if not user-set...
 //! }
 //! ```
 
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
+use crate::physics_engine::{calculate_d_exponent, calculate_dxc};
 use crate::types::WitsPacket;
 
 /// Minimum samples before auto-detection is attempted.
 const DEFAULT_MIN_SAMPLES: usize = 30;
 
+/// Packets peeked from a CSV before replay for auto-detection. Longer than
+/// `DEFAULT_MIN_SAMPLES` so the window can reach steady on-bottom drilling.
+pub const PEEK_PACKETS: usize = 300;
+
 /// Maximum coefficient of variation for a signal to be considered stable.
 /// 0.15 = 15% — if the signal varies more than this, we don't trust it.
 const DEFAULT_CONFIDENCE_CV: f64 = 0.15;
 
+/// Default fracture gradient offset above the observed mud weight (ppg).
+const DEFAULT_FRACTURE_OFFSET_PPG: f64 = 3.0;
+
+/// Normal hydrostatic pore pressure gradient (ppg, 0.447 psi/ft).
+const NORMAL_PORE_GRADIENT_PPG: f64 = 8.6;
+
+/// Overburden gradient for Eaton's method (ppg, 1.0 psi/ft).
+const OVERBURDEN_GRADIENT_PPG: f64 = 19.2;
+
+/// Eaton exponent for d-exponent based pore pressure.
+const EATON_EXPONENT: f64 = 1.2;
+
+/// Minimum drilled interval (ft) for a d-exponent depth trend.
+pub const MIN_TREND_INTERVAL_FT: f64 = 300.0;
+
 /// Observes WITS packets to auto-detect configuration values.
 pub struct AutoDetector {
     mud_weight_samples: Vec<f64>,
+    /// (depth ft, corrected d-exponent) from steady on-bottom drilling packets
+    dxc_samples: Vec<(f64, f64)>,
     min_samples: usize,
     confidence_cv: f64,
+    estimate_pore_fracture: bool,
+    fracture_offset_ppg: f64,
 }
 
 /// Values auto-detected from WITS stream observation.
@@ -45,6 +85,19 @@ pub struct AutoDetector {
 pub struct AutoDetectedValues {
     /// Normal mud weight in ppg, detected from `mud_weight_in` (WITS 0124).
     pub normal_mud_weight_ppg: Option<f64>,
+
+    /// Pore pressure in ppg, estimated from the d-exponent trend.
+    #[serde(default)]
+    pub pore_pressure_ppg: Option<f64>,
+
+    /// Fracture gradient in ppg, mud weight plus the configured offset.
+    #[serde(default)]
+    pub fracture_gradient_ppg: Option<f64>,
+
+    /// Well the values were detected on (`well.name`); the cache is only
+    /// restored for the same well.
+    #[serde(default)]
+    pub well: String,
 }
 
 impl AutoDetector {
@@ -52,11 +105,26 @@ impl AutoDetector {
     pub fn new() -> Self {
         Self {
             mud_weight_samples: Vec::new(),
+            dxc_samples: Vec::new(),
             min_samples: DEFAULT_MIN_SAMPLES,
             confidence_cv: DEFAULT_CONFIDENCE_CV,
+            estimate_pore_fracture: false,
+            fracture_offset_ppg: DEFAULT_FRACTURE_OFFSET_PPG,
         }
     }
 
+    /// Also estimate pore pressure and fracture gradient (off by default).
+    pub fn with_pore_fracture_estimate(mut self, enabled: bool) -> Self {
+        self.estimate_pore_fracture = enabled;
+        self
+    }
+
+    /// Set the fracture gradient offset above the observed mud weight (ppg).
+    pub fn with_fracture_offset(mut self, offset_ppg: f64) -> Self {
+        self.fracture_offset_ppg = offset_ppg;
+        self
+    }
+
     /// Observe a single WITS packet, collecting samples for auto-detection.
     pub fn observe(&mut self, packet: &WitsPacket) {
         // Mud weight: skip zeros and non-finite values
@@ -64,6 +132,28 @@ impl AutoDetector {
         if mw > 0.0 && mw.is_finite() {
             self.mud_weight_samples.push(mw);
         }
+
+        // D-exponent: only steady on-bottom drilling with a valid mud weight
+        let on_bottom = packet.hole_depth <= 0.0 || packet.hole_depth - packet.bit_depth < 5.0;
+        if on_bottom && mw > 0.0 && mw.is_finite() {
+            // Prefer the d-exponent channel when the feed supplies one
+            let d = if packet.d_exponent > 0.0 {
+                packet.d_exponent
+            } else if packet.rop > 0.0 && packet.rpm > 0.0 && packet.wob > 0.0 {
+                calculate_d_exponent(packet.rop, packet.rpm, packet.wob, packet.bit_diameter)
+            } else {
+                0.0
+            };
+            let depth = if packet.hole_depth > 0.0 {
+                packet.hole_depth
+            } else {
+                packet.bit_depth
+            };
+            if d > 0.0 && d.is_finite() && depth > 0.0 {
+                self.dxc_samples
+                    .push((depth, calculate_dxc(d, mw, NORMAL_PORE_GRADIENT_PPG)));
+            }
+        }
     }
 
     /// Check whether enough samples have been collected to attempt detection.
@@ -114,9 +204,94 @@ impl AutoDetector {
             }
         }
 
+        // --- Pore Pressure and Fracture Gradient (opt-in, steady drilling only) ---
+        if self.estimate_pore_fracture && self.dxc_samples.len() >= self.min_samples {
+            if let Some(pore) = self.eaton_pore_pressure() {
+                result.pore_pressure_ppg = Some(pore);
+                if let Some(mw) = result.normal_mud_weight_ppg {
+                    if self.fracture_offset_ppg > 0.0 {
+                        result.fracture_gradient_ppg = Some(mw + self.fracture_offset_ppg);
+                    }
+                }
+            }
+        }
+
         result
     }
 
+    /// Eaton pore pressure from the dxc depth trend, if the samples allow one.
+    ///
+    /// Fits ln(dxc) against depth over the shallow half of the drilled
+    /// interval and compares the deep half's median dxc with that trend
+    /// extrapolated to the deep half's median depth.
+    fn eaton_pore_pressure(&self) -> Option<f64> {
+        let dxc: Vec<f64> = self.dxc_samples.iter().map(|&(_, d)| d).collect();
+        let cv = Self::coefficient_of_variation(&dxc);
+        if cv > self.confidence_cv {
+            warn!(
+                cv = format!("{:.4}", cv),
+                samples = dxc.len(),
+                "Auto-detect: d-exponent not stable, skipping pore pressure and fracture gradient"
+            );
+            return None;
+        }
+
+        let (top, bottom) = self
+            .dxc_samples
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &(z, _)| {
+                (lo.min(z), hi.max(z))
+            });
+        if bottom - top < MIN_TREND_INTERVAL_FT {
+            info!(
+                interval_ft = format!("{:.0}", bottom - top),
+                min_ft = MIN_TREND_INTERVAL_FT,
+                "Auto-detect: drilled interval too short for a d-exponent trend, skipping pore pressure"
+            );
+            return None;
+        }
+        let mid = top + (bottom - top) / 2.0;
+        let (shallow, deep): (Vec<(f64, f64)>, Vec<(f64, f64)>) =
+            self.dxc_samples.iter().partition(|&&(z, _)| z <= mid);
+        if shallow.len() < self.min_samples / 2 || deep.len() < self.min_samples / 2 {
+            return None;
+        }
+
+        // Least-squares ln(dxc) = a + b * depth over the shallow half
+        let n = shallow.len() as f64;
+        let z_mean = shallow.iter().map(|&(z, _)| z).sum::<f64>() / n;
+        let y_mean = shallow.iter().map(|&(_, d)| d.ln()).sum::<f64>() / n;
+        let sxx: f64 = shallow.iter().map(|&(z, _)| (z - z_mean).powi(2)).sum();
+        let sxy: f64 = shallow
+            .iter()
+            .map(|&(z, d)| (z - z_mean) * (d.ln() - y_mean))
+            .sum();
+        let slope = if sxx > 1e-9 { sxy / sxx } else { 0.0 };
+
+        let deep_depth = Self::median(&deep.iter().map(|&(z, _)| z).collect::<Vec<_>>());
+        let normal = (y_mean + slope * (deep_depth - z_mean)).exp();
+        let observed = Self::median(&deep.iter().map(|&(_, d)| d).collect::<Vec<_>>());
+        let pore = OVERBURDEN_GRADIENT_PPG
+            - (OVERBURDEN_GRADIENT_PPG - NORMAL_PORE_GRADIENT_PPG)
+                * (observed / normal).powf(EATON_EXPONENT);
+        if !(5.0..OVERBURDEN_GRADIENT_PPG).contains(&pore) {
+            warn!(
+                pore_pressure = format!("{:.2}", pore),
+                "Auto-detect: pore pressure estimate outside physical range, skipping"
+            );
+            return None;
+        }
+        info!(
+            pore_pressure = format!("{:.2}", pore),
+            dxc = format!("{:.3}", observed),
+            normal_dxc = format!("{:.3}", normal),
+            depth_ft = format!("{:.0}", deep_depth),
+            samples = dxc.len(),
+            "Auto-detect: pore pressure from d-exponent depth trend"
+        );
+        Some(pore)
+    }
+
     /// Compute the median of a slice (non-destructive — clones and sorts).
     fn median(values: &[f64]) -> f64 {
         if values.is_empty() {
@@ -173,11 +348,96 @@ impl AutoDetectedValues {
         Ok(())
     }
 
-    /// Load cached auto-detected values from disk.
-    pub fn load_cached() -> Option<Self> {
+    /// Load cached auto-detected values from disk, if they were detected on
+    /// `well`.
+    pub fn load_cached(well: &str) -> Option<Self> {
         let path = std::path::Path::new(AUTO_DETECTED_PATH);
         let json = std::fs::read_to_string(path).ok()?;
-        serde_json::from_str(&json).ok()
+        let cached: Self = serde_json::from_str(&json).ok()?;
+        if cached.well != well {
+            info!(
+                cached_well = %cached.well,
+                well = %well,
+                "Auto-detected values cached for another well, ignoring"
+            );
+            return None;
+        }
+        Some(cached)
+    }
+
+    /// Apply detected values to `config`, never overriding a user-set key.
+    ///
+    /// `source` names where the values came from for logging ("WITS stream",
+    /// "cache"). Pore pressure and fracture gradient are only applied with
+    /// `thresholds.hydraulics.auto_pore_fracture` on, and a detected pore
+    /// pressure at or above the resulting fracture gradient is dropped. If
+    /// the result fails `validate()`, the hydraulics thresholds are restored.
+    pub fn apply_to(
+        &self,
+        config: &mut super::WellConfig,
+        provenance: &super::ConfigProvenance,
+        source: &str,
+    ) {
+        let before = config.thresholds.hydraulics.clone();
+        self.apply_hydraulics(&mut config.thresholds.hydraulics, provenance, source);
+        if let Err(e) = config.validate() {
+            warn!(
+                "Auto-detected values from {} rejected, keeping configured hydraulics: {}",
+                source, e
+            );
+            config.thresholds.hydraulics = before;
+        }
+    }
+
+    fn apply_hydraulics(
+        &self,
+        h: &mut super::HydraulicsThresholds,
+        provenance: &super::ConfigProvenance,
+        source: &str,
+    ) {
+        if let Some(mw) = self.normal_mud_weight_ppg {
+            if !provenance.is_user_set("thresholds.hydraulics.normal_mud_weight_ppg") {
+                info!("Auto-detected mud weight: {:.1} ppg (from {})", mw, source);
+                h.normal_mud_weight_ppg = mw;
+            } else {
+                info!(
+                    "Mud weight: {:.1} ppg (user-configured, ignoring auto-detected {:.1})",
+                    h.normal_mud_weight_ppg, mw
+                );
+            }
+        }
+
+        if !h.auto_pore_fracture {
+            return;
+        }
+
+        if let Some(frac) = self.fracture_gradient_ppg {
+            if !provenance.is_user_set("thresholds.hydraulics.fracture_gradient_ppg") {
+                info!(
+                    "Auto-detected fracture gradient: {:.1} ppg (from {})",
+                    frac, source
+                );
+                h.fracture_gradient_ppg = frac;
+            }
+        }
+
+        if let Some(pore) = self.pore_pressure_ppg {
+            if !provenance.is_user_set("thresholds.hydraulics.pore_pressure_ppg") {
+                if pore < h.fracture_gradient_ppg {
+                    info!(
+                        "Auto-detected pore pressure: {:.1} ppg (from {})",
+                        pore, source
+                    );
+                    h.pore_pressure_ppg = pore;
+                } else {
+                    warn!(
+                        pore = format!("{:.2}", pore),
+                        fracture = format!("{:.2}", h.fracture_gradient_ppg),
+                        "Auto-detect: pore pressure at or above fracture gradient, skipping"
+                    );
+                }
+            }
+        }
     }
 }
 
@@ -277,6 +537,178 @@ mod tests {
         assert!(AutoDetector::coefficient_of_variation(&values).is_infinite());
     }
 
+    fn make_drilling_packet(mud_weight_in: f64, d_exponent: f64, depth: f64) -> WitsPacket {
+        WitsPacket {
+            mud_weight_in,
+            wob: 25.0,
+            rpm: 120.0,
+            rop: 60.0,
+            bit_depth: depth,
+            hole_depth: depth,
+            d_exponent,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_steady_drilling_detects_pore_pressure_and_fracture_gradient() {
+        let mut detector = AutoDetector::new()
+            .with_pore_fracture_estimate(true)
+            .with_fracture_offset(3.5);
+        // Connection before drilling: off bottom, no d-exponent samples
+        for _ in 0..10 {
+            let mut p = make_drilling_packet(10.0, 1.6, 5000.0);
+            p.bit_depth = 4950.0;
+            detector.observe(&p);
+        }
+        // 600 ft drilled, dxc on a normal compaction trend (rising with depth)
+        for i in 0..60 {
+            let depth = 5000.0 + i as f64 * 10.0;
+            let jitter = (i % 3) as f64 * 0.01;
+            detector.observe(&make_drilling_packet(
+                10.0,
+                1.6 + i as f64 * 0.002 + jitter,
+                depth,
+            ));
+        }
+        assert!(detector.ready());
+        let detected = detector.detect();
+
+        let mw = detected.normal_mud_weight_ppg.expect("mud weight");
+        assert!((mw - 10.0).abs() < 1e-9);
+
+        // dxc on trend: normally pressured, close to the hydrostatic gradient
+        let pore = detected.pore_pressure_ppg.expect("pore pressure");
+        assert!(
+            (8.0..9.5).contains(&pore),
+            "pore pressure should be near normal, got {pore}"
+        );
+
+        let frac = detected.fracture_gradient_ppg.expect("fracture gradient");
+        assert!((frac - 13.5).abs() < 1e-9);
+        assert!(frac > pore);
+    }
+
+    #[test]
+    fn test_dxc_below_depth_trend_indicates_overpressure() {
+        let mut detector = AutoDetector::new().with_pore_fracture_estimate(true);
+        // Drilling faster than the trend: dxc falls off it in the deep half
+        for i in 0..60 {
+            let d = if i < 30 { 1.6 } else { 1.45 };
+            detector.observe(&make_drilling_packet(10.0, d, 5000.0 + i as f64 * 10.0));
+        }
+        let detected = detector.detect();
+        let pore = detected.pore_pressure_ppg.expect("pore pressure");
+        assert!(
+            pore > 9.0,
+            "dxc below the trend should raise pore pressure, got {pore}"
+        );
+        assert!(pore < 19.2);
+    }
+
+    #[test]
+    fn test_pore_fracture_estimate_needs_opt_in_and_a_depth_interval() {
+        let drill = |detector: &mut AutoDetector, step_ft: f64| {
+            for i in 0..60 {
+                let d = if i < 30 { 1.6 } else { 1.45 };
+                detector.observe(&make_drilling_packet(10.0, d, 5000.0 + i as f64 * step_ft));
+            }
+        };
+
+        // Off by default
+        let mut detector = AutoDetector::new();
+        drill(&mut detector, 10.0);
+        let detected = detector.detect();
+        assert!(detected.normal_mud_weight_ppg.is_some());
+        assert!(detected.pore_pressure_ppg.is_none());
+        assert!(detected.fracture_gradient_ppg.is_none());
+
+        // 59 ft drilled: a change over time is not a depth trend
+        let mut detector = AutoDetector::new().with_pore_fracture_estimate(true);
+        drill(&mut detector, 1.0);
+        let detected = detector.detect();
+        assert!(detected.pore_pressure_ppg.is_none());
+        assert!(detected.fracture_gradient_ppg.is_none());
+    }
+
+    #[test]
+    fn test_no_drilling_no_pore_or_fracture() {
+        let mut detector = AutoDetector::new().with_pore_fracture_estimate(true);
+        for _ in 0..40 {
+            detector.observe(&make_packet(9.5));
+        }
+        assert!(detector.ready());
+        let detected = detector.detect();
+        assert!(detected.normal_mud_weight_ppg.is_some());
+        assert!(detected.pore_pressure_ppg.is_none());
+        assert!(detected.fracture_gradient_ppg.is_none());
+    }
+
+    fn pore_fracture_config() -> super::super::WellConfig {
+        let mut config = super::super::WellConfig::default();
+        config.thresholds.hydraulics.auto_pore_fracture = true;
+        config
+    }
+
+    #[test]
+    fn test_apply_respects_user_set_values() {
+        let detected = AutoDetectedValues {
+            normal_mud_weight_ppg: Some(10.0),
+            pore_pressure_ppg: Some(9.2),
+            fracture_gradient_ppg: Some(13.0),
+            well: String::new(),
+        };
+
+        // Nothing user-set: all three applied
+        let mut config = pore_fracture_config();
+        detected.apply_to(&mut config, &Default::default(), "test");
+        let h = &config.thresholds.hydraulics;
+        assert_eq!(h.normal_mud_weight_ppg, 10.0);
+        assert_eq!(h.fracture_gradient_ppg, 13.0);
+        assert_eq!(h.pore_pressure_ppg, 9.2);
+
+        // User-set fracture gradient is kept
+        let toml = "[thresholds.hydraulics]\nfracture_gradient_ppg = 15.5\n";
+        let provenance = super::super::ConfigProvenance {
+            explicit_keys: super::super::validation::walk_toml_keys(
+                &toml.parse::<toml::Value>().unwrap(),
+                "",
+            )
+            .into_iter()
+            .collect(),
+        };
+        let mut config = pore_fracture_config();
+        config.thresholds.hydraulics.fracture_gradient_ppg = 15.5;
+        detected.apply_to(&mut config, &provenance, "test");
+        assert_eq!(config.thresholds.hydraulics.fracture_gradient_ppg, 15.5);
+        assert_eq!(config.thresholds.hydraulics.normal_mud_weight_ppg, 10.0);
+
+        // Estimate off: only the mud weight is applied, even from a cache
+        let mut config = super::super::WellConfig::default();
+        detected.apply_to(&mut config, &Default::default(), "cache");
+        let h = &config.thresholds.hydraulics;
+        assert_eq!(h.normal_mud_weight_ppg, 10.0);
+        assert_eq!(h.fracture_gradient_ppg, 14.0);
+        assert_eq!(h.pore_pressure_ppg, 0.0);
+    }
+
+    #[test]
+    fn test_apply_rolls_back_values_that_fail_validation() {
+        // Fracture gradient below the detected mud weight
+        let detected = AutoDetectedValues {
+            normal_mud_weight_ppg: Some(12.0),
+            pore_pressure_ppg: None,
+            fracture_gradient_ppg: Some(11.5),
+            well: String::new(),
+        };
+        let mut config = pore_fracture_config();
+        detected.apply_to(&mut config, &Default::default(), "cache");
+        let h = &config.thresholds.hydraulics;
+        assert_eq!(h.normal_mud_weight_ppg, 8.6);
+        assert_eq!(h.fracture_gradient_ppg, 14.0);
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_out_of_range_mud_weight_rejected() {
         let mut detector = AutoDetector::new();
@@ -3,7 +3,9 @@
 //! Loads structured pre-drill geological data from TOML files.
 //! Search order: `$SAIREN_PROGNOSIS` env var → `./well_prognosis.toml` → None.
 
-use crate::types::{FormationInterval, FormationPrognosis};
+use crate::types::{
+    FormationInterval, FormationPrognosis, MudProgramInterval, MudWeightDeviation,
+};
 use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 
@@ -82,4 +84,82 @@ impl FormationPrognosis {
     pub fn next_formation(&self, depth_ft: f64) -> Option<&FormationInterval> {
         self.formations.iter().find(|f| f.depth_top_ft > depth_ft)
     }
+
+    /// Build the prognosis as seen from a sidetrack branch.
+    ///
+    /// Parent formations are kept down to the kickoff depth (the interval
+    /// straddling kickoff is truncated there) and the branch's own formations
+    /// take over below it. Returns `None` if no branch with that name
+    /// (case-insensitive) is defined.
+    pub fn for_branch(&self, branch: &str) -> Option<Self> {
+        let sidetrack = self
+            .sidetracks
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(branch))?;
+        let kickoff = sidetrack.kickoff_depth_ft;
+
+        let mut formations: Vec<FormationInterval> = self
+            .formations
+            .iter()
+            .filter(|f| f.depth_top_ft < kickoff)
+            .cloned()
+            .map(|mut f| {
+                f.depth_base_ft = f.depth_base_ft.min(kickoff);
+                f
+            })
+            .collect();
+        formations.extend(
+            sidetrack
+                .formations
+                .iter()
+                .filter(|f| f.depth_base_ft > kickoff)
+                .cloned(),
+        );
+
+        Some(Self {
+            well: self.well.clone(),
+            formations,
+            casings: self.casings.clone(),
+            sidetracks: Vec::new(),
+            mud_program: self.mud_program.clone(),
+        })
+    }
+
+    /// Planned mud-program interval at a given depth
+    pub fn mud_program_at_depth(&self, depth_ft: f64) -> Option<&MudProgramInterval> {
+        self.mud_program
+            .iter()
+            .find(|m| depth_ft >= m.depth_top_ft && depth_ft < m.depth_base_ft)
+    }
+
+    /// Compare the actual mud weight against the planned program.
+    ///
+    /// Returns a deviation when the depth is covered by the program and the
+    /// actual weight differs by more than the interval's tolerance (or
+    /// `default_tolerance_ppg` when the interval doesn't set one).
+    pub fn mud_weight_deviation(
+        &self,
+        depth_ft: f64,
+        actual_ppg: f64,
+        default_tolerance_ppg: f64,
+    ) -> Option<MudWeightDeviation> {
+        if actual_ppg <= 0.0 {
+            return None;
+        }
+        let planned = self.mud_program_at_depth(depth_ft)?;
+        let tolerance_ppg = planned.tolerance_ppg.unwrap_or(default_tolerance_ppg);
+        let deviation_ppg = actual_ppg - planned.mud_weight_ppg;
+        if deviation_ppg.abs() <= tolerance_ppg {
+            return None;
+        }
+        Some(MudWeightDeviation {
+            depth_ft,
+            interval_top_ft: planned.depth_top_ft,
+            interval_base_ft: planned.depth_base_ft,
+            planned_ppg: planned.mud_weight_ppg,
+            actual_ppg,
+            deviation_ppg,
+            tolerance_ppg,
+        })
+    }
 }
@@ -41,6 +41,7 @@
 pub mod auto_detect;
 pub mod defaults;
 mod formation;
+pub mod sanity;
 pub mod validation;
 pub mod watcher;
 mod well_config;
@@ -0,0 +1,266 @@
+//! Config Sanity Check — detect a config written for a different well
+//!
+//! Running with a config from another well or hole section (most often the
+//! wrong `bit_diameter_inches`) skews MSE and every threshold derived from it
+//! without any obvious error. After enough packets this compares:
+//!
+//! - the configured bit diameter with the median bit diameter reported by
+//!   the feed (feeds that do not report one, e.g. WITS Level 0 TCP, are
+//!   stamped with the configured value and always pass);
+//! - locked baseline means with field-typical ranges.
+//!
+//! The result is served at `GET /api/v2/config/sanity`.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+use super::{ConfigSanityConfig, WellConfig};
+use crate::baseline::{wits_metrics, ThresholdManager};
+use crate::types::WitsPacket;
+
+/// Field-typical ranges for locked baseline means: (metric, min, max, unit).
+///
+/// Deliberately wide — a mean outside these is almost certainly a unit or
+/// config error rather than an unusual well.
+const TYPICAL_BASELINE_RANGES: &[(&str, f64, f64, &str)] = &[
+    (wits_metrics::MSE, 1_000.0, 400_000.0, "psi"),
+    (wits_metrics::SPP, 100.0, 7_500.0, "psi"),
+    (wits_metrics::TORQUE, 0.2, 80.0, "kft-lbs"),
+    (wits_metrics::WOB, 0.5, 120.0, "klbs"),
+    (wits_metrics::ECD, 7.0, 22.0, "ppg"),
+];
+
+/// Overall result of the sanity check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SanityStatus {
+    /// Not enough packets yet (or check disabled)
+    Pending,
+    Ok,
+    /// At least one check disagrees — likely the wrong config
+    Mismatch,
+}
+
+/// One failed check
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SanityWarning {
+    /// `bit_diameter` or `baseline.<metric>`
+    pub check: String,
+    pub message: String,
+}
+
+/// Response body for `GET /api/v2/config/sanity`
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSanityReport {
+    pub status: SanityStatus,
+    pub packets_observed: usize,
+    pub min_packets: usize,
+    pub configured_bit_diameter_in: f64,
+    /// Median bit diameter reported by the feed (recent window)
+    pub reported_bit_diameter_in: Option<f64>,
+    pub warnings: Vec<SanityWarning>,
+}
+
+/// Collects the feed's reported bit diameter over a rolling window.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSanityChecker {
+    packets_observed: usize,
+    bit_diameters: VecDeque<f64>,
+    warned: bool,
+}
+
+impl ConfigSanityChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Observe a packet. Returns the feed-side warnings once, on the packet
+    /// that first completes the window, so the caller can log them.
+    pub fn observe(&mut self, packet: &WitsPacket, cfg: &WellConfig) -> Option<Vec<SanityWarning>> {
+        let sanity = &cfg.config_sanity;
+        if !sanity.enabled {
+            return None;
+        }
+
+        self.packets_observed += 1;
+        let d = packet.bit_diameter;
+        if d.is_finite() && d > 0.0 {
+            self.bit_diameters.push_back(d);
+            while self.bit_diameters.len() > sanity.min_packets.max(1) {
+                self.bit_diameters.pop_front();
+            }
+        }
+
+        if self.warned || self.packets_observed < sanity.min_packets {
+            return None;
+        }
+        self.warned = true;
+        let warnings: Vec<_> = self
+            .bit_diameter_warning(cfg.well.bit_diameter_inches, sanity)
+            .into_iter()
+            .collect();
+        (!warnings.is_empty()).then_some(warnings)
+    }
+
+    /// Full report. `baseline_means` are locked baseline means by metric id
+    /// (see [`locked_baseline_means`]).
+    pub fn report(&self, cfg: &WellConfig, baseline_means: &[(&str, f64)]) -> ConfigSanityReport {
+        let sanity = &cfg.config_sanity;
+        let configured = cfg.well.bit_diameter_inches;
+        let mut report = ConfigSanityReport {
+            status: SanityStatus::Pending,
+            packets_observed: self.packets_observed,
+            min_packets: sanity.min_packets,
+            configured_bit_diameter_in: configured,
+            reported_bit_diameter_in: self.reported_bit_diameter(),
+            warnings: Vec::new(),
+        };
+        if !sanity.enabled || self.packets_observed < sanity.min_packets {
+            return report;
+        }
+
+        report
+            .warnings
+            .extend(self.bit_diameter_warning(configured, sanity));
+        for &(metric, mean) in baseline_means {
+            let Some(&(_, min, max, unit)) =
+                TYPICAL_BASELINE_RANGES.iter().find(|(m, ..)| *m == metric)
+            else {
+                continue;
+            };
+            if mean < min || mean > max {
+                report.warnings.push(SanityWarning {
+                    check: format!("baseline.{}", metric),
+                    message: format!(
+                        "Learned {} baseline {:.1} {} is outside the field-typical range {}-{} {}",
+                        metric, mean, unit, min, max, unit
+                    ),
+                });
+            }
+        }
+
+        report.status = if report.warnings.is_empty() {
+            SanityStatus::Ok
+        } else {
+            SanityStatus::Mismatch
+        };
+        report
+    }
+
+    fn reported_bit_diameter(&self) -> Option<f64> {
+        if self.bit_diameters.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.bit_diameters.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Some(sorted[sorted.len() / 2])
+    }
+
+    fn bit_diameter_warning(
+        &self,
+        configured: f64,
+        sanity: &ConfigSanityConfig,
+    ) -> Option<SanityWarning> {
+        let reported = self.reported_bit_diameter()?;
+        if (reported - configured).abs() <= sanity.bit_diameter_tolerance_in {
+            return None;
+        }
+        // MSE's torque term scales with 1/d²
+        let mse_factor = (reported / configured).powi(2);
+        Some(SanityWarning {
+            check: "bit_diameter".to_string(),
+            message: format!(
+                "Configured bit diameter {:.2}\" but the feed reports {:.2}\" — MSE is {} \
+                 ~{:.1}x. The config is likely from a different well or hole section",
+                configured,
+                reported,
+                if mse_factor > 1.0 {
+                    "overstated"
+                } else {
+                    "understated"
+                },
+                mse_factor.max(1.0 / mse_factor)
+            ),
+        })
+    }
+}
+
+/// Means of the locked baselines that have a field-typical range.
+pub fn locked_baseline_means(
+    manager: &ThresholdManager,
+    equipment_id: &str,
+) -> Vec<(&'static str, f64)> {
+    TYPICAL_BASELINE_RANGES
+        .iter()
+        .filter_map(|&(metric, ..)| {
+            manager
+                .get_threshold(equipment_id, metric)
+                .filter(|t| t.locked)
+                .map(|t| (metric, t.baseline_mean))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(bit_diameter: f64) -> WitsPacket {
+        WitsPacket {
+            bit_diameter,
+            ..WitsPacket::default()
+        }
+    }
+
+    fn config(bit_diameter_inches: f64) -> WellConfig {
+        let mut cfg = WellConfig::default();
+        cfg.well.bit_diameter_inches = bit_diameter_inches;
+        cfg.config_sanity.min_packets = 50;
+        cfg
+    }
+
+    #[test]
+    fn test_feed_bit_diameter_disagreeing_with_config_is_flagged() {
+        let cfg = config(8.5);
+        let mut checker = ConfigSanityChecker::new();
+
+        let mut logged = Vec::new();
+        for _ in 0..60 {
+            if let Some(w) = checker.observe(&packet(12.25), &cfg) {
+                logged.extend(w);
+            }
+        }
+        assert_eq!(logged.len(), 1, "warning is returned once for logging");
+        assert_eq!(logged[0].check, "bit_diameter");
+
+        let report = checker.report(&cfg, &[]);
+        assert_eq!(report.status, SanityStatus::Mismatch);
+        assert_eq!(report.reported_bit_diameter_in, Some(12.25));
+        assert!(report.warnings[0].message.contains("8.50\""));
+        assert!(report.warnings[0].message.contains("12.25\""));
+    }
+
+    #[test]
+    fn test_matching_config_passes_and_baselines_checked() {
+        let cfg = config(8.5);
+        let mut checker = ConfigSanityChecker::new();
+        for i in 0..49 {
+            assert!(
+                checker.observe(&packet(8.5), &cfg).is_none(),
+                "packet {}",
+                i
+            );
+        }
+        assert_eq!(checker.report(&cfg, &[]).status, SanityStatus::Pending);
+        assert!(checker.observe(&packet(8.5), &cfg).is_none());
+
+        let ok = checker.report(&cfg, &[(wits_metrics::MSE, 35_000.0)]);
+        assert_eq!(ok.status, SanityStatus::Ok);
+
+        // An ECD baseline in SG rather than ppg
+        let bad = checker.report(&cfg, &[(wits_metrics::ECD, 1.4)]);
+        assert_eq!(bad.status, SanityStatus::Mismatch);
+        assert_eq!(bad.warnings[0].check, "baseline.ecd");
+    }
+}
@@ -1,5 +1,5 @@
-//! Config validation: unknown-key detection with Levenshtein suggestions
-//! and physical range checks.
+//! Config validation: unknown-key detection with Levenshtein suggestions,
+//! physical range checks, and cross-field invariants.
 //!
 //! Two-pass parse approach: first deserialize raw TOML into `toml::Value`,
 //! walk the key tree, compare against known field names, and emit warnings
@@ -46,6 +46,10 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         // [server]
         "server",
         "server.addr",
+        "server.config_reload_quiet_ms",
+        // [api]
+        "api",
+        "api.v1_sunset",
         // [thresholds]
         "thresholds",
         // [thresholds.well_control]
@@ -69,11 +73,15 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "thresholds.hydraulics",
         "thresholds.hydraulics.normal_mud_weight_ppg",
         "thresholds.hydraulics.fracture_gradient_ppg",
+        "thresholds.hydraulics.pore_pressure_ppg",
+        "thresholds.hydraulics.auto_pore_fracture",
+        "thresholds.hydraulics.auto_fracture_offset_ppg",
         "thresholds.hydraulics.ecd_margin_warning_ppg",
         "thresholds.hydraulics.ecd_margin_critical_ppg",
         "thresholds.hydraulics.spp_deviation_warning_psi",
         "thresholds.hydraulics.spp_deviation_critical_psi",
         "thresholds.hydraulics.annular_pressure_loss_coefficient",
+        "thresholds.hydraulics.mud_thermal_expansion_ppg_per_degf",
         // [thresholds.mechanical]
         "thresholds.mechanical",
         "thresholds.mechanical.torque_increase_warning",
@@ -83,6 +91,8 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "thresholds.mechanical.packoff_spp_increase_threshold",
         "thresholds.mechanical.packoff_rop_decrease_threshold",
         "thresholds.mechanical.stick_slip_min_samples",
+        "thresholds.mechanical.washout_spp_decline_percent",
+        "thresholds.mechanical.washout_flat_tolerance_percent",
         // [thresholds.founder]
         "thresholds.founder",
         "thresholds.founder.wob_increase_min",
@@ -100,6 +110,20 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "thresholds.formation.dxc_trend_threshold",
         "thresholds.formation.dxc_pressure_threshold",
         "thresholds.formation.mse_pressure_tolerance",
+        // [thresholds.drilling_break]
+        "thresholds.drilling_break",
+        "thresholds.drilling_break.enabled",
+        "thresholds.drilling_break.rop_increase_factor",
+        "thresholds.drilling_break.baseline_packets",
+        "thresholds.drilling_break.sustain_packets",
+        "thresholds.drilling_break.min_baseline_rop_ft_hr",
+        // [thresholds.packoff_classification]
+        "thresholds.packoff_classification",
+        "thresholds.packoff_classification.enabled",
+        "thresholds.packoff_classification.abrupt_window_packets",
+        "thresholds.packoff_classification.abrupt_rise_fraction",
+        "thresholds.packoff_classification.reversal_fraction",
+        "thresholds.packoff_classification.ecd_trend_threshold",
         // [thresholds.rig_state]
         "thresholds.rig_state",
         "thresholds.rig_state.idle_rpm_max",
@@ -110,6 +134,7 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "thresholds.rig_state.trip_out_hook_load_min",
         "thresholds.rig_state.trip_in_hook_load_max",
         "thresholds.rig_state.tripping_flow_max",
+        "thresholds.rig_state.transition_debounce_packets",
         // [thresholds.operation_detection]
         "thresholds.operation_detection",
         "thresholds.operation_detection.milling_torque_min",
@@ -132,6 +157,13 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "thresholds.strategic_verification.trend_consistency_threshold",
         "thresholds.strategic_verification.formation_trend_consistency",
         "thresholds.strategic_verification.dxc_change_threshold",
+        // [thresholds.tripping]
+        "thresholds.tripping",
+        "thresholds.tripping.pipe_velocity_threshold_ft_min",
+        "thresholds.tripping.swab_margin_warning_ppg",
+        "thresholds.tripping.swab_margin_critical_ppg",
+        "thresholds.tripping.surge_margin_warning_ppg",
+        "thresholds.tripping.surge_margin_critical_ppg",
         // [baseline_learning]
         "baseline_learning",
         "baseline_learning.warning_sigma",
@@ -140,6 +172,22 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "baseline_learning.min_std_floor",
         "baseline_learning.max_outlier_percentage",
         "baseline_learning.outlier_sigma_threshold",
+        "baseline_learning.operational_lock_fraction",
+        "baseline_learning.quantization_check_enabled",
+        "baseline_learning.quantization_ratio",
+        "baseline_learning.widen_quantized_std_floor",
+        "baseline_learning.preview_mode",
+        "baseline_learning.override_flow_imbalance_sigma",
+        "baseline_learning.override_warning_sigma",
+        "baseline_learning.override_critical_sigma",
+        "baseline_learning.rederive_overrides_on_reload",
+        "baseline_learning.persist_accumulators",
+        "baseline_learning.accumulator_save_interval_samples",
+        "baseline_learning.stall_watchdog_enabled",
+        "baseline_learning.stall_warning_secs",
+        "baseline_learning.drift_alpha",
+        "baseline_learning.drift_widen_std",
+        "baseline_learning.drift_persist_interval_secs",
         // [advisory]
         "advisory",
         "advisory.default_cooldown_seconds",
@@ -152,6 +200,8 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "advisory.sustained_depth_multiplier",
         "advisory.sustained_max_depth_cooldown_ft",
         "advisory.sustained_reset_normal_count",
+        "advisory.ack_ttl_seconds",
+        "advisory.critical_ack_ttl_seconds",
         // [ensemble_weights]
         "ensemble_weights",
         "ensemble_weights.mse",
@@ -159,6 +209,13 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "ensemble_weights.well_control",
         "ensemble_weights.formation",
         "ensemble_weights.stuck_pipe",
+        // [orchestrator]
+        "orchestrator",
+        "orchestrator.mse",
+        "orchestrator.hydraulic",
+        "orchestrator.well_control",
+        "orchestrator.formation",
+        "orchestrator.stuck_pipe",
         // [trip_parameters]
         "trip_parameters",
         "trip_parameters.pipe_od_inches",
@@ -181,6 +238,8 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         // [ml]
         "ml",
         "ml.rop_lag_seconds",
+        "ml.gas_lag_seconds",
+        "ml.flow_lag_seconds",
         "ml.interval_secs",
         // [lookahead]
         "lookahead",
@@ -206,6 +265,278 @@ pub fn known_config_keys() -> HashSet<&'static str> {
         "gossip.interval_secs",
         "gossip.max_events_per_exchange",
         "gossip.timeout_secs",
+        "gossip.compress_uploads",
+        "gossip.store_max_bytes",
+        // [trapped_pressure]
+        "trapped_pressure",
+        "trapped_pressure.enabled",
+        "trapped_pressure.pump_on_flow_gpm",
+        "trapped_pressure.transient_window_secs",
+        "trapped_pressure.settle_tolerance_psi",
+        "trapped_pressure.min_connections_to_learn",
+        "trapped_pressure.sigma_threshold",
+        "trapped_pressure.max_learned_connections",
+        // [feedback]
+        "feedback",
+        "feedback.structured_prompts",
+        "feedback.well_control_prompt",
+        "feedback.drilling_efficiency_prompt",
+        "feedback.hydraulics_prompt",
+        "feedback.mechanical_prompt",
+        "feedback.formation_prompt",
+        "feedback.fp_source_min_rated",
+        "feedback.fp_source_suggest_rate",
+        "feedback.suppression_enabled",
+        "feedback.suppression_min_rated",
+        "feedback.suppression_confirmation_floor",
+        // [sidetrack]
+        "sidetrack",
+        "sidetrack.auto_detect",
+        "sidetrack.redrill_margin_ft",
+        "sidetrack.auto_detect_packets",
+        // [sensor_rate_limits]
+        "sensor_rate_limits",
+        "sensor_rate_limits.enabled",
+        "sensor_rate_limits.hold_glitches",
+        "sensor_rate_limits.wob_klbs_per_sec",
+        "sensor_rate_limits.rpm_per_sec",
+        "sensor_rate_limits.torque_kftlb_per_sec",
+        "sensor_rate_limits.spp_psi_per_sec",
+        "sensor_rate_limits.hook_load_klbs_per_sec",
+        "sensor_rate_limits.flow_in_gpm_per_sec",
+        // [candidate_tops]
+        "candidate_tops",
+        "candidate_tops.enabled",
+        "candidate_tops.cluster_window_ft",
+        "candidate_tops.match_prognosis",
+        "candidate_tops.match_tolerance_ft",
+        "candidate_tops.max_candidates",
+        // [lost_returns]
+        "lost_returns",
+        "lost_returns.enabled",
+        "lost_returns.min_flow_in_gpm",
+        "lost_returns.seepage_deficit_pct",
+        "lost_returns.partial_deficit_pct",
+        "lost_returns.total_deficit_pct",
+        "lost_returns.complete_deficit_pct",
+        "lost_returns.seepage_pit_loss_bbl_hr",
+        "lost_returns.partial_pit_loss_bbl_hr",
+        "lost_returns.total_pit_loss_bbl_hr",
+        "lost_returns.confirm_packets",
+        "lost_returns.clear_hysteresis_pct",
+        "lost_returns.report_cooldown_secs",
+        "lost_returns.detect_surface_losses",
+        "lost_returns.surface_window_packets",
+        "lost_returns.surface_spp_drop_pct",
+        "lost_returns.surface_ecd_tolerance_ppg",
+        // [bit_runs]
+        "bit_runs",
+        "bit_runs.correlate_advisories",
+        "bit_runs.wear_problem_threshold",
+        // [pipeline]
+        "pipeline",
+        "pipeline.decimate_to_hz",
+        // [pipeline_metrics]
+        "pipeline_metrics",
+        "pipeline_metrics.enabled",
+        "pipeline_metrics.max_lag_secs",
+        "pipeline_metrics.alert_cooldown_secs",
+        "pipeline_metrics.window_packets",
+        // [feed_flatline]
+        "feed_flatline",
+        "feed_flatline.enabled",
+        "feed_flatline.window_secs",
+        "feed_flatline.tolerance",
+        // [history_gaps]
+        "history_gaps",
+        "history_gaps.enabled",
+        "history_gaps.max_gap_secs",
+        // [mud_program]
+        "mud_program",
+        "mud_program.enabled",
+        "mud_program.tolerance_ppg",
+        "mud_program.alert_cooldown_secs",
+        // [equipment_limits]
+        "equipment_limits",
+        "equipment_limits.enabled",
+        "equipment_limits.approach_fraction",
+        "equipment_limits.max_wob_klbs",
+        "equipment_limits.max_rpm",
+        "equipment_limits.max_flow_gpm",
+        "equipment_limits.rearm_margin_fraction",
+        "equipment_limits.report_cooldown_secs",
+        // [flow_meter_check]
+        "flow_meter_check",
+        "flow_meter_check.enabled",
+        "flow_meter_check.pump_output_gal_per_stroke",
+        "flow_meter_check.min_spm",
+        "flow_meter_check.max_discrepancy_pct",
+        "flow_meter_check.sustain_secs",
+        // [stick_slip_trend]
+        "stick_slip_trend",
+        "stick_slip_trend.enabled",
+        "stick_slip_trend.cv_window_packets",
+        "stick_slip_trend.clear_hysteresis_pct",
+        "stick_slip_trend.damaging_after_samples",
+        "stick_slip_trend.trend_window",
+        "stick_slip_trend.trend_threshold_per_sample",
+        // [pump_events]
+        "pump_events",
+        "pump_events.enabled",
+        "pump_events.drop_pct",
+        "pump_events.min_flow_gpm",
+        "pump_events.recovery_pct",
+        "pump_events.settle_secs",
+        "pump_events.max_event_secs",
+        // [hardness_calibration]
+        "hardness_calibration",
+        "hardness_calibration.enabled",
+        "hardness_calibration.min_reports",
+        "hardness_calibration.min_sample_count",
+        "hardness_calibration.smoothing",
+        // [charts]
+        "charts",
+        "charts.enabled",
+        "charts.time_resolution_secs",
+        "charts.depth_resolution_ft",
+        "charts.max_points",
+        "charts.mse_log",
+        // [cfc_health]
+        "cfc_health",
+        "cfc_health.enabled",
+        "cfc_health.window_packets",
+        "cfc_health.loss_divergence_ratio",
+        "cfc_health.saturation_score",
+        "cfc_health.saturation_fraction",
+        "cfc_health.stuck_score_std",
+        "cfc_health.auto_reset",
+        // [sparklines]
+        "sparklines",
+        "sparklines.enabled",
+        "sparklines.length",
+        // [gas_lag]
+        "gas_lag",
+        "gas_lag.enabled",
+        "gas_lag.surface_volume_bbl",
+        "gas_lag.max_lag_secs",
+        // [gas_migration]
+        "gas_migration",
+        "gas_migration.enabled",
+        "gas_migration.pump_off_flow_gpm",
+        "gas_migration.min_static_secs",
+        "gas_migration.min_rise_psi",
+        "gas_migration.min_rate_psi_per_min",
+        "gas_migration.max_pipe_movement_ft",
+        // [directional]
+        "directional",
+        "directional.enabled",
+        "directional.slide_rpm_max",
+        "directional.slide_wob_min",
+        "directional.slide_flow_min_gpm",
+        "directional.slide_rop_ratio",
+        "directional.rotating_rop_window",
+        "directional.survey_min_secs",
+        "directional.survey_max_secs",
+        "directional.survey_max_pipe_movement_ft",
+        "directional.survey_max_off_bottom_ft",
+        "directional.max_logged",
+        // [kb_snapshots]
+        "kb_snapshots",
+        "kb_snapshots.enabled",
+        "kb_snapshots.retry_attempts",
+        "kb_snapshots.retry_backoff_ms",
+        "kb_snapshots.max_pending",
+        // [config_sanity]
+        "config_sanity",
+        "config_sanity.enabled",
+        "config_sanity.min_packets",
+        "config_sanity.bit_diameter_tolerance_in",
+        // [mpd]
+        "mpd",
+        "mpd.enabled",
+        "mpd.min_choke_move_pct",
+        "mpd.detect_window_secs",
+        "mpd.context_secs",
+        // [detection_benchmark]
+        "detection_benchmark",
+        "detection_benchmark.enabled",
+        "detection_benchmark.max_latency_secs",
+        // [optimization]
+        "optimization",
+        "optimization.max_wob_step_klbs",
+        // [connection_scorecard]
+        "connection_scorecard",
+        "connection_scorecard.enabled",
+        "connection_scorecard.max_connections",
+        "connection_scorecard.duration_cv_limit",
+        "connection_scorecard.gas_delta_limit_units",
+        "connection_scorecard.spp_overshoot_limit_pct",
+        "connection_scorecard.outlier_ratio",
+        // [bit_wear]
+        "bit_wear",
+        "bit_wear.new_hole_only",
+        // [debrief]
+        "debrief",
+        "debrief.cluster_advisories",
+        "debrief.cluster_distance_ft",
+        "debrief.min_cluster_size",
+        "debrief.threshold_recommendations",
+        // [cfc]
+        "cfc",
+        "cfc.fast_neurons",
+        "cfc.slow_neurons",
+        "cfc.inter_fraction",
+        "cfc.regime_merge_distance",
+        "cfc.regime_merge_interval_packets",
+        // [cfc_feature_sets]
+        "cfc_feature_sets",
+        "cfc_feature_sets.enabled",
+        "cfc_feature_sets.production",
+        "cfc_feature_sets.plug_abandonment",
+        "cfc_feature_sets.production.wob",
+        "cfc_feature_sets.production.rop",
+        "cfc_feature_sets.production.rpm",
+        "cfc_feature_sets.production.torque",
+        "cfc_feature_sets.production.mse",
+        "cfc_feature_sets.production.spp",
+        "cfc_feature_sets.production.d_exponent",
+        "cfc_feature_sets.production.hookload",
+        "cfc_feature_sets.production.ecd",
+        "cfc_feature_sets.production.flow_balance",
+        "cfc_feature_sets.production.pit_rate",
+        "cfc_feature_sets.production.dxc",
+        "cfc_feature_sets.production.pump_spm",
+        "cfc_feature_sets.production.mud_weight_in",
+        "cfc_feature_sets.production.gas_units",
+        "cfc_feature_sets.production.pit_volume",
+        "cfc_feature_sets.plug_abandonment.wob",
+        "cfc_feature_sets.plug_abandonment.rop",
+        "cfc_feature_sets.plug_abandonment.rpm",
+        "cfc_feature_sets.plug_abandonment.torque",
+        "cfc_feature_sets.plug_abandonment.mse",
+        "cfc_feature_sets.plug_abandonment.spp",
+        "cfc_feature_sets.plug_abandonment.d_exponent",
+        "cfc_feature_sets.plug_abandonment.hookload",
+        "cfc_feature_sets.plug_abandonment.ecd",
+        "cfc_feature_sets.plug_abandonment.flow_balance",
+        "cfc_feature_sets.plug_abandonment.pit_rate",
+        "cfc_feature_sets.plug_abandonment.dxc",
+        "cfc_feature_sets.plug_abandonment.pump_spm",
+        "cfc_feature_sets.plug_abandonment.mud_weight_in",
+        "cfc_feature_sets.plug_abandonment.gas_units",
+        "cfc_feature_sets.plug_abandonment.pit_volume",
+        // [episode_scoring]
+        "episode_scoring",
+        "episode_scoring.enabled",
+        "episode_scoring.rescore_interval_secs",
+        // [replay]
+        "replay",
+        "replay.eof_behavior",
+        // [acquisition]
+        "acquisition",
+        "acquisition.reconnect_base_ms",
+        "acquisition.reconnect_max_ms",
+        "acquisition.wits_item_map_path",
         // formation_tops (array of tables)
         "formation_tops",
     ];
@@ -386,6 +717,23 @@ pub fn validate_physical_ranges(
         });
     }
 
+    // Narrow mud weight window: static mud alone sits inside the ECD warning
+    // margin. Legitimate on narrow-margin wells, so not an error.
+    if h.fracture_gradient_ppg > h.normal_mud_weight_ppg
+        && h.ecd_margin_warning_ppg >= h.fracture_gradient_ppg - h.normal_mud_weight_ppg
+    {
+        warnings.push(ValidationWarning {
+            field: "thresholds.hydraulics.ecd_margin_warning_ppg".to_string(),
+            message: format!(
+                "ecd_margin_warning ({:.2}) is not less than the mud weight window \
+                 (fracture_gradient {:.2} - normal_mud_weight {:.2}); ECD margin \
+                 advisories will fire at static mud weight",
+                h.ecd_margin_warning_ppg, h.fracture_gradient_ppg, h.normal_mud_weight_ppg
+            ),
+            suggestion: None,
+        });
+    }
+
     // Flow imbalance: suspicious if outside 1-500 gpm
     let fi = config.thresholds.well_control.flow_imbalance_warning_gpm;
     if fi < 1.0 || fi > 500.0 {
@@ -473,6 +821,202 @@ pub fn validate_physical_ranges(
     (errors, warnings)
 }
 
+// ============================================================================
+// Cross-Field Validation
+// ============================================================================
+
+/// Push an error unless `critical >= warning` (both finite).
+fn check_escalation(warning: f64, critical: f64, name: &str, errors: &mut Vec<String>) {
+    // NaN/Inf comparisons silently pass — catch them explicitly
+    if !warning.is_finite() || !critical.is_finite() {
+        errors.push(format!(
+            "{name}: values must be finite (got warning={warning}, critical={critical})"
+        ));
+        return;
+    }
+    if critical < warning {
+        errors.push(format!(
+            "{name}: critical ({critical:.3}) must be >= warning ({warning:.3})"
+        ));
+    }
+}
+
+/// Validate invariants that span several fields.
+///
+/// Each warning/critical pair must escalate in the right direction, otherwise
+/// the warning would fire after the critical (or never). Also checks ranges
+/// that only make sense relative to each other, such as the fracture gradient
+/// sitting above the mud weight. Returns one descriptive error per violation;
+/// `WellConfig::validate()` runs this, so both startup and hot reload reject a
+/// config that breaks any of them.
+pub fn validate_cross_field(config: &super::WellConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    let t = &config.thresholds;
+
+    // Well control: critical >= warning
+    let wc = &t.well_control;
+    check_escalation(
+        wc.flow_imbalance_warning_gpm,
+        wc.flow_imbalance_critical_gpm,
+        "well_control.flow_imbalance",
+        &mut errors,
+    );
+    check_escalation(
+        wc.pit_gain_warning_bbl,
+        wc.pit_gain_critical_bbl,
+        "well_control.pit_gain",
+        &mut errors,
+    );
+    check_escalation(
+        wc.pit_rate_warning_bbl_hr,
+        wc.pit_rate_critical_bbl_hr,
+        "well_control.pit_rate",
+        &mut errors,
+    );
+    check_escalation(
+        wc.gas_units_warning,
+        wc.gas_units_critical,
+        "well_control.gas_units",
+        &mut errors,
+    );
+    check_escalation(
+        wc.h2s_warning_ppm,
+        wc.h2s_critical_ppm,
+        "well_control.h2s",
+        &mut errors,
+    );
+
+    // Hydraulics: warning margin > critical margin (lower margin = more dangerous)
+    let h = &t.hydraulics;
+    if h.ecd_margin_critical_ppg >= h.ecd_margin_warning_ppg {
+        errors.push(format!(
+            "hydraulics.ecd_margin_critical ({:.2}) must be less than ecd_margin_warning ({:.2})",
+            h.ecd_margin_critical_ppg, h.ecd_margin_warning_ppg
+        ));
+    }
+    check_escalation(
+        h.spp_deviation_warning_psi,
+        h.spp_deviation_critical_psi,
+        "hydraulics.spp_deviation",
+        &mut errors,
+    );
+
+    // Mud weight must sit below the fracture gradient (a window narrower
+    // than the ECD warning margin is only a warning, see
+    // `validate_physical_ranges`)
+    if h.fracture_gradient_ppg <= h.normal_mud_weight_ppg {
+        errors.push(format!(
+            "hydraulics.fracture_gradient_ppg ({:.2}) must be greater than normal_mud_weight_ppg ({:.2})",
+            h.fracture_gradient_ppg, h.normal_mud_weight_ppg
+        ));
+    }
+
+    // Pore pressure (when known) sits below the fracture gradient, and an
+    // auto-detected fracture gradient must clear the ECD warning margin
+    if h.pore_pressure_ppg < 0.0 {
+        errors.push(format!(
+            "hydraulics.pore_pressure_ppg ({:.2}) cannot be negative (0 = unknown)",
+            h.pore_pressure_ppg
+        ));
+    } else if h.pore_pressure_ppg > 0.0 && h.pore_pressure_ppg >= h.fracture_gradient_ppg {
+        errors.push(format!(
+            "hydraulics.pore_pressure_ppg ({:.2}) must be less than fracture_gradient_ppg ({:.2})",
+            h.pore_pressure_ppg, h.fracture_gradient_ppg
+        ));
+    }
+    if h.auto_pore_fracture && h.auto_fracture_offset_ppg <= h.ecd_margin_warning_ppg {
+        errors.push(format!(
+            "hydraulics.auto_fracture_offset_ppg ({:.2}) must be greater than ecd_margin_warning ({:.2})",
+            h.auto_fracture_offset_ppg, h.ecd_margin_warning_ppg
+        ));
+    }
+
+    // Tripping: same margin ordering as ECD
+    let tr = &t.tripping;
+    if tr.swab_margin_critical_ppg >= tr.swab_margin_warning_ppg {
+        errors.push(format!(
+            "tripping.swab_margin_critical ({:.2}) must be less than swab_margin_warning ({:.2})",
+            tr.swab_margin_critical_ppg, tr.swab_margin_warning_ppg
+        ));
+    }
+    if tr.surge_margin_critical_ppg >= tr.surge_margin_warning_ppg {
+        errors.push(format!(
+            "tripping.surge_margin_critical ({:.2}) must be less than surge_margin_warning ({:.2})",
+            tr.surge_margin_critical_ppg, tr.surge_margin_warning_ppg
+        ));
+    }
+
+    // Mechanical
+    check_escalation(
+        t.mechanical.torque_increase_warning,
+        t.mechanical.torque_increase_critical,
+        "mechanical.torque_increase",
+        &mut errors,
+    );
+    check_escalation(
+        t.mechanical.stick_slip_cv_warning,
+        t.mechanical.stick_slip_cv_critical,
+        "mechanical.stick_slip_cv",
+        &mut errors,
+    );
+
+    // Founder
+    check_escalation(
+        t.founder.severity_warning,
+        t.founder.severity_high,
+        "founder.severity",
+        &mut errors,
+    );
+
+    // MSE: optimal > warning > poor
+    if t.mse.efficiency_warning_percent <= t.mse.efficiency_poor_percent {
+        errors.push(format!(
+            "mse.efficiency_warning ({:.0}) must be > efficiency_poor ({:.0})",
+            t.mse.efficiency_warning_percent, t.mse.efficiency_poor_percent
+        ));
+    }
+
+    // Baseline learning sigmas
+    let bl = &config.baseline_learning;
+    if bl.critical_sigma <= bl.warning_sigma {
+        errors.push(format!(
+            "baseline_learning.critical_sigma ({:.1}) must be > warning_sigma ({:.1})",
+            bl.critical_sigma, bl.warning_sigma
+        ));
+    }
+    if bl.override_critical_sigma < bl.override_warning_sigma {
+        errors.push(format!(
+            "baseline_learning.override_critical_sigma ({:.1}) must be >= \
+             override_warning_sigma ({:.1})",
+            bl.override_critical_sigma, bl.override_warning_sigma
+        ));
+    }
+
+    // Advisory: critical tickets may repeat sooner, never later
+    let adv = &config.advisory;
+    if adv.critical_packet_cooldown > adv.packet_cooldown {
+        errors.push(format!(
+            "advisory.critical_packet_cooldown ({}) must be <= packet_cooldown ({})",
+            adv.critical_packet_cooldown, adv.packet_cooldown
+        ));
+    }
+    if adv.critical_depth_cooldown_ft > adv.depth_cooldown_ft {
+        errors.push(format!(
+            "advisory.critical_depth_cooldown_ft ({:.1}) must be <= depth_cooldown_ft ({:.1})",
+            adv.critical_depth_cooldown_ft, adv.depth_cooldown_ft
+        ));
+    }
+    // 0 = never expires, for either TTL
+    if adv.ack_ttl_seconds > 0 && adv.critical_ack_ttl_seconds > adv.ack_ttl_seconds {
+        errors.push(format!(
+            "advisory.critical_ack_ttl_seconds ({}) must be <= ack_ttl_seconds ({}) or 0 (never expires)",
+            adv.critical_ack_ttl_seconds, adv.ack_ttl_seconds
+        ));
+    }
+
+    errors
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -610,6 +1154,126 @@ some_field = 42
         assert!(known.contains("ml.rop_lag_seconds"));
     }
 
+    #[test]
+    fn test_cross_field_default_config_is_consistent() {
+        let errors = validate_cross_field(&crate::config::WellConfig::default());
+        assert!(errors.is_empty(), "Default config should pass: {errors:?}");
+    }
+
+    #[test]
+    fn test_cross_field_each_inverted_pair_is_reported() {
+        type Invert = fn(&mut crate::config::WellConfig);
+        let cases: &[(Invert, &str)] = &[
+            (
+                |c| c.thresholds.well_control.flow_imbalance_critical_gpm = 1.0,
+                "well_control.flow_imbalance: critical",
+            ),
+            (
+                |c| c.thresholds.well_control.pit_gain_critical_bbl = 0.1,
+                "well_control.pit_gain: critical",
+            ),
+            (
+                |c| c.thresholds.well_control.pit_rate_critical_bbl_hr = 0.1,
+                "well_control.pit_rate: critical",
+            ),
+            (
+                |c| c.thresholds.well_control.gas_units_critical = 1.0,
+                "well_control.gas_units: critical",
+            ),
+            (
+                |c| c.thresholds.well_control.h2s_critical_ppm = 1.0,
+                "well_control.h2s: critical",
+            ),
+            (
+                |c| c.thresholds.hydraulics.ecd_margin_critical_ppg = 0.5,
+                "ecd_margin_critical (0.50) must be less than ecd_margin_warning (0.30)",
+            ),
+            (
+                |c| c.thresholds.hydraulics.spp_deviation_warning_psi = 1000.0,
+                "hydraulics.spp_deviation: critical",
+            ),
+            (
+                |c| c.thresholds.hydraulics.fracture_gradient_ppg = 8.0,
+                "fracture_gradient_ppg (8.00) must be greater than normal_mud_weight_ppg (8.60)",
+            ),
+            (
+                |c| c.thresholds.hydraulics.pore_pressure_ppg = 15.0,
+                "pore_pressure_ppg (15.00) must be less than fracture_gradient_ppg (14.00)",
+            ),
+            (
+                |c| {
+                    c.thresholds.hydraulics.auto_pore_fracture = true;
+                    c.thresholds.hydraulics.auto_fracture_offset_ppg = 0.2;
+                },
+                "auto_fracture_offset_ppg (0.20) must be greater than ecd_margin_warning (0.30)",
+            ),
+            (
+                |c| c.thresholds.tripping.swab_margin_critical_ppg = 5.0,
+                "tripping.swab_margin_critical",
+            ),
+            (
+                |c| c.thresholds.tripping.surge_margin_critical_ppg = 5.0,
+                "tripping.surge_margin_critical",
+            ),
+            (
+                |c| c.thresholds.mechanical.torque_increase_critical = 0.0,
+                "mechanical.torque_increase: critical",
+            ),
+            (
+                |c| c.thresholds.mechanical.stick_slip_cv_critical = 0.0,
+                "mechanical.stick_slip_cv: critical",
+            ),
+            (
+                |c| c.thresholds.founder.severity_high = 0.0,
+                "founder.severity: critical",
+            ),
+            (
+                |c| c.thresholds.mse.efficiency_poor_percent = 90.0,
+                "mse.efficiency_warning (70) must be > efficiency_poor (90)",
+            ),
+            (
+                |c| c.baseline_learning.critical_sigma = 1.0,
+                "baseline_learning.critical_sigma",
+            ),
+            (
+                |c| c.baseline_learning.override_critical_sigma = 0.1,
+                "baseline_learning.override_critical_sigma",
+            ),
+            (
+                |c| c.advisory.critical_packet_cooldown = 1_000,
+                "advisory.critical_packet_cooldown (1000) must be <= packet_cooldown",
+            ),
+            (
+                |c| c.advisory.critical_depth_cooldown_ft = 1_000.0,
+                "advisory.critical_depth_cooldown_ft (1000.0) must be <= depth_cooldown_ft",
+            ),
+            (
+                |c| c.advisory.critical_ack_ttl_seconds = 20_000,
+                "advisory.critical_ack_ttl_seconds (20000) must be <= ack_ttl_seconds (14400)",
+            ),
+        ];
+
+        for (invert, expected) in cases {
+            let mut config = crate::config::WellConfig::default();
+            invert(&mut config);
+            let errors = validate_cross_field(&config);
+            assert_eq!(
+                errors.len(),
+                1,
+                "expected one error for {expected:?}: {errors:?}"
+            );
+            assert!(
+                errors[0].contains(expected),
+                "error {:?} should mention {expected:?}",
+                errors[0]
+            );
+            assert!(
+                config.validate().is_err(),
+                "validate() must reject {expected:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_physical_range_mud_weight_too_low() {
         let mut config = crate::config::WellConfig::default();
@@ -631,6 +1295,36 @@ some_field = 42
         assert!(mud_errors.is_empty(), "Mud weight 8.6 should be valid");
     }
 
+    #[test]
+    fn test_narrow_mud_window_is_a_warning_not_an_error() {
+        type Narrow = fn(&mut crate::config::WellConfig);
+        let cases: &[Narrow] = &[
+            |c| c.thresholds.hydraulics.ecd_margin_warning_ppg = 6.0,
+            |c| c.thresholds.hydraulics.normal_mud_weight_ppg = 13.8,
+        ];
+        for narrow in cases {
+            let mut config = crate::config::WellConfig::default();
+            narrow(&mut config);
+            let (_, warnings) = validate_physical_ranges(&config);
+            assert!(
+                warnings
+                    .iter()
+                    .any(|w| w.message.contains("not less than the mud weight window")),
+                "narrow window should warn: {warnings:?}"
+            );
+            assert!(validate_cross_field(&config).is_empty());
+            assert!(config.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_critical_ack_ttl_zero_never_expires_is_valid() {
+        let mut config = crate::config::WellConfig::default();
+        config.advisory.critical_ack_ttl_seconds = 0;
+        assert!(validate_cross_field(&config).is_empty());
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_physical_range_flow_imbalance_suspicious() {
         let mut config = crate::config::WellConfig::default();
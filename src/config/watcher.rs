@@ -1,13 +1,15 @@
 //! Polling-based config file watcher.
 //!
 //! Checks the config file's mtime every 2 seconds. When a change is detected,
-//! debounces for 500ms (to handle partial writes from editors), then calls
-//! `config::reload()` and sends the result via an mpsc channel.
+//! polls faster until the file has been stable for the configured quiet
+//! period (`server.config_reload_quiet_ms`, to collapse the partial writes
+//! editors make), then calls `config::reload()` once and sends the result via
+//! an mpsc channel.
 //!
 //! Consistent with the existing KB watcher pattern — no external crate needed.
 
 use std::path::PathBuf;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 
 use tokio::sync::mpsc;
 
@@ -25,60 +27,108 @@ pub enum ConfigEvent {
 /// Interval between mtime checks.
 const POLL_INTERVAL: Duration = Duration::from_secs(2);
 
-/// Debounce delay after detecting a change (editors often write in stages).
-const DEBOUNCE_DELAY: Duration = Duration::from_millis(500);
+/// Interval between mtime checks while a change is settling.
+const SETTLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Collapses a burst of mtime changes into a single reload.
+///
+/// Each new mtime restarts the quiet period; a reload is due only once the
+/// file has kept the same mtime for `quiet`. An editor that writes, truncates
+/// and writes again therefore triggers one reload against the final content.
+#[derive(Debug)]
+pub struct ReloadDebouncer {
+    quiet: Duration,
+    last_loaded: Option<SystemTime>,
+    pending: Option<(SystemTime, Instant)>,
+}
+
+impl ReloadDebouncer {
+    /// Start from the mtime of the currently loaded file.
+    pub fn new(loaded_mtime: Option<SystemTime>, quiet: Duration) -> Self {
+        Self {
+            quiet,
+            last_loaded: loaded_mtime,
+            pending: None,
+        }
+    }
+
+    /// Set the quiet period (applies from the next observation).
+    pub fn set_quiet(&mut self, quiet: Duration) {
+        self.quiet = quiet;
+    }
+
+    /// Whether a change is waiting for its quiet period.
+    pub fn is_settling(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Feed the file's mtime observed at `now`; returns true when a reload
+    /// is due. `None` (file missing) abandons any pending change.
+    pub fn observe(&mut self, mtime: Option<SystemTime>, now: Instant) -> bool {
+        let Some(mtime) = mtime else {
+            self.last_loaded = None;
+            self.pending = None;
+            return false;
+        };
+
+        match self.pending {
+            Some((pending, since)) if pending == mtime => {
+                if now.duration_since(since) >= self.quiet {
+                    self.last_loaded = Some(mtime);
+                    self.pending = None;
+                    return true;
+                }
+            }
+            // A further write: restart the quiet period
+            Some(_) => self.pending = Some((mtime, now)),
+            None if self.last_loaded != Some(mtime) => self.pending = Some((mtime, now)),
+            None => {}
+        }
+        false
+    }
+}
 
 /// Run the config file watcher loop.
 ///
-/// Polls `path` for mtime changes and reloads the global config when detected.
-/// Sends events on `tx`. Returns when the channel is closed or the task is cancelled.
+/// Polls `path` for mtime changes and reloads the global config once the
+/// file has been stable for `server.config_reload_quiet_ms`. `reload()`
+/// parses and validates the file before swapping, so a broken edit leaves
+/// the old config active. Sends one event per settled change on `tx`.
+/// Returns when the channel is closed or the task is cancelled.
 pub async fn run_config_watcher(path: PathBuf, tx: mpsc::Sender<ConfigEvent>) {
     tracing::info!(path = %path.display(), "Config watcher started");
 
-    let mut last_mtime = get_mtime(&path);
+    let mut debouncer = ReloadDebouncer::new(get_mtime(&path), quiet_period());
+    let mut file_present = true;
 
     loop {
-        tokio::time::sleep(POLL_INTERVAL).await;
+        let interval = if debouncer.is_settling() {
+            SETTLE_POLL_INTERVAL
+        } else {
+            POLL_INTERVAL
+        };
+        tokio::time::sleep(interval).await;
 
-        let current_mtime = get_mtime(&path);
+        let mtime = get_mtime(&path);
 
         // If we can't read mtime (file deleted, permissions), warn and keep polling
-        let current = match current_mtime {
-            Some(t) => t,
-            None => {
-                // Only warn if we previously had a valid mtime (file was deleted)
-                if last_mtime.is_some() {
-                    tracing::warn!(
-                        path = %path.display(),
-                        "Config file not accessible — keeping current config, will retry"
-                    );
-                    last_mtime = None;
-                }
-                continue;
+        if mtime.is_none() {
+            if file_present {
+                tracing::warn!(
+                    path = %path.display(),
+                    "Config file not accessible — keeping current config, will retry"
+                );
+                file_present = false;
             }
-        };
-
-        // If file reappeared after being gone, update mtime and reload
-        let changed = match last_mtime {
-            Some(prev) => current != prev,
-            None => true, // File reappeared
-        };
-
-        if !changed {
-            continue;
+        } else {
+            file_present = true;
         }
 
-        // Debounce: wait, then re-check mtime to ensure write is complete
-        tokio::time::sleep(DEBOUNCE_DELAY).await;
-
-        let stable_mtime = get_mtime(&path);
-        if stable_mtime != Some(current) {
-            // Still changing — wait for next poll cycle
+        debouncer.set_quiet(quiet_period());
+        if !debouncer.observe(mtime, Instant::now()) {
             continue;
         }
 
-        last_mtime = Some(current);
-
         // Reload
         let event = match super::reload() {
             Ok(changes) => ConfigEvent::Reloaded(changes),
@@ -95,7 +145,76 @@ pub async fn run_config_watcher(path: PathBuf, tx: mpsc::Sender<ConfigEvent>) {
     }
 }
 
+/// Quiet period from the active config (default before init)
+fn quiet_period() -> Duration {
+    let ms = if super::is_initialized() {
+        super::get().server.config_reload_quiet_ms
+    } else {
+        500
+    };
+    Duration::from_millis(ms)
+}
+
 /// Read the modification time of a file, returning None on any error.
 fn get_mtime(path: &PathBuf) -> Option<SystemTime> {
     std::fs::metadata(path).ok().and_then(|m| m.modified().ok())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapid_saves_trigger_one_reload_of_final_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("well_config.toml");
+        std::fs::write(&path, "[well]\nname = \"A\"\n").unwrap();
+
+        let quiet = Duration::from_millis(500);
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let start = Instant::now();
+        let mut debouncer = ReloadDebouncer::new(Some(epoch), quiet);
+        let mut reloads = Vec::new();
+
+        // Three saves 100 ms apart: write, truncate, write again
+        let writes = ["[well]\nname = \"B\"\n", "", "[well]\nname = \"C\"\n"];
+        let mut poll = |debouncer: &mut ReloadDebouncer, mtime: SystemTime, at_ms: u64| {
+            if debouncer.observe(Some(mtime), start + Duration::from_millis(at_ms)) {
+                reloads.push(std::fs::read_to_string(&path).unwrap());
+            }
+        };
+        for (i, content) in writes.iter().enumerate() {
+            std::fs::write(&path, content).unwrap();
+            let mtime = epoch + Duration::from_millis(100 * (i as u64 + 1));
+            poll(&mut debouncer, mtime, 100 * (i as u64 + 1));
+            poll(&mut debouncer, mtime, 100 * (i as u64 + 1) + 50);
+        }
+        let last = epoch + Duration::from_millis(300);
+
+        // Still inside the quiet period after the last write
+        poll(&mut debouncer, last, 700);
+        // Stable for the full quiet period: one reload, then nothing more
+        poll(&mut debouncer, last, 800);
+        poll(&mut debouncer, last, 900);
+        poll(&mut debouncer, last, 5_000);
+
+        assert_eq!(reloads, vec!["[well]\nname = \"C\"\n".to_string()]);
+    }
+
+    #[test]
+    fn test_missing_file_abandons_pending_change() {
+        let quiet = Duration::from_millis(500);
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let start = Instant::now();
+        let mut debouncer = ReloadDebouncer::new(Some(epoch), quiet);
+
+        let changed = epoch + Duration::from_secs(1);
+        assert!(!debouncer.observe(Some(changed), start));
+        assert!(!debouncer.observe(None, start + Duration::from_millis(600)));
+        assert!(!debouncer.is_settling());
+
+        // File back: settles and reloads
+        assert!(!debouncer.observe(Some(changed), start + Duration::from_secs(1)));
+        assert!(debouncer.observe(Some(changed), start + Duration::from_secs(2)));
+    }
+}
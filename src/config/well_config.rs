@@ -65,6 +65,10 @@ pub struct WellConfig {
     #[serde(default)]
     pub ensemble_weights: EnsembleWeightsConfig,
 
+    /// Orchestrator specialist set and vote weights (overrides `ensemble_weights`)
+    #[serde(default)]
+    pub orchestrator: OrchestratorConfig,
+
     /// Physics engine tuning constants
     #[serde(default)]
     pub physics: PhysicsConfig,
@@ -73,6 +77,10 @@ pub struct WellConfig {
     #[serde(default)]
     pub server: ServerConfig,
 
+    /// API versioning (v1 sunset)
+    #[serde(default)]
+    pub api: ApiConfig,
+
     /// ML engine tuning
     #[serde(default)]
     pub ml: MlConfig,
@@ -97,6 +105,150 @@ pub struct WellConfig {
     #[serde(default)]
     pub trip_parameters: TripParameters,
 
+    /// Post-connection trapped-pressure detection
+    #[serde(default)]
+    pub trapped_pressure: TrappedPressureConfig,
+
+    /// Operator feedback prompts
+    #[serde(default)]
+    pub feedback: FeedbackConfig,
+
+    /// Sidetrack / multi-lateral wellbore branching
+    #[serde(default)]
+    pub sidetrack: SidetrackConfig,
+
+    /// Per-channel rate-of-change limits (single-sample glitch filter)
+    #[serde(default)]
+    pub sensor_rate_limits: SensorRateLimitConfig,
+
+    /// Candidate formation tops from detected transitions
+    #[serde(default)]
+    pub candidate_tops: CandidateTopsConfig,
+
+    /// Lost-returns severity staging (seepage → partial → total → complete)
+    #[serde(default)]
+    pub lost_returns: LostReturnsConfig,
+
+    /// Bit-run archive and dull-grade correlation
+    #[serde(default)]
+    pub bit_runs: BitRunConfig,
+
+    /// Input decimation for high-rate WITS feeds
+    #[serde(default)]
+    pub pipeline: PipelineConfig,
+
+    /// Streaming ingestion metrics and lag monitoring
+    #[serde(default)]
+    pub pipeline_metrics: PipelineMetricsConfig,
+
+    /// Whole-feed flatline (hung acquisition) detection
+    #[serde(default)]
+    pub feed_flatline: FeedFlatlineConfig,
+
+    /// Time-series gap handling in strategic analysis
+    #[serde(default)]
+    pub history_gaps: HistoryGapConfig,
+
+    /// Planned vs actual mud weight comparison
+    #[serde(default)]
+    pub mud_program: MudProgramConfig,
+
+    /// Rig equipment ratings (WOB, RPM, flow) and approach margin
+    #[serde(default)]
+    pub equipment_limits: EquipmentLimitsConfig,
+
+    /// Pump-derived expected flow vs reported flow_in
+    #[serde(default)]
+    pub flow_meter_check: FlowMeterCheckConfig,
+
+    /// Stick-slip severity classification and trend
+    #[serde(default)]
+    pub stick_slip_trend: StickSlipTrendConfig,
+
+    /// Connection-practices scorecard
+    #[serde(default)]
+    pub connection_scorecard: ConnectionScorecardConfig,
+
+    /// Bit wear / footage integration
+    #[serde(default)]
+    pub bit_wear: BitWearConfig,
+
+    /// Post-well debrief generation
+    #[serde(default)]
+    pub debrief: DebriefConfig,
+
+    /// CfC network sizing per hardware tier
+    #[serde(default)]
+    pub cfc: CfcConfig,
+
+    /// Campaign-specific CfC training feature weights
+    #[serde(default)]
+    pub cfc_feature_sets: CfcFeatureSetsConfig,
+
+    /// Periodic re-scoring of fleet episodes against current benchmarks
+    #[serde(default)]
+    pub episode_scoring: EpisodeScoringConfig,
+
+    /// What a CSV replay does when it runs out of packets
+    #[serde(default)]
+    pub replay: ReplayConfig,
+
+    /// WITS TCP link reconnection backoff
+    #[serde(default)]
+    pub acquisition: AcquisitionConfig,
+
+    /// Pump trip / changeover detection and flow-alarm suppression
+    #[serde(default)]
+    pub pump_events: PumpEventConfig,
+
+    /// Formation-hardness calibration from ML analysis reports
+    #[serde(default)]
+    pub hardness_calibration: HardnessCalibrationConfig,
+
+    /// Time-depth and ROP-depth chart series
+    #[serde(default)]
+    pub charts: ChartsConfig,
+
+    /// CfC network drift / degradation monitoring
+    #[serde(default)]
+    pub cfc_health: CfcHealthConfig,
+
+    /// Trend sparklines attached to advisories
+    #[serde(default)]
+    pub sparklines: SparklineConfig,
+
+    /// Mud-logging lag correction of gas advisories to source depth
+    #[serde(default)]
+    pub gas_lag: GasLagConfig,
+
+    /// Gas-migration detection during pumps-off periods
+    #[serde(default)]
+    pub gas_migration: GasMigrationConfig,
+
+    /// Slide / rotate / survey detection for directional wells
+    #[serde(default)]
+    pub directional: DirectionalConfig,
+
+    /// Retry / buffering of failed knowledge-base snapshot writes
+    #[serde(default)]
+    pub kb_snapshots: KbSnapshotConfig,
+
+    /// Startup check that the config matches the well being drilled
+    #[serde(default)]
+    pub config_sanity: ConfigSanityConfig,
+
+    /// MPD choke adjustment detection (contextualizes pressure changes)
+    #[serde(default)]
+    pub mpd: MpdConfig,
+
+    /// Detection latency against injected faults (synthetic data)
+    #[serde(default)]
+    pub detection_benchmark: DetectionBenchmarkConfig,
+
+    /// ROP-maximizing WOB step recommendations
+    #[serde(default)]
+    pub optimization: OptimizationConfig,
+
     /// Formation tops table (depth -> formation name)
     #[serde(default)]
     pub formation_tops: Vec<FormationTop>,
@@ -110,14 +262,52 @@ impl Default for WellConfig {
             baseline_learning: BaselineLearningConfig::default(),
             advisory: AdvisoryConfig::default(),
             ensemble_weights: EnsembleWeightsConfig::default(),
+            orchestrator: OrchestratorConfig::default(),
             physics: PhysicsConfig::default(),
             server: ServerConfig::default(),
+            api: ApiConfig::default(),
             ml: MlConfig::default(),
             lookahead: LookaheadConfig::default(),
             damping: DampingConfig::default(),
             mesh: MeshConfig::default(),
             gossip: GossipConfig::default(),
             trip_parameters: TripParameters::default(),
+            trapped_pressure: TrappedPressureConfig::default(),
+            feedback: FeedbackConfig::default(),
+            sidetrack: SidetrackConfig::default(),
+            sensor_rate_limits: SensorRateLimitConfig::default(),
+            candidate_tops: CandidateTopsConfig::default(),
+            lost_returns: LostReturnsConfig::default(),
+            bit_runs: BitRunConfig::default(),
+            pipeline: PipelineConfig::default(),
+            pipeline_metrics: PipelineMetricsConfig::default(),
+            feed_flatline: FeedFlatlineConfig::default(),
+            history_gaps: HistoryGapConfig::default(),
+            mud_program: MudProgramConfig::default(),
+            equipment_limits: EquipmentLimitsConfig::default(),
+            flow_meter_check: FlowMeterCheckConfig::default(),
+            stick_slip_trend: StickSlipTrendConfig::default(),
+            connection_scorecard: ConnectionScorecardConfig::default(),
+            bit_wear: BitWearConfig::default(),
+            debrief: DebriefConfig::default(),
+            cfc: CfcConfig::default(),
+            cfc_feature_sets: CfcFeatureSetsConfig::default(),
+            episode_scoring: EpisodeScoringConfig::default(),
+            replay: ReplayConfig::default(),
+            acquisition: AcquisitionConfig::default(),
+            pump_events: PumpEventConfig::default(),
+            hardness_calibration: HardnessCalibrationConfig::default(),
+            charts: ChartsConfig::default(),
+            cfc_health: CfcHealthConfig::default(),
+            sparklines: SparklineConfig::default(),
+            gas_lag: GasLagConfig::default(),
+            gas_migration: GasMigrationConfig::default(),
+            directional: DirectionalConfig::default(),
+            kb_snapshots: KbSnapshotConfig::default(),
+            config_sanity: ConfigSanityConfig::default(),
+            mpd: MpdConfig::default(),
+            detection_benchmark: DetectionBenchmarkConfig::default(),
+            optimization: OptimizationConfig::default(),
             formation_tops: Vec::new(),
         }
     }
@@ -262,7 +452,8 @@ impl WellConfig {
     /// Validate all thresholds for internal consistency.
     ///
     /// Rules:
-    /// - Critical thresholds must be >= warning thresholds (for absolute values)
+    /// - Warning/critical pairs must escalate in the right direction
+    ///   (see `validation::validate_cross_field`)
     /// - Ensemble weights must sum to approximately 1.0
     /// - Sigma multipliers must be positive
     /// - Min-samples must be > 0
@@ -270,84 +461,58 @@ impl WellConfig {
         let t = &self.thresholds;
         let mut errors: Vec<String> = Vec::new();
 
-        // Well control: critical >= warning
-        Self::check_escalation(
-            t.well_control.flow_imbalance_warning_gpm,
-            t.well_control.flow_imbalance_critical_gpm,
-            "well_control.flow_imbalance",
-            &mut errors,
-        );
-        Self::check_escalation(
-            t.well_control.pit_gain_warning_bbl,
-            t.well_control.pit_gain_critical_bbl,
-            "well_control.pit_gain",
-            &mut errors,
-        );
-        Self::check_escalation(
-            t.well_control.pit_rate_warning_bbl_hr,
-            t.well_control.pit_rate_critical_bbl_hr,
-            "well_control.pit_rate",
-            &mut errors,
-        );
-        Self::check_escalation(
-            t.well_control.gas_units_warning,
-            t.well_control.gas_units_critical,
-            "well_control.gas_units",
-            &mut errors,
-        );
-        Self::check_escalation(
-            t.well_control.h2s_warning_ppm,
-            t.well_control.h2s_critical_ppm,
-            "well_control.h2s",
-            &mut errors,
-        );
+        // Warning/critical ordering and other cross-field invariants
+        errors.extend(super::validation::validate_cross_field(self));
 
-        // Hydraulics: warning margin > critical margin (lower margin = more dangerous)
-        if t.hydraulics.ecd_margin_critical_ppg >= t.hydraulics.ecd_margin_warning_ppg {
+        if !(0.0..=0.05).contains(&t.hydraulics.mud_thermal_expansion_ppg_per_degf) {
             errors.push(format!(
-                "hydraulics.ecd_margin_critical ({:.2}) must be less than ecd_margin_warning ({:.2})",
-                t.hydraulics.ecd_margin_critical_ppg, t.hydraulics.ecd_margin_warning_ppg
+                "hydraulics.mud_thermal_expansion_ppg_per_degf ({}) must be in [0, 0.05]",
+                t.hydraulics.mud_thermal_expansion_ppg_per_degf
             ));
         }
 
-        // SPP deviations
-        Self::check_escalation(
-            t.hydraulics.spp_deviation_warning_psi,
-            t.hydraulics.spp_deviation_critical_psi,
-            "hydraulics.spp_deviation",
-            &mut errors,
-        );
-
         // Mechanical
-        Self::check_escalation(
-            t.mechanical.torque_increase_warning,
-            t.mechanical.torque_increase_critical,
-            "mechanical.torque_increase",
-            &mut errors,
-        );
-        Self::check_escalation(
-            t.mechanical.stick_slip_cv_warning,
-            t.mechanical.stick_slip_cv_critical,
-            "mechanical.stick_slip_cv",
-            &mut errors,
-        );
+        if !(t.mechanical.washout_spp_decline_percent > 0.0
+            && t.mechanical.washout_spp_decline_percent < 100.0)
+        {
+            errors.push(format!(
+                "mechanical.washout_spp_decline_percent ({:.1}) must be in (0, 100)",
+                t.mechanical.washout_spp_decline_percent
+            ));
+        }
+        if t.mechanical.washout_flat_tolerance_percent <= 0.0 {
+            errors.push("mechanical.washout_flat_tolerance_percent must be > 0".to_string());
+        }
 
         // Founder
-        Self::check_escalation(
-            t.founder.severity_warning,
-            t.founder.severity_high,
-            "founder.severity",
-            &mut errors,
-        );
         if t.founder.min_samples == 0 {
             errors.push("founder.min_samples must be > 0".to_string());
         }
 
-        // MSE: optimal > warning > poor
-        if t.mse.efficiency_warning_percent <= t.mse.efficiency_poor_percent {
+        // Drilling break
+        if t.drilling_break.rop_increase_factor <= 1.0 {
+            errors.push(format!(
+                "drilling_break.rop_increase_factor ({:.2}) must be > 1.0",
+                t.drilling_break.rop_increase_factor
+            ));
+        }
+        if t.drilling_break.baseline_packets == 0 || t.drilling_break.sustain_packets == 0 {
+            errors.push(
+                "drilling_break.baseline_packets and sustain_packets must be > 0".to_string(),
+            );
+        }
+
+        // Pack-off classification
+        let pc = &t.packoff_classification;
+        if pc.abrupt_window_packets == 0 {
+            errors.push("packoff_classification.abrupt_window_packets must be > 0".to_string());
+        }
+        if !(0.0..=1.0).contains(&pc.abrupt_rise_fraction)
+            || !(0.0..=1.0).contains(&pc.reversal_fraction)
+        {
             errors.push(format!(
-                "mse.efficiency_warning ({:.0}) must be > efficiency_poor ({:.0})",
-                t.mse.efficiency_warning_percent, t.mse.efficiency_poor_percent
+                "packoff_classification.abrupt_rise_fraction ({:.2}) and reversal_fraction ({:.2}) must be in [0, 1]",
+                pc.abrupt_rise_fraction, pc.reversal_fraction
             ));
         }
 
@@ -361,20 +526,64 @@ impl WellConfig {
             ));
         }
 
+        // Orchestrator weights: non-negative, and WellControl always votes
+        // (its CRITICAL override depends on it being consulted)
+        for (key, weight) in self.orchestrator.configured() {
+            if !weight.is_finite() || weight < 0.0 {
+                errors.push(format!(
+                    "orchestrator.{} must be a finite weight >= 0, got {}",
+                    key, weight
+                ));
+            }
+        }
+        if self.specialist_weight("well_control") <= 0.0 {
+            errors.push(
+                "well_control weight ([orchestrator] or [ensemble_weights]) must be > 0"
+                    .to_string(),
+            );
+        }
+
+        // Feedback suppression floor is a confirmation rate
+        let fb = &self.feedback;
+        if !(0.0..=1.0).contains(&fb.suppression_confirmation_floor) {
+            errors.push(format!(
+                "feedback.suppression_confirmation_floor must be in [0, 1], got {}",
+                fb.suppression_confirmation_floor
+            ));
+        }
+
         // Baseline learning
         let bl = &self.baseline_learning;
         if bl.warning_sigma <= 0.0 {
             errors.push("baseline_learning.warning_sigma must be > 0".to_string());
         }
-        if bl.critical_sigma <= bl.warning_sigma {
-            errors.push(format!(
-                "baseline_learning.critical_sigma ({:.1}) must be > warning_sigma ({:.1})",
-                bl.critical_sigma, bl.warning_sigma
-            ));
+        if bl.override_flow_imbalance_sigma <= 0.0 || bl.override_warning_sigma <= 0.0 {
+            errors.push(
+                "baseline_learning override_flow_imbalance_sigma and override_warning_sigma \
+                 must be > 0"
+                    .to_string(),
+            );
         }
         if bl.min_samples_for_lock == 0 {
             errors.push("baseline_learning.min_samples_for_lock must be > 0".to_string());
         }
+        if bl.operational_lock_fraction <= 0.0 || bl.operational_lock_fraction > 1.0 {
+            errors.push("baseline_learning.operational_lock_fraction must be in (0, 1]".to_string());
+        }
+        if bl.quantization_ratio <= 0.0 {
+            errors.push("baseline_learning.quantization_ratio must be > 0".to_string());
+        }
+        if bl.accumulator_save_interval_samples == 0 {
+            errors.push(
+                "baseline_learning.accumulator_save_interval_samples must be > 0".to_string(),
+            );
+        }
+        if bl.stall_watchdog_enabled && bl.stall_warning_secs == 0 {
+            errors.push("baseline_learning.stall_warning_secs must be > 0".to_string());
+        }
+        if !(0.0..1.0).contains(&bl.drift_alpha) {
+            errors.push("baseline_learning.drift_alpha must be in [0, 1)".to_string());
+        }
 
         // Physics: divisors must be positive (used in division)
         let p = &self.physics;
@@ -420,117 +629,603 @@ impl WellConfig {
             errors.push("trip_parameters.yield_point must be >= 0".to_string());
         }
 
-        // Physical range validation
-        let (range_errors, range_warnings) = super::validation::validate_physical_ranges(self);
-        errors.extend(range_errors);
-        for w in &range_warnings {
-            warn!("{}", w);
+        // Trapped pressure detection
+        let tpc = &self.trapped_pressure;
+        if tpc.sigma_threshold <= 0.0 {
+            errors.push("trapped_pressure.sigma_threshold must be > 0".to_string());
+        }
+        if tpc.transient_window_secs == 0 {
+            errors.push("trapped_pressure.transient_window_secs must be > 0".to_string());
+        }
+        if tpc.max_learned_connections < tpc.min_connections_to_learn {
+            errors.push(format!(
+                "trapped_pressure.max_learned_connections ({}) must be >= min_connections_to_learn ({})",
+                tpc.max_learned_connections, tpc.min_connections_to_learn
+            ));
         }
 
-        // Reject NaN/Inf in any config value (sweep all f64 fields via TOML value tree)
-        if let Ok(s) = toml::to_string(self) {
-            if let Ok(toml_val) = s.parse::<toml::Value>() {
-                if Self::has_non_finite_float(&toml_val) {
-                    errors.push(
-                        "Config contains NaN or Inf values — all thresholds must be finite numbers"
-                            .to_string(),
-                    );
-                }
+        // Sidetrack detection
+        let st = &self.sidetrack;
+        if st.redrill_margin_ft <= 0.0 {
+            errors.push("sidetrack.redrill_margin_ft must be > 0".to_string());
+        }
+        if st.auto_detect_packets == 0 {
+            errors.push("sidetrack.auto_detect_packets must be > 0".to_string());
+        }
+
+        // Sensor rate-of-change limits
+        for (channel, limit) in self.sensor_rate_limits.channel_limits() {
+            if limit.is_nan() || limit < 0.0 {
+                errors.push(format!(
+                    "sensor_rate_limits.{} must be >= 0 (0 disables the channel)",
+                    channel
+                ));
             }
         }
 
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(ConfigError::Validation(errors))
+        // Candidate formation tops
+        let ct = &self.candidate_tops;
+        if ct.cluster_window_ft <= 0.0 {
+            errors.push("candidate_tops.cluster_window_ft must be > 0".to_string());
+        }
+        if ct.match_tolerance_ft <= 0.0 {
+            errors.push("candidate_tops.match_tolerance_ft must be > 0".to_string());
         }
-    }
 
-    fn has_non_finite_float(val: &toml::Value) -> bool {
-        match val {
-            toml::Value::Float(f) => f.is_nan() || f.is_infinite(),
-            toml::Value::Table(t) => t.values().any(Self::has_non_finite_float),
-            toml::Value::Array(a) => a.iter().any(Self::has_non_finite_float),
-            _ => false,
+        // Lost-returns staging: bands must increase with severity
+        let lr = &self.lost_returns;
+        let deficit_bands = [
+            lr.seepage_deficit_pct,
+            lr.partial_deficit_pct,
+            lr.total_deficit_pct,
+            lr.complete_deficit_pct,
+        ];
+        if lr.seepage_deficit_pct <= 0.0
+            || lr.complete_deficit_pct > 100.0
+            || deficit_bands.windows(2).any(|w| w[0] >= w[1])
+        {
+            errors.push(
+                "lost_returns deficit bands must satisfy 0 < seepage < partial < total < complete <= 100"
+                    .to_string(),
+            );
+        }
+        let pit_bands = [
+            lr.seepage_pit_loss_bbl_hr,
+            lr.partial_pit_loss_bbl_hr,
+            lr.total_pit_loss_bbl_hr,
+        ];
+        if lr.seepage_pit_loss_bbl_hr <= 0.0 || pit_bands.windows(2).any(|w| w[0] >= w[1]) {
+            errors.push(
+                "lost_returns pit-loss bands must satisfy 0 < seepage < partial < total".to_string(),
+            );
+        }
+        if lr.confirm_packets == 0 {
+            errors.push("lost_returns.confirm_packets must be > 0".to_string());
+        }
+        if !(0.0..100.0).contains(&lr.clear_hysteresis_pct) {
+            errors.push("lost_returns.clear_hysteresis_pct must be in [0, 100)".to_string());
+        }
+        if lr.surface_window_packets < 2 {
+            errors.push("lost_returns.surface_window_packets must be >= 2".to_string());
         }
-    }
 
-    fn check_escalation(warning: f64, critical: f64, name: &str, errors: &mut Vec<String>) {
-        // NaN/Inf comparisons silently pass — catch them explicitly
-        if !warning.is_finite() || !critical.is_finite() {
+        // Dull-grade correlation (IADC wear is graded 0-8)
+        let br = &self.bit_runs;
+        if br.wear_problem_threshold == 0 || br.wear_problem_threshold > 8 {
             errors.push(format!(
-                "{name}: values must be finite (got warning={warning}, critical={critical})"
+                "bit_runs.wear_problem_threshold ({}) must be in 1-8",
+                br.wear_problem_threshold
             ));
-            return;
         }
-        if critical < warning {
+
+        // Pipeline lag monitoring
+        if self.pipeline.decimate_to_hz < 0.0 {
+            errors.push("pipeline.decimate_to_hz must be >= 0 (0 = off)".to_string());
+        }
+        let pm = &self.pipeline_metrics;
+        if pm.max_lag_secs <= 0.0 {
+            errors.push("pipeline_metrics.max_lag_secs must be > 0".to_string());
+        }
+        if pm.window_packets < 2 {
+            errors.push("pipeline_metrics.window_packets must be >= 2".to_string());
+        }
+
+        // Feed flatline detection
+        if self.feed_flatline.window_secs == 0 {
+            errors.push("feed_flatline.window_secs must be > 0".to_string());
+        }
+        if self.feed_flatline.tolerance < 0.0 {
             errors.push(format!(
-                "{name}: critical ({critical:.3}) must be >= warning ({warning:.3})"
+                "feed_flatline.tolerance ({}) must be >= 0",
+                self.feed_flatline.tolerance
             ));
         }
-    }
-}
 
-// ============================================================================
-// Error Type
-// ============================================================================
+        // History gaps
+        if self.history_gaps.max_gap_secs == 0 {
+            errors.push("history_gaps.max_gap_secs must be > 0".to_string());
+        }
 
-#[derive(Debug)]
-pub enum ConfigError {
-    Io(PathBuf, std::io::Error),
-    Parse(PathBuf, toml::de::Error),
-    Serialize(toml::ser::Error),
-    Validation(Vec<String>),
-}
+        // Mud program comparison
+        if self.mud_program.tolerance_ppg <= 0.0 {
+            errors.push(format!(
+                "mud_program.tolerance_ppg ({}) must be > 0",
+                self.mud_program.tolerance_ppg
+            ));
+        }
 
-impl std::fmt::Display for ConfigError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ConfigError::Io(path, e) => write!(f, "Config I/O error ({}): {}", path.display(), e),
-            ConfigError::Parse(path, e) => {
-                write!(f, "Config parse error ({}): {}", path.display(), e)
-            }
-            ConfigError::Serialize(e) => write!(f, "Config serialization error: {}", e),
-            ConfigError::Validation(errors) => {
-                writeln!(f, "Config validation failed:")?;
-                for e in errors {
-                    writeln!(f, "  - {}", e)?;
-                }
-                Ok(())
+        // Equipment limits
+        if !(self.equipment_limits.approach_fraction > 0.0
+            && self.equipment_limits.approach_fraction < 1.0)
+        {
+            errors.push(format!(
+                "equipment_limits.approach_fraction ({}) must be in (0, 1)",
+                self.equipment_limits.approach_fraction
+            ));
+        }
+        if !(0.0..self.equipment_limits.approach_fraction)
+            .contains(&self.equipment_limits.rearm_margin_fraction)
+        {
+            errors.push(format!(
+                "equipment_limits.rearm_margin_fraction ({}) must be in [0, approach_fraction)",
+                self.equipment_limits.rearm_margin_fraction
+            ));
+        }
+        for (key, limit) in [
+            ("equipment_limits.max_wob_klbs", self.equipment_limits.max_wob_klbs),
+            ("equipment_limits.max_rpm", self.equipment_limits.max_rpm),
+            ("equipment_limits.max_flow_gpm", self.equipment_limits.max_flow_gpm),
+        ] {
+            if limit < 0.0 {
+                errors.push(format!("{} ({}) must be >= 0 (0 = not rated)", key, limit));
             }
         }
-    }
-}
-
-impl std::error::Error for ConfigError {}
 
-// ============================================================================
-// Well Info
-// ============================================================================
+        // Flow meter cross-check
+        let fm = &self.flow_meter_check;
+        if fm.pump_output_gal_per_stroke < 0.0 {
+            errors.push(format!(
+                "flow_meter_check.pump_output_gal_per_stroke ({}) must be >= 0 (0 = not configured)",
+                fm.pump_output_gal_per_stroke
+            ));
+        }
+        if fm.max_discrepancy_pct <= 0.0 || fm.max_discrepancy_pct >= 100.0 {
+            errors.push(format!(
+                "flow_meter_check.max_discrepancy_pct ({}) must be in (0, 100)",
+                fm.max_discrepancy_pct
+            ));
+        }
 
-/// Identification metadata — not used for logic, but appears in logs and reports.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WellInfo {
-    /// Well name / identifier
-    #[serde(default = "default_well_name")]
-    pub name: String,
+        // Stick-slip trend
+        let sst = &self.stick_slip_trend;
+        if !(0.0..100.0).contains(&sst.clear_hysteresis_pct) {
+            errors.push("stick_slip_trend.clear_hysteresis_pct must be in [0, 100)".to_string());
+        }
+        if sst.cv_window_packets < 2 {
+            errors.push(format!(
+                "stick_slip_trend.cv_window_packets ({}) must be >= 2",
+                sst.cv_window_packets
+            ));
+        }
+        if sst.damaging_after_samples == 0 {
+            errors.push("stick_slip_trend.damaging_after_samples must be >= 1".to_string());
+        }
 
-    /// Field name
-    #[serde(default)]
-    pub field: String,
+        // Pump events
+        let pe = &self.pump_events;
+        if !(pe.drop_pct > 0.0 && pe.drop_pct < 100.0) {
+            errors.push(format!(
+                "pump_events.drop_pct ({}) must be between 0 and 100",
+                pe.drop_pct
+            ));
+        }
+        if !(pe.recovery_pct > 0.0 && pe.recovery_pct <= 100.0) {
+            errors.push(format!(
+                "pump_events.recovery_pct ({}) must be in (0, 100]",
+                pe.recovery_pct
+            ));
+        }
 
-    /// Rig name
-    #[serde(default)]
-    pub rig: String,
+        // Hardness calibration
+        let hc = &self.hardness_calibration;
+        if !(hc.smoothing > 0.0 && hc.smoothing <= 1.0) {
+            errors.push(format!(
+                "hardness_calibration.smoothing ({}) must be in (0, 1]",
+                hc.smoothing
+            ));
+        }
+        if hc.min_reports == 0 {
+            errors.push("hardness_calibration.min_reports must be >= 1".to_string());
+        }
 
-    /// Bit diameter in inches (used by physics calculations)
-    #[serde(default = "default_bit_diameter")]
-    pub bit_diameter_inches: f64,
+        // Charts
+        if self.charts.depth_resolution_ft <= 0.0 {
+            errors.push(format!(
+                "charts.depth_resolution_ft ({}) must be > 0",
+                self.charts.depth_resolution_ft
+            ));
+        }
+        if self.charts.max_points < 2 {
+            errors.push(format!(
+                "charts.max_points ({}) must be >= 2",
+                self.charts.max_points
+            ));
+        }
 
-    /// Campaign type: "production" or "plug_abandonment"
-    #[serde(default = "default_campaign")]
-    pub campaign: String,
-}
+        // CfC health
+        let ch = &self.cfc_health;
+        if ch.window_packets < 2 {
+            errors.push(format!(
+                "cfc_health.window_packets ({}) must be >= 2",
+                ch.window_packets
+            ));
+        }
+        if ch.loss_divergence_ratio <= 1.0 {
+            errors.push(format!(
+                "cfc_health.loss_divergence_ratio ({}) must be > 1",
+                ch.loss_divergence_ratio
+            ));
+        }
+        if !(0.5..=1.0).contains(&ch.saturation_score) {
+            errors.push(format!(
+                "cfc_health.saturation_score ({}) must be in [0.5, 1]",
+                ch.saturation_score
+            ));
+        }
+        if ch.saturation_fraction <= 0.0 || ch.saturation_fraction > 1.0 {
+            errors.push(format!(
+                "cfc_health.saturation_fraction ({}) must be in (0, 1]",
+                ch.saturation_fraction
+            ));
+        }
+
+        // Sparklines
+        let max_sparkline = crate::config::defaults::HISTORY_BUFFER_SIZE;
+        if self.sparklines.length < 2 || self.sparklines.length > max_sparkline {
+            errors.push(format!(
+                "sparklines.length ({}) must be in [2, {}] (history buffer size)",
+                self.sparklines.length, max_sparkline
+            ));
+        }
+
+        // Gas lag
+        if self.gas_lag.surface_volume_bbl < 0.0 {
+            errors.push(format!(
+                "gas_lag.surface_volume_bbl ({}) must be >= 0",
+                self.gas_lag.surface_volume_bbl
+            ));
+        }
+        if self.gas_lag.max_lag_secs == 0 {
+            errors.push("gas_lag.max_lag_secs must be > 0".to_string());
+        }
+
+        // Gas migration
+        let gm = &self.gas_migration;
+        for (key, value) in [
+            ("min_rise_psi", gm.min_rise_psi),
+            ("min_rate_psi_per_min", gm.min_rate_psi_per_min),
+            ("max_pipe_movement_ft", gm.max_pipe_movement_ft),
+        ] {
+            if value <= 0.0 {
+                errors.push(format!("gas_migration.{} ({}) must be > 0", key, value));
+            }
+        }
+        if gm.min_static_secs == 0 {
+            errors.push("gas_migration.min_static_secs must be > 0".to_string());
+        }
+
+        // Directional
+        let dir = &self.directional;
+        for (key, value) in [
+            ("slide_flow_min_gpm", dir.slide_flow_min_gpm),
+            ("slide_wob_min", dir.slide_wob_min),
+            ("survey_max_pipe_movement_ft", dir.survey_max_pipe_movement_ft),
+            ("survey_max_off_bottom_ft", dir.survey_max_off_bottom_ft),
+        ] {
+            if value <= 0.0 {
+                errors.push(format!("directional.{} ({}) must be > 0", key, value));
+            }
+        }
+        if dir.slide_rop_ratio <= 0.0 || dir.slide_rop_ratio > 1.0 {
+            errors.push(format!(
+                "directional.slide_rop_ratio ({}) must be in (0, 1]",
+                dir.slide_rop_ratio
+            ));
+        }
+        if dir.survey_min_secs == 0 || dir.survey_min_secs >= dir.survey_max_secs {
+            errors.push(format!(
+                "directional.survey_min_secs ({}) must be > 0 and < survey_max_secs ({})",
+                dir.survey_min_secs, dir.survey_max_secs
+            ));
+        }
+        if dir.rotating_rop_window == 0 || dir.max_logged == 0 {
+            errors.push("directional.rotating_rop_window and max_logged must be > 0".to_string());
+        }
+
+        // KB snapshot retry
+        if self.kb_snapshots.max_pending == 0 {
+            errors.push("kb_snapshots.max_pending must be > 0".to_string());
+        }
+
+        // Config sanity check
+        let cs = &self.config_sanity;
+        if cs.min_packets == 0 {
+            errors.push("config_sanity.min_packets must be > 0".to_string());
+        }
+        if !cs.bit_diameter_tolerance_in.is_finite() || cs.bit_diameter_tolerance_in < 0.0 {
+            errors.push(format!(
+                "config_sanity.bit_diameter_tolerance_in ({}) must be >= 0",
+                cs.bit_diameter_tolerance_in
+            ));
+        }
+
+        // MPD choke adjustments
+        let mpd = &self.mpd;
+        if !mpd.min_choke_move_pct.is_finite()
+            || mpd.min_choke_move_pct <= 0.0
+            || mpd.min_choke_move_pct > 100.0
+        {
+            errors.push(format!(
+                "mpd.min_choke_move_pct ({}) must be in (0, 100]",
+                mpd.min_choke_move_pct
+            ));
+        }
+        if mpd.detect_window_secs == 0 {
+            errors.push("mpd.detect_window_secs must be > 0".to_string());
+        }
+
+        // Detection benchmark
+        if self.detection_benchmark.max_latency_secs == 0 {
+            errors.push("detection_benchmark.max_latency_secs must be > 0".to_string());
+        }
+
+        // WOB step recommendations
+        let max_step = self.optimization.max_wob_step_klbs;
+        if !max_step.is_finite() || max_step <= 0.0 {
+            errors.push(format!(
+                "optimization.max_wob_step_klbs ({}) must be > 0",
+                max_step
+            ));
+        }
+
+        // Connection scorecard
+        let csc = &self.connection_scorecard;
+        for (key, limit) in [
+            ("connection_scorecard.duration_cv_limit", csc.duration_cv_limit),
+            ("connection_scorecard.gas_delta_limit_units", csc.gas_delta_limit_units),
+            ("connection_scorecard.spp_overshoot_limit_pct", csc.spp_overshoot_limit_pct),
+        ] {
+            if limit <= 0.0 {
+                errors.push(format!("{} ({}) must be > 0", key, limit));
+            }
+        }
+        if csc.outlier_ratio <= 1.0 {
+            errors.push(format!(
+                "connection_scorecard.outlier_ratio ({}) must be > 1",
+                csc.outlier_ratio
+            ));
+        }
+
+        // Debrief clustering
+        if self.debrief.cluster_distance_ft <= 0.0 {
+            errors.push(format!(
+                "debrief.cluster_distance_ft ({}) must be > 0",
+                self.debrief.cluster_distance_ft
+            ));
+        }
+        if self.debrief.min_cluster_size < 2 {
+            errors.push("debrief.min_cluster_size must be >= 2".to_string());
+        }
+
+        // Episode re-scoring
+        if self.episode_scoring.rescore_interval_secs == 0 {
+            errors.push("episode_scoring.rescore_interval_secs must be > 0".to_string());
+        }
+
+        // Replay end-of-file
+        if !REPLAY_EOF_BEHAVIORS.contains(&self.replay.eof_behavior.as_str()) {
+            errors.push(format!(
+                "replay.eof_behavior ({:?}) must be one of {:?}",
+                self.replay.eof_behavior, REPLAY_EOF_BEHAVIORS
+            ));
+        }
+
+        // WITS TCP reconnect backoff
+        let acq = &self.acquisition;
+        if acq.reconnect_base_ms == 0 {
+            errors.push("acquisition.reconnect_base_ms must be > 0".to_string());
+        }
+        if acq.reconnect_max_ms < acq.reconnect_base_ms {
+            errors.push(format!(
+                "acquisition.reconnect_max_ms ({}) must be >= reconnect_base_ms ({})",
+                acq.reconnect_max_ms, acq.reconnect_base_ms
+            ));
+        }
+
+        // CfC network sizing
+        for (key, neurons) in [
+            ("cfc.fast_neurons", self.cfc.fast_neurons),
+            ("cfc.slow_neurons", self.cfc.slow_neurons),
+        ] {
+            if !(CFC_MIN_NEURONS..=CFC_MAX_NEURONS).contains(&neurons) {
+                errors.push(format!(
+                    "{} ({}) must be in {}-{}",
+                    key, neurons, CFC_MIN_NEURONS, CFC_MAX_NEURONS
+                ));
+                continue;
+            }
+            if let Err(e) = crate::cfc::wiring::NcpConfig::scaled(neurons, self.cfc.inter_fraction)
+                .validate_proportions()
+            {
+                errors.push(format!(
+                    "{} ({}) gives invalid NCP wiring: {}",
+                    key, neurons, e
+                ));
+            }
+        }
+        if !(0.5..1.0).contains(&self.cfc.inter_fraction) {
+            errors.push(format!(
+                "cfc.inter_fraction ({}) must be in [0.5, 1.0)",
+                self.cfc.inter_fraction
+            ));
+        }
+        if !self.cfc.regime_merge_distance.is_finite() || self.cfc.regime_merge_distance < 0.0 {
+            errors.push(format!(
+                "cfc.regime_merge_distance ({}) must be >= 0",
+                self.cfc.regime_merge_distance
+            ));
+        }
+        if self.cfc.regime_merge_interval_packets == 0 {
+            errors.push("cfc.regime_merge_interval_packets must be >= 1".to_string());
+        }
+
+        // CfC campaign feature sets
+        for (campaign, weights) in [
+            ("production", &self.cfc_feature_sets.production),
+            ("plug_abandonment", &self.cfc_feature_sets.plug_abandonment),
+        ] {
+            let weights = weights.to_array();
+            for (name, weight) in crate::cfc::normalizer::FEATURE_NAMES.iter().zip(weights) {
+                if !(weight >= 0.0 && weight.is_finite()) {
+                    errors.push(format!(
+                        "cfc_feature_sets.{}.{} ({}) must be >= 0",
+                        campaign, name, weight
+                    ));
+                }
+            }
+            if weights.iter().sum::<f64>() <= 0.0 {
+                errors.push(format!(
+                    "cfc_feature_sets.{} needs at least one feature weight > 0",
+                    campaign
+                ));
+            }
+        }
+
+        // Physical range validation
+        let (range_errors, range_warnings) = super::validation::validate_physical_ranges(self);
+        errors.extend(range_errors);
+        for w in &range_warnings {
+            warn!("{}", w);
+        }
+
+        // Reject NaN/Inf in any config value (sweep all f64 fields via TOML value tree)
+        if let Ok(s) = toml::to_string(self) {
+            if let Ok(toml_val) = s.parse::<toml::Value>() {
+                if Self::has_non_finite_float(&toml_val) {
+                    errors.push(
+                        "Config contains NaN or Inf values — all thresholds must be finite numbers"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Validation(errors))
+        }
+    }
+
+    /// Raw vote weight for a specialist key (`mse`, `hydraulic`,
+    /// `well_control`, `formation`, `stuck_pipe`), before normalisation.
+    ///
+    /// From `[orchestrator]` when the key is listed there, otherwise from
+    /// `[ensemble_weights]`. Unknown keys get 0.
+    pub fn specialist_weight(&self, key: &str) -> f64 {
+        let o = &self.orchestrator;
+        let listed = match key {
+            "mse" => o.mse,
+            "hydraulic" => o.hydraulic,
+            "well_control" => o.well_control,
+            "formation" => o.formation,
+            "stuck_pipe" => o.stuck_pipe,
+            _ => None,
+        };
+        if let Some(w) = listed {
+            return if w.is_finite() { w.max(0.0) } else { 0.0 };
+        }
+        let e = &self.ensemble_weights;
+        match key {
+            "mse" => e.mse,
+            "hydraulic" => e.hydraulic,
+            "well_control" => e.well_control,
+            "formation" => e.formation,
+            "stuck_pipe" => e.stuck_pipe,
+            _ => 0.0,
+        }
+    }
+
+    fn has_non_finite_float(val: &toml::Value) -> bool {
+        match val {
+            toml::Value::Float(f) => f.is_nan() || f.is_infinite(),
+            toml::Value::Table(t) => t.values().any(Self::has_non_finite_float),
+            toml::Value::Array(a) => a.iter().any(Self::has_non_finite_float),
+            _ => false,
+        }
+    }
+}
+
+// ============================================================================
+// Error Type
+// ============================================================================
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, std::io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Serialize(toml::ser::Error),
+    Validation(Vec<String>),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, e) => write!(f, "Config I/O error ({}): {}", path.display(), e),
+            ConfigError::Parse(path, e) => {
+                write!(f, "Config parse error ({}): {}", path.display(), e)
+            }
+            ConfigError::Serialize(e) => write!(f, "Config serialization error: {}", e),
+            ConfigError::Validation(errors) => {
+                writeln!(f, "Config validation failed:")?;
+                for e in errors {
+                    writeln!(f, "  - {}", e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// ============================================================================
+// Well Info
+// ============================================================================
+
+/// Identification metadata — not used for logic, but appears in logs and reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WellInfo {
+    /// Well name / identifier
+    #[serde(default = "default_well_name")]
+    pub name: String,
+
+    /// Field name
+    #[serde(default)]
+    pub field: String,
+
+    /// Rig name
+    #[serde(default)]
+    pub rig: String,
+
+    /// Bit diameter in inches (used by physics calculations)
+    #[serde(default = "default_bit_diameter")]
+    pub bit_diameter_inches: f64,
+
+    /// Campaign type: "production" or "plug_abandonment"
+    #[serde(default = "default_campaign")]
+    pub campaign: String,
+}
 
 fn default_well_name() -> String {
     "DEFAULT".to_string()
@@ -579,14 +1274,23 @@ pub struct ThresholdConfig {
     #[serde(default)]
     pub formation: FormationThresholds,
 
+    #[serde(default)]
+    pub drilling_break: DrillingBreakThresholds,
+
     #[serde(default)]
     pub rig_state: RigStateThresholds,
+    #[serde(default)]
+    pub packoff_classification: PackoffClassificationThresholds,
+
 
     #[serde(default)]
     pub operation_detection: OperationDetectionThresholds,
 
     #[serde(default)]
     pub strategic_verification: StrategicVerificationThresholds,
+
+    #[serde(default)]
+    pub tripping: TrippingThresholds,
 }
 
 impl Default for ThresholdConfig {
@@ -598,9 +1302,12 @@ impl Default for ThresholdConfig {
             mechanical: MechanicalThresholds::default(),
             founder: FounderThresholds::default(),
             formation: FormationThresholds::default(),
+            drilling_break: DrillingBreakThresholds::default(),
             rig_state: RigStateThresholds::default(),
             operation_detection: OperationDetectionThresholds::default(),
+            packoff_classification: PackoffClassificationThresholds::default(),
             strategic_verification: StrategicVerificationThresholds::default(),
+            tripping: TrippingThresholds::default(),
         }
     }
 }
@@ -765,6 +1472,24 @@ pub struct HydraulicsThresholds {
     #[serde(default = "default_fracture_gradient")]
     pub fracture_gradient_ppg: f64,
 
+    /// Formation pore pressure (ppg). 0.0 = unknown: swab margins fall back
+    /// to `normal_mud_weight_ppg`. Auto-detected from the d-exponent depth
+    /// trend when not set and `auto_pore_fracture` is on.
+    #[serde(default)]
+    pub pore_pressure_ppg: f64,
+
+    /// Estimate pore pressure and fracture gradient from the first packets
+    /// when they are not set. Off by default: the estimate needs a normally
+    /// pressured interval to fit the dxc trend on and feeds the swab / ECD
+    /// margins directly.
+    #[serde(default)]
+    pub auto_pore_fracture: bool,
+
+    /// Offset above the observed mud weight used to auto-detect the fracture
+    /// gradient once drilling is steady (ppg, `auto_pore_fracture` only).
+    #[serde(default = "default_auto_fracture_offset")]
+    pub auto_fracture_offset_ppg: f64,
+
     /// ECD margin warning threshold (ppg to fracture gradient).
     /// Advisory when ECD is within this margin of the fracture gradient.
     #[serde(default = "default_ecd_margin_warning")]
@@ -786,6 +1511,13 @@ pub struct HydraulicsThresholds {
     /// APL ≈ coefficient × flow_rate × depth / 1000.
     #[serde(default = "default_apl_coefficient")]
     pub annular_pressure_loss_coefficient: f64,
+
+    /// Thermal correction to ECD (ppg per °F of mud_temp_out − mud_temp_in).
+    /// HPHT wells can shift effective downhole density by 0.3-0.5 ppg.
+    /// 0.0 (the default) disables the correction; ~0.006 suits a water-based
+    /// mud on an HPHT well.
+    #[serde(default = "default_mud_thermal_expansion")]
+    pub mud_thermal_expansion_ppg_per_degf: f64,
 }
 
 fn default_normal_mud_weight() -> f64 {
@@ -794,6 +1526,9 @@ fn default_normal_mud_weight() -> f64 {
 fn default_fracture_gradient() -> f64 {
     14.0
 }
+fn default_auto_fracture_offset() -> f64 {
+    3.0
+}
 fn default_ecd_margin_warning() -> f64 {
     0.3
 }
@@ -809,17 +1544,24 @@ fn default_spp_deviation_critical() -> f64 {
 fn default_apl_coefficient() -> f64 {
     0.1
 }
+pub(crate) fn default_mud_thermal_expansion() -> f64 {
+    0.0
+}
 
 impl Default for HydraulicsThresholds {
     fn default() -> Self {
         Self {
             normal_mud_weight_ppg: default_normal_mud_weight(),
             fracture_gradient_ppg: default_fracture_gradient(),
+            pore_pressure_ppg: 0.0,
+            auto_pore_fracture: false,
+            auto_fracture_offset_ppg: default_auto_fracture_offset(),
             ecd_margin_warning_ppg: default_ecd_margin_warning(),
             ecd_margin_critical_ppg: default_ecd_margin_critical(),
             spp_deviation_warning_psi: default_spp_deviation_warning(),
             spp_deviation_critical_psi: default_spp_deviation_critical(),
             annular_pressure_loss_coefficient: default_apl_coefficient(),
+            mud_thermal_expansion_ppg_per_degf: default_mud_thermal_expansion(),
         }
     }
 }
@@ -858,6 +1600,16 @@ pub struct MechanicalThresholds {
     /// Minimum torque samples required for stick-slip analysis.
     #[serde(default = "default_stick_slip_min_samples")]
     pub stick_slip_min_samples: usize,
+
+    /// SPP decline (percent, normalized by flow) over the history window
+    /// that flags a drillstring washout.
+    #[serde(default = "default_washout_spp_decline")]
+    pub washout_spp_decline_percent: f64,
+
+    /// Max spread (percent of mean) of pump SPM and flow-in for the pumps to
+    /// count as steady during washout detection.
+    #[serde(default = "default_washout_flat_tolerance")]
+    pub washout_flat_tolerance_percent: f64,
 }
 
 fn default_torque_warning() -> f64 {
@@ -881,6 +1633,12 @@ fn default_packoff_rop() -> f64 {
 fn default_stick_slip_min_samples() -> usize {
     5
 }
+fn default_washout_spp_decline() -> f64 {
+    10.0
+}
+fn default_washout_flat_tolerance() -> f64 {
+    5.0
+}
 
 impl Default for MechanicalThresholds {
     fn default() -> Self {
@@ -892,6 +1650,8 @@ impl Default for MechanicalThresholds {
             packoff_spp_increase_threshold: default_packoff_spp(),
             packoff_rop_decrease_threshold: default_packoff_rop(),
             stick_slip_min_samples: default_stick_slip_min_samples(),
+            washout_spp_decline_percent: default_washout_spp_decline(),
+            washout_flat_tolerance_percent: default_washout_flat_tolerance(),
         }
     }
 }
@@ -1036,68 +1796,192 @@ impl Default for FormationThresholds {
 }
 
 // ============================================================================
-// Rig State Classification Thresholds
+// Drilling Break Thresholds
 // ============================================================================
 
-/// Thresholds for classifying rig operational state from WITS data.
+/// Drilling break (sudden sustained ROP increase) detection.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RigStateThresholds {
-    /// RPM below this is considered "not rotating".
-    #[serde(default = "default_idle_rpm_max")]
-    pub idle_rpm_max: f64,
-
-    /// Flow rate above this indicates pumps running (gpm).
-    #[serde(default = "default_circulation_flow_min")]
-    pub circulation_flow_min: f64,
-
-    /// WOB above this indicates "on bottom" (klbs).
-    #[serde(default = "default_drilling_wob_min")]
-    pub drilling_wob_min: f64,
-
-    /// Bit depth offset below hole depth for reaming classification (ft).
-    #[serde(default = "default_reaming_depth_offset")]
-    pub reaming_depth_offset: f64,
+pub struct DrillingBreakThresholds {
+    /// Enable/disable drilling-break advisories.
+    #[serde(default = "default_db_enabled")]
+    pub enabled: bool,
 
-    /// Hook load above this during no-rotation / low-flow = tripping out (klbs).
-    #[serde(default = "default_trip_out_hookload")]
-    pub trip_out_hook_load_min: f64,
+    /// ROP must exceed the baseline by this factor to count as a break.
+    #[serde(default = "default_db_rop_factor")]
+    pub rop_increase_factor: f64,
 
-    /// Hook load below this during no-rotation / low-flow = tripping in (klbs).
-    #[serde(default = "default_trip_in_hookload")]
-    pub trip_in_hook_load_max: f64,
+    /// Drilling packets averaged for the pre-break ROP baseline.
+    #[serde(default = "default_db_baseline_packets")]
+    pub baseline_packets: usize,
 
-    /// Maximum flow rate to consider tripping state (gpm).
-    #[serde(default = "default_tripping_flow_max")]
-    pub tripping_flow_max: f64,
+    /// Consecutive packets above the factor before the break is reported.
+    #[serde(default = "default_db_sustain_packets")]
+    pub sustain_packets: usize,
 
-    /// Minimum ROP to enter Drilling state (ft/hr). Prevents flip-flop from sensor noise near zero.
-    #[serde(default = "default_drilling_rop_min")]
-    pub drilling_rop_min: f64,
+    /// Baseline ROP below this (ft/hr) is too slow for a ratio to be meaningful.
+    #[serde(default = "default_db_min_baseline_rop")]
+    pub min_baseline_rop_ft_hr: f64,
 }
 
-fn default_idle_rpm_max() -> f64 {
-    5.0
-}
-fn default_circulation_flow_min() -> f64 {
-    50.0
-}
-fn default_drilling_wob_min() -> f64 {
-    1.0
-}
-fn default_reaming_depth_offset() -> f64 {
-    5.0
+fn default_db_enabled() -> bool {
+    true
 }
-fn default_trip_out_hookload() -> f64 {
-    200.0
+fn default_db_rop_factor() -> f64 {
+    1.8
 }
-fn default_trip_in_hookload() -> f64 {
-    50.0
+fn default_db_baseline_packets() -> usize {
+    30
 }
-fn default_tripping_flow_max() -> f64 {
-    100.0
+fn default_db_sustain_packets() -> usize {
+    5
 }
-fn default_drilling_rop_min() -> f64 {
-    2.0
+fn default_db_min_baseline_rop() -> f64 {
+    5.0
+}
+
+impl Default for DrillingBreakThresholds {
+    fn default() -> Self {
+        Self {
+            enabled: default_db_enabled(),
+            rop_increase_factor: default_db_rop_factor(),
+            baseline_packets: default_db_baseline_packets(),
+            sustain_packets: default_db_sustain_packets(),
+            min_baseline_rop_ft_hr: default_db_min_baseline_rop(),
+        }
+    }
+}
+
+// ============================================================================
+// Rig State Classification Thresholds
+// ============================================================================
+// Pack-off vs Cuttings-Loading Classification
+// ============================================================================
+
+/// Distinguishes an acute pack-off from gradual cuttings loading when
+/// SPP/torque are rising.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackoffClassificationThresholds {
+    /// Enable/disable the pack-off vs cuttings-loading split. When disabled,
+    /// every qualifying SPP/torque rise is reported as a pack-off.
+    #[serde(default = "default_pc_enabled")]
+    pub enabled: bool,
+
+    /// Span (packets) over which a rise is measured for abruptness.
+    #[serde(default = "default_pc_abrupt_window")]
+    pub abrupt_window_packets: usize,
+
+    /// Fraction of the total rise occurring within one abrupt window for the
+    /// rise to count as acute.
+    #[serde(default = "default_pc_abrupt_fraction")]
+    pub abrupt_rise_fraction: f64,
+
+    /// Fraction of the rise that must bleed back off the peak for the rise to
+    /// count as reversible (intermittent pack-off).
+    #[serde(default = "default_pc_reversal_fraction")]
+    pub reversal_fraction: f64,
+
+    /// ECD slope (ppg per packet) above which ECD is considered trending up.
+    #[serde(default = "default_pc_ecd_trend")]
+    pub ecd_trend_threshold: f64,
+}
+
+fn default_pc_enabled() -> bool {
+    true
+}
+fn default_pc_abrupt_window() -> usize {
+    3
+}
+fn default_pc_abrupt_fraction() -> f64 {
+    0.6
+}
+fn default_pc_reversal_fraction() -> f64 {
+    0.5
+}
+fn default_pc_ecd_trend() -> f64 {
+    0.001
+}
+
+impl Default for PackoffClassificationThresholds {
+    fn default() -> Self {
+        Self {
+            enabled: default_pc_enabled(),
+            abrupt_window_packets: default_pc_abrupt_window(),
+            abrupt_rise_fraction: default_pc_abrupt_fraction(),
+            reversal_fraction: default_pc_reversal_fraction(),
+            ecd_trend_threshold: default_pc_ecd_trend(),
+        }
+    }
+}
+
+// ============================================================================
+
+/// Thresholds for classifying rig operational state from WITS data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RigStateThresholds {
+    /// RPM below this is considered "not rotating".
+    #[serde(default = "default_idle_rpm_max")]
+    pub idle_rpm_max: f64,
+
+    /// Flow rate above this indicates pumps running (gpm).
+    #[serde(default = "default_circulation_flow_min")]
+    pub circulation_flow_min: f64,
+
+    /// WOB above this indicates "on bottom" (klbs).
+    #[serde(default = "default_drilling_wob_min")]
+    pub drilling_wob_min: f64,
+
+    /// Bit depth offset below hole depth for reaming classification (ft).
+    #[serde(default = "default_reaming_depth_offset")]
+    pub reaming_depth_offset: f64,
+
+    /// Hook load above this during no-rotation / low-flow = tripping out (klbs).
+    #[serde(default = "default_trip_out_hookload")]
+    pub trip_out_hook_load_min: f64,
+
+    /// Hook load below this during no-rotation / low-flow = tripping in (klbs).
+    #[serde(default = "default_trip_in_hookload")]
+    pub trip_in_hook_load_max: f64,
+
+    /// Maximum flow rate to consider tripping state (gpm).
+    #[serde(default = "default_tripping_flow_max")]
+    pub tripping_flow_max: f64,
+
+    /// Minimum ROP to enter Drilling state (ft/hr). Prevents flip-flop from sensor noise near zero.
+    #[serde(default = "default_drilling_rop_min")]
+    pub drilling_rop_min: f64,
+
+    /// Consecutive packets a new rig state must hold before the tactical agent
+    /// commits it. 0 or 1 disables debouncing.
+    #[serde(default = "default_transition_debounce_packets")]
+    pub transition_debounce_packets: u32,
+}
+
+fn default_idle_rpm_max() -> f64 {
+    5.0
+}
+fn default_circulation_flow_min() -> f64 {
+    50.0
+}
+fn default_drilling_wob_min() -> f64 {
+    1.0
+}
+fn default_reaming_depth_offset() -> f64 {
+    5.0
+}
+fn default_trip_out_hookload() -> f64 {
+    200.0
+}
+fn default_trip_in_hookload() -> f64 {
+    50.0
+}
+fn default_tripping_flow_max() -> f64 {
+    100.0
+}
+fn default_drilling_rop_min() -> f64 {
+    2.0
+}
+fn default_transition_debounce_packets() -> u32 {
+    3
 }
 
 impl Default for RigStateThresholds {
@@ -1111,6 +1995,7 @@ impl Default for RigStateThresholds {
             trip_in_hook_load_max: default_trip_in_hookload(),
             tripping_flow_max: default_tripping_flow_max(),
             drilling_rop_min: default_drilling_rop_min(),
+            transition_debounce_packets: default_transition_debounce_packets(),
         }
     }
 }
@@ -1291,6 +2176,66 @@ impl Default for StrategicVerificationThresholds {
     }
 }
 
+// ============================================================================
+// Tripping (Swab / Surge) Thresholds
+// ============================================================================
+
+/// Swab and surge detection while tripping.
+///
+/// Pipe velocity comes from the bit depth change between packets; the
+/// swab/surge pressure uses the `[trip_parameters]` geometry and rheology and
+/// is compared against the pore (swab) and fracture (surge) margins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrippingThresholds {
+    /// Pipe speeds below this (ft/min) are not evaluated.
+    #[serde(default = "default_tt_velocity_threshold")]
+    pub pipe_velocity_threshold_ft_min: f64,
+
+    /// Swabbed density margin above pore pressure, warning (ppg).
+    #[serde(default = "default_tt_swab_warning")]
+    pub swab_margin_warning_ppg: f64,
+
+    /// Swabbed density margin above pore pressure, critical (ppg).
+    #[serde(default = "default_tt_swab_critical")]
+    pub swab_margin_critical_ppg: f64,
+
+    /// Surged density margin below fracture gradient, warning (ppg).
+    #[serde(default = "default_tt_surge_warning")]
+    pub surge_margin_warning_ppg: f64,
+
+    /// Surged density margin below fracture gradient, critical (ppg).
+    #[serde(default = "default_tt_surge_critical")]
+    pub surge_margin_critical_ppg: f64,
+}
+
+fn default_tt_velocity_threshold() -> f64 {
+    30.0
+}
+fn default_tt_swab_warning() -> f64 {
+    0.3
+}
+fn default_tt_swab_critical() -> f64 {
+    0.1
+}
+fn default_tt_surge_warning() -> f64 {
+    0.3
+}
+fn default_tt_surge_critical() -> f64 {
+    0.1
+}
+
+impl Default for TrippingThresholds {
+    fn default() -> Self {
+        Self {
+            pipe_velocity_threshold_ft_min: default_tt_velocity_threshold(),
+            swab_margin_warning_ppg: default_tt_swab_warning(),
+            swab_margin_critical_ppg: default_tt_swab_critical(),
+            surge_margin_warning_ppg: default_tt_surge_warning(),
+            surge_margin_critical_ppg: default_tt_surge_critical(),
+        }
+    }
+}
+
 // ============================================================================
 // Baseline Learning Config
 // ============================================================================
@@ -1321,6 +2266,82 @@ pub struct BaselineLearningConfig {
     /// Sigma threshold for outlier detection during learning.
     #[serde(default = "default_bl_outlier_sigma")]
     pub outlier_sigma_threshold: f64,
+
+    /// Fraction of WITS metrics (0–1) that must lock before the rig is
+    /// announced as live on dynamic anomaly detection.
+    #[serde(default = "default_bl_operational_fraction")]
+    pub operational_lock_fraction: f64,
+
+    /// Measure each channel's value resolution (smallest non-zero step)
+    /// during learning and flag heavily quantized feeds.
+    #[serde(default = "default_bl_quantization_check")]
+    pub quantization_check_enabled: bool,
+
+    /// A channel is flagged as quantized when its resolution is at least
+    /// this fraction of its learned std dev.
+    #[serde(default = "default_bl_quantization_ratio")]
+    pub quantization_ratio: f64,
+
+    /// Raise a quantized channel's effective std floor to one resolution
+    /// step so a single quantum change cannot trip a warning on its own.
+    #[serde(default = "default_bl_widen_quantized_floor")]
+    pub widen_quantized_std_floor: bool,
+
+    /// Run anomaly detection while baselines are still learning, flagging
+    /// the resulting advisories provisional. Provisional advisories are kept
+    /// out of history and stats until the baselines lock.
+    #[serde(default = "default_bl_preview_mode")]
+    pub preview_mode: bool,
+
+    /// Sigma of the learned flow_balance std used as the flow imbalance
+    /// warning override once baselines lock.
+    #[serde(default = "default_bl_override_flow_sigma")]
+    pub override_flow_imbalance_sigma: f64,
+
+    /// Sigma for the SPP deviation / torque warning overrides.
+    #[serde(default = "default_bl_override_warning_sigma")]
+    pub override_warning_sigma: f64,
+
+    /// Sigma for the SPP deviation / torque critical overrides.
+    #[serde(default = "default_bl_override_critical_sigma")]
+    pub override_critical_sigma: f64,
+
+    /// Re-derive the live overrides from the locked baselines when a config
+    /// hot-reload changes the override sigmas.
+    #[serde(default = "default_bl_rederive_on_reload")]
+    pub rederive_overrides_on_reload: bool,
+
+    /// Persist in-progress learning accumulators alongside locked baselines
+    /// so a restart resumes learning instead of starting from zero. When
+    /// off, accumulators left in the state file by an earlier run are ignored.
+    #[serde(default = "default_bl_persist_accumulators")]
+    pub persist_accumulators: bool,
+
+    /// Save in-progress accumulators every N learning samples (only when
+    /// `persist_accumulators` is enabled).
+    #[serde(default = "default_bl_accumulator_save_interval")]
+    pub accumulator_save_interval_samples: usize,
+
+    /// Warn when learning has made no progress toward locking for this long.
+    #[serde(default = "default_bl_stall_watchdog")]
+    pub stall_watchdog_enabled: bool,
+
+    /// Seconds in learning mode without progress before the stall warning.
+    #[serde(default = "default_bl_stall_warning_secs")]
+    pub stall_warning_secs: u64,
+
+    /// EWMA factor by which normal samples re-center a locked baseline mean
+    /// (e.g. 0.001). 0.0 keeps locked baselines frozen.
+    #[serde(default = "default_bl_drift_alpha")]
+    pub drift_alpha: f64,
+
+    /// Let drifting baselines widen their std (never narrow) as well.
+    #[serde(default = "default_bl_drift_widen_std")]
+    pub drift_widen_std: bool,
+
+    /// Seconds between saves of drifted baselines to the state file.
+    #[serde(default = "default_bl_drift_persist_interval")]
+    pub drift_persist_interval_secs: u64,
 }
 
 fn default_bl_warning_sigma() -> f64 {
@@ -1341,6 +2362,54 @@ fn default_bl_max_outlier() -> f64 {
 fn default_bl_outlier_sigma() -> f64 {
     3.0
 }
+fn default_bl_operational_fraction() -> f64 {
+    1.0
+}
+fn default_bl_quantization_check() -> bool {
+    true
+}
+fn default_bl_quantization_ratio() -> f64 {
+    0.5
+}
+fn default_bl_widen_quantized_floor() -> bool {
+    true
+}
+fn default_bl_preview_mode() -> bool {
+    false
+}
+fn default_bl_override_flow_sigma() -> f64 {
+    3.0
+}
+fn default_bl_override_warning_sigma() -> f64 {
+    2.0
+}
+fn default_bl_override_critical_sigma() -> f64 {
+    3.0
+}
+fn default_bl_rederive_on_reload() -> bool {
+    true
+}
+fn default_bl_persist_accumulators() -> bool {
+    false
+}
+fn default_bl_accumulator_save_interval() -> usize {
+    10
+}
+fn default_bl_stall_watchdog() -> bool {
+    true
+}
+fn default_bl_stall_warning_secs() -> u64 {
+    21600
+}
+fn default_bl_drift_alpha() -> f64 {
+    0.0
+}
+fn default_bl_drift_widen_std() -> bool {
+    false
+}
+fn default_bl_drift_persist_interval() -> u64 {
+    900
+}
 
 impl Default for BaselineLearningConfig {
     fn default() -> Self {
@@ -1351,6 +2420,22 @@ impl Default for BaselineLearningConfig {
             min_std_floor: default_bl_std_floor(),
             max_outlier_percentage: default_bl_max_outlier(),
             outlier_sigma_threshold: default_bl_outlier_sigma(),
+            operational_lock_fraction: default_bl_operational_fraction(),
+            quantization_check_enabled: default_bl_quantization_check(),
+            quantization_ratio: default_bl_quantization_ratio(),
+            widen_quantized_std_floor: default_bl_widen_quantized_floor(),
+            preview_mode: default_bl_preview_mode(),
+            override_flow_imbalance_sigma: default_bl_override_flow_sigma(),
+            override_warning_sigma: default_bl_override_warning_sigma(),
+            override_critical_sigma: default_bl_override_critical_sigma(),
+            rederive_overrides_on_reload: default_bl_rederive_on_reload(),
+            persist_accumulators: default_bl_persist_accumulators(),
+            accumulator_save_interval_samples: default_bl_accumulator_save_interval(),
+            stall_watchdog_enabled: default_bl_stall_watchdog(),
+            stall_warning_secs: default_bl_stall_warning_secs(),
+            drift_alpha: default_bl_drift_alpha(),
+            drift_widen_std: default_bl_drift_widen_std(),
+            drift_persist_interval_secs: default_bl_drift_persist_interval(),
         }
     }
 }
@@ -1401,6 +2486,16 @@ pub struct AdvisoryConfig {
     /// Non-anomalous packets required to reset sustained throttle for a category.
     #[serde(default = "default_sustained_reset_normal_count")]
     pub sustained_reset_normal_count: u32,
+
+    /// Seconds an operator acknowledgment is kept before it expires and is
+    /// swept from the acknowledgment store (0 = never expires).
+    #[serde(default = "default_ack_ttl_seconds")]
+    pub ack_ttl_seconds: u64,
+
+    /// Acknowledgment lifetime for CRITICAL advisories (seconds, 0 = never).
+    /// When set, at most `ack_ttl_seconds` so a stale critical ack is dropped sooner.
+    #[serde(default = "default_critical_ack_ttl_seconds")]
+    pub critical_ack_ttl_seconds: u64,
 }
 
 fn default_cooldown_seconds() -> u64 {
@@ -1433,6 +2528,12 @@ fn default_sustained_max_depth_cooldown() -> f64 {
 fn default_sustained_reset_normal_count() -> u32 {
     500
 }
+fn default_ack_ttl_seconds() -> u64 {
+    14_400
+}
+fn default_critical_ack_ttl_seconds() -> u64 {
+    1_800
+}
 
 impl Default for AdvisoryConfig {
     fn default() -> Self {
@@ -1447,6 +2548,8 @@ impl Default for AdvisoryConfig {
             sustained_depth_multiplier: default_sustained_depth_multiplier(),
             sustained_max_depth_cooldown_ft: default_sustained_max_depth_cooldown(),
             sustained_reset_normal_count: default_sustained_reset_normal_count(),
+            ack_ttl_seconds: default_ack_ttl_seconds(),
+            critical_ack_ttl_seconds: default_critical_ack_ttl_seconds(),
         }
     }
 }
@@ -1509,6 +2612,42 @@ impl Default for EnsembleWeightsConfig {
     }
 }
 
+/// Specialist set and vote weights for the Phase 8 orchestrator.
+///
+/// A specialist listed here uses this weight; one left out keeps its
+/// `[ensemble_weights]` weight. Specialists weighted 0 are not consulted,
+/// except WellControl, which must keep a weight > 0. The weights are
+/// normalised to 1.0 at vote time, so they need not sum to 1.0 here.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrchestratorConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mse: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hydraulic: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub well_control: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formation: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stuck_pipe: Option<f64>,
+}
+
+impl OrchestratorConfig {
+    /// Specialists explicitly listed in `[orchestrator]`, as (key, weight)
+    pub fn configured(&self) -> Vec<(&'static str, f64)> {
+        [
+            ("mse", self.mse),
+            ("hydraulic", self.hydraulic),
+            ("well_control", self.well_control),
+            ("formation", self.formation),
+            ("stuck_pipe", self.stuck_pipe),
+        ]
+        .into_iter()
+        .filter_map(|(key, w)| w.map(|w| (key, w)))
+        .collect()
+    }
+}
+
 // ============================================================================
 // Trip Parameters (Swab/Surge)
 // ============================================================================
@@ -1691,6 +2830,19 @@ pub struct MlConfig {
     #[serde(default = "default_rop_lag_seconds")]
     pub rop_lag_seconds: u64,
 
+    /// Gas lag-to-surface compensation in seconds.
+    ///
+    /// Gas liberated at the bit only reaches the gas trap after a bottoms-up
+    /// delay, so `gas_units` is paired with the reading this many seconds
+    /// later. 0 disables. The ML window is truncated by the largest of the
+    /// three lags.
+    #[serde(default)]
+    pub gas_lag_seconds: u64,
+
+    /// Return flow (`flow_out`) lag compensation in seconds. 0 disables.
+    #[serde(default)]
+    pub flow_lag_seconds: u64,
+
     /// ML analysis interval in seconds.
     ///
     /// How often the ML scheduler runs its analysis pass.
@@ -1710,6 +2862,8 @@ impl Default for MlConfig {
     fn default() -> Self {
         Self {
             rop_lag_seconds: default_rop_lag_seconds(),
+            gas_lag_seconds: 0,
+            flow_lag_seconds: 0,
             interval_secs: default_ml_interval_secs(),
         }
     }
@@ -1727,16 +2881,52 @@ pub struct ServerConfig {
     /// Can be overridden by `SAIREN_SERVER_ADDR` env var or `--addr` CLI flag.
     #[serde(default = "default_server_addr")]
     pub addr: String,
+
+    /// Quiet period the config file must stay unchanged before a hot reload
+    /// (ms). Collapses the several writes an editor makes per save into one.
+    #[serde(default = "default_config_reload_quiet_ms")]
+    pub config_reload_quiet_ms: u64,
 }
 
 fn default_server_addr() -> String {
     "0.0.0.0:8080".to_string()
 }
+fn default_config_reload_quiet_ms() -> u64 {
+    500
+}
 
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
             addr: default_server_addr(),
+            config_reload_quiet_ms: default_config_reload_quiet_ms(),
+        }
+    }
+}
+
+// ============================================================================
+// API Versioning Config
+// ============================================================================
+
+/// Lifecycle of the deprecated v1 API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiConfig {
+    /// Date (`YYYY-MM-DD`, UTC) after which `/api/v1/*` answers `410 Gone`.
+    /// Until then v1 responses carry `Deprecation`/`Sunset` headers. Set an
+    /// earlier date on staging to exercise the post-sunset behaviour, or a
+    /// later one to keep v1 clients running longer.
+    #[serde(default = "default_v1_sunset")]
+    pub v1_sunset: chrono::NaiveDate,
+}
+
+fn default_v1_sunset() -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(2027, 6, 30).expect("valid date")
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            v1_sunset: default_v1_sunset(),
         }
     }
 }
@@ -1907,6 +3097,13 @@ pub struct GossipConfig {
     /// Per-peer HTTP timeout for gossip exchanges (seconds).
     #[serde(default = "default_gossip_timeout")]
     pub timeout_secs: u64,
+    /// zstd-compress envelopes sent to peers (`Content-Encoding: zstd`).
+    #[serde(default = "default_gossip_compress_uploads")]
+    pub compress_uploads: bool,
+    /// Cap on compressed event bytes in the local gossip store; past it the
+    /// oldest non-critical events are evicted (0 = unbounded).
+    #[serde(default = "default_gossip_store_max_bytes")]
+    pub store_max_bytes: u64,
 }
 
 fn default_gossip_interval() -> u64 {
@@ -1918,6 +3115,12 @@ fn default_gossip_max_events() -> usize {
 fn default_gossip_timeout() -> u64 {
     10
 }
+fn default_gossip_compress_uploads() -> bool {
+    true
+}
+fn default_gossip_store_max_bytes() -> u64 {
+    256 * 1024 * 1024
+}
 
 impl Default for GossipConfig {
     fn default() -> Self {
@@ -1925,52 +3128,2087 @@ impl Default for GossipConfig {
             interval_secs: default_gossip_interval(),
             max_events_per_exchange: default_gossip_max_events(),
             timeout_secs: default_gossip_timeout(),
+            compress_uploads: default_gossip_compress_uploads(),
+            store_max_bytes: default_gossip_store_max_bytes(),
         }
     }
 }
 
-/// A formation top entry — maps a depth to a formation name.
+// ============================================================================
+// Trapped Pressure Config
+// ============================================================================
+
+/// Post-connection trapped-pressure detection settings.
 ///
-/// The formation tops table is provided by the geologist before spud.
-/// Entries should be ordered by depth (shallowest first). The current
-/// formation is determined by finding the deepest entry whose depth
-/// is <= the current bit depth.
+/// When pumps are brought back online after a connection, a short SPP spike
+/// is normal. The detector learns the typical overshoot and bleed-off time
+/// from recent connections and flags transients that are abnormally large
+/// or slow to bleed (plugged jet, restriction, closed valve).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FormationTop {
-    /// Top of formation (measured depth in feet).
-    pub depth_ft: f64,
-    /// Formation name (e.g. "shale", "limestone", "sandstone").
-    pub formation: String,
+pub struct TrappedPressureConfig {
+    /// Enable/disable trapped-pressure advisories
+    #[serde(default = "default_tp_enabled")]
+    pub enabled: bool,
+    /// Flow-in above which pumps are considered running (gpm)
+    #[serde(default = "default_tp_pump_on_flow")]
+    pub pump_on_flow_gpm: f64,
+    /// Maximum time to observe the pump-resume transient (seconds)
+    #[serde(default = "default_tp_transient_window")]
+    pub transient_window_secs: u64,
+    /// SPP is considered bled off once within this band of the pre-connection SPP (psi)
+    #[serde(default = "default_tp_settle_tolerance")]
+    pub settle_tolerance_psi: f64,
+    /// Normal connections required before abnormal transients can be flagged
+    #[serde(default = "default_tp_min_connections")]
+    pub min_connections_to_learn: usize,
+    /// Sigma multiplier above the learned mean for overshoot / bleed time
+    #[serde(default = "default_tp_sigma")]
+    pub sigma_threshold: f64,
+    /// Number of recent normal connections kept in the learned baseline
+    #[serde(default = "default_tp_max_learned")]
+    pub max_learned_connections: usize,
+}
+
+fn default_tp_enabled() -> bool {
+    true
+}
+fn default_tp_pump_on_flow() -> f64 {
+    50.0
+}
+fn default_tp_transient_window() -> u64 {
+    120
+}
+fn default_tp_settle_tolerance() -> f64 {
+    50.0
+}
+fn default_tp_min_connections() -> usize {
+    5
+}
+fn default_tp_sigma() -> f64 {
+    3.0
+}
+fn default_tp_max_learned() -> usize {
+    20
+}
+
+impl Default for TrappedPressureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_tp_enabled(),
+            pump_on_flow_gpm: default_tp_pump_on_flow(),
+            transient_window_secs: default_tp_transient_window(),
+            settle_tolerance_psi: default_tp_settle_tolerance(),
+            min_connections_to_learn: default_tp_min_connections(),
+            sigma_threshold: default_tp_sigma(),
+            max_learned_connections: default_tp_max_learned(),
+        }
+    }
 }
 
 // ============================================================================
-// Tests
+// Sidetrack Config
 // ============================================================================
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Sidetrack / multi-lateral handling.
+///
+/// A sidetrack is either marked by the operator (`POST /api/v2/sidetrack`) or
+/// auto-detected as sustained drilling well above the deepest depth already
+/// drilled. Either way the depth context branches so formation lookup and
+/// depth-indexed storage follow the active wellbore.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SidetrackConfig {
+    /// Auto-detect sidetracks from sustained drilling at already-drilled depths
+    #[serde(default = "default_st_auto_detect")]
+    pub auto_detect: bool,
+    /// Bit must be at least this far above the deepest drilled depth (ft)
+    #[serde(default = "default_st_redrill_margin")]
+    pub redrill_margin_ft: f64,
+    /// Consecutive Drilling packets above the drilled depth before branching
+    #[serde(default = "default_st_auto_detect_packets")]
+    pub auto_detect_packets: usize,
+}
+
+fn default_st_auto_detect() -> bool {
+    true
+}
+fn default_st_redrill_margin() -> f64 {
+    100.0
+}
+fn default_st_auto_detect_packets() -> usize {
+    300
+}
 
-    #[test]
-    fn test_default_config_validates() {
-        let config = WellConfig::default();
-        assert!(
-            config.validate().is_ok(),
-            "Default config must always validate"
-        );
+impl Default for SidetrackConfig {
+    fn default() -> Self {
+        Self {
+            auto_detect: default_st_auto_detect(),
+            redrill_margin_ft: default_st_redrill_margin(),
+            auto_detect_packets: default_st_auto_detect_packets(),
+        }
     }
+}
 
-    #[test]
-    fn test_empty_toml_produces_defaults() {
-        let config: WellConfig = toml::from_str("").expect("empty TOML should parse");
-        assert_eq!(
-            config.thresholds.well_control.flow_imbalance_warning_gpm,
-            5.0
-        );
-        assert_eq!(config.thresholds.mse.efficiency_warning_percent, 70.0);
-        assert_eq!(config.thresholds.hydraulics.normal_mud_weight_ppg, 8.6);
-        assert_eq!(config.baseline_learning.warning_sigma, 3.0);
-        assert_eq!(config.ensemble_weights.well_control, 0.30);
+// ============================================================================
+// Sensor Rate-of-Change Limits
+// ============================================================================
+
+/// Maximum physically plausible rate of change per channel.
+///
+/// A single sample that moves faster than its channel limit is treated as an
+/// electrical glitch: it is flagged and (optionally) replaced with the last
+/// good value. If the next sample confirms the new level the change is
+/// accepted as a genuine fast transient. A limit of 0 disables the channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensorRateLimitConfig {
+    /// Enable/disable the rate-of-change filter
+    #[serde(default = "default_rl_enabled")]
+    pub enabled: bool,
+    /// Replace a glitched sample with the previous good value (false = flag only)
+    #[serde(default = "default_rl_hold_glitches")]
+    pub hold_glitches: bool,
+    #[serde(default = "default_rl_wob")]
+    pub wob_klbs_per_sec: f64,
+    #[serde(default = "default_rl_rpm")]
+    pub rpm_per_sec: f64,
+    #[serde(default = "default_rl_torque")]
+    pub torque_kftlb_per_sec: f64,
+    #[serde(default = "default_rl_spp")]
+    pub spp_psi_per_sec: f64,
+    #[serde(default = "default_rl_hook_load")]
+    pub hook_load_klbs_per_sec: f64,
+    #[serde(default = "default_rl_flow_in")]
+    pub flow_in_gpm_per_sec: f64,
+}
+
+fn default_rl_enabled() -> bool {
+    true
+}
+fn default_rl_hold_glitches() -> bool {
+    true
+}
+fn default_rl_wob() -> f64 {
+    20.0
+}
+fn default_rl_rpm() -> f64 {
+    100.0
+}
+fn default_rl_torque() -> f64 {
+    15.0
+}
+fn default_rl_spp() -> f64 {
+    1000.0
+}
+fn default_rl_hook_load() -> f64 {
+    150.0
+}
+fn default_rl_flow_in() -> f64 {
+    300.0
+}
+
+impl SensorRateLimitConfig {
+    /// Config key and limit for each filtered channel.
+    pub fn channel_limits(&self) -> [(&'static str, f64); 6] {
+        [
+            ("wob_klbs_per_sec", self.wob_klbs_per_sec),
+            ("rpm_per_sec", self.rpm_per_sec),
+            ("torque_kftlb_per_sec", self.torque_kftlb_per_sec),
+            ("spp_psi_per_sec", self.spp_psi_per_sec),
+            ("hook_load_klbs_per_sec", self.hook_load_klbs_per_sec),
+            ("flow_in_gpm_per_sec", self.flow_in_gpm_per_sec),
+        ]
+    }
+}
+
+impl Default for SensorRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_rl_enabled(),
+            hold_glitches: default_rl_hold_glitches(),
+            wob_klbs_per_sec: default_rl_wob(),
+            rpm_per_sec: default_rl_rpm(),
+            torque_kftlb_per_sec: default_rl_torque(),
+            spp_psi_per_sec: default_rl_spp(),
+            hook_load_klbs_per_sec: default_rl_hook_load(),
+            flow_in_gpm_per_sec: default_rl_flow_in(),
+        }
+    }
+}
+
+// ============================================================================
+// Candidate Formation Tops Config
+// ============================================================================
+
+/// Candidate formation tops accumulated from CfC formation transitions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateTopsConfig {
+    /// Enable/disable candidate top accumulation
+    #[serde(default = "default_ct_enabled")]
+    pub enabled: bool,
+    /// Transitions within this distance of a candidate are merged into it (ft)
+    #[serde(default = "default_ct_cluster_window")]
+    pub cluster_window_ft: f64,
+    /// Match candidates against prognosis tops to report depth error
+    #[serde(default = "default_ct_match_prognosis")]
+    pub match_prognosis: bool,
+    /// Maximum distance from a prognosis top for a match (ft)
+    #[serde(default = "default_ct_match_tolerance")]
+    pub match_tolerance_ft: f64,
+    /// Maximum candidates retained (oldest dropped first)
+    #[serde(default = "default_ct_max_candidates")]
+    pub max_candidates: usize,
+}
+
+fn default_ct_enabled() -> bool {
+    true
+}
+fn default_ct_cluster_window() -> f64 {
+    30.0
+}
+fn default_ct_match_prognosis() -> bool {
+    true
+}
+fn default_ct_match_tolerance() -> f64 {
+    150.0
+}
+fn default_ct_max_candidates() -> usize {
+    200
+}
+
+impl Default for CandidateTopsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_ct_enabled(),
+            cluster_window_ft: default_ct_cluster_window(),
+            match_prognosis: default_ct_match_prognosis(),
+            match_tolerance_ft: default_ct_match_tolerance(),
+            max_candidates: default_ct_max_candidates(),
+        }
+    }
+}
+
+// ============================================================================
+// Lost Returns Config
+// ============================================================================
+
+/// Lost-returns severity bands.
+///
+/// A loss is staged from the flow-out deficit as a percentage of flow-in and
+/// from the pit-loss rate; the more severe of the two wins. Pit rate alone
+/// cannot reach Complete — that stage means returns have stopped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LostReturnsConfig {
+    /// Enable/disable lost-returns staging advisories
+    #[serde(default = "default_lr_enabled")]
+    pub enabled: bool,
+    /// Flow-in above which pumps are considered running (gpm)
+    #[serde(default = "default_lr_min_flow_in")]
+    pub min_flow_in_gpm: f64,
+    /// Flow-out deficit for seepage losses (% of flow-in)
+    #[serde(default = "default_lr_seepage_deficit")]
+    pub seepage_deficit_pct: f64,
+    /// Flow-out deficit for partial losses (% of flow-in)
+    #[serde(default = "default_lr_partial_deficit")]
+    pub partial_deficit_pct: f64,
+    /// Flow-out deficit for total losses (% of flow-in)
+    #[serde(default = "default_lr_total_deficit")]
+    pub total_deficit_pct: f64,
+    /// Flow-out deficit for complete losses — no returns (% of flow-in)
+    #[serde(default = "default_lr_complete_deficit")]
+    pub complete_deficit_pct: f64,
+    /// Pit-loss rate for seepage losses (bbl/hr)
+    #[serde(default = "default_lr_seepage_pit")]
+    pub seepage_pit_loss_bbl_hr: f64,
+    /// Pit-loss rate for partial losses (bbl/hr)
+    #[serde(default = "default_lr_partial_pit")]
+    pub partial_pit_loss_bbl_hr: f64,
+    /// Pit-loss rate for total losses (bbl/hr)
+    #[serde(default = "default_lr_total_pit")]
+    pub total_pit_loss_bbl_hr: f64,
+    /// Consecutive packets at a stage before it is adopted (or cleared)
+    #[serde(default = "default_lr_confirm_packets")]
+    pub confirm_packets: usize,
+    /// How far below its band a stage must fall before it clears (% of the
+    /// band threshold), so a loss hovering on a boundary isn't re-reported
+    #[serde(default = "default_lr_clear_hysteresis")]
+    pub clear_hysteresis_pct: f64,
+    /// Seconds after a report before the same (or a milder) stage is
+    /// reported again; a more severe stage is always reported
+    #[serde(default = "default_lr_report_cooldown")]
+    pub report_cooldown_secs: u64,
+    /// Route losses with a surface-leak signature to an Equipment advisory
+    #[serde(default = "default_lr_detect_surface")]
+    pub detect_surface_losses: bool,
+    /// Packets looked back over when separating surface from formation losses
+    #[serde(default = "default_lr_surface_window")]
+    pub surface_window_packets: usize,
+    /// SPP drop across the window marking a surface leak (% of starting SPP)
+    #[serde(default = "default_lr_surface_spp_drop")]
+    pub surface_spp_drop_pct: f64,
+    /// ECD change across the window still considered stable (ppg)
+    #[serde(default = "default_lr_surface_ecd_tolerance")]
+    pub surface_ecd_tolerance_ppg: f64,
+}
+
+fn default_lr_enabled() -> bool {
+    true
+}
+fn default_lr_min_flow_in() -> f64 {
+    50.0
+}
+fn default_lr_seepage_deficit() -> f64 {
+    2.0
+}
+fn default_lr_partial_deficit() -> f64 {
+    10.0
+}
+fn default_lr_total_deficit() -> f64 {
+    50.0
+}
+fn default_lr_complete_deficit() -> f64 {
+    95.0
+}
+fn default_lr_seepage_pit() -> f64 {
+    2.0
+}
+fn default_lr_partial_pit() -> f64 {
+    10.0
+}
+fn default_lr_total_pit() -> f64 {
+    40.0
+}
+fn default_lr_confirm_packets() -> usize {
+    3
+}
+fn default_lr_clear_hysteresis() -> f64 {
+    25.0
+}
+fn default_lr_report_cooldown() -> u64 {
+    600
+}
+fn default_lr_detect_surface() -> bool {
+    true
+}
+fn default_lr_surface_window() -> usize {
+    30
+}
+fn default_lr_surface_spp_drop() -> f64 {
+    5.0
+}
+fn default_lr_surface_ecd_tolerance() -> f64 {
+    0.05
+}
+
+impl Default for LostReturnsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_lr_enabled(),
+            min_flow_in_gpm: default_lr_min_flow_in(),
+            seepage_deficit_pct: default_lr_seepage_deficit(),
+            partial_deficit_pct: default_lr_partial_deficit(),
+            total_deficit_pct: default_lr_total_deficit(),
+            complete_deficit_pct: default_lr_complete_deficit(),
+            seepage_pit_loss_bbl_hr: default_lr_seepage_pit(),
+            partial_pit_loss_bbl_hr: default_lr_partial_pit(),
+            total_pit_loss_bbl_hr: default_lr_total_pit(),
+            confirm_packets: default_lr_confirm_packets(),
+            clear_hysteresis_pct: default_lr_clear_hysteresis(),
+            report_cooldown_secs: default_lr_report_cooldown(),
+            detect_surface_losses: default_lr_detect_surface(),
+            surface_window_packets: default_lr_surface_window(),
+            surface_spp_drop_pct: default_lr_surface_spp_drop(),
+            surface_ecd_tolerance_ppg: default_lr_surface_ecd_tolerance(),
+        }
+    }
+}
+
+// ============================================================================
+// Bit Run Config
+// ============================================================================
+
+/// Bit-run archive settings.
+///
+/// When a post-run dull grade is entered, the run's Mechanical (and, for a
+/// hydraulic dull, Hydraulics) advisories are scored against the outcome
+/// and fed into the feedback confirmation stats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitRunConfig {
+    /// Turn dull grades into advisory feedback
+    #[serde(default = "default_br_correlate")]
+    pub correlate_advisories: bool,
+    /// IADC inner/outer wear (0-8) at or above which the bit counts as damaged
+    #[serde(default = "default_br_wear_threshold")]
+    pub wear_problem_threshold: u8,
+}
+
+fn default_br_correlate() -> bool {
+    true
+}
+fn default_br_wear_threshold() -> u8 {
+    3
+}
+
+impl Default for BitRunConfig {
+    fn default() -> Self {
+        Self {
+            correlate_advisories: default_br_correlate(),
+            wear_problem_threshold: default_br_wear_threshold(),
+        }
+    }
+}
+
+// ============================================================================
+// Pipeline Metrics Config
+// ============================================================================
+
+/// Streaming ingestion metrics (`GET /api/v2/pipeline/metrics`).
+///
+/// Processing lag is ingestion lag (arrival minus packet timestamp) above
+/// the lowest lag seen, i.e. how far analysis has fallen behind the feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineMetricsConfig {
+    /// Enable/disable the lag system advisory (metrics are always tracked)
+    #[serde(default = "default_pm_enabled")]
+    pub enabled: bool,
+    /// Processing lag above which the pipeline is falling behind (seconds)
+    #[serde(default = "default_pm_max_lag")]
+    pub max_lag_secs: f64,
+    /// Minimum time between lag advisories (seconds)
+    #[serde(default = "default_pm_cooldown")]
+    pub alert_cooldown_secs: u64,
+    /// Packets in the rolling processing-time / throughput window
+    #[serde(default = "default_pm_window")]
+    pub window_packets: usize,
+}
+
+fn default_pm_enabled() -> bool {
+    true
+}
+fn default_pm_max_lag() -> f64 {
+    30.0
+}
+fn default_pm_cooldown() -> u64 {
+    600
+}
+fn default_pm_window() -> usize {
+    300
+}
+
+impl Default for PipelineMetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_pm_enabled(),
+            max_lag_secs: default_pm_max_lag(),
+            alert_cooldown_secs: default_pm_cooldown(),
+            window_packets: default_pm_window(),
+        }
+    }
+}
+
+// ============================================================================
+// Pipeline Input Decimation
+// ============================================================================
+
+/// Thinning of high-rate WITS feeds before the heavy analysis path.
+///
+/// Every packet is still scanned for well-control step changes and still
+/// feeds baseline learning; only the full coordinator cycle is rate-limited.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PipelineConfig {
+    /// Maximum packets per second forwarded to the full pipeline (0 = off).
+    /// Packet timestamps have one-second resolution, so rates above 1 Hz
+    /// forward one packet per timestamp.
+    #[serde(default)]
+    pub decimate_to_hz: f64,
+}
+
+// ============================================================================
+// Feed Flatline Config
+// ============================================================================
+
+/// Whole-feed flatline detection.
+///
+/// A hung acquisition system can keep the WITS connection open while every
+/// channel repeats its last value. When no channel changes by more than
+/// `tolerance` for `window_secs` a high-priority system advisory is raised.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedFlatlineConfig {
+    /// Enable/disable the feed-frozen advisory
+    #[serde(default = "default_ff_enabled")]
+    pub enabled: bool,
+    /// Time all channels must be unchanged before the feed is frozen (seconds)
+    #[serde(default = "default_ff_window")]
+    pub window_secs: u64,
+    /// Largest change per channel still counted as unchanged
+    #[serde(default = "default_ff_tolerance")]
+    pub tolerance: f64,
+}
+
+fn default_ff_enabled() -> bool {
+    true
+}
+fn default_ff_window() -> u64 {
+    120
+}
+fn default_ff_tolerance() -> f64 {
+    0.0
+}
+
+impl Default for FeedFlatlineConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_ff_enabled(),
+            window_secs: default_ff_window(),
+            tolerance: default_ff_tolerance(),
+        }
+    }
+}
+
+// ============================================================================
+// History Gap Config
+// ============================================================================
+
+/// Gaps in the history window used by strategic analysis. Consecutive
+/// packets more than `max_gap_secs` apart (a feed outage) split the history
+/// into continuous segments; trends are only fitted over the latest segment
+/// and the report carries the fraction of the window actually covered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryGapConfig {
+    /// Split the history at gaps (off = treat history as continuous)
+    #[serde(default = "default_hg_enabled")]
+    pub enabled: bool,
+    /// Packet spacing above which the feed counts as interrupted (seconds)
+    #[serde(default = "default_hg_max_gap")]
+    pub max_gap_secs: u64,
+}
+
+fn default_hg_enabled() -> bool {
+    true
+}
+fn default_hg_max_gap() -> u64 {
+    60
+}
+
+impl Default for HistoryGapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_hg_enabled(),
+            max_gap_secs: default_hg_max_gap(),
+        }
+    }
+}
+
+// ============================================================================
+// Mud Program Config
+// ============================================================================
+
+/// Planned vs actual mud weight comparison.
+///
+/// The planned program comes from the prognosis `[[mud_program]]` intervals;
+/// an interval's own `tolerance_ppg` overrides the default here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MudProgramConfig {
+    /// Enable/disable the mud-weight deviation advisory
+    #[serde(default = "default_mp_enabled")]
+    pub enabled: bool,
+    /// Allowed deviation from the planned mud weight (ppg)
+    #[serde(default = "default_mp_tolerance")]
+    pub tolerance_ppg: f64,
+    /// Minimum time between deviation advisories (seconds)
+    #[serde(default = "default_mp_cooldown")]
+    pub alert_cooldown_secs: u64,
+}
+
+fn default_mp_enabled() -> bool {
+    true
+}
+fn default_mp_tolerance() -> f64 {
+    0.3
+}
+fn default_mp_cooldown() -> u64 {
+    1800
+}
+
+impl Default for MudProgramConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_mp_enabled(),
+            tolerance_ppg: default_mp_tolerance(),
+            alert_cooldown_secs: default_mp_cooldown(),
+        }
+    }
+}
+
+// ============================================================================
+// Equipment Limits Config
+// ============================================================================
+
+/// Rated limits of the rig's drilling equipment.
+///
+/// Each parameter is flagged when it reaches `approach_fraction` of its
+/// rating and escalated when it exceeds it, independent of the statistical
+/// anomaly detection. A rating of 0 means "not rated" and skips the check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquipmentLimitsConfig {
+    /// Enable/disable equipment-limit advisories
+    #[serde(default = "default_el_enabled")]
+    pub enabled: bool,
+    /// Fraction of a rating (0-1) at which the parameter is approaching it
+    #[serde(default = "default_el_approach_fraction")]
+    pub approach_fraction: f64,
+    /// Maximum weight on bit for the BHA (klbs, 0 = not rated)
+    #[serde(default)]
+    pub max_wob_klbs: f64,
+    /// Maximum RPM for the mud motor (0 = not rated)
+    #[serde(default)]
+    pub max_rpm: f64,
+    /// Maximum flow rate through the motor and bit nozzles (gpm, 0 = not rated)
+    #[serde(default)]
+    pub max_flow_gpm: f64,
+    /// Fraction of a rating a parameter must drop below a level before it
+    /// clears and can be reported again
+    #[serde(default = "default_el_rearm_margin_fraction")]
+    pub rearm_margin_fraction: f64,
+    /// Minimum seconds before a parameter is reported again at the same or a
+    /// lower level
+    #[serde(default = "default_el_report_cooldown_secs")]
+    pub report_cooldown_secs: u64,
+}
+
+fn default_el_enabled() -> bool {
+    true
+}
+fn default_el_approach_fraction() -> f64 {
+    0.9
+}
+fn default_el_rearm_margin_fraction() -> f64 {
+    0.05
+}
+fn default_el_report_cooldown_secs() -> u64 {
+    600
+}
+
+impl Default for EquipmentLimitsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_el_enabled(),
+            approach_fraction: default_el_approach_fraction(),
+            max_wob_klbs: 0.0,
+            max_rpm: 0.0,
+            max_flow_gpm: 0.0,
+            rearm_margin_fraction: default_el_rearm_margin_fraction(),
+            report_cooldown_secs: default_el_report_cooldown_secs(),
+        }
+    }
+}
+
+// ============================================================================
+// Flow Meter Check Config
+// ============================================================================
+
+/// Cross-check of reported flow-in against the flow the pumps should deliver.
+///
+/// Expected flow is `pump_spm` × `pump_output_gal_per_stroke`. A reported
+/// flow-in that disagrees by more than `max_discrepancy_pct` for
+/// `sustain_secs` points at a flow-meter fault or a suction problem, either
+/// of which blinds kick and loss detection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowMeterCheckConfig {
+    /// Enable/disable the flow-meter discrepancy advisory
+    #[serde(default = "default_fm_enabled")]
+    pub enabled: bool,
+    /// Pump output per stroke, all pumps combined (gal/stroke, 0 = not configured)
+    #[serde(default)]
+    pub pump_output_gal_per_stroke: f64,
+    /// Strokes per minute below which the pumps are not considered running
+    #[serde(default = "default_fm_min_spm")]
+    pub min_spm: f64,
+    /// Disagreement with expected flow that counts as a discrepancy (%)
+    #[serde(default = "default_fm_max_discrepancy")]
+    pub max_discrepancy_pct: f64,
+    /// How long the discrepancy must persist before it is reported (seconds)
+    #[serde(default = "default_fm_sustain")]
+    pub sustain_secs: u64,
+}
+
+fn default_fm_enabled() -> bool {
+    true
+}
+fn default_fm_min_spm() -> f64 {
+    20.0
+}
+fn default_fm_max_discrepancy() -> f64 {
+    30.0
+}
+fn default_fm_sustain() -> u64 {
+    30
+}
+
+impl Default for FlowMeterCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_fm_enabled(),
+            pump_output_gal_per_stroke: 0.0,
+            min_spm: default_fm_min_spm(),
+            max_discrepancy_pct: default_fm_max_discrepancy(),
+            sustain_secs: default_fm_sustain(),
+        }
+    }
+}
+
+// ============================================================================
+// Stick-Slip Trend Config
+// ============================================================================
+
+/// Stick-slip severity classification and trend tracking.
+///
+/// Torque CV over the last `cv_window_packets` drilling packets is banded
+/// into mild / moderate / severe using `thresholds.mechanical.stick_slip_cv_*`
+/// (mild from warning, moderate from halfway to critical, severe from
+/// critical); severe stick-slip that lasts for `damaging_after_samples`
+/// packets is classified as damaging to the RSS or motor. The trend is the
+/// CV slope over the last `trend_window` samples.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickSlipTrendConfig {
+    /// Enable/disable stick-slip severity advisories
+    #[serde(default = "default_sst_enabled")]
+    pub enabled: bool,
+    /// Drilling packets of torque per CV sample
+    #[serde(default = "default_sst_cv_window_packets")]
+    pub cv_window_packets: usize,
+    /// How far (% of the warning threshold) the CV must fall below
+    /// `stick_slip_cv_warning` before a reported episode re-arms
+    #[serde(default = "default_sst_clear_hysteresis")]
+    pub clear_hysteresis_pct: f64,
+    /// Consecutive severe samples before it is classified as damaging
+    #[serde(default = "default_sst_damaging_after_samples")]
+    pub damaging_after_samples: usize,
+    /// CV samples used for the trend slope
+    #[serde(default = "default_sst_trend_window")]
+    pub trend_window: usize,
+    /// CV slope per sample beyond which the trend is worsening / improving
+    #[serde(default = "default_sst_trend_threshold")]
+    pub trend_threshold_per_sample: f64,
+}
+
+fn default_sst_enabled() -> bool {
+    true
+}
+fn default_sst_cv_window_packets() -> usize {
+    20
+}
+fn default_sst_clear_hysteresis() -> f64 {
+    20.0
+}
+fn default_sst_damaging_after_samples() -> usize {
+    20
+}
+fn default_sst_trend_window() -> usize {
+    10
+}
+fn default_sst_trend_threshold() -> f64 {
+    0.002
+}
+
+impl Default for StickSlipTrendConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_sst_enabled(),
+            cv_window_packets: default_sst_cv_window_packets(),
+            clear_hysteresis_pct: default_sst_clear_hysteresis(),
+            damaging_after_samples: default_sst_damaging_after_samples(),
+            trend_window: default_sst_trend_window(),
+            trend_threshold_per_sample: default_sst_trend_threshold(),
+        }
+    }
+}
+
+// ============================================================================
+// Pump Event Config
+// ============================================================================
+
+/// Pump trip / changeover detection.
+///
+/// A pump event starts when flow-in and pump strokes both drop by at least
+/// `drop_pct` in one packet while pumping at `min_flow_gpm` or more; a drop
+/// to zero (pumps off) does not count. It ends when flow-in and SPP are back
+/// to `recovery_pct` of their pre-event values (loss-side flow alarms stay
+/// suppressed `settle_secs` longer), or after `max_event_secs` without
+/// recovery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PumpEventConfig {
+    /// Enable/disable pump event detection and flow-alarm suppression
+    #[serde(default = "default_pe_enabled")]
+    pub enabled: bool,
+    /// Drop in both flow-in and pump strokes in one packet (%)
+    #[serde(default = "default_pe_drop_pct")]
+    pub drop_pct: f64,
+    /// Pre-drop flow-in required to count as pumping (gpm)
+    #[serde(default = "default_pe_min_flow_gpm")]
+    pub min_flow_gpm: f64,
+    /// Flow-in and SPP recovered when back to this % of pre-event
+    #[serde(default = "default_pe_recovery_pct")]
+    pub recovery_pct: f64,
+    /// Flow alarms stay suppressed this long after recovery
+    #[serde(default = "default_pe_settle_secs")]
+    pub settle_secs: u64,
+    /// Give up on recovery after this long and log a pump failure
+    #[serde(default = "default_pe_max_event_secs")]
+    pub max_event_secs: u64,
+}
+
+fn default_pe_enabled() -> bool {
+    true
+}
+fn default_pe_drop_pct() -> f64 {
+    25.0
+}
+fn default_pe_min_flow_gpm() -> f64 {
+    100.0
+}
+fn default_pe_recovery_pct() -> f64 {
+    90.0
+}
+fn default_pe_settle_secs() -> u64 {
+    60
+}
+fn default_pe_max_event_secs() -> u64 {
+    300
+}
+
+impl Default for PumpEventConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_pe_enabled(),
+            drop_pct: default_pe_drop_pct(),
+            min_flow_gpm: default_pe_min_flow_gpm(),
+            recovery_pct: default_pe_recovery_pct(),
+            settle_secs: default_pe_settle_secs(),
+            max_event_secs: default_pe_max_event_secs(),
+        }
+    }
+}
+
+// ============================================================================
+// Hardness Calibration Config
+// ============================================================================
+
+/// Formation-hardness calibration from ML analysis.
+///
+/// Each successful ML report with at least `min_sample_count` samples gives
+/// an observed expected-ROP coefficient, smoothed with weight `smoothing` on
+/// the newest report. The calibrated coefficient replaces the fixed model
+/// once a formation (or, failing that, the well) has `min_reports` reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HardnessCalibrationConfig {
+    /// Enable/disable calibration (disabled = fixed model always)
+    #[serde(default = "default_hc_enabled")]
+    pub enabled: bool,
+    /// Qualifying reports before the calibrated coefficient is used
+    #[serde(default = "default_hc_min_reports")]
+    pub min_reports: usize,
+    /// ML samples a report needs to qualify
+    #[serde(default = "default_hc_min_sample_count")]
+    pub min_sample_count: usize,
+    /// Weight of the newest report in the smoothed coefficient (0-1]
+    #[serde(default = "default_hc_smoothing")]
+    pub smoothing: f64,
+}
+
+fn default_hc_enabled() -> bool {
+    true
+}
+fn default_hc_min_reports() -> usize {
+    3
+}
+fn default_hc_min_sample_count() -> usize {
+    900
+}
+fn default_hc_smoothing() -> f64 {
+    0.3
+}
+
+impl Default for HardnessCalibrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_hc_enabled(),
+            min_reports: default_hc_min_reports(),
+            min_sample_count: default_hc_min_sample_count(),
+            smoothing: default_hc_smoothing(),
+        }
+    }
+}
+
+// ============================================================================
+// Charts Config
+// ============================================================================
+
+/// Time-depth (`GET /api/v2/charts/time-depth`) and ROP-depth
+/// (`GET /api/v2/charts/rop-depth`) chart series, and the MSE-depth log
+/// (`GET /api/v2/logs/mse`).
+///
+/// One time-depth point is kept per `time_resolution_secs`; ROP and MSE are
+/// averaged per `depth_resolution_ft`. Served series are thinned to
+/// `max_points`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartsConfig {
+    /// Enable/disable chart series recording
+    #[serde(default = "default_charts_enabled")]
+    pub enabled: bool,
+    /// Seconds between time-depth samples
+    #[serde(default = "default_charts_time_resolution_secs")]
+    pub time_resolution_secs: u64,
+    /// Depth interval for ROP averaging (ft)
+    #[serde(default = "default_charts_depth_resolution_ft")]
+    pub depth_resolution_ft: f64,
+    /// Maximum points per served series
+    #[serde(default = "default_charts_max_points")]
+    pub max_points: usize,
+    /// Record the MSE-depth log (valid on-bottom rotary MSE only)
+    #[serde(default = "default_charts_mse_log")]
+    pub mse_log: bool,
+}
+
+fn default_charts_enabled() -> bool {
+    true
+}
+fn default_charts_time_resolution_secs() -> u64 {
+    60
+}
+fn default_charts_depth_resolution_ft() -> f64 {
+    10.0
+}
+fn default_charts_max_points() -> usize {
+    2000
+}
+fn default_charts_mse_log() -> bool {
+    true
+}
+
+impl Default for ChartsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_charts_enabled(),
+            time_resolution_secs: default_charts_time_resolution_secs(),
+            depth_resolution_ft: default_charts_depth_resolution_ft(),
+            max_points: default_charts_max_points(),
+            mse_log: default_charts_mse_log(),
+        }
+    }
+}
+
+// ============================================================================
+// CfC Health Config
+// ============================================================================
+
+/// CfC network drift monitoring (`GET /api/v2/cfc/health`).
+///
+/// Over the last `window_packets` calibrated packets a network is degraded
+/// when its mean training loss exceeds `loss_divergence_ratio` × the best
+/// window mean, when `saturation_fraction` of its scores are at or above
+/// `saturation_score`, or when its score is stuck at an extreme. With
+/// `auto_reset` a degraded network is re-initialised to recalibrate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CfcHealthConfig {
+    /// Enable/disable CfC health monitoring
+    #[serde(default = "default_ch_enabled")]
+    pub enabled: bool,
+    /// Calibrated packets per health window
+    #[serde(default = "default_ch_window_packets")]
+    pub window_packets: usize,
+    /// Recent / best window loss ratio treated as divergence
+    #[serde(default = "default_ch_loss_divergence_ratio")]
+    pub loss_divergence_ratio: f64,
+    /// Anomaly score counted as saturated
+    #[serde(default = "default_ch_saturation_score")]
+    pub saturation_score: f64,
+    /// Share of saturated scores in the window that flags degradation
+    #[serde(default = "default_ch_saturation_fraction")]
+    pub saturation_fraction: f64,
+    /// Score std below which a score at an extreme is stuck
+    #[serde(default = "default_ch_stuck_score_std")]
+    pub stuck_score_std: f64,
+    /// Re-initialise a network when it is flagged degraded
+    #[serde(default)]
+    pub auto_reset: bool,
+}
+
+fn default_ch_enabled() -> bool {
+    true
+}
+fn default_ch_window_packets() -> usize {
+    300
+}
+fn default_ch_loss_divergence_ratio() -> f64 {
+    5.0
+}
+fn default_ch_saturation_score() -> f64 {
+    0.95
+}
+fn default_ch_saturation_fraction() -> f64 {
+    0.9
+}
+fn default_ch_stuck_score_std() -> f64 {
+    1e-4
+}
+
+impl Default for CfcHealthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_ch_enabled(),
+            window_packets: default_ch_window_packets(),
+            loss_divergence_ratio: default_ch_loss_divergence_ratio(),
+            saturation_score: default_ch_saturation_score(),
+            saturation_fraction: default_ch_saturation_fraction(),
+            stuck_score_std: default_ch_stuck_score_std(),
+            auto_reset: false,
+        }
+    }
+}
+
+// ============================================================================
+// Sparkline Config
+// ============================================================================
+
+/// Trend sparklines on advisories: the triggering metric's last `length`
+/// values (with timestamps), taken from the packet history buffer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparklineConfig {
+    /// Enable/disable sparkline enrichment
+    #[serde(default = "default_sparklines_enabled")]
+    pub enabled: bool,
+    /// Samples per sparkline (at most the history buffer size)
+    #[serde(default = "default_sparklines_length")]
+    pub length: usize,
+}
+
+fn default_sparklines_enabled() -> bool {
+    true
+}
+fn default_sparklines_length() -> usize {
+    30
+}
+
+impl Default for SparklineConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_sparklines_enabled(),
+            length: default_sparklines_length(),
+        }
+    }
+}
+
+// ============================================================================
+// Gas Lag Config
+// ============================================================================
+
+/// Depth correlation of gas advisories: gas read at surface entered the
+/// annulus one bottoms-up earlier. The lag is the time taken to pump the
+/// annular volume (from `trip_parameters` geometry) plus `surface_volume_bbl`;
+/// gas advisories are tagged with the bit depth at that earlier time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasLagConfig {
+    /// Enable/disable lag-depth tagging of gas advisories
+    #[serde(default = "default_gl_enabled")]
+    pub enabled: bool,
+    /// Flowline / gas-trap volume between bell nipple and detector (bbl)
+    #[serde(default)]
+    pub surface_volume_bbl: f64,
+    /// Longest lag tracked; older depth/flow samples are dropped (seconds)
+    #[serde(default = "default_gl_max_lag")]
+    pub max_lag_secs: u64,
+}
+
+fn default_gl_enabled() -> bool {
+    true
+}
+fn default_gl_max_lag() -> u64 {
+    4 * 3600
+}
+
+impl Default for GasLagConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_gl_enabled(),
+            surface_volume_bbl: 0.0,
+            max_lag_secs: default_gl_max_lag(),
+        }
+    }
+}
+
+// ============================================================================
+// Gas Migration Config
+// ============================================================================
+
+/// Gas-migration detection: with the pumps off and the pipe stationary, a
+/// casing (or annular) pressure rise of at least `min_rise_psi` at
+/// `min_rate_psi_per_min` or more after `min_static_secs` raises a
+/// WellControl advisory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasMigrationConfig {
+    /// Enable/disable gas-migration detection
+    #[serde(default = "default_gm_enabled")]
+    pub enabled: bool,
+    /// Flow-in below which the pumps are considered off (gpm)
+    #[serde(default = "default_gm_pump_off_flow_gpm")]
+    pub pump_off_flow_gpm: f64,
+    /// Static time before a build-up is evaluated (s)
+    #[serde(default = "default_gm_min_static_secs")]
+    pub min_static_secs: u64,
+    /// Minimum pressure rise since pumps off (psi)
+    #[serde(default = "default_gm_min_rise_psi")]
+    pub min_rise_psi: f64,
+    /// Minimum average build-up rate (psi/min)
+    #[serde(default = "default_gm_min_rate_psi_per_min")]
+    pub min_rate_psi_per_min: f64,
+    /// Bit movement that restarts the static baseline (ft)
+    #[serde(default = "default_gm_max_pipe_movement_ft")]
+    pub max_pipe_movement_ft: f64,
+}
+
+fn default_gm_enabled() -> bool {
+    true
+}
+fn default_gm_pump_off_flow_gpm() -> f64 {
+    50.0
+}
+fn default_gm_min_static_secs() -> u64 {
+    120
+}
+fn default_gm_min_rise_psi() -> f64 {
+    50.0
+}
+fn default_gm_min_rate_psi_per_min() -> f64 {
+    5.0
+}
+fn default_gm_max_pipe_movement_ft() -> f64 {
+    5.0
+}
+
+impl Default for GasMigrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_gm_enabled(),
+            pump_off_flow_gpm: default_gm_pump_off_flow_gpm(),
+            min_static_secs: default_gm_min_static_secs(),
+            min_rise_psi: default_gm_min_rise_psi(),
+            min_rate_psi_per_min: default_gm_min_rate_psi_per_min(),
+            max_pipe_movement_ft: default_gm_max_pipe_movement_ft(),
+        }
+    }
+}
+
+// ============================================================================
+// Directional Config
+// ============================================================================
+
+/// Slide / rotate / survey detection for directional wells.
+///
+/// On bottom with pumps on and no surface rotation (`rpm < slide_rpm_max`,
+/// `wob >= slide_wob_min`, `flow_in >= slide_flow_min_gpm`) is a slide,
+/// classified `RigState::Sliding` so MSE and efficiency logic stay
+/// rotary-only. Slides are judged against `slide_rop_ratio` × the recent
+/// rotating ROP. Stationary circulating within `survey_max_off_bottom_ft`
+/// of bottom for `survey_min_secs`..`survey_max_secs` is a survey station.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalConfig {
+    /// Enable/disable slide and survey detection
+    #[serde(default = "default_dir_enabled")]
+    pub enabled: bool,
+    /// Surface RPM below this while on bottom is sliding
+    #[serde(default = "default_dir_slide_rpm_max")]
+    pub slide_rpm_max: f64,
+    /// Minimum WOB for a slide (klbs)
+    #[serde(default = "default_dir_slide_wob_min")]
+    pub slide_wob_min: f64,
+    /// Minimum flow to drive the motor while sliding (gpm)
+    #[serde(default = "default_dir_slide_flow_min_gpm")]
+    pub slide_flow_min_gpm: f64,
+    /// Expected slide ROP as a fraction of the recent rotating ROP
+    #[serde(default = "default_dir_slide_rop_ratio")]
+    pub slide_rop_ratio: f64,
+    /// Rotating packets averaged for the rotating ROP reference
+    #[serde(default = "default_dir_rotating_rop_window")]
+    pub rotating_rop_window: usize,
+    /// Minimum stationary circulating time for a survey station (s)
+    #[serde(default = "default_dir_survey_min_secs")]
+    pub survey_min_secs: u64,
+    /// Longer stationary periods are not surveys (s)
+    #[serde(default = "default_dir_survey_max_secs")]
+    pub survey_max_secs: u64,
+    /// Bit movement that ends a survey station (ft)
+    #[serde(default = "default_dir_survey_max_pipe_movement_ft")]
+    pub survey_max_pipe_movement_ft: f64,
+    /// Maximum bit distance off bottom for a survey station (ft)
+    #[serde(default = "default_dir_survey_max_off_bottom_ft")]
+    pub survey_max_off_bottom_ft: f64,
+    /// Completed slides and survey stations retained
+    #[serde(default = "default_dir_max_logged")]
+    pub max_logged: usize,
+}
+
+fn default_dir_enabled() -> bool {
+    true
+}
+fn default_dir_slide_rpm_max() -> f64 {
+    5.0
+}
+fn default_dir_slide_wob_min() -> f64 {
+    5.0
+}
+fn default_dir_slide_flow_min_gpm() -> f64 {
+    200.0
+}
+fn default_dir_slide_rop_ratio() -> f64 {
+    0.5
+}
+fn default_dir_rotating_rop_window() -> usize {
+    300
+}
+fn default_dir_survey_min_secs() -> u64 {
+    30
+}
+fn default_dir_survey_max_secs() -> u64 {
+    600
+}
+fn default_dir_survey_max_pipe_movement_ft() -> f64 {
+    2.0
+}
+fn default_dir_survey_max_off_bottom_ft() -> f64 {
+    100.0
+}
+fn default_dir_max_logged() -> usize {
+    50
+}
+
+impl Default for DirectionalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_dir_enabled(),
+            slide_rpm_max: default_dir_slide_rpm_max(),
+            slide_wob_min: default_dir_slide_wob_min(),
+            slide_flow_min_gpm: default_dir_slide_flow_min_gpm(),
+            slide_rop_ratio: default_dir_slide_rop_ratio(),
+            rotating_rop_window: default_dir_rotating_rop_window(),
+            survey_min_secs: default_dir_survey_min_secs(),
+            survey_max_secs: default_dir_survey_max_secs(),
+            survey_max_pipe_movement_ft: default_dir_survey_max_pipe_movement_ft(),
+            survey_max_off_bottom_ft: default_dir_survey_max_off_bottom_ft(),
+            max_logged: default_dir_max_logged(),
+        }
+    }
+}
+
+// ============================================================================
+// KB Snapshot Retry Config
+// ============================================================================
+
+/// Retry of failed mid-well knowledge-base snapshot writes.
+///
+/// A failed write is retried `retry_attempts` times with a backoff starting
+/// at `retry_backoff_ms` and doubling. Snapshots that still fail (e.g. disk
+/// full) are held in memory, oldest dropped beyond `max_pending`, and
+/// flushed in order once a write succeeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KbSnapshotConfig {
+    /// Enable retry and buffering (disabled: failed snapshots are dropped)
+    #[serde(default = "default_kbs_enabled")]
+    pub enabled: bool,
+    /// Retries after the first failed attempt
+    #[serde(default = "default_kbs_retry_attempts")]
+    pub retry_attempts: u32,
+    /// Backoff before the first retry (ms), doubled per retry
+    #[serde(default = "default_kbs_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+    /// Unwritten snapshots held in memory
+    #[serde(default = "default_kbs_max_pending")]
+    pub max_pending: usize,
+}
+
+fn default_kbs_enabled() -> bool {
+    true
+}
+fn default_kbs_retry_attempts() -> u32 {
+    3
+}
+fn default_kbs_retry_backoff_ms() -> u64 {
+    500
+}
+fn default_kbs_max_pending() -> usize {
+    48
+}
+
+impl Default for KbSnapshotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_kbs_enabled(),
+            retry_attempts: default_kbs_retry_attempts(),
+            retry_backoff_ms: default_kbs_retry_backoff_ms(),
+            max_pending: default_kbs_max_pending(),
+        }
+    }
+}
+
+// ============================================================================
+// Config Sanity Check
+// ============================================================================
+
+/// Detection of a config written for a different well or bit size.
+///
+/// After `min_packets` packets the configured `well.bit_diameter_inches` is
+/// compared with the median bit diameter reported by the feed, and locked
+/// baselines are compared with field-typical ranges. Results are served at
+/// `GET /api/v2/config/sanity`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSanityConfig {
+    #[serde(default = "default_cs_enabled")]
+    pub enabled: bool,
+    /// Packets observed before the check runs
+    #[serde(default = "default_cs_min_packets")]
+    pub min_packets: usize,
+    /// Allowed difference between configured and reported bit size (inches)
+    #[serde(default = "default_cs_bit_diameter_tolerance")]
+    pub bit_diameter_tolerance_in: f64,
+}
+
+fn default_cs_enabled() -> bool {
+    true
+}
+fn default_cs_min_packets() -> usize {
+    300
+}
+fn default_cs_bit_diameter_tolerance() -> f64 {
+    0.25
+}
+
+impl Default for ConfigSanityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_cs_enabled(),
+            min_packets: default_cs_min_packets(),
+            bit_diameter_tolerance_in: default_cs_bit_diameter_tolerance(),
+        }
+    }
+}
+
+// ============================================================================
+// MPD Choke Adjustments
+// ============================================================================
+
+/// Contextualizing deliberate MPD choke moves.
+///
+/// A choke move is either declared by the operator
+/// (`POST /api/v2/mpd/choke-adjustment`) or detected when
+/// `choke_position_pct` changes by `min_choke_move_pct` within
+/// `detect_window_secs`. SPP changes are treated as operator-induced until
+/// `context_secs` after the last move.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MpdConfig {
+    #[serde(default = "default_mpd_enabled")]
+    pub enabled: bool,
+    /// Choke position change (% open) that counts as an adjustment
+    #[serde(default = "default_mpd_min_choke_move_pct")]
+    pub min_choke_move_pct: f64,
+    /// Window the position change is measured over (seconds)
+    #[serde(default = "default_mpd_detect_window_secs")]
+    pub detect_window_secs: u64,
+    /// How long pressure changes stay attributed to the choke (seconds)
+    #[serde(default = "default_mpd_context_secs")]
+    pub context_secs: u64,
+}
+
+fn default_mpd_enabled() -> bool {
+    true
+}
+fn default_mpd_min_choke_move_pct() -> f64 {
+    5.0
+}
+fn default_mpd_detect_window_secs() -> u64 {
+    30
+}
+fn default_mpd_context_secs() -> u64 {
+    120
+}
+
+impl Default for MpdConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_mpd_enabled(),
+            min_choke_move_pct: default_mpd_min_choke_move_pct(),
+            detect_window_secs: default_mpd_detect_window_secs(),
+            context_secs: default_mpd_context_secs(),
+        }
+    }
+}
+
+// ============================================================================
+// Detection Benchmark
+// ============================================================================
+
+/// Detection latency benchmark against the faults injected into synthetic
+/// data (run without `--csv`/`--stdin`/`--wits-tcp`).
+///
+/// The first advisory of a fault's expected category within
+/// `max_latency_secs` of its onset is the detection. Results are served at
+/// `GET /api/v2/benchmark/detection`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectionBenchmarkConfig {
+    #[serde(default = "default_dbench_enabled")]
+    pub enabled: bool,
+    /// Later advisories count as a missed detection (seconds after onset)
+    #[serde(default = "default_dbench_max_latency_secs")]
+    pub max_latency_secs: u64,
+}
+
+fn default_dbench_enabled() -> bool {
+    true
+}
+fn default_dbench_max_latency_secs() -> u64 {
+    900
+}
+
+impl Default for DetectionBenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_dbench_enabled(),
+            max_latency_secs: default_dbench_max_latency_secs(),
+        }
+    }
+}
+
+// ============================================================================
+// Optimization Config
+// ============================================================================
+
+/// WOB step recommendations (`optimization::recommend_step`).
+///
+/// While ROP still responds linearly to WOB the next step is an increase;
+/// once the founder point is detected it is a decrease. Either way a single
+/// step never moves WOB by more than `max_wob_step_klbs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizationConfig {
+    /// Largest WOB change one recommended step may propose (klbs)
+    #[serde(default = "default_opt_max_wob_step_klbs")]
+    pub max_wob_step_klbs: f64,
+}
+
+fn default_opt_max_wob_step_klbs() -> f64 {
+    2.0
+}
+
+impl Default for OptimizationConfig {
+    fn default() -> Self {
+        Self {
+            max_wob_step_klbs: default_opt_max_wob_step_klbs(),
+        }
+    }
+}
+
+// ============================================================================
+// Connection Scorecard Config
+// ============================================================================
+
+/// Connection-practices scorecard (`GET /api/v2/connections/scorecard`).
+///
+/// Each category scores 100 at zero and 0 at its limit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionScorecardConfig {
+    /// Enable/disable connection logging for the scorecard
+    #[serde(default = "default_csc_enabled")]
+    pub enabled: bool,
+    /// Connections retained for the scorecard (oldest dropped first)
+    #[serde(default = "default_csc_max_connections")]
+    pub max_connections: usize,
+    /// Duration coefficient of variation that scores 0 for consistency
+    #[serde(default = "default_csc_duration_cv_limit")]
+    pub duration_cv_limit: f64,
+    /// Mean connection gas delta (units) that scores 0 for gas control
+    #[serde(default = "default_csc_gas_delta_limit")]
+    pub gas_delta_limit_units: f64,
+    /// Mean pump-restart SPP overshoot (%) that scores 0 for pressure management
+    #[serde(default = "default_csc_spp_overshoot_limit")]
+    pub spp_overshoot_limit_pct: f64,
+    /// A connection is an outlier when a metric exceeds this multiple of its median
+    #[serde(default = "default_csc_outlier_ratio")]
+    pub outlier_ratio: f64,
+}
+
+fn default_csc_enabled() -> bool {
+    true
+}
+fn default_csc_max_connections() -> usize {
+    500
+}
+fn default_csc_duration_cv_limit() -> f64 {
+    0.5
+}
+fn default_csc_gas_delta_limit() -> f64 {
+    50.0
+}
+fn default_csc_spp_overshoot_limit() -> f64 {
+    20.0
+}
+fn default_csc_outlier_ratio() -> f64 {
+    2.0
+}
+
+impl Default for ConnectionScorecardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_csc_enabled(),
+            max_connections: default_csc_max_connections(),
+            duration_cv_limit: default_csc_duration_cv_limit(),
+            gas_delta_limit_units: default_csc_gas_delta_limit(),
+            spp_overshoot_limit_pct: default_csc_spp_overshoot_limit(),
+            outlier_ratio: default_csc_outlier_ratio(),
+        }
+    }
+}
+
+// ============================================================================
+// Bit Wear Config
+// ============================================================================
+
+/// Bit wear and footage integration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitWearConfig {
+    /// Accumulate footage and MSE only past the hole-depth high-water mark,
+    /// so hole re-drilled after a pick-up is not counted twice
+    #[serde(default = "default_bw_new_hole_only")]
+    pub new_hole_only: bool,
+}
+
+fn default_bw_new_hole_only() -> bool {
+    true
+}
+
+impl Default for BitWearConfig {
+    fn default() -> Self {
+        Self {
+            new_hole_only: default_bw_new_hole_only(),
+        }
+    }
+}
+
+// ============================================================================
+// Debrief Config
+// ============================================================================
+
+/// Post-well debrief generation (`POST /api/v2/well/debrief`).
+///
+/// Same-category advisories within `cluster_distance_ft` of each other are
+/// grouped, and the narrative summarizes recurring groups instead of listing
+/// every event. With `threshold_recommendations`, the well's feedback is
+/// turned into concrete threshold changes and a config patch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebriefConfig {
+    /// Group advisories by category and depth proximity
+    #[serde(default = "default_db_cluster")]
+    pub cluster_advisories: bool,
+    /// Maximum depth gap between neighbouring advisories in a cluster (ft)
+    #[serde(default = "default_db_cluster_distance")]
+    pub cluster_distance_ft: f64,
+    /// Advisories needed before a cluster is reported as a recurring issue
+    #[serde(default = "default_db_min_cluster")]
+    pub min_cluster_size: usize,
+    /// Turn the well's feedback into recommended threshold changes
+    #[serde(default = "default_db_threshold_recommendations")]
+    pub threshold_recommendations: bool,
+}
+
+fn default_db_cluster() -> bool {
+    true
+}
+fn default_db_cluster_distance() -> f64 {
+    250.0
+}
+fn default_db_min_cluster() -> usize {
+    3
+}
+fn default_db_threshold_recommendations() -> bool {
+    true
+}
+
+impl Default for DebriefConfig {
+    fn default() -> Self {
+        Self {
+            cluster_advisories: default_db_cluster(),
+            cluster_distance_ft: default_db_cluster_distance(),
+            min_cluster_size: default_db_min_cluster(),
+            threshold_recommendations: default_db_threshold_recommendations(),
+        }
+    }
+}
+
+// ============================================================================
+// CfC Network Config
+// ============================================================================
+
+/// Smallest supported CfC network (24 sensory + 8 motor + 8 hidden).
+const CFC_MIN_NEURONS: usize = 40;
+/// Largest supported CfC network.
+const CFC_MAX_NEURONS: usize = 512;
+
+/// CfC neuron counts for the dual fast/slow networks.
+///
+/// The 24 sensory and 8 motor neurons are fixed; the rest are split between
+/// the inter and command groups by `inter_fraction`. Per-packet training
+/// cost grows roughly with the square of the hidden neuron count, so size
+/// the networks to the host: the default 64/64 suits a 4-core edge box.
+/// Checkpoints only restore into a network of the same size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CfcConfig {
+    /// Fast (acute-event) network neurons
+    #[serde(default = "default_cfc_neurons")]
+    pub fast_neurons: usize,
+    /// Slow (gradual-trend) network neurons
+    #[serde(default = "default_cfc_neurons")]
+    pub slow_neurons: usize,
+    /// Share of the hidden neurons in the inter group (rest are command)
+    #[serde(default = "default_cfc_inter_fraction")]
+    pub inter_fraction: f64,
+    /// Regime centroids closer than this (motor-output Euclidean distance)
+    /// are merged into one; 0 disables merging
+    #[serde(default = "default_cfc_regime_merge_distance")]
+    pub regime_merge_distance: f64,
+    /// Packets between regime merge passes
+    #[serde(default = "default_cfc_regime_merge_interval_packets")]
+    pub regime_merge_interval_packets: u64,
+}
+
+fn default_cfc_neurons() -> usize {
+    64
+}
+fn default_cfc_inter_fraction() -> f64 {
+    0.625
+}
+fn default_cfc_regime_merge_distance() -> f64 {
+    0.05
+}
+fn default_cfc_regime_merge_interval_packets() -> u64 {
+    600
+}
+
+impl Default for CfcConfig {
+    fn default() -> Self {
+        Self {
+            fast_neurons: default_cfc_neurons(),
+            slow_neurons: default_cfc_neurons(),
+            inter_fraction: default_cfc_inter_fraction(),
+            regime_merge_distance: default_cfc_regime_merge_distance(),
+            regime_merge_interval_packets: default_cfc_regime_merge_interval_packets(),
+        }
+    }
+}
+
+// ============================================================================
+// CfC Feature Sets Config
+// ============================================================================
+
+/// Per-feature weights in the CfC training loss, one field per input feature
+/// (see `cfc::normalizer::FEATURE_NAMES`). Features left out of a table
+/// weigh 1.0; a weight of 0 stops the networks learning to predict that
+/// feature, so it no longer drives the anomaly score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CfcFeatureWeights {
+    /// Weight on bit
+    #[serde(default = "default_cfs_weight")]
+    pub wob: f64,
+    /// Rate of penetration
+    #[serde(default = "default_cfs_weight")]
+    pub rop: f64,
+    /// Rotary speed
+    #[serde(default = "default_cfs_weight")]
+    pub rpm: f64,
+    /// Surface torque
+    #[serde(default = "default_cfs_weight")]
+    pub torque: f64,
+    /// Mechanical specific energy
+    #[serde(default = "default_cfs_weight")]
+    pub mse: f64,
+    /// Standpipe pressure
+    #[serde(default = "default_cfs_weight")]
+    pub spp: f64,
+    /// D-exponent
+    #[serde(default = "default_cfs_weight")]
+    pub d_exponent: f64,
+    /// Hook load
+    #[serde(default = "default_cfs_weight")]
+    pub hookload: f64,
+    /// Equivalent circulating density
+    #[serde(default = "default_cfs_weight")]
+    pub ecd: f64,
+    /// Flow out − flow in
+    #[serde(default = "default_cfs_weight")]
+    pub flow_balance: f64,
+    /// Pit volume rate of change
+    #[serde(default = "default_cfs_weight")]
+    pub pit_rate: f64,
+    /// Corrected d-exponent
+    #[serde(default = "default_cfs_weight")]
+    pub dxc: f64,
+    /// Pump strokes per minute
+    #[serde(default = "default_cfs_weight")]
+    pub pump_spm: f64,
+    /// Mud weight in
+    #[serde(default = "default_cfs_weight")]
+    pub mud_weight_in: f64,
+    /// Total gas
+    #[serde(default = "default_cfs_weight")]
+    pub gas_units: f64,
+    /// Active pit volume
+    #[serde(default = "default_cfs_weight")]
+    pub pit_volume: f64,
+}
+
+fn default_cfs_weight() -> f64 {
+    1.0
+}
+
+impl CfcFeatureWeights {
+    /// Drilling weighting: primary drilling features (WOB through hookload) 2x.
+    pub fn drilling() -> Self {
+        Self {
+            wob: 2.0,
+            rop: 2.0,
+            rpm: 2.0,
+            torque: 2.0,
+            mse: 2.0,
+            spp: 2.0,
+            d_exponent: 2.0,
+            hookload: 2.0,
+            ecd: 1.0,
+            flow_balance: 1.0,
+            pit_rate: 1.0,
+            dxc: 1.0,
+            pump_spm: 1.0,
+            mud_weight_in: 1.0,
+            gas_units: 1.0,
+            pit_volume: 1.0,
+        }
+    }
+
+    /// P&A weighting: pressure, flow and pit signals of cementing and
+    /// pressure testing dominate; ROP, MSE and d-exponent barely matter.
+    pub fn plug_abandonment() -> Self {
+        Self {
+            wob: 0.5,
+            rop: 0.25,
+            rpm: 0.5,
+            torque: 0.5,
+            mse: 0.25,
+            spp: 3.0,
+            d_exponent: 0.25,
+            hookload: 1.0,
+            ecd: 2.0,
+            flow_balance: 3.0,
+            pit_rate: 2.0,
+            dxc: 0.25,
+            pump_spm: 1.5,
+            mud_weight_in: 1.5,
+            gas_units: 1.5,
+            pit_volume: 2.0,
+        }
+    }
+
+    /// Weights in CfC feature order.
+    pub fn to_array(&self) -> [f64; crate::cfc::NUM_FEATURES] {
+        [
+            self.wob,
+            self.rop,
+            self.rpm,
+            self.torque,
+            self.mse,
+            self.spp,
+            self.d_exponent,
+            self.hookload,
+            self.ecd,
+            self.flow_balance,
+            self.pit_rate,
+            self.dxc,
+            self.pump_spm,
+            self.mud_weight_in,
+            self.gas_units,
+            self.pit_volume,
+        ]
+    }
+}
+
+/// Campaign-specific CfC feature sets: the weights for the active campaign
+/// are applied to both networks' training loss, so P&A networks spend their
+/// capacity on pressure and flow rather than ROP and MSE.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CfcFeatureSetsConfig {
+    /// Select weights by campaign (off = production weights for every campaign)
+    #[serde(default = "default_cfs_enabled")]
+    pub enabled: bool,
+    /// Weights while drilling (production campaign)
+    #[serde(default = "CfcFeatureWeights::drilling")]
+    pub production: CfcFeatureWeights,
+    /// Weights during plug & abandonment
+    #[serde(default = "CfcFeatureWeights::plug_abandonment")]
+    pub plug_abandonment: CfcFeatureWeights,
+}
+
+fn default_cfs_enabled() -> bool {
+    true
+}
+
+impl CfcFeatureSetsConfig {
+    /// Weights in CfC feature order for a campaign.
+    pub fn weights_for(&self, campaign: crate::types::Campaign) -> [f64; crate::cfc::NUM_FEATURES] {
+        match campaign {
+            crate::types::Campaign::PlugAbandonment if self.enabled => {
+                self.plug_abandonment.to_array()
+            }
+            _ => self.production.to_array(),
+        }
+    }
+}
+
+impl Default for CfcFeatureSetsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_cfs_enabled(),
+            production: CfcFeatureWeights::drilling(),
+            plug_abandonment: CfcFeatureWeights::plug_abandonment(),
+        }
+    }
+}
+
+// ============================================================================
+// Episode Scoring Config
+// ============================================================================
+
+/// Re-scoring of fleet episodes in RAM Recall.
+///
+/// An episode's score is its ROP and MSE efficiency relative to the fleet
+/// benchmarks for its category. Benchmarks drift as the library grows, so
+/// every `rescore_interval_secs` all episodes are scored again against the
+/// current library.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpisodeScoringConfig {
+    /// Periodically re-score episodes
+    #[serde(default = "default_es_enabled")]
+    pub enabled: bool,
+    /// Minimum time between re-scoring passes (seconds)
+    #[serde(default = "default_es_interval")]
+    pub rescore_interval_secs: u64,
+}
+
+fn default_es_enabled() -> bool {
+    true
+}
+fn default_es_interval() -> u64 {
+    3600
+}
+
+impl Default for EpisodeScoringConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_es_enabled(),
+            rescore_interval_secs: default_es_interval(),
+        }
+    }
+}
+
+// ============================================================================
+// Replay Config
+// ============================================================================
+
+/// Accepted values for `replay.eof_behavior`.
+pub const REPLAY_EOF_BEHAVIORS: [&str; 3] = ["halt", "loop", "complete"];
+
+/// End-of-data handling for CSV / synthetic replays.
+///
+/// - `halt`: stop processing and mark the replay complete on the dashboard
+/// - `loop`: restart from the first packet
+/// - `complete`: treat EOF as well complete — generate the post-well summary
+///   and debrief, then mark the replay complete
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayConfig {
+    /// One of "halt", "loop", "complete"
+    #[serde(default = "default_replay_eof_behavior")]
+    pub eof_behavior: String,
+}
+
+fn default_replay_eof_behavior() -> String {
+    "halt".to_string()
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            eof_behavior: default_replay_eof_behavior(),
+        }
+    }
+}
+
+// ============================================================================
+// Acquisition Config
+// ============================================================================
+
+/// WITS Level 0 TCP acquisition.
+///
+/// When the link drops the source retries indefinitely, doubling the delay
+/// from `reconnect_base_ms` up to `reconnect_max_ms`, until it reconnects
+/// or the process shuts down. `wits_item_map_path` optionally names a TOML
+/// file mapping extra WITS record:item codes onto packet fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcquisitionConfig {
+    /// Delay before the first reconnect attempt (ms)
+    #[serde(default = "default_acq_reconnect_base_ms")]
+    pub reconnect_base_ms: u64,
+    /// Cap on the doubled reconnect delay (ms)
+    #[serde(default = "default_acq_reconnect_max_ms")]
+    pub reconnect_max_ms: u64,
+    /// TOML overlay of WITS item codes on the built-in Record 01 map
+    #[serde(default)]
+    pub wits_item_map_path: Option<String>,
+}
+
+fn default_acq_reconnect_base_ms() -> u64 {
+    1000
+}
+fn default_acq_reconnect_max_ms() -> u64 {
+    60_000
+}
+
+impl Default for AcquisitionConfig {
+    fn default() -> Self {
+        Self {
+            reconnect_base_ms: default_acq_reconnect_base_ms(),
+            reconnect_max_ms: default_acq_reconnect_max_ms(),
+            wits_item_map_path: None,
+        }
+    }
+}
+
+// ============================================================================
+// Feedback Config
+// ============================================================================
+
+/// Operator feedback prompt settings.
+///
+/// Each advisory category carries a tailored question telling the operator
+/// what to assess, plus structured follow-up fields (actual cause, action
+/// taken). Empty question strings fall back to the built-in wording.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackConfig {
+    /// Attach category-specific prompts to advisories
+    #[serde(default = "default_fb_structured_prompts")]
+    pub structured_prompts: bool,
+    /// Question override for well control advisories
+    #[serde(default)]
+    pub well_control_prompt: String,
+    /// Question override for drilling efficiency advisories
+    #[serde(default)]
+    pub drilling_efficiency_prompt: String,
+    /// Question override for hydraulics advisories
+    #[serde(default)]
+    pub hydraulics_prompt: String,
+    /// Question override for mechanical advisories
+    #[serde(default)]
+    pub mechanical_prompt: String,
+    /// Question override for formation advisories
+    #[serde(default)]
+    pub formation_prompt: String,
+    /// Rated advisories a trigger needs to appear in the false-positive sources report
+    #[serde(default = "default_fb_fp_source_min_rated")]
+    pub fp_source_min_rated: usize,
+    /// False-positive rate at which a trigger gets a threshold suggestion
+    #[serde(default = "default_fb_fp_source_suggest_rate")]
+    pub fp_source_suggest_rate: f64,
+    /// Hold back below-High advisories in categories operators keep rejecting
+    /// on this well (off by default: it changes ticket outcomes)
+    #[serde(default = "default_fb_suppression_enabled")]
+    pub suppression_enabled: bool,
+    /// Rated (confirmed + false positive) advisories before a category can be suppressed
+    #[serde(default = "default_fb_suppression_min_rated")]
+    pub suppression_min_rated: usize,
+    /// Confirmation rate below which a category is suppressed
+    #[serde(default = "default_fb_suppression_confirmation_floor")]
+    pub suppression_confirmation_floor: f64,
+}
+
+fn default_fb_structured_prompts() -> bool {
+    true
+}
+fn default_fb_fp_source_min_rated() -> usize {
+    5
+}
+fn default_fb_fp_source_suggest_rate() -> f64 {
+    0.5
+}
+fn default_fb_suppression_enabled() -> bool {
+    false
+}
+fn default_fb_suppression_min_rated() -> usize {
+    10
+}
+fn default_fb_suppression_confirmation_floor() -> f64 {
+    0.2
+}
+
+impl Default for FeedbackConfig {
+    fn default() -> Self {
+        Self {
+            structured_prompts: default_fb_structured_prompts(),
+            well_control_prompt: String::new(),
+            drilling_efficiency_prompt: String::new(),
+            hydraulics_prompt: String::new(),
+            mechanical_prompt: String::new(),
+            formation_prompt: String::new(),
+            fp_source_min_rated: default_fb_fp_source_min_rated(),
+            fp_source_suggest_rate: default_fb_fp_source_suggest_rate(),
+            suppression_enabled: default_fb_suppression_enabled(),
+            suppression_min_rated: default_fb_suppression_min_rated(),
+            suppression_confirmation_floor: default_fb_suppression_confirmation_floor(),
+        }
+    }
+}
+
+/// A formation top entry — maps a depth to a formation name.
+///
+/// The formation tops table is provided by the geologist before spud.
+/// Entries should be ordered by depth (shallowest first). The current
+/// formation is determined by finding the deepest entry whose depth
+/// is <= the current bit depth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormationTop {
+    /// Top of formation (measured depth in feet).
+    pub depth_ft: f64,
+    /// Formation name (e.g. "shale", "limestone", "sandstone").
+    pub formation: String,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_validates() {
+        let config = WellConfig::default();
+        assert!(
+            config.validate().is_ok(),
+            "Default config must always validate"
+        );
+    }
+
+    #[test]
+    fn test_empty_toml_produces_defaults() {
+        let config: WellConfig = toml::from_str("").expect("empty TOML should parse");
+        assert_eq!(
+            config.thresholds.well_control.flow_imbalance_warning_gpm,
+            5.0
+        );
+        assert_eq!(config.thresholds.mse.efficiency_warning_percent, 70.0);
+        assert_eq!(config.thresholds.hydraulics.normal_mud_weight_ppg, 8.6);
+        assert_eq!(config.baseline_learning.warning_sigma, 3.0);
+        assert_eq!(config.ensemble_weights.well_control, 0.30);
     }
 
     #[test]
@@ -2025,6 +5263,34 @@ flow_imbalance_critical_gpm = 50.0
         assert!(result.is_err(), "Weights summing to 2.0 should fail");
     }
 
+    #[test]
+    fn test_validation_catches_bad_orchestrator_weights() {
+        let mut config = WellConfig::default();
+        config.orchestrator.formation = Some(0.0);
+        config.orchestrator.mse = Some(2.0);
+        assert!(
+            config.validate().is_ok(),
+            "Weights are normalised, sum is free"
+        );
+
+        config.orchestrator.mse = Some(-1.0);
+        assert!(config.validate().is_err(), "Negative weight should fail");
+
+        config.orchestrator.mse = Some(0.0);
+        assert!(
+            config.validate().is_ok(),
+            "Unlisted specialists keep their ensemble weights"
+        );
+
+        config.orchestrator.well_control = Some(0.0);
+        assert!(config.validate().is_err(), "WellControl must keep a vote");
+
+        let mut config = WellConfig::default();
+        config.ensemble_weights.well_control = 0.0;
+        config.ensemble_weights.mse = 0.5;
+        assert!(config.validate().is_err(), "WellControl must keep a vote");
+    }
+
     #[test]
     fn test_validation_catches_bad_baseline() {
         let mut config = WellConfig::default();
@@ -2097,6 +5363,21 @@ flow_imbalance_critical_gpm = 50.0
         assert!(result.is_err(), "Critical ECD margin > warning should fail");
     }
 
+    #[test]
+    fn test_cfc_sizing_validation() {
+        let mut config = WellConfig::default();
+        config.cfc.fast_neurons = 128;
+        config.cfc.slow_neurons = 48;
+        assert!(config.validate().is_ok(), "128/48 neurons should be valid");
+
+        config.cfc.slow_neurons = 32;
+        assert!(config.validate().is_err(), "32 neurons leaves no hidden layer");
+
+        config.cfc.slow_neurons = 64;
+        config.cfc.inter_fraction = 0.3;
+        assert!(config.validate().is_err(), "inter smaller than command should fail");
+    }
+
     #[test]
     fn test_all_fields_serialize() {
         // Ensure no fields are silently skipped during serialization
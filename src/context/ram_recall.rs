@@ -10,9 +10,16 @@
 //! O(n) but fast for <10,000 episodes. For larger fleet libraries, swap
 //! the scoring path with an HNSW index (e.g., `instant-distance` crate)
 //! for O(log n) approximate nearest neighbor search.
+//!
+//! ## Re-scoring
+//!
+//! Episode scores are relative to the fleet benchmarks for their category.
+//! As the library grows those benchmarks drift, so episodes are re-scored
+//! against the current library at most once per re-scoring interval.
 
 use crate::context::knowledge_store::KnowledgeStore;
-use crate::fleet::types::{EpisodeMetrics, FleetEpisode};
+use crate::config::EpisodeScoringConfig;
+use crate::fleet::types::{score_episode, EpisodeBenchmarks, EpisodeMetrics, FleetEpisode};
 use crate::types::{AnomalyCategory, Campaign};
 use std::sync::RwLock;
 use tracing::debug;
@@ -24,14 +31,68 @@ const MAX_EPISODES: usize = 10_000;
 pub struct RAMRecall {
     /// Fleet episodes indexed in memory
     episodes: RwLock<Vec<FleetEpisode>>,
+    /// Re-scoring settings
+    scoring: EpisodeScoringConfig,
+    /// Unix time of the last re-scoring pass
+    last_rescore: RwLock<Option<u64>>,
 }
 
 impl RAMRecall {
     /// Create an empty RAM Recall store
     pub fn new() -> Self {
+        Self::with_scoring(EpisodeScoringConfig::default())
+    }
+
+    /// Create an empty store with the given re-scoring settings
+    pub fn with_scoring(scoring: EpisodeScoringConfig) -> Self {
         Self {
             episodes: RwLock::new(Vec::new()),
+            scoring,
+            last_rescore: RwLock::new(None),
+        }
+    }
+
+    /// Re-score every episode against benchmarks from the current library.
+    ///
+    /// Returns the number of episodes scored.
+    pub fn rescore(&self) -> usize {
+        let mut store = self.episodes.write().unwrap_or_else(|e| e.into_inner());
+        let benchmarks = EpisodeBenchmarks::from_episodes(&store);
+        for ep in store.iter_mut() {
+            ep.score = score_episode(ep, &benchmarks);
         }
+        debug!(count = store.len(), "RAMRecall re-scored episodes");
+        store.len()
+    }
+
+    /// Re-score if enabled and the interval has elapsed since the last pass.
+    ///
+    /// Returns `true` if a pass ran.
+    pub fn rescore_if_due(&self, now_secs: u64) -> bool {
+        if !self.scoring.enabled {
+            return false;
+        }
+        {
+            let mut last = self.last_rescore.write().unwrap_or_else(|e| e.into_inner());
+            if let Some(prev) = *last {
+                if now_secs.saturating_sub(prev) < self.scoring.rescore_interval_secs {
+                    return false;
+                }
+            }
+            *last = Some(now_secs);
+        }
+        self.rescore();
+        true
+    }
+
+    /// Score of an episode by ID
+    pub fn episode_score(&self, id: &str) -> Option<f64> {
+        self.episodes
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|ep| ep.id == id)
+            .map(|ep| ep.score)
     }
 
     /// Load episodes into memory (e.g., from fleet library sync)
@@ -45,6 +106,8 @@ impl RAMRecall {
             store.truncate(MAX_EPISODES);
         }
         debug!(count = store.len(), "RAMRecall loaded episodes");
+        drop(store);
+        self.rescore_if_due(unix_now());
     }
 
     /// Add a single episode (e.g., from a local advisory)
@@ -63,6 +126,8 @@ impl RAMRecall {
             store.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
             store.truncate(MAX_EPISODES);
         }
+        drop(store);
+        self.rescore_if_due(unix_now());
     }
 
     /// Query precedents by category, campaign, and depth
@@ -152,6 +217,13 @@ impl RAMRecall {
     }
 }
 
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 impl Default for RAMRecall {
     fn default() -> Self {
         Self::new()
@@ -228,6 +300,7 @@ mod tests {
                 ecd_margin: 0.4,
                 rop: 45.0,
             },
+            score: 0.5,
         }
     }
 
@@ -273,6 +346,46 @@ mod tests {
         assert_eq!(recall.episode_count(), 1);
     }
 
+    #[test]
+    fn test_rescore_lowers_average_episode_as_fleet_improves() {
+        let recall = RAMRecall::with_scoring(EpisodeScoringConfig {
+            enabled: true,
+            rescore_interval_secs: 3600,
+        });
+        let with_perf = |id: &str, rop: f64, eff: f64| {
+            let mut ep = make_episode(id, AnomalyCategory::Mechanical, Campaign::Production);
+            ep.key_metrics.rop = rop;
+            ep.key_metrics.mse_efficiency = eff;
+            ep
+        };
+
+        // Older episode at the fleet average of its time
+        recall.load_episodes(vec![
+            with_perf("old-avg", 45.0, 60.0),
+            with_perf("peer-1", 40.0, 55.0),
+            with_perf("peer-2", 50.0, 65.0),
+        ]);
+        let t0 = unix_now() + 3600;
+        assert!(recall.rescore_if_due(t0));
+        let before = recall.episode_score("old-avg").unwrap();
+        assert!((before - 0.5).abs() < 1e-9);
+
+        // Fleet accumulates high-performing runs
+        for i in 0..3 {
+            recall.add_episode(with_perf(&format!("new-{i}"), 90.0, 85.0));
+        }
+
+        // Not due yet — score unchanged
+        assert!(!recall.rescore_if_due(t0 + 1800));
+        assert_eq!(recall.episode_score("old-avg"), Some(before));
+
+        // After the interval the old episode drops below average
+        assert!(recall.rescore_if_due(t0 + 3600));
+        let after = recall.episode_score("old-avg").unwrap();
+        assert!(after < before - 0.05, "score {after} should fall below {before}");
+        assert!(recall.episode_score("new-0").unwrap() > 0.5);
+    }
+
     #[test]
     fn test_knowledge_store_trait() {
         let store: Box<dyn KnowledgeStore> = Box::new(RAMRecall::new());
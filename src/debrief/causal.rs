@@ -0,0 +1,151 @@
+//! Causal-lead aggregation: which parameters most often preceded trouble
+
+use crate::types::{CausalLeadSummary, StrategicAdvisory};
+use std::collections::HashMap;
+
+#[derive(Default)]
+struct LeadTally {
+    occurrences: usize,
+    top_lead_count: usize,
+    lag_sum: f64,
+    abs_r_sum: f64,
+    co_moving: usize,
+    categories: Vec<crate::types::AnomalyCategory>,
+}
+
+/// Aggregate the causal leads recorded on advisories into a ranked list.
+///
+/// Each advisory counts a parameter at most once. Ranked by occurrences,
+/// then by how often the parameter was the strongest lead, then by mean
+/// |r|. Advisories without leads are ignored.
+pub fn summarize_leads(advisories: &[StrategicAdvisory]) -> Vec<CausalLeadSummary> {
+    let mut tallies: HashMap<String, LeadTally> = HashMap::new();
+
+    for adv in advisories {
+        let mut seen: Vec<&str> = Vec::new();
+        for (rank, lead) in adv.causal_leads.iter().enumerate() {
+            if seen.contains(&lead.parameter.as_str()) {
+                continue;
+            }
+            seen.push(&lead.parameter);
+
+            let tally = tallies.entry(lead.parameter.clone()).or_default();
+            tally.occurrences += 1;
+            if rank == 0 {
+                tally.top_lead_count += 1;
+            }
+            tally.lag_sum += f64::from(lead.lag_seconds);
+            tally.abs_r_sum += lead.pearson_r.abs();
+            if lead.correlation_sign > 0 {
+                tally.co_moving += 1;
+            }
+            if !tally.categories.contains(&adv.category) {
+                tally.categories.push(adv.category);
+            }
+        }
+    }
+
+    let mut summary: Vec<CausalLeadSummary> = tallies
+        .into_iter()
+        .map(|(parameter, t)| {
+            let n = t.occurrences as f64;
+            CausalLeadSummary {
+                parameter,
+                occurrences: t.occurrences,
+                top_lead_count: t.top_lead_count,
+                avg_lag_seconds: t.lag_sum / n,
+                avg_abs_r: t.abs_r_sum / n,
+                co_moving: t.co_moving,
+                categories: t.categories,
+            }
+        })
+        .collect();
+
+    summary.sort_by(|a, b| {
+        b.occurrences
+            .cmp(&a.occurrences)
+            .then(b.top_lead_count.cmp(&a.top_lead_count))
+            .then(
+                b.avg_abs_r
+                    .partial_cmp(&a.avg_abs_r)
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+            .then(a.parameter.cmp(&b.parameter))
+    });
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AnomalyCategory, CausalLead};
+
+    fn lead(parameter: &str, lag_seconds: u32, pearson_r: f64) -> CausalLead {
+        CausalLead {
+            parameter: parameter.to_string(),
+            lag_seconds,
+            pearson_r,
+            correlation_sign: if pearson_r >= 0.0 { 1 } else { -1 },
+            partial_r: None,
+        }
+    }
+
+    fn advisory(ts: u64, category: AnomalyCategory, leads: Vec<CausalLead>) -> StrategicAdvisory {
+        StrategicAdvisory {
+            timestamp: ts,
+            category,
+            causal_leads: leads,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_most_frequent_lead_ranks_first() {
+        let advisories = vec![
+            advisory(
+                1,
+                AnomalyCategory::DrillingEfficiency,
+                vec![lead("RPM", 20, 0.9), lead("WOB", 10, 0.6)],
+            ),
+            advisory(
+                2,
+                AnomalyCategory::Mechanical,
+                vec![lead("WOB", 30, 0.7), lead("Torque", 5, 0.8)],
+            ),
+            advisory(
+                3,
+                AnomalyCategory::DrillingEfficiency,
+                vec![lead("WOB", 20, -0.5)],
+            ),
+            advisory(4, AnomalyCategory::Hydraulics, Vec::new()),
+        ];
+
+        let summary = summarize_leads(&advisories);
+        assert_eq!(summary.len(), 3);
+
+        let wob = &summary[0];
+        assert_eq!(wob.parameter, "WOB");
+        assert_eq!(wob.occurrences, 3);
+        assert_eq!(wob.top_lead_count, 2);
+        assert!((wob.avg_lag_seconds - 20.0).abs() < 1e-9);
+        assert!((wob.avg_abs_r - 0.6).abs() < 1e-9);
+        assert_eq!(wob.co_moving, 2);
+        assert_eq!(
+            wob.categories,
+            vec![
+                AnomalyCategory::DrillingEfficiency,
+                AnomalyCategory::Mechanical
+            ]
+        );
+
+        // Single occurrences: RPM was the strongest lead once, Torque never
+        assert_eq!(summary[1].parameter, "RPM");
+        assert_eq!(summary[2].parameter, "Torque");
+    }
+
+    #[test]
+    fn test_no_leads_gives_empty_summary() {
+        let advisories = vec![advisory(1, AnomalyCategory::Mechanical, Vec::new())];
+        assert!(summarize_leads(&advisories).is_empty());
+    }
+}
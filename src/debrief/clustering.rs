@@ -0,0 +1,101 @@
+//! Advisory clustering: group recurring same-category advisories by depth
+
+use crate::config::DebriefConfig;
+use crate::types::{AdvisoryCluster, TimelineEvent};
+
+/// Group timeline events by category and depth proximity.
+///
+/// Events of one category are sorted by depth and chained while the gap to
+/// the previous event is within `cluster_distance_ft`. Clusters are returned
+/// largest first; every event lands in exactly one cluster (possibly alone).
+pub fn cluster_timeline(timeline: &[TimelineEvent], cfg: &DebriefConfig) -> Vec<AdvisoryCluster> {
+    let mut sorted: Vec<&TimelineEvent> = timeline.iter().collect();
+    sorted.sort_by(|a, b| {
+        (a.category as u8).cmp(&(b.category as u8)).then(
+            a.depth_ft
+                .partial_cmp(&b.depth_ft)
+                .unwrap_or(std::cmp::Ordering::Equal),
+        )
+    });
+
+    let mut clusters: Vec<AdvisoryCluster> = Vec::new();
+    for event in sorted {
+        let extends_last = clusters.last().is_some_and(|c| {
+            c.category == event.category
+                && event.depth_ft - c.depth_base_ft <= cfg.cluster_distance_ft
+        });
+        if !extends_last {
+            clusters.push(AdvisoryCluster {
+                category: event.category,
+                depth_top_ft: event.depth_ft,
+                depth_base_ft: event.depth_ft,
+                count: 0,
+                critical_count: 0,
+                first_timestamp: event.timestamp,
+                last_timestamp: event.timestamp,
+                formations: Vec::new(),
+            });
+        }
+        let Some(cluster) = clusters.last_mut() else {
+            continue;
+        };
+        cluster.depth_base_ft = event.depth_ft;
+        cluster.count += 1;
+        if event.severity == "CRITICAL" {
+            cluster.critical_count += 1;
+        }
+        cluster.first_timestamp = cluster.first_timestamp.min(event.timestamp);
+        cluster.last_timestamp = cluster.last_timestamp.max(event.timestamp);
+        if let Some(ref name) = event.formation_name {
+            if !cluster.formations.contains(name) {
+                cluster.formations.push(name.clone());
+            }
+        }
+    }
+
+    clusters.sort_by(|a, b| b.count.cmp(&a.count));
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AnomalyCategory;
+
+    fn event(ts: u64, depth: f64, category: AnomalyCategory, formation: &str) -> TimelineEvent {
+        TimelineEvent {
+            timestamp: ts,
+            depth_ft: depth,
+            formation_name: Some(formation.to_string()),
+            category,
+            severity: "HIGH".to_string(),
+            recommendation: String::new(),
+            feedback: None,
+            top_lead: None,
+        }
+    }
+
+    #[test]
+    fn test_clusters_split_by_category_and_depth_gap() {
+        let cfg = DebriefConfig::default();
+        let timeline = vec![
+            event(100, 8050.0, AnomalyCategory::Mechanical, "Shale"),
+            event(200, 8200.0, AnomalyCategory::Mechanical, "Shale"),
+            event(300, 8400.0, AnomalyCategory::Hydraulics, "Shale"),
+            event(400, 8420.0, AnomalyCategory::Mechanical, "Shale"),
+            // Too far below the previous mechanical event to chain
+            event(500, 9500.0, AnomalyCategory::Mechanical, "Sand"),
+        ];
+
+        let clusters = cluster_timeline(&timeline, &cfg);
+        assert_eq!(clusters.len(), 3);
+        assert_eq!(clusters[0].category, AnomalyCategory::Mechanical);
+        assert_eq!(clusters[0].count, 3);
+        assert_eq!(clusters[0].depth_top_ft, 8050.0);
+        assert_eq!(clusters[0].depth_base_ft, 8420.0);
+        assert_eq!(clusters[0].first_timestamp, 100);
+        assert_eq!(clusters[0].last_timestamp, 400);
+        assert!(clusters.iter().all(|c| c.count >= 1));
+        assert_eq!(clusters.iter().map(|c| c.count).sum::<usize>(), 5);
+    }
+}
@@ -200,6 +200,8 @@ mod tests {
             },
             formations,
             casings: Vec::new(),
+            sidetracks: Vec::new(),
+            mud_program: Vec::new(),
         }
     }
 
@@ -258,6 +260,7 @@ mod tests {
                 severity: "MEDIUM".to_string(),
                 recommendation: "test".to_string(),
                 feedback: None,
+                top_lead: None,
             },
             TimelineEvent {
                 timestamp: 2000,
@@ -267,6 +270,7 @@ mod tests {
                 severity: "CRITICAL".to_string(),
                 recommendation: "test".to_string(),
                 feedback: None,
+                top_lead: None,
             },
             TimelineEvent {
                 timestamp: 3000,
@@ -276,6 +280,7 @@ mod tests {
                 severity: "HIGH".to_string(),
                 recommendation: "test".to_string(),
                 feedback: None,
+                top_lead: None,
             },
         ];
 
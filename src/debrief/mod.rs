@@ -1,25 +1,42 @@
 //! Post-well AI debrief: structured narrative correlating advisory history
 //! with formation transitions and planned vs actual performance.
 
+pub mod causal;
+pub mod clustering;
 pub mod comparison;
 pub mod narrative;
+pub mod npt;
+pub mod recommendations;
 pub mod timeline;
 
+use crate::config::WellConfig;
+use crate::gossip::store::Eviction;
 use crate::storage::feedback::{FeedbackOutcome, FeedbackRecord};
 use crate::storage::suggestions;
 use crate::types::{
-    CategoryFeedbackRate, FeedbackSummary, FormationPrognosis, PostWellSummary, StrategicAdvisory,
-    WellDebrief,
+    CategoryFeedbackRate, FeedbackSummary, FormationPrognosis, PostWellSummary, RigStateLog,
+    StrategicAdvisory, WellDebrief,
 };
 
 /// Generate a complete well debrief from post-well data, advisories, and feedback.
+///
+/// `config` supplies the `[debrief]` options and the current thresholds the
+/// feedback-based recommendations are measured against. `rig_states` is the
+/// well's rig state history, used to attribute non-productive time.
+/// `mesh_evictions` is the mesh store's eviction log; evictions since well
+/// start are reported as fleet data loss.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_debrief(
     post_well: &PostWellSummary,
     advisories: &[StrategicAdvisory],
     feedback_records: &[FeedbackRecord],
     prognosis: Option<&FormationPrognosis>,
+    rig_states: &RigStateLog,
+    mesh_evictions: &[Eviction],
     well_start_ts: u64,
+    config: &WellConfig,
 ) -> WellDebrief {
+    let cfg = &config.debrief;
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
@@ -34,7 +51,39 @@ pub fn generate_debrief(
     // 3. Build feedback summary
     let feedback_summary = build_feedback_summary(advisories.len(), feedback_records);
 
-    // 4. Generate narrative
+    // 4. Group recurring advisories
+    let clusters = if cfg.cluster_advisories {
+        clustering::cluster_timeline(&timeline, cfg)
+            .into_iter()
+            .filter(|c| c.count >= cfg.min_cluster_size)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // 5. Rank the causal leads that preceded advisories
+    let causal_summary = causal::summarize_leads(advisories);
+
+    // 6. Attribute non-productive time to advisory categories
+    let npt_breakdown = npt::compute_npt(advisories, rig_states, now);
+
+    // 7. Recommend threshold changes from feedback
+    let threshold_recommendations = if cfg.threshold_recommendations {
+        recommendations::recommend_thresholds(feedback_records, config)
+    } else {
+        Vec::new()
+    };
+    let config_patch =
+        recommendations::config_patch(&post_well.well_id, &threshold_recommendations);
+
+    // 8. Fleet events dropped by the mesh store while this well was drilled
+    let mesh_data_loss: Vec<Eviction> = mesh_evictions
+        .iter()
+        .filter(|e| e.at >= well_start_ts)
+        .cloned()
+        .collect();
+
+    // 9. Generate narrative
     let narrative = narrative::generate_narrative(
         &post_well.well_id,
         post_well.total_depth_ft,
@@ -42,6 +91,11 @@ pub fn generate_debrief(
         &formation_comparisons,
         &feedback_summary,
         &timeline,
+        &clusters,
+        &causal_summary,
+        &npt_breakdown,
+        &threshold_recommendations,
+        &mesh_data_loss,
     );
 
     WellDebrief {
@@ -55,10 +109,68 @@ pub fn generate_debrief(
         timeline,
         formation_comparisons,
         feedback_summary,
+        clusters,
+        causal_summary,
+        npt_breakdown,
+        threshold_recommendations,
+        config_patch,
+        mesh_data_loss,
         narrative,
     }
 }
 
+/// Mark the well complete in the knowledge base and persist its debrief.
+///
+/// Generates the post-well summary, builds the debrief from the stored
+/// advisory history, operator feedback, and the mesh store's `mesh_evictions`,
+/// and writes `debrief.json` to the well's post-well directory. Fails only if the post-well summary cannot be
+/// generated; a failed debrief write is logged and the debrief still returned.
+pub fn complete_well(
+    kb: &crate::knowledge_base::KnowledgeBase,
+    rig_states: &RigStateLog,
+    mesh_evictions: &[Eviction],
+) -> std::io::Result<WellDebrief> {
+    let post_well = kb.complete_well()?;
+    let advisories = crate::storage::history::get_all_reports();
+    let feedback_records = crate::storage::feedback::load_all();
+    let prognosis = kb.prognosis();
+
+    // Well start is the first advisory on record
+    let well_start_ts = advisories.first().map(|a| a.timestamp).unwrap_or(0);
+
+    let debrief = generate_debrief(
+        &post_well,
+        &advisories,
+        &feedback_records,
+        prognosis.as_ref(),
+        rig_states,
+        mesh_evictions,
+        well_start_ts,
+        &crate::config::get(),
+    );
+
+    let post_well_dir = kb.config().post_well_dir(&kb.config().well);
+    if let Err(e) = std::fs::create_dir_all(&post_well_dir) {
+        tracing::warn!("Failed to create post-well dir: {}", e);
+    }
+
+    let debrief_path = post_well_dir.join("debrief.json");
+    match serde_json::to_string_pretty(&debrief) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(&debrief_path, &json) {
+                tracing::warn!("Failed to write debrief.json: {}", e);
+            } else {
+                tracing::info!(path = ?debrief_path, "Wrote post-well debrief");
+            }
+        }
+        Err(e) => {
+            tracing::warn!("Failed to serialize debrief: {}", e);
+        }
+    }
+
+    Ok(debrief)
+}
+
 /// Build a feedback summary from advisory count and feedback records.
 ///
 /// Reuses `storage::suggestions::compute_stats()` for per-category rates.
@@ -229,6 +341,8 @@ mod tests {
                 },
             }],
             casings: Vec::new(),
+            sidetracks: Vec::new(),
+            mud_program: Vec::new(),
         }
     }
 
@@ -237,47 +351,63 @@ mod tests {
         let records = vec![
             FeedbackRecord {
                 advisory_timestamp: 1000,
+                well_id: String::new(),
                 outcome: FeedbackOutcome::Confirmed,
                 category: AnomalyCategory::DrillingEfficiency,
                 trigger_parameter: "mse".to_string(),
                 trigger_value: 30000.0,
                 threshold_value: 25000.0,
                 submitted_by: "driller".to_string(),
+                source: crate::storage::feedback::FeedbackSource::Operator,
                 submitted_at: 1100,
                 notes: String::new(),
+                actual_cause: None,
+                action_taken: None,
             },
             FeedbackRecord {
                 advisory_timestamp: 2000,
+                well_id: String::new(),
                 outcome: FeedbackOutcome::FalsePositive,
                 category: AnomalyCategory::DrillingEfficiency,
                 trigger_parameter: "mse".to_string(),
                 trigger_value: 26000.0,
                 threshold_value: 25000.0,
                 submitted_by: "driller".to_string(),
+                source: crate::storage::feedback::FeedbackSource::Operator,
                 submitted_at: 2100,
                 notes: String::new(),
+                actual_cause: None,
+                action_taken: None,
             },
             FeedbackRecord {
                 advisory_timestamp: 3000,
+                well_id: String::new(),
                 outcome: FeedbackOutcome::Confirmed,
                 category: AnomalyCategory::WellControl,
                 trigger_parameter: "flow".to_string(),
                 trigger_value: 50.0,
                 threshold_value: 30.0,
                 submitted_by: "driller".to_string(),
+                source: crate::storage::feedback::FeedbackSource::Operator,
                 submitted_at: 3100,
                 notes: String::new(),
+                actual_cause: None,
+                action_taken: None,
             },
             FeedbackRecord {
                 advisory_timestamp: 4000,
+                well_id: String::new(),
                 outcome: FeedbackOutcome::Unclear,
                 category: AnomalyCategory::Mechanical,
                 trigger_parameter: "torque".to_string(),
                 trigger_value: 10.0,
                 threshold_value: 8.0,
                 submitted_by: "driller".to_string(),
+                source: crate::storage::feedback::FeedbackSource::Operator,
                 submitted_at: 4100,
                 notes: String::new(),
+                actual_cause: None,
+                action_taken: None,
             },
         ];
 
@@ -303,7 +433,16 @@ mod tests {
         )];
         let prognosis = make_prognosis();
 
-        let debrief = generate_debrief(&post_well, &advisories, &[], Some(&prognosis), 1000);
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &[],
+            Some(&prognosis),
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &WellConfig::default(),
+        );
 
         let json = serde_json::to_string_pretty(&debrief).unwrap();
         let decoded: WellDebrief = serde_json::from_str(&json).unwrap();
@@ -324,18 +463,31 @@ mod tests {
         ];
         let feedback = vec![FeedbackRecord {
             advisory_timestamp: 1000,
+            well_id: String::new(),
             outcome: FeedbackOutcome::Confirmed,
             category: AnomalyCategory::DrillingEfficiency,
             trigger_parameter: "mse".to_string(),
             trigger_value: 30000.0,
             threshold_value: 25000.0,
             submitted_by: "driller".to_string(),
+            source: crate::storage::feedback::FeedbackSource::Operator,
             submitted_at: 1100,
             notes: String::new(),
+            actual_cause: None,
+            action_taken: None,
         }];
         let prognosis = make_prognosis();
 
-        let debrief = generate_debrief(&post_well, &advisories, &feedback, Some(&prognosis), 1000);
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &feedback,
+            Some(&prognosis),
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &WellConfig::default(),
+        );
 
         // Verify all components assembled
         assert_eq!(debrief.well_id, "Well-A");
@@ -349,4 +501,242 @@ mod tests {
         assert!(debrief.narrative.contains("## Summary"));
         assert!(debrief.narrative.contains("EXCEEDED PLAN"));
     }
+
+    #[test]
+    fn test_recurring_advisories_summarized_as_one_cluster() {
+        let post_well = make_post_well();
+        let mut advisories: Vec<StrategicAdvisory> = [1200.0, 1300.0, 1450.0, 1500.0, 1600.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &depth)| {
+                make_advisory(1000 + i as u64 * 100, depth, AnomalyCategory::Mechanical)
+            })
+            .collect();
+        // Isolated events: same category far below, and another category in the band
+        advisories.push(make_advisory(3000, 2500.0, AnomalyCategory::Mechanical));
+        advisories.push(make_advisory(3100, 1400.0, AnomalyCategory::Hydraulics));
+        let prognosis = make_prognosis();
+
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &[],
+            Some(&prognosis),
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &WellConfig::default(),
+        );
+
+        assert_eq!(debrief.timeline.len(), 7);
+        assert_eq!(debrief.clusters.len(), 1);
+        let cluster = &debrief.clusters[0];
+        assert_eq!(cluster.category, AnomalyCategory::Mechanical);
+        assert_eq!(cluster.count, 5);
+        assert_eq!(cluster.depth_top_ft, 1200.0);
+        assert_eq!(cluster.depth_base_ft, 1600.0);
+        assert_eq!(cluster.formations, vec!["Shallow".to_string()]);
+
+        assert!(debrief.narrative.contains("## Recurring Issues"));
+        assert_eq!(debrief.narrative.matches("- Recurring ").count(), 1);
+        assert!(debrief.narrative.contains(
+            "Recurring mechanical dysfunction (torque / vibration / pack-off) \
+             in the 1200-1600 ft interval (Shallow): 5 occurrences."
+        ));
+
+        // Clustering disabled: no recurring-issue summary
+        let mut cfg = WellConfig::default();
+        cfg.debrief.cluster_advisories = false;
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &[],
+            Some(&prognosis),
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &cfg,
+        );
+        assert!(debrief.clusters.is_empty());
+        assert!(!debrief.narrative.contains("## Recurring Issues"));
+    }
+
+    #[test]
+    fn test_stuck_pipe_npt_in_debrief_narrative() {
+        use crate::types::RigState;
+
+        let post_well = make_post_well();
+        let advisories = vec![make_advisory(10_000, 2000.0, AnomalyCategory::Mechanical)];
+        let mut rig_states = RigStateLog::default();
+        rig_states.record(0, RigState::Drilling);
+        rig_states.record(10_000, RigState::Circulating);
+        rig_states.record(20_800, RigState::Drilling);
+        rig_states.record(30_000, RigState::Drilling);
+
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &[],
+            None,
+            &rig_states,
+            &[],
+            0,
+            &WellConfig::default(),
+        );
+
+        assert_eq!(debrief.npt_breakdown.len(), 1);
+        assert_eq!(
+            debrief.npt_breakdown[0].category,
+            AnomalyCategory::Mechanical
+        );
+        assert!((debrief.npt_breakdown[0].hours - 3.0).abs() < 1e-9);
+        // 3 hr over 6000 ft
+        assert!(debrief
+            .narrative
+            .contains("Total NPT 3.0 hr (0.50 hr per 1000 ft)."));
+        assert!(debrief
+            .narrative
+            .contains("- **Mechanical**: 3.0 hr over 1 event(s)."));
+    }
+
+    #[test]
+    fn test_mesh_evictions_during_well_reported_as_data_loss() {
+        let post_well = make_post_well();
+        let advisories = vec![make_advisory(10_000, 2000.0, AnomalyCategory::Mechanical)];
+        let evictions = vec![
+            // Before the well started: not this well's loss
+            Eviction {
+                at: 500,
+                events: 7,
+                bytes: 7000,
+                oldest_ts: 100,
+                newest_ts: 400,
+            },
+            Eviction {
+                at: 1_700_000_000,
+                events: 12,
+                bytes: 2048,
+                oldest_ts: 1_699_990_000,
+                newest_ts: 1_699_999_000,
+            },
+        ];
+
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &[],
+            None,
+            &RigStateLog::default(),
+            &evictions,
+            1000,
+            &WellConfig::default(),
+        );
+
+        assert_eq!(debrief.mesh_data_loss, evictions[1..]);
+        assert!(debrief.narrative.contains("## Fleet Data Loss"));
+        assert!(debrief
+            .narrative
+            .contains("dropped 12 fleet event(s) (2.0 KB) in 1 eviction(s)"));
+        assert!(debrief
+            .narrative
+            .contains("- 12 event(s) from 2023-11-14 19:26 UTC to 2023-11-14 21:56 UTC."));
+
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &[],
+            None,
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &WellConfig::default(),
+        );
+        assert!(!debrief.narrative.contains("## Fleet Data Loss"));
+    }
+
+    #[test]
+    fn test_high_false_positive_rate_recommends_widening_threshold() {
+        let post_well = make_post_well();
+        let advisories: Vec<StrategicAdvisory> = (0..10)
+            .map(|i| make_advisory(1000 + i * 100, 1500.0, AnomalyCategory::Mechanical))
+            .collect();
+        // 6 of 10 torque advisories rejected at +18%; confirmed ones were at +25%
+        let feedback: Vec<FeedbackRecord> = (0..10)
+            .map(|i| FeedbackRecord {
+                advisory_timestamp: 1000 + i * 100,
+                well_id: String::new(),
+                outcome: if i < 6 {
+                    FeedbackOutcome::FalsePositive
+                } else {
+                    FeedbackOutcome::Confirmed
+                },
+                category: AnomalyCategory::Mechanical,
+                trigger_parameter: "torque_delta_percent".to_string(),
+                trigger_value: if i < 6 { 0.18 } else { 0.25 },
+                threshold_value: 0.15,
+                submitted_by: "driller".to_string(),
+                source: crate::storage::feedback::FeedbackSource::Operator,
+                submitted_at: 1100 + i * 100,
+                notes: String::new(),
+                actual_cause: None,
+                action_taken: None,
+            })
+            .collect();
+
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &feedback,
+            None,
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &WellConfig::default(),
+        );
+
+        // Only the torque threshold — not the category's stick-slip threshold
+        assert_eq!(debrief.threshold_recommendations.len(), 1);
+        let rec = &debrief.threshold_recommendations[0];
+        assert_eq!(
+            rec.threshold_key,
+            "thresholds.mechanical.torque_increase_warning"
+        );
+        assert_eq!(
+            rec.trigger_parameter.as_deref(),
+            Some("torque_delta_percent")
+        );
+        assert_eq!(rec.current_value, 0.15);
+        assert_eq!(rec.recommended_value, 0.18);
+        assert!(rec.widen);
+        assert!((rec.false_positive_rate - 0.6).abs() < 1e-9);
+
+        assert!(debrief.config_patch.contains(
+            "[thresholds.mechanical]\ntorque_increase_warning = 0.18  # was 0.15, 60% false positives"
+        ));
+        let patch: toml::Value = toml::from_str(&debrief.config_patch).expect("valid TOML");
+        assert_eq!(
+            patch["thresholds"]["mechanical"]["torque_increase_warning"].as_float(),
+            Some(0.18)
+        );
+        assert!(debrief.narrative.contains(
+            "- Widen `thresholds.mechanical.torque_increase_warning` from 0.15 to 0.18 \
+             (60% false positives over 10 rated Mechanical advisories)."
+        ));
+
+        // Disabled: no recommendations or patch
+        let mut cfg = WellConfig::default();
+        cfg.debrief.threshold_recommendations = false;
+        let debrief = generate_debrief(
+            &post_well,
+            &advisories,
+            &feedback,
+            None,
+            &RigStateLog::default(),
+            &[],
+            1000,
+            &cfg,
+        );
+        assert!(debrief.threshold_recommendations.is_empty());
+        assert!(debrief.config_patch.is_empty());
+    }
 }
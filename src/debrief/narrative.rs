@@ -1,11 +1,44 @@
 //! Template-based narrative generation for post-well debrief
 
-use crate::types::{AnomalyCategory, FeedbackSummary, FormationComparison, TimelineEvent};
+use crate::gossip::store::Eviction;
+use crate::types::{
+    AdvisoryCluster, AnomalyCategory, CausalLeadSummary, FeedbackSummary, FormationComparison,
+    NptEntry, ThresholdRecommendation, TimelineEvent,
+};
+
+/// Leading indicators listed in the narrative
+const MAX_NARRATIVE_LEADS: usize = 3;
+
+/// Narrative wording for a recurring issue of the given category
+fn recurring_issue_label(category: AnomalyCategory) -> &'static str {
+    match category {
+        AnomalyCategory::DrillingEfficiency => "drilling inefficiency",
+        AnomalyCategory::Hydraulics => "hydraulics anomalies",
+        AnomalyCategory::WellControl => "well-control indications",
+        AnomalyCategory::Mechanical => "mechanical dysfunction (torque / vibration / pack-off)",
+        AnomalyCategory::Formation => "formation-change indications",
+        AnomalyCategory::Equipment => "surface equipment faults",
+        AnomalyCategory::None => "system advisories",
+    }
+}
+
+/// Unix seconds as a UTC date and time for the narrative
+fn format_unix(ts: u64) -> String {
+    chrono::DateTime::from_timestamp(ts as i64, 0).map_or_else(
+        || ts.to_string(),
+        |t| t.format("%Y-%m-%d %H:%M UTC").to_string(),
+    )
+}
 
 /// Generate a human-readable debrief narrative from structured data.
 ///
-/// Produces sections: Summary, Formation Performance, Advisory Timeline
-/// Highlights, Feedback Summary, and Lessons Learned.
+/// Produces sections: Summary, Formation Performance, Non-Productive Time,
+/// Recurring Issues, Leading Indicators, Advisory Timeline Highlights,
+/// Feedback Summary, Recommended Threshold Adjustments, Fleet Data Loss, and
+/// Lessons Learned.
+/// Critical advisories that belong to a recurring cluster are summarized
+/// under Recurring Issues rather than listed individually.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_narrative(
     well_id: &str,
     total_depth_ft: f64,
@@ -13,6 +46,11 @@ pub fn generate_narrative(
     comparisons: &[FormationComparison],
     feedback: &FeedbackSummary,
     timeline: &[TimelineEvent],
+    clusters: &[AdvisoryCluster],
+    causal_summary: &[CausalLeadSummary],
+    npt: &[NptEntry],
+    recommendations: &[ThresholdRecommendation],
+    data_loss: &[Eviction],
 ) -> String {
     let mut sections = Vec::new();
 
@@ -66,7 +104,85 @@ pub fn generate_narrative(
         sections.push(perf_lines.join("\n"));
     }
 
-    // 3. Advisory Timeline Highlights
+    // 2b. Non-Productive Time
+    if !npt.is_empty() {
+        let total_hours: f64 = npt.iter().map(|e| e.hours).sum();
+        let per_kft = if total_depth_ft > 0.0 {
+            total_hours / (total_depth_ft / 1000.0)
+        } else {
+            0.0
+        };
+        let mut npt_lines = vec![format!(
+            "## Non-Productive Time\n\n\
+             Total NPT {:.1} hr ({:.2} hr per 1000 ft).\n",
+            total_hours, per_kft,
+        )];
+        for entry in npt {
+            let open_str = if entry.open_ended > 0 {
+                format!(", {} unresolved at well end", entry.open_ended)
+            } else {
+                String::new()
+            };
+            npt_lines.push(format!(
+                "- **{}**: {:.1} hr over {} event(s){}.",
+                entry.category, entry.hours, entry.events, open_str,
+            ));
+        }
+        sections.push(npt_lines.join("\n"));
+    }
+
+    // 3. Recurring Issues
+    if !clusters.is_empty() {
+        let mut cluster_lines = vec!["## Recurring Issues\n".to_string()];
+        for cluster in clusters {
+            let formations = if cluster.formations.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", cluster.formations.join(", "))
+            };
+            let critical = if cluster.critical_count > 0 {
+                format!(", {} critical", cluster.critical_count)
+            } else {
+                String::new()
+            };
+            cluster_lines.push(format!(
+                "- Recurring {} in the {:.0}-{:.0} ft interval{}: {} occurrences{}.",
+                recurring_issue_label(cluster.category),
+                cluster.depth_top_ft,
+                cluster.depth_base_ft,
+                formations,
+                cluster.count,
+                critical,
+            ));
+        }
+        sections.push(cluster_lines.join("\n"));
+    }
+
+    // 3b. Leading Indicators — what most often preceded trouble
+    if !causal_summary.is_empty() {
+        let mut lead_lines = vec!["## Leading Indicators\n".to_string()];
+        for lead in causal_summary.iter().take(MAX_NARRATIVE_LEADS) {
+            lead_lines.push(format!(
+                "- **{}** preceded {} advisory(ies) ({} as the strongest lead), \
+                 leading by {:.0}s on average (mean |r| {:.2}).",
+                lead.parameter,
+                lead.occurrences,
+                lead.top_lead_count,
+                lead.avg_lag_seconds,
+                lead.avg_abs_r,
+            ));
+        }
+        sections.push(lead_lines.join("\n"));
+    }
+
+    // 4. Advisory Timeline Highlights
+    let in_cluster = |e: &TimelineEvent| {
+        clusters.iter().any(|c| {
+            c.category == e.category
+                && e.depth_ft >= c.depth_top_ft
+                && e.depth_ft <= c.depth_base_ft
+        })
+    };
     let critical_events: Vec<&TimelineEvent> = timeline
         .iter()
         .filter(|e| e.severity == "CRITICAL")
@@ -84,7 +200,7 @@ pub fn generate_narrative(
                 "- **Critical advisories**: {} event(s)",
                 critical_events.len()
             ));
-            for evt in critical_events.iter().take(5) {
+            for evt in critical_events.iter().filter(|e| !in_cluster(e)).take(5) {
                 let fm = evt.formation_name.as_deref().unwrap_or("unknown formation");
                 highlight_lines.push(format!(
                     "  - At {:.0} ft ({}): {}",
@@ -103,7 +219,7 @@ pub fn generate_narrative(
         sections.push(highlight_lines.join("\n"));
     }
 
-    // 4. Feedback Summary
+    // 5. Feedback Summary
     if feedback.total_feedback > 0 {
         let mut fb_lines = vec!["## Feedback Summary\n".to_string()];
         fb_lines.push(format!(
@@ -147,7 +263,52 @@ pub fn generate_narrative(
         sections.push(fb_lines.join("\n"));
     }
 
-    // 5. Lessons Learned
+    // 6. Recommended Threshold Adjustments
+    if !recommendations.is_empty() {
+        let mut rec_lines = vec!["## Recommended Threshold Adjustments\n".to_string()];
+        for rec in recommendations {
+            let verb = if rec.widen { "Widen" } else { "Tighten" };
+            rec_lines.push(format!(
+                "- {} `{}` from {} to {} ({:.0}% false positives over {} rated {:?} advisories).",
+                verb,
+                rec.threshold_key,
+                rec.current_value,
+                rec.recommended_value,
+                rec.false_positive_rate * 100.0,
+                rec.rated,
+                rec.category,
+            ));
+        }
+        rec_lines.push(
+            "\nThe debrief's `config_patch` applies these to the next well's config.".to_string(),
+        );
+        sections.push(rec_lines.join("\n"));
+    }
+
+    // 6b. Fleet Data Loss — offset-well events the mesh store had to drop
+    if !data_loss.is_empty() {
+        let events: u64 = data_loss.iter().map(|e| e.events).sum();
+        let bytes: u64 = data_loss.iter().map(|e| e.bytes).sum();
+        let mut loss_lines = vec![format!(
+            "## Fleet Data Loss\n\n\
+             The mesh event store reached its size cap and dropped {} fleet event(s) \
+             ({:.1} KB) in {} eviction(s). Offset-well history from these windows is missing:\n",
+            events,
+            bytes as f64 / 1024.0,
+            data_loss.len(),
+        )];
+        for eviction in data_loss {
+            loss_lines.push(format!(
+                "- {} event(s) from {} to {}.",
+                eviction.events,
+                format_unix(eviction.oldest_ts),
+                format_unix(eviction.newest_ts),
+            ));
+        }
+        sections.push(loss_lines.join("\n"));
+    }
+
+    // 7. Lessons Learned
     let exceeded: Vec<&FormationComparison> = comparisons
         .iter()
         .filter(|c| c.assessment == "exceeded_plan")
@@ -270,10 +431,22 @@ mod tests {
             severity: "CRITICAL".to_string(),
             recommendation: "Reduce WOB".to_string(),
             feedback: Some("confirmed".to_string()),
+            top_lead: None,
         }];
 
-        let narrative =
-            generate_narrative("Well-A", 6000.0, 100.0, &comparisons, &feedback, &timeline);
+        let narrative = generate_narrative(
+            "Well-A",
+            6000.0,
+            100.0,
+            &comparisons,
+            &feedback,
+            &timeline,
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+        );
 
         assert!(!narrative.is_empty());
         assert!(narrative.contains("## Summary"));
@@ -285,5 +458,6 @@ mod tests {
         assert!(narrative.contains("## Lessons Learned"));
         assert!(narrative.contains("What worked"));
         assert!(narrative.contains("Areas for improvement"));
+        assert!(!narrative.contains("## Recurring Issues"));
     }
 }
@@ -0,0 +1,174 @@
+//! Non-productive time (NPT) accounting from advisories and rig state history
+
+use std::collections::HashMap;
+
+use crate::types::{AnomalyCategory, NptEntry, RigState, RigStateLog, StrategicAdvisory};
+
+/// On-bottom states that count as making hole
+fn is_productive(state: RigState) -> bool {
+    matches!(state, RigState::Drilling | RigState::Sliding)
+}
+
+/// Attribute non-productive time to advisory categories.
+///
+/// An advisory raised while the rig is off bottom opens an NPT window that
+/// closes at the next return to drilling (rotary or sliding). A window that
+/// never closes is capped at well end: the last packet in `rig_states`, or
+/// `well_end_ts` if none was recorded. Advisories raised while drilling, before
+/// the first recorded state, or inside an already open window add nothing, so
+/// overlapping advisories are not double counted. Returns an empty breakdown
+/// when there is no rig state history.
+pub fn compute_npt(
+    advisories: &[StrategicAdvisory],
+    rig_states: &RigStateLog,
+    well_end_ts: u64,
+) -> Vec<NptEntry> {
+    let changes = &rig_states.changes;
+    if changes.is_empty() {
+        return Vec::new();
+    }
+    let well_end = if rig_states.last_timestamp > 0 {
+        rig_states.last_timestamp
+    } else {
+        well_end_ts
+    };
+
+    let mut sorted: Vec<&StrategicAdvisory> = advisories.iter().collect();
+    sorted.sort_by_key(|a| a.timestamp);
+
+    let mut totals: HashMap<AnomalyCategory, NptEntry> = HashMap::new();
+    let mut covered_until = 0u64;
+
+    for adv in sorted {
+        let ts = adv.timestamp;
+        if ts < covered_until || ts >= well_end {
+            continue;
+        }
+        // State in effect at the advisory: last change at or before it
+        let idx = changes.partition_point(|c| c.timestamp <= ts);
+        if idx == 0 || is_productive(changes[idx - 1].state) {
+            continue;
+        }
+
+        let resume = changes[idx..]
+            .iter()
+            .find(|c| is_productive(c.state))
+            .map(|c| c.timestamp.min(well_end));
+        let window_end = resume.unwrap_or(well_end);
+        covered_until = window_end;
+
+        let entry = totals.entry(adv.category).or_insert(NptEntry {
+            category: adv.category,
+            hours: 0.0,
+            events: 0,
+            open_ended: 0,
+        });
+        entry.hours += (window_end - ts) as f64 / 3600.0;
+        entry.events += 1;
+        if resume.is_none() {
+            entry.open_ended += 1;
+        }
+    }
+
+    let mut breakdown: Vec<NptEntry> = totals.into_values().collect();
+    breakdown.sort_by(|a, b| {
+        b.hours
+            .partial_cmp(&a.hours)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.events.cmp(&a.events))
+    });
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advisory(ts: u64, category: AnomalyCategory) -> StrategicAdvisory {
+        StrategicAdvisory {
+            timestamp: ts,
+            category,
+            ..Default::default()
+        }
+    }
+
+    fn log(changes: &[(u64, RigState)], last_timestamp: u64) -> RigStateLog {
+        let mut log = RigStateLog::default();
+        for &(ts, state) in changes {
+            log.record(ts, state);
+        }
+        log.last_timestamp = last_timestamp;
+        log
+    }
+
+    #[test]
+    fn test_stuck_pipe_gap_attributed_to_mechanical() {
+        // Drilling, pack-off at 10_000: pipe worked off bottom for 3 h, then drilling resumes
+        let rig_states = log(
+            &[
+                (0, RigState::Drilling),
+                (10_000, RigState::Circulating),
+                (12_000, RigState::Idle),
+                (20_800, RigState::Drilling),
+            ],
+            30_000,
+        );
+        let advisories = vec![
+            advisory(5_000, AnomalyCategory::DrillingEfficiency), // while drilling
+            advisory(10_000, AnomalyCategory::Mechanical),
+            advisory(11_000, AnomalyCategory::Hydraulics), // inside the open window
+        ];
+
+        let npt = compute_npt(&advisories, &rig_states, 0);
+        assert_eq!(npt.len(), 1);
+        assert_eq!(npt[0].category, AnomalyCategory::Mechanical);
+        assert!((npt[0].hours - 3.0).abs() < 1e-9);
+        assert_eq!(npt[0].events, 1);
+        assert_eq!(npt[0].open_ended, 0);
+    }
+
+    #[test]
+    fn test_open_ended_npt_capped_at_well_end() {
+        let rig_states = log(
+            &[(0, RigState::Drilling), (7_200, RigState::Circulating)],
+            14_400,
+        );
+        let advisories = vec![advisory(7_200, AnomalyCategory::WellControl)];
+
+        let npt = compute_npt(&advisories, &rig_states, u64::MAX);
+        assert_eq!(npt.len(), 1);
+        assert_eq!(npt[0].category, AnomalyCategory::WellControl);
+        assert!((npt[0].hours - 2.0).abs() < 1e-9);
+        assert_eq!(npt[0].open_ended, 1);
+
+        // No rig state history: nothing to measure against
+        assert!(compute_npt(&advisories, &RigStateLog::default(), 14_400).is_empty());
+    }
+
+    #[test]
+    fn test_rig_state_log_drops_oldest_transitions_when_full() {
+        let mut log = RigStateLog::default();
+        assert!(log.record(0, RigState::Drilling).is_some());
+        assert!(log.record(10, RigState::Drilling).is_none());
+        assert_eq!(log.last_timestamp, 10);
+
+        for i in 1..=crate::types::MAX_RIG_STATE_CHANGES as u64 {
+            let state = if i % 2 == 0 {
+                RigState::Drilling
+            } else {
+                RigState::Connection
+            };
+            log.record(i * 100, state);
+        }
+        assert!(log.changes.len() <= crate::types::MAX_RIG_STATE_CHANGES);
+        assert!(log.changes[0].timestamp > 0);
+        assert_eq!(
+            log.changes.last().unwrap().timestamp,
+            crate::types::MAX_RIG_STATE_CHANGES as u64 * 100
+        );
+
+        let restored = RigStateLog::from_changes(log.changes.clone());
+        assert_eq!(restored.changes, log.changes);
+        assert_eq!(restored.last_timestamp, log.last_timestamp);
+    }
+}
@@ -0,0 +1,127 @@
+//! Recommended threshold adjustments from the well's operator feedback
+//!
+//! Consolidates the false-positive analysis in `storage::suggestions` into
+//! one list per threshold key for the debrief, plus a TOML patch that can be
+//! merged into the next well's config.
+
+use std::collections::BTreeMap;
+
+use crate::config::WellConfig;
+use crate::storage::feedback::FeedbackRecord;
+use crate::storage::suggestions::{self, ThresholdSuggestion};
+use crate::types::ThresholdRecommendation;
+
+/// Recommend threshold changes from the well's feedback.
+///
+/// Trigger-level suggestions (a threshold widened toward the readings the
+/// operators rejected) come first. A category's confirmation-rate suggestion
+/// is only used when none of its triggers mapped to a threshold, so a
+/// category is never adjusted twice. At most one recommendation per key.
+pub fn recommend_thresholds(
+    records: &[FeedbackRecord],
+    config: &WellConfig,
+) -> Vec<ThresholdRecommendation> {
+    let sources = suggestions::compute_false_positive_sources(records, config);
+    let stats = suggestions::compute_stats(records);
+
+    let mut recommendations: Vec<ThresholdRecommendation> = Vec::new();
+    for source in &sources {
+        if let Some(s) = &source.suggestion {
+            push_unique(
+                &mut recommendations,
+                s,
+                Some(source.trigger_parameter.clone()),
+                source.confirmed + source.false_positives,
+                source.false_positive_rate,
+                true,
+            );
+        }
+    }
+
+    for s in suggestions::compute_suggestions(records, config) {
+        let trigger_covered = sources
+            .iter()
+            .any(|src| src.category == s.category && src.suggestion.is_some());
+        if trigger_covered {
+            continue;
+        }
+        let Some(stat) = stats.iter().find(|st| st.category == s.category) else {
+            continue;
+        };
+        push_unique(
+            &mut recommendations,
+            &s,
+            None,
+            stat.confirmed + stat.false_positives,
+            1.0 - stat.confirmation_rate,
+            // compute_suggestions only loosens at a >90% confirmation rate
+            stat.confirmation_rate < 0.5,
+        );
+    }
+
+    recommendations
+}
+
+fn push_unique(
+    out: &mut Vec<ThresholdRecommendation>,
+    s: &ThresholdSuggestion,
+    trigger_parameter: Option<String>,
+    rated: usize,
+    false_positive_rate: f64,
+    widen: bool,
+) {
+    if out.iter().any(|r| r.threshold_key == s.threshold_key) {
+        return;
+    }
+    out.push(ThresholdRecommendation {
+        category: s.category,
+        trigger_parameter,
+        threshold_key: s.threshold_key.clone(),
+        current_value: s.current_value,
+        recommended_value: s.suggested_value,
+        widen,
+        rated,
+        false_positive_rate,
+        rationale: s.rationale.clone(),
+    });
+}
+
+/// Render recommendations as a TOML patch, grouped by table.
+///
+/// Empty when there is nothing to change.
+pub fn config_patch(well_id: &str, recommendations: &[ThresholdRecommendation]) -> String {
+    if recommendations.is_empty() {
+        return String::new();
+    }
+
+    let mut tables: BTreeMap<&str, Vec<&ThresholdRecommendation>> = BTreeMap::new();
+    for rec in recommendations {
+        let Some((table, _)) = rec.threshold_key.rsplit_once('.') else {
+            continue;
+        };
+        tables.entry(table).or_default().push(rec);
+    }
+
+    let mut patch = format!(
+        "# Recommended threshold adjustments from {} operator feedback\n",
+        well_id
+    );
+    for (table, recs) in tables {
+        patch.push_str(&format!("\n[{}]\n", table));
+        for rec in recs {
+            let key = rec
+                .threshold_key
+                .rsplit_once('.')
+                .map_or(rec.threshold_key.as_str(), |(_, k)| k);
+            patch.push_str(&format!(
+                "{} = {}  # was {}, {:.0}% false positives over {} rated\n",
+                key,
+                rec.recommended_value,
+                rec.current_value,
+                rec.false_positive_rate * 100.0,
+                rec.rated
+            ));
+        }
+    }
+    patch
+}
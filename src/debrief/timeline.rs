@@ -37,6 +37,7 @@ pub fn build_timeline(
                 severity: format!("{}", adv.severity),
                 recommendation: adv.recommendation.clone(),
                 feedback,
+                top_lead: adv.causal_leads.first().cloned(),
             }
         })
         .collect();
@@ -162,6 +163,8 @@ mod tests {
                 },
             ],
             casings: Vec::new(),
+            sidetracks: Vec::new(),
+            mud_program: Vec::new(),
         }
     }
 
@@ -190,14 +193,18 @@ mod tests {
         ];
         let feedback = vec![FeedbackRecord {
             advisory_timestamp: 1000,
+            well_id: String::new(),
             outcome: FeedbackOutcome::Confirmed,
             category: AnomalyCategory::DrillingEfficiency,
             trigger_parameter: "mse".to_string(),
             trigger_value: 30000.0,
             threshold_value: 25000.0,
             submitted_by: "driller".to_string(),
+            source: crate::storage::feedback::FeedbackSource::Operator,
             submitted_at: 1100,
             notes: String::new(),
+            actual_cause: None,
+            action_taken: None,
         }];
         let prognosis = make_prognosis();
 
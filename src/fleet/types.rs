@@ -1,10 +1,12 @@
 //! Fleet data types for hub-and-spoke multi-rig learning
 
+use crate::strategic::HistorySummary;
 use crate::types::{
     AnomalyCategory, Campaign, DrillingMetrics, FinalSeverity, RiskLevel, StrategicAdvisory,
     WitsPacket,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// A confirmed advisory event to be uploaded to the fleet hub
 ///
@@ -26,6 +28,11 @@ pub struct FleetEvent {
     pub advisory: StrategicAdvisory,
     /// History window: last N packets + metrics around the event
     pub history_window: Vec<HistorySnapshot>,
+    /// Compact statistical summary of the history window (see
+    /// `strategic::history_summary`); readers prefer it over the raw
+    /// snapshots when present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_summary: Option<HistorySummary>,
     /// Event outcome (updated when driller acknowledges)
     pub outcome: EventOutcome,
     /// Free-text notes from driller (optional)
@@ -134,6 +141,14 @@ pub struct FleetEpisode {
     pub timestamp: u64,
     /// Key metrics at time of event (for similarity matching)
     pub key_metrics: EpisodeMetrics,
+    /// Performance relative to fleet benchmarks for the category (0-1,
+    /// 0.5 = fleet average, lower = worse). Refreshed by re-scoring.
+    #[serde(default = "default_episode_score")]
+    pub score: f64,
+}
+
+fn default_episode_score() -> f64 {
+    0.5
 }
 
 /// Key metrics stored with a fleet episode for similarity matching
@@ -175,10 +190,83 @@ impl FleetEpisode {
                 ecd_margin: 0.0,
                 rop: metrics.current_rop,
             },
+            score: default_episode_score(),
         }
     }
 }
 
+/// Fleet-average ROP and MSE efficiency for one anomaly category
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CategoryBenchmark {
+    pub mean_rop: f64,
+    pub mean_mse_efficiency: f64,
+    pub episodes: usize,
+}
+
+/// Per-category fleet benchmarks that episodes are scored against
+#[derive(Debug, Clone, Default)]
+pub struct EpisodeBenchmarks {
+    by_category: HashMap<AnomalyCategory, CategoryBenchmark>,
+}
+
+impl EpisodeBenchmarks {
+    /// Compute benchmarks from the current episode library
+    pub fn from_episodes(episodes: &[FleetEpisode]) -> Self {
+        let mut sums: HashMap<AnomalyCategory, (f64, f64, usize)> = HashMap::new();
+        for ep in episodes {
+            let (rop, eff) = (ep.key_metrics.rop, ep.key_metrics.mse_efficiency);
+            if !rop.is_finite() || !eff.is_finite() {
+                continue;
+            }
+            let entry = sums.entry(ep.category).or_default();
+            entry.0 += rop;
+            entry.1 += eff;
+            entry.2 += 1;
+        }
+        let by_category = sums
+            .into_iter()
+            .map(|(category, (rop, eff, n))| {
+                (
+                    category,
+                    CategoryBenchmark {
+                        mean_rop: rop / n as f64,
+                        mean_mse_efficiency: eff / n as f64,
+                        episodes: n,
+                    },
+                )
+            })
+            .collect();
+        Self { by_category }
+    }
+
+    /// Benchmark for a category, if any episodes exist for it
+    pub fn get(&self, category: AnomalyCategory) -> Option<&CategoryBenchmark> {
+        self.by_category.get(&category)
+    }
+}
+
+/// Score an episode against the current fleet benchmarks for its category
+///
+/// ROP and MSE efficiency are each taken relative to the fleet mean and
+/// averaged, so 0.5 is a fleet-average episode and a run at half the fleet
+/// ROP and efficiency scores 0.25. Clamped to 0-1; without a benchmark the
+/// episode is treated as average.
+pub fn score_episode(episode: &FleetEpisode, benchmarks: &EpisodeBenchmarks) -> f64 {
+    let Some(bench) = benchmarks.get(episode.category) else {
+        return default_episode_score();
+    };
+    let relative = |value: f64, mean: f64| {
+        if mean > 0.0 && value.is_finite() {
+            value / mean - 1.0
+        } else {
+            0.0
+        }
+    };
+    let rop = relative(episode.key_metrics.rop, bench.mean_rop);
+    let eff = relative(episode.key_metrics.mse_efficiency, bench.mean_mse_efficiency);
+    (0.5 + 0.25 * (rop + eff)).clamp(0.0, 1.0)
+}
+
 // ─── Intelligence distribution types ─────────────────────────────────────────
 
 /// A hub intelligence output received by the rig during an intelligence sync.
@@ -253,6 +341,10 @@ mod tests {
             trigger_parameter: String::new(),
             trigger_value: 0.0,
             threshold_value: 0.0,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
         }
     }
 
@@ -288,6 +380,7 @@ mod tests {
             campaign: Campaign::Production,
             advisory: make_advisory(RiskLevel::High),
             history_window: Vec::new(),
+            history_summary: None,
             outcome: EventOutcome::Resolved {
                 action_taken: "reduced WOB".to_string(),
             },
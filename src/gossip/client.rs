@@ -4,10 +4,11 @@
 //! events, and receives theirs. Reuses the reqwest client pattern from
 //! `src/fleet/client.rs`.
 
-use super::protocol::{self, GossipEnvelope, PROTOCOL_VERSION};
+use super::protocol::{self, GossipEnvelope, PayloadEncoding, PROTOCOL_VERSION, ZSTD_ENCODING};
 use super::state::MeshState;
 use super::store::EventStore;
 use crate::config::{GossipConfig, PeerInfo};
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing::{debug, info, warn};
@@ -15,7 +16,9 @@ use tracing::{debug, info, warn};
 /// Run the gossip broadcast loop.
 ///
 /// Contacts all peers every `config.interval_secs`, sending recent events
-/// and receiving theirs. Peers are contacted concurrently.
+/// and receiving theirs. Peers are contacted concurrently. Envelopes are
+/// zstd-compressed when `config.compress_uploads` is set; a peer answering
+/// 415 is sent plain JSON from then on.
 #[allow(clippy::too_many_lines)]
 pub async fn run_gossip_loop(
     node_id: String,
@@ -32,6 +35,9 @@ pub async fn run_gossip_loop(
             reqwest::Client::new()
         });
 
+    // Peers that rejected zstd bodies (415) — sent plain JSON instead
+    let plain_peers: Arc<std::sync::Mutex<HashSet<String>>> = Arc::default();
+
     let mut round = 0u64;
 
     loop {
@@ -53,6 +59,8 @@ pub async fn run_gossip_loop(
             let mesh_state = Arc::clone(&mesh_state);
             let max_events = config.max_events_per_exchange;
             let base_interval = config.interval_secs;
+            let plain_peers = Arc::clone(&plain_peers);
+            let compress_uploads = config.compress_uploads;
 
             handles.push(tokio::spawn(async move {
                 // Check backoff
@@ -97,29 +105,49 @@ pub async fn run_gossip_loop(
                         return;
                     }
                 };
-                let compressed = match protocol::compress(&json) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        warn!(peer = %peer_id, error = %e, "[Gossip] Failed to compress envelope");
-                        return;
-                    }
+                let peer_takes_zstd = plain_peers
+                    .lock()
+                    .map(|p| !p.contains(&peer_id))
+                    .unwrap_or(true);
+                let mut encoding = if compress_uploads && peer_takes_zstd {
+                    PayloadEncoding::Zstd
+                } else {
+                    PayloadEncoding::Identity
                 };
 
                 let url = format!("http://{peer_addr}/api/mesh/gossip");
-                let result = http
-                    .post(&url)
-                    .header("Content-Type", "application/octet-stream")
-                    .header("X-Node-ID", &envelope.sender_id)
-                    .body(compressed)
-                    .send()
-                    .await;
+                let mut result =
+                    post_envelope(&http, &url, &envelope.sender_id, &json, encoding).await;
+
+                // Peer doesn't accept zstd: resend uncompressed and remember
+                if encoding == PayloadEncoding::Zstd
+                    && matches!(&result, Ok(resp) if resp.status() == reqwest::StatusCode::UNSUPPORTED_MEDIA_TYPE)
+                {
+                    warn!(peer = %peer_id, "[Gossip] Peer rejected zstd envelope — sending uncompressed");
+                    if let Ok(mut p) = plain_peers.lock() {
+                        p.insert(peer_id.clone());
+                    }
+                    encoding = PayloadEncoding::Identity;
+                    result = post_envelope(&http, &url, &envelope.sender_id, &json, encoding).await;
+                }
 
                 match result {
                     Ok(resp) if resp.status().is_success() => {
+                        let header = |name: &str| {
+                            resp.headers()
+                                .get(name)
+                                .and_then(|v| v.to_str().ok())
+                                .map(str::to_string)
+                        };
+                        let response_encoding = PayloadEncoding::from_headers(
+                            header("content-encoding").as_deref(),
+                            header("content-type").as_deref(),
+                        )
+                        .unwrap_or(encoding);
                         // Parse response envelope
                         match resp.bytes().await {
                             Ok(body) => {
-                                let decompressed = match protocol::decompress(&body) {
+                                let decompressed = match response_encoding.decode(&body) {
                                     Ok(d) => d,
                                     Err(e) => {
                                         warn!(peer = %peer_id, error = %e, "[Gossip] Failed to decompress response");
@@ -195,3 +223,38 @@ pub async fn run_gossip_loop(
         tokio::time::sleep(std::time::Duration::from_secs(config.interval_secs)).await;
     }
 }
+
+/// POST a serialized envelope to a peer in the given encoding.
+///
+/// Logs the compression ratio for zstd bodies. A compression failure falls
+/// back to sending plain JSON.
+async fn post_envelope(
+    http: &reqwest::Client,
+    url: &str,
+    sender_id: &str,
+    json: &[u8],
+    encoding: PayloadEncoding,
+) -> reqwest::Result<reqwest::Response> {
+    let (encoding, body) = match encoding.encode(json) {
+        Ok(body) => (encoding, body),
+        Err(e) => {
+            warn!(error = %e, "[Gossip] Failed to compress envelope — sending uncompressed");
+            (PayloadEncoding::Identity, json.to_vec())
+        }
+    };
+
+    let mut request = http
+        .post(url)
+        .header("Content-Type", encoding.content_type())
+        .header("X-Node-ID", sender_id);
+    if encoding == PayloadEncoding::Zstd {
+        debug!(
+            raw_bytes = json.len(),
+            sent_bytes = body.len(),
+            ratio = format!("{:.1}", protocol::compression_ratio(json.len(), body.len())),
+            "[Gossip] Compressed envelope"
+        );
+        request = request.header("Content-Encoding", ZSTD_ENCODING);
+    }
+    request.body(body).send().await
+}
@@ -40,9 +40,125 @@ pub fn decompress(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
     zstd::decode_all(data)
 }
 
+/// `Content-Encoding` value for zstd-compressed gossip bodies.
+pub const ZSTD_ENCODING: &str = "zstd";
+
+/// How a gossip request or response body is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadEncoding {
+    /// zstd-compressed JSON (`gossip.compress_uploads`).
+    Zstd,
+    /// Plain JSON.
+    Identity,
+}
+
+impl PayloadEncoding {
+    /// Read the encoding from a body's `Content-Encoding` / `Content-Type`.
+    ///
+    /// Peers predating the header send zstd as `application/octet-stream`
+    /// with no `Content-Encoding`, so only an explicit JSON content type
+    /// means plain JSON. Returns `None` for an unsupported encoding.
+    pub fn from_headers(
+        content_encoding: Option<&str>,
+        content_type: Option<&str>,
+    ) -> Option<Self> {
+        match content_encoding.map(str::trim) {
+            Some(enc) if enc.eq_ignore_ascii_case(ZSTD_ENCODING) => Some(Self::Zstd),
+            None | Some("") | Some("identity") => {
+                let is_json =
+                    content_type.is_some_and(|ct| ct.trim().starts_with("application/json"));
+                Some(if is_json { Self::Identity } else { Self::Zstd })
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// `Content-Type` header for a body in this encoding.
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Zstd => "application/octet-stream",
+            Self::Identity => "application/json",
+        }
+    }
+
+    /// Encode a JSON body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if zstd compression fails.
+    pub fn encode(self, json: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+        match self {
+            Self::Zstd => compress(json),
+            Self::Identity => Ok(json.to_vec()),
+        }
+    }
+
+    /// Decode a body back to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if zstd decompression fails.
+    pub fn decode(self, body: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+        match self {
+            Self::Zstd => decompress(body),
+            Self::Identity => Ok(body.to_vec()),
+        }
+    }
+}
+
+/// Compression ratio (raw / encoded bytes) for logging.
+pub fn compression_ratio(raw_len: usize, encoded_len: usize) -> f64 {
+    if encoded_len == 0 {
+        return 1.0;
+    }
+    raw_len as f64 / encoded_len as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fleet::types::EventOutcome;
+    use crate::types::{
+        AnomalyCategory, Campaign, DrillingPhysicsReport, FinalSeverity, RiskLevel,
+        StrategicAdvisory,
+    };
+
+    fn event(id: &str, depth: f64, timestamp: u64) -> FleetEvent {
+        FleetEvent {
+            id: id.to_string(),
+            rig_id: "rig-001".to_string(),
+            well_id: "well-alpha".to_string(),
+            field: "test-field".to_string(),
+            campaign: Campaign::Production,
+            advisory: StrategicAdvisory {
+                timestamp,
+                efficiency_score: 70,
+                risk_level: RiskLevel::Elevated,
+                severity: FinalSeverity::Medium,
+                recommendation: "Reduce WOB to 20 klbs".to_string(),
+                expected_benefit: "Lower torque oscillation".to_string(),
+                reasoning: "Torque CV above threshold".to_string(),
+                votes: Vec::new(),
+                physics_report: DrillingPhysicsReport::default(),
+                context_used: Vec::new(),
+                trace_log: Vec::new(),
+                category: AnomalyCategory::Mechanical,
+                trigger_parameter: "torque_cv".to_string(),
+                trigger_value: 0.25,
+                threshold_value: 0.15,
+                provisional_during_learning: false,
+                sparkline: None,
+                gas_lag: None,
+                causal_leads: Vec::new(),
+            },
+            history_window: Vec::new(),
+            history_summary: None,
+            outcome: EventOutcome::Pending,
+            notes: Some("driller acknowledged".to_string()),
+            depth,
+            timestamp,
+        }
+    }
 
     #[test]
     fn test_gossip_envelope_serde_round_trip() {
@@ -85,4 +201,50 @@ mod tests {
         assert_eq!(roundtripped.sender_id, "rig-001");
         assert!(compressed.len() < json.len(), "zstd should reduce size");
     }
+
+    #[test]
+    fn test_fleet_event_batch_zstd_round_trip() {
+        let events: Vec<FleetEvent> = (0..20u32)
+            .map(|i| {
+                event(
+                    &format!("evt-{i}"),
+                    8000.0 + f64::from(i) * 10.0,
+                    1_700_000_000 + u64::from(i),
+                )
+            })
+            .collect();
+
+        let json = serde_json::to_vec(&events).expect("serialize");
+        let encoded = PayloadEncoding::Zstd.encode(&json).expect("compress");
+        let decoded = PayloadEncoding::Zstd.decode(&encoded).expect("decompress");
+        let roundtripped: Vec<FleetEvent> = serde_json::from_slice(&decoded).expect("deserialize");
+
+        assert_eq!(roundtripped.len(), events.len());
+        for (original, back) in events.iter().zip(&roundtripped) {
+            assert_eq!(
+                serde_json::to_value(original).unwrap(),
+                serde_json::to_value(back).unwrap()
+            );
+        }
+        // Repetitive advisory JSON compresses well
+        assert!(compression_ratio(json.len(), encoded.len()) > 3.0);
+    }
+
+    #[test]
+    fn test_payload_encoding_from_headers() {
+        assert_eq!(
+            PayloadEncoding::from_headers(Some("zstd"), Some("application/octet-stream")),
+            Some(PayloadEncoding::Zstd)
+        );
+        assert_eq!(
+            PayloadEncoding::from_headers(None, Some("application/json")),
+            Some(PayloadEncoding::Identity)
+        );
+        // Legacy peers: zstd body without Content-Encoding
+        assert_eq!(
+            PayloadEncoding::from_headers(None, Some("application/octet-stream")),
+            Some(PayloadEncoding::Zstd)
+        );
+        assert_eq!(PayloadEncoding::from_headers(Some("gzip"), None), None);
+    }
 }
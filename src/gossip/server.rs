@@ -1,6 +1,6 @@
 //! Axum handlers for incoming gossip exchanges and mesh status.
 
-use super::protocol::{self, GossipEnvelope, PROTOCOL_VERSION};
+use super::protocol::{GossipEnvelope, PayloadEncoding, PROTOCOL_VERSION, ZSTD_ENCODING};
 use super::state::MeshState;
 use super::store::EventStore;
 use crate::config;
@@ -24,8 +24,10 @@ pub struct MeshHandlerState {
 
 /// POST /api/mesh/gossip — handle an incoming gossip exchange.
 ///
-/// Accepts a zstd-compressed `GossipEnvelope`, upserts received events,
-/// and returns a response envelope with events the sender hasn't seen.
+/// Accepts a `GossipEnvelope` (zstd-compressed or plain JSON, per the
+/// `Content-Encoding` header), upserts received events, and returns a
+/// response envelope with events the sender hasn't seen, encoded the same
+/// way as the request. Unsupported encodings get 415.
 pub async fn handle_gossip(
     State(state): State<MeshHandlerState>,
     headers: HeaderMap,
@@ -44,8 +46,23 @@ pub async fn handle_gossip(
         return (StatusCode::FORBIDDEN, "unknown peer").into_response();
     }
 
-    // Decompress and deserialize
-    let decompressed = match protocol::decompress(&body) {
+    // Decompress (if needed) and deserialize
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let Some(encoding) =
+        PayloadEncoding::from_headers(header("content-encoding"), header("content-type"))
+    else {
+        warn!(
+            sender = sender_id,
+            encoding = header("content-encoding").unwrap_or(""),
+            "[Gossip] Unsupported content encoding"
+        );
+        return (
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "unsupported content encoding",
+        )
+            .into_response();
+    };
+    let decompressed = match encoding.decode(&body) {
         Ok(d) => d,
         Err(e) => {
             warn!(sender = sender_id, error = %e, "[Gossip] Decompression failed");
@@ -115,7 +132,7 @@ pub async fn handle_gossip(
     };
     state.mesh_state.record_success(sender_id, new_cursor);
 
-    // Serialize and compress response
+    // Serialize and encode response
     let json = match serde_json::to_vec(&response_envelope) {
         Ok(j) => j,
         Err(e) => {
@@ -123,7 +140,7 @@ pub async fn handle_gossip(
             return (StatusCode::INTERNAL_SERVER_ERROR, "serialization error").into_response();
         }
     };
-    let compressed = match protocol::compress(&json) {
+    let encoded = match encoding.encode(&json) {
         Ok(c) => c,
         Err(e) => {
             warn!(error = %e, "[Gossip] Failed to compress response");
@@ -131,12 +148,23 @@ pub async fn handle_gossip(
         }
     };
 
-    (
-        StatusCode::OK,
-        [("content-type", "application/octet-stream")],
-        compressed,
-    )
-        .into_response()
+    match encoding {
+        PayloadEncoding::Zstd => (
+            StatusCode::OK,
+            [
+                ("content-type", encoding.content_type()),
+                ("content-encoding", ZSTD_ENCODING),
+            ],
+            encoded,
+        )
+            .into_response(),
+        PayloadEncoding::Identity => (
+            StatusCode::OK,
+            [("content-type", encoding.content_type())],
+            encoded,
+        )
+            .into_response(),
+    }
 }
 
 // ─── Outcome feedback endpoint ───────────────────────────────────────────────
@@ -244,14 +272,17 @@ pub struct NodeStatus {
 pub struct MeshStatus {
     pub peers_total: usize,
     pub events_stored: usize,
+    /// Events evicted by the store size cap (absent from older nodes)
+    #[serde(default)]
+    pub events_dropped: u64,
 }
 
 /// GET /api/mesh/status — return this node's status summary.
 pub async fn handle_status(State(state): State<MeshHandlerState>) -> Json<NodeStatus> {
     let cfg = config::get();
-    let events_stored = {
+    let (events_stored, events_dropped) = {
         let store = state.store.lock().await;
-        store.count().unwrap_or(0)
+        (store.count().unwrap_or(0), store.dropped_events())
     };
 
     Json(NodeStatus {
@@ -261,6 +292,7 @@ pub async fn handle_status(State(state): State<MeshHandlerState>) -> Json<NodeSt
         mesh: MeshStatus {
             peers_total: cfg.mesh.peers.len(),
             events_stored,
+            events_dropped,
         },
     })
 }
@@ -8,7 +8,9 @@
 use crate::config::FormationTop;
 use crate::fleet::types::FleetEvent;
 use rusqlite::{params, Connection, OptionalExtension};
+use std::cell::Cell;
 use std::path::Path;
+use std::sync::{Arc, RwLock};
 use tracing::warn;
 
 /// Maximum events to retain.
@@ -18,9 +20,91 @@ const RETENTION_AGE_SECS: u64 = 365 * 86400;
 /// False positive cleanup age in seconds (3 months).
 const FALSE_POSITIVE_AGE_SECS: u64 = 90 * 86400;
 
+/// Schema for the event table, its indexes, and the eviction log.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS events (
+    id            TEXT PRIMARY KEY,
+    rig_id        TEXT NOT NULL,
+    well_id       TEXT NOT NULL,
+    timestamp     INTEGER NOT NULL,
+    last_modified INTEGER NOT NULL,
+    formation     TEXT,
+    depth_ft      REAL,
+    category      TEXT NOT NULL,
+    severity      TEXT NOT NULL,
+    risk_level    TEXT,
+    outcome       TEXT DEFAULT 'Pending',
+    action_taken  TEXT,
+    data          BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_events_formation     ON events(formation, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_events_category      ON events(category, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_events_timestamp     ON events(timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_events_depth         ON events(depth_ft);
+CREATE INDEX IF NOT EXISTS idx_events_last_modified ON events(last_modified);
+CREATE TABLE IF NOT EXISTS evictions (
+    at        INTEGER NOT NULL,
+    events    INTEGER NOT NULL,
+    bytes     INTEGER NOT NULL,
+    oldest_ts INTEGER NOT NULL,
+    newest_ts INTEGER NOT NULL
+);";
+
+/// One size-cap eviction: a window of events dropped to reclaim disk.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Eviction {
+    /// Unix seconds when the eviction ran.
+    pub at: u64,
+    /// Events dropped.
+    pub events: u64,
+    /// Compressed bytes reclaimed.
+    pub bytes: u64,
+    /// Timestamp of the oldest dropped event.
+    pub oldest_ts: u64,
+    /// Timestamp of the newest dropped event.
+    pub newest_ts: u64,
+}
+
+/// The store's size-cap evictions, shared so the live API and the debrief
+/// can report data loss without taking the store lock.
+#[derive(Debug, Clone, Default)]
+pub struct EvictionLog(Arc<RwLock<Vec<Eviction>>>);
+
+impl EvictionLog {
+    /// Total events dropped by the size cap over the store's lifetime.
+    #[must_use]
+    pub fn dropped_events(&self) -> u64 {
+        self.0
+            .read()
+            .map(|log| log.iter().map(|e| e.events).sum())
+            .unwrap_or(0)
+    }
+
+    /// Recorded evictions, oldest first — the data-loss windows for a debrief.
+    #[must_use]
+    pub fn windows(&self) -> Vec<Eviction> {
+        self.0.read().map(|log| log.clone()).unwrap_or_default()
+    }
+
+    fn push(&self, eviction: Eviction) {
+        if let Ok(mut log) = self.0.write() {
+            log.push(eviction);
+        }
+    }
+}
+
 /// Embedded `SQLite` event store.
 pub struct EventStore {
     conn: Connection,
+    /// Cap on stored event bytes (0 = unbounded).
+    max_bytes: u64,
+    /// Compressed bytes of stored events, seeded on open and kept current
+    /// on insert and eviction so the size cap never rescans the table.
+    stored_bytes: Cell<u64>,
+    /// Set once the over-cap warning for "only criticals left" has been
+    /// logged; cleared when the store drops back under the cap.
+    critical_only_warned: Cell<bool>,
+    evictions: EvictionLog,
 }
 
 #[allow(clippy::missing_errors_doc)]
@@ -32,61 +116,40 @@ impl EventStore {
         let conn = Connection::open(path)?;
         conn.pragma_update(None, "journal_mode", "WAL")?;
         conn.pragma_update(None, "synchronous", "NORMAL")?;
-        conn.execute_batch(
-            "CREATE TABLE IF NOT EXISTS events (
-                id            TEXT PRIMARY KEY,
-                rig_id        TEXT NOT NULL,
-                well_id       TEXT NOT NULL,
-                timestamp     INTEGER NOT NULL,
-                last_modified INTEGER NOT NULL,
-                formation     TEXT,
-                depth_ft      REAL,
-                category      TEXT NOT NULL,
-                severity      TEXT NOT NULL,
-                risk_level    TEXT,
-                outcome       TEXT DEFAULT 'Pending',
-                action_taken  TEXT,
-                data          BLOB NOT NULL
-            );
-            CREATE INDEX IF NOT EXISTS idx_events_formation     ON events(formation, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_events_category      ON events(category, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_events_timestamp     ON events(timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_events_depth         ON events(depth_ft);
-            CREATE INDEX IF NOT EXISTS idx_events_last_modified ON events(last_modified);",
-        )?;
-        Ok(Self { conn })
+        Self::init(conn)
     }
 
     /// Open an in-memory store (for testing).
     #[allow(dead_code)]
     pub fn open_in_memory() -> Result<Self, rusqlite::Error> {
-        let conn = Connection::open_in_memory()?;
-        let store = Self { conn };
-        store.conn.execute_batch(
-            "CREATE TABLE IF NOT EXISTS events (
-                id            TEXT PRIMARY KEY,
-                rig_id        TEXT NOT NULL,
-                well_id       TEXT NOT NULL,
-                timestamp     INTEGER NOT NULL,
-                last_modified INTEGER NOT NULL,
-                formation     TEXT,
-                depth_ft      REAL,
-                category      TEXT NOT NULL,
-                severity      TEXT NOT NULL,
-                risk_level    TEXT,
-                outcome       TEXT DEFAULT 'Pending',
-                action_taken  TEXT,
-                data          BLOB NOT NULL
-            );
-            CREATE INDEX IF NOT EXISTS idx_events_formation     ON events(formation, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_events_category      ON events(category, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_events_timestamp     ON events(timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_events_depth         ON events(depth_ft);
-            CREATE INDEX IF NOT EXISTS idx_events_last_modified ON events(last_modified);",
-        )?;
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    /// Apply the schema and load the byte total and eviction log.
+    fn init(conn: Connection) -> Result<Self, rusqlite::Error> {
+        conn.execute_batch(SCHEMA)?;
+        let store = Self {
+            conn,
+            max_bytes: 0,
+            stored_bytes: Cell::new(0),
+            critical_only_warned: Cell::new(false),
+            evictions: EvictionLog::default(),
+        };
+        store.recount_bytes()?;
+        for eviction in store.load_evictions()? {
+            store.evictions.push(eviction);
+        }
         Ok(store)
     }
 
+    /// Cap stored event bytes; past it the oldest non-critical events are
+    /// evicted on insert (0 = unbounded).
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
     /// Insert or update an event. On conflict (same UUID), replaces the row
     /// only if the incoming `last_modified` is newer.
     #[allow(clippy::too_many_lines)]
@@ -116,21 +179,23 @@ impl EventStore {
         };
 
         // Check if existing row has newer last_modified
-        let existing_lm: Option<u64> = self
+        let existing: Option<(u64, u64)> = self
             .conn
             .query_row(
-                "SELECT last_modified FROM events WHERE id = ?1",
+                "SELECT last_modified, length(data) FROM events WHERE id = ?1",
                 params![event.id],
-                |row| row.get(0),
+                |row| Ok((row.get(0)?, row.get(1)?)),
             )
             .optional()?;
 
-        if let Some(existing) = existing_lm {
-            if existing >= last_modified {
+        if let Some((existing_lm, _)) = existing {
+            if existing_lm >= last_modified {
                 // Existing row is newer or same — skip
                 return Ok(());
             }
         }
+        let replaced_len = existing.map_or(0, |(_, len)| len);
+        let inserted_len = compressed.len() as u64;
 
         self.conn.execute(
             "INSERT OR REPLACE INTO events
@@ -153,9 +218,153 @@ impl EventStore {
                 compressed,
             ],
         )?;
+        self.stored_bytes
+            .set((self.stored_bytes.get() + inserted_len).saturating_sub(replaced_len));
+
+        // Once only criticals are left over the cap, another critical gives
+        // the cap nothing new to evict
+        let critical = event.advisory.severity == crate::types::FinalSeverity::Critical;
+        if self.max_bytes > 0 && !(critical && self.critical_only_warned.get()) {
+            self.enforce_size_cap(self.max_bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Total compressed bytes of stored events.
+    #[cfg(test)]
+    #[must_use]
+    pub fn stored_bytes(&self) -> u64 {
+        self.stored_bytes.get()
+    }
+
+    /// Re-sum stored bytes from the table, after bulk deletes.
+    fn recount_bytes(&self) -> Result<(), rusqlite::Error> {
+        let total = self.conn.query_row(
+            "SELECT COALESCE(SUM(length(data)), 0) FROM events",
+            [],
+            |row| row.get(0),
+        )?;
+        self.stored_bytes.set(total);
         Ok(())
     }
 
+    /// Evict the oldest non-critical events until stored bytes fit `max_bytes`.
+    ///
+    /// Critical events are never evicted, so the store can stay over the cap
+    /// if criticals alone exceed it; that is warned about once until the
+    /// store drops back under the cap. Each eviction is recorded in the
+    /// `evictions` table. Returns the number of events dropped.
+    pub fn enforce_size_cap(&self, max_bytes: u64) -> Result<usize, rusqlite::Error> {
+        let stored = self.stored_bytes.get();
+        if stored <= max_bytes {
+            self.critical_only_warned.set(false);
+            return Ok(0);
+        }
+        let excess = stored - max_bytes;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, timestamp, length(data) FROM events
+             WHERE severity != 'Critical'
+             ORDER BY timestamp ASC, last_modified ASC",
+        )?;
+        let mut rows = stmt.query([])?;
+        let mut victims: Vec<String> = Vec::new();
+        let mut reclaimed = 0u64;
+        let mut oldest_ts = u64::MAX;
+        let mut newest_ts = 0u64;
+        while reclaimed < excess {
+            let Some(row) = rows.next()? else { break };
+            let ts: u64 = row.get(1)?;
+            let len: u64 = row.get(2)?;
+            victims.push(row.get(0)?);
+            reclaimed += len;
+            oldest_ts = oldest_ts.min(ts);
+            newest_ts = newest_ts.max(ts);
+        }
+        drop(rows);
+        drop(stmt);
+
+        if victims.is_empty() {
+            if !self.critical_only_warned.replace(true) {
+                warn!(
+                    stored_bytes = stored,
+                    max_bytes, "EventStore: over size cap with only critical events left"
+                );
+            }
+            return Ok(0);
+        }
+        self.critical_only_warned.set(false);
+
+        let tx = self.conn.unchecked_transaction()?;
+        for id in &victims {
+            tx.execute("DELETE FROM events WHERE id = ?1", params![id])?;
+        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let eviction = Eviction {
+            at: now,
+            events: victims.len() as u64,
+            bytes: reclaimed,
+            oldest_ts,
+            newest_ts,
+        };
+        tx.execute(
+            "INSERT INTO evictions (at, events, bytes, oldest_ts, newest_ts)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                eviction.at,
+                eviction.events,
+                eviction.bytes,
+                eviction.oldest_ts,
+                eviction.newest_ts
+            ],
+        )?;
+        tx.commit()?;
+        self.stored_bytes.set(stored.saturating_sub(reclaimed));
+        self.evictions.push(eviction);
+
+        warn!(
+            dropped = victims.len(),
+            reclaimed_bytes = reclaimed,
+            max_bytes,
+            oldest_ts,
+            newest_ts,
+            "EventStore: size cap exceeded, evicted oldest non-critical events"
+        );
+        Ok(victims.len())
+    }
+
+    /// Total events dropped by the size cap over the store's lifetime.
+    #[must_use]
+    pub fn dropped_events(&self) -> u64 {
+        self.evictions.dropped_events()
+    }
+
+    /// Shared handle on the eviction log, kept current as the cap evicts.
+    #[must_use]
+    pub fn eviction_log(&self) -> EvictionLog {
+        self.evictions.clone()
+    }
+
+    /// Read the persisted eviction log, oldest first.
+    fn load_evictions(&self) -> Result<Vec<Eviction>, rusqlite::Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT at, events, bytes, oldest_ts, newest_ts FROM evictions ORDER BY at ASC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(Eviction {
+                at: row.get(0)?,
+                events: row.get(1)?,
+                bytes: row.get(2)?,
+                oldest_ts: row.get(3)?,
+                newest_ts: row.get(4)?,
+            })
+        })?;
+        rows.collect()
+    }
+
     /// Get events modified since a given cursor (unix seconds).
     ///
     /// Returns events ordered by `last_modified` ascending, limited to `limit`.
@@ -253,6 +462,7 @@ impl EventStore {
         }
 
         if total_pruned > 0 {
+            self.recount_bytes()?;
             warn!(pruned = total_pruned, "EventStore: pruned stale events");
         }
         Ok(total_pruned)
@@ -367,8 +577,13 @@ mod tests {
                 trigger_parameter: "torque_cv".to_string(),
                 trigger_value: 0.25,
                 threshold_value: 0.15,
+                provisional_during_learning: false,
+                sparkline: None,
+                gas_lag: None,
+                causal_leads: Vec::new(),
             },
             history_window: Vec::new(),
+            history_summary: None,
             outcome: EventOutcome::Pending,
             notes: None,
             depth,
@@ -603,4 +818,89 @@ mod tests {
         assert_eq!(tp_counts[0].0, "DrillingEfficiency");
         assert_eq!(tp_counts[0].1, 1);
     }
+
+    #[test]
+    fn test_size_cap_evicts_oldest_keeps_criticals() {
+        // Size one event to put the cap at roughly ten of them
+        let probe = EventStore::open_in_memory().expect("open");
+        probe
+            .upsert_event(&make_test_event("probe", 8000.0, 1_700_000_000), None)
+            .expect("insert");
+        let per_event = probe.stored_bytes();
+        let cap = per_event * 10;
+
+        let store = EventStore::open_in_memory()
+            .expect("open")
+            .with_max_bytes(cap);
+        // Oldest events are critical — they must outlive newer informational ones
+        for i in 0..3u64 {
+            let mut event = make_test_event(&format!("crit-{i}"), 8000.0, 1_700_000_000 + i);
+            event.advisory.severity = FinalSeverity::Critical;
+            store.upsert_event(&event, None).expect("insert critical");
+        }
+        for i in 0..30u64 {
+            let event = make_test_event(&format!("info-{i:02}"), 8000.0, 1_700_001_000 + i);
+            store.upsert_event(&event, None).expect("insert info");
+        }
+
+        assert!(store.stored_bytes() <= cap + per_event);
+        let ids: Vec<String> = store
+            .events_modified_since(0, 1_000)
+            .expect("all")
+            .into_iter()
+            .map(|e| e.id)
+            .collect();
+        for i in 0..3 {
+            assert!(ids.contains(&format!("crit-{i}")), "critical {i} evicted");
+        }
+        assert!(!ids.contains(&"info-00".to_string()), "oldest info kept");
+        assert!(ids.contains(&"info-29".to_string()), "newest info evicted");
+
+        let dropped = store.dropped_events();
+        assert_eq!(dropped as usize, 33 - store.count().expect("count"));
+        let evictions = store.eviction_log().windows();
+        assert!(!evictions.is_empty());
+        assert!(evictions.iter().all(|e| e.bytes > 0 && e.oldest_ts >= 1_700_001_000));
+        assert_eq!(evictions, store.load_evictions().expect("persisted"));
+    }
+
+    #[test]
+    fn test_stored_bytes_tracked_across_inserts_and_evictions() {
+        let store = EventStore::open_in_memory().expect("open");
+        for i in 0..10u64 {
+            let event = make_test_event(&format!("evt-{i}"), 8000.0, 1_700_000_000 + i);
+            store.upsert_event(&event, None).expect("insert");
+        }
+        // A duplicate that is not newer leaves the total alone
+        store
+            .upsert_event(&make_test_event("evt-0", 8000.0, 1_700_000_000), None)
+            .expect("duplicate");
+        let tracked = store.stored_bytes();
+        store.recount_bytes().expect("recount");
+        assert_eq!(tracked, store.stored_bytes());
+
+        let cap = tracked / 2;
+        let store = store.with_max_bytes(cap);
+        store
+            .upsert_event(&make_test_event("evt-10", 8000.0, 1_700_000_010), None)
+            .expect("insert");
+        assert!(store.dropped_events() > 0);
+        assert!(store.stored_bytes() <= cap);
+        let tracked = store.stored_bytes();
+        store.recount_bytes().expect("recount");
+        assert_eq!(tracked, store.stored_bytes());
+    }
+
+    #[test]
+    fn test_size_cap_never_evicts_criticals() {
+        let store = EventStore::open_in_memory().expect("open").with_max_bytes(1);
+        for i in 0..5u64 {
+            let mut event = make_test_event(&format!("crit-{i}"), 8000.0, 1_700_000_000 + i);
+            event.advisory.severity = FinalSeverity::Critical;
+            store.upsert_event(&event, None).expect("insert");
+        }
+        assert_eq!(store.count().expect("count"), 5);
+        assert_eq!(store.dropped_events(), 0);
+        assert!(store.critical_only_warned.get());
+    }
 }
@@ -121,7 +121,9 @@ pub fn assemble_prognosis(config: &KnowledgeBaseConfig) -> Option<FormationProgn
             coordinate_system: String::new(),
         });
 
-    let casings = pre_spud.map(|ps| ps.casings).unwrap_or_default();
+    let (casings, sidetracks, mud_program) = pre_spud
+        .map(|ps| (ps.casings, ps.sidetracks, ps.mud_program))
+        .unwrap_or_default();
 
     info!(
         formations = formations.len(),
@@ -133,6 +135,8 @@ pub fn assemble_prognosis(config: &KnowledgeBaseConfig) -> Option<FormationProgn
         well: well_info,
         formations,
         casings,
+        sidetracks,
+        mud_program,
     })
 }
 
@@ -53,15 +53,19 @@ pub fn compress_file(path: &Path) -> io::Result<()> {
     Ok(())
 }
 
-/// Write a value as TOML to a file
-pub fn write_toml<T: serde::Serialize>(path: &Path, value: &T) -> io::Result<()> {
-    let content = toml::to_string_pretty(value).map_err(|e| {
+/// Serialize a value to the pretty TOML written by [`write_toml`]
+pub fn to_toml_string<T: serde::Serialize>(value: &T) -> io::Result<String> {
+    toml::to_string_pretty(value).map_err(|e| {
         io::Error::new(
             io::ErrorKind::InvalidData,
             format!("TOML serialize error: {}", e),
         )
-    })?;
-    std::fs::write(path, content)
+    })
+}
+
+/// Write a value as TOML to a file
+pub fn write_toml<T: serde::Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    std::fs::write(path, to_toml_string(value)?)
 }
 
 #[cfg(test)]
@@ -1,13 +1,27 @@
 //! Mid-well snapshot writer and cap enforcement
+//!
+//! Consecutive snapshots are mostly identical, so [`write_snapshot_delta`]
+//! stores `delta_{timestamp}.toml` files holding only the fields changed
+//! since the previous snapshot, with a full `snapshot_{timestamp}.toml`
+//! every `full_snapshot_every` writes to bound the chain.
+//! [`load_all_snapshots`] resolves deltas back into full snapshots.
 
 use crate::knowledge_base::compressor;
 use crate::types::{
-    AnalysisResult, KnowledgeBaseConfig, MLInsightsReport, MidWellSnapshot, SustainedStats,
-    WitsPacket,
+    AnalysisResult, KnowledgeBaseConfig, MLInsightsReport, MidWellSnapshot, MidWellSnapshotDelta,
+    SustainedStats, WitsPacket,
 };
+use std::collections::HashMap;
 use std::io;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
+const FULL_PREFIX: &str = "snapshot_";
+const DELTA_PREFIX: &str = "delta_";
+
+/// A stored snapshot file: `(timestamp, path, is_delta)`.
+type SnapshotFile = (u64, PathBuf, bool);
+
 /// Write a mid-well snapshot from a successful `MLInsightsReport`.
 ///
 /// Only writes if the report contains successful analysis results.
@@ -48,14 +62,25 @@ pub fn write_snapshot_with_packets(
     report: &MLInsightsReport,
     packets: &[WitsPacket],
 ) -> io::Result<()> {
+    match build_snapshot_with_packets(report, packets) {
+        Some(snapshot) => store_snapshot(config, &snapshot),
+        None => Ok(()),
+    }
+}
+
+/// Build a mid-well snapshot with sustained-stats from raw packets.
+///
+/// Returns `None` for failed analyses (nothing to record).
+pub fn build_snapshot_with_packets(
+    report: &MLInsightsReport,
+    packets: &[WitsPacket],
+) -> Option<MidWellSnapshot> {
     let insights = match &report.result {
         AnalysisResult::Success(insights) => insights,
-        AnalysisResult::Failure(_) => return Ok(()),
+        AnalysisResult::Failure(_) => return None,
     };
 
-    let sustained_stats = compute_sustained_stats(packets);
-
-    let snapshot = MidWellSnapshot {
+    Some(MidWellSnapshot {
         timestamp: report.timestamp,
         well_id: report.well_id.clone(),
         formation_name: report.formation_type.clone(),
@@ -65,21 +90,238 @@ pub fn write_snapshot_with_packets(
         optimal_params: insights.optimal_params.clone(),
         sample_count: insights.sample_count,
         confidence: insights.confidence,
-        sustained_stats,
-    };
+        sustained_stats: compute_sustained_stats(packets),
+    })
+}
 
+/// Write a built snapshot to `mid-well/snapshot_{timestamp}.toml`.
+///
+/// Idempotent: rewriting the same snapshot replaces the same file.
+pub fn store_snapshot(config: &KnowledgeBaseConfig, snapshot: &MidWellSnapshot) -> io::Result<()> {
     let dir = config.mid_well_dir();
     std::fs::create_dir_all(&dir)?;
 
     let filename = format!("snapshot_{}.toml", snapshot.timestamp);
     let path = dir.join(&filename);
 
-    compressor::write_toml(&path, &snapshot)?;
+    compressor::write_toml(&path, snapshot)?;
     debug!(path = %path.display(), "Wrote mid-well snapshot (with sustained stats)");
 
     Ok(())
 }
 
+/// Write a snapshot as a delta against the previous one.
+///
+/// Falls back to a full snapshot when there is no previous snapshot, the
+/// previous one can't be resolved, or the chain since the last full
+/// snapshot has reached `full_snapshot_every`.
+pub fn write_snapshot_delta(
+    config: &KnowledgeBaseConfig,
+    snapshot: &MidWellSnapshot,
+) -> io::Result<()> {
+    let dir = config.mid_well_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    let mut files = list_snapshot_files(&dir)?;
+    // Ignore this timestamp's own file so a retried write picks the same base
+    files.retain(|(ts, _, _)| *ts < snapshot.timestamp);
+
+    let base = match latest_with_chain(&files) {
+        Ok(base) => base,
+        Err(e) => {
+            warn!(error = %e, "Previous mid-well snapshot unreadable — writing full snapshot");
+            None
+        }
+    };
+    let Some((base, chain_len)) = base else {
+        return store_snapshot(config, snapshot);
+    };
+    if chain_len + 1 >= config.full_snapshot_every.max(1) {
+        return store_snapshot(config, snapshot);
+    }
+
+    let delta = diff_snapshots(&base, snapshot)?;
+    let path = dir.join(format!("{}{}.toml", DELTA_PREFIX, snapshot.timestamp));
+    compressor::write_toml(&path, &delta)?;
+    debug!(
+        path = %path.display(),
+        base = delta.base_timestamp,
+        changed = delta.changes.len(),
+        "Wrote mid-well snapshot delta"
+    );
+    Ok(())
+}
+
+/// Fields of `snapshot` that differ from `base`.
+pub fn diff_snapshots(
+    base: &MidWellSnapshot,
+    snapshot: &MidWellSnapshot,
+) -> io::Result<MidWellSnapshotDelta> {
+    let base_table = to_table(base)?;
+    let new_table = to_table(snapshot)?;
+    let mut removed = Vec::new();
+    let changes = diff_tables(&base_table, &new_table, "", &mut removed);
+    Ok(MidWellSnapshotDelta {
+        timestamp: snapshot.timestamp,
+        base_timestamp: base.timestamp,
+        removed,
+        changes,
+    })
+}
+
+/// Reconstruct the full snapshot a delta describes from its base.
+pub fn assemble(
+    base: &MidWellSnapshot,
+    delta: &MidWellSnapshotDelta,
+) -> io::Result<MidWellSnapshot> {
+    let mut table = to_table(base)?;
+    for path in &delta.removed {
+        remove_path(&mut table, path);
+    }
+    merge_tables(&mut table, &delta.changes);
+    table.insert(
+        "timestamp".to_string(),
+        toml::Value::Integer(delta.timestamp as i64),
+    );
+    toml::Value::Table(table).try_into().map_err(|e| {
+        invalid_data(format!(
+            "delta {} does not assemble: {}",
+            delta.timestamp, e
+        ))
+    })
+}
+
+fn to_table(snapshot: &MidWellSnapshot) -> io::Result<toml::Table> {
+    toml::Table::try_from(snapshot)
+        .map_err(|e| invalid_data(format!("TOML serialize error: {}", e)))
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn diff_tables(
+    base: &toml::Table,
+    new: &toml::Table,
+    prefix: &str,
+    removed: &mut Vec<String>,
+) -> toml::Table {
+    for key in base.keys().filter(|k| !new.contains_key(*k)) {
+        removed.push(format!("{}{}", prefix, key));
+    }
+    let mut changes = toml::Table::new();
+    for (key, value) in new {
+        match (base.get(key), value) {
+            (Some(old), _) if old == value => {}
+            (Some(toml::Value::Table(old)), toml::Value::Table(new_sub)) => {
+                let sub = diff_tables(old, new_sub, &format!("{}{}.", prefix, key), removed);
+                if !sub.is_empty() {
+                    changes.insert(key.clone(), toml::Value::Table(sub));
+                }
+            }
+            _ => {
+                changes.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    changes
+}
+
+fn merge_tables(target: &mut toml::Table, changes: &toml::Table) {
+    for (key, value) in changes {
+        match (target.get_mut(key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(sub)) => {
+                merge_tables(existing, sub);
+            }
+            _ => {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+fn remove_path(table: &mut toml::Table, path: &str) {
+    match path.split_once('.') {
+        Some((head, rest)) => {
+            if let Some(toml::Value::Table(sub)) = table.get_mut(head) {
+                remove_path(sub, rest);
+            }
+        }
+        None => {
+            table.remove(path);
+        }
+    }
+}
+
+/// Stored snapshot and delta files in `dir`, oldest first.
+fn list_snapshot_files(dir: &Path) -> io::Result<Vec<SnapshotFile>> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let fname = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let (rest, is_delta) = if let Some(rest) = fname.strip_prefix(FULL_PREFIX) {
+            (rest, false)
+        } else if let Some(rest) = fname.strip_prefix(DELTA_PREFIX) {
+            (rest, true)
+        } else {
+            continue;
+        };
+        // Extract timestamp from filename: {prefix}{timestamp}.toml[.zst]
+        let ts = rest
+            .strip_suffix(".toml.zst")
+            .or_else(|| rest.strip_suffix(".toml"))
+            .and_then(|s| s.parse::<u64>().ok());
+        if let Some(ts) = ts {
+            files.push((ts, path, is_delta));
+        }
+    }
+    files.sort_by_key(|(ts, _, _)| *ts);
+    Ok(files)
+}
+
+/// Resolve the newest file to a full snapshot, with the number of deltas
+/// chained on top of its full base.
+fn latest_with_chain(files: &[SnapshotFile]) -> io::Result<Option<(MidWellSnapshot, usize)>> {
+    let Some(&(latest_ts, _, _)) = files.last() else {
+        return Ok(None);
+    };
+    let by_ts: HashMap<u64, (&PathBuf, bool)> = files
+        .iter()
+        .map(|(ts, path, is_delta)| (*ts, (path, *is_delta)))
+        .collect();
+
+    // Walk base pointers back to a full snapshot, then replay forward
+    let mut deltas: Vec<MidWellSnapshotDelta> = Vec::new();
+    let mut ts = latest_ts;
+    let base = loop {
+        let (path, is_delta) = by_ts
+            .get(&ts)
+            .ok_or_else(|| invalid_data(format!("mid-well delta base {} is missing", ts)))?;
+        if !is_delta {
+            break compressor::read_toml::<MidWellSnapshot>(path)?;
+        }
+        let delta: MidWellSnapshotDelta = compressor::read_toml(path)?;
+        if delta.base_timestamp >= ts {
+            return Err(invalid_data(format!(
+                "mid-well delta {} points forward to {}",
+                ts, delta.base_timestamp
+            )));
+        }
+        ts = delta.base_timestamp;
+        deltas.push(delta);
+    };
+
+    let chain_len = deltas.len();
+    let mut snapshot = base;
+    for delta in deltas.iter().rev() {
+        snapshot = assemble(&snapshot, delta)?;
+    }
+    Ok(Some((snapshot, chain_len)))
+}
+
 fn compute_sustained_stats(packets: &[WitsPacket]) -> Option<SustainedStats> {
     let sustained: Vec<_> = packets
         .iter()
@@ -99,34 +341,37 @@ fn compute_sustained_stats(packets: &[WitsPacket]) -> Option<SustainedStats> {
     })
 }
 
-/// Load all mid-well snapshots (both plain .toml and compressed .toml.zst)
+/// Load all mid-well snapshots (both plain .toml and compressed .toml.zst),
+/// resolving deltas into full snapshots.
+///
+/// Deltas whose base is missing or unreadable are skipped with a warning.
 pub fn load_all_snapshots(config: &KnowledgeBaseConfig) -> io::Result<Vec<MidWellSnapshot>> {
-    let dir = config.mid_well_dir();
-    if !dir.exists() {
-        return Ok(Vec::new());
-    }
-
-    let mut snapshots = Vec::new();
-    for entry in std::fs::read_dir(&dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let fname = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-        if !fname.starts_with("snapshot_") {
-            continue;
-        }
-        if !fname.ends_with(".toml") && !fname.ends_with(".toml.zst") {
-            continue;
-        }
-
-        match compressor::read_toml::<MidWellSnapshot>(&path) {
-            Ok(s) => snapshots.push(s),
+    let files = list_snapshot_files(&config.mid_well_dir())?;
+
+    // Files are oldest first, so a delta's base is resolved before it
+    let mut resolved: HashMap<u64, MidWellSnapshot> = HashMap::new();
+    for (ts, path, is_delta) in &files {
+        let snapshot = if *is_delta {
+            compressor::read_toml::<MidWellSnapshotDelta>(path).and_then(|delta| {
+                let base = resolved.get(&delta.base_timestamp).ok_or_else(|| {
+                    invalid_data(format!("delta base {} is missing", delta.base_timestamp))
+                })?;
+                assemble(base, &delta)
+            })
+        } else {
+            compressor::read_toml::<MidWellSnapshot>(path)
+        };
+        match snapshot {
+            Ok(s) => {
+                resolved.insert(*ts, s);
+            }
             Err(e) => {
                 warn!(path = %path.display(), error = %e, "Failed to read snapshot");
             }
         }
     }
 
+    let mut snapshots: Vec<MidWellSnapshot> = resolved.into_values().collect();
     snapshots.sort_by_key(|s| s.timestamp);
     Ok(snapshots)
 }
@@ -135,50 +380,49 @@ pub fn load_all_snapshots(config: &KnowledgeBaseConfig) -> io::Result<Vec<MidWel
 /// - Keep newest `max_mid_well_snapshots` as plain TOML
 /// - Beyond cap but within `cold_retention_days` → compress
 /// - Beyond retention → delete
+///
+/// Deletion works on whole delta chains (a full snapshot and the deltas
+/// written on top of it): a chain is only deleted once its newest file is
+/// beyond retention, so a base is never removed from under a delta that
+/// [`load_all_snapshots`] still needs to resolve.
 pub fn enforce_snapshot_cap(config: &KnowledgeBaseConfig) -> io::Result<()> {
     let dir = config.mid_well_dir();
     if !dir.exists() {
         return Ok(());
     }
 
-    // Collect all snapshot files with their timestamps
-    let mut files: Vec<(u64, std::path::PathBuf, bool)> = Vec::new(); // (timestamp, path, is_compressed)
-
-    for entry in std::fs::read_dir(&dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let fname = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("")
-            .to_string();
-
-        if !fname.starts_with("snapshot_") {
-            continue;
-        }
-
-        let is_compressed = fname.ends_with(".toml.zst");
-        if !fname.ends_with(".toml") && !is_compressed {
-            continue;
-        }
-
-        // Extract timestamp from filename: snapshot_{timestamp}.toml[.zst]
-        let ts_str = fname.strip_prefix("snapshot_").and_then(|s| {
-            s.strip_suffix(".toml.zst")
-                .or_else(|| s.strip_suffix(".toml"))
-        });
-
-        if let Some(ts) = ts_str.and_then(|s| s.parse::<u64>().ok()) {
-            files.push((ts, path, is_compressed));
-        }
-    }
-
+    // Collect all snapshot and delta files with their timestamps
+    let files = list_snapshot_files(&dir)?;
     if files.is_empty() {
         return Ok(());
     }
 
-    // Sort by timestamp descending (newest first)
-    files.sort_by(|a, b| b.0.cmp(&a.0));
+    // Deltas always build on the newest file before them, so a chain runs
+    // from a full snapshot up to the next one. Key each file by its chain's
+    // full snapshot; deltas with no full snapshot before them are orphans
+    // and form a chain of their own.
+    let mut chain_newest: HashMap<u64, u64> = HashMap::new();
+    let mut current_chain: Option<u64> = None;
+    let files: Vec<(u64, PathBuf, bool, u64)> = files
+        .into_iter()
+        .map(|(ts, path, is_delta)| {
+            if !is_delta {
+                current_chain = Some(ts);
+            }
+            let chain = if is_delta {
+                current_chain.unwrap_or(ts)
+            } else {
+                ts
+            };
+            let newest = chain_newest.entry(chain).or_insert(ts);
+            *newest = (*newest).max(ts);
+            let is_compressed = path.extension().and_then(|e| e.to_str()) == Some("zst");
+            (ts, path, is_compressed, chain)
+        })
+        .collect();
+
+    // Newest first
+    let files: Vec<_> = files.into_iter().rev().collect();
 
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -189,14 +433,14 @@ pub fn enforce_snapshot_cap(config: &KnowledgeBaseConfig) -> io::Result<()> {
     let mut compressed_count = 0u32;
     let mut deleted_count = 0u32;
 
-    for (i, (ts, path, is_compressed)) in files.iter().enumerate() {
+    for (i, (_, path, is_compressed, chain)) in files.iter().enumerate() {
         if i < config.max_mid_well_snapshots {
             // Within hot cap — keep as plain TOML (already is, nothing to do)
             continue;
         }
 
-        if *ts < retention_cutoff {
-            // Beyond retention — delete
+        if chain_newest[chain] < retention_cutoff {
+            // Whole chain beyond retention — delete
             if let Err(e) = std::fs::remove_file(path) {
                 warn!(path = %path.display(), error = %e, "Failed to delete old snapshot");
             } else {
@@ -235,6 +479,7 @@ mod tests {
             depth_range: (1000.0, 2000.0),
             well_id: "Well-A".to_string(),
             field_name: "TestField".to_string(),
+            wellbore: None,
             bit_hours: 10.0,
             bit_depth: 500.0,
             formation_type: "Shallow".to_string(),
@@ -277,6 +522,7 @@ mod tests {
             well: "Well-A".to_string(),
             max_mid_well_snapshots: 2,
             cold_retention_days: 365, // long retention so nothing gets deleted
+            ..Default::default()
         };
         config.ensure_dirs().expect("dirs");
 
@@ -314,4 +560,123 @@ mod tests {
         assert_eq!(plain, 2, "should keep 2 newest as plain");
         assert_eq!(compressed, 2, "should compress 2 oldest");
     }
+
+    #[test]
+    fn test_retention_keeps_base_of_live_delta_chain() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let config = KnowledgeBaseConfig {
+            root: tmp.path().to_path_buf(),
+            field: "TestField".to_string(),
+            well: "Well-A".to_string(),
+            max_mid_well_snapshots: 0,
+            cold_retention_days: 1,
+            full_snapshot_every: 10,
+            ..Default::default()
+        };
+        config.ensure_dirs().expect("dirs");
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let old = now - 3 * 86400;
+
+        // Old chain that has fully aged out
+        let stale = build_snapshot_with_packets(&make_report(old - 7200), &[]).expect("stale");
+        store_snapshot(&config, &stale).expect("write stale");
+
+        // Chain whose base is past retention but whose newest delta is not
+        let base = build_snapshot_with_packets(&make_report(old), &[]).expect("base");
+        store_snapshot(&config, &base).expect("write base");
+        let mut second = base.clone();
+        second.timestamp = old + 3600;
+        second.bit_hours = 11.0;
+        write_snapshot_delta(&config, &second).expect("write delta 1");
+        let mut third = second.clone();
+        third.timestamp = now - 3600;
+        third.bit_hours = 12.0;
+        write_snapshot_delta(&config, &third).expect("write delta 2");
+
+        enforce_snapshot_cap(&config).expect("enforce");
+
+        let snapshots = load_all_snapshots(&config).expect("load");
+        let timestamps: Vec<u64> = snapshots.iter().map(|s| s.timestamp).collect();
+        assert_eq!(timestamps, vec![old, old + 3600, now - 3600]);
+        assert_eq!(snapshots[2].bit_hours, 12.0);
+    }
+
+    #[test]
+    fn test_delta_chain_reconstructs_latest() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let config = KnowledgeBaseConfig {
+            root: tmp.path().to_path_buf(),
+            field: "TestField".to_string(),
+            well: "Well-A".to_string(),
+            full_snapshot_every: 3,
+            ..Default::default()
+        };
+        config.ensure_dirs().expect("dirs");
+
+        let base = build_snapshot_with_packets(&make_report(1700000000), &[]).expect("base");
+        write_snapshot_delta(&config, &base).expect("write base");
+
+        let mut second = base.clone();
+        second.timestamp = 1700003600;
+        second.bit_hours = 11.0;
+        second.optimal_params.best_wob = 25.0;
+        second.sustained_stats = Some(SustainedStats {
+            avg_rop_ft_hr: 60.0,
+            best_rop_ft_hr: 80.0,
+            avg_mse_psi: 20000.0,
+            avg_wob_klbs: 24.0,
+            avg_rpm: 120.0,
+            sample_count: 40,
+        });
+        write_snapshot_delta(&config, &second).expect("write delta 1");
+
+        let mut third = second.clone();
+        third.timestamp = 1700007200;
+        third.formation_name = "Deep".to_string();
+        third.depth_range = (2000.0, 2500.0);
+        third.optimal_params.best_rpm = 140.0;
+        third.sustained_stats = None;
+        write_snapshot_delta(&config, &third).expect("write delta 2");
+
+        // Base is full, the next two are deltas carrying only changed fields
+        let dir = config.mid_well_dir();
+        assert!(dir.join("snapshot_1700000000.toml").exists());
+        assert!(dir.join("delta_1700003600.toml").exists());
+        let delta: MidWellSnapshotDelta =
+            compressor::read_toml(&dir.join("delta_1700007200.toml")).expect("read delta");
+        assert_eq!(delta.base_timestamp, 1700003600);
+        assert!(delta.changes.contains_key("formation_name"));
+        assert!(!delta.changes.contains_key("well_id"));
+        let params = delta.changes["optimal_params"].as_table().expect("table");
+        assert_eq!(params.len(), 1, "only best_rpm changed: {params:?}");
+        assert_eq!(delta.removed, vec!["sustained_stats".to_string()]);
+
+        let snapshots = load_all_snapshots(&config).expect("load");
+        assert_eq!(snapshots.len(), 3);
+        let latest = &snapshots[2];
+        assert_eq!(latest.timestamp, 1700007200);
+        assert_eq!(latest.formation_name, "Deep");
+        assert_eq!(latest.depth_range, (2000.0, 2500.0));
+        assert_eq!(latest.bit_hours, 11.0);
+        assert_eq!(latest.optimal_params.best_wob, 25.0);
+        assert_eq!(latest.optimal_params.best_rpm, 140.0);
+        assert!(latest.sustained_stats.is_none());
+        assert_eq!(
+            snapshots[1]
+                .sustained_stats
+                .as_ref()
+                .map(|s| s.sample_count),
+            Some(40)
+        );
+
+        // Chain reached full_snapshot_every: the next write is a full snapshot
+        let mut fourth = third.clone();
+        fourth.timestamp = 1700010800;
+        write_snapshot_delta(&config, &fourth).expect("write full");
+        assert!(dir.join("snapshot_1700010800.toml").exists());
+    }
 }
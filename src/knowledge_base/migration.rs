@@ -6,17 +6,81 @@ use crate::types::{
     OffsetPerformanceOverride, PostWellFormationPerformance, PreSpudFormation, PreSpudPrognosis,
 };
 use std::collections::HashMap;
+use std::fmt;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
+/// What a migration does to one knowledge base file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteAction {
+    /// File does not exist yet
+    Create,
+    /// File exists with different content
+    Overwrite,
+    /// File exists with identical content — left untouched
+    Skip,
+}
+
+impl fmt::Display for WriteAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Create => "create",
+            Self::Overwrite => "overwrite",
+            Self::Skip => "skip",
+        })
+    }
+}
+
+/// A file the migration writes (or would write, in a dry run)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedWrite {
+    pub path: PathBuf,
+    pub action: WriteAction,
+}
+
+/// Plan (and unless `dry_run`, perform) writing `value` as TOML to `path`
+fn plan_write<T: serde::Serialize>(
+    path: &Path,
+    value: &T,
+    dry_run: bool,
+    plan: &mut Vec<PlannedWrite>,
+) -> io::Result<()> {
+    let content = compressor::to_toml_string(value)?;
+    let action = match std::fs::read_to_string(path) {
+        Ok(existing) if existing == content => WriteAction::Skip,
+        Ok(_) => WriteAction::Overwrite,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => WriteAction::Create,
+        Err(e) => return Err(e),
+    };
+    if !dry_run && action != WriteAction::Skip {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)?;
+    }
+    plan.push(PlannedWrite {
+        path: path.to_path_buf(),
+        action,
+    });
+    Ok(())
+}
+
 /// Migrate a flat `well_prognosis.toml` into the knowledge base directory structure.
 ///
 /// Splits the file into:
 /// 1. `{field}/geology.toml` — geological data
 /// 2. `{field}/wells/{well}/pre-spud/prognosis.toml` — engineering parameters
 /// 3. `{field}/wells/{offset_well}/post-well/performance_{formation}.toml` — offset data
-pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
+///
+/// Returns every file written, marked create / overwrite / skip (identical
+/// content is not rewritten). With `dry_run` nothing touches disk and the
+/// returned plan shows what a real run would do.
+pub fn migrate_flat_to_kb(
+    flat_path: &Path,
+    kb_root: &Path,
+    dry_run: bool,
+) -> io::Result<Vec<PlannedWrite>> {
     let content = std::fs::read_to_string(flat_path)?;
     let prognosis: FormationPrognosis = toml::from_str(&content).map_err(|e| {
         io::Error::new(
@@ -31,6 +95,7 @@ pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
     info!(
         field = field,
         well = well,
+        dry_run,
         "Migrating flat prognosis to knowledge base"
     );
 
@@ -40,7 +105,10 @@ pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
         well: well.clone(),
         ..Default::default()
     };
-    config.ensure_dirs()?;
+    if !dry_run {
+        config.ensure_dirs()?;
+    }
+    let mut plan = Vec::new();
 
     // 1. Extract geology
     let geology = FieldGeology {
@@ -61,8 +129,11 @@ pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
             })
             .collect(),
     };
-    compressor::write_toml(&config.geology_path(), &geology)?;
-    info!(formations = geology.formations.len(), "Wrote geology.toml");
+    plan_write(&config.geology_path(), &geology, dry_run, &mut plan)?;
+    info!(
+        formations = geology.formations.len(),
+        "Planned geology.toml"
+    );
 
     // 2. Extract pre-spud prognosis
     let pre_spud = PreSpudPrognosis {
@@ -93,9 +164,11 @@ pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
             })
             .collect(),
         casings: prognosis.casings.clone(),
+        sidetracks: prognosis.sidetracks.clone(),
+        mud_program: prognosis.mud_program.clone(),
     };
-    compressor::write_toml(&config.pre_spud_path(well), &pre_spud)?;
-    info!("Wrote pre-spud prognosis");
+    plan_write(&config.pre_spud_path(well), &pre_spud, dry_run, &mut plan)?;
+    info!("Planned pre-spud prognosis");
 
     // 3. Extract offset performance into per-well post-well files
     let mut offset_wells: HashMap<String, Vec<(&FormationInterval, &str)>> = HashMap::new();
@@ -110,7 +183,6 @@ pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
 
     for (offset_well_name, formation_refs) in &offset_wells {
         let post_dir = config.post_well_dir(offset_well_name);
-        std::fs::create_dir_all(&post_dir)?;
 
         for (formation, _) in formation_refs {
             // Since offset data in the flat file is aggregated across wells,
@@ -141,23 +213,25 @@ pub fn migrate_flat_to_kb(flat_path: &Path, kb_root: &Path) -> io::Result<()> {
                 .replace(' ', "_")
                 .replace(['/', '\\', '(', ')'], "");
             let filename = format!("performance_{}.toml", safe_name);
-            compressor::write_toml(&post_dir.join(&filename), &perf)?;
+            plan_write(&post_dir.join(&filename), &perf, dry_run, &mut plan)?;
         }
 
         info!(
             well = offset_well_name,
             formations = formation_refs.len(),
-            "Wrote offset well performance files"
+            "Planned offset well performance files"
         );
     }
 
     info!(
         geology = geology.formations.len(),
         offset_wells = offset_wells.len(),
+        files = plan.len(),
+        dry_run,
         "Migration complete"
     );
 
-    Ok(())
+    Ok(plan)
 }
 
 #[cfg(test)]
@@ -174,7 +248,7 @@ mod tests {
         }
 
         let tmp = tempfile::tempdir().expect("tempdir");
-        migrate_flat_to_kb(flat_path, tmp.path()).expect("migration");
+        migrate_flat_to_kb(flat_path, tmp.path(), false).expect("migration");
 
         // Verify structure was created
         assert!(tmp.path().join("Volve/geology.toml").exists());
@@ -210,4 +284,119 @@ mod tests {
         assert!(!nordland.offset_performance.wells.is_empty());
         assert!(nordland.offset_performance.avg_rop_ft_hr > 0.0);
     }
+
+    fn write_flat_prognosis(dir: &Path) -> PathBuf {
+        use crate::types::{
+            BestParams, FormationParameters, OffsetPerformance, ParameterRange, PrognosisWellInfo,
+        };
+        let range = |min, optimal, max| ParameterRange { min, optimal, max };
+        let prognosis = FormationPrognosis {
+            well: PrognosisWellInfo {
+                name: "F-15B".into(),
+                field: "Volve".into(),
+                spud_date: String::new(),
+                target_depth_ft: 9000.0,
+                coordinate_system: String::new(),
+            },
+            formations: vec![FormationInterval {
+                name: "Hugin Fm".into(),
+                depth_top_ft: 8000.0,
+                depth_base_ft: 9000.0,
+                lithology: "sandstone".into(),
+                hardness: 5.0,
+                drillability: "medium".into(),
+                pore_pressure_ppg: 10.0,
+                fracture_gradient_ppg: 14.0,
+                hazards: vec![],
+                parameters: FormationParameters {
+                    wob_klbs: range(15.0, 20.0, 30.0),
+                    rpm: range(80.0, 100.0, 140.0),
+                    flow_gpm: range(400.0, 500.0, 600.0),
+                    mud_weight_ppg: 12.0,
+                    bit_type: "PDC".into(),
+                },
+                offset_performance: OffsetPerformance {
+                    wells: vec!["F-9A".into(), "F-12".into()],
+                    avg_rop_ft_hr: 60.0,
+                    best_rop_ft_hr: 90.0,
+                    avg_mse_psi: 20000.0,
+                    best_params: BestParams {
+                        wob_klbs: 25.0,
+                        rpm: 120.0,
+                    },
+                    notes: String::new(),
+                },
+            }],
+            casings: vec![],
+            sidetracks: vec![],
+            mud_program: Vec::new(),
+        };
+        let path = dir.join("well_prognosis.toml");
+        compressor::write_toml(&path, &prognosis).expect("write flat prognosis");
+        path
+    }
+
+    /// Every file under `root` with its contents
+    fn snapshot_tree(root: &Path) -> Vec<(PathBuf, Vec<u8>)> {
+        let mut files = Vec::new();
+        let mut dirs = vec![root.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(&dir).expect("readdir") {
+                let path = entry.expect("entry").path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else {
+                    let data = std::fs::read(&path).expect("read");
+                    files.push((path, data));
+                }
+            }
+        }
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_dry_run_reports_overwrites_without_writing() {
+        let src = tempfile::tempdir().expect("tempdir");
+        let kb = tempfile::tempdir().expect("tempdir");
+        let flat = write_flat_prognosis(src.path());
+
+        // Fresh KB: a dry run plans creates and writes nothing
+        let plan = migrate_flat_to_kb(&flat, kb.path(), true).expect("dry run");
+        assert_eq!(plan.len(), 4, "geology, pre-spud, 2 offset wells: {plan:?}");
+        assert!(plan.iter().all(|w| w.action == WriteAction::Create));
+        assert!(snapshot_tree(kb.path()).is_empty());
+
+        // Populate the KB, then edit two files by hand
+        migrate_flat_to_kb(&flat, kb.path(), false).expect("migrate");
+        let geology = kb.path().join("Volve/geology.toml");
+        let perf = kb
+            .path()
+            .join("Volve/wells/F-9A/post-well/performance_Hugin_Fm.toml");
+        std::fs::write(&geology, "# edited by hand\n").expect("edit geology");
+        std::fs::write(&perf, "# edited by hand\n").expect("edit perf");
+        let before = snapshot_tree(kb.path());
+
+        let plan = migrate_flat_to_kb(&flat, kb.path(), true).expect("dry run");
+        assert_eq!(snapshot_tree(kb.path()), before, "dry run touched the KB");
+
+        let action_for = |path: &Path| {
+            plan.iter()
+                .find(|w| w.path == path)
+                .map(|w| w.action)
+                .expect("planned")
+        };
+        assert_eq!(action_for(&geology), WriteAction::Overwrite);
+        assert_eq!(action_for(&perf), WriteAction::Overwrite);
+        assert_eq!(
+            action_for(&kb.path().join("Volve/wells/F-15B/pre-spud/prognosis.toml")),
+            WriteAction::Skip
+        );
+        assert_eq!(
+            plan.iter()
+                .filter(|w| w.action == WriteAction::Overwrite)
+                .count(),
+            2
+        );
+    }
 }
@@ -13,7 +13,7 @@
 //!     wells/
 //!       {well}/
 //!         pre-spud/prognosis.toml
-//!         mid-well/snapshot_{timestamp}.toml[.zst]
+//!         mid-well/snapshot_{timestamp}.toml[.zst], delta_{timestamp}.toml[.zst]
 //!         post-well/summary.toml, performance_{formation}.toml
 //! ```
 
@@ -23,11 +23,12 @@ pub mod layout;
 pub mod mid_well;
 pub mod migration;
 pub mod post_well;
+pub mod snapshot_queue;
 pub mod watcher;
 
 use crate::types::{
-    FormationInterval, FormationPrognosis, KnowledgeBaseConfig, MLInsightsReport, PostWellSummary,
-    WitsPacket,
+    FormationInterval, FormationParameters, FormationPrognosis, KnowledgeBaseConfig,
+    MLInsightsReport, MidWellSnapshot, ParameterRange, PostWellSummary, WitsPacket,
 };
 use std::sync::Arc;
 use std::time::Duration;
@@ -37,6 +38,11 @@ use tracing::{info, warn};
 /// Default watcher poll interval
 const WATCHER_POLL_SECS: u64 = 30;
 
+/// Offset wells the prognosis counts as when blending recommended
+/// parameters: offset weight is `n / (n + OFFSET_PRIOR_WELLS)`, so three
+/// offset wells carry equal weight to the plan.
+const OFFSET_PRIOR_WELLS: f64 = 3.0;
+
 /// Knowledge base entry point
 pub struct KnowledgeBase {
     config: KnowledgeBaseConfig,
@@ -68,7 +74,7 @@ impl KnowledgeBase {
             std::env::var("SAIREN_KB_WELL").unwrap_or_else(|_| "unknown".to_string())
         };
 
-        let config = KnowledgeBaseConfig {
+        Self::from_config(KnowledgeBaseConfig {
             root: std::path::PathBuf::from(root),
             field,
             well,
@@ -80,8 +86,17 @@ impl KnowledgeBase {
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(30),
-        };
+            full_snapshot_every: std::env::var("SAIREN_KB_FULL_SNAPSHOT_EVERY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(24),
+        })
+    }
 
+    /// Initialize from an explicit configuration.
+    ///
+    /// Runs initial assembly and ensures directory structure.
+    pub fn from_config(config: KnowledgeBaseConfig) -> Option<Self> {
         // Ensure directories exist
         if let Err(e) = config.ensure_dirs() {
             warn!(error = %e, "Failed to create knowledge base directories");
@@ -131,11 +146,16 @@ impl KnowledgeBase {
         guard.as_ref()?.formation_at_depth(depth_ft).cloned()
     }
 
+    /// Recommended drilling parameters at a depth, blending the prognosis
+    /// ranges toward the offset wells' best parameters (see
+    /// [`blend_formation_parameters`])
+    pub fn recommended_params_at_depth(&self, depth_ft: f64) -> Option<FormationParameters> {
+        blend_formation_parameters(&self.formation_at_depth(depth_ft)?)
+    }
+
     /// Write a mid-well snapshot from an ML insights report
     pub fn write_snapshot(&self, report: &MLInsightsReport) -> std::io::Result<()> {
-        mid_well::write_snapshot(&self.config, report)?;
-        mid_well::enforce_snapshot_cap(&self.config)?;
-        Ok(())
+        self.write_snapshot_with_packets(report, &[])
     }
 
     /// Write a mid-well snapshot with sustained-stats computed from raw packets
@@ -144,7 +164,18 @@ impl KnowledgeBase {
         report: &MLInsightsReport,
         packets: &[WitsPacket],
     ) -> std::io::Result<()> {
-        mid_well::write_snapshot_with_packets(&self.config, report, packets)?;
+        match mid_well::build_snapshot_with_packets(report, packets) {
+            Some(snapshot) => self.store_snapshot(&snapshot),
+            None => Ok(()),
+        }
+    }
+
+    /// Write an already-built mid-well snapshot (see
+    /// [`mid_well::build_snapshot_with_packets`]) and enforce the cap.
+    /// Stored as a delta against the previous snapshot between full ones.
+    /// Safe to retry: the snapshot file is keyed by its timestamp.
+    pub fn store_snapshot(&self, snapshot: &MidWellSnapshot) -> std::io::Result<()> {
+        mid_well::write_snapshot_delta(&self.config, snapshot)?;
         mid_well::enforce_snapshot_cap(&self.config)?;
         Ok(())
     }
@@ -170,3 +201,173 @@ impl KnowledgeBase {
         &self.config
     }
 }
+
+/// Blend a formation's planned parameters with its offset-well performance.
+///
+/// WOB and RPM optimals move toward the offset best parameters with weight
+/// `n / (n + OFFSET_PRIOR_WELLS)` for `n` offset wells, clamped to the
+/// planned min/max so offset data never widens the plan's limits. Flow, mud
+/// weight and bit type come from the prognosis. With only one source it is
+/// returned as-is (offset-only collapses the WOB/RPM ranges onto the best
+/// values); with neither, `None`.
+pub fn blend_formation_parameters(formation: &FormationInterval) -> Option<FormationParameters> {
+    let planned = &formation.parameters;
+    let offset = &formation.offset_performance;
+    let has_plan = planned.wob_klbs.optimal > 0.0 || planned.rpm.optimal > 0.0;
+    let has_offset = !offset.wells.is_empty()
+        && (offset.best_params.wob_klbs > 0.0 || offset.best_params.rpm > 0.0);
+
+    let weight = match (has_plan, has_offset) {
+        (false, false) => return None,
+        (true, false) => return Some(planned.clone()),
+        (false, true) => 1.0,
+        (true, true) => {
+            let n = offset.wells.len() as f64;
+            n / (n + OFFSET_PRIOR_WELLS)
+        }
+    };
+
+    let mut params = planned.clone();
+    params.wob_klbs = blend_range(&planned.wob_klbs, offset.best_params.wob_klbs, weight);
+    params.rpm = blend_range(&planned.rpm, offset.best_params.rpm, weight);
+    Some(params)
+}
+
+/// Move a range's optimal toward `best` by `weight`, clamped to the planned
+/// min/max. Unset values (≤ 0) on either side are skipped.
+fn blend_range(range: &ParameterRange, best: f64, weight: f64) -> ParameterRange {
+    if best <= 0.0 {
+        return range.clone();
+    }
+    if range.optimal <= 0.0 {
+        return ParameterRange {
+            min: best,
+            optimal: best,
+            max: best,
+        };
+    }
+    let mut optimal = (1.0 - weight) * range.optimal + weight * best;
+    if range.min > 0.0 {
+        optimal = optimal.max(range.min);
+    }
+    if range.max > 0.0 {
+        optimal = optimal.min(range.max);
+    }
+    ParameterRange {
+        optimal,
+        ..range.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BestParams, FormationPrognosis, OffsetPerformance, PrognosisWellInfo};
+
+    fn range(min: f64, optimal: f64, max: f64) -> ParameterRange {
+        ParameterRange { min, optimal, max }
+    }
+
+    fn make_formation(planned: bool, offset_wells: usize) -> FormationInterval {
+        let (wob, rpm, flow) = if planned {
+            (
+                range(15.0, 20.0, 30.0),
+                range(80.0, 100.0, 140.0),
+                range(400.0, 500.0, 600.0),
+            )
+        } else {
+            (
+                range(0.0, 0.0, 0.0),
+                range(0.0, 0.0, 0.0),
+                range(0.0, 0.0, 0.0),
+            )
+        };
+        FormationInterval {
+            name: "Hugin".into(),
+            depth_top_ft: 5000.0,
+            depth_base_ft: 6000.0,
+            lithology: "sandstone".into(),
+            hardness: 5.0,
+            drillability: "medium".into(),
+            pore_pressure_ppg: 10.0,
+            fracture_gradient_ppg: 14.0,
+            hazards: vec![],
+            parameters: FormationParameters {
+                wob_klbs: wob,
+                rpm,
+                flow_gpm: flow,
+                mud_weight_ppg: 12.0,
+                bit_type: "PDC".into(),
+            },
+            offset_performance: OffsetPerformance {
+                wells: (0..offset_wells).map(|i| format!("W-{i}")).collect(),
+                avg_rop_ft_hr: 60.0,
+                best_rop_ft_hr: 90.0,
+                avg_mse_psi: 20000.0,
+                best_params: BestParams {
+                    wob_klbs: 28.0,
+                    rpm: 160.0,
+                },
+                notes: String::new(),
+            },
+        }
+    }
+
+    fn make_kb(formation: FormationInterval) -> KnowledgeBase {
+        let prognosis = FormationPrognosis {
+            well: PrognosisWellInfo {
+                name: "Test-1".into(),
+                field: "Volve".into(),
+                spud_date: String::new(),
+                target_depth_ft: 8000.0,
+                coordinate_system: String::new(),
+            },
+            formations: vec![formation],
+            casings: vec![],
+            sidetracks: vec![],
+            mud_program: Vec::new(),
+        };
+        KnowledgeBase {
+            config: KnowledgeBaseConfig::default(),
+            prognosis: Arc::new(RwLock::new(Some(prognosis))),
+        }
+    }
+
+    #[test]
+    fn test_prognosis_only_returned_unchanged() {
+        let kb = make_kb(make_formation(true, 0));
+        let params = kb.recommended_params_at_depth(5500.0).expect("params");
+        assert_eq!(params.wob_klbs.optimal, 20.0);
+        assert_eq!(params.rpm.optimal, 100.0);
+        assert_eq!(params.rpm.max, 140.0);
+        assert!(kb.recommended_params_at_depth(7000.0).is_none());
+    }
+
+    #[test]
+    fn test_offset_only_uses_best_params() {
+        let kb = make_kb(make_formation(false, 2));
+        let params = kb.recommended_params_at_depth(5500.0).expect("params");
+        assert_eq!(params.wob_klbs.optimal, 28.0);
+        assert_eq!(params.wob_klbs.min, 28.0);
+        assert_eq!(params.rpm.optimal, 160.0);
+        // Nothing to blend for flow: stays as planned (unset)
+        assert_eq!(params.flow_gpm.optimal, 0.0);
+    }
+
+    #[test]
+    fn test_blend_weight_grows_with_offset_wells() {
+        // 3 offset wells vs prior of 3 → equal weight
+        let params = blend_formation_parameters(&make_formation(true, 3)).expect("params");
+        assert!((params.wob_klbs.optimal - 24.0).abs() < 1e-9);
+        assert!((params.rpm.optimal - 130.0).abs() < 1e-9);
+        assert_eq!(params.flow_gpm.optimal, 500.0);
+
+        // 9 offset wells → offset weight 0.75
+        let params = blend_formation_parameters(&make_formation(true, 9)).expect("params");
+        assert!((params.wob_klbs.optimal - (0.25 * 20.0 + 0.75 * 28.0)).abs() < 1e-9);
+        // Blended RPM (145) exceeds the planned max (140): clamped to the plan
+        assert_eq!(params.rpm.optimal, 140.0);
+        assert_eq!(params.rpm.max, 140.0);
+        assert_eq!(params.rpm.min, 80.0);
+    }
+}
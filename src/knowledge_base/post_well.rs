@@ -313,6 +313,7 @@ mod tests {
             depth_range: (1000.0, 2000.0),
             well_id: "Well-A".to_string(),
             field_name: "TestField".to_string(),
+            wellbore: None,
             bit_hours: 10.0,
             bit_depth: 500.0,
             formation_type: formation.to_string(),
@@ -0,0 +1,273 @@
+//! Retry and buffering for mid-well snapshot writes
+//!
+//! Each snapshot is one point of the mid-well data series, so a transient
+//! I/O error should not lose it. A failed write is retried with a doubling
+//! backoff; if it keeps failing (disk full, unmounted volume) the snapshot
+//! stays queued in memory and the queue is flushed, oldest first, on the
+//! next submission. The queue is bounded by `max_pending`.
+
+use std::collections::VecDeque;
+use std::io;
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::config::KbSnapshotConfig;
+use crate::types::MidWellSnapshot;
+
+/// Snapshot write health, shown under `components.kb_snapshots` on
+/// `GET /api/v2/system/health`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SnapshotWriteStatus {
+    /// Snapshots waiting to be written
+    pub pending: usize,
+    pub written: u64,
+    /// Writes that succeeded only after a retry
+    pub recovered: u64,
+    /// Snapshots dropped (queue full, or retry disabled)
+    pub dropped: u64,
+    /// Submissions in a row that left snapshots unwritten
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    /// Unix timestamp of the last failed write
+    pub last_failure_at: Option<u64>,
+}
+
+impl SnapshotWriteStatus {
+    /// True while snapshots are unwritten
+    pub fn is_failing(&self) -> bool {
+        self.pending > 0
+    }
+}
+
+/// Bounded queue of unwritten snapshots
+#[derive(Debug, Default)]
+pub struct SnapshotQueue {
+    pending: VecDeque<MidWellSnapshot>,
+    status: SnapshotWriteStatus,
+}
+
+impl SnapshotQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn status(&self) -> &SnapshotWriteStatus {
+        &self.status
+    }
+
+    /// Queue a snapshot and write everything pending, oldest first, via
+    /// `write`. Stops at the first snapshot that still fails after retries;
+    /// it and anything newer stay queued.
+    pub async fn submit<F>(
+        &mut self,
+        snapshot: MidWellSnapshot,
+        cfg: &KbSnapshotConfig,
+        mut write: F,
+    ) where
+        F: FnMut(&MidWellSnapshot) -> io::Result<()>,
+    {
+        if !cfg.enabled {
+            if let Err(e) = write(&snapshot) {
+                warn!(timestamp = snapshot.timestamp, error = %e, "Failed to write KB snapshot");
+                self.record_failure(&e);
+                self.status.dropped += 1;
+            } else {
+                self.status.written += 1;
+                self.status.consecutive_failures = 0;
+            }
+            return;
+        }
+
+        self.pending.push_back(snapshot);
+        while self.pending.len() > cfg.max_pending.max(1) {
+            if let Some(dropped) = self.pending.pop_front() {
+                warn!(
+                    timestamp = dropped.timestamp,
+                    max_pending = cfg.max_pending,
+                    "KB snapshot queue full — dropping oldest unwritten snapshot"
+                );
+                self.status.dropped += 1;
+            }
+        }
+
+        let backlog = self.pending.len();
+        while let Some(front) = self.pending.front() {
+            match write_with_retry(front, cfg, &mut write).await {
+                Ok(retries) => {
+                    self.status.written += 1;
+                    if retries > 0 {
+                        self.status.recovered += 1;
+                    }
+                    self.pending.pop_front();
+                }
+                Err(e) => {
+                    warn!(
+                        timestamp = front.timestamp,
+                        pending = self.pending.len(),
+                        error = %e,
+                        "KB snapshot write failed after retries — keeping it queued"
+                    );
+                    self.record_failure(&e);
+                    self.status.pending = self.pending.len();
+                    return;
+                }
+            }
+        }
+
+        if backlog > 1 {
+            info!(flushed = backlog, "KB snapshot queue flushed");
+        }
+        self.status.consecutive_failures = 0;
+        self.status.pending = 0;
+    }
+
+    fn record_failure(&mut self, error: &io::Error) {
+        self.status.consecutive_failures += 1;
+        self.status.last_error = Some(error.to_string());
+        self.status.last_failure_at = Some(chrono::Utc::now().timestamp().max(0) as u64);
+    }
+}
+
+/// One write plus up to `retry_attempts` retries with doubling backoff.
+/// Returns the number of retries used.
+async fn write_with_retry<F>(
+    snapshot: &MidWellSnapshot,
+    cfg: &KbSnapshotConfig,
+    write: &mut F,
+) -> io::Result<u32>
+where
+    F: FnMut(&MidWellSnapshot) -> io::Result<()>,
+{
+    let mut backoff = Duration::from_millis(cfg.retry_backoff_ms);
+    let mut retries = 0;
+    loop {
+        match write(snapshot) {
+            Ok(()) => return Ok(retries),
+            Err(e) if retries >= cfg.retry_attempts => return Err(e),
+            Err(e) => {
+                warn!(
+                    timestamp = snapshot.timestamp,
+                    retry = retries + 1,
+                    backoff_ms = backoff.as_millis() as u64,
+                    error = %e,
+                    "KB snapshot write failed — retrying"
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                retries += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knowledge_base::{mid_well, KnowledgeBase};
+    use crate::types::{
+        AnalysisInsights, AnalysisResult, Campaign, ConfidenceLevel, KnowledgeBaseConfig,
+        MLInsightsReport, OptimalParams,
+    };
+
+    fn snapshot(ts: u64) -> MidWellSnapshot {
+        let report = MLInsightsReport {
+            timestamp: ts,
+            campaign: Campaign::Production,
+            depth_range: (1000.0, 2000.0),
+            well_id: "Well-A".to_string(),
+            field_name: "TestField".to_string(),
+            wellbore: None,
+            bit_hours: 10.0,
+            bit_depth: 500.0,
+            formation_type: "Shallow".to_string(),
+            result: AnalysisResult::Success(AnalysisInsights {
+                optimal_params: OptimalParams::default(),
+                correlations: Vec::new(),
+                summary_text: "test".to_string(),
+                confidence: ConfidenceLevel::Medium,
+                sample_count: 1000,
+            }),
+        };
+        mid_well::build_snapshot_with_packets(&report, &[]).expect("snapshot")
+    }
+
+    fn cfg() -> KbSnapshotConfig {
+        KbSnapshotConfig {
+            retry_backoff_ms: 1,
+            ..KbSnapshotConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transient_write_failure_is_retried_and_snapshot_kept() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let kb = KnowledgeBase::from_config(KnowledgeBaseConfig {
+            root: tmp.path().to_path_buf(),
+            field: "TestField".to_string(),
+            well: "Well-A".to_string(),
+            ..Default::default()
+        })
+        .expect("kb");
+
+        // First two attempts hit a transient I/O error
+        let mut attempts = 0;
+        let mut queue = SnapshotQueue::new();
+        queue
+            .submit(snapshot(1_700_000_000), &cfg(), |s| {
+                attempts += 1;
+                if attempts <= 2 {
+                    return Err(io::Error::other("resource temporarily unavailable"));
+                }
+                kb.store_snapshot(s)
+            })
+            .await;
+
+        assert_eq!(attempts, 3);
+        let status = queue.status();
+        assert_eq!(
+            (status.written, status.recovered, status.pending),
+            (1, 1, 0)
+        );
+        assert!(!status.is_failing());
+
+        let stored = mid_well::load_all_snapshots(kb.config()).expect("load");
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].timestamp, 1_700_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_persistent_failure_queues_then_flushes_in_order() {
+        let mut queue = SnapshotQueue::new();
+        let cfg = KbSnapshotConfig {
+            max_pending: 2,
+            ..cfg()
+        };
+        let disk_full = |_: &MidWellSnapshot| Err(io::Error::other("No space left on device"));
+
+        queue.submit(snapshot(100), &cfg, disk_full).await;
+        queue.submit(snapshot(200), &cfg, disk_full).await;
+        queue.submit(snapshot(300), &cfg, disk_full).await;
+        let status = queue.status();
+        assert!(status.is_failing());
+        assert_eq!((status.pending, status.dropped), (2, 1));
+        assert_eq!(status.consecutive_failures, 3);
+        assert!(status
+            .last_error
+            .as_deref()
+            .is_some_and(|e| e.contains("No space")));
+
+        // Disk freed: the backlog is written oldest first, then the new one
+        let mut written = Vec::new();
+        queue
+            .submit(snapshot(400), &cfg, |s| {
+                written.push(s.timestamp);
+                Ok(())
+            })
+            .await;
+        assert_eq!(written, vec![300, 400]);
+        assert_eq!(queue.status().pending, 0);
+        assert_eq!(queue.status().consecutive_failures, 0);
+    }
+}
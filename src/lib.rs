@@ -21,9 +21,12 @@ pub mod types;
 // available in the binary crate which declares its own `mod storage;`.
 pub mod storage {
     pub mod acks;
+    pub mod bit_runs;
     pub mod damping_recipes;
+    pub mod export;
     pub mod feedback;
     pub mod history;
+    pub mod rig_state_log;
     pub mod strategic;
     pub mod suggestions;
     pub use strategic::StrategicStorage;
@@ -54,7 +54,9 @@ pub mod volve;
 use api::{create_app, DashboardState};
 use axum::Router;
 use pipeline::processing_loop::{PostProcessHooks, ProcessingLoop};
-use pipeline::source::{CsvSource, PacketSource, StdinSource, TcpSource};
+use pipeline::source::{
+    CsvSource, PacketSource, ReplayEofBehavior, StdinSource, StreamingCsvSource, TcpSource,
+};
 use pipeline::{AppState, PipelineCoordinator};
 
 // ============================================================================
@@ -84,15 +86,27 @@ struct CliArgs {
     #[arg(long)]
     csv: Option<String>,
 
+    /// Only replay the Volve CSV interval MIN:MAX (bit depth, ft, inclusive)
+    /// Example: ./sairen-os --csv well.csv --depth-window 3200:3600
+    #[arg(long, value_name = "MIN_FT:MAX_FT", requires = "csv")]
+    depth_window: Option<String>,
+
+    /// Stream the CSV row by row instead of loading it into memory (for
+    /// multi-GB exports; Volve gap interpolation and filters are skipped)
+    #[arg(long, requires = "csv", conflicts_with = "depth_window")]
+    stream: bool,
+
     /// Speed multiplier for simulation (1 = realtime, 60 = 60x faster, 0 = no delay)
     #[arg(long, default_value = "1")]
     speed: u64,
 
-    /// Reset all persistent data (databases, ML insights, thresholds) on startup.
+    /// Reset persistent data on startup. SCOPE is `all` (default: databases,
+    /// ML insights, learned baselines), `reports`, `ml`, or `keep-baselines`
+    /// (everything except baseline_state.json).
     /// WARNING: This is destructive and cannot be undone!
-    /// Can also be set via RESET_DB=true environment variable.
-    #[arg(long)]
-    reset_db: bool,
+    /// Can also be set via RESET_DB=true (or RESET_DB=<scope>).
+    #[arg(long, value_name = "SCOPE", num_args = 0..=1, default_missing_value = "all")]
+    reset_db: Option<storage::reset::ResetScope>,
 
     #[command(subcommand)]
     command: Option<SubCommand>,
@@ -111,6 +125,9 @@ enum SubCommand {
         /// Path to the knowledge base root directory
         #[arg(long = "to")]
         to: String,
+        /// Print the planned writes (create / overwrite / skip) without touching disk
+        #[arg(long = "dry-run")]
+        dry_run: bool,
     },
 
     /// Launch the setup wizard (web UI on port 8080)
@@ -134,22 +151,50 @@ enum SubCommand {
 /// Default data directory path
 const DATA_DIR: &str = "./data";
 
-/// Check if database reset is requested via CLI flag or environment variable.
-fn should_reset_db(cli_flag: bool) -> bool {
-    if cli_flag {
+/// Database reset scope requested via CLI flag or `RESET_DB`, if any.
+fn reset_db_scope(
+    cli_scope: Option<storage::reset::ResetScope>,
+) -> Option<storage::reset::ResetScope> {
+    if cli_scope.is_some() {
+        return cli_scope;
+    }
+    let val = std::env::var("RESET_DB").ok()?;
+    let scope = storage::reset::ResetScope::from_env_value(&val);
+    if scope.is_none() && !matches!(val.to_lowercase().as_str(), "" | "false" | "0" | "no") {
+        warn!(
+            "RESET_DB={} not recognised (expected true, all, reports, ml or keep-baselines); \
+             not resetting",
+            val
+        );
+    }
+    scope
+}
+
+/// Ask for confirmation on an interactive terminal. Non-interactive starts
+/// (services, piped input) proceed, as before.
+fn confirm_full_reset() -> bool {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    if !std::io::stdin().is_terminal() {
         return true;
     }
-    if let Ok(val) = std::env::var("RESET_DB") {
-        let val_lower = val.to_lowercase();
-        return val_lower == "true" || val_lower == "1" || val_lower == "yes";
+    print!(
+        "--reset-db=all also deletes learned baselines (use --reset-db=keep-baselines to keep them).\n\
+         Type 'yes' to wipe {}: ",
+        DATA_DIR
+    );
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
     }
-    false
+    answer.trim().eq_ignore_ascii_case("yes")
 }
 
-/// Safely remove the data directory and all its contents.
-fn reset_data_directory() -> Result<()> {
-    use std::fs;
+/// Remove the parts of the data directory covered by `scope`.
+fn reset_data_directory(scope: storage::reset::ResetScope) -> Result<()> {
     use std::path::Path;
+    use storage::reset::{apply_reset, plan_reset, ResetScope};
 
     let data_path = Path::new(DATA_DIR);
 
@@ -158,25 +203,39 @@ fn reset_data_directory() -> Result<()> {
         return Ok(());
     }
 
+    let plan = plan_reset(data_path, scope).context("Failed to read data directory")?;
+
     warn!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    warn!("  RESET_DB DETECTED - WIPING ALL PERSISTENT DATA");
+    if scope == ResetScope::All {
+        warn!("  RESET_DB DETECTED - WIPING ALL PERSISTENT DATA (including learned baselines)");
+    } else {
+        warn!("  RESET_DB DETECTED - RESET SCOPE: {}", scope.as_str());
+    }
     warn!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     warn!("");
-    warn!("  Removing: {}", data_path.display());
-
-    if let Ok(entries) = fs::read_dir(data_path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            let file_type = if path.is_dir() { "DIR " } else { "FILE" };
-            warn!("    {} {}", file_type, path.display());
+    warn!("  Removing:");
+    for path in &plan.remove {
+        let file_type = if path.is_dir() { "DIR " } else { "FILE" };
+        warn!("    {} {}", file_type, path.display());
+    }
+    if !plan.keep.is_empty() {
+        warn!("  Keeping:");
+        for path in &plan.keep {
+            warn!("    {}", path.display());
         }
     }
 
-    fs::remove_dir_all(data_path).context("Failed to remove data directory")?;
+    if scope == ResetScope::All && !confirm_full_reset() {
+        warn!("  Reset cancelled — data directory left untouched.");
+        warn!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        return Ok(());
+    }
+
+    apply_reset(data_path, scope, &plan).context("Failed to reset data directory")?;
 
     warn!("");
-    warn!("  Data directory removed successfully.");
-    warn!("  A fresh database will be created on startup.");
+    warn!("  Reset complete ({} entries removed).", plan.remove.len());
+    warn!("  Removed stores will be recreated on startup.");
     warn!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     warn!("");
 
@@ -220,6 +279,8 @@ struct PipelineCore {
     coordinator: PipelineCoordinator,
     listener: tokio::net::TcpListener,
     app: Router,
+    /// Live event bus shared by the processing loop and `/api/v2/stream`.
+    live_events: pipeline::live_events::LiveEventBus,
     equipment_id: String,
     /// Gossip event store (shared with server handlers and client loop).
     gossip_store: Option<Arc<tokio::sync::Mutex<gossip::store::EventStore>>>,
@@ -261,6 +322,24 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
             Ok(()) => info!("✓ Feedback storage initialized"),
         }
 
+        // Initialise bit-run archive for post-run dull grades.
+        match storage::bit_runs::init() {
+            Err(e) => warn!("Failed to init bit-run store: {}", e),
+            Ok(()) => info!("✓ Bit-run storage initialized"),
+        }
+
+        // Initialise the rig state log and restore it for debrief NPT accounting.
+        match storage::rig_state_log::init() {
+            Err(e) => warn!("Failed to init rig state log store: {}", e),
+            Ok(()) => {
+                let log = storage::rig_state_log::load();
+                if !log.changes.is_empty() {
+                    info!(count = log.changes.len(), "Restored rig state log from disk");
+                    app_state.write().await.rig_state_log = log;
+                }
+            }
+        }
+
         // Initialise damping recipes tree for formation-specific recipe persistence.
         match storage::damping_recipes::init() {
             Err(e) => warn!("Failed to init damping recipes store: {}", e),
@@ -297,14 +376,24 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
     );
 
     let thresholds_path = Path::new(baseline::DEFAULT_STATE_PATH);
+    let persist_accumulators = config::get().baseline_learning.persist_accumulators;
     let threshold_manager = Arc::new(std::sync::RwLock::new({
-        match ThresholdManager::load_from_file(thresholds_path) {
-            Some(mgr) => {
+        let loaded = ThresholdManager::load_from_file(thresholds_path, persist_accumulators);
+        let mut mgr = match loaded {
+            Some(mut mgr) => {
                 let locked_count = mgr.locked_count();
                 info!(
                     "✓ Loaded {} locked baselines from {:?}",
                     locked_count, thresholds_path
                 );
+                if mgr.learning_count() > 0 {
+                    // Resumed mid-learning: fill in any metric that had no samples yet
+                    mgr.start_wits_learning(equipment_id, 0);
+                    info!(
+                        "   Resumed learning for {} WITS drilling metrics",
+                        mgr.learning_count()
+                    );
+                }
                 mgr
             }
             None => {
@@ -314,7 +403,14 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
                 info!("   Started learning for WITS drilling metrics");
                 mgr
             }
-        }
+        };
+        mgr.set_persist_accumulators(persist_accumulators);
+        let bl = &config::get().baseline_learning;
+        mgr.set_drift_mode(baseline::DriftMode::from_alpha(
+            bl.drift_alpha,
+            bl.drift_widen_std,
+        ));
+        mgr
     }));
 
     let start_in_learning_mode = {
@@ -331,11 +427,20 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
         }
     };
 
-    let coordinator = PipelineCoordinator::new_with_thresholds(
+    let mut coordinator = PipelineCoordinator::new_with_thresholds(
         threshold_manager.clone(),
         equipment_id.to_string(),
         start_in_learning_mode,
     );
+    if let Some(run) = storage::bit_runs::load_active_run() {
+        info!(
+            "✓ Resumed bit run: {:.1} bit hours, baseline {}/{} samples",
+            run.bit_hours,
+            run.samples,
+            physics_engine::bit_run::BIT_RUN_BASELINE_SAMPLES
+        );
+        coordinator.restore_bit_run(run);
+    }
 
     if let Some(handle) = coordinator.start_kb_watcher() {
         info!("✓ Knowledge base watcher started");
@@ -366,6 +471,7 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
         Err(e) => warn!("Failed to open ML insights storage for dashboard: {}", e),
     }
 
+    let live_events = dashboard_state.live_events.clone();
     let mut app = create_app(dashboard_state);
 
     // Initialize gossip store and mesh routes if mesh is enabled
@@ -376,6 +482,8 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
         let store_path = std::path::Path::new("./data/gossip_events.db");
         match gossip::store::EventStore::open(store_path) {
             Ok(store) => {
+                let store = store.with_max_bytes(config::get().gossip.store_max_bytes);
+                app_state.write().await.mesh_evictions = Some(store.eviction_log());
                 let store = Arc::new(tokio::sync::Mutex::new(store));
                 let sled_db =
                     sled::open("./data/mesh_state").context("Failed to open mesh state sled DB")?;
@@ -425,6 +533,7 @@ async fn init_pipeline(equipment_id: &str, server_addr: &str) -> Result<Pipeline
         coordinator,
         listener,
         app,
+        live_events,
         equipment_id: equipment_id.to_string(),
         gossip_store,
         mesh_state,
@@ -471,6 +580,7 @@ fn spawn_ml_scheduler(
         use ml_engine::{MLScheduler, get_interval};
 
         let ml_knowledge_base = knowledge_base::KnowledgeBase::init();
+        let mut snapshot_queue = knowledge_base::snapshot_queue::SnapshotQueue::new();
 
         info!("[MLScheduler] Task starting with interval {:?}", get_interval());
 
@@ -484,13 +594,14 @@ fn spawn_ml_scheduler(
                     return Ok(TaskName::MLScheduler);
                 }
                 _ = interval.tick() => {
-                    let (packets, campaign, well_id, field_name, bit_hours, bit_depth, cfc_transition_timestamps, regime_centroids) = {
+                    let (packets, campaign, well_id, field_name, wellbore, bit_hours, bit_depth, cfc_transition_timestamps, regime_centroids) = {
                         let state = app_state.read().await;
                         (
                             state.wits_history.iter().cloned().collect::<Vec<_>>(),
                             state.campaign,
                             state.well_id.clone(),
                             state.field_name.clone(),
+                            state.active_wellbore.as_ref().map(|w| w.branch.clone()),
                             state.bit_hours,
                             state.bit_depth_drilled,
                             state.formation_transition_timestamps.clone(),
@@ -498,21 +609,18 @@ fn spawn_ml_scheduler(
                         )
                     };
 
-                    // Apply ROP lag compensation
-                    let rop_lag = config::get().ml.rop_lag_seconds as usize;
+                    // Apply per-series lag compensation (ROP, gas, return flow)
+                    let lags = ml_engine::LagConfig::from_config(&config::get().ml);
+                    let max_lag = lags.max_lag();
                     let mut packets = packets;
-                    if rop_lag > 0 && packets.len() > rop_lag {
-                        for i in 0..packets.len() - rop_lag {
-                            packets[i].rop = packets[i + rop_lag].rop;
-                        }
-                        packets.truncate(packets.len() - rop_lag);
-                    } else if rop_lag > 0 && packets.len() <= rop_lag {
+                    if max_lag > 0 && packets.len() <= max_lag {
                         info!(
-                            "[MLScheduler] Skipping analysis: insufficient data for ROP lag ({} packets, need > {})",
-                            packets.len(), rop_lag
+                            "[MLScheduler] Skipping analysis: insufficient data for lag compensation ({} packets, need > {})",
+                            packets.len(), max_lag
                         );
                         continue;
                     }
+                    MLScheduler::apply_lag_compensation(&mut packets, &lags);
 
                     if packets.len() < config::defaults::MIN_PACKETS_FOR_ML_ANALYSIS {
                         info!(
@@ -536,14 +644,14 @@ fn spawn_ml_scheduler(
                         //      instead of using the per-packet fracture_gradient field.
                         //   3. flow_balance sign was inverted (flow_in - flow_out)
                         //      vs the rest of the codebase (flow_out - flow_in).
-                        let mut m = physics_engine::tactical_update(p, None, None);
+                        let mut m = physics_engine::tactical_update(p, None, None, None);
                         // tactical_update leaves operation as default; set it from the
                         // same campaign-aware classifier the tactical agent uses.
                         m.operation = agents::tactical::detect_operation(p, campaign);
                         m
                     }).collect();
 
-                    let dataset = MLScheduler::build_dataset(
+                    let mut dataset = MLScheduler::build_dataset(
                         packets,
                         metrics,
                         &well_id,
@@ -554,6 +662,7 @@ fn spawn_ml_scheduler(
                         &cfc_transition_timestamps,
                         regime_centroids,
                     );
+                    dataset.wellbore = wellbore;
 
                     let report = MLScheduler::run_analysis(&dataset);
 
@@ -563,8 +672,11 @@ fn spawn_ml_scheduler(
                     }
 
                     if let Some(ref kb) = ml_knowledge_base {
-                        if let Err(e) = kb.write_snapshot_with_packets(&report, &snapshot_packets) {
-                            warn!("Failed to write KB snapshot: {}", e);
+                        if let Some(snapshot) = knowledge_base::mid_well::build_snapshot_with_packets(&report, &snapshot_packets) {
+                            snapshot_queue
+                                .submit(snapshot, &config::get().kb_snapshots, |s| kb.store_snapshot(s))
+                                .await;
+                            app_state.write().await.kb_snapshot_status = Some(snapshot_queue.status().clone());
                         }
                     }
 
@@ -689,13 +801,15 @@ async fn run_supervisor(
 /// All input modes (CSV, stdin, TCP) flow through this function.
 /// The `hooks` parameter provides mode-specific per-packet processing
 /// (e.g. regime stamping for TCP). The `spawn_ml` flag controls
-/// whether the ML scheduler task is started.
+/// whether the ML scheduler task is started. `injected_faults` (synthetic
+/// data only) enables the detection latency benchmark.
 async fn run_pipeline<S: PacketSource, H: PostProcessHooks>(
     mut source: S,
     hooks: H,
     equipment_id: &str,
     server_addr: String,
     spawn_ml: bool,
+    injected_faults: Vec<sensors::InjectedFault>,
     cancel_token: CancellationToken,
 ) -> Result<()> {
     info!("🚀 Starting SAIREN-OS Drilling Intelligence Pipeline");
@@ -706,7 +820,10 @@ async fn run_pipeline<S: PacketSource, H: PostProcessHooks>(
     info!("   Phase 4: History Buffer (60 packets)");
     info!("   Phase 5: Strategic Agent -> verify_ticket()");
     info!("   Phase 6: Context Lookup");
-    info!("   Phase 7: Orchestrator Voting (4 Specialists)");
+    info!(
+        "   Phase 7: Orchestrator Voting ({})",
+        agents::orchestrator::weighting_summary(&config::get())
+    );
     info!("   Phase 8: Storage");
     info!("   Phase 9: Dashboard API");
     info!("");
@@ -714,6 +831,16 @@ async fn run_pipeline<S: PacketSource, H: PostProcessHooks>(
     let core = init_pipeline(equipment_id, &server_addr).await?;
     let app_state = core.app_state;
 
+    if !injected_faults.is_empty() && config::get().detection_benchmark.enabled {
+        info!(
+            "🎯 Detection benchmark: timing advisories against {} injected faults",
+            injected_faults.len()
+        );
+        app_state.write().await.detection_benchmark = Some(
+            pipeline::detection_benchmark::DetectionBenchmark::new(injected_faults),
+        );
+    }
+
     info!("🔒 Supervisor: Initializing task monitoring");
     let mut task_set: JoinSet<Result<TaskName>> = JoinSet::new();
 
@@ -726,7 +853,8 @@ async fn run_pipeline<S: PacketSource, H: PostProcessHooks>(
     task_set.spawn(async move {
         info!("[PacketProcessor] Task starting");
 
-        let processing_loop = ProcessingLoop::new(core.coordinator, proc_state, hooks, proc_cancel);
+        let processing_loop = ProcessingLoop::new(core.coordinator, proc_state, hooks, proc_cancel)
+            .with_live_events(core.live_events);
 
         let _stats = processing_loop.run(&mut source).await;
         Ok(TaskName::PacketProcessor)
@@ -767,10 +895,23 @@ async fn run_pipeline<S: PacketSource, H: PostProcessHooks>(
 // ============================================================================
 
 /// Load WITS packets from CSV file or generate synthetic test data.
-fn load_packets(csv_path: Option<String>) -> Result<Vec<types::WitsPacket>> {
+///
+/// Synthetic data also returns the faults injected into it (empty for CSV).
+fn load_packets(
+    csv_path: Option<String>,
+    depth_window: Option<&str>,
+) -> Result<(Vec<types::WitsPacket>, Vec<sensors::InjectedFault>)> {
     if let Some(path) = csv_path {
         info!("📂 Loading WITS data from CSV: {}", path);
-        let data = match volve::VolveReplay::load(&path, volve::VolveConfig::default()) {
+        let depth_window = depth_window
+            .map(volve::parse_depth_window)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let volve_config = volve::VolveConfig {
+            depth_window,
+            ..Default::default()
+        };
+        let data = match volve::VolveReplay::load(&path, volve_config) {
             Ok(replay) => {
                 info!(
                     "   Detected Volve WITSML format: {} ({} packets, {:.0}-{:.0} ft)",
@@ -781,21 +922,44 @@ fn load_packets(csv_path: Option<String>) -> Result<Vec<types::WitsPacket>> {
                 );
                 replay.into_packets()
             }
+            // Windowing needs the Volve parser; don't silently replay everything
+            Err(e) if depth_window.is_some() => return Err(anyhow::anyhow!(e)),
             Err(_) => sensors::read_csv_data(&path),
         };
         if data.is_empty() {
             return Err(anyhow::anyhow!("No WITS data loaded from CSV"));
         }
         info!("   Loaded {} packets", data.len());
-        Ok(data)
+        Ok((data, Vec::new()))
     } else {
         info!("🧪 Using synthetic test data (drilling fault simulation)");
-        let data = sensors::generate_fault_test_data();
-        info!("   Generated {} packets", data.len());
-        Ok(data)
+        let scenario = sensors::generate_fault_scenario();
+        info!(
+            "   Generated {} packets ({} injected faults)",
+            scenario.packets.len(),
+            scenario.faults.len()
+        );
+        Ok((scenario.packets, scenario.faults))
     }
 }
 
+/// Read the first `count` packets of a CSV for auto-detection without
+/// loading the rest of the file.
+async fn peek_streamed_packets(csv_path: &str, count: usize) -> Result<Vec<types::WitsPacket>> {
+    let mut source = StreamingCsvSource::open(csv_path, 0).await?;
+    let mut packets = Vec::with_capacity(count);
+    while packets.len() < count {
+        match source.next_row().await? {
+            Some(packet) => packets.push(packet),
+            None => break,
+        }
+    }
+    if packets.is_empty() {
+        return Err(anyhow::anyhow!("No WITS data in CSV"));
+    }
+    Ok(packets)
+}
+
 // ============================================================================
 // Generate Config Template
 // ============================================================================
@@ -824,7 +988,7 @@ addr = "0.0.0.0:8080"
 
 [thresholds.hydraulics]
 normal_mud_weight_ppg = 8.6      # auto-detected from WITS if not set
-fracture_gradient_ppg = 14.0
+fracture_gradient_ppg = 14.0     # auto-detected (mud weight + offset) if not set and auto_pore_fracture = true
 
 [thresholds.well_control]
 flow_imbalance_warning_gpm = 10.0   # auto-detected from baseline if not set
@@ -898,6 +1062,8 @@ flow_imbalance_critical_gpm = 20.0
 
 # [ml]
 # rop_lag_seconds = 60
+# gas_lag_seconds = 0
+# flow_lag_seconds = 0
 # interval_secs = 3600
 "#
     );
@@ -969,12 +1135,19 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
-    if let Some(SubCommand::MigrateKb { from, to }) = &args.command {
+    if let Some(SubCommand::MigrateKb { from, to, dry_run }) = &args.command {
         let from_path = std::path::Path::new(from);
         let to_path = std::path::Path::new(to);
         info!("Migrating knowledge base: {} -> {}", from, to);
-        knowledge_base::migration::migrate_flat_to_kb(from_path, to_path)?;
-        info!("Knowledge base migration complete");
+        let plan = knowledge_base::migration::migrate_flat_to_kb(from_path, to_path, *dry_run)?;
+        if *dry_run {
+            println!("Dry run — no files written. Planned writes:");
+            for write in &plan {
+                println!("  {:<9} {}", write.action, write.path.display());
+            }
+        } else {
+            info!("Knowledge base migration complete ({} files)", plan.len());
+        }
         return Ok(());
     }
 
@@ -989,38 +1162,40 @@ async fn main() -> Result<()> {
     }
 
     // Reset DB check — BEFORE any storage initialization
-    if should_reset_db(args.reset_db) {
-        reset_data_directory()?;
+    if let Some(scope) = reset_db_scope(args.reset_db) {
+        reset_data_directory(scope)?;
     }
 
     // Load well configuration with provenance tracking
     let (mut well_config, provenance) = config::WellConfig::load_with_provenance();
 
     // Pre-init auto-detection: infer config values from WITS data before freezing config.
-    // For CSV mode: peek at first 30 packets from the CSV file.
+    // For CSV mode: peek at the first packets from the CSV file.
     // For other modes: restore from cached auto-detected values from a previous run.
+    // When streaming, only the peeked packets are read here.
     let preloaded_packets = if let Some(ref csv_path) = args.csv {
-        match load_packets(Some(csv_path.clone())) {
-            Ok(packets) => {
+        let peeked = if args.stream {
+            peek_streamed_packets(csv_path, config::auto_detect::PEEK_PACKETS)
+                .await
+                .map(|p| (p, Vec::new()))
+        } else {
+            load_packets(Some(csv_path.clone()), args.depth_window.as_deref())
+        };
+        match peeked {
+            Ok((packets, _)) => {
                 // Auto-detect from first N packets
-                let mut detector = config::auto_detect::AutoDetector::new();
-                let peek_count = packets.len().min(30);
+                let hydraulics = &well_config.thresholds.hydraulics;
+                let mut detector = config::auto_detect::AutoDetector::new()
+                    .with_pore_fracture_estimate(hydraulics.auto_pore_fracture)
+                    .with_fracture_offset(hydraulics.auto_fracture_offset_ppg);
+                let peek_count = packets.len().min(config::auto_detect::PEEK_PACKETS);
                 for packet in &packets[..peek_count] {
                     detector.observe(packet);
                 }
                 if detector.ready() {
-                    let detected = detector.detect();
-                    if let Some(mw) = detected.normal_mud_weight_ppg {
-                        if !provenance.is_user_set("thresholds.hydraulics.normal_mud_weight_ppg") {
-                            info!("Auto-detected mud weight: {:.1} ppg (from WITS stream)", mw);
-                            well_config.thresholds.hydraulics.normal_mud_weight_ppg = mw;
-                        } else {
-                            info!(
-                                "Mud weight: {:.1} ppg (user-configured, ignoring auto-detected {:.1})",
-                                well_config.thresholds.hydraulics.normal_mud_weight_ppg, mw
-                            );
-                        }
-                    }
+                    let mut detected = detector.detect();
+                    detected.well = well_config.well.name.clone();
+                    detected.apply_to(&mut well_config, &provenance, "WITS stream");
                     // Cache auto-detected values for next restart
                     if let Err(e) = detected.save() {
                         warn!("Failed to cache auto-detected values: {}", e);
@@ -1035,16 +1210,10 @@ async fn main() -> Result<()> {
         }
     } else {
         // For non-CSV modes, try loading cached auto-detected values
-        if let Some(cached) = config::auto_detect::AutoDetectedValues::load_cached() {
-            if let Some(mw) = cached.normal_mud_weight_ppg {
-                if !provenance.is_user_set("thresholds.hydraulics.normal_mud_weight_ppg") {
-                    info!(
-                        "Restored auto-detected mud weight: {:.1} ppg (from cache)",
-                        mw
-                    );
-                    well_config.thresholds.hydraulics.normal_mud_weight_ppg = mw;
-                }
-            }
+        if let Some(cached) =
+            config::auto_detect::AutoDetectedValues::load_cached(&well_config.well.name)
+        {
+            cached.apply_to(&mut well_config, &provenance, "cache");
         }
         None
     };
@@ -1153,8 +1322,26 @@ async fn main() -> Result<()> {
         let host = parts[0];
 
         info!("📥 Input: WITS TCP (Level 0 protocol from {})", addr);
-        let source = TcpSource::connect(host, port).await?;
-        run_pipeline(source, (), "WITS-TCP", server_addr, true, cancel_token).await?;
+        let source = TcpSource::connect(host, port)
+            .await?
+            .with_reconnect(&config::get().acquisition)
+            .with_cancel_token(cancel_token.clone());
+        let reconnects = source.reconnect_counter();
+        let result = run_pipeline(
+            source,
+            (),
+            "WITS-TCP",
+            server_addr,
+            true,
+            Vec::new(),
+            cancel_token,
+        )
+        .await;
+        info!(
+            "🔒 Supervisor: WITS link reconnected {} time(s)",
+            reconnects.load(std::sync::atomic::Ordering::Relaxed)
+        );
+        result?;
     } else if args.stdin {
         // --- Stdin mode ---
         info!("📥 Input: stdin (JSON WITS packets from simulation)");
@@ -1164,16 +1351,12 @@ async fn main() -> Result<()> {
             "WITS",
             server_addr,
             false,
+            Vec::new(),
             cancel_token,
         )
         .await?;
     } else {
         // --- CSV / synthetic mode ---
-        // Reuse preloaded packets from auto-detection if available, otherwise load fresh
-        let packets = match preloaded_packets {
-            Some(p) => p,
-            None => load_packets(args.csv)?,
-        };
         let delay_ms = if args.speed == 0 {
             0
         } else {
@@ -1188,10 +1371,47 @@ async fn main() -> Result<()> {
             },
             delay_ms
         );
-        let total = packets.len();
-        info!("📊 {} WITS packets queued for processing", total);
-        let source = CsvSource::new(packets, delay_ms);
-        run_pipeline(source, (), "Volve", server_addr, false, cancel_token).await?;
+        let eof_behavior = ReplayEofBehavior::from_config(&config::get().replay.eof_behavior);
+        info!("⏹️  End of replay: {:?}", eof_behavior);
+        if let (true, Some(csv_path)) = (args.stream, args.csv.as_deref()) {
+            let source = StreamingCsvSource::open(csv_path, delay_ms)
+                .await?
+                .with_eof_behavior(eof_behavior);
+            info!(
+                "📂 Streaming WITS data from CSV: {} ({} format)",
+                csv_path,
+                source.format_name()
+            );
+            run_pipeline(
+                source,
+                (),
+                "Volve",
+                server_addr,
+                false,
+                Vec::new(),
+                cancel_token,
+            )
+            .await?;
+        } else {
+            // Reuse preloaded packets from auto-detection if available, otherwise load fresh
+            let (packets, injected_faults) = match preloaded_packets {
+                Some(p) => (p, Vec::new()),
+                None => load_packets(args.csv, args.depth_window.as_deref())?,
+            };
+            let total = packets.len();
+            info!("📊 {} WITS packets queued for processing", total);
+            let source = CsvSource::new(packets, delay_ms).with_eof_behavior(eof_behavior);
+            run_pipeline(
+                source,
+                (),
+                "Volve",
+                server_addr,
+                false,
+                injected_faults,
+                cancel_token,
+            )
+            .await?;
+        }
     }
 
     info!("");
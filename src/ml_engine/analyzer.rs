@@ -169,6 +169,7 @@ impl HourlyAnalyzer {
             depth_range,
             well_id: dataset.well_id.clone(),
             field_name: dataset.field_name.clone(),
+            wellbore: dataset.wellbore.clone(),
             bit_hours: dataset.bit_hours,
             bit_depth: dataset.bit_depth,
             formation_type: best.formation_type,
@@ -371,6 +372,7 @@ impl HourlyAnalyzer {
             depth_range: (dataset.avg_depth, dataset.avg_depth),
             well_id: dataset.well_id.clone(),
             field_name: dataset.field_name.clone(),
+            wellbore: dataset.wellbore.clone(),
             bit_hours: dataset.bit_hours,
             bit_depth: dataset.bit_depth,
             formation_type: dataset.formation_estimate.clone(),
@@ -505,6 +507,7 @@ mod tests {
             co2: 0.0,
             casing_pressure: 100.0,
             annular_pressure: 150.0,
+            choke_position_pct: 0.0,
             pore_pressure: 8.6,
             fracture_gradient: 14.0,
             mse: 20000.0,
@@ -553,6 +556,7 @@ mod tests {
             rig_states_breakdown: HashMap::new(),
             well_id: "WELL-001".to_string(),
             field_name: "TEST-FIELD".to_string(),
+            wellbore: None,
             bit_hours: 24.0,
             bit_depth: 500.0,
             rejected_sample_count: 0,
@@ -1,7 +1,8 @@
 //! Statistical Correlation Engine (V2.1)
 //!
 //! Calculates Pearson correlations with p-value filtering using the statrs crate.
-//! Only returns correlations that meet the statistical significance threshold (p < 0.05).
+//! Only returns correlations that meet the statistical significance threshold (p < 0.05)
+//! and are strong enough to matter (|r| >= 0.3).
 //!
 //! ## Key Features
 //! - Pearson correlation coefficient calculation
@@ -10,7 +11,8 @@
 //! - Analysis of all relevant drilling parameter pairs
 
 use crate::types::{
-    ml_quality_thresholds::SIGNIFICANCE_THRESHOLD, SignificantCorrelation, WitsPacket,
+    ml_quality_thresholds::{MIN_CORRELATION_R, SIGNIFICANCE_THRESHOLD},
+    SignificantCorrelation, WitsPacket,
 };
 use statrs::distribution::{ContinuousCDF, StudentsT};
 
@@ -29,7 +31,7 @@ impl CorrelationEngine {
     /// * `y_name` - Name of second variable (for reporting)
     ///
     /// # Returns
-    /// Some(SignificantCorrelation) if p < 0.05, None otherwise
+    /// Some(SignificantCorrelation) if p < 0.05 and |r| >= 0.3, None otherwise
     pub fn calculate(
         x: &[f64],
         y: &[f64],
@@ -45,8 +47,8 @@ impl CorrelationEngine {
         let r = Self::pearson(x, y);
         let p_value = Self::p_value_for_r(r, n);
 
-        // V2: Only return if statistically significant
-        if p_value >= SIGNIFICANCE_THRESHOLD {
+        // V2: Only return if statistically significant and not negligibly weak
+        if p_value >= SIGNIFICANCE_THRESHOLD || r.abs() < MIN_CORRELATION_R {
             return None;
         }
 
@@ -202,6 +204,7 @@ mod tests {
             co2: 0.0,
             casing_pressure: 100.0,
             annular_pressure: 150.0,
+            choke_position_pct: 0.0,
             pore_pressure: 8.6,
             fracture_gradient: 14.0,
             mse,
@@ -351,6 +354,47 @@ mod tests {
         assert!(p > 0.2, "r=0.2, n=30 should have p > 0.2, got {}", p);
     }
 
+    /// y = x plus an alternating ±noise term, scaled to give roughly `target_r`.
+    fn noisy_linear(n: usize, target_r: f64) -> (Vec<f64>, Vec<f64>) {
+        let var_x = ((n * n) as f64 - 1.0) / 12.0;
+        let noise = (var_x * (1.0 / (target_r * target_r) - 1.0)).sqrt();
+        let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..n)
+            .map(|i| i as f64 + if i % 2 == 0 { noise } else { -noise })
+            .collect();
+        (x, y)
+    }
+
+    #[test]
+    fn test_p_value_orders_by_sample_count_not_just_r() {
+        // Strong but small-n vs moderate but large-n
+        let (x_small, y_small) = noisy_linear(32, 0.6);
+        let (x_large, y_large) = noisy_linear(1000, 0.35);
+
+        let small = CorrelationEngine::calculate(&x_small, &y_small, "X", "Y").unwrap();
+        let large = CorrelationEngine::calculate(&x_large, &y_large, "X", "Y").unwrap();
+
+        assert_eq!(small.sample_count, 32);
+        assert_eq!(large.sample_count, 1000);
+        assert!(small.r_value > large.r_value);
+        assert!(
+            large.p_value < small.p_value,
+            "large-n p ({}) should be below small-n p ({})",
+            large.p_value,
+            small.p_value
+        );
+    }
+
+    #[test]
+    fn test_significant_but_negligible_r_rejected() {
+        // r ≈ 0.15 over 1000 samples: p < 0.05 but below the |r| bar
+        let (x, y) = noisy_linear(1000, 0.15);
+        let r = CorrelationEngine::pearson(&x, &y);
+        assert!(CorrelationEngine::p_value_for_r(r, x.len()) < SIGNIFICANCE_THRESHOLD);
+        assert!(r.abs() < MIN_CORRELATION_R);
+        assert!(CorrelationEngine::calculate(&x, &y, "X", "Y").is_none());
+    }
+
     #[test]
     fn test_correlations_sorted_by_strength() {
         // Create packets with varying correlation strengths
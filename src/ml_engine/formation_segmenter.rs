@@ -261,6 +261,7 @@ mod tests {
             co2: 0.0,
             casing_pressure: 100.0,
             annular_pressure: 150.0,
+            choke_position_pct: 0.0,
             pore_pressure: 8.6,
             fracture_gradient: 14.0,
             mse: 20000.0,
@@ -34,5 +34,5 @@ pub mod storage;
 // Re-export public types
 pub use formation_segmenter::FormationSegmenter;
 pub use optimal_finder::OptimalFinder;
-pub use scheduler::{get_interval, MLScheduler};
+pub use scheduler::{get_interval, LagConfig, MLScheduler};
 pub use storage::MLInsightsStorage;
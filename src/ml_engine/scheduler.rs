@@ -12,7 +12,8 @@
 use std::time::Duration;
 use tracing::{info, warn};
 
-use crate::types::{AnalysisResult, Campaign, HourlyDataset, MLInsightsReport};
+use crate::config::MlConfig;
+use crate::types::{AnalysisResult, Campaign, HourlyDataset, MLInsightsReport, WitsPacket};
 
 use super::analyzer::HourlyAnalyzer;
 
@@ -31,6 +32,38 @@ pub fn get_interval() -> Duration {
     Duration::from_secs(get_interval_secs())
 }
 
+/// Per-series lag compensation, in seconds (one packet per second).
+///
+/// Each series is read from `lag` packets later, so it lines up with the
+/// WOB/RPM that produced it at the bit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LagConfig {
+    /// ROP response lag (`ml.rop_lag_seconds`)
+    pub rop_seconds: usize,
+    /// Gas lag-to-surface (`ml.gas_lag_seconds`)
+    pub gas_units_seconds: usize,
+    /// Return flow lag (`ml.flow_lag_seconds`)
+    pub flow_out_seconds: usize,
+}
+
+impl LagConfig {
+    /// Lags from the `[ml]` config section.
+    pub fn from_config(ml: &MlConfig) -> Self {
+        Self {
+            rop_seconds: ml.rop_lag_seconds as usize,
+            gas_units_seconds: ml.gas_lag_seconds as usize,
+            flow_out_seconds: ml.flow_lag_seconds as usize,
+        }
+    }
+
+    /// Largest of the per-series lags.
+    pub fn max_lag(&self) -> usize {
+        self.rop_seconds
+            .max(self.gas_units_seconds)
+            .max(self.flow_out_seconds)
+    }
+}
+
 /// ML Analysis Scheduler
 ///
 /// Runs ML analysis at configurable intervals and stores results.
@@ -77,6 +110,31 @@ impl MLScheduler {
         report
     }
 
+    /// Shift `rop`, `gas_units` and `flow_out` forward by their own lags.
+    ///
+    /// The last `max_lag` packets have no lagged values for the longest-lag
+    /// series, so the window is truncated to `len - max_lag` whatever the
+    /// other lags are; mismatched lags cost the shorter series that tail too.
+    /// A window no longer than `max_lag` is emptied.
+    pub fn apply_lag_compensation(packets: &mut Vec<WitsPacket>, lags: &LagConfig) {
+        let max_lag = lags.max_lag();
+        if max_lag == 0 {
+            return;
+        }
+        if packets.len() <= max_lag {
+            packets.clear();
+            return;
+        }
+
+        let kept = packets.len() - max_lag;
+        for i in 0..kept {
+            packets[i].rop = packets[i + lags.rop_seconds].rop;
+            packets[i].gas_units = packets[i + lags.gas_units_seconds].gas_units;
+            packets[i].flow_out = packets[i + lags.flow_out_seconds].flow_out;
+        }
+        packets.truncate(kept);
+    }
+
     /// Build a dataset from history data
     ///
     /// This helper creates an HourlyDataset from raw packets/metrics.
@@ -130,6 +188,7 @@ impl MLScheduler {
             rig_states_breakdown,
             well_id: well_id.to_string(),
             field_name: field_name.to_string(),
+            wellbore: None,
             bit_hours,
             bit_depth,
             rejected_sample_count: 0,
@@ -180,6 +239,7 @@ mod tests {
             co2: 0.0,
             casing_pressure: 100.0,
             annular_pressure: 150.0,
+            choke_position_pct: 0.0,
             pore_pressure: 8.6,
             fracture_gradient: 14.0,
             mse: 20000.0,
@@ -230,6 +290,63 @@ mod tests {
         // Scheduler uses env-configured interval (default 3600s)
     }
 
+    fn lag_series(n: usize) -> Vec<WitsPacket> {
+        (0..n)
+            .map(|i| {
+                let mut p = make_packet(20.0, 100.0, i as f64);
+                p.gas_units = 1000.0 + i as f64;
+                p.flow_out = 2000.0 + i as f64;
+                p
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lag_compensation_shifts_each_series_by_its_own_lag() {
+        let mut packets = lag_series(100);
+        let lags = LagConfig {
+            rop_seconds: 10,
+            gas_units_seconds: 30,
+            flow_out_seconds: 5,
+        };
+        MLScheduler::apply_lag_compensation(&mut packets, &lags);
+
+        assert_eq!(packets.len(), 100 - 30);
+        for (i, p) in packets.iter().enumerate() {
+            assert_eq!(p.rop, (i + 10) as f64);
+            assert_eq!(p.gas_units, 1000.0 + (i + 30) as f64);
+            assert_eq!(p.flow_out, 2000.0 + (i + 5) as f64);
+            // Unlagged channels stay put
+            assert_eq!(p.wob, 20.0);
+        }
+    }
+
+    #[test]
+    fn test_lag_compensation_edge_cases() {
+        let mut packets = lag_series(50);
+        MLScheduler::apply_lag_compensation(&mut packets, &LagConfig::default());
+        assert_eq!(packets.len(), 50);
+        assert_eq!(packets[0].rop, 0.0);
+
+        // ROP-only lag matches the old single-series behaviour
+        let config = crate::config::WellConfig::default();
+        let lags = LagConfig::from_config(&config.ml);
+        assert_eq!(lags.max_lag(), config.ml.rop_lag_seconds as usize);
+        let mut packets = lag_series(100);
+        MLScheduler::apply_lag_compensation(&mut packets, &lags);
+        assert_eq!(packets.len(), 100 - lags.rop_seconds);
+        assert_eq!(packets[0].rop, lags.rop_seconds as f64);
+
+        // Window shorter than the longest lag is emptied
+        let mut packets = lag_series(20);
+        let lags = LagConfig {
+            gas_units_seconds: 20,
+            ..LagConfig::default()
+        };
+        MLScheduler::apply_lag_compensation(&mut packets, &lags);
+        assert!(packets.is_empty());
+    }
+
     #[test]
     fn test_build_dataset() {
         let packets: Vec<_> = (0..100)
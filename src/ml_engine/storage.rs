@@ -196,10 +196,13 @@ impl MLInsightsStorage {
     ///
     /// Returns reports where the depth range overlaps with the query depth.
     /// Uses `scan_prefix()` to restrict iteration to `{field_name}/{well_id}/`.
+    /// Only reports drilled on `wellbore` are returned (`None` = original hole),
+    /// so a sidetrack and the hole it left are never mixed at the same depth.
     pub fn find_by_depth(
         &self,
         field_name: &str,
         well_id: &str,
+        wellbore: Option<&str>,
         depth: f64,
         tolerance: f64,
         limit: usize,
@@ -210,6 +213,9 @@ impl MLInsightsStorage {
         for result in self.db.scan_prefix(prefix.as_bytes()) {
             let (_, value) = result?;
             if let Ok(report) = serde_json::from_slice::<MLInsightsReport>(&value) {
+                if report.wellbore.as_deref() != wellbore {
+                    continue;
+                }
                 // Check if depth is within range
                 let (min_depth, max_depth) = report.depth_range;
                 if depth >= min_depth - tolerance && depth <= max_depth + tolerance {
@@ -276,6 +282,7 @@ mod tests {
             depth_range: (depth - 50.0, depth + 50.0),
             well_id: well_id.to_string(),
             field_name: field_name.to_string(),
+            wellbore: None,
             bit_hours: 24.0,
             bit_depth: 500.0,
             formation_type: "Test Formation".to_string(),
@@ -428,12 +435,36 @@ mod tests {
 
         // Search near 5000ft
         let near_5000 = storage
-            .find_by_depth("FIELD-A", "WELL-001", 5000.0, 100.0, 10)
+            .find_by_depth("FIELD-A", "WELL-001", None, 5000.0, 100.0, 10)
             .unwrap();
         assert_eq!(near_5000.len(), 1);
         assert!((near_5000[0].depth_range.0 - 4950.0).abs() < 1.0);
     }
 
+    #[test]
+    fn test_find_by_depth_separates_wellbores() {
+        let storage = MLInsightsStorage::open_temp().unwrap();
+
+        // Original hole and sidetrack both drilled through 8000 ft
+        let original = make_report("WELL-001", "FIELD-A", Campaign::Production, 1000, 8000.0);
+        let mut sidetrack = make_report("WELL-001", "FIELD-A", Campaign::Production, 2000, 8000.0);
+        sidetrack.wellbore = Some("ST1".to_string());
+        storage.store_report(&original).unwrap();
+        storage.store_report(&sidetrack).unwrap();
+
+        let on_original = storage
+            .find_by_depth("FIELD-A", "WELL-001", None, 8000.0, 100.0, 10)
+            .unwrap();
+        assert_eq!(on_original.len(), 1);
+        assert_eq!(on_original[0].timestamp, 1000);
+
+        let on_sidetrack = storage
+            .find_by_depth("FIELD-A", "WELL-001", Some("ST1"), 8000.0, 100.0, 10)
+            .unwrap();
+        assert_eq!(on_sidetrack.len(), 1);
+        assert_eq!(on_sidetrack[0].timestamp, 2000);
+    }
+
     #[test]
     fn test_count() {
         let storage = MLInsightsStorage::open_temp().unwrap();
@@ -11,6 +11,13 @@
 //! 2. Normalize MSE against estimated optimal MSE for formation hardness
 //! 3. Compare current normalized MSE against the reference (first bucket)
 //! 4. Wear index = (current_normalized - reference_normalized) / reference_normalized
+//!
+//! ## Backward Depth
+//!
+//! Picking up off bottom for a survey or wiper trip moves depth backwards,
+//! and drilling back down re-covers hole that was already cut. With
+//! `bit_wear.new_hole_only` (default), samples only count while depth is past
+//! the high-water mark, so footage and MSE accumulate for new hole once.
 
 use serde::Serialize;
 use std::collections::VecDeque;
@@ -108,6 +115,10 @@ pub struct BitWearTracker {
     pub current_wear_index: f64,
     last_depth: f64,
     current_bucket: Option<AccumulatingBucket>,
+    /// Deepest hole depth seen on this bit run
+    hole_depth_hwm: Option<f64>,
+    /// Footage of new hole drilled on this bit run (ft)
+    footage_drilled_ft: f64,
 }
 
 impl Default for BitWearTracker {
@@ -122,6 +133,7 @@ impl std::fmt::Debug for BitWearTracker {
             .field("wear_index", &self.current_wear_index)
             .field("buckets", &self.buckets.len())
             .field("last_depth", &self.last_depth)
+            .field("footage_drilled_ft", &self.footage_drilled_ft)
             .finish()
     }
 }
@@ -134,12 +146,15 @@ impl BitWearTracker {
             current_wear_index: 0.0,
             last_depth: 0.0,
             current_bucket: None,
+            hole_depth_hwm: None,
+            footage_drilled_ft: 0.0,
         }
     }
 
     /// Update tracker with a new drilling sample.
     ///
     /// Only call during active drilling (Drilling/Reaming rig state).
+    /// `depth` is the hole depth (bit depth when hole depth is unavailable).
     /// `hardness` is the current formation hardness (0-10), use 5.0 as default.
     pub fn update(&mut self, depth: f64, mse: f64, wob: f64, rpm: f64, hardness: f64) {
         // Ignore non-positive MSE
@@ -147,6 +162,20 @@ impl BitWearTracker {
             return;
         }
 
+        let new_hole_only =
+            !crate::config::is_initialized() || crate::config::get().bit_wear.new_hole_only;
+        match self.hole_depth_hwm {
+            // Re-drilling hole already cut on this run
+            Some(hwm) if new_hole_only && depth <= hwm => {
+                self.last_depth = depth;
+                return;
+            }
+            Some(hwm) if new_hole_only => self.footage_drilled_ft += depth - hwm,
+            Some(_) => self.footage_drilled_ft += (depth - self.last_depth).max(0.0),
+            None => {}
+        }
+        self.hole_depth_hwm = Some(self.hole_depth_hwm.map_or(depth, |hwm| hwm.max(depth)));
+
         // Initialize or check if we need a new bucket
         let bucket = self.current_bucket.get_or_insert_with(|| {
             AccumulatingBucket::new(depth, hardness)
@@ -202,6 +231,11 @@ impl BitWearTracker {
         }
     }
 
+    /// Footage drilled on this bit run (ft)
+    pub fn footage_drilled(&self) -> f64 {
+        self.footage_drilled_ft
+    }
+
     /// Get finalized footage buckets
     pub fn buckets(&self) -> &VecDeque<FootageBucket> {
         &self.buckets
@@ -223,6 +257,8 @@ impl BitWearTracker {
         self.current_wear_index = 0.0;
         self.last_depth = 0.0;
         self.current_bucket = None;
+        self.hole_depth_hwm = None;
+        self.footage_drilled_ft = 0.0;
     }
 }
 
@@ -311,6 +347,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pickup_and_redrill_counts_only_new_hole() {
+        let mut tracker = BitWearTracker::new();
+
+        // Drill 5000 → 5060 ft
+        for i in 0..=60 {
+            tracker.update(5000.0 + i as f64, 20000.0, 20.0, 120.0, 5.0);
+        }
+        assert_eq!(tracker.footage_drilled(), 60.0);
+        let samples_before = tracker.current_bucket.as_ref().map(|b| b.count);
+
+        // Pick up 30 ft for a survey, then re-drill back to 5060 ft
+        for i in 1..=30 {
+            tracker.update(5060.0 - i as f64, 20000.0, 20.0, 120.0, 5.0);
+        }
+        for i in 1..=30 {
+            tracker.update(5030.0 + i as f64, 20000.0, 20.0, 120.0, 5.0);
+        }
+        assert_eq!(tracker.footage_drilled(), 60.0, "Re-drilled interval must not count");
+        assert_eq!(
+            tracker.current_bucket.as_ref().map(|b| b.count),
+            samples_before,
+            "Re-drilled samples must not be accumulated"
+        );
+
+        // New hole past the high-water mark counts again
+        for i in 1..=40 {
+            tracker.update(5060.0 + i as f64, 20000.0, 20.0, 120.0, 5.0);
+        }
+        assert_eq!(tracker.footage_drilled(), 100.0);
+        assert_eq!(tracker.buckets().len(), 1);
+        assert_eq!(tracker.buckets()[0].sample_count, 101);
+    }
+
     #[test]
     fn test_reset_clears_state() {
         let mut tracker = BitWearTracker::new();
@@ -323,5 +393,6 @@ mod tests {
         tracker.reset();
         assert!(tracker.buckets().is_empty());
         assert_eq!(tracker.wear_index(), 0.0);
+        assert_eq!(tracker.footage_drilled(), 0.0);
     }
 }
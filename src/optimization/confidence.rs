@@ -178,6 +178,7 @@ mod tests {
                     co2: 0.1,
                     casing_pressure: 0.0,
                     annular_pressure: 0.0,
+                    choke_position_pct: 0.0,
                     pore_pressure: 10.5,
                     fracture_gradient: 14.0,
                     mse: 30000.0,
@@ -198,6 +198,8 @@ mod tests {
             },
             formations: vec![fm1.clone(), fm2],
             casings: vec![],
+            sidetracks: vec![],
+            mud_program: Vec::new(),
         };
 
         (prognosis, fm1)
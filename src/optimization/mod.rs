@@ -9,6 +9,8 @@ mod confidence;
 pub mod look_ahead;
 mod optimizer;
 mod rate_limiter;
+mod step;
 pub mod templates;
 
 pub use optimizer::ParameterOptimizer;
+pub use step::recommend_step;
@@ -324,6 +324,8 @@ mod tests {
             },
             formations: vec![formation.clone()],
             casings: vec![],
+            sidetracks: vec![],
+            mud_program: Vec::new(),
         }
     }
 
@@ -356,6 +358,7 @@ mod tests {
             co2: 0.1,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.0,
             mse: 35000.0,
@@ -0,0 +1,243 @@
+//! ROP-maximizing WOB step recommendation with a founder guardrail
+//!
+//! Fits the local ROP-vs-WOB response over the recent drilling history.
+//! While ROP still climbs with WOB the next step is a bounded increase; once
+//! `detect_founder` sees WOB rising without an ROP response the step is a
+//! decrease back towards the WOB where ROP peaked. RPM is held.
+
+use crate::physics_engine::detect_founder;
+use crate::types::{HistoryEntry, ParameterStep, RigState, StepAction, WitsPacket};
+
+/// Minimum WOB spread (klbs) across the history to fit an ROP response.
+const MIN_WOB_SPREAD_KLBS: f64 = 1.0;
+
+/// ROP elasticity to WOB at or above which the response is still linear.
+const LINEAR_RESPONSE_MIN: f64 = 0.5;
+
+/// Recommend the next WOB step for `current` from the drilling history.
+///
+/// The step is clamped to `optimization.max_wob_step_klbs`. Too little
+/// history, WOB held too steady to fit a response, or a flattening response
+/// short of foundering all give a `Hold`.
+pub fn recommend_step(history: &[HistoryEntry], current: &WitsPacket) -> ParameterStep {
+    let cfg = crate::config::get();
+    let max_step = cfg.optimization.max_wob_step_klbs;
+    let current_wob = current.wob;
+
+    let drilling: Vec<&WitsPacket> = history
+        .iter()
+        .map(|h| &h.packet)
+        .filter(|p| p.rig_state == RigState::Drilling)
+        .collect();
+    let min_samples = cfg.thresholds.founder.min_samples;
+    if drilling.len() < min_samples {
+        return hold(
+            current_wob,
+            0.0,
+            format!(
+                "Hold WOB at {:.1} klbs: {} drilling samples, need {} to fit the ROP response",
+                current_wob,
+                drilling.len(),
+                min_samples
+            ),
+        );
+    }
+
+    let wob: Vec<f64> = drilling.iter().map(|p| p.wob).collect();
+    let rop: Vec<f64> = drilling.iter().map(|p| p.rop).collect();
+    let response = rop_elasticity(&wob, &rop);
+
+    let (founder, severity, optimal_wob) = detect_founder(&wob, &rop);
+    if founder {
+        let excess = current_wob - optimal_wob;
+        let decrement = if optimal_wob > 0.0 && excess > 0.0 {
+            excess.min(max_step)
+        } else {
+            max_step * severity
+        };
+        let target = (current_wob - decrement).max(0.0);
+        return ParameterStep {
+            action: StepAction::Decrease,
+            current_wob,
+            recommended_wob: target,
+            step_klbs: target - current_wob,
+            rop_response: response,
+            rationale: format!(
+                "Founder detected (severity {:.0}%): ROP no longer responds to WOB. \
+                 Reduce WOB {:.1} -> {:.1} klbs (ROP peaked at {:.1} klbs)",
+                severity * 100.0,
+                current_wob,
+                target,
+                optimal_wob
+            ),
+        };
+    }
+
+    let (min_wob, max_wob) = wob
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(lo, hi), &w| (lo.min(w), hi.max(w)));
+    if max_wob - min_wob < MIN_WOB_SPREAD_KLBS {
+        return hold(
+            current_wob,
+            0.0,
+            format!(
+                "Hold WOB at {:.1} klbs: WOB varied only {:.1} klbs, too little to fit the \
+                 ROP response",
+                current_wob,
+                max_wob - min_wob
+            ),
+        );
+    }
+
+    if response >= LINEAR_RESPONSE_MIN {
+        let increment = max_step * response.min(1.0);
+        let target = current_wob + increment;
+        return ParameterStep {
+            action: StepAction::Increase,
+            current_wob,
+            recommended_wob: target,
+            step_klbs: increment,
+            rop_response: response,
+            rationale: format!(
+                "ROP still responding linearly to WOB ({:.2}% ROP per 1% WOB). \
+                 Increase WOB {:.1} -> {:.1} klbs",
+                response, current_wob, target
+            ),
+        };
+    }
+
+    hold(
+        current_wob,
+        response,
+        format!(
+            "Hold WOB at {:.1} klbs: ROP response flattening ({:.2}% ROP per 1% WOB), \
+             approaching the founder point",
+            current_wob, response
+        ),
+    )
+}
+
+fn hold(current_wob: f64, rop_response: f64, rationale: String) -> ParameterStep {
+    ParameterStep {
+        action: StepAction::Hold,
+        current_wob,
+        recommended_wob: current_wob,
+        step_klbs: 0.0,
+        rop_response,
+        rationale,
+    }
+}
+
+/// Least-squares ROP-vs-WOB slope normalized by the means, as in
+/// `detect_founder`: % ROP change per % WOB change. 0 if it can't be fitted.
+fn rop_elasticity(wob: &[f64], rop: &[f64]) -> f64 {
+    let n = wob.len().min(rop.len());
+    if n < 2 {
+        return 0.0;
+    }
+    let mean_wob = wob[..n].iter().sum::<f64>() / n as f64;
+    let mean_rop = rop[..n].iter().sum::<f64>() / n as f64;
+    if mean_wob <= 0.0 || mean_rop <= 0.0 {
+        return 0.0;
+    }
+
+    let (mut sxy, mut sxx) = (0.0, 0.0);
+    for (w, r) in wob[..n].iter().zip(&rop[..n]) {
+        sxy += (w - mean_wob) * (r - mean_rop);
+        sxx += (w - mean_wob).powi(2);
+    }
+    if sxx < 1e-10 {
+        return 0.0;
+    }
+
+    let elasticity = sxy / sxx * mean_wob / mean_rop;
+    if elasticity.is_finite() {
+        elasticity
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DrillingMetrics;
+
+    fn ensure_config() {
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+    }
+
+    fn make_history(points: &[(f64, f64)]) -> Vec<HistoryEntry> {
+        points
+            .iter()
+            .map(|&(wob, rop)| HistoryEntry {
+                packet: WitsPacket {
+                    wob,
+                    rop,
+                    rpm: 120.0,
+                    rig_state: RigState::Drilling,
+                    ..WitsPacket::default()
+                },
+                metrics: DrillingMetrics::default(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn linear_response_recommends_bounded_increase() {
+        ensure_config();
+        let max_step = crate::config::get().optimization.max_wob_step_klbs;
+
+        // ROP proportional to WOB: elasticity 1.0
+        let points: Vec<(f64, f64)> = (0..20)
+            .map(|i| {
+                let wob = 20.0 + 0.5 * i as f64;
+                (wob, 3.0 * wob)
+            })
+            .collect();
+        let history = make_history(&points);
+        let current = history.last().unwrap().packet.clone();
+
+        let step = recommend_step(&history, &current);
+        assert_eq!(step.action, StepAction::Increase);
+        assert!((step.rop_response - 1.0).abs() < 1e-6);
+        assert!(step.step_klbs > 0.0 && step.step_klbs <= max_step + 1e-9);
+        assert!((step.recommended_wob - (current.wob + step.step_klbs)).abs() < 1e-9);
+        assert!(step.rationale.contains("Increase WOB"));
+    }
+
+    #[test]
+    fn foundering_recommends_decrease() {
+        ensure_config();
+        let max_step = crate::config::get().optimization.max_wob_step_klbs;
+
+        // WOB climbing while ROP falls away
+        let points: Vec<(f64, f64)> = (0..20)
+            .map(|i| (20.0 + 0.5 * i as f64, 80.0 - 1.0 * i as f64))
+            .collect();
+        let history = make_history(&points);
+        let current = history.last().unwrap().packet.clone();
+
+        let step = recommend_step(&history, &current);
+        assert_eq!(step.action, StepAction::Decrease);
+        assert!(step.step_klbs < 0.0 && step.step_klbs >= -max_step - 1e-9);
+        assert!(step.recommended_wob < current.wob);
+        // Never steps below the WOB where ROP peaked
+        assert!(step.recommended_wob >= 20.0);
+        assert!(step.rationale.contains("Founder"));
+    }
+
+    #[test]
+    fn steady_wob_holds() {
+        ensure_config();
+        let history = make_history(&[(25.0, 60.0); 20]);
+        let step = recommend_step(&history, &history[0].packet);
+        assert_eq!(step.action, StepAction::Hold);
+        assert_eq!(step.step_klbs, 0.0);
+    }
+}
@@ -107,6 +107,10 @@ pub fn format_optimization_advisory(
         trigger_parameter: String::new(),
         trigger_value: 0.0,
         threshold_value: 0.0,
+        provisional_during_learning: false,
+        sparkline: None,
+        gas_lag: None,
+        causal_leads: Vec::new(),
     }
 }
 
@@ -182,6 +186,10 @@ pub fn format_lookahead_advisory(
         trigger_parameter: "formation_lookahead".to_string(),
         trigger_value: look_ahead.estimated_minutes,
         threshold_value: 0.0,
+        provisional_during_learning: false,
+        sparkline: None,
+        gas_lag: None,
+        causal_leads: Vec::new(),
     }
 }
 
@@ -0,0 +1,203 @@
+//! Bit Run Tracking
+//!
+//! Keeps the state of the bit currently in the hole: on-bottom hours and the
+//! MSE / ROP / dxc baseline averaged over the first rotating-drilling samples
+//! of the run. Bit wear is judged against that run-start baseline rather than
+//! against whatever happens to be in the 60-packet history window.
+//!
+//! A run ends when the bit is pulled back to surface after having been
+//! downhole; the next packet on bottom starts a fresh run with zero hours and
+//! a new baseline. The coordinator persists the run so a restart mid-run
+//! keeps both.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{HistoryEntry, RigState};
+
+/// Rotating-drilling samples averaged into the run-start baseline
+pub const BIT_RUN_BASELINE_SAMPLES: usize = 30;
+
+/// Bit depth (ft) at or above which the bit counts as at surface
+const BIT_AT_SURFACE_FT: f64 = 100.0;
+
+/// State of the bit run in progress
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BitRunBaseline {
+    /// Timestamp of the first baseline sample (0 until the bit drills)
+    pub started_at: u64,
+    /// Bit depth at the first baseline sample (ft)
+    pub start_depth_ft: f64,
+    /// Samples averaged into the baseline so far
+    pub samples: usize,
+    /// Run-start MSE (psi)
+    pub mse: f64,
+    /// Run-start ROP (ft/hr)
+    pub rop: f64,
+    /// Run-start corrected d-exponent
+    pub dxc: f64,
+    /// WOB the baseline was drilled at (klbs)
+    pub wob: f64,
+    /// RPM the baseline was drilled at
+    pub rpm: f64,
+    /// Drilling / sliding / reaming hours on the bit
+    pub bit_hours: f64,
+    /// Deepest bit depth seen on the run (ft)
+    pub deepest_ft: f64,
+}
+
+impl BitRunBaseline {
+    /// Whether enough samples have been averaged to judge wear against
+    pub fn is_complete(&self) -> bool {
+        self.samples >= BIT_RUN_BASELINE_SAMPLES
+    }
+}
+
+/// Tracks the current bit run from the packet stream
+#[derive(Debug, Default)]
+pub struct BitRunTracker {
+    run: BitRunBaseline,
+    last_ts: Option<u64>,
+}
+
+impl BitRunTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resume a run persisted before a restart
+    pub fn restore(&mut self, run: BitRunBaseline) {
+        self.run = run;
+        self.last_ts = None;
+    }
+
+    /// The run in progress
+    pub fn run(&self) -> &BitRunBaseline {
+        &self.run
+    }
+
+    /// Feed one history entry. Returns the finished run when the bit has been
+    /// pulled to surface, after which a new run starts from zero.
+    ///
+    /// Hours accumulate only across gaps of at most `max_gap_secs`.
+    pub fn update(&mut self, entry: &HistoryEntry, max_gap_secs: u64) -> Option<BitRunBaseline> {
+        let packet = &entry.packet;
+        let ts = packet.timestamp;
+        let dt = self.last_ts.map(|prev| ts.saturating_sub(prev));
+        self.last_ts = Some(ts);
+
+        let at_surface = packet.bit_depth > 0.0 && packet.bit_depth <= BIT_AT_SURFACE_FT;
+        if at_surface && self.run.deepest_ft > BIT_AT_SURFACE_FT && self.run.bit_hours > 0.0 {
+            return Some(std::mem::take(&mut self.run));
+        }
+        self.run.deepest_ft = self.run.deepest_ft.max(packet.bit_depth);
+
+        if matches!(
+            entry.metrics.state,
+            RigState::Drilling | RigState::Sliding | RigState::Reaming
+        ) {
+            if let Some(dt) = dt.filter(|&dt| dt <= max_gap_secs) {
+                self.run.bit_hours += dt as f64 / 3600.0;
+            }
+        }
+
+        let baseline_sample = entry.metrics.state == RigState::Drilling
+            && entry.metrics.mse.is_finite()
+            && entry.metrics.mse > 0.0
+            && packet.wob > 0.0
+            && packet.rpm > 0.0;
+        if baseline_sample && !self.run.is_complete() {
+            let run = &mut self.run;
+            if run.samples == 0 {
+                run.started_at = ts;
+                run.start_depth_ft = packet.bit_depth;
+            }
+            let n = run.samples as f64;
+            let avg = |mean: f64, x: f64| (mean * n + x) / (n + 1.0);
+            run.mse = avg(run.mse, entry.metrics.mse);
+            run.rop = avg(run.rop, packet.rop);
+            run.dxc = avg(run.dxc, entry.metrics.dxc);
+            run.wob = avg(run.wob, packet.wob);
+            run.rpm = avg(run.rpm, packet.rpm);
+            run.samples += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DrillingMetrics, WitsPacket};
+
+    fn entry(ts: u64, bit_depth: f64, state: RigState, mse: f64) -> HistoryEntry {
+        let mut packet = WitsPacket::default();
+        packet.timestamp = ts;
+        packet.bit_depth = bit_depth;
+        packet.wob = 25.0;
+        packet.rpm = 120.0;
+        packet.rop = 60.0;
+        HistoryEntry {
+            packet,
+            metrics: DrillingMetrics {
+                state,
+                mse,
+                dxc: 1.5,
+                ..DrillingMetrics::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_baseline_is_run_start_and_resets_after_trip_out() {
+        let mut tracker = BitRunTracker::new();
+        for i in 0..100u64 {
+            let mse = 20_000.0 + i as f64 * 500.0;
+            assert!(tracker
+                .update(
+                    &entry(1_000 + i * 10, 8_000.0 + i as f64, RigState::Drilling, mse),
+                    60
+                )
+                .is_none());
+        }
+        let run = tracker.run().clone();
+        assert_eq!(run.samples, BIT_RUN_BASELINE_SAMPLES);
+        assert_eq!(run.started_at, 1_000);
+        // Mean of the first 30 samples only, not of the later worn-bit MSE
+        assert!(
+            (run.mse - (20_000.0 + 14.5 * 500.0)).abs() < 1.0,
+            "{}",
+            run.mse
+        );
+        assert!((run.bit_hours - 990.0 / 3600.0).abs() < 1e-9);
+
+        // Trip out: hours keep counting only while on bottom
+        tracker.update(&entry(2_100, 4_000.0, RigState::TrippingOut, 0.0), 60);
+        assert_eq!(tracker.run().bit_hours, run.bit_hours);
+        let ended = tracker
+            .update(&entry(2_200, 50.0, RigState::TrippingOut, 0.0), 60)
+            .expect("bit at surface ends the run");
+        assert_eq!(ended.samples, BIT_RUN_BASELINE_SAMPLES);
+
+        // New bit: zero hours and a baseline from the new run
+        assert_eq!(tracker.run(), &BitRunBaseline::default());
+        tracker.update(&entry(5_000, 8_100.0, RigState::Drilling, 18_000.0), 60);
+        assert_eq!(tracker.run().samples, 1);
+        assert_eq!(tracker.run().started_at, 5_000);
+        assert_eq!(tracker.run().mse, 18_000.0);
+        assert_eq!(tracker.run().bit_hours, 0.0);
+    }
+
+    #[test]
+    fn test_shallow_spud_is_not_a_trip() {
+        let mut tracker = BitRunTracker::new();
+        for i in 0..40u64 {
+            assert!(tracker
+                .update(
+                    &entry(i * 10, 30.0 + i as f64, RigState::Drilling, 20_000.0),
+                    60
+                )
+                .is_none());
+        }
+        assert!(tracker.run().bit_hours > 0.0);
+    }
+}
@@ -0,0 +1,178 @@
+//! MPD Choke Adjustment Detection
+//!
+//! On managed-pressure wells the crew moves the surface choke to hold
+//! annular pressure. The back-pressure change shows up immediately in SPP,
+//! which the hydraulics detector would otherwise report as an anomaly.
+//!
+//! `ChokeAdjustmentTracker` recognises a choke move in two ways:
+//!
+//! - detected: `choke_position_pct` changes by at least `min_choke_move_pct`
+//!   within `detect_window_secs`;
+//! - declared: the operator posts to `POST /api/v2/mpd/choke-adjustment`.
+//!
+//! Pressure changes are then attributed to the choke until `context_secs`
+//! after the last move. A feed without choke position (`choke_position_pct`
+//! = 0) only ever gets declared adjustments.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::MpdConfig;
+
+/// How a choke adjustment became known
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChokeAdjustmentSource {
+    /// Choke position change in the feed
+    Detected,
+    /// Operator declaration via the API
+    Declared,
+}
+
+/// Start of a choke adjustment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChokeAdjustment {
+    pub source: ChokeAdjustmentSource,
+    /// Unix timestamp of the packet the adjustment was recognised on
+    pub timestamp: u64,
+    /// Choke position before the move (detected only)
+    pub from_pct: Option<f64>,
+    /// Choke position after the move (detected only)
+    pub to_pct: Option<f64>,
+}
+
+/// Operator declaration of a choke move, applied on the next packet
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChokeAdjustmentDeclaration {
+    /// Free-text reason (e.g. "stepping up back-pressure for connection")
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// Tracks choke moves and the window in which pressure changes are
+/// attributed to them
+#[derive(Debug, Clone, Default)]
+pub struct ChokeAdjustmentTracker {
+    /// Recent (timestamp, choke position) samples within the detect window
+    positions: VecDeque<(u64, f64)>,
+    /// Pressure changes are contextualized until this timestamp
+    context_until: u64,
+    last_ts: u64,
+}
+
+impl ChokeAdjustmentTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True while pressure changes are attributed to a choke move
+    pub fn is_contextualizing(&self) -> bool {
+        self.last_ts < self.context_until
+    }
+
+    /// Update with one packet. Returns the adjustment when a new one starts;
+    /// continued movement only extends the context window.
+    pub fn update(
+        &mut self,
+        timestamp: u64,
+        choke_position_pct: f64,
+        cfg: &MpdConfig,
+    ) -> Option<ChokeAdjustment> {
+        let was_contextualizing = self.is_contextualizing();
+        self.last_ts = timestamp;
+
+        if !choke_position_pct.is_finite() || choke_position_pct <= 0.0 {
+            return None;
+        }
+
+        self.positions.push_back((timestamp, choke_position_pct));
+        while let Some(&(ts, _)) = self.positions.front() {
+            if timestamp.saturating_sub(ts) > cfg.detect_window_secs {
+                self.positions.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        let from = self.positions.front().map_or(choke_position_pct, |p| p.1);
+        let (min, max) = self
+            .positions
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(lo, hi), &(_, p)| {
+                (lo.min(p), hi.max(p))
+            });
+        if max - min < cfg.min_choke_move_pct {
+            return None;
+        }
+
+        self.context_until = timestamp + cfg.context_secs;
+        // Measure the next move from here
+        self.positions.clear();
+        self.positions.push_back((timestamp, choke_position_pct));
+
+        (!was_contextualizing).then_some(ChokeAdjustment {
+            source: ChokeAdjustmentSource::Detected,
+            timestamp,
+            from_pct: Some(from),
+            to_pct: Some(choke_position_pct),
+        })
+    }
+
+    /// Record an operator-declared adjustment at `timestamp`.
+    pub fn declare(&mut self, timestamp: u64, cfg: &MpdConfig) -> ChokeAdjustment {
+        self.last_ts = self.last_ts.max(timestamp);
+        self.context_until = self.context_until.max(timestamp + cfg.context_secs);
+        ChokeAdjustment {
+            source: ChokeAdjustmentSource::Declared,
+            timestamp,
+            from_pct: None,
+            to_pct: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choke_move_opens_context_window() {
+        let cfg = MpdConfig::default();
+        let mut tracker = ChokeAdjustmentTracker::new();
+
+        // Small drift is not an adjustment
+        for (i, pos) in [40.0, 41.0, 40.5, 42.0].into_iter().enumerate() {
+            assert!(tracker.update(1000 + i as u64, pos, &cfg).is_none());
+        }
+        assert!(!tracker.is_contextualizing());
+
+        let adj = tracker
+            .update(1004, 55.0, &cfg)
+            .expect("choke move detected");
+        assert_eq!(adj.source, ChokeAdjustmentSource::Detected);
+        assert_eq!((adj.from_pct, adj.to_pct), (Some(40.0), Some(55.0)));
+        assert!(tracker.is_contextualizing());
+
+        // Holding position: context expires context_secs after the move
+        assert!(tracker.update(1005, 55.0, &cfg).is_none());
+        assert!(tracker.is_contextualizing());
+        tracker.update(1004 + cfg.context_secs, 55.0, &cfg);
+        assert!(!tracker.is_contextualizing());
+    }
+
+    #[test]
+    fn test_declared_adjustment_without_choke_data() {
+        let cfg = MpdConfig::default();
+        let mut tracker = ChokeAdjustmentTracker::new();
+        tracker.update(1000, 0.0, &cfg);
+        assert!(!tracker.is_contextualizing());
+
+        let adj = tracker.declare(1001, &cfg);
+        assert_eq!(adj.source, ChokeAdjustmentSource::Declared);
+        tracker.update(1002, 0.0, &cfg);
+        assert!(tracker.is_contextualizing());
+        tracker.update(1001 + cfg.context_secs, 0.0, &cfg);
+        assert!(!tracker.is_contextualizing());
+    }
+}
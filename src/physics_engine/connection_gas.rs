@@ -11,6 +11,10 @@
 //! 3. During connection: track peak gas
 //! 4. non-Drilling → Drilling transition: finalize event with post-connection gas
 //! 5. Compute delta and trend across multiple events
+//!
+//! Each event also records the connection duration and the standpipe
+//! pressure before and during the connection, so connection practices can be
+//! scored from the same detection (see `connection_scorecard`).
 
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -25,21 +29,39 @@ const BACKGROUND_GAS_ALPHA: f64 = 0.05;
 
 /// Connection state machine
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 enum ConnectionState {
     /// Actively drilling — tracking background gas
     Drilling {
         last_gas: f64,
+        last_spp: f64,
+        last_flow: f64,
     },
-    /// In a connection (non-drilling) — tracking peak gas
+    /// In a connection (non-drilling) — tracking peak gas and SPP
     InConnection {
         pre_gas: f64,
         peak_gas: f64,
+        pre_spp: f64,
+        peak_spp: f64,
+        pre_flow: f64,
         start_ts: u64,
         depth_ft: f64,
+        saw_connection: bool,
     },
 }
 
+/// Drilling values captured when the connection in progress started
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionStart {
+    /// Unix timestamp when drilling stopped
+    pub start_ts: u64,
+    /// Bit depth at time of connection (ft)
+    pub depth_ft: f64,
+    /// Drilling SPP just before the connection (psi)
+    pub pre_spp: f64,
+    /// Drilling flow-in just before the connection (gpm)
+    pub pre_flow: f64,
+}
+
 /// A single connection gas event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectionGasEvent {
@@ -57,6 +79,20 @@ pub struct ConnectionGasEvent {
     pub delta: f64,
     /// Delta above background gas (units)
     pub above_background: f64,
+    /// Seconds from drilling stopping to drilling resuming
+    #[serde(default)]
+    pub duration_secs: u64,
+    /// Drilling SPP just before the connection (psi)
+    #[serde(default)]
+    pub pre_spp: f64,
+    /// Peak SPP during the connection, typically on pump restart (psi)
+    #[serde(default)]
+    pub peak_spp: f64,
+    /// Whether the rig was in `RigState::Connection` at any point during the
+    /// break. Trips and circulating breaks also stop drilling but are not
+    /// pipe connections.
+    #[serde(default)]
+    pub rig_connection: bool,
 }
 
 /// Tracks connection gas events and trends
@@ -77,7 +113,11 @@ impl Default for ConnectionGasTracker {
 impl ConnectionGasTracker {
     pub fn new() -> Self {
         Self {
-            state: ConnectionState::Drilling { last_gas: 0.0 },
+            state: ConnectionState::Drilling {
+                last_gas: 0.0,
+                last_spp: 0.0,
+                last_flow: 0.0,
+            },
             events: VecDeque::with_capacity(MAX_EVENTS),
             background_gas: 0.0,
             background_initialized: false,
@@ -93,13 +133,22 @@ impl ConnectionGasTracker {
         rig_state: RigState,
     ) -> Option<ConnectionGasEvent> {
         let gas = packet.gas_units;
-        let is_drilling = rig_state == RigState::Drilling || rig_state == RigState::Reaming;
+        let is_drilling = matches!(
+            rig_state,
+            RigState::Drilling | RigState::Sliding | RigState::Reaming
+        );
 
         match &mut self.state {
-            ConnectionState::Drilling { last_gas } => {
+            ConnectionState::Drilling {
+                last_gas,
+                last_spp,
+                last_flow,
+            } => {
                 if is_drilling {
                     // Still drilling — update background gas EMA
                     *last_gas = gas;
+                    *last_spp = packet.spp;
+                    *last_flow = packet.flow_in;
                     if !self.background_initialized {
                         self.background_gas = gas;
                         self.background_initialized = true;
@@ -111,11 +160,17 @@ impl ConnectionGasTracker {
                 } else {
                     // Drilling → Connection: record pre-connection gas
                     let pre_gas = *last_gas;
+                    let pre_spp = *last_spp;
+                    let pre_flow = *last_flow;
                     self.state = ConnectionState::InConnection {
                         pre_gas,
                         peak_gas: gas,
+                        pre_spp,
+                        peak_spp: packet.spp,
+                        pre_flow,
                         start_ts: packet.timestamp,
                         depth_ft: packet.bit_depth,
+                        saw_connection: rig_state == RigState::Connection,
                     };
                     None
                 }
@@ -123,14 +178,22 @@ impl ConnectionGasTracker {
             ConnectionState::InConnection {
                 pre_gas,
                 peak_gas,
+                pre_spp,
+                peak_spp,
+                start_ts,
                 depth_ft,
+                saw_connection,
                 ..
             } => {
                 if !is_drilling {
-                    // Still in connection — track peak gas
+                    // Still in connection — track peak gas and SPP
+                    *saw_connection |= rig_state == RigState::Connection;
                     if gas > *peak_gas {
                         *peak_gas = gas;
                     }
+                    if packet.spp > *peak_spp {
+                        *peak_spp = packet.spp;
+                    }
                     None
                 } else {
                     // Connection → Drilling: finalize event
@@ -142,6 +205,10 @@ impl ConnectionGasTracker {
                         post_gas: gas,
                         delta: *peak_gas - *pre_gas,
                         above_background: (*peak_gas - self.background_gas).max(0.0),
+                        duration_secs: packet.timestamp.saturating_sub(*start_ts),
+                        pre_spp: *pre_spp,
+                        peak_spp: *peak_spp,
+                        rig_connection: *saw_connection,
                     };
 
                     // Store event
@@ -151,7 +218,11 @@ impl ConnectionGasTracker {
                     self.events.push_back(event.clone());
 
                     // Return to drilling state
-                    self.state = ConnectionState::Drilling { last_gas: gas };
+                    self.state = ConnectionState::Drilling {
+                        last_gas: gas,
+                        last_spp: packet.spp,
+                        last_flow: packet.flow_in,
+                    };
 
                     Some(event)
                 }
@@ -159,6 +230,25 @@ impl ConnectionGasTracker {
         }
     }
 
+    /// The connection in progress, if drilling has stopped
+    pub fn connection_in_progress(&self) -> Option<ConnectionStart> {
+        match self.state {
+            ConnectionState::Drilling { .. } => None,
+            ConnectionState::InConnection {
+                pre_spp,
+                pre_flow,
+                start_ts,
+                depth_ft,
+                ..
+            } => Some(ConnectionStart {
+                start_ts,
+                depth_ft,
+                pre_spp,
+                pre_flow,
+            }),
+        }
+    }
+
     /// Compute linear regression slope on event deltas.
     /// Positive slope = connection gas increasing over successive connections.
     pub fn trend_slope(&self) -> f64 {
@@ -251,6 +341,46 @@ mod tests {
         assert_eq!(ev.peak_gas, 50.0);
         assert_eq!(ev.post_gas, 22.0);
         assert_eq!(ev.delta, 30.0);
+        assert_eq!(ev.duration_secs, 3);
+        assert!(ev.rig_connection);
+    }
+
+    #[test]
+    fn test_trip_break_is_not_a_rig_connection() {
+        let mut tracker = ConnectionGasTracker::new();
+        tracker.update(&make_packet(0, 5000.0, 20.0), RigState::Drilling);
+        for (ts, state) in [
+            (1, RigState::Circulating),
+            (2, RigState::TrippingOut),
+            (3, RigState::TrippingIn),
+            (4, RigState::Circulating),
+        ] {
+            assert!(tracker
+                .update(&make_packet(ts, 5000.0, 20.0), state)
+                .is_none());
+        }
+        let ev = tracker
+            .update(&make_packet(5, 5000.0, 20.0), RigState::Drilling)
+            .expect("drilling resumed");
+        assert!(!ev.rig_connection);
+    }
+
+    #[test]
+    fn test_sliding_counts_as_drilling() {
+        let mut tracker = ConnectionGasTracker::new();
+        tracker.update(&make_packet(0, 5000.0, 20.0), RigState::Drilling);
+        // Rotary to slide is not a connection
+        assert!(tracker
+            .update(&make_packet(1, 5000.0, 20.0), RigState::Sliding)
+            .is_none());
+        assert!(tracker.connection_in_progress().is_none());
+
+        tracker.update(&make_packet(2, 5000.0, 40.0), RigState::Connection);
+        let ev = tracker
+            .update(&make_packet(3, 5000.0, 21.0), RigState::Sliding)
+            .expect("sliding ends the connection");
+        assert_eq!(ev.peak_gas, 40.0);
+        assert!(ev.rig_connection);
     }
 
     #[test]
@@ -0,0 +1,268 @@
+//! Connection-Practices Scorecard
+//!
+//! Connection quality reflects crew technique. Using the connections found by
+//! `ConnectionGasTracker` that went through `RigState::Connection` (trips and
+//! circulating breaks are skipped), each connection is measured on:
+//!
+//! - **Duration**: drilling-stop to drilling-resume time. Scored on
+//!   consistency (coefficient of variation across connections).
+//! - **Gas control**: peak gas above the pre-connection reading.
+//! - **Pressure management**: SPP overshoot on pump restart, as a percentage
+//!   of the pre-connection drilling SPP.
+//!
+//! Each category scores 0-100 (100 = perfect, 0 = at or beyond the configured
+//! limit) and the overall score is their mean. A connection is an outlier when
+//! any metric exceeds `outlier_ratio` × the median of that metric.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConnectionScorecardConfig;
+use crate::physics_engine::connection_gas::ConnectionGasEvent;
+
+/// One connection measured for the scorecard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionScore {
+    /// Unix timestamp when drilling resumed
+    pub timestamp: u64,
+    pub depth_ft: f64,
+    pub duration_secs: u64,
+    /// Peak gas above pre-connection gas (units)
+    pub gas_delta: f64,
+    /// SPP overshoot above pre-connection drilling SPP (%)
+    pub spp_overshoot_pct: f64,
+    /// Metrics that made this connection an outlier (empty = not an outlier)
+    pub outlier_reasons: Vec<String>,
+}
+
+impl ConnectionScore {
+    pub fn is_outlier(&self) -> bool {
+        !self.outlier_reasons.is_empty()
+    }
+}
+
+/// Aggregate connection-practices scorecard
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConnectionScorecard {
+    pub connections: usize,
+    pub mean_duration_secs: f64,
+    pub median_duration_secs: f64,
+    /// Coefficient of variation of connection durations
+    pub duration_cv: f64,
+    pub mean_gas_delta: f64,
+    pub max_gas_delta: f64,
+    pub mean_spp_overshoot_pct: f64,
+    pub max_spp_overshoot_pct: f64,
+    /// Duration consistency score (0-100)
+    pub duration_score: f64,
+    /// Gas control score (0-100)
+    pub gas_score: f64,
+    /// Pressure management score (0-100)
+    pub pressure_score: f64,
+    /// Mean of the category scores (0-100)
+    pub overall_score: f64,
+    /// Per-connection measurements, oldest first
+    pub scored: Vec<ConnectionScore>,
+    /// Indices into `scored` of outlier connections
+    pub outliers: Vec<usize>,
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len();
+    if n == 0 {
+        0.0
+    } else if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+/// Linear 0-100 score: 100 at zero, 0 at or beyond `limit`
+fn score_against(value: f64, limit: f64) -> f64 {
+    if limit <= 0.0 {
+        return 100.0;
+    }
+    (100.0 * (1.0 - value / limit)).clamp(0.0, 100.0)
+}
+
+/// Measure a single connection
+fn measure(event: &ConnectionGasEvent) -> ConnectionScore {
+    let spp_overshoot_pct = if event.pre_spp > 0.0 {
+        (event.peak_spp - event.pre_spp).max(0.0) / event.pre_spp * 100.0
+    } else {
+        0.0
+    };
+    ConnectionScore {
+        timestamp: event.timestamp,
+        depth_ft: event.depth_ft,
+        duration_secs: event.duration_secs,
+        gas_delta: event.delta.max(0.0),
+        spp_overshoot_pct,
+        outlier_reasons: Vec::new(),
+    }
+}
+
+/// Build a scorecard from detected connections (oldest first).
+pub fn build_scorecard(
+    events: &[ConnectionGasEvent],
+    cfg: &ConnectionScorecardConfig,
+) -> ConnectionScorecard {
+    let mut scored: Vec<ConnectionScore> = events.iter().map(measure).collect();
+    if scored.is_empty() {
+        return ConnectionScorecard::default();
+    }
+
+    let durations: Vec<f64> = scored.iter().map(|c| c.duration_secs as f64).collect();
+    let gas: Vec<f64> = scored.iter().map(|c| c.gas_delta).collect();
+    let overshoot: Vec<f64> = scored.iter().map(|c| c.spp_overshoot_pct).collect();
+
+    let mean_duration_secs = mean(&durations);
+    let duration_cv = if mean_duration_secs > 0.0 {
+        let variance = durations
+            .iter()
+            .map(|d| (d - mean_duration_secs).powi(2))
+            .sum::<f64>()
+            / durations.len() as f64;
+        variance.sqrt() / mean_duration_secs
+    } else {
+        0.0
+    };
+    let mean_gas_delta = mean(&gas);
+    let mean_spp_overshoot_pct = mean(&overshoot);
+
+    // Floors keep a near-zero median from flagging trivial differences
+    let median_duration_secs = median(&durations);
+    let limits = [
+        ("duration", median_duration_secs.max(1.0), "s"),
+        ("gas", median(&gas).max(1.0), " units"),
+        ("spp_overshoot", median(&overshoot).max(1.0), "%"),
+    ];
+    let mut outliers = Vec::new();
+    for (i, conn) in scored.iter_mut().enumerate() {
+        let values = [
+            conn.duration_secs as f64,
+            conn.gas_delta,
+            conn.spp_overshoot_pct,
+        ];
+        for ((name, typical, unit), value) in limits.iter().zip(values) {
+            if value > cfg.outlier_ratio * typical {
+                conn.outlier_reasons.push(format!(
+                    "{} {:.0}{} vs median {:.0}{}",
+                    name, value, unit, typical, unit
+                ));
+            }
+        }
+        if conn.is_outlier() {
+            outliers.push(i);
+        }
+    }
+
+    let duration_score = score_against(duration_cv, cfg.duration_cv_limit);
+    let gas_score = score_against(mean_gas_delta, cfg.gas_delta_limit_units);
+    let pressure_score = score_against(mean_spp_overshoot_pct, cfg.spp_overshoot_limit_pct);
+
+    ConnectionScorecard {
+        connections: scored.len(),
+        mean_duration_secs,
+        median_duration_secs,
+        duration_cv,
+        mean_gas_delta,
+        max_gas_delta: gas.iter().copied().fold(0.0, f64::max),
+        mean_spp_overshoot_pct,
+        max_spp_overshoot_pct: overshoot.iter().copied().fold(0.0, f64::max),
+        duration_score,
+        gas_score,
+        pressure_score,
+        overall_score: (duration_score + gas_score + pressure_score) / 3.0,
+        scored,
+        outliers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection(
+        ts: u64,
+        duration_secs: u64,
+        gas_delta: f64,
+        peak_spp: f64,
+    ) -> ConnectionGasEvent {
+        ConnectionGasEvent {
+            timestamp: ts,
+            depth_ft: 8000.0 + ts as f64 / 10.0,
+            pre_gas: 20.0,
+            peak_gas: 20.0 + gas_delta,
+            post_gas: 20.0,
+            delta: gas_delta,
+            above_background: gas_delta,
+            duration_secs,
+            pre_spp: 3000.0,
+            peak_spp,
+            rig_connection: true,
+        }
+    }
+
+    #[test]
+    fn test_scorecard_aggregates_and_flags_outlier() {
+        let cfg = ConnectionScorecardConfig::default();
+        let events = vec![
+            connection(1000, 120, 4.0, 3090.0),
+            connection(2000, 130, 6.0, 3150.0),
+            connection(3000, 110, 5.0, 3060.0),
+            // Slow connection with a gas spike and a hard pump restart
+            connection(4000, 420, 30.0, 3600.0),
+            connection(5000, 120, 5.0, 3120.0),
+        ];
+
+        let card = build_scorecard(&events, &cfg);
+        assert_eq!(card.connections, 5);
+        assert!((card.mean_duration_secs - 180.0).abs() < 1e-9);
+        assert_eq!(card.median_duration_secs, 120.0);
+        assert!((card.mean_gas_delta - 10.0).abs() < 1e-9);
+        assert_eq!(card.max_gas_delta, 30.0);
+        // Overshoots: 3%, 5%, 2%, 20%, 4%
+        assert!((card.mean_spp_overshoot_pct - 6.8).abs() < 1e-9);
+        assert!((card.max_spp_overshoot_pct - 20.0).abs() < 1e-9);
+
+        assert_eq!(card.outliers, vec![3]);
+        let outlier = &card.scored[3];
+        assert_eq!(outlier.outlier_reasons.len(), 3);
+        assert!(outlier.outlier_reasons[0].starts_with("duration 420s"));
+
+        // The outlier drags every category below a clean crew's score
+        let clean = build_scorecard(
+            &events
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != 3)
+                .map(|(_, e)| e.clone())
+                .collect::<Vec<_>>(),
+            &cfg,
+        );
+        assert!(clean.outliers.is_empty());
+        assert!(card.duration_score < clean.duration_score);
+        assert!(card.gas_score < clean.gas_score);
+        assert!(card.pressure_score < clean.pressure_score);
+        assert!(card.overall_score < clean.overall_score);
+        assert!(clean.overall_score > 80.0);
+    }
+
+    #[test]
+    fn test_empty_scorecard() {
+        let card = build_scorecard(&[], &ConnectionScorecardConfig::default());
+        assert_eq!(card.connections, 0);
+        assert!(card.outliers.is_empty());
+    }
+}
@@ -0,0 +1,403 @@
+//! Directional Drilling: Slide / Rotate / Survey Tracking
+//!
+//! Steerable-motor wells alternate rotating and sliding (orienting the bend
+//! with the string held still), and stop for surveys along the way. Slides
+//! are classified as `RigState::Sliding` by `classify_rig_state`, which keeps
+//! MSE, d-exponent and efficiency logic rotary-only; this tracker follows the
+//! sequence on top of that:
+//!
+//! - **Slides**: contiguous sliding (brief pick-ups to re-orient tolerated
+//!   for `SLIDE_BREAK_SECS`) becomes one `SlideSegment`. Its mean ROP is
+//!   compared with `slide_rop_ratio` × the mean ROP of the last
+//!   `rotating_rop_window` rotating packets.
+//! - **Survey stations**: no rotation, pumps on, bit within
+//!   `survey_max_off_bottom_ft` of bottom and held within
+//!   `survey_max_pipe_movement_ft` for `survey_min_secs`..`survey_max_secs`.
+//! - **Footage**: hole made while sliding vs rotating.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::DirectionalConfig;
+use crate::types::{RigState, WitsPacket};
+
+/// Sliding gap tolerated before a slide is closed (s)
+const SLIDE_BREAK_SECS: u64 = 60;
+
+/// Hole-depth advance per packet above this is a depth glitch, not footage (ft)
+const MAX_FOOTAGE_STEP_FT: f64 = 10.0;
+
+/// Current directional drilling mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DrillingMode {
+    Rotating,
+    Sliding,
+    Survey,
+    /// Anything else (connection, tripping, reaming, off bottom)
+    #[default]
+    Other,
+}
+
+/// A completed slide
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlideSegment {
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub start_depth_ft: f64,
+    pub end_depth_ft: f64,
+    pub footage_ft: f64,
+    /// Mean ROP over the slide (ft/hr)
+    pub rop_ft_hr: f64,
+    /// `slide_rop_ratio` × rotating ROP at slide start (`None` before any
+    /// rotating reference)
+    pub expected_rop_ft_hr: Option<f64>,
+    /// rop_ft_hr / expected_rop_ft_hr
+    pub rop_ratio: Option<f64>,
+    pub below_expectation: bool,
+}
+
+/// A detected survey station
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyStation {
+    pub start_ts: u64,
+    pub duration_secs: u64,
+    pub bit_depth_ft: f64,
+    pub hole_depth_ft: f64,
+}
+
+/// Completed slide or survey station
+#[derive(Debug, Clone)]
+pub enum DirectionalEvent {
+    SlideCompleted(SlideSegment),
+    SurveyStation(SurveyStation),
+}
+
+/// Slide / rotate / survey snapshot (`GET /api/v2/drilling/directional`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalSummary {
+    pub mode: DrillingMode,
+    pub slide_footage_ft: f64,
+    pub rotate_footage_ft: f64,
+    /// Share of footage made sliding
+    pub slide_fraction: f64,
+    /// Mean ROP over the rotating window (ft/hr)
+    pub rotating_rop_ft_hr: Option<f64>,
+    pub expected_slide_rop_ft_hr: Option<f64>,
+    /// Completed slides, oldest first
+    pub slides: Vec<SlideSegment>,
+    /// Survey stations, oldest first
+    pub surveys: Vec<SurveyStation>,
+}
+
+#[derive(Debug, Clone)]
+struct ActiveSlide {
+    start_ts: u64,
+    last_ts: u64,
+    start_depth_ft: f64,
+    end_depth_ft: f64,
+    rop_sum: f64,
+    samples: u32,
+    expected_rop: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+struct Stationary {
+    start_ts: u64,
+    last_ts: u64,
+    bit_depth_ft: f64,
+    hole_depth_ft: f64,
+}
+
+impl Stationary {
+    fn duration(&self) -> u64 {
+        self.last_ts.saturating_sub(self.start_ts)
+    }
+}
+
+/// Tracks slides, rotating ROP and survey stations
+#[derive(Debug, Clone, Default)]
+pub struct DirectionalTracker {
+    mode: DrillingMode,
+    rotating_rops: VecDeque<f64>,
+    slide: Option<ActiveSlide>,
+    station: Option<Stationary>,
+    prev_hole_depth: Option<f64>,
+    slide_footage_ft: f64,
+    rotate_footage_ft: f64,
+    slides: VecDeque<SlideSegment>,
+    surveys: VecDeque<SurveyStation>,
+}
+
+fn push_bounded<T>(log: &mut VecDeque<T>, item: T, max: usize) {
+    log.push_back(item);
+    while log.len() > max.max(1) {
+        log.pop_front();
+    }
+}
+
+impl DirectionalTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mode(&self) -> DrillingMode {
+        self.mode
+    }
+
+    /// Mean ROP over the rotating window
+    pub fn rotating_rop(&self) -> Option<f64> {
+        if self.rotating_rops.is_empty() {
+            return None;
+        }
+        Some(self.rotating_rops.iter().sum::<f64>() / self.rotating_rops.len() as f64)
+    }
+
+    /// Expected slide ROP from the current rotating reference
+    pub fn expected_slide_rop(&self, cfg: &DirectionalConfig) -> Option<f64> {
+        self.rotating_rop().map(|rop| rop * cfg.slide_rop_ratio)
+    }
+
+    /// Update with one packet and its classified rig state. Returns slides
+    /// and survey stations completed on this packet.
+    pub fn update(
+        &mut self,
+        packet: &WitsPacket,
+        state: RigState,
+        cfg: &DirectionalConfig,
+    ) -> Vec<DirectionalEvent> {
+        let mut events = Vec::new();
+        if !cfg.enabled {
+            self.mode = DrillingMode::Other;
+            return events;
+        }
+
+        // Footage by mode
+        let advance = self
+            .prev_hole_depth
+            .map(|prev| packet.hole_depth - prev)
+            .filter(|d| *d > 0.0 && *d < MAX_FOOTAGE_STEP_FT)
+            .unwrap_or(0.0);
+        self.prev_hole_depth = Some(packet.hole_depth);
+        match state {
+            RigState::Drilling => {
+                self.rotate_footage_ft += advance;
+                push_bounded(&mut self.rotating_rops, packet.rop, cfg.rotating_rop_window);
+            }
+            RigState::Sliding => self.slide_footage_ft += advance,
+            _ => {}
+        }
+
+        // Survey stations
+        let on_station = state == RigState::Connection
+            && packet.hole_depth - packet.bit_depth <= cfg.survey_max_off_bottom_ft;
+        let moved = self.station.as_ref().is_some_and(|s| {
+            (packet.bit_depth - s.bit_depth_ft).abs() > cfg.survey_max_pipe_movement_ft
+        });
+        if !on_station || moved {
+            events.extend(self.close_station(cfg).map(DirectionalEvent::SurveyStation));
+        }
+        if on_station {
+            let station = self.station.get_or_insert(Stationary {
+                start_ts: packet.timestamp,
+                last_ts: packet.timestamp,
+                bit_depth_ft: packet.bit_depth,
+                hole_depth_ft: packet.hole_depth,
+            });
+            station.last_ts = packet.timestamp;
+        }
+
+        // Slides
+        if state == RigState::Sliding {
+            let expected_rop = self.expected_slide_rop(cfg);
+            let slide = self.slide.get_or_insert(ActiveSlide {
+                start_ts: packet.timestamp,
+                last_ts: packet.timestamp,
+                start_depth_ft: packet.hole_depth,
+                end_depth_ft: packet.hole_depth,
+                rop_sum: 0.0,
+                samples: 0,
+                expected_rop,
+            });
+            slide.last_ts = packet.timestamp;
+            slide.end_depth_ft = packet.hole_depth;
+            slide.rop_sum += packet.rop;
+            slide.samples += 1;
+        } else if self.slide.as_ref().is_some_and(|s| {
+            state == RigState::Drilling
+                || packet.timestamp.saturating_sub(s.last_ts) > SLIDE_BREAK_SECS
+        }) {
+            events.extend(self.close_slide(cfg).map(DirectionalEvent::SlideCompleted));
+        }
+
+        self.mode = match state {
+            RigState::Drilling => DrillingMode::Rotating,
+            RigState::Sliding => DrillingMode::Sliding,
+            _ if self.station.as_ref().is_some_and(|s| {
+                (cfg.survey_min_secs..=cfg.survey_max_secs).contains(&s.duration())
+            }) =>
+            {
+                DrillingMode::Survey
+            }
+            _ => DrillingMode::Other,
+        };
+        events
+    }
+
+    fn close_station(&mut self, cfg: &DirectionalConfig) -> Option<SurveyStation> {
+        let station = self.station.take()?;
+        let duration = station.duration();
+        if !(cfg.survey_min_secs..=cfg.survey_max_secs).contains(&duration) {
+            return None;
+        }
+        let survey = SurveyStation {
+            start_ts: station.start_ts,
+            duration_secs: duration,
+            bit_depth_ft: station.bit_depth_ft,
+            hole_depth_ft: station.hole_depth_ft,
+        };
+        push_bounded(&mut self.surveys, survey.clone(), cfg.max_logged);
+        Some(survey)
+    }
+
+    fn close_slide(&mut self, cfg: &DirectionalConfig) -> Option<SlideSegment> {
+        let slide = self.slide.take()?;
+        let rop = slide.rop_sum / f64::from(slide.samples.max(1));
+        let rop_ratio = slide
+            .expected_rop
+            .filter(|e| *e > 0.0)
+            .map(|expected| rop / expected);
+        let segment = SlideSegment {
+            start_ts: slide.start_ts,
+            end_ts: slide.last_ts,
+            start_depth_ft: slide.start_depth_ft,
+            end_depth_ft: slide.end_depth_ft,
+            footage_ft: (slide.end_depth_ft - slide.start_depth_ft).max(0.0),
+            rop_ft_hr: rop,
+            expected_rop_ft_hr: slide.expected_rop,
+            rop_ratio,
+            below_expectation: rop_ratio.is_some_and(|r| r < 1.0),
+        };
+        push_bounded(&mut self.slides, segment.clone(), cfg.max_logged);
+        Some(segment)
+    }
+
+    pub fn summary(&self, cfg: &DirectionalConfig) -> DirectionalSummary {
+        let total = self.slide_footage_ft + self.rotate_footage_ft;
+        DirectionalSummary {
+            mode: self.mode,
+            slide_footage_ft: self.slide_footage_ft,
+            rotate_footage_ft: self.rotate_footage_ft,
+            slide_fraction: if total > 0.0 {
+                self.slide_footage_ft / total
+            } else {
+                0.0
+            },
+            rotating_rop_ft_hr: self.rotating_rop(),
+            expected_slide_rop_ft_hr: self.expected_slide_rop(cfg),
+            slides: self.slides.iter().cloned().collect(),
+            surveys: self.surveys.iter().cloned().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ensure_config() {
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+    }
+
+    /// One packet 1 s apart; depth advances at `rop`
+    fn packet(ts: u64, depth: f64, rpm: f64, wob: f64, rop: f64) -> WitsPacket {
+        WitsPacket {
+            timestamp: ts,
+            bit_depth: depth,
+            hole_depth: depth,
+            rpm,
+            wob,
+            rop,
+            flow_in: 500.0,
+            flow_out: 500.0,
+            ..WitsPacket::default()
+        }
+    }
+
+    #[test]
+    fn test_slide_between_rotating_intervals_is_tracked() {
+        ensure_config();
+        let cfg = DirectionalConfig::default();
+        let mut tracker = DirectionalTracker::new();
+        let mut depth = 9000.0;
+        let mut events = Vec::new();
+        let mut step = |tracker: &mut DirectionalTracker, ts: u64, rpm: f64, rop: f64| {
+            depth += rop / 3600.0;
+            let p = packet(ts, depth, rpm, 20.0, rop);
+            let state = crate::physics_engine::classify_rig_state(&p);
+            events.extend(tracker.update(&p, state, &cfg));
+            state
+        };
+
+        // Rotate at 100 ft/hr, slide at 30 ft/hr, rotate again
+        for ts in 0..300 {
+            assert_eq!(step(&mut tracker, ts, 120.0, 100.0), RigState::Drilling);
+        }
+        assert_eq!(tracker.mode(), DrillingMode::Rotating);
+        for ts in 300..900 {
+            assert_eq!(step(&mut tracker, ts, 0.0, 30.0), RigState::Sliding);
+        }
+        assert_eq!(tracker.mode(), DrillingMode::Sliding);
+        for ts in 900..960 {
+            step(&mut tracker, ts, 120.0, 100.0);
+        }
+
+        assert_eq!(events.len(), 1);
+        let DirectionalEvent::SlideCompleted(slide) = &events[0] else {
+            panic!("expected a completed slide");
+        };
+        assert_eq!((slide.start_ts, slide.end_ts), (300, 899));
+        assert!((slide.rop_ft_hr - 30.0).abs() < 1e-9);
+        assert!((slide.expected_rop_ft_hr.unwrap() - 50.0).abs() < 1e-9);
+        assert!(slide.below_expectation);
+        assert!((slide.footage_ft - 5.0).abs() < 0.01);
+
+        let summary = tracker.summary(&cfg);
+        assert!((summary.slide_footage_ft - 5.0).abs() < 0.01);
+        assert!(summary.rotate_footage_ft > 9.0);
+        assert_eq!(summary.slides.len(), 1);
+    }
+
+    #[test]
+    fn test_stationary_circulating_off_bottom_is_survey_station() {
+        ensure_config();
+        let cfg = DirectionalConfig::default();
+        let mut tracker = DirectionalTracker::new();
+        let mut events = Vec::new();
+
+        // Picked up 45 ft off bottom, pumps on, no rotation for 90 s
+        for ts in 0..90 {
+            let mut p = packet(ts, 9955.0, 0.0, 0.0, 0.0);
+            p.hole_depth = 10000.0;
+            events.extend(tracker.update(&p, RigState::Connection, &cfg));
+        }
+        assert_eq!(tracker.mode(), DrillingMode::Survey);
+        // Back to bottom, rotating
+        let p = packet(90, 10000.0, 120.0, 20.0, 100.0);
+        events.extend(tracker.update(&p, RigState::Drilling, &cfg));
+
+        assert_eq!(events.len(), 1);
+        let DirectionalEvent::SurveyStation(survey) = &events[0] else {
+            panic!("expected a survey station");
+        };
+        assert_eq!(survey.duration_secs, 89);
+        assert_eq!(survey.bit_depth_ft, 9955.0);
+        assert_eq!(tracker.summary(&cfg).surveys.len(), 1);
+    }
+}
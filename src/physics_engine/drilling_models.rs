@@ -4,10 +4,16 @@
 //! - MSE (Mechanical Specific Energy)
 //! - D-exponent and corrected dxc
 //! - Kick/loss detection
+//! - Swab/surge detection while tripping
 //! - Pack-off and stick-slip detection
+//! - Washout (drillstring leak) detection
+//! - Bit wear (dull grade) estimation
 //! - Formation change detection
 
-use crate::types::{DrillingPhysicsReport, HistoryEntry, RigState, WitsPacket};
+use super::bit_run::{BitRunBaseline, BIT_RUN_BASELINE_SAMPLES};
+use crate::types::{
+    AnomalyCategory, BitWearEstimate, DrillingPhysicsReport, HistoryEntry, RigState, WitsPacket,
+};
 
 // ============================================================================
 // MSE (Mechanical Specific Energy) Calculations
@@ -325,6 +331,111 @@ pub fn detect_lost_circulation(
     (is_loss, final_severity)
 }
 
+/// Detect swab (pulling out) or surge (running in) pressure while tripping
+///
+/// Pipe velocity is the bit depth change over the packet interval; the
+/// pressure change comes from the Burkhardt estimate in
+/// [`super::swab_surge`] using the `[trip_parameters]` geometry and
+/// rheology. Swab is checked against the pore pressure margin (kick risk),
+/// surge against the fracture gradient margin (loss risk). Pore and fracture
+/// gradients come from the packet, falling back to `[thresholds.hydraulics]`.
+///
+/// Returns `(category, description, margin_ppg)` when the margin is inside
+/// the `[thresholds.tripping]` warning band; `None` outside tripping, for
+/// slow pipe, or without a mud weight.
+pub fn detect_swab_surge(
+    prev: &WitsPacket,
+    cur: &WitsPacket,
+    state: &RigState,
+) -> Option<(AnomalyCategory, String, f64)> {
+    if *state != RigState::TrippingIn && *state != RigState::TrippingOut {
+        return None;
+    }
+    let dt_secs = cur.timestamp.saturating_sub(prev.timestamp) as f64;
+    if dt_secs <= 0.0 || cur.mud_weight_in <= 0.0 {
+        return None;
+    }
+    let cfg = crate::config::get();
+    let tr = &cfg.thresholds.tripping;
+    let hyd = &cfg.thresholds.hydraulics;
+    let trip = &cfg.trip_parameters;
+
+    // Positive = running in, negative = pulling out
+    let velocity_ft_min = (cur.bit_depth - prev.bit_depth) / dt_secs * 60.0;
+    let speed = velocity_ft_min.abs();
+    if speed < tr.pipe_velocity_threshold_ft_min {
+        return None;
+    }
+    let running_in = velocity_ft_min > 0.0;
+
+    let pore_ppg = if cur.pore_pressure > 0.0 {
+        cur.pore_pressure
+    } else if hyd.pore_pressure_ppg > 0.0 {
+        hyd.pore_pressure_ppg
+    } else {
+        hyd.normal_mud_weight_ppg
+    };
+    let frac_ppg = if cur.fracture_gradient > 0.0 {
+        cur.fracture_gradient
+    } else {
+        hyd.fracture_gradient_ppg
+    };
+    let hole_dia = if trip.hole_diameter_inches > 0.0 {
+        trip.hole_diameter_inches
+    } else {
+        cfg.well.bit_diameter_inches
+    };
+
+    let estimate = super::swab_surge::estimate_swab_surge(
+        speed,
+        cur.bit_depth,
+        cur.mud_weight_in,
+        pore_ppg,
+        frac_ppg,
+        trip.pipe_od_inches,
+        hole_dia,
+        trip.plastic_viscosity_cp,
+        trip.yield_point_lbf_100sqft,
+        running_in,
+    );
+
+    let (margin, warning, critical) = if running_in {
+        (
+            estimate.margin_to_frac_gradient_ppg,
+            tr.surge_margin_warning_ppg,
+            tr.surge_margin_critical_ppg,
+        )
+    } else {
+        (
+            estimate.margin_to_pore_pressure_ppg,
+            tr.swab_margin_warning_ppg,
+            tr.swab_margin_critical_ppg,
+        )
+    };
+    let severity_str = if margin < critical {
+        "CRITICAL"
+    } else if margin < warning {
+        "WARNING"
+    } else {
+        return None;
+    };
+
+    let description = if running_in {
+        format!(
+            "{}: Surge risk - running in at {:.0} ft/min, EMW {:.2} ppg only {:.2} ppg \
+             below fracture gradient ({:.2} ppg). Slow the trip.",
+            severity_str, speed, estimate.equivalent_mud_weight_ppg, margin, frac_ppg
+        )
+    } else {
+        format!(
+            "{}: Swab risk - pulling out at {:.0} ft/min, EMW {:.2} ppg only {:.2} ppg \
+             over pore pressure ({:.2} ppg). Slow the trip.",
+            severity_str, speed, estimate.equivalent_mud_weight_ppg, margin, pore_ppg
+        )
+    };
+    Some((AnomalyCategory::WellControl, description, margin))
+}
+
 // ============================================================================
 // Mechanical Problem Detection
 // ============================================================================
@@ -384,6 +495,186 @@ pub fn detect_packoff(
     (is_packoff, final_severity)
 }
 
+/// Minimum pumping samples for a washout trend
+const WASHOUT_MIN_SAMPLES: usize = 30;
+/// Minimum fit quality for the SPP decline to count as a trend, not noise
+const WASHOUT_MIN_R_SQUARED: f64 = 0.5;
+
+/// Detect a drillstring washout from SPP / flow divergence
+///
+/// A washout bypasses part of the flow around the bit, so SPP bleeds off
+/// gradually while pump SPM and flow-in hold steady. Fits a trend of SPP
+/// normalized by flow-in over the `Drilling`/`Sliding`/`Circulating` packets
+/// in the history window.
+///
+/// Returns (severity_factor, description) when the fitted decline exceeds
+/// `thresholds.mechanical.washout_spp_decline_percent` with steady pumps.
+pub fn detect_washout(history: &[HistoryEntry]) -> Option<(f64, String)> {
+    let pumping = |state: RigState| {
+        matches!(
+            state,
+            RigState::Drilling | RigState::Sliding | RigState::Circulating
+        )
+    };
+    if !history.last().is_some_and(|e| pumping(e.metrics.state)) {
+        return None;
+    }
+    let entries: Vec<&WitsPacket> = history
+        .iter()
+        .filter(|e| pumping(e.metrics.state))
+        .map(|e| &e.packet)
+        .filter(|p| p.spp > 0.0 && p.flow_in > 0.0 && p.pump_spm > 0.0)
+        .collect();
+    if entries.len() < WASHOUT_MIN_SAMPLES {
+        return None;
+    }
+
+    let cfg = &crate::config::get().thresholds.mechanical;
+
+    // Pumps must be steady, otherwise the SPP change is just the pump schedule
+    let spread_percent = |values: Vec<f64>| {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let (min, max) = values
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+        (max - min) / mean * 100.0
+    };
+    let spm_spread = spread_percent(entries.iter().map(|p| p.pump_spm).collect());
+    let flow_spread = spread_percent(entries.iter().map(|p| p.flow_in).collect());
+    if spm_spread > cfg.washout_flat_tolerance_percent
+        || flow_spread > cfg.washout_flat_tolerance_percent
+    {
+        return None;
+    }
+
+    let normalized: Vec<f64> = entries.iter().map(|p| p.spp / p.flow_in).collect();
+    let slope = calculate_trend(&normalized);
+    if slope >= 0.0 || calculate_r_squared(&normalized) < WASHOUT_MIN_R_SQUARED {
+        return None;
+    }
+
+    // Fitted start value and total decline across the window
+    let n = normalized.len() as f64;
+    let mean = normalized.iter().sum::<f64>() / n;
+    let start = mean - slope * (n - 1.0) / 2.0;
+    if start <= 0.0 {
+        return None;
+    }
+    let decline_percent = -slope * (n - 1.0) / start * 100.0;
+    let threshold = cfg.washout_spp_decline_percent;
+    if decline_percent < threshold {
+        return None;
+    }
+
+    let first = entries[0];
+    let last = entries[entries.len() - 1];
+    let severity = (decline_percent / (2.0 * threshold)).min(1.0);
+    Some((
+        severity,
+        format!(
+            "SPP down {:.1}% over {} packets ({:.0} → {:.0} psi) at steady {:.0} spm / {:.0} gpm \
+             — possible drillstring washout",
+            decline_percent,
+            entries.len(),
+            first.spp,
+            last.spp,
+            last.pump_spm,
+            last.flow_in
+        ),
+    ))
+}
+
+/// Which mechanism is behind a qualifying SPP/torque rise
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MechanicalRise {
+    /// Acute pack-off: abrupt or reversible rise — work pipe
+    PackOff,
+    /// Gradual cuttings loading with ECD trending up — improve hole cleaning
+    CuttingsLoading,
+}
+
+/// Classify an SPP/torque rise as pack-off or cuttings loading
+///
+/// Series are oldest first. A rise qualifies when the peak torque or SPP
+/// exceeds the first value by the pack-off thresholds. It is then:
+/// - Pack-off if most of the rise happened within one short window, or a
+///   large share of it has already bled back off the peak
+/// - Cuttings loading if it built steadily and ECD is trending up
+///
+/// Returns `None` when nothing qualifies or a gradual rise has no ECD
+/// support (left to the generic torque checks). With classification
+/// disabled, any qualifying rise is reported as a pack-off.
+pub fn classify_mechanical_rise(
+    torque_values: &[f64],
+    spp_values: &[f64],
+    ecd_values: &[f64],
+) -> Option<MechanicalRise> {
+    let cfg = crate::config::get();
+    let mech = &cfg.thresholds.mechanical;
+    let pc = &cfg.thresholds.packoff_classification;
+
+    let torque = RiseShape::of(torque_values, pc.abrupt_window_packets);
+    let spp = RiseShape::of(spp_values, pc.abrupt_window_packets);
+    let torque_rising = torque.map_or(false, |r| r.rise > mech.torque_increase_warning);
+    let spp_rising = spp.map_or(false, |r| r.rise > mech.packoff_spp_increase_threshold);
+    if !torque_rising && !spp_rising {
+        return None;
+    }
+    if !pc.enabled {
+        return Some(MechanicalRise::PackOff);
+    }
+
+    let acute = [(torque, torque_rising), (spp, spp_rising)]
+        .iter()
+        .filter(|(_, rising)| *rising)
+        .filter_map(|(shape, _)| *shape)
+        .any(|r| {
+            r.max_window_rise >= pc.abrupt_rise_fraction * r.rise
+                || r.bleed_off >= pc.reversal_fraction * r.rise
+        });
+    if acute {
+        return Some(MechanicalRise::PackOff);
+    }
+
+    if calculate_trend(ecd_values) > pc.ecd_trend_threshold {
+        Some(MechanicalRise::CuttingsLoading)
+    } else {
+        None
+    }
+}
+
+/// Rise of a series relative to its first value, all as fractions of it
+#[derive(Debug, Clone, Copy)]
+struct RiseShape {
+    /// Peak minus first
+    rise: f64,
+    /// Largest increase across any `window` packets
+    max_window_rise: f64,
+    /// Peak minus last
+    bleed_off: f64,
+}
+
+impl RiseShape {
+    fn of(values: &[f64], window: usize) -> Option<Self> {
+        let first = *values.first()?;
+        if values.len() < 2 || first <= 0.0 {
+            return None;
+        }
+        let last = values[values.len() - 1];
+        let peak = values.iter().copied().fold(first, f64::max);
+        let window = window.max(1).min(values.len() - 1);
+        let max_window_rise = values
+            .windows(window + 1)
+            .map(|w| w[window] - w[0])
+            .fold(0.0, f64::max);
+        Some(Self {
+            rise: (peak - first) / first,
+            max_window_rise: max_window_rise / first,
+            bleed_off: (peak - last) / first,
+        })
+    }
+}
+
 /// Detect stick-slip condition
 ///
 /// Stick-slip is torsional oscillation where the bit alternates
@@ -788,13 +1079,70 @@ pub fn detect_formation_change(
     FormationChange::None
 }
 
+// ============================================================================
+// Drilling Break Detection
+// ============================================================================
+
+/// A sudden, sustained ROP increase over the recent baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrillingBreak {
+    /// Mean ROP over the pre-break baseline window (ft/hr)
+    pub baseline_rop: f64,
+    /// Mean ROP since the break (ft/hr)
+    pub current_rop: f64,
+    /// current_rop / baseline_rop
+    pub magnitude: f64,
+}
+
+/// Detect a drilling break from recent on-bottom ROP values (oldest first)
+///
+/// A break is the last `sustain_packets` values all exceeding the mean of
+/// the preceding `baseline_packets` by `rop_increase_factor`. The value just
+/// before the sustained run must still be below the factor, so a gradual
+/// ramp (whose baseline rises with it) does not qualify and each break is
+/// reported once, on the packet that confirms it.
+pub fn detect_drilling_break(rop_values: &[f64]) -> Option<DrillingBreak> {
+    let cfg = &crate::config::get().thresholds.drilling_break;
+    if !cfg.enabled {
+        return None;
+    }
+
+    let sustain = cfg.sustain_packets.max(1);
+    let needed = cfg.baseline_packets.max(1) + sustain;
+    if rop_values.len() < needed {
+        return None;
+    }
+
+    let window = &rop_values[rop_values.len() - needed..];
+    let (baseline, recent) = window.split_at(needed - sustain);
+    let baseline_rop = baseline.iter().sum::<f64>() / baseline.len() as f64;
+    if baseline_rop < cfg.min_baseline_rop_ft_hr {
+        return None;
+    }
+
+    let break_rop = baseline_rop * cfg.rop_increase_factor;
+    let before_break = baseline[baseline.len() - 1];
+    if before_break >= break_rop || recent.iter().any(|&rop| rop < break_rop) {
+        return None;
+    }
+
+    let current_rop = recent.iter().sum::<f64>() / recent.len() as f64;
+    Some(DrillingBreak {
+        baseline_rop,
+        current_rop,
+        magnitude: current_rop / baseline_rop,
+    })
+}
+
 // ============================================================================
 // Rig State Classification
 // ============================================================================
 
 /// Classify rig operational state from WITS parameters
 ///
-/// Uses RPM, WOB, flow rate, and hook load to determine state.
+/// Uses RPM, WOB, flow rate, and hook load to determine state. With
+/// `[directional]` enabled, on-bottom drilling without surface rotation is
+/// `Sliding` rather than `Connection`.
 pub fn classify_rig_state(packet: &WitsPacket) -> RigState {
     let cfg = crate::config::get();
 
@@ -811,6 +1159,19 @@ pub fn classify_rig_state(packet: &WitsPacket) -> RigState {
     let trip_out_hl = cfg.thresholds.rig_state.trip_out_hook_load_min;
     let trip_in_hl = cfg.thresholds.rig_state.trip_in_hook_load_max;
     let trip_flow_max = cfg.thresholds.rig_state.tripping_flow_max;
+    let rop_min = cfg.thresholds.rig_state.drilling_rop_min;
+
+    // Sliding: on bottom, WOB and motor flow, making hole without surface rotation
+    let dir = &cfg.directional;
+    if dir.enabled
+        && rpm < dir.slide_rpm_max
+        && wob >= dir.slide_wob_min
+        && flow_in >= dir.slide_flow_min_gpm
+        && rop > rop_min
+        && packet.bit_depth >= packet.hole_depth - reaming_offset
+    {
+        return RigState::Sliding;
+    }
 
     // Idle: No rotation, no flow
     if rpm < rpm_threshold && flow_in < flow_min {
@@ -822,8 +1183,6 @@ pub fn classify_rig_state(packet: &WitsPacket) -> RigState {
         return RigState::Connection;
     }
 
-    let rop_min = cfg.thresholds.rig_state.drilling_rop_min;
-
     // Drilling: Rotation + WOB + ROP (above noise floor)
     if rpm > rpm_threshold && wob > wob_min && rop > rop_min {
         // Reaming if bit is above hole depth
@@ -853,6 +1212,68 @@ pub fn classify_rig_state(packet: &WitsPacket) -> RigState {
     RigState::Circulating
 }
 
+/// Debounced rig state classification.
+///
+/// `classify_rig_state` is stateless, so a parameter hovering near a
+/// threshold flips the state every packet. The classifier holds the last
+/// committed state until a new raw state has been seen on
+/// `debounce_packets` consecutive packets. The first packet commits
+/// immediately; a `debounce_packets` of 0 or 1 passes raw states through.
+#[derive(Debug, Clone, Default)]
+pub struct RigStateClassifier {
+    committed: Option<RigState>,
+    pending: Option<RigState>,
+    pending_count: u32,
+}
+
+impl RigStateClassifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify a packet and debounce it with
+    /// `thresholds.rig_state.transition_debounce_packets`.
+    pub fn classify(&mut self, packet: &WitsPacket) -> RigState {
+        let debounce = crate::config::get()
+            .thresholds
+            .rig_state
+            .transition_debounce_packets;
+        self.update(classify_rig_state(packet), debounce)
+    }
+
+    /// Feed one raw classification; returns the committed state.
+    pub fn update(&mut self, raw: RigState, debounce_packets: u32) -> RigState {
+        let committed = match self.committed {
+            Some(state) if state != raw && debounce_packets > 1 => state,
+            _ => {
+                self.committed = Some(raw);
+                self.pending = None;
+                self.pending_count = 0;
+                return raw;
+            }
+        };
+
+        if self.pending == Some(raw) {
+            self.pending_count += 1;
+        } else {
+            self.pending = Some(raw);
+            self.pending_count = 1;
+        }
+        if self.pending_count >= debounce_packets {
+            self.committed = Some(raw);
+            self.pending = None;
+            self.pending_count = 0;
+            return raw;
+        }
+        committed
+    }
+
+    /// Last committed state (`None` before the first packet)
+    pub fn state(&self) -> Option<RigState> {
+        self.committed
+    }
+}
+
 // ============================================================================
 // Trend Analysis
 // ============================================================================
@@ -1028,7 +1449,13 @@ pub fn strategic_drilling_analysis(history: &[HistoryEntry]) -> DrillingPhysicsR
             0.0
         };
         let (is_packoff, _) = detect_packoff(torque_change, spp_change, 0.0);
-        if is_packoff {
+        let spp_values: Vec<f64> = history.iter().map(|h| h.packet.spp).collect();
+        let ecd_values: Vec<f64> = history.iter().map(|h| h.packet.ecd).collect();
+        // A gradual, ECD-accompanied rise is hole cleaning, not a pack-off
+        let rise = classify_mechanical_rise(&torque_values, &spp_values, &ecd_values);
+        if rise == Some(MechanicalRise::CuttingsLoading) {
+            detected_dysfunctions.push("Cuttings loading (hole cleaning)".to_string());
+        } else if is_packoff {
             detected_dysfunctions.push("Pack-off condition".to_string());
         }
     }
@@ -1079,6 +1506,121 @@ pub fn strategic_drilling_analysis(history: &[HistoryEntry]) -> DrillingPhysicsR
         current_ecd: latest.map(|p| p.ecd).unwrap_or(0.0),
         current_gas: latest.map(|p| p.gas_units).unwrap_or(0.0),
         current_pit_volume: latest.map(|p| p.pit_volume).unwrap_or(0.0),
+        bit_wear: estimate_bit_wear(history, None),
+    }
+}
+
+// ============================================================================
+// Bit Wear Estimation
+// ============================================================================
+
+/// Minimum recent drilling samples for a bit wear estimate
+const BIT_WEAR_MIN_SAMPLES: usize = 30;
+/// WOB / RPM tolerance for a recent sample to be comparable to the run start
+const BIT_WEAR_PARAM_TOLERANCE: f64 = 0.15;
+/// Wear fraction (MSE-equivalent rise over the run start) graded as fully worn
+const BIT_WEAR_FULL_DULL: f64 = 1.0;
+
+/// Estimate bit dull grade from MSE, ROP and d-exponent drift
+///
+/// Compares the recent drilling samples in `history` against the baseline
+/// taken at the start of the bit run, restricted to samples drilled at
+/// comparable WOB/RPM so a parameter change isn't mistaken for wear. A
+/// dulling bit needs more energy and drills slower for the same parameters,
+/// so MSE and dxc climb while ROP falls.
+///
+/// The run's bit hours only feed confidence — a few minutes on bottom can't
+/// say much about wear.
+///
+/// Returns confidence 0 without a complete run baseline or with fewer than
+/// 30 recent drilling samples.
+pub fn estimate_bit_wear(
+    history: &[HistoryEntry],
+    run: Option<&BitRunBaseline>,
+) -> BitWearEstimate {
+    let Some(run) = run.filter(|r| r.is_complete()) else {
+        return BitWearEstimate {
+            basis: format!(
+                "Bit-run baseline forming ({}/{} samples)",
+                run.map_or(0, |r| r.samples),
+                BIT_RUN_BASELINE_SAMPLES
+            ),
+            ..BitWearEstimate::default()
+        };
+    };
+
+    let drilling: Vec<&HistoryEntry> = history
+        .iter()
+        .filter(|e| e.metrics.state == RigState::Drilling)
+        .filter(|e| e.metrics.mse.is_finite() && e.metrics.mse > 0.0)
+        .filter(|e| e.packet.wob > 0.0 && e.packet.rpm > 0.0)
+        .collect();
+    if drilling.len() < BIT_WEAR_MIN_SAMPLES {
+        return BitWearEstimate {
+            basis: format!(
+                "Insufficient drilling data ({}/{} samples)",
+                drilling.len(),
+                BIT_WEAR_MIN_SAMPLES
+            ),
+            ..BitWearEstimate::default()
+        };
+    }
+
+    let mean = |entries: &[&HistoryEntry], f: fn(&HistoryEntry) -> f64| {
+        entries.iter().map(|e| f(e)).sum::<f64>() / entries.len() as f64
+    };
+    let comparable: Vec<&HistoryEntry> = drilling
+        .iter()
+        .copied()
+        .filter(|e| {
+            (e.packet.wob - run.wob).abs() <= run.wob * BIT_WEAR_PARAM_TOLERANCE
+                && (e.packet.rpm - run.rpm).abs() <= run.rpm * BIT_WEAR_PARAM_TOLERANCE
+        })
+        .collect();
+    // Too few like-for-like samples: fall back to all recent samples
+    let (recent, comparability) = if comparable.len() * 2 >= drilling.len() {
+        (comparable.as_slice(), 1.0)
+    } else {
+        (drilling.as_slice(), 0.5)
+    };
+
+    let mse_ratio = mean(recent, |e| e.metrics.mse) / run.mse;
+    let dxc_drift = if run.dxc > 0.0 {
+        mean(recent, |e| e.metrics.dxc) / run.dxc - 1.0
+    } else {
+        0.0
+    };
+    let rop_drop = if run.rop > 0.0 {
+        1.0 - mean(recent, |e| e.packet.rop) / run.rop
+    } else {
+        0.0
+    };
+
+    // MSE carries the estimate; dxc and ROP corroborate it
+    let wear = (0.6 * (mse_ratio - 1.0) + 0.2 * dxc_drift + 0.2 * rop_drop).max(0.0);
+    let dull_grade_estimate = (wear / BIT_WEAR_FULL_DULL * 8.0).clamp(0.0, 8.0);
+
+    let sample_factor = (drilling.len() as f64 / (2 * BIT_WEAR_MIN_SAMPLES) as f64).min(1.0);
+    let hours_factor = (run.bit_hours / 4.0).clamp(0.25, 1.0);
+    let confidence = sample_factor * comparability * hours_factor;
+
+    let basis = format!(
+        "MSE {:.2}x run-start baseline{}, dxc {:+.0}%, ROP {:+.0}%, {:.1} bit hours",
+        mse_ratio,
+        if comparability < 1.0 {
+            " (WOB/RPM changed)"
+        } else {
+            " at comparable WOB/RPM"
+        },
+        dxc_drift * 100.0,
+        -rop_drop * 100.0,
+        run.bit_hours
+    );
+
+    BitWearEstimate {
+        dull_grade_estimate,
+        confidence,
+        basis,
     }
 }
 
@@ -1095,6 +1637,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_drilling_break_sudden_vs_gradual() {
+        ensure_config();
+
+        // Steady 40 ft/hr, then a sustained jump to 80 ft/hr
+        let mut rops = vec![40.0; 30];
+        let mut detected = Vec::new();
+        for i in 0..10 {
+            rops.push(80.0);
+            detected.push((i, detect_drilling_break(&rops)));
+        }
+        let hits: Vec<_> = detected.iter().filter(|(_, d)| d.is_some()).collect();
+        assert_eq!(hits.len(), 1, "break reported exactly once");
+        let (at, brk) = hits[0];
+        assert_eq!(*at, 4, "confirmed after sustain_packets");
+        let brk = brk.unwrap();
+        assert!((brk.magnitude - 2.0).abs() < 1e-9);
+        assert!((brk.baseline_rop - 40.0).abs() < 1e-9);
+
+        // Same 40 -> 80 ft/hr change spread over 60 packets
+        let gradual: Vec<f64> = (0..60).map(|i| 40.0 + i as f64 * 40.0 / 59.0).collect();
+        for end in 35..=gradual.len() {
+            assert!(detect_drilling_break(&gradual[..end]).is_none());
+        }
+
+        // Single-packet spike is not sustained
+        let mut spike = vec![40.0; 30];
+        spike.extend([90.0, 40.0, 40.0, 40.0, 40.0]);
+        assert!(detect_drilling_break(&spike).is_none());
+    }
+
+    #[test]
+    fn test_packoff_vs_cuttings_loading() {
+        ensure_config();
+
+        // Abrupt spike: flat, then SPP +20% and torque +30% within two packets
+        let mut torque = vec![10.0; 20];
+        let mut spp = vec![3000.0; 20];
+        torque.extend([11.5, 13.0, 13.0, 13.0]);
+        spp.extend([3300.0, 3600.0, 3600.0, 3600.0]);
+        let ecd_flat = vec![10.5; torque.len()];
+        assert_eq!(
+            classify_mechanical_rise(&torque, &spp, &ecd_flat),
+            Some(MechanicalRise::PackOff)
+        );
+
+        // Spike that relieves back off the peak is still a pack-off
+        let mut relieved_spp = vec![3000.0; 20];
+        relieved_spp.extend([3400.0, 3700.0, 3200.0, 3100.0]);
+        assert_eq!(
+            classify_mechanical_rise(&torque, &relieved_spp, &ecd_flat),
+            Some(MechanicalRise::PackOff)
+        );
+
+        // Same magnitude built steadily over 40 packets with ECD creeping up
+        let n = 40;
+        let ramp = |start: f64, end: f64| -> Vec<f64> {
+            (0..n)
+                .map(|i| start + (end - start) * i as f64 / (n - 1) as f64)
+                .collect()
+        };
+        let torque = ramp(10.0, 13.0);
+        let spp = ramp(3000.0, 3600.0);
+        let ecd_rising = ramp(10.5, 10.8);
+        assert_eq!(
+            classify_mechanical_rise(&torque, &spp, &ecd_rising),
+            Some(MechanicalRise::CuttingsLoading)
+        );
+
+        // Gradual rise without ECD support is left unclassified
+        assert_eq!(
+            classify_mechanical_rise(&torque, &spp, &vec![10.5; n]),
+            None
+        );
+
+        // No meaningful rise
+        assert_eq!(
+            classify_mechanical_rise(&vec![10.0; n], &vec![3000.0; n], &ecd_rising),
+            None
+        );
+    }
+
     #[test]
     fn test_calculate_mse() {
         ensure_config();
@@ -1200,6 +1824,71 @@ mod tests {
         assert_eq!(classify_rig_state(&packet), RigState::Drilling);
     }
 
+    #[test]
+    fn test_debounced_rig_state_ignores_wob_noise() {
+        ensure_config();
+
+        // WOB hovering around drilling_wob_min (1.0 klbs)
+        let wob_series = [1.5, 0.8, 1.2, 0.9, 1.1, 0.7, 1.3, 0.9, 1.2, 0.8, 1.1];
+        let mut classifier = RigStateClassifier::new();
+        let mut raw_states = Vec::new();
+        let mut debounced = Vec::new();
+        for wob in wob_series {
+            let mut packet = WitsPacket::default();
+            packet.rpm = 120.0;
+            packet.wob = wob;
+            packet.rop = 40.0;
+            packet.flow_in = 500.0;
+            packet.bit_depth = 10000.0;
+            packet.hole_depth = 10000.0;
+            let raw = classify_rig_state(&packet);
+            raw_states.push(raw);
+            debounced.push(classifier.update(raw, 3));
+        }
+
+        let raw_changes = raw_states.windows(2).filter(|w| w[0] != w[1]).count();
+        assert_eq!(raw_changes, 10, "raw state should flap: {raw_states:?}");
+        assert!(debounced.iter().all(|s| *s == RigState::Drilling));
+
+        // A sustained change commits on its third consecutive packet
+        let committed: Vec<RigState> = (0..3)
+            .map(|_| classifier.update(RigState::Circulating, 3))
+            .collect();
+        assert_eq!(
+            committed,
+            vec![
+                RigState::Drilling,
+                RigState::Drilling,
+                RigState::Circulating
+            ]
+        );
+        assert_eq!(classifier.state(), Some(RigState::Circulating));
+
+        // Debounce of 1 passes raw states through
+        assert_eq!(classifier.update(RigState::Idle, 1), RigState::Idle);
+    }
+
+    #[test]
+    fn test_classify_rig_state_sliding() {
+        ensure_config();
+
+        // On bottom, motor flow and WOB, no surface rotation
+        let mut packet = WitsPacket::default();
+        packet.rpm = 0.0;
+        packet.wob = 20.0;
+        packet.rop = 30.0;
+        packet.flow_in = 500.0;
+        packet.bit_depth = 10000.0;
+        packet.hole_depth = 10000.0;
+        assert_eq!(classify_rig_state(&packet), RigState::Sliding);
+
+        // Same string off bottom with no WOB is a connection, not a slide
+        packet.wob = 0.0;
+        packet.rop = 0.0;
+        packet.bit_depth = 9950.0;
+        assert_eq!(classify_rig_state(&packet), RigState::Connection);
+    }
+
     #[test]
     fn test_classify_rig_state_circulating() {
         ensure_config();
@@ -1421,4 +2110,148 @@ mod tests {
         );
         assert!(rec.wob_change_pct < 0.0, "Should still reduce WOB");
     }
+
+    #[test]
+    fn test_swab_surge_flags_fast_trip_with_thin_margin() {
+        ensure_config();
+
+        let trip = |depth: f64, ts: u64| {
+            let mut p = WitsPacket::default();
+            p.timestamp = ts;
+            p.bit_depth = depth;
+            p.mud_weight_in = 9.6;
+            p.pore_pressure = 9.3;
+            p.fracture_gradient = 9.9;
+            p
+        };
+
+        // Pulling out 20 ft in 10 s (120 ft/min) with 0.3 ppg over pore pressure
+        let prev = trip(10_000.0, 0);
+        let cur = trip(9_980.0, 10);
+        let (category, description, margin) =
+            detect_swab_surge(&prev, &cur, &RigState::TrippingOut).expect("swab flagged");
+        assert_eq!(category, AnomalyCategory::WellControl);
+        assert!(description.contains("Swab risk"), "{}", description);
+        assert!(margin < 0.3, "swab margin {:.3}", margin);
+
+        // Running in at the same speed eats into the fracture margin instead
+        let run_in = trip(10_020.0, 10);
+        let (_, description, _) =
+            detect_swab_surge(&prev, &run_in, &RigState::TrippingIn).expect("surge flagged");
+        assert!(description.contains("Surge risk"), "{}", description);
+
+        // Slow pull and non-tripping states are ignored
+        let slow = trip(9_998.0, 10);
+        assert!(detect_swab_surge(&prev, &slow, &RigState::TrippingOut).is_none());
+        assert!(detect_swab_surge(&prev, &cur, &RigState::Drilling).is_none());
+    }
+
+    fn pumping_history(
+        spp: impl Fn(usize) -> f64,
+        flow: impl Fn(usize) -> f64,
+    ) -> Vec<HistoryEntry> {
+        (0..60)
+            .map(|i| {
+                let mut packet = WitsPacket::default();
+                packet.timestamp = i as u64 * 10;
+                packet.pump_spm = 120.0;
+                packet.flow_in = flow(i);
+                packet.spp = spp(i);
+                HistoryEntry {
+                    packet,
+                    metrics: crate::types::DrillingMetrics {
+                        state: RigState::Drilling,
+                        ..crate::types::DrillingMetrics::default()
+                    },
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_washout_flags_declining_spp_at_steady_pumps() {
+        ensure_config();
+
+        // 3000 psi bleeding off 9 psi per packet: ~18% over the window
+        let declining = pumping_history(|i| 3000.0 - 9.0 * i as f64 + (i % 3) as f64, |_| 500.0);
+        let (severity, description) = detect_washout(&declining).expect("washout flagged");
+        assert!((0.5..=1.0).contains(&severity), "severity {:.2}", severity);
+        assert!(description.contains("washout"), "{}", description);
+
+        // Steady SPP is quiet
+        let steady = pumping_history(|i| 3000.0 + (i % 3) as f64, |_| 500.0);
+        assert!(detect_washout(&steady).is_none());
+
+        // Same SPP drop while flow is cut back is the pump schedule, not a leak
+        let cut_back = pumping_history(|i| 3000.0 - 9.0 * i as f64, |i| 500.0 - 2.0 * i as f64);
+        assert!(detect_washout(&cut_back).is_none());
+
+        // Only evaluated while drilling or circulating
+        let mut tripping = declining.clone();
+        tripping.last_mut().unwrap().metrics.state = RigState::TrippingOut;
+        assert!(detect_washout(&tripping).is_none());
+    }
+
+    fn bit_run(mse: impl Fn(usize) -> f64, n: usize) -> Vec<HistoryEntry> {
+        (0..n)
+            .map(|i| {
+                let mut packet = WitsPacket::default();
+                packet.wob = 25.0;
+                packet.rpm = 120.0;
+                HistoryEntry {
+                    packet,
+                    metrics: crate::types::DrillingMetrics {
+                        state: RigState::Drilling,
+                        mse: mse(i),
+                        dxc: 1.5 * mse(i) / 20_000.0,
+                        ..crate::types::DrillingMetrics::default()
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn run_start(mse: f64, samples: usize) -> BitRunBaseline {
+        BitRunBaseline {
+            samples,
+            mse,
+            dxc: 1.5 * mse / 20_000.0,
+            wob: 25.0,
+            rpm: 120.0,
+            bit_hours: 8.0,
+            ..BitRunBaseline::default()
+        }
+    }
+
+    #[test]
+    fn test_bit_wear_sharp_vs_worn_bit() {
+        let run = run_start(20_000.0, BIT_RUN_BASELINE_SAMPLES);
+
+        // Sharp bit: MSE still at the run-start level
+        let flat = bit_run(|i| 20_000.0 + (i % 3) as f64 * 100.0, 60);
+        let sharp = estimate_bit_wear(&flat, Some(&run));
+        assert!(sharp.dull_grade_estimate < 1.0, "{:?}", sharp);
+        assert!(sharp.confidence > 0.9, "{:?}", sharp);
+
+        // Worn bit: MSE flat across the whole window but ~1.5x the run start,
+        // at the same WOB/RPM
+        let worn_window = bit_run(|i| 30_000.0 + (i % 3) as f64 * 100.0, 60);
+        let worn = estimate_bit_wear(&worn_window, Some(&run));
+        assert!(worn.dull_grade_estimate >= 3.0, "{:?}", worn);
+        assert!(worn.dull_grade_estimate > sharp.dull_grade_estimate + 2.0);
+        assert!(worn.basis.contains("comparable WOB/RPM"), "{}", worn.basis);
+
+        // Too little recent drilling to judge
+        let short_window = bit_run(|i| 20_000.0 + i as f64 * 200.0, 20);
+        let short = estimate_bit_wear(&short_window, Some(&run));
+        assert_eq!(short.confidence, 0.0);
+        assert_eq!(short.dull_grade_estimate, 0.0);
+        assert!(short.basis.contains("20/30"), "{}", short.basis);
+
+        // New bit: no judgement until the run-start baseline has formed
+        let forming = estimate_bit_wear(&worn_window, Some(&run_start(20_000.0, 10)));
+        assert_eq!(forming.confidence, 0.0);
+        assert!(forming.basis.contains("10/30"), "{}", forming.basis);
+        assert_eq!(estimate_bit_wear(&worn_window, None).confidence, 0.0);
+    }
 }
@@ -0,0 +1,215 @@
+//! Equipment Rating Limits
+//!
+//! Crews must keep drilling parameters inside the ratings of the equipment
+//! in the hole: weight on bit for the BHA, RPM for the mud motor, and flow
+//! rate for the motor and bit nozzles. These are hard limits, not statistical
+//! anomalies, so they are checked directly against `[equipment_limits]`
+//! rather than learned baselines.
+//!
+//! A parameter is *approaching* its rating at `approach_fraction` of it and
+//! *exceeded* above it. `EquipmentLimitTracker` reports each parameter once
+//! per escalation. A level only clears once the parameter is
+//! `rearm_margin_fraction` of the rating below it, and a parameter that
+//! re-escalates within `report_cooldown_secs` of being reported at the same
+//! or a higher level is not reported again, so a value hovering on a
+//! threshold does not repeat the advisory.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::EquipmentLimitsConfig;
+use crate::types::WitsPacket;
+
+/// How close a parameter is to its rating, ordered by severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitLevel {
+    Within,
+    Approaching,
+    Exceeded,
+}
+
+/// A parameter measured against its equipment rating
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LimitCheck {
+    /// Parameter name for advisory text (e.g. "flow")
+    pub parameter: &'static str,
+    /// Rated equipment (e.g. "motor")
+    pub equipment: &'static str,
+    /// Unit for advisory text (e.g. "gpm")
+    pub unit: &'static str,
+    /// Measured value
+    pub value: f64,
+    /// Rated limit
+    pub limit: f64,
+    pub level: LimitLevel,
+}
+
+impl LimitCheck {
+    /// e.g. "flow 650 gpm approaching motor max 700 gpm"
+    pub fn describe(&self) -> String {
+        let verb = match self.level {
+            LimitLevel::Exceeded => "exceeds",
+            LimitLevel::Approaching => "approaching",
+            LimitLevel::Within => "within",
+        };
+        format!(
+            "{} {:.0} {} {} {} max {:.0} {}",
+            self.parameter, self.value, self.unit, verb, self.equipment, self.limit, self.unit
+        )
+    }
+}
+
+/// Check WOB, RPM and flow against their ratings.
+///
+/// Returns one `LimitCheck` per rated parameter (ratings of 0 are skipped).
+pub fn check_equipment_limits(packet: &WitsPacket, cfg: &EquipmentLimitsConfig) -> Vec<LimitCheck> {
+    [
+        ("wob", "BHA", "klbs", packet.wob, cfg.max_wob_klbs),
+        ("rpm", "motor", "rpm", packet.rpm, cfg.max_rpm),
+        ("flow", "motor", "gpm", packet.flow_in, cfg.max_flow_gpm),
+    ]
+    .into_iter()
+    .filter(|&(_, _, _, _, limit)| limit > 0.0)
+    .map(|(parameter, equipment, unit, value, limit)| LimitCheck {
+        parameter,
+        equipment,
+        unit,
+        value,
+        limit,
+        level: classify(value, limit, cfg),
+    })
+    .collect()
+}
+
+fn classify(value: f64, limit: f64, cfg: &EquipmentLimitsConfig) -> LimitLevel {
+    if value > limit {
+        LimitLevel::Exceeded
+    } else if value >= limit * cfg.approach_fraction {
+        LimitLevel::Approaching
+    } else {
+        LimitLevel::Within
+    }
+}
+
+/// Tracks the reported level per parameter so each escalation fires once
+#[derive(Debug, Clone, Default)]
+pub struct EquipmentLimitTracker {
+    reported: HashMap<&'static str, LimitLevel>,
+    /// Timestamp and level of the last advisory per parameter
+    last_report: HashMap<&'static str, (u64, LimitLevel)>,
+}
+
+impl EquipmentLimitTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update with one packet.
+    ///
+    /// Returns the most severe parameter that escalated past its last
+    /// reported level. A parameter that drops `rearm_margin_fraction` of its
+    /// rating below a level is re-armed silently.
+    pub fn update(
+        &mut self,
+        packet: &WitsPacket,
+        cfg: &EquipmentLimitsConfig,
+    ) -> Option<LimitCheck> {
+        let mut escalated: Option<LimitCheck> = None;
+        for check in check_equipment_limits(packet, cfg) {
+            let reported = self
+                .reported
+                .entry(check.parameter)
+                .or_insert(LimitLevel::Within);
+            if check.level > *reported {
+                *reported = check.level;
+                let cooling_down =
+                    self.last_report
+                        .get(check.parameter)
+                        .is_some_and(|&(ts, level)| {
+                            check.level <= level
+                                && packet.timestamp.saturating_sub(ts) < cfg.report_cooldown_secs
+                        });
+                if !cooling_down && escalated.as_ref().is_none_or(|e| check.level > e.level) {
+                    escalated = Some(check);
+                }
+            } else if check.level < *reported {
+                let margin = check.limit * cfg.rearm_margin_fraction;
+                *reported = (*reported).min(classify(check.value + margin, check.limit, cfg));
+            }
+        }
+        if let Some(check) = &escalated {
+            self.last_report
+                .insert(check.parameter, (packet.timestamp, check.level));
+        }
+        escalated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_approaching_then_exceeding_motor_limit() {
+        let cfg = EquipmentLimitsConfig {
+            max_flow_gpm: 700.0,
+            ..EquipmentLimitsConfig::default()
+        };
+        let packet = |timestamp: u64, flow_in: f64| WitsPacket {
+            timestamp,
+            flow_in,
+            ..WitsPacket::default()
+        };
+
+        let mut tracker = EquipmentLimitTracker::new();
+        assert!(tracker.update(&packet(0, 600.0), &cfg).is_none());
+
+        let approaching = tracker
+            .update(&packet(1, 650.0), &cfg)
+            .expect("approaching");
+        assert_eq!(approaching.level, LimitLevel::Approaching);
+        assert_eq!(
+            approaching.describe(),
+            "flow 650 gpm approaching motor max 700 gpm"
+        );
+        // Reported once per level
+        assert!(tracker.update(&packet(2, 660.0), &cfg).is_none());
+
+        let exceeded = tracker.update(&packet(3, 720.0), &cfg).expect("exceeded");
+        assert_eq!(exceeded.level, LimitLevel::Exceeded);
+
+        // Dropping back re-arms the check once the cooldown has passed
+        assert!(tracker.update(&packet(4, 500.0), &cfg).is_none());
+        let later = 4 + cfg.report_cooldown_secs;
+        assert!(tracker.update(&packet(later, 650.0), &cfg).is_some());
+    }
+
+    #[test]
+    fn test_flow_hovering_on_approach_threshold_reported_once() {
+        let cfg = EquipmentLimitsConfig {
+            max_flow_gpm: 700.0,
+            ..EquipmentLimitsConfig::default()
+        };
+        let packet = |timestamp: u64, flow_in: f64| WitsPacket {
+            timestamp,
+            flow_in,
+            ..WitsPacket::default()
+        };
+
+        // Approach threshold is 630 gpm; the flow meter jitters across it
+        let mut tracker = EquipmentLimitTracker::new();
+        let reports = (0..2 * cfg.report_cooldown_secs)
+            .filter(|ts| {
+                let flow = if ts % 2 == 0 { 632.0 } else { 625.0 };
+                tracker.update(&packet(*ts, flow), &cfg).is_some()
+            })
+            .count();
+        assert_eq!(reports, 1);
+
+        // A real escalation is not held back by the cooldown
+        let ts = 2 * cfg.report_cooldown_secs;
+        assert!(tracker.update(&packet(ts, 710.0), &cfg).is_some());
+    }
+}
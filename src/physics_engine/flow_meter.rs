@@ -0,0 +1,132 @@
+//! Flow Meter Cross-Check
+//!
+//! With the pumps running, flow-in should track pump strokes times the
+//! configured output per stroke. A flow meter that reads near zero, sticks,
+//! or drifts far from that figure silently corrupts every flow-balance
+//! check downstream, so the disagreement itself is reported as an equipment
+//! / data-integrity problem rather than interpreted as a kick or loss.
+//!
+//! `FlowMeterCheckTracker` reports a discrepancy once it has persisted for
+//! `sustain_secs`, and re-arms when flow-in agrees with the pumps again.
+
+use crate::config::FlowMeterCheckConfig;
+use crate::types::WitsPacket;
+
+/// Reported flow-in disagreeing with pump-derived flow
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowMeterDiscrepancy {
+    /// Pump strokes per minute
+    pub pump_spm: f64,
+    /// Flow expected from the pumps (gpm)
+    pub expected_gpm: f64,
+    /// Reported flow-in (gpm)
+    pub flow_in_gpm: f64,
+    /// |expected − flow_in| as a percentage of expected
+    pub discrepancy_pct: f64,
+    /// How long the discrepancy has persisted (s)
+    pub duration_secs: u64,
+}
+
+/// Percentage disagreement between reported and pump-derived flow.
+///
+/// `None` when the check does not apply: displacement not configured or
+/// pumps below `min_spm`.
+pub fn flow_discrepancy_pct(packet: &WitsPacket, cfg: &FlowMeterCheckConfig) -> Option<(f64, f64)> {
+    if cfg.pump_output_gal_per_stroke <= 0.0 || packet.pump_spm < cfg.min_spm {
+        return None;
+    }
+    let expected = packet.pump_spm * cfg.pump_output_gal_per_stroke;
+    if expected <= 0.0 {
+        return None;
+    }
+    let pct = (expected - packet.flow_in).abs() / expected * 100.0;
+    Some((expected, pct))
+}
+
+/// Tracks how long flow-in has disagreed with the pumps
+#[derive(Debug, Clone, Default)]
+pub struct FlowMeterCheckTracker {
+    /// Packet timestamp when the current discrepancy started
+    since: Option<u64>,
+    /// Discrepancy already reported for the current episode
+    reported: bool,
+}
+
+impl FlowMeterCheckTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update with one packet.
+    ///
+    /// Returns the discrepancy the first time it has persisted for
+    /// `sustain_secs`. Agreement, or pumps stopping, ends the episode.
+    pub fn update(
+        &mut self,
+        packet: &WitsPacket,
+        cfg: &FlowMeterCheckConfig,
+    ) -> Option<FlowMeterDiscrepancy> {
+        let Some((expected, pct)) =
+            flow_discrepancy_pct(packet, cfg).filter(|&(_, pct)| pct > cfg.max_discrepancy_pct)
+        else {
+            self.since = None;
+            self.reported = false;
+            return None;
+        };
+
+        let since = *self.since.get_or_insert(packet.timestamp);
+        let duration_secs = packet.timestamp.saturating_sub(since);
+        if self.reported || duration_secs < cfg.sustain_secs {
+            return None;
+        }
+        self.reported = true;
+
+        Some(FlowMeterDiscrepancy {
+            pump_spm: packet.pump_spm,
+            expected_gpm: expected,
+            flow_in_gpm: packet.flow_in,
+            discrepancy_pct: pct,
+            duration_secs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dead_flow_meter_reported_once_after_sustain() {
+        let cfg = FlowMeterCheckConfig {
+            pump_output_gal_per_stroke: 4.0,
+            ..FlowMeterCheckConfig::default()
+        };
+        let packet = |ts: u64, flow_in: f64| WitsPacket {
+            timestamp: ts,
+            pump_spm: 120.0,
+            flow_in,
+            ..WitsPacket::default()
+        };
+
+        let mut tracker = FlowMeterCheckTracker::new();
+        // 120 spm x 4 gal = 480 gpm expected; 470 agrees
+        assert!(tracker.update(&packet(0, 470.0), &cfg).is_none());
+
+        let mut reports = Vec::new();
+        for t in 1..=(cfg.sustain_secs * 2) {
+            reports.extend(tracker.update(&packet(t, 0.0), &cfg));
+        }
+        assert_eq!(reports.len(), 1);
+        assert!((reports[0].expected_gpm - 480.0).abs() < 1e-9);
+        assert!((reports[0].discrepancy_pct - 100.0).abs() < 1e-9);
+        assert_eq!(reports[0].duration_secs, cfg.sustain_secs);
+
+        // Pumps off: no expectation, episode ends
+        let stopped = WitsPacket {
+            timestamp: 1000,
+            ..WitsPacket::default()
+        };
+        assert!(tracker.update(&stopped, &cfg).is_none());
+        assert!(tracker.update(&packet(1001, 0.0), &cfg).is_none());
+    }
+}
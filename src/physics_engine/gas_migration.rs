@@ -0,0 +1,199 @@
+//! Gas Migration Detection During Static Periods
+//!
+//! When circulation stops (connection, survey, flow check), gas that has
+//! already entered the wellbore keeps migrating up the annulus and expanding.
+//! With the pumps off nothing else should be raising surface pressure, so a
+//! steady climb in casing (or annular) pressure is the signature — a
+//! well-control concern distinct from an active influx while circulating.
+//!
+//! ## Detection Logic
+//!
+//! 1. Pumps off (`flow_in < pump_off_flow_gpm`): remember the starting
+//!    pressure and bit depth
+//! 2. Pipe moved more than `max_pipe_movement_ft`: surge/swab, not
+//!    migration — restart the baseline at the new position
+//! 3. After `min_static_secs`, a rise of at least `min_rise_psi` at an
+//!    average of at least `min_rate_psi_per_min` raises one event per
+//!    static period
+//! 4. Pumps back on: reset
+//!
+//! Casing pressure is used when the feed provides it, annular pressure
+//! otherwise. Feeds with neither never trigger.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::GasMigrationConfig;
+use crate::types::WitsPacket;
+
+/// A detected pressure build-up during a pumps-off period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasMigrationEvent {
+    /// Unix timestamp of detection
+    pub timestamp: u64,
+    /// Unix timestamp the static baseline was taken
+    pub static_since: u64,
+    /// Bit depth during the static period (ft)
+    pub depth_ft: f64,
+    /// "casing" or "annular"
+    pub pressure_source: String,
+    pub start_pressure_psi: f64,
+    pub pressure_psi: f64,
+    pub rise_psi: f64,
+    /// Average build-up rate since the baseline (psi/min)
+    pub rate_psi_per_min: f64,
+}
+
+/// Static-period baseline
+#[derive(Debug, Clone)]
+struct StaticPeriod {
+    start_ts: u64,
+    start_pressure: f64,
+    depth_ft: f64,
+    source: &'static str,
+    reported: bool,
+}
+
+/// Watches pumps-off periods for rising casing/annular pressure
+#[derive(Debug, Clone, Default)]
+pub struct GasMigrationTracker {
+    period: Option<StaticPeriod>,
+}
+
+/// Surface pressure to watch: casing first, annular as fallback
+fn surface_pressure(packet: &WitsPacket) -> Option<(f64, &'static str)> {
+    if packet.casing_pressure > 0.0 {
+        Some((packet.casing_pressure, "casing"))
+    } else if packet.annular_pressure > 0.0 {
+        Some((packet.annular_pressure, "annular"))
+    } else {
+        None
+    }
+}
+
+impl GasMigrationTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True while a pumps-off period is being watched
+    pub fn is_static(&self) -> bool {
+        self.period.is_some()
+    }
+
+    /// Update with one packet. Returns an event the first time a static
+    /// period shows a qualifying pressure build-up.
+    pub fn update(
+        &mut self,
+        packet: &WitsPacket,
+        cfg: &GasMigrationConfig,
+    ) -> Option<GasMigrationEvent> {
+        let pumps_off = packet.flow_in < cfg.pump_off_flow_gpm;
+        let Some((pressure, source)) = surface_pressure(packet).filter(|_| pumps_off) else {
+            self.period = None;
+            return None;
+        };
+
+        let restart = self.period.as_ref().is_none_or(|p| {
+            p.source != source || (packet.bit_depth - p.depth_ft).abs() > cfg.max_pipe_movement_ft
+        });
+        if restart {
+            self.period = Some(StaticPeriod {
+                start_ts: packet.timestamp,
+                start_pressure: pressure,
+                depth_ft: packet.bit_depth,
+                source,
+                reported: false,
+            });
+            return None;
+        }
+
+        let period = self.period.as_mut()?;
+        let elapsed = packet.timestamp.saturating_sub(period.start_ts);
+        if period.reported || elapsed < cfg.min_static_secs {
+            return None;
+        }
+        let rise = pressure - period.start_pressure;
+        let rate = rise / (elapsed as f64 / 60.0);
+        if rise < cfg.min_rise_psi || rate < cfg.min_rate_psi_per_min {
+            return None;
+        }
+
+        period.reported = true;
+        Some(GasMigrationEvent {
+            timestamp: packet.timestamp,
+            static_since: period.start_ts,
+            depth_ft: period.depth_ft,
+            pressure_source: source.to_string(),
+            start_pressure_psi: period.start_pressure,
+            pressure_psi: pressure,
+            rise_psi: rise,
+            rate_psi_per_min: rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(ts: u64, flow_in: f64, casing_pressure: f64) -> WitsPacket {
+        WitsPacket {
+            timestamp: ts,
+            bit_depth: 9000.0,
+            flow_in,
+            casing_pressure,
+            ..WitsPacket::default()
+        }
+    }
+
+    #[test]
+    fn test_rising_casing_pressure_while_static_is_gas_migration() {
+        let cfg = GasMigrationConfig::default();
+        let mut tracker = GasMigrationTracker::new();
+
+        // Circulating: never evaluated
+        for ts in 0..60 {
+            assert!(tracker.update(&packet(ts, 500.0, 150.0), &cfg).is_none());
+        }
+
+        // Pumps off, casing pressure building 20 psi/min
+        let mut events = Vec::new();
+        for ts in 60..600 {
+            let p = 150.0 + (ts - 60) as f64 / 3.0;
+            events.extend(tracker.update(&packet(ts, 0.0, p), &cfg));
+        }
+        assert_eq!(events.len(), 1, "one event per static period");
+        let event = &events[0];
+        assert_eq!(event.pressure_source, "casing");
+        assert!(event.timestamp - event.static_since >= cfg.min_static_secs);
+        assert!(event.rise_psi >= cfg.min_rise_psi);
+        assert!((event.rate_psi_per_min - 20.0).abs() < 0.5);
+
+        // Pumps back on resets the period
+        tracker.update(&packet(600, 500.0, 150.0), &cfg);
+        assert!(!tracker.is_static());
+    }
+
+    #[test]
+    fn test_stable_static_pressure_is_not_flagged() {
+        let cfg = GasMigrationConfig::default();
+        let mut tracker = GasMigrationTracker::new();
+        for ts in 0..900 {
+            // ±2 psi sensor noise around 150 psi
+            let p = 150.0 + if ts % 2 == 0 { 2.0 } else { -2.0 };
+            assert!(tracker.update(&packet(ts, 0.0, p), &cfg).is_none());
+        }
+    }
+
+    #[test]
+    fn test_pipe_movement_restarts_baseline() {
+        let cfg = GasMigrationConfig::default();
+        let mut tracker = GasMigrationTracker::new();
+        // Tripping out with pumps off: pressure moves with the pipe
+        for ts in 0..600 {
+            let mut p = packet(ts, 0.0, 150.0 + ts as f64 / 3.0);
+            p.bit_depth = 9000.0 - ts as f64;
+            assert!(tracker.update(&p, &cfg).is_none());
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Formation-Hardness Calibration from ML Analysis
+//!
+//! `estimate_formation_hardness_from_rop()` compares actual ROP to an
+//! expected ROP of `coefficient × WOB × RPM`. The fixed coefficient
+//! (`FIXED_DRILLABILITY_COEFFICIENT`) is a universal guess, so the hardness
+//! estimate (and the optimal MSE derived from it) says little about how
+//! this rig is actually drilling this formation.
+//!
+//! Each successful ML analysis reports the ROP achieved at its optimal
+//! WOB/RPM. `HardnessCalibration` turns that into an observed coefficient
+//! (`achieved_rop / (best_wob × best_rpm)`), smoothed across reports, per
+//! prognosis formation and well-wide. A formation's own coefficient is used
+//! once it has `min_reports` qualifying reports, then the well-wide one;
+//! before that the fixed model applies.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::HardnessCalibrationConfig;
+use crate::types::{AnalysisResult, MLInsightsReport};
+
+/// Expected-ROP coefficient of the uncalibrated model (ft/hr per klbs·RPM)
+pub const FIXED_DRILLABILITY_COEFFICIENT: f64 = 0.01;
+
+/// Smoothed drillability coefficient for one formation (or the whole well)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DrillabilityEstimate {
+    /// Expected-ROP coefficient (ft/hr per klbs·RPM)
+    pub coefficient: f64,
+    /// Qualifying ML reports folded into the coefficient
+    pub reports: usize,
+}
+
+impl DrillabilityEstimate {
+    fn update(&mut self, observed: f64, smoothing: f64) {
+        self.coefficient = if self.reports == 0 {
+            observed
+        } else {
+            smoothing * observed + (1.0 - smoothing) * self.coefficient
+        };
+        self.reports += 1;
+    }
+}
+
+/// Drillability coefficients learned from ML reports
+#[derive(Debug, Clone, Default)]
+pub struct HardnessCalibration {
+    by_formation: HashMap<String, DrillabilityEstimate>,
+    well_wide: DrillabilityEstimate,
+}
+
+impl HardnessCalibration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold in an ML report. `formation` is the prognosis formation the
+    /// report's depth range falls in, if known.
+    ///
+    /// Returns the observed coefficient when the report qualified.
+    pub fn ingest(
+        &mut self,
+        report: &MLInsightsReport,
+        formation: Option<&str>,
+        cfg: &HardnessCalibrationConfig,
+    ) -> Option<f64> {
+        let AnalysisResult::Success(ref insights) = report.result else {
+            return None;
+        };
+        if insights.sample_count < cfg.min_sample_count {
+            return None;
+        }
+        let params = &insights.optimal_params;
+        let wob_rpm = params.best_wob * params.best_rpm;
+        if wob_rpm <= 0.0 || params.achieved_rop <= 0.0 {
+            return None;
+        }
+        let observed = params.achieved_rop / wob_rpm;
+
+        self.well_wide.update(observed, cfg.smoothing);
+        if let Some(name) = formation {
+            self.by_formation
+                .entry(name.to_string())
+                .or_default()
+                .update(observed, cfg.smoothing);
+        }
+        Some(observed)
+    }
+
+    /// Calibrated coefficient for a formation, or `None` to use the fixed
+    /// model.
+    pub fn coefficient(
+        &self,
+        formation: Option<&str>,
+        cfg: &HardnessCalibrationConfig,
+    ) -> Option<f64> {
+        formation
+            .and_then(|name| self.by_formation.get(name))
+            .filter(|est| est.reports >= cfg.min_reports)
+            .or(Some(&self.well_wide).filter(|est| est.reports >= cfg.min_reports))
+            .map(|est| est.coefficient)
+    }
+
+    /// Per-formation estimates
+    pub fn formations(&self) -> &HashMap<String, DrillabilityEstimate> {
+        &self.by_formation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics_engine::estimate_formation_hardness_from_rop;
+    use crate::types::{AnalysisInsights, Campaign, ConfidenceLevel, OptimalParams};
+
+    fn report(achieved_rop: f64, wob: f64, rpm: f64) -> MLInsightsReport {
+        MLInsightsReport {
+            timestamp: 1700000000,
+            campaign: Campaign::Production,
+            depth_range: (8000.0, 8500.0),
+            well_id: "Well-A".to_string(),
+            field_name: "TestField".to_string(),
+            wellbore: None,
+            bit_hours: 10.0,
+            bit_depth: 500.0,
+            formation_type: "Hard Sandstone".to_string(),
+            result: AnalysisResult::Success(AnalysisInsights {
+                optimal_params: OptimalParams {
+                    achieved_rop,
+                    best_wob: wob,
+                    best_rpm: rpm,
+                    ..Default::default()
+                },
+                correlations: Vec::new(),
+                summary_text: "test".to_string(),
+                confidence: ConfidenceLevel::High,
+                sample_count: 2000,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_calibrated_formation_reads_harder_when_it_drills_faster_than_model() {
+        let cfg = HardnessCalibrationConfig::default();
+        let mut cal = HardnessCalibration::new();
+
+        // Before enough reports: fixed model
+        cal.ingest(&report(60.0, 25.0, 120.0), Some("Hugin"), &cfg);
+        assert_eq!(cal.coefficient(Some("Hugin"), &cfg), None);
+        for _ in 1..cfg.min_reports {
+            cal.ingest(&report(60.0, 25.0, 120.0), Some("Hugin"), &cfg);
+        }
+        // This rig makes 60 ft/hr at 25 klbs × 120 RPM: twice the fixed model
+        let k = cal.coefficient(Some("Hugin"), &cfg).expect("calibrated");
+        assert!((k - 0.02).abs() < 1e-12);
+
+        // 40 ft/hr at the same parameters is fast by the fixed model but
+        // slow for what this formation normally gives up
+        let fixed = estimate_formation_hardness_from_rop(40.0, 25.0, 120.0, None);
+        let calibrated = estimate_formation_hardness_from_rop(40.0, 25.0, 120.0, Some(k));
+        assert!(fixed < 5.0);
+        assert!(calibrated > 5.0);
+        assert!(calibrated > fixed);
+
+        // Unknown formation falls back to the well-wide coefficient
+        assert_eq!(cal.coefficient(Some("Draupne"), &cfg), Some(k));
+    }
+
+    #[test]
+    fn test_low_sample_reports_are_ignored() {
+        let cfg = HardnessCalibrationConfig::default();
+        let mut cal = HardnessCalibration::new();
+        let mut r = report(60.0, 25.0, 120.0);
+        if let AnalysisResult::Success(ref mut insights) = r.result {
+            insights.sample_count = cfg.min_sample_count - 1;
+        }
+        assert_eq!(cal.ingest(&r, Some("Hugin"), &cfg), None);
+        assert!(cal.formations().is_empty());
+    }
+}
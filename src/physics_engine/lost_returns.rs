@@ -0,0 +1,460 @@
+//! Lost-Returns Severity Staging
+//!
+//! Losses to the formation are conventionally staged by how much of the
+//! pumped mud fails to come back:
+//!
+//! - **Seepage**: a few percent of flow-in — monitor, often self-healing
+//! - **Partial**: a significant fraction lost — pump an LCM pill
+//! - **Total**: most returns lost — reduce pump rate, spot a heavy LCM pill
+//! - **Complete**: no returns — stop pumping, fill the annulus, watch for a kick
+//!
+//! The stage is the more severe of the flow-out deficit band and the pit-loss
+//! rate band (see `LostReturnsConfig`). `LostReturnsTracker` requires a stage
+//! to persist for `confirm_packets` before adopting it and reports each
+//! escalation, so an operator sees the loss progressing rather than a single
+//! generic lost-circulation alarm. A stage only clears once the readings fall
+//! `clear_hysteresis_pct` below its band, and a stage already reported within
+//! `report_cooldown_secs` is adopted silently, so seepage hovering around 2%
+//! doesn't re-alarm every few packets.
+//!
+//! Not every flow-out deficit is downhole. A leaking top-drive washpipe or
+//! rotating-head seal spills mud at surface: SPP bleeds off with the lost
+//! flow while the annulus, and so ECD, is unaffected. `classify_loss_source`
+//! separates that signature from formation losses, which drop the annular
+//! level (and ECD) while SPP holds.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::LostReturnsConfig;
+
+/// Lost-returns severity stage, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LossSeverity {
+    Seepage,
+    Partial,
+    Total,
+    Complete,
+}
+
+impl LossSeverity {
+    /// Upper-case label for advisory text
+    pub fn label(self) -> &'static str {
+        match self {
+            LossSeverity::Seepage => "SEEPAGE",
+            LossSeverity::Partial => "PARTIAL",
+            LossSeverity::Total => "TOTAL",
+            LossSeverity::Complete => "COMPLETE",
+        }
+    }
+
+    /// Stage-appropriate response
+    pub fn recommendation(self) -> &'static str {
+        match self {
+            LossSeverity::Seepage => {
+                "Monitor pit volume and flow-out closely; consider reducing ROP \
+                 and adding fine LCM to the active system."
+            }
+            LossSeverity::Partial => {
+                "Pump an LCM pill, reduce pump rate to lower ECD, and keep \
+                 the hole full."
+            }
+            LossSeverity::Total => {
+                "Reduce pumps to minimum, spot a heavy LCM pill across the loss \
+                 zone, and prepare a cement or gunk plug."
+            }
+            LossSeverity::Complete => {
+                "Stop pumping. Fill the annulus from the top with water or light \
+                 mud, monitor fluid level, and watch for a kick from the \
+                 reduced hydrostatic."
+            }
+        }
+    }
+}
+
+/// Classify losses from flow-in, flow-out and pit rate.
+///
+/// `pit_rate_bbl_hr` is signed (negative = pits dropping). Returns `None`
+/// when the pumps are off or both indicators are below the seepage band.
+///
+/// A zero flow-out reading is indistinguishable from a dead paddle, so it
+/// only counts as lost returns when the pits are also dropping.
+pub fn classify_loss_severity(
+    flow_in_gpm: f64,
+    flow_out_gpm: f64,
+    pit_rate_bbl_hr: f64,
+    cfg: &LostReturnsConfig,
+) -> Option<LossSeverity> {
+    if flow_in_gpm < cfg.min_flow_in_gpm {
+        return None;
+    }
+
+    let pit_loss = (-pit_rate_bbl_hr).max(0.0);
+    let flow_out_credible = flow_out_gpm > 0.0 || pit_loss >= cfg.seepage_pit_loss_bbl_hr;
+
+    let deficit_pct = ((flow_in_gpm - flow_out_gpm.max(0.0)) / flow_in_gpm * 100.0).max(0.0);
+    let by_deficit = if !flow_out_credible {
+        None
+    } else if deficit_pct >= cfg.complete_deficit_pct {
+        Some(LossSeverity::Complete)
+    } else if deficit_pct >= cfg.total_deficit_pct {
+        Some(LossSeverity::Total)
+    } else if deficit_pct >= cfg.partial_deficit_pct {
+        Some(LossSeverity::Partial)
+    } else if deficit_pct >= cfg.seepage_deficit_pct {
+        Some(LossSeverity::Seepage)
+    } else {
+        None
+    };
+
+    let by_pit = if pit_loss >= cfg.total_pit_loss_bbl_hr {
+        Some(LossSeverity::Total)
+    } else if pit_loss >= cfg.partial_pit_loss_bbl_hr {
+        Some(LossSeverity::Partial)
+    } else if pit_loss >= cfg.seepage_pit_loss_bbl_hr {
+        Some(LossSeverity::Seepage)
+    } else {
+        None
+    };
+
+    by_deficit.max(by_pit)
+}
+
+/// Where the missing returns are going
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LossSource {
+    /// Lost downhole to the formation
+    Formation,
+    /// Leaking at surface (washpipe, rotating-head seal, lines)
+    SurfaceEquipment,
+}
+
+/// Separate surface-equipment losses from formation losses.
+///
+/// `spp_psi` and `ecd_ppg` cover the pumps-on packets leading up to the
+/// loss, oldest first. Surface losses show SPP falling by at least
+/// `surface_spp_drop_pct` while ECD stays within `surface_ecd_tolerance_ppg`.
+/// Without an ECD reading there is no downhole evidence, so the loss is
+/// treated as formation losses.
+pub fn classify_loss_source(
+    spp_psi: &[f64],
+    ecd_ppg: &[f64],
+    cfg: &LostReturnsConfig,
+) -> LossSource {
+    let (Some(&spp_start), Some(&spp_now)) = (spp_psi.first(), spp_psi.last()) else {
+        return LossSource::Formation;
+    };
+    let (Some(&ecd_start), Some(&ecd_now)) = (ecd_ppg.first(), ecd_ppg.last()) else {
+        return LossSource::Formation;
+    };
+    if spp_start <= 0.0 || ecd_start <= 0.0 || ecd_now <= 0.0 {
+        return LossSource::Formation;
+    }
+
+    let spp_drop_pct = (spp_start - spp_now) / spp_start * 100.0;
+    let ecd_stable = (ecd_now - ecd_start).abs() <= cfg.surface_ecd_tolerance_ppg;
+    if spp_drop_pct >= cfg.surface_spp_drop_pct && ecd_stable {
+        LossSource::SurfaceEquipment
+    } else {
+        LossSource::Formation
+    }
+}
+
+/// A confirmed escalation to a more severe loss stage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LossEscalation {
+    /// Unix timestamp of the packet that confirmed the stage
+    pub timestamp: u64,
+    /// Stage before the escalation (`None` = no losses)
+    pub from: Option<LossSeverity>,
+    /// Newly confirmed stage
+    pub to: LossSeverity,
+    /// Flow-out deficit at confirmation (% of flow-in)
+    pub deficit_pct: f64,
+    /// Pit-loss rate at confirmation (bbl/hr, positive = losing)
+    pub pit_loss_bbl_hr: f64,
+}
+
+/// `cfg` with every band lowered by `clear_hysteresis_pct`, for deciding
+/// whether a confirmed stage has cleared
+fn clearing_bands(cfg: &LostReturnsConfig) -> LostReturnsConfig {
+    let k = 1.0 - cfg.clear_hysteresis_pct / 100.0;
+    LostReturnsConfig {
+        seepage_deficit_pct: cfg.seepage_deficit_pct * k,
+        partial_deficit_pct: cfg.partial_deficit_pct * k,
+        total_deficit_pct: cfg.total_deficit_pct * k,
+        complete_deficit_pct: cfg.complete_deficit_pct * k,
+        seepage_pit_loss_bbl_hr: cfg.seepage_pit_loss_bbl_hr * k,
+        partial_pit_loss_bbl_hr: cfg.partial_pit_loss_bbl_hr * k,
+        total_pit_loss_bbl_hr: cfg.total_pit_loss_bbl_hr * k,
+        ..cfg.clone()
+    }
+}
+
+/// Tracks the confirmed loss stage and reports escalations
+#[derive(Debug, Clone, Default)]
+pub struct LostReturnsTracker {
+    stage: Option<LossSeverity>,
+    /// Candidate stage and how many consecutive packets have shown it
+    pending: Option<(Option<LossSeverity>, usize)>,
+    /// Timestamp and stage of the last reported escalation
+    last_report: Option<(u64, LossSeverity)>,
+}
+
+impl LostReturnsTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Currently confirmed loss stage
+    pub fn stage(&self) -> Option<LossSeverity> {
+        self.stage
+    }
+
+    /// Update with one packet's flow and pit readings.
+    ///
+    /// Returns a `LossEscalation` when a more severe stage is confirmed,
+    /// unless that stage (or a more severe one) was reported less than
+    /// `report_cooldown_secs` ago. De-escalation (including losses healing)
+    /// is adopted silently, once clear of the hysteresis margin.
+    pub fn update(
+        &mut self,
+        timestamp: u64,
+        flow_in_gpm: f64,
+        flow_out_gpm: f64,
+        pit_rate_bbl_hr: f64,
+        cfg: &LostReturnsConfig,
+    ) -> Option<LossEscalation> {
+        // Pumps off: no flow-based reading, hold the current stage
+        if flow_in_gpm < cfg.min_flow_in_gpm {
+            self.pending = None;
+            return None;
+        }
+
+        let mut observed = classify_loss_severity(flow_in_gpm, flow_out_gpm, pit_rate_bbl_hr, cfg);
+        if observed < self.stage {
+            // Hold the stage until the readings are clear of its band
+            let clearing = clearing_bands(cfg);
+            if classify_loss_severity(flow_in_gpm, flow_out_gpm, pit_rate_bbl_hr, &clearing)
+                >= self.stage
+            {
+                observed = self.stage;
+            }
+        }
+        if observed == self.stage {
+            self.pending = None;
+            return None;
+        }
+
+        let count = match self.pending {
+            Some((stage, n)) if stage == observed => n + 1,
+            _ => 1,
+        };
+        if count < cfg.confirm_packets.max(1) {
+            self.pending = Some((observed, count));
+            return None;
+        }
+
+        let from = self.stage;
+        self.stage = observed;
+        self.pending = None;
+
+        let to = observed.filter(|to| Some(*to) > from)?;
+        if let Some((reported_at, reported)) = self.last_report {
+            if to <= reported && timestamp.saturating_sub(reported_at) < cfg.report_cooldown_secs {
+                return None;
+            }
+        }
+        self.last_report = Some((timestamp, to));
+        Some(LossEscalation {
+            timestamp,
+            from,
+            to,
+            deficit_pct: ((flow_in_gpm - flow_out_gpm.max(0.0)) / flow_in_gpm * 100.0).max(0.0),
+            pit_loss_bbl_hr: (-pit_rate_bbl_hr).max(0.0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification_bands() {
+        let cfg = LostReturnsConfig::default();
+        assert_eq!(classify_loss_severity(600.0, 599.0, 0.0, &cfg), None);
+        assert_eq!(
+            classify_loss_severity(600.0, 585.0, 0.0, &cfg),
+            Some(LossSeverity::Seepage)
+        );
+        assert_eq!(
+            classify_loss_severity(600.0, 480.0, 0.0, &cfg),
+            Some(LossSeverity::Partial)
+        );
+        assert_eq!(
+            classify_loss_severity(600.0, 200.0, 0.0, &cfg),
+            Some(LossSeverity::Total)
+        );
+        assert_eq!(
+            classify_loss_severity(600.0, 0.0, -20.0, &cfg),
+            Some(LossSeverity::Complete)
+        );
+        // Zero flow-out with steady pits is a dead sensor, not total losses
+        assert_eq!(classify_loss_severity(600.0, 0.0, 0.0, &cfg), None);
+        // Pit rate alone can stage a loss when flow-out is unreliable
+        assert_eq!(
+            classify_loss_severity(600.0, 600.0, -15.0, &cfg),
+            Some(LossSeverity::Partial)
+        );
+        // Pumps off
+        assert_eq!(classify_loss_severity(0.0, 0.0, -20.0, &cfg), None);
+    }
+
+    #[test]
+    fn test_surface_vs_formation_loss_source() {
+        let cfg = LostReturnsConfig::default();
+
+        // Washpipe leak: SPP bleeds 3000 -> 2700 psi, ECD flat
+        let spp: Vec<f64> = (0..30).map(|i| 3000.0 - i as f64 * 10.0).collect();
+        let ecd = vec![10.8; 30];
+        assert_eq!(
+            classify_loss_source(&spp, &ecd, &cfg),
+            LossSource::SurfaceEquipment
+        );
+
+        // Formation losses: SPP holds, ECD falls as the annulus level drops
+        let spp = vec![3000.0; 30];
+        let ecd: Vec<f64> = (0..30).map(|i| 10.8 - i as f64 * 0.01).collect();
+        assert_eq!(
+            classify_loss_source(&spp, &ecd, &cfg),
+            LossSource::Formation
+        );
+
+        // SPP drop with ECD falling too is downhole
+        let spp: Vec<f64> = (0..30).map(|i| 3000.0 - i as f64 * 10.0).collect();
+        assert_eq!(
+            classify_loss_source(&spp, &ecd, &cfg),
+            LossSource::Formation
+        );
+
+        // No ECD reading — no downhole evidence
+        assert_eq!(
+            classify_loss_source(&spp, &[0.0; 30], &cfg),
+            LossSource::Formation
+        );
+    }
+
+    #[test]
+    fn test_increasing_loss_rate_escalates_through_stages() {
+        let cfg = LostReturnsConfig::default();
+        let mut tracker = LostReturnsTracker::new();
+        let flow_in = 600.0;
+
+        // (flow_out, pit_rate) held for 5 packets each, losses worsening
+        let steps = [
+            (600.0, 0.0),
+            (585.0, -3.0),
+            (500.0, -12.0),
+            (250.0, -45.0),
+            (0.0, -50.0),
+        ];
+
+        let mut escalations = Vec::new();
+        let mut ts = 0;
+        for (flow_out, pit_rate) in steps {
+            for _ in 0..5 {
+                ts += 10;
+                if let Some(e) = tracker.update(ts, flow_in, flow_out, pit_rate, &cfg) {
+                    escalations.push(e);
+                }
+            }
+        }
+
+        let stages: Vec<_> = escalations.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(
+            stages,
+            vec![
+                (None, LossSeverity::Seepage),
+                (Some(LossSeverity::Seepage), LossSeverity::Partial),
+                (Some(LossSeverity::Partial), LossSeverity::Total),
+                (Some(LossSeverity::Total), LossSeverity::Complete),
+            ]
+        );
+        assert_eq!(tracker.stage(), Some(LossSeverity::Complete));
+        assert!(escalations[3].deficit_pct > 99.0);
+
+        // Losses healing de-escalate without another escalation event
+        for _ in 0..5 {
+            ts += 10;
+            assert!(tracker.update(ts, flow_in, 598.0, 0.0, &cfg).is_none());
+        }
+        assert_eq!(tracker.stage(), None);
+    }
+
+    #[test]
+    fn test_seepage_on_band_edge_reported_once() {
+        let cfg = LostReturnsConfig::default();
+        let mut tracker = LostReturnsTracker::new();
+        let mut ts = 0;
+        let mut reports = 0;
+        // Deficit wandering between 1.8% and 2.2% for 6 minutes
+        for i in 0..36 {
+            ts += 10;
+            let flow_out = if (i / 4) % 2 == 0 { 586.8 } else { 589.2 };
+            if tracker.update(ts, 600.0, flow_out, 0.0, &cfg).is_some() {
+                reports += 1;
+            }
+            if i >= 3 {
+                // 1.8% is inside the hysteresis margin: the stage holds
+                assert_eq!(tracker.stage(), Some(LossSeverity::Seepage));
+            }
+        }
+        assert_eq!(reports, 1);
+        assert_eq!(tracker.stage(), Some(LossSeverity::Seepage));
+
+        // Healed, then back to seepage inside the cooldown: adopted silently
+        for _ in 0..5 {
+            ts += 10;
+            assert!(tracker.update(ts, 600.0, 600.0, 0.0, &cfg).is_none());
+        }
+        assert_eq!(tracker.stage(), None);
+        for _ in 0..5 {
+            ts += 10;
+            assert!(tracker.update(ts, 600.0, 585.0, 0.0, &cfg).is_none());
+        }
+        assert_eq!(tracker.stage(), Some(LossSeverity::Seepage));
+
+        // Worse losses are reported regardless of the cooldown
+        let mut escalated = None;
+        for _ in 0..5 {
+            ts += 10;
+            escalated = escalated.or(tracker.update(ts, 600.0, 500.0, 0.0, &cfg));
+        }
+        assert_eq!(escalated.map(|e| e.to), Some(LossSeverity::Partial));
+
+        // After the cooldown a new seepage episode is reported again
+        for _ in 0..5 {
+            ts += 10;
+            tracker.update(ts, 600.0, 600.0, 0.0, &cfg);
+        }
+        ts += cfg.report_cooldown_secs;
+        let mut reported = false;
+        for _ in 0..5 {
+            ts += 10;
+            reported |= tracker.update(ts, 600.0, 585.0, 0.0, &cfg).is_some();
+        }
+        assert!(reported);
+    }
+
+    #[test]
+    fn test_single_packet_spike_not_confirmed() {
+        let cfg = LostReturnsConfig::default();
+        let mut tracker = LostReturnsTracker::new();
+        assert!(tracker.update(10, 600.0, 600.0, 0.0, &cfg).is_none());
+        assert!(tracker.update(20, 600.0, 100.0, 0.0, &cfg).is_none());
+        assert!(tracker.update(30, 600.0, 600.0, 0.0, &cfg).is_none());
+        assert_eq!(tracker.stage(), None);
+    }
+}
@@ -49,13 +49,13 @@ pub fn kurtosis(signal: &[f64]) -> f64 {
 /// Calculate shock factor based on hookload and rig state
 ///
 /// Returns a multiplier indicating shock severity:
-/// - Drilling/Reaming: 1.2 (moderate continuous load)
+/// - Drilling/Reaming/Sliding: 1.2 (moderate continuous load)
 /// - Other: 1.0 (baseline)
 pub fn shock_factor(hookload: f64, state: &RigState) -> f64 {
     let _ = hookload; // Reserved for future load-based adjustments
 
     match state {
-        RigState::Drilling | RigState::Reaming => 1.2,
+        RigState::Drilling | RigState::Reaming | RigState::Sliding => 1.2,
         RigState::Circulating => 1.0,
         RigState::Connection => 1.0,
         RigState::TrippingIn | RigState::TrippingOut => 1.0,
@@ -8,27 +8,53 @@
 //! - `calculate_mse()` - Mechanical Specific Energy
 //! - `calculate_d_exponent()` - Drilling exponent for pore pressure
 //! - `classify_rig_state()` - Operational state classification
+//! - `hardness_calibration` - Drillability coefficient learned from ML reports
 //!
 //! ## Phase 5 Functions (Advanced, run only on ticket)
 //! - `strategic_drilling_analysis()` - Comprehensive trend analysis
+//! - `estimate_bit_wear()` - IADC-style dull estimate from MSE / dxc drift
+//! - `bit_run` - Bit-run hours and run-start MSE / ROP baseline (resets on trip out)
 //! - `detect_kick()` / `detect_lost_circulation()` - Well control
+//! - `lost_returns` - Seepage → partial → total → complete loss staging
+//! - `pump_events` - Pump trip / changeover detection (suppresses flow alarms)
+//! - `choke` - MPD choke adjustments (contextualizes SPP changes)
 //! - `detect_packoff()` / `detect_stick_slip()` - Mechanical issues
-
+//! - `detect_washout()` - Gradual SPP decline at steady pumps (drillstring leak)
+//! - `stick_slip_trend` - Mild → moderate → severe → damaging stick-slip with trend
+//! - `classify_mechanical_rise()` - Pack-off vs cuttings loading on SPP/torque rise
+//! - `detect_drilling_break()` - Sudden sustained ROP increase (flow-check trigger)
+//! - `equipment_limits` - WOB / RPM / flow against rig equipment ratings
+//! - `flow_meter` - Reported flow-in vs pump strokes × displacement
+
+pub mod bit_run;
+pub mod choke;
 pub mod connection_gas;
+pub mod connection_scorecard;
+pub mod directional;
 pub mod drilling_models;
+pub mod equipment_limits;
+pub mod flow_meter;
+pub mod gas_migration;
+pub mod hardness_calibration;
+pub mod lost_returns;
 pub mod metrics;
 pub mod models;
+pub mod pump_events;
+pub mod stick_slip_trend;
 pub mod swab_surge;
+pub mod trapped_pressure;
 
 // Export drilling-specific functions
 pub use drilling_models::{
     calculate_d_exponent, calculate_dxc, calculate_ecd, calculate_mse, calculate_mse_efficiency,
-    calculate_r_squared, calculate_trend, characterize_oscillation, classify_rig_state,
-    detect_founder, detect_founder_quick, detect_kick, detect_lost_circulation, detect_packoff,
-    detect_stick_slip, estimate_optimal_mse, recommend_damping,
+    calculate_r_squared, calculate_trend, characterize_oscillation, classify_mechanical_rise,
+    classify_rig_state, detect_drilling_break, detect_founder, detect_founder_quick, detect_kick,
+    detect_lost_circulation, detect_packoff, detect_stick_slip, detect_swab_surge,
+    detect_washout, estimate_bit_wear, estimate_optimal_mse, recommend_damping, MechanicalRise,
+    RigStateClassifier,
 };
 
-use tracing::warn;
+use tracing::{debug, warn};
 
 use crate::baseline::BaselineOverrides;
 use crate::types::{
@@ -51,14 +77,33 @@ use crate::types::{
 /// - D-exponent and dxc
 /// - Flow balance and pit rate
 /// - Anomaly detection
+///
+/// `drillability_coefficient` is the calibrated expected-ROP coefficient
+/// from `hardness_calibration` (`None` = fixed model).
 pub fn tactical_update(
     packet: &WitsPacket,
     prev_packet: Option<&WitsPacket>,
     baseline_overrides: Option<&BaselineOverrides>,
+    drillability_coefficient: Option<f64>,
 ) -> DrillingMetrics {
-    // Classify operational state
-    let state = classify_rig_state(packet);
+    tactical_update_with_state(
+        packet,
+        classify_rig_state(packet),
+        prev_packet,
+        baseline_overrides,
+        drillability_coefficient,
+    )
+}
 
+/// `tactical_update` with a rig state already classified by the caller
+/// (e.g. debounced by a `RigStateClassifier`).
+pub fn tactical_update_with_state(
+    packet: &WitsPacket,
+    state: RigState,
+    prev_packet: Option<&WitsPacket>,
+    baseline_overrides: Option<&BaselineOverrides>,
+    drillability_coefficient: Option<f64>,
+) -> DrillingMetrics {
     // Calculate MSE (only meaningful during drilling)
     let mse = if state == RigState::Drilling || state == RigState::Reaming {
         calculate_mse(
@@ -124,7 +169,6 @@ pub fn tactical_update(
     // Calculate ECD margin to fracture.
     // Warn once-ish if fracture gradient is unavailable — the 1.5 ppg fallback
     // silences alarms and could mask a real well control issue.
-    let ecd_margin = packet.ecd_margin();
     if packet.fracture_gradient <= 0.0 && packet.ecd > 0.0 {
         use std::sync::Once;
         static FRAC_GRAD_WARN: Once = Once::new();
@@ -132,6 +176,19 @@ pub fn tactical_update(
             warn!("Fracture gradient unavailable (0.0) — ECD margin defaulting to 1.5 ppg; alarms suppressed");
         });
     }
+    // Thermally corrected when mud temperatures are reported; otherwise raw ECD
+    let ecd_margin = if packet.has_mud_temps() {
+        packet.corrected_ecd_margin()
+    } else {
+        if packet.ecd > 0.0 {
+            use std::sync::Once;
+            static MUD_TEMP_WARN: Once = Once::new();
+            MUD_TEMP_WARN.call_once(|| {
+                warn!("Mud temperatures unavailable (0.0) — ECD margin not thermally corrected");
+            });
+        }
+        packet.ecd_margin()
+    };
 
     // Calculate deltas from previous packet
     let (torque_delta_percent, spp_delta) = if let Some(prev) = prev_packet {
@@ -151,8 +208,12 @@ pub fn tactical_update(
     };
 
     // Estimate MSE efficiency
-    let formation_hardness =
-        estimate_formation_hardness_from_rop(packet.rop, packet.wob, packet.rpm);
+    let formation_hardness = estimate_formation_hardness_from_rop(
+        packet.rop,
+        packet.wob,
+        packet.rpm,
+        drillability_coefficient,
+    );
     let optimal_mse = estimate_optimal_mse(formation_hardness);
     let mse_efficiency = calculate_mse_efficiency(mse, optimal_mse);
 
@@ -166,6 +227,7 @@ pub fn tactical_update(
         mse_efficiency,
         torque_delta_percent,
         spp_delta,
+        ecd_margin,
         flow_out_available,
         baseline_overrides,
     );
@@ -195,15 +257,24 @@ pub fn tactical_update(
 /// Estimate formation hardness from drilling parameters
 ///
 /// Uses relationship between ROP, WOB, and RPM to estimate
-/// relative formation hardness on 0-10 scale.
-fn estimate_formation_hardness_from_rop(rop: f64, wob: f64, rpm: f64) -> f64 {
+/// relative formation hardness on 0-10 scale. Expected ROP is
+/// `coefficient × WOB × RPM`, with the coefficient calibrated from ML
+/// analysis when available (see `hardness_calibration`).
+pub(crate) fn estimate_formation_hardness_from_rop(
+    rop: f64,
+    wob: f64,
+    rpm: f64,
+    coefficient: Option<f64>,
+) -> f64 {
     if rop <= 0.0 || wob <= 0.0 || rpm <= 0.0 {
         return 5.0; // Default medium hardness
     }
 
     // Drillability exponent approximation
     // Higher ROP for given WOB/RPM = softer formation
-    let expected_rop = wob * rpm * 0.01; // Simplified model
+    let coefficient =
+        coefficient.unwrap_or(hardness_calibration::FIXED_DRILLABILITY_COEFFICIENT);
+    let expected_rop = wob * rpm * coefficient;
     let drillability = rop / expected_rop.max(1.0);
 
     // Convert to hardness (inverse relationship)
@@ -222,11 +293,23 @@ fn detect_anomalies(
     mse_efficiency: f64,
     torque_delta_percent: f64,
     spp_delta: f64,
+    ecd_margin: f64,
     flow_out_available: bool,
     baseline_overrides: Option<&BaselineOverrides>,
 ) -> (bool, AnomalyCategory, Option<String>) {
+    // Tripping: swab / surge is the well-control risk while moving pipe
+    if *state == RigState::TrippingIn || *state == RigState::TrippingOut {
+        if let Some((category, description, _margin)) =
+            prev_packet.and_then(|prev| detect_swab_surge(prev, packet, state))
+        {
+            return (true, category, Some(description));
+        }
+        return (false, AnomalyCategory::None, None);
+    }
+
     // Only check during active drilling states
     if *state != RigState::Drilling
+        && *state != RigState::Sliding
         && *state != RigState::Reaming
         && *state != RigState::Circulating
     {
@@ -404,8 +487,8 @@ fn detect_anomalies(
     // === HYDRAULICS ===
 
     // ECD margin
-    if packet.ecd_margin() < ecd_warn {
-        let severity_str = if packet.ecd_margin() < ecd_crit {
+    if ecd_margin < ecd_warn {
+        let severity_str = if ecd_margin < ecd_crit {
             "CRITICAL"
         } else {
             "WARNING"
@@ -415,8 +498,7 @@ fn detect_anomalies(
             AnomalyCategory::Hydraulics,
             Some(format!(
                 "{}: ECD margin only {:.2} ppg to fracture",
-                severity_str,
-                packet.ecd_margin()
+                severity_str, ecd_margin
             )),
         );
     }
@@ -530,37 +612,90 @@ pub fn strategic_analysis(history: &[HistoryEntry]) -> DrillingPhysicsReport {
     drilling_models::strategic_drilling_analysis(history)
 }
 
+/// Split history into continuous segments at timestamp gaps longer than
+/// `max_gap_secs` (oldest segment first).
+fn continuous_segments(history: &[HistoryEntry], max_gap_secs: u64) -> Vec<&[HistoryEntry]> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for i in 1..history.len() {
+        let gap = history[i]
+            .packet
+            .timestamp
+            .saturating_sub(history[i - 1].packet.timestamp);
+        if gap > max_gap_secs {
+            segments.push(&history[start..i]);
+            start = i;
+        }
+    }
+    if start < history.len() {
+        segments.push(&history[start..]);
+    }
+    segments
+}
+
+/// Seconds between the first and last packet of a slice
+fn span_secs(history: &[HistoryEntry]) -> u64 {
+    match (history.first(), history.last()) {
+        (Some(first), Some(last)) => last.packet.timestamp.saturating_sub(first.packet.timestamp),
+        _ => 0,
+    }
+}
+
 /// Enhanced strategic analysis for verification system
 ///
 /// Provides additional confidence metrics for verification decisions.
+/// Feed gaps (see `[history_gaps]`) split the history: trends are fitted
+/// only over the latest continuous segment, and `history_hours` /
+/// `data_coverage` count continuous time only.
 pub fn enhanced_strategic_analysis(history: &[HistoryEntry]) -> EnhancedPhysicsReport {
     if history.is_empty() {
         return EnhancedPhysicsReport::default();
     }
 
-    let base = drilling_models::strategic_drilling_analysis(history);
-
-    // Calculate history duration in hours
-    let history_hours = if history.len() >= 2 {
-        let first_ts = history.first().map(|h| h.packet.timestamp).unwrap_or(0);
-        let last_ts = history.last().map(|h| h.packet.timestamp).unwrap_or(0);
-        (last_ts - first_ts) as f64 / 3600.0
+    let gap_cfg = if crate::config::is_initialized() {
+        crate::config::get().history_gaps.clone()
     } else {
-        0.0
+        crate::config::HistoryGapConfig::default()
     };
+    let segments = if gap_cfg.enabled {
+        continuous_segments(history, gap_cfg.max_gap_secs)
+    } else {
+        vec![history]
+    };
+    let gap_count = segments.len().saturating_sub(1) as u32;
+    // Latest continuous run: trends never span a gap
+    let trend_window = segments.last().copied().unwrap_or(history);
+
+    let base = drilling_models::strategic_drilling_analysis(trend_window);
+
+    // Continuous history duration and coverage of the window
+    let continuous_secs: u64 = segments.iter().map(|s| span_secs(s)).sum();
+    let window_secs = span_secs(history);
+    let history_hours = continuous_secs as f64 / 3600.0;
+    let data_coverage = if window_secs > 0 {
+        continuous_secs as f64 / window_secs as f64
+    } else {
+        1.0
+    };
+    if gap_count > 0 {
+        debug!(
+            gap_count,
+            data_coverage, "Strategic analysis: history has feed gaps"
+        );
+    }
 
     // Calculate trend consistency
-    let mse_values: Vec<f64> = history.iter().map(|h| h.metrics.mse).collect();
+    let mse_values: Vec<f64> = trend_window.iter().map(|h| h.metrics.mse).collect();
     let trend_consistency = calculate_r_squared(&mse_values);
 
-    // Calculate confidence factor
-    let depth_factor = (history.len() as f64 / 60.0).min(1.0);
+    // Calculate confidence factor (continuous packets only)
+    let depth_factor = (trend_window.len() as f64 / 60.0).min(1.0);
     let consistency_factor = trend_consistency;
-    let operating_count = history
+    let operating_count = trend_window
         .iter()
         .filter(|h| h.metrics.state == RigState::Drilling || h.metrics.state == RigState::Reaming)
         .count();
-    let operating_factor = operating_count as f64 / history.len().max(1) as f64;
+    let operating_factor = operating_count as f64 / trend_window.len().max(1) as f64;
     let confidence_factor =
         (depth_factor * 0.4 + consistency_factor * 0.3 + operating_factor * 0.3).min(1.0);
 
@@ -578,6 +713,8 @@ pub fn enhanced_strategic_analysis(history: &[HistoryEntry]) -> EnhancedPhysicsR
         trend_consistency,
         confidence_factor,
         history_hours,
+        data_coverage,
+        gap_count,
         is_sustained,
         consecutive_anomaly_count: anomaly_count as u32,
     }
@@ -616,6 +753,7 @@ mod tests {
             co2: 0.0,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 9.0,
             fracture_gradient: 14.0,
             mse: 0.0,
@@ -633,7 +771,7 @@ mod tests {
     #[test]
     fn test_tactical_update_normal_drilling() {
         let packet = create_drilling_packet();
-        let metrics = tactical_update(&packet, None, None);
+        let metrics = tactical_update(&packet, None, None, None);
 
         assert_eq!(metrics.state, RigState::Drilling);
         assert!(
@@ -659,12 +797,43 @@ mod tests {
         packet.pit_volume_change = 8.0; // 8 bbl gain
         packet.gas_units = 200.0; // Elevated gas
 
-        let metrics = tactical_update(&packet, None, None);
+        let metrics = tactical_update(&packet, None, None, None);
 
         assert!(metrics.is_anomaly, "Should detect kick conditions");
         assert_eq!(metrics.anomaly_category, AnomalyCategory::WellControl);
     }
 
+    #[test]
+    fn test_tactical_update_thermally_corrected_ecd_margin() {
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+        let mut packet = create_drilling_packet();
+        packet.ecd = 13.5;
+        packet.fracture_gradient = 14.0;
+        packet.mud_temp_in = 100.0;
+        packet.mud_temp_out = 150.0; // 50°F gradient
+
+        // 0.5 ppg raw margin tightens by 50 × 0.006 = 0.3 ppg
+        assert!((packet.corrected_ecd_with(0.006) - 13.8).abs() < 1e-9);
+
+        // The correction is off by default: raw margin, no alarm
+        assert_eq!(packet.corrected_ecd(), 13.5);
+        let metrics = tactical_update(&packet, None, None, None);
+        assert!((metrics.ecd_margin - 0.5).abs() < 1e-9);
+        assert!(!metrics.is_anomaly);
+
+        // No temperature data: raw margin, no alarm
+        packet.mud_temp_in = 0.0;
+        packet.mud_temp_out = 0.0;
+        let metrics = tactical_update(&packet, None, None, None);
+        assert!((metrics.ecd_margin - 0.5).abs() < 1e-9);
+        assert!(!metrics.is_anomaly);
+    }
+
     #[test]
     fn test_tactical_update_low_efficiency() {
         let mut packet = create_drilling_packet();
@@ -672,7 +841,7 @@ mod tests {
         packet.wob = 35.0; // High WOB
                            // This should result in poor MSE efficiency
 
-        let metrics = tactical_update(&packet, None, None);
+        let metrics = tactical_update(&packet, None, None, None);
 
         // Low efficiency is detected when MSE is higher than optimal
         // MSE efficiency is capped at 100.0, so just verify metrics were calculated
@@ -704,4 +873,52 @@ mod tests {
         packet.rop = 0.0;
         assert_eq!(classify_rig_state(&packet), RigState::Circulating);
     }
+
+    #[test]
+    fn test_enhanced_analysis_history_gap_reduces_coverage() {
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+
+        // 30 s at ~20k psi MSE, a one-hour feed outage, then 30 s at ~40k.
+        // Each side is flat with alternating noise; fitted across the gap the
+        // step would look like a strong MSE trend.
+        let entry = |ts: u64, mse: f64| HistoryEntry {
+            packet: WitsPacket {
+                timestamp: ts,
+                ..create_drilling_packet()
+            },
+            metrics: DrillingMetrics {
+                state: RigState::Drilling,
+                mse,
+                ..DrillingMetrics::default()
+            },
+        };
+        let noise = |i: u64| if i % 2 == 0 { 300.0 } else { -300.0 };
+        let mut history: Vec<HistoryEntry> =
+            (0..30).map(|i| entry(1000 + i, 20_000.0 + noise(i))).collect();
+        history.extend((0..30).map(|i| entry(4630 + i, 40_000.0 + noise(i))));
+
+        let across_gap: Vec<f64> = history.iter().map(|h| h.metrics.mse).collect();
+        assert!(calculate_r_squared(&across_gap) > 0.7);
+
+        let report = enhanced_strategic_analysis(&history);
+        assert_eq!(report.gap_count, 1);
+        // 29 s + 29 s of continuous data in a 3659 s window
+        assert!((report.history_hours - 58.0 / 3600.0).abs() < 1e-9);
+        assert!((report.data_coverage - 58.0 / 3659.0).abs() < 1e-9);
+        assert!(
+            report.trend_consistency < 0.2,
+            "trend fitted across the gap: {}",
+            report.trend_consistency
+        );
+
+        // Continuous history is fully covered
+        let report = enhanced_strategic_analysis(&history[..30]);
+        assert_eq!(report.gap_count, 0);
+        assert!((report.data_coverage - 1.0).abs() < 1e-9);
+    }
 }
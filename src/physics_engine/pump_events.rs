@@ -0,0 +1,257 @@
+//! Pump Failure / Changeover Detection
+//!
+//! When a mud pump trips or the crew swaps pumps, flow-in, pump strokes and
+//! SPP drop abruptly and then come back. Until the annulus settles, flow-out
+//! lags flow-in and the pits move, which looks exactly like a loss (or, on
+//! recovery, a gain) to the flow-balance logic.
+//!
+//! `PumpEventTracker` recognises the coordinated drop: flow-in *and*
+//! `pump_spm` both falling by at least `drop_pct` from one packet to the
+//! next while pumping. The event lasts until flow-in and SPP are back to
+//! `recovery_pct` of their pre-event values, after which flow-based alarms
+//! stay suppressed for `settle_secs` more. Pumps that have not come back
+//! within `max_event_secs` end the event as a failure, and suppression is
+//! lifted so a genuine pumps-off loss is not hidden.
+//!
+//! A feed without pump strokes (`pump_spm` = 0) never triggers an event, and
+//! neither does shutting the pumps down: a drop to zero strokes or zero flow
+//! is a pumps-off, not a trip, and the pits must stay fully monitored. Pumps
+//! that go off mid-event end the event without a settle window.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PumpEventConfig;
+
+/// How a pump event ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PumpEventKind {
+    /// Flow and SPP recovered — a pump swap or a tripped pump brought back
+    Changeover,
+    /// Flow did not recover within `max_event_secs`
+    Failure,
+}
+
+/// A completed pump event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PumpEvent {
+    pub kind: PumpEventKind,
+    /// Unix timestamp of the drop
+    pub start_ts: u64,
+    /// Unix timestamp of recovery (or of giving up)
+    pub end_ts: u64,
+    pub duration_secs: u64,
+    pub pre_flow_gpm: f64,
+    pub pre_spm: f64,
+    pub pre_spp_psi: f64,
+    /// Lowest flow-in during the event
+    pub min_flow_gpm: f64,
+    /// Lowest pump strokes during the event
+    pub min_spm: f64,
+}
+
+/// Pump event in progress
+#[derive(Debug, Clone)]
+struct ActiveEvent {
+    start_ts: u64,
+    pre_flow: f64,
+    pre_spm: f64,
+    pre_spp: f64,
+    min_flow: f64,
+    min_spm: f64,
+}
+
+impl ActiveEvent {
+    fn finish(&self, kind: PumpEventKind, end_ts: u64) -> PumpEvent {
+        PumpEvent {
+            kind,
+            start_ts: self.start_ts,
+            end_ts,
+            duration_secs: end_ts.saturating_sub(self.start_ts),
+            pre_flow_gpm: self.pre_flow,
+            pre_spm: self.pre_spm,
+            pre_spp_psi: self.pre_spp,
+            min_flow_gpm: self.min_flow,
+            min_spm: self.min_spm,
+        }
+    }
+}
+
+/// Detects pump trips / changeovers and the flow-alarm suppression window
+#[derive(Debug, Clone, Default)]
+pub struct PumpEventTracker {
+    /// Last (flow_in, pump_spm, spp) outside an event
+    prev: Option<(f64, f64, f64)>,
+    active: Option<ActiveEvent>,
+    /// Suppression continues until this timestamp after recovery
+    settle_until: u64,
+    last_ts: u64,
+}
+
+impl PumpEventTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True while a pump event is in progress
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// True while flow-based alarms should be suppressed (event in progress
+    /// or still settling after recovery)
+    pub fn is_suppressing(&self) -> bool {
+        self.active.is_some() || self.last_ts < self.settle_until
+    }
+
+    /// Update with one packet. Returns the event once it has ended.
+    pub fn update(
+        &mut self,
+        timestamp: u64,
+        flow_in: f64,
+        pump_spm: f64,
+        spp: f64,
+        cfg: &PumpEventConfig,
+    ) -> Option<PumpEvent> {
+        self.last_ts = timestamp;
+
+        let pumps_off = pump_spm <= 0.0 || flow_in <= 0.0;
+
+        if let Some(active) = self.active.as_mut() {
+            if pumps_off {
+                self.active = None;
+                self.settle_until = timestamp;
+                self.prev = None;
+                return None;
+            }
+            active.min_flow = active.min_flow.min(flow_in);
+            active.min_spm = active.min_spm.min(pump_spm);
+
+            let recovery = cfg.recovery_pct / 100.0;
+            let flow_back = flow_in >= active.pre_flow * recovery;
+            let spp_back = active.pre_spp <= 0.0 || spp >= active.pre_spp * recovery;
+            if flow_back && spp_back {
+                let event = active.finish(PumpEventKind::Changeover, timestamp);
+                self.active = None;
+                self.settle_until = timestamp + cfg.settle_secs;
+                self.prev = Some((flow_in, pump_spm, spp));
+                return Some(event);
+            }
+            if timestamp.saturating_sub(active.start_ts) >= cfg.max_event_secs {
+                let event = active.finish(PumpEventKind::Failure, timestamp);
+                self.active = None;
+                self.settle_until = timestamp;
+                self.prev = Some((flow_in, pump_spm, spp));
+                return Some(event);
+            }
+            return None;
+        }
+
+        if let Some((prev_flow, prev_spm, prev_spp)) = self.prev {
+            let keep = 1.0 - cfg.drop_pct / 100.0;
+            if !pumps_off
+                && prev_flow >= cfg.min_flow_gpm
+                && prev_spm > 0.0
+                && flow_in <= prev_flow * keep
+                && pump_spm <= prev_spm * keep
+            {
+                self.active = Some(ActiveEvent {
+                    start_ts: timestamp,
+                    pre_flow: prev_flow,
+                    pre_spm: prev_spm,
+                    pre_spp: prev_spp,
+                    min_flow: flow_in,
+                    min_spm: pump_spm,
+                });
+                return None;
+            }
+        }
+        self.prev = Some((flow_in, pump_spm, spp));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_and_recover_is_changeover_with_suppression() {
+        let cfg = PumpEventConfig::default();
+        let mut tracker = PumpEventTracker::new();
+
+        // Steady pumping: 600 gpm, 120 spm, 3000 psi
+        for ts in 0..10 {
+            assert!(tracker.update(ts, 600.0, 120.0, 3000.0, &cfg).is_none());
+        }
+        assert!(!tracker.is_suppressing());
+
+        // One of two pumps trips
+        tracker.update(10, 300.0, 60.0, 1100.0, &cfg);
+        assert!(tracker.is_active());
+        assert!(tracker.is_suppressing());
+        for ts in 11..40 {
+            assert!(tracker.update(ts, 300.0, 60.0, 1100.0, &cfg).is_none());
+            assert!(tracker.is_suppressing());
+        }
+
+        // Standby pump brought on line
+        let event = tracker
+            .update(40, 595.0, 118.0, 2950.0, &cfg)
+            .expect("recovery ends the event");
+        assert_eq!(event.kind, PumpEventKind::Changeover);
+        assert_eq!(event.start_ts, 10);
+        assert_eq!(event.duration_secs, 30);
+        assert_eq!(event.pre_flow_gpm, 600.0);
+        assert_eq!(event.min_flow_gpm, 300.0);
+        assert!(!tracker.is_active());
+
+        // Still settling, then suppression lifts
+        tracker.update(40 + cfg.settle_secs - 1, 600.0, 120.0, 3000.0, &cfg);
+        assert!(tracker.is_suppressing());
+        tracker.update(40 + cfg.settle_secs, 600.0, 120.0, 3000.0, &cfg);
+        assert!(!tracker.is_suppressing());
+    }
+
+    #[test]
+    fn test_flow_drop_without_stroke_drop_is_not_a_pump_event() {
+        let cfg = PumpEventConfig::default();
+        let mut tracker = PumpEventTracker::new();
+        tracker.update(0, 600.0, 120.0, 3000.0, &cfg);
+        // Flow meter drops but strokes hold — not the pumps
+        tracker.update(1, 300.0, 120.0, 3000.0, &cfg);
+        assert!(!tracker.is_suppressing());
+    }
+
+    #[test]
+    fn test_unrecovered_pumps_end_as_failure() {
+        let cfg = PumpEventConfig::default();
+        let mut tracker = PumpEventTracker::new();
+        tracker.update(0, 600.0, 120.0, 3000.0, &cfg);
+        tracker.update(1, 300.0, 60.0, 1100.0, &cfg);
+        assert!(tracker.is_active());
+
+        let event = tracker
+            .update(1 + cfg.max_event_secs, 300.0, 60.0, 1100.0, &cfg)
+            .expect("timeout ends the event");
+        assert_eq!(event.kind, PumpEventKind::Failure);
+        assert!(!tracker.is_suppressing());
+    }
+
+    #[test]
+    fn test_pumps_off_is_not_a_pump_event() {
+        let cfg = PumpEventConfig::default();
+        let mut tracker = PumpEventTracker::new();
+        tracker.update(0, 600.0, 120.0, 3000.0, &cfg);
+        // Normal shutdown for a connection
+        assert!(tracker.update(1, 0.0, 0.0, 0.0, &cfg).is_none());
+        assert!(!tracker.is_suppressing());
+
+        // Pumps switched off part-way through a trip end it without settling
+        tracker.update(2, 600.0, 120.0, 3000.0, &cfg);
+        tracker.update(3, 300.0, 60.0, 1100.0, &cfg);
+        assert!(tracker.is_active());
+        assert!(tracker.update(4, 0.0, 0.0, 0.0, &cfg).is_none());
+        assert!(!tracker.is_suppressing());
+    }
+}
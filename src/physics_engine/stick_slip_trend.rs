@@ -0,0 +1,321 @@
+//! Stick-Slip Severity Trend
+//!
+//! `detect_stick_slip()` answers "is it happening?". Downhole tools — RSS
+//! electronics and mud-motor power sections in particular — are damaged by
+//! how long and how hard stick-slip runs, so crews also need to know whether
+//! it is getting worse.
+//!
+//! Each drilling packet contributes one torque CV sample. The CV is banded
+//! against the same `thresholds.mechanical.stick_slip_cv_warning` /
+//! `stick_slip_cv_critical` that `detect_stick_slip()` uses: mild from the
+//! warning threshold, moderate from halfway to critical, severe from
+//! critical. Severe stick-slip that persists for `damaging_after_samples` is
+//! promoted to *damaging*. The slope of the recent CV samples gives the
+//! trend. `StickSlipTrendTracker` reports each escalation once, and re-arms
+//! when the CV falls `clear_hysteresis_pct` below the warning threshold.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{MechanicalThresholds, StickSlipTrendConfig};
+use crate::physics_engine::drilling_models::calculate_trend;
+
+/// Stick-slip classification, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickSlipClass {
+    Mild,
+    Moderate,
+    Severe,
+    /// Severe stick-slip sustained long enough to damage downhole tools
+    Damaging,
+}
+
+impl StickSlipClass {
+    /// Upper-case label for advisory text
+    pub fn label(self) -> &'static str {
+        match self {
+            StickSlipClass::Mild => "MILD",
+            StickSlipClass::Moderate => "MODERATE",
+            StickSlipClass::Severe => "SEVERE",
+            StickSlipClass::Damaging => "DAMAGING",
+        }
+    }
+
+    /// Class-appropriate RPM / WOB mitigation
+    pub fn recommendation(self) -> &'static str {
+        match self {
+            StickSlipClass::Mild => {
+                "Increase surface RPM 5-10% to carry the string through the stick \
+                 phase; hold WOB."
+            }
+            StickSlipClass::Moderate => {
+                "Reduce WOB 10% and increase RPM 10%; confirm torque CV falls \
+                 within a few minutes."
+            }
+            StickSlipClass::Severe => {
+                "Reduce WOB 15-20% and increase RPM; if torque does not smooth \
+                 out, pick up off bottom and restart rotation before re-applying \
+                 weight."
+            }
+            StickSlipClass::Damaging => {
+                "Pick up off bottom and stop the stick-slip now. Restart at reduced \
+                 WOB and higher RPM, and flag the RSS / motor for inspection at \
+                 the next trip."
+            }
+        }
+    }
+}
+
+/// Direction of the recent torque CV samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeverityTrend {
+    Worsening,
+    Stable,
+    Improving,
+}
+
+impl SeverityTrend {
+    pub fn label(self) -> &'static str {
+        match self {
+            SeverityTrend::Worsening => "worsening",
+            SeverityTrend::Stable => "stable",
+            SeverityTrend::Improving => "improving",
+        }
+    }
+}
+
+/// Coefficient of variation of a torque window.
+///
+/// Returns `None` for an empty window or non-positive mean torque.
+pub fn torque_cv(torque_values: &[f64]) -> Option<f64> {
+    let valid: Vec<f64> = torque_values
+        .iter()
+        .copied()
+        .filter(|v| v.is_finite())
+        .collect();
+    if valid.is_empty() {
+        return None;
+    }
+    let n = valid.len() as f64;
+    let mean = valid.iter().sum::<f64>() / n;
+    if mean <= 0.0 {
+        return None;
+    }
+    let variance = valid.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    Some(variance.sqrt() / mean)
+}
+
+/// Band a torque CV (`None` = below the stick-slip warning threshold).
+///
+/// Only the CV bands are classified here; `Damaging` requires persistence
+/// and is assigned by `StickSlipTrendTracker`.
+pub fn classify_stick_slip(cv: f64, mech: &MechanicalThresholds) -> Option<StickSlipClass> {
+    let warning = mech.stick_slip_cv_warning;
+    let critical = mech.stick_slip_cv_critical;
+    if cv >= critical {
+        Some(StickSlipClass::Severe)
+    } else if cv >= (warning + critical) / 2.0 {
+        Some(StickSlipClass::Moderate)
+    } else if cv >= warning {
+        Some(StickSlipClass::Mild)
+    } else {
+        None
+    }
+}
+
+/// A stick-slip escalation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickSlipTrendReport {
+    /// Classification before the escalation (`None` = no stick-slip)
+    pub from: Option<StickSlipClass>,
+    /// Newly reached classification
+    pub to: StickSlipClass,
+    pub trend: SeverityTrend,
+    /// Latest torque CV
+    pub cv: f64,
+    /// CV change per sample over the trend window
+    pub cv_slope: f64,
+    /// Consecutive samples at severe or worse
+    pub severe_samples: usize,
+}
+
+/// Tracks the stick-slip classification and its trend
+#[derive(Debug, Clone, Default)]
+pub struct StickSlipTrendTracker {
+    recent_cv: VecDeque<f64>,
+    severe_samples: usize,
+    reported: Option<StickSlipClass>,
+}
+
+impl StickSlipTrendTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Highest classification reported since stick-slip last died out
+    pub fn reported(&self) -> Option<StickSlipClass> {
+        self.reported
+    }
+
+    /// Trend of the CV samples currently in the window
+    pub fn trend(&self, cfg: &StickSlipTrendConfig) -> (SeverityTrend, f64) {
+        let samples: Vec<f64> = self.recent_cv.iter().copied().collect();
+        let slope = calculate_trend(&samples);
+        let trend = if samples.len() < 2 {
+            SeverityTrend::Stable
+        } else if slope > cfg.trend_threshold_per_sample {
+            SeverityTrend::Worsening
+        } else if slope < -cfg.trend_threshold_per_sample {
+            SeverityTrend::Improving
+        } else {
+            SeverityTrend::Stable
+        };
+        (trend, slope)
+    }
+
+    /// Update with one torque CV sample.
+    ///
+    /// Returns a report when the classification escalates past the last
+    /// reported one. De-escalation is adopted silently; dropping
+    /// `clear_hysteresis_pct` below the warning threshold re-arms the tracker.
+    pub fn update(
+        &mut self,
+        cv: f64,
+        mech: &MechanicalThresholds,
+        cfg: &StickSlipTrendConfig,
+    ) -> Option<StickSlipTrendReport> {
+        self.recent_cv.push_back(cv);
+        while self.recent_cv.len() > cfg.trend_window.max(2) {
+            self.recent_cv.pop_front();
+        }
+
+        let class = classify_stick_slip(cv, mech);
+        if class == Some(StickSlipClass::Severe) {
+            self.severe_samples += 1;
+        } else {
+            self.severe_samples = 0;
+        }
+        let class = match class {
+            Some(StickSlipClass::Severe) if self.severe_samples >= cfg.damaging_after_samples => {
+                Some(StickSlipClass::Damaging)
+            }
+            other => other,
+        };
+
+        let Some(to) = class else {
+            let clear_cv = mech.stick_slip_cv_warning * (1.0 - cfg.clear_hysteresis_pct / 100.0);
+            if cv < clear_cv {
+                self.reported = None;
+            }
+            return None;
+        };
+        if Some(to) <= self.reported {
+            return None;
+        }
+
+        let from = self.reported.replace(to);
+        let (trend, cv_slope) = self.trend(cfg);
+        Some(StickSlipTrendReport {
+            from,
+            to,
+            trend,
+            cv,
+            cv_slope,
+            severe_samples: self.severe_samples,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_torque_cv() {
+        assert_eq!(torque_cv(&[]), None);
+        assert_eq!(torque_cv(&[0.0, 0.0]), None);
+        assert_eq!(torque_cv(&[10.0; 5]), Some(0.0));
+        let cv = torque_cv(&[8.0, 12.0, 8.0, 12.0]).expect("cv");
+        assert!((cv - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_increasing_cv_worsens_and_escalates_to_damaging() {
+        let cfg = StickSlipTrendConfig::default();
+        let mech = MechanicalThresholds::default();
+        let mut tracker = StickSlipTrendTracker::new();
+
+        // CV ramps from 0.05 to 0.35 over 30 samples
+        let mut reports = Vec::new();
+        for i in 0..30 {
+            let cv = 0.05 + i as f64 * 0.01;
+            if let Some(r) = tracker.update(cv, &mech, &cfg) {
+                reports.push(r);
+            }
+        }
+        let classes: Vec<_> = reports.iter().map(|r| (r.from, r.to)).collect();
+        assert_eq!(
+            classes,
+            vec![
+                (None, StickSlipClass::Mild),
+                (Some(StickSlipClass::Mild), StickSlipClass::Moderate),
+                (Some(StickSlipClass::Moderate), StickSlipClass::Severe),
+            ]
+        );
+        // Nothing below the stick-slip warning threshold
+        assert!(reports[0].cv >= mech.stick_slip_cv_warning);
+        assert!(reports
+            .iter()
+            .skip(1)
+            .all(|r| r.trend == SeverityTrend::Worsening));
+
+        // Holding severe: no repeat until it has persisted long enough
+        let mut damaging = None;
+        for n in 0..cfg.damaging_after_samples {
+            if let Some(r) = tracker.update(0.35, &mech, &cfg) {
+                damaging = Some((n, r));
+                break;
+            }
+        }
+        let (n, report) = damaging.expect("sustained severe escalates to damaging");
+        assert_eq!(report.from, Some(StickSlipClass::Severe));
+        assert_eq!(report.to, StickSlipClass::Damaging);
+        assert!(report.severe_samples >= cfg.damaging_after_samples);
+        assert!(n > 0);
+
+        // Stick-slip dying out re-arms the tracker, and the trend turns
+        for i in 0..10 {
+            tracker.update(0.30 - i as f64 * 0.03, &mech, &cfg);
+        }
+        assert_eq!(tracker.trend(&cfg).0, SeverityTrend::Improving);
+        assert_eq!(tracker.reported(), None);
+    }
+
+    #[test]
+    fn test_cv_hovering_at_warning_reports_once() {
+        let cfg = StickSlipTrendConfig::default();
+        let mech = MechanicalThresholds::default();
+        let mut tracker = StickSlipTrendTracker::new();
+
+        let warning = mech.stick_slip_cv_warning;
+        let reports = (0..40)
+            .map(|i| {
+                if i % 2 == 0 {
+                    warning + 0.005
+                } else {
+                    warning - 0.005
+                }
+            })
+            .filter_map(|cv| tracker.update(cv, &mech, &cfg))
+            .count();
+        assert_eq!(reports, 1);
+
+        // Well below the warning threshold re-arms
+        tracker.update(warning * 0.5, &mech, &cfg);
+        assert_eq!(tracker.reported(), None);
+        assert!(tracker.update(warning + 0.005, &mech, &cfg).is_some());
+    }
+}
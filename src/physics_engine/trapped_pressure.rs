@@ -0,0 +1,470 @@
+//! Post-Connection Trapped Pressure Detection
+//!
+//! After a connection the pumps are brought back online and the standpipe
+//! pressure briefly overshoots its drilling value before bleeding back down.
+//! A modest, quickly-bleeding spike is normal. An abnormally large spike, or
+//! one that does not bleed off, points to a plugged jet or a restriction in
+//! the string and is raised as a Hydraulics advisory.
+//!
+//! ## Detection Logic
+//!
+//! 1. Connections are found by the same drilling → connection state machine
+//!    that drives connection gas (`ConnectionGasTracker`), which also
+//!    remembers the steady drilling SPP and flow before the connection
+//! 2. Pumps off during a connection: wait for them to come back. A move into
+//!    tripping abandons the connection — the next pump start is not a
+//!    post-connection transient
+//! 3. Pumps back on: observe the SPP transient (peak + time to bleed back
+//!    within `settle_tolerance_psi` of the pre-connection SPP). Bleed-off only
+//!    counts once the pumps are back up to the drilling flow rate, so SPP
+//!    passing through the band on the way up a ramp is not a bleed-off
+//! 4. Compare overshoot and bleed time against the learned distribution of
+//!    recent normal connections; normal transients extend the baseline
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::config::TrappedPressureConfig;
+use crate::physics_engine::connection_gas::{ConnectionGasTracker, ConnectionStart};
+use crate::types::{RigState, WitsPacket};
+
+/// Floor on the learned bleed-time standard deviation (seconds).
+///
+/// Prevents a perfectly repeatable connection routine (std ≈ 0) from
+/// flagging a transient that is only a couple of seconds slower.
+const MIN_BLEED_STD_SECS: f64 = 5.0;
+
+/// Fraction of the pre-connection flow-in at which the pumps count as back
+/// up to rate and bleed-off is evaluated
+const STABLE_FLOW_FRACTION: f64 = 0.95;
+
+/// Pump phase within a connection
+#[derive(Debug, Clone)]
+enum Phase {
+    /// Waiting for the pumps to go off during a connection
+    Idle,
+    /// Pumps off during a connection
+    PumpsOff { connection: ConnectionStart },
+    /// Pumps back on — observing the pressure transient
+    Transient {
+        connection: ConnectionStart,
+        start_ts: u64,
+        peak_spp: f64,
+    },
+}
+
+/// A completed post-connection pressure transient
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrappedPressureEvent {
+    /// Unix timestamp when the transient finished (bled off or window expired)
+    pub timestamp: u64,
+    /// Bit depth at the connection (ft)
+    pub depth_ft: f64,
+    /// Steady drilling SPP before the connection (psi)
+    pub pre_spp_psi: f64,
+    /// Peak SPP after pumps resumed (psi)
+    pub peak_spp_psi: f64,
+    /// Peak SPP above the pre-connection value (psi)
+    pub overshoot_psi: f64,
+    /// Seconds from pump start until SPP bled off; `None` if it never did
+    pub bleed_secs: Option<u64>,
+    /// Whether the transient was flagged as abnormal
+    pub abnormal: bool,
+    /// Overshoot exceeded the learned limit
+    #[serde(default)]
+    pub overshoot_exceeded: bool,
+    /// Bleed-off was slower than the learned limit, or never happened
+    #[serde(default)]
+    pub bleed_exceeded: bool,
+    /// Human-readable reason when abnormal
+    pub reason: Option<String>,
+    /// Number of normal connections in the learned baseline at evaluation time
+    pub learned_connections: usize,
+}
+
+/// Outcome of comparing a transient against the learned baseline
+struct Evaluation {
+    overshoot_exceeded: bool,
+    bleed_exceeded: bool,
+    reason: Option<String>,
+}
+
+/// Learns the normal pump-resume transient and flags abnormal ones
+#[derive(Debug, Clone)]
+pub struct TrappedPressureTracker {
+    connections: ConnectionGasTracker,
+    phase: Phase,
+    /// Start of the last connection already evaluated or abandoned, so each
+    /// connection yields at most one transient
+    handled_connection: Option<u64>,
+    /// (overshoot_psi, bleed_secs) from recent normal connections
+    learned: VecDeque<(f64, f64)>,
+    latest: Option<TrappedPressureEvent>,
+}
+
+impl Default for TrappedPressureTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrappedPressureTracker {
+    pub fn new() -> Self {
+        Self {
+            connections: ConnectionGasTracker::new(),
+            phase: Phase::Idle,
+            handled_connection: None,
+            learned: VecDeque::new(),
+            latest: None,
+        }
+    }
+
+    /// Update the tracker with a new packet.
+    ///
+    /// Returns a `TrappedPressureEvent` when a post-connection transient completes.
+    pub fn update(
+        &mut self,
+        packet: &WitsPacket,
+        rig_state: RigState,
+        cfg: &TrappedPressureConfig,
+    ) -> Option<TrappedPressureEvent> {
+        let pumps_on = packet.flow_in >= cfg.pump_on_flow_gpm;
+        self.connections.update(packet, rig_state);
+
+        match &self.phase {
+            Phase::Idle => {
+                let connection = self
+                    .connections
+                    .connection_in_progress()
+                    .filter(|c| c.pre_spp > 0.0)
+                    .filter(|c| self.handled_connection != Some(c.start_ts));
+                if let Some(connection) = connection.filter(|_| !pumps_on) {
+                    self.phase = Phase::PumpsOff { connection };
+                }
+                None
+            }
+            Phase::PumpsOff { connection } => {
+                let connection = *connection;
+                if matches!(rig_state, RigState::TrippingIn | RigState::TrippingOut) {
+                    // Pulled out of the connection into a trip — abandon it
+                    self.handled_connection = Some(connection.start_ts);
+                    self.phase = Phase::Idle;
+                    return None;
+                }
+                if pumps_on {
+                    self.phase = Phase::Transient {
+                        connection,
+                        start_ts: packet.timestamp,
+                        peak_spp: packet.spp,
+                    };
+                    // The first pumping packet can already be back within tolerance
+                    return self.check_transient(packet, cfg);
+                }
+                None
+            }
+            Phase::Transient { connection, .. } => {
+                if !pumps_on {
+                    // Pumps tripped again before the transient settled — restart
+                    self.phase = Phase::PumpsOff {
+                        connection: *connection,
+                    };
+                    return None;
+                }
+                self.check_transient(packet, cfg)
+            }
+        }
+    }
+
+    /// Advance an in-progress transient, finalizing it when SPP bleeds off
+    /// or the observation window expires.
+    fn check_transient(
+        &mut self,
+        packet: &WitsPacket,
+        cfg: &TrappedPressureConfig,
+    ) -> Option<TrappedPressureEvent> {
+        let Phase::Transient {
+            connection,
+            start_ts,
+            ref mut peak_spp,
+        } = self.phase
+        else {
+            return None;
+        };
+
+        if packet.spp > *peak_spp {
+            *peak_spp = packet.spp;
+        }
+        let peak_spp = *peak_spp;
+        let pre_spp = connection.pre_spp;
+        let elapsed = packet.timestamp.saturating_sub(start_ts);
+
+        let pumps_at_rate = packet.flow_in >= connection.pre_flow * STABLE_FLOW_FRACTION;
+        let bled_off = pumps_at_rate && (packet.spp - pre_spp).abs() <= cfg.settle_tolerance_psi;
+        let bleed_secs = if bled_off {
+            Some(elapsed)
+        } else if elapsed >= cfg.transient_window_secs {
+            None
+        } else {
+            return None;
+        };
+
+        let overshoot = (peak_spp - pre_spp).max(0.0);
+        let evaluation = self.evaluate(overshoot, bleed_secs, cfg);
+        let abnormal = evaluation.overshoot_exceeded || evaluation.bleed_exceeded;
+
+        if !abnormal {
+            if self.learned.len() >= cfg.max_learned_connections.max(1) {
+                self.learned.pop_front();
+            }
+            let bleed = bleed_secs.unwrap_or(cfg.transient_window_secs) as f64;
+            self.learned.push_back((overshoot, bleed));
+        }
+
+        let event = TrappedPressureEvent {
+            timestamp: packet.timestamp,
+            depth_ft: connection.depth_ft,
+            pre_spp_psi: pre_spp,
+            peak_spp_psi: peak_spp,
+            overshoot_psi: overshoot,
+            bleed_secs,
+            abnormal,
+            overshoot_exceeded: evaluation.overshoot_exceeded,
+            bleed_exceeded: evaluation.bleed_exceeded,
+            reason: evaluation.reason,
+            learned_connections: self.learned.len(),
+        };
+
+        self.handled_connection = Some(connection.start_ts);
+        self.phase = Phase::Idle;
+        self.latest = Some(event.clone());
+        Some(event)
+    }
+
+    /// Compare a completed transient against the learned baseline.
+    fn evaluate(
+        &self,
+        overshoot: f64,
+        bleed_secs: Option<u64>,
+        cfg: &TrappedPressureConfig,
+    ) -> Evaluation {
+        if self.learned.len() < cfg.min_connections_to_learn {
+            return Evaluation {
+                overshoot_exceeded: false,
+                bleed_exceeded: false,
+                reason: None,
+            };
+        }
+
+        let n = self.learned.len() as f64;
+        let (mean_os, std_os) = mean_std(self.learned.iter().map(|(o, _)| *o), n);
+        let (mean_bl, std_bl) = mean_std(self.learned.iter().map(|(_, b)| *b), n);
+
+        let os_limit = mean_os + cfg.sigma_threshold * std_os.max(cfg.settle_tolerance_psi);
+        let bl_limit = mean_bl + cfg.sigma_threshold * std_bl.max(MIN_BLEED_STD_SECS);
+
+        let overshoot_exceeded = overshoot > os_limit;
+        let bleed_exceeded = bleed_secs.map_or(true, |secs| secs as f64 > bl_limit);
+
+        let mut reasons = Vec::new();
+        if overshoot_exceeded {
+            reasons.push(format!(
+                "overshoot {overshoot:.0} psi exceeds learned limit {os_limit:.0} psi (normal {mean_os:.0} psi)"
+            ));
+        }
+        match bleed_secs {
+            None => reasons.push(format!(
+                "SPP did not bleed off within {} s (normal {mean_bl:.0} s)",
+                cfg.transient_window_secs
+            )),
+            Some(secs) if secs as f64 > bl_limit => reasons.push(format!(
+                "bleed-off took {secs} s, exceeds learned limit {bl_limit:.0} s (normal {mean_bl:.0} s)"
+            )),
+            Some(_) => {}
+        }
+
+        Evaluation {
+            overshoot_exceeded,
+            bleed_exceeded,
+            reason: (!reasons.is_empty()).then(|| reasons.join("; ")),
+        }
+    }
+
+    /// Most recent completed transient (normal or abnormal)
+    pub fn latest_event(&self) -> Option<&TrappedPressureEvent> {
+        self.latest.as_ref()
+    }
+
+    /// Number of normal connections in the learned baseline
+    pub fn learned_count(&self) -> usize {
+        self.learned.len()
+    }
+}
+
+fn mean_std(values: impl Iterator<Item = f64> + Clone, n: f64) -> (f64, f64) {
+    let mean = values.clone().sum::<f64>() / n;
+    let variance = values.map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_packet(timestamp: u64, flow_in: f64, spp: f64) -> WitsPacket {
+        let mut p = WitsPacket::default();
+        p.timestamp = timestamp;
+        p.bit_depth = 8000.0;
+        p.flow_in = flow_in;
+        p.spp = spp;
+        p
+    }
+
+    /// Drive one drilling → connection → pump-resume cycle.
+    ///
+    /// `transient` is the SPP sequence (1 Hz) after pumps come back on.
+    fn run_connection(
+        tracker: &mut TrappedPressureTracker,
+        cfg: &TrappedPressureConfig,
+        start_ts: u64,
+        transient: &[f64],
+    ) -> Option<TrappedPressureEvent> {
+        let transient: Vec<(f64, f64)> = transient.iter().map(|&spp| (500.0, spp)).collect();
+        run_connection_with_flow(tracker, cfg, start_ts, &transient)
+    }
+
+    /// Like `run_connection`, with `(flow_in, spp)` pairs for the transient.
+    fn run_connection_with_flow(
+        tracker: &mut TrappedPressureTracker,
+        cfg: &TrappedPressureConfig,
+        start_ts: u64,
+        transient: &[(f64, f64)],
+    ) -> Option<TrappedPressureEvent> {
+        let mut ts = start_ts;
+        for _ in 0..5 {
+            tracker.update(&make_packet(ts, 500.0, 3000.0), RigState::Drilling, cfg);
+            ts += 1;
+        }
+        for _ in 0..60 {
+            tracker.update(&make_packet(ts, 0.0, 0.0), RigState::Connection, cfg);
+            ts += 1;
+        }
+        let mut event = None;
+        for &(flow, spp) in transient {
+            if let Some(ev) =
+                tracker.update(&make_packet(ts, flow, spp), RigState::Circulating, cfg)
+            {
+                event = Some(ev);
+            }
+            ts += 1;
+        }
+        event
+    }
+
+    /// Normal transient: ~150 psi overshoot, bleeds off in ~6 s.
+    const NORMAL: [f64; 8] = [
+        2800.0, 3150.0, 3120.0, 3090.0, 3070.0, 3040.0, 3020.0, 3000.0,
+    ];
+
+    fn learned_tracker(cfg: &TrappedPressureConfig) -> TrappedPressureTracker {
+        let mut tracker = TrappedPressureTracker::new();
+        for i in 0..cfg.min_connections_to_learn {
+            let ev = run_connection(&mut tracker, cfg, i as u64 * 1000, &NORMAL)
+                .expect("normal connection should complete");
+            assert!(!ev.abnormal, "learning connections must not be flagged");
+        }
+        tracker
+    }
+
+    #[test]
+    fn test_normal_transient_not_flagged() {
+        let cfg = TrappedPressureConfig::default();
+        let mut tracker = learned_tracker(&cfg);
+        let ev = run_connection(&mut tracker, &cfg, 100_000, &NORMAL).unwrap();
+        assert!(!ev.abnormal, "normal transient flagged: {:?}", ev.reason);
+        assert!((ev.overshoot_psi - 150.0).abs() < 1e-6);
+        assert_eq!(tracker.learned_count(), cfg.min_connections_to_learn + 1);
+    }
+
+    #[test]
+    fn test_oversized_transient_flagged() {
+        let cfg = TrappedPressureConfig::default();
+        let mut tracker = learned_tracker(&cfg);
+        let oversized = [2800.0, 3900.0, 3600.0, 3300.0, 3100.0, 3020.0];
+        let ev = run_connection(&mut tracker, &cfg, 100_000, &oversized).unwrap();
+        assert!(ev.abnormal);
+        assert!(ev.overshoot_exceeded);
+        assert!(!ev.bleed_exceeded);
+        // Abnormal transients do not contaminate the learned baseline
+        assert_eq!(tracker.learned_count(), cfg.min_connections_to_learn);
+    }
+
+    #[test]
+    fn test_slow_bleed_transient_flagged() {
+        let cfg = TrappedPressureConfig::default();
+        let mut tracker = learned_tracker(&cfg);
+        // Modest overshoot that never bleeds back within the window
+        let slow: Vec<f64> = (0..=cfg.transient_window_secs).map(|_| 3200.0).collect();
+        let ev = run_connection(&mut tracker, &cfg, 100_000, &slow).unwrap();
+        assert!(ev.abnormal);
+        assert!(ev.bleed_secs.is_none());
+        assert!(ev.bleed_exceeded);
+        assert!(ev.reason.as_deref().unwrap().contains("did not bleed off"));
+    }
+
+    #[test]
+    fn test_ramping_pumps_do_not_bleed_off_early() {
+        let cfg = TrappedPressureConfig::default();
+        let mut tracker = learned_tracker(&cfg);
+        // SPP crosses the pre-connection value while the pumps are still
+        // ramping, then spikes once they reach drilling rate
+        let ramp = [
+            (150.0, 2000.0),
+            (250.0, 3000.0),
+            (350.0, 3400.0),
+            (500.0, 3900.0),
+            (500.0, 3500.0),
+            (500.0, 3200.0),
+            (500.0, 3020.0),
+        ];
+        let ev = run_connection_with_flow(&mut tracker, &cfg, 100_000, &ramp).unwrap();
+        assert_eq!(ev.bleed_secs, Some(6));
+        assert!(ev.overshoot_exceeded, "ramp hid the spike: {ev:?}");
+    }
+
+    #[test]
+    fn test_trip_abandons_connection() {
+        let cfg = TrappedPressureConfig::default();
+        let mut tracker = learned_tracker(&cfg);
+        let mut ts = 100_000;
+        for _ in 0..5 {
+            tracker.update(&make_packet(ts, 500.0, 3000.0), RigState::Drilling, &cfg);
+            ts += 1;
+        }
+        for state in [RigState::Connection, RigState::TrippingOut, RigState::Idle] {
+            for _ in 0..30 {
+                tracker.update(&make_packet(ts, 0.0, 0.0), state, &cfg);
+                ts += 1;
+            }
+        }
+        // Pumps back on after the trip: not a post-connection transient
+        for spp in [3900.0, 3800.0, 3700.0] {
+            assert!(tracker
+                .update(&make_packet(ts, 500.0, spp), RigState::Circulating, &cfg)
+                .is_none());
+            ts += 1;
+        }
+
+        // The next real connection is evaluated again
+        let ev = run_connection(&mut tracker, &cfg, ts, &NORMAL).unwrap();
+        assert!(!ev.abnormal, "{:?}", ev.reason);
+    }
+
+    #[test]
+    fn test_no_flagging_before_learning_complete() {
+        let cfg = TrappedPressureConfig::default();
+        let mut tracker = TrappedPressureTracker::new();
+        let oversized = [2800.0, 3900.0, 3600.0, 3300.0, 3100.0, 3020.0];
+        let ev = run_connection(&mut tracker, &cfg, 0, &oversized).unwrap();
+        assert!(!ev.abnormal);
+    }
+}
@@ -0,0 +1,304 @@
+//! Acknowledgment Expiry
+//!
+//! An operator acknowledgment is not permanent: it expires after
+//! `advisory.ack_ttl_seconds`, or the shorter
+//! `advisory.critical_ack_ttl_seconds` when the acknowledged advisory was
+//! CRITICAL, so a stale ack for a condition that is still active does not
+//! linger in the acknowledgment store. Acknowledgments never suppress new
+//! advisories; every advisory still reaches the dashboard.
+//!
+//! The processing loop calls `sweep_expired()` once per packet and removes
+//! the returned records from the sled tree as well. A TTL of 0 never expires.
+//!
+//! `bulk_acknowledge()` backs `POST /api/v2/advisories/ack`: it resolves each
+//! referenced advisory and reports a per-item outcome, so a shift clearing a
+//! backlog of advisories sees exactly which ones were not acknowledged.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use super::AppState;
+use crate::api::handlers::{AckReasonCode, AcknowledgmentRecord};
+use crate::config::AdvisoryConfig;
+use crate::storage::feedback::{FeedbackRecord, FeedbackSubmission};
+use crate::storage::history::StorageError;
+use crate::types::{AnomalyCategory, RiskLevel, StrategicAdvisory};
+
+/// Most advisories accepted in one bulk acknowledgment
+pub const MAX_BULK_ACKS: usize = 200;
+
+/// Category and criticality of the advisory an operator is acknowledging.
+///
+/// Resolved from the latest advisory when its timestamp matches the ticket;
+/// otherwise the acknowledgment is recorded without a category and expires on
+/// the normal TTL.
+pub fn ack_subject(state: &AppState, ticket_timestamp: u64) -> (Option<AnomalyCategory>, bool) {
+    state
+        .latest_advisory
+        .as_ref()
+        .filter(|adv| adv.timestamp == ticket_timestamp)
+        .map(|adv| (Some(adv.category), adv.risk_level == RiskLevel::Critical))
+        .unwrap_or((None, false))
+}
+
+/// Lifetime of an acknowledgment in seconds (0 = never expires)
+pub fn ttl_secs(record: &AcknowledgmentRecord, cfg: &AdvisoryConfig) -> u64 {
+    if record.critical {
+        cfg.critical_ack_ttl_seconds
+    } else {
+        cfg.ack_ttl_seconds
+    }
+}
+
+/// Whether `record` has outlived its TTL at wall-clock time `now` (seconds)
+pub fn is_expired(record: &AcknowledgmentRecord, now: u64, cfg: &AdvisoryConfig) -> bool {
+    let ttl = ttl_secs(record, cfg);
+    ttl > 0 && now >= record.acknowledged_at.saturating_add(ttl)
+}
+
+/// Drop expired acknowledgments, returning the removed records so the caller
+/// can delete them from persistent storage.
+pub fn sweep_expired(
+    acks: &mut VecDeque<AcknowledgmentRecord>,
+    now: u64,
+    cfg: &AdvisoryConfig,
+) -> Vec<AcknowledgmentRecord> {
+    let mut expired = Vec::new();
+    acks.retain(|record| {
+        if is_expired(record, now, cfg) {
+            expired.push(record.clone());
+            false
+        } else {
+            true
+        }
+    });
+    expired
+}
+
+/// Request body for `POST /api/v2/advisories/ack`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkAckRequest {
+    /// Timestamps of the advisories being acknowledged
+    pub advisory_timestamps: Vec<u64>,
+    /// Who acknowledged (crew role or name)
+    pub submitted_by: String,
+    /// Shared note applied to every acknowledgment
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub reason_code: Option<AckReasonCode>,
+}
+
+/// Outcome for one advisory in a bulk acknowledgment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAckStatus {
+    Acknowledged,
+    /// No stored advisory has this timestamp
+    NotFound,
+    /// Timestamp repeated earlier in the same request
+    Duplicate,
+    /// Advisory lookup failed (storage error)
+    Failed,
+}
+
+/// Per-item result of a bulk acknowledgment
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkAckItem {
+    pub advisory_timestamp: u64,
+    pub status: BulkAckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Records produced by a bulk acknowledgment, ready to persist
+#[derive(Debug, Default)]
+pub struct BulkAckOutcome {
+    pub items: Vec<BulkAckItem>,
+    pub records: Vec<AcknowledgmentRecord>,
+    /// Operator feedback implied by the reason code
+    pub feedback: Vec<FeedbackRecord>,
+}
+
+/// Resolve every advisory in `request` through `lookup` and build the
+/// acknowledgment (and feedback) records for the ones that exist.
+pub fn bulk_acknowledge(
+    request: BulkAckRequest,
+    well_id: &str,
+    now: u64,
+    lookup: impl Fn(u64) -> Result<Option<StrategicAdvisory>, StorageError>,
+) -> BulkAckOutcome {
+    let mut outcome = BulkAckOutcome::default();
+    let mut seen = std::collections::HashSet::new();
+    let action_taken = request
+        .reason_code
+        .map(|code| code.as_str().to_string())
+        .unwrap_or_default();
+
+    for ts in request.advisory_timestamps {
+        let (status, error) = if !seen.insert(ts) {
+            (BulkAckStatus::Duplicate, None)
+        } else {
+            match lookup(ts) {
+                Ok(Some(advisory)) => {
+                    outcome.records.push(AcknowledgmentRecord {
+                        ticket_timestamp: ts,
+                        acknowledged_by: request.submitted_by.clone(),
+                        acknowledged_at: now,
+                        notes: request.notes.clone(),
+                        action_taken: action_taken.clone(),
+                        category: Some(advisory.category),
+                        critical: advisory.risk_level == RiskLevel::Critical,
+                        reason_code: request.reason_code,
+                    });
+                    if let Some(outcome_kind) = request
+                        .reason_code
+                        .and_then(AckReasonCode::feedback_outcome)
+                    {
+                        let submission = FeedbackSubmission {
+                            outcome: outcome_kind,
+                            submitted_by: request.submitted_by.clone(),
+                            notes: request.notes.clone(),
+                            actual_cause: None,
+                            action_taken: Some(action_taken.clone()),
+                        };
+                        outcome.feedback.push(FeedbackRecord::from_submission(
+                            &advisory, submission, well_id, now,
+                        ));
+                    }
+                    (BulkAckStatus::Acknowledged, None)
+                }
+                Ok(None) => (BulkAckStatus::NotFound, None),
+                Err(e) => (BulkAckStatus::Failed, Some(e.to_string())),
+            }
+        };
+        outcome.items.push(BulkAckItem {
+            advisory_timestamp: ts,
+            status,
+            error,
+        });
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ack(
+        acknowledged_at: u64,
+        category: AnomalyCategory,
+        critical: bool,
+    ) -> AcknowledgmentRecord {
+        AcknowledgmentRecord {
+            ticket_timestamp: acknowledged_at,
+            acknowledged_by: "driller".to_string(),
+            acknowledged_at,
+            notes: String::new(),
+            action_taken: "monitored".to_string(),
+            category: Some(category),
+            critical,
+            reason_code: None,
+        }
+    }
+
+    #[test]
+    fn test_critical_ack_expires_before_normal_ack() {
+        let cfg = AdvisoryConfig {
+            ack_ttl_seconds: 14_400,
+            critical_ack_ttl_seconds: 1_800,
+            ..AdvisoryConfig::default()
+        };
+        let acked_at = 1_700_000_000;
+        let mut acks = VecDeque::from(vec![
+            ack(acked_at, AnomalyCategory::WellControl, true),
+            ack(acked_at, AnomalyCategory::Hydraulics, false),
+        ]);
+
+        // Mock clock inside the critical TTL: both acks are kept
+        assert!(sweep_expired(&mut acks, acked_at + 1_799, &cfg).is_empty());
+
+        // Advance past the critical TTL: only the critical ack is dropped
+        let expired = sweep_expired(&mut acks, acked_at + 1_800, &cfg);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].category, Some(AnomalyCategory::WellControl));
+        assert_eq!(acks.len(), 1);
+
+        // The non-critical ack lives on its longer TTL
+        assert!(sweep_expired(&mut acks, acked_at + 14_399, &cfg).is_empty());
+        assert_eq!(sweep_expired(&mut acks, acked_at + 14_400, &cfg).len(), 1);
+        assert!(acks.is_empty());
+    }
+
+    #[test]
+    fn test_bulk_ack_reports_per_item_outcome() {
+        let stored = [
+            StrategicAdvisory {
+                timestamp: 100,
+                category: AnomalyCategory::WellControl,
+                risk_level: RiskLevel::Critical,
+                ..Default::default()
+            },
+            StrategicAdvisory {
+                timestamp: 200,
+                category: AnomalyCategory::Mechanical,
+                risk_level: RiskLevel::Elevated,
+                ..Default::default()
+            },
+        ];
+        let lookup = |ts: u64| match ts {
+            999 => Err(StorageError::NotInitialized),
+            _ => Ok(stored.iter().find(|a| a.timestamp == ts).cloned()),
+        };
+        let request = BulkAckRequest {
+            advisory_timestamps: vec![100, 150, 200, 100, 999],
+            submitted_by: "driller".to_string(),
+            notes: "pump trip".to_string(),
+            reason_code: Some(AckReasonCode::FalseAlarm),
+        };
+
+        let outcome = bulk_acknowledge(request, "Well-A", 5_000, lookup);
+        let statuses: Vec<_> = outcome.items.iter().map(|i| i.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                BulkAckStatus::Acknowledged,
+                BulkAckStatus::NotFound,
+                BulkAckStatus::Acknowledged,
+                BulkAckStatus::Duplicate,
+                BulkAckStatus::Failed,
+            ]
+        );
+        assert!(outcome.items[4].error.is_some());
+
+        assert_eq!(outcome.records.len(), 2);
+        assert!(outcome.records[0].critical);
+        assert_eq!(
+            outcome.records[1].category,
+            Some(AnomalyCategory::Mechanical)
+        );
+        assert!(outcome
+            .records
+            .iter()
+            .all(|r| r.reason_code == Some(AckReasonCode::FalseAlarm) && r.notes == "pump trip"));
+
+        // A false alarm feeds the false-positive statistics
+        assert_eq!(outcome.feedback.len(), 2);
+        assert!(outcome
+            .feedback
+            .iter()
+            .all(|f| f.outcome == crate::storage::feedback::FeedbackOutcome::FalsePositive));
+    }
+
+    #[test]
+    fn test_zero_ttl_never_expires() {
+        let cfg = AdvisoryConfig {
+            ack_ttl_seconds: 0,
+            ..AdvisoryConfig::default()
+        };
+        let mut acks = VecDeque::from(vec![ack(100, AnomalyCategory::Mechanical, false)]);
+        assert!(sweep_expired(&mut acks, u64::MAX, &cfg).is_empty());
+        assert_eq!(acks.len(), 1);
+    }
+}
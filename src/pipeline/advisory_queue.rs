@@ -0,0 +1,161 @@
+//! Pending Event Advisories
+//!
+//! The event checkers (gas migration, lost returns, washout, ...) are
+//! edge-triggered: each reports an episode once and then stays quiet until
+//! it re-arms. Only one advisory leaves the coordinator per packet, and a
+//! ticket or a periodic summary takes that slot, so an advisory raised on a
+//! busy packet would otherwise be lost for the whole episode.
+//!
+//! Every advisory a checker raises is queued here and dispatched on the next
+//! packet that has no ticket, highest priority first (FIFO within a source).
+//! Safety sources are dispatched ahead of the periodic summary.
+
+use std::collections::VecDeque;
+
+use tracing::warn;
+
+use crate::types::StrategicAdvisory;
+
+/// Queued advisories kept at most; the lowest-priority oldest entry is
+/// dropped first.
+const MAX_PENDING_ADVISORIES: usize = 32;
+
+/// Event advisory sources, in dispatch priority order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AdvisorySource {
+    GasMigration,
+    LostReturns,
+    DrillingBreak,
+    TrappedPressure,
+    Washout,
+    StickSlip,
+    EquipmentLimits,
+    FlowMeter,
+    MudProgram,
+    Lookahead,
+}
+
+impl AdvisorySource {
+    /// Well-control sources dispatched ahead of the periodic summary
+    pub fn is_safety(self) -> bool {
+        matches!(
+            self,
+            AdvisorySource::GasMigration
+                | AdvisorySource::LostReturns
+                | AdvisorySource::DrillingBreak
+        )
+    }
+}
+
+/// Event advisories waiting for a packet without a ticket
+#[derive(Debug, Default)]
+pub struct PendingAdvisories {
+    queue: VecDeque<(AdvisorySource, StrategicAdvisory)>,
+}
+
+impl PendingAdvisories {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue an advisory raised this packet
+    pub fn push(&mut self, source: AdvisorySource, advisory: StrategicAdvisory) {
+        if self.queue.len() >= MAX_PENDING_ADVISORIES {
+            if let Some(drop_at) = self.lowest_priority_index() {
+                if let Some((dropped, _)) = self.queue.remove(drop_at) {
+                    warn!(
+                        source = ?dropped,
+                        "Pending advisory queue full — dropping oldest lowest-priority advisory"
+                    );
+                }
+            }
+        }
+        self.queue.push_back((source, advisory));
+    }
+
+    /// Highest-priority pending safety advisory
+    pub fn pop_safety(&mut self) -> Option<StrategicAdvisory> {
+        self.pop_where(AdvisorySource::is_safety)
+    }
+
+    /// Highest-priority pending advisory of any source
+    pub fn pop(&mut self) -> Option<StrategicAdvisory> {
+        self.pop_where(|_| true)
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    fn pop_where(&mut self, filter: impl Fn(AdvisorySource) -> bool) -> Option<StrategicAdvisory> {
+        let index = self
+            .queue
+            .iter()
+            .enumerate()
+            .filter(|(_, (source, _))| filter(*source))
+            .min_by_key(|(i, (source, _))| (*source, *i))
+            .map(|(i, _)| i)?;
+        self.queue.remove(index).map(|(_, advisory)| advisory)
+    }
+
+    fn lowest_priority_index(&self) -> Option<usize> {
+        self.queue
+            .iter()
+            .enumerate()
+            .max_by_key(|(i, (source, _))| (*source, std::cmp::Reverse(*i)))
+            .map(|(i, _)| i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advisory(trigger: &str) -> StrategicAdvisory {
+        StrategicAdvisory {
+            trigger_parameter: trigger.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dispatch_by_priority_then_fifo() {
+        let mut pending = PendingAdvisories::new();
+        pending.push(AdvisorySource::MudProgram, advisory("mud"));
+        pending.push(AdvisorySource::Washout, advisory("washout-1"));
+        pending.push(AdvisorySource::GasMigration, advisory("gas"));
+        pending.push(AdvisorySource::Washout, advisory("washout-2"));
+
+        let order: Vec<String> = std::iter::from_fn(|| pending.pop())
+            .map(|a| a.trigger_parameter)
+            .collect();
+        assert_eq!(order, ["gas", "washout-1", "washout-2", "mud"]);
+    }
+
+    #[test]
+    fn test_pop_safety_leaves_other_sources_queued() {
+        let mut pending = PendingAdvisories::new();
+        pending.push(AdvisorySource::StickSlip, advisory("stick_slip"));
+        assert!(pending.pop_safety().is_none());
+
+        pending.push(AdvisorySource::LostReturns, advisory("losses"));
+        assert_eq!(pending.pop_safety().unwrap().trigger_parameter, "losses");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending.pop().unwrap().trigger_parameter, "stick_slip");
+    }
+
+    #[test]
+    fn test_full_queue_drops_lowest_priority_oldest() {
+        let mut pending = PendingAdvisories::new();
+        pending.push(AdvisorySource::Lookahead, advisory("lookahead-old"));
+        for _ in 1..MAX_PENDING_ADVISORIES {
+            pending.push(AdvisorySource::Lookahead, advisory("lookahead"));
+        }
+        pending.push(AdvisorySource::GasMigration, advisory("gas"));
+
+        assert_eq!(pending.len(), MAX_PENDING_ADVISORIES);
+        assert_eq!(pending.pop().unwrap().trigger_parameter, "gas");
+        assert!(std::iter::from_fn(|| pending.pop()).all(|a| a.trigger_parameter == "lookahead"));
+    }
+}
@@ -0,0 +1,474 @@
+//! Drilling Performance Charts
+//!
+//! Whole-well series for the two standard drilling charts, kept compact
+//! enough to hold for the life of the well:
+//!
+//! - **Time-depth**: one point per `time_resolution_secs` with the hole depth
+//!   (its high-water mark, so the curve never goes backwards) and the bit
+//!   depth (which shows trips).
+//! - **ROP-depth**: mean on-bottom ROP per `depth_resolution_ft` interval,
+//!   from packets classified as drilling.
+//! - **MSE-depth log**: mean MSE and MSE efficiency per interval, from valid
+//!   MSE only — rotary drilling on bottom with ROP above
+//!   `physics.min_rop_for_mse`. Slides, reaming and low-ROP packets are
+//!   counted as excluded, and intervals with no valid MSE are served as gaps.
+//!
+//! All are thinned to at most `max_points` when served at
+//! `GET /api/v2/charts/time-depth`, `GET /api/v2/charts/rop-depth` and
+//! `GET /api/v2/logs/mse`.
+//!
+//! The series live in memory only: a restart starts them over from the
+//! first packet it sees, so "whole well" means since the last start.
+//! Replay the well's data to rebuild them.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::config::ChartsConfig;
+use crate::types::{DrillingMetrics, RigState, WitsPacket};
+
+/// Resolution change (ft) below which a reloaded `depth_resolution_ft` is
+/// treated as unchanged
+const RESOLUTION_TOLERANCE_FT: f64 = 1e-6;
+
+/// One time-depth sample
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct TimeDepthPoint {
+    pub timestamp: u64,
+    /// Deepest hole depth reached so far (ft)
+    pub hole_depth_ft: f64,
+    pub bit_depth_ft: f64,
+}
+
+/// Mean ROP over one depth interval
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct RopDepthPoint {
+    /// Top of the interval (ft)
+    pub depth_ft: f64,
+    pub rop_ft_hr: f64,
+    /// Drilling packets averaged
+    pub samples: usize,
+}
+
+/// MSE over one depth interval; `None` values are gaps (no valid MSE)
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MseDepthPoint {
+    /// Top of the interval (ft)
+    pub depth_ft: f64,
+    /// Mean MSE (psi)
+    pub mse_psi: Option<f64>,
+    /// Mean MSE efficiency (%) over samples where it was assessable
+    pub mse_efficiency_pct: Option<f64>,
+    /// Valid MSE samples averaged
+    pub samples: usize,
+    /// Packets at the bit rejected by the MSE validity guards
+    pub excluded: usize,
+}
+
+/// Time-depth chart served at `GET /api/v2/charts/time-depth`
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeDepthChart {
+    /// Seconds between retained samples (after thinning)
+    pub resolution_secs: u64,
+    pub points: Vec<TimeDepthPoint>,
+}
+
+/// ROP-depth chart served at `GET /api/v2/charts/rop-depth`
+#[derive(Debug, Clone, Serialize)]
+pub struct RopDepthChart {
+    /// Depth interval per point (after merging)
+    pub resolution_ft: f64,
+    pub points: Vec<RopDepthPoint>,
+}
+
+/// MSE-depth log served at `GET /api/v2/logs/mse`
+#[derive(Debug, Clone, Serialize)]
+pub struct MseDepthLog {
+    /// Depth interval per point (after merging)
+    pub resolution_ft: f64,
+    /// Every interval from the shallowest to the deepest recorded, top down
+    pub points: Vec<MseDepthPoint>,
+}
+
+/// Running sums for one MSE interval
+#[derive(Debug, Clone, Copy, Default)]
+struct MseBucket {
+    mse_sum: f64,
+    samples: usize,
+    efficiency_sum: f64,
+    efficiency_samples: usize,
+    excluded: usize,
+}
+
+impl MseBucket {
+    fn add(&mut self, other: &MseBucket) {
+        self.mse_sum += other.mse_sum;
+        self.samples += other.samples;
+        self.efficiency_sum += other.efficiency_sum;
+        self.efficiency_samples += other.efficiency_samples;
+        self.excluded += other.excluded;
+    }
+}
+
+/// Accumulates the chart series from processed packets
+#[derive(Debug, Clone, Default)]
+pub struct ChartLog {
+    time_depth: Vec<TimeDepthPoint>,
+    hole_depth_hwm: f64,
+    /// Interval index -> (ROP sum, samples)
+    rop_buckets: BTreeMap<i64, (f64, usize)>,
+    /// Interval index -> MSE sums
+    mse_buckets: BTreeMap<i64, MseBucket>,
+    /// Interval size the buckets were built with
+    bucket_ft: f64,
+}
+
+impl ChartLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one processed packet.
+    pub fn record(&mut self, packet: &WitsPacket, state: RigState, cfg: &ChartsConfig) {
+        let depth = if packet.hole_depth > 0.0 {
+            packet.hole_depth
+        } else {
+            packet.bit_depth
+        };
+        if !depth.is_finite() || depth <= 0.0 {
+            return;
+        }
+        self.hole_depth_hwm = self.hole_depth_hwm.max(depth);
+
+        let due = self.time_depth.last().is_none_or(|last| {
+            packet.timestamp >= last.timestamp + cfg.time_resolution_secs.max(1)
+        });
+        if due {
+            self.time_depth.push(TimeDepthPoint {
+                timestamp: packet.timestamp,
+                hole_depth_ft: self.hole_depth_hwm,
+                bit_depth_ft: packet.bit_depth,
+            });
+        }
+
+        if state == RigState::Drilling && packet.rop > 0.0 && packet.rop.is_finite() {
+            let index = self.bucket_index(packet.bit_depth, cfg);
+            let bucket = self.rop_buckets.entry(index).or_insert((0.0, 0));
+            bucket.0 += packet.rop;
+            bucket.1 += 1;
+        }
+    }
+
+    /// Record one packet's MSE for the MSE-depth log. Only rotary drilling on
+    /// bottom with ROP above `min_rop_for_mse` is valid; other packets at the
+    /// bit (sliding, reaming, low ROP) are counted as excluded.
+    pub fn record_mse(
+        &mut self,
+        packet: &WitsPacket,
+        metrics: &DrillingMetrics,
+        min_rop_for_mse: f64,
+        cfg: &ChartsConfig,
+    ) {
+        let at_bit = matches!(
+            metrics.state,
+            RigState::Drilling | RigState::Sliding | RigState::Reaming
+        );
+        if !at_bit || !packet.bit_depth.is_finite() || packet.bit_depth <= 0.0 {
+            return;
+        }
+        let valid = metrics.state == RigState::Drilling
+            && packet.rop > min_rop_for_mse
+            && metrics.mse > 0.0
+            && metrics.mse.is_finite();
+
+        let index = self.bucket_index(packet.bit_depth, cfg);
+        let bucket = self.mse_buckets.entry(index).or_default();
+        if !valid {
+            bucket.excluded += 1;
+            return;
+        }
+        bucket.mse_sum += metrics.mse;
+        bucket.samples += 1;
+        if metrics.mse_efficiency > 0.0 {
+            bucket.efficiency_sum += metrics.mse_efficiency;
+            bucket.efficiency_samples += 1;
+        }
+    }
+
+    /// Interval index for a depth. A resolution change (config reload)
+    /// restarts the depth-binned series.
+    fn bucket_index(&mut self, depth: f64, cfg: &ChartsConfig) -> i64 {
+        if (self.bucket_ft - cfg.depth_resolution_ft).abs() > RESOLUTION_TOLERANCE_FT {
+            self.rop_buckets.clear();
+            self.mse_buckets.clear();
+            self.bucket_ft = cfg.depth_resolution_ft;
+        }
+        (depth / self.bucket_ft).floor() as i64
+    }
+
+    /// Time-depth series thinned to `max_points` (the latest point is kept).
+    pub fn time_depth(&self, cfg: &ChartsConfig) -> TimeDepthChart {
+        let stride = self.time_depth.len().div_ceil(cfg.max_points.max(1)).max(1);
+        let mut points: Vec<TimeDepthPoint> =
+            self.time_depth.iter().step_by(stride).copied().collect();
+        if let (Some(last), Some(kept)) = (self.time_depth.last(), points.last()) {
+            if kept.timestamp != last.timestamp {
+                points.push(*last);
+            }
+        }
+        TimeDepthChart {
+            resolution_secs: cfg.time_resolution_secs.max(1) * stride as u64,
+            points,
+        }
+    }
+
+    /// ROP-depth series; adjacent intervals are merged (sample-weighted)
+    /// until there are at most `max_points`.
+    pub fn rop_depth(&self, cfg: &ChartsConfig) -> RopDepthChart {
+        let span = match (
+            self.rop_buckets.keys().next(),
+            self.rop_buckets.keys().next_back(),
+        ) {
+            (Some(first), Some(last)) => (last - first + 1) as usize,
+            _ => 0,
+        };
+        let merge = span.div_ceil(cfg.max_points.max(1)).max(1) as i64;
+        let resolution_ft = self.bucket_ft * merge as f64;
+
+        let mut merged: BTreeMap<i64, (f64, usize)> = BTreeMap::new();
+        for (&index, &(sum, count)) in &self.rop_buckets {
+            let entry = merged.entry(index.div_euclid(merge)).or_insert((0.0, 0));
+            entry.0 += sum;
+            entry.1 += count;
+        }
+
+        RopDepthChart {
+            resolution_ft,
+            points: merged
+                .into_iter()
+                .map(|(index, (sum, count))| RopDepthPoint {
+                    depth_ft: index as f64 * resolution_ft,
+                    rop_ft_hr: sum / count as f64,
+                    samples: count,
+                })
+                .collect(),
+        }
+    }
+
+    /// MSE-depth log over every interval between the shallowest and deepest
+    /// recorded, with gaps where no valid MSE was seen; adjacent intervals
+    /// are merged until there are at most `max_points`.
+    pub fn mse_log(&self, cfg: &ChartsConfig) -> MseDepthLog {
+        let (Some(&first), Some(&last)) = (
+            self.mse_buckets.keys().next(),
+            self.mse_buckets.keys().next_back(),
+        ) else {
+            return MseDepthLog {
+                resolution_ft: self.bucket_ft.max(cfg.depth_resolution_ft),
+                points: Vec::new(),
+            };
+        };
+        let span = (last - first + 1) as usize;
+        let merge = span.div_ceil(cfg.max_points.max(1)).max(1) as i64;
+        let resolution_ft = self.bucket_ft * merge as f64;
+
+        let mut merged: BTreeMap<i64, MseBucket> = BTreeMap::new();
+        for (&index, bucket) in &self.mse_buckets {
+            merged
+                .entry(index.div_euclid(merge))
+                .or_default()
+                .add(bucket);
+        }
+
+        let points = (first.div_euclid(merge)..=last.div_euclid(merge))
+            .map(|index| {
+                let bucket = merged.get(&index).copied().unwrap_or_default();
+                MseDepthPoint {
+                    depth_ft: index as f64 * resolution_ft,
+                    mse_psi: (bucket.samples > 0).then(|| bucket.mse_sum / bucket.samples as f64),
+                    mse_efficiency_pct: (bucket.efficiency_samples > 0)
+                        .then(|| bucket.efficiency_sum / bucket.efficiency_samples as f64),
+                    samples: bucket.samples,
+                    excluded: bucket.excluded,
+                }
+            })
+            .collect();
+
+        MseDepthLog {
+            resolution_ft,
+            points,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(ts: u64, bit_depth: f64, hole_depth: f64, rop: f64) -> WitsPacket {
+        WitsPacket {
+            timestamp: ts,
+            bit_depth,
+            hole_depth,
+            rop,
+            ..WitsPacket::default()
+        }
+    }
+
+    #[test]
+    fn test_drilling_sequence_builds_time_depth_and_rop_depth() {
+        let cfg = ChartsConfig {
+            time_resolution_secs: 60,
+            depth_resolution_ft: 10.0,
+            ..ChartsConfig::default()
+        };
+        let mut log = ChartLog::new();
+        let mut ts = 0;
+        let mut depth = 5000.0;
+
+        // 5000-5020 ft at 60 ft/hr (1 ft/min), one packet per 10 s
+        while depth < 5020.0 {
+            log.record(&packet(ts, depth, depth, 60.0), RigState::Drilling, &cfg);
+            ts += 10;
+            depth += 1.0 / 6.0;
+        }
+        // Pick up 90 ft for a survey: hole depth holds, bit depth drops
+        for i in 0..30 {
+            let bit = depth - 3.0 * i as f64;
+            log.record(&packet(ts, bit, depth, 0.0), RigState::TrippingOut, &cfg);
+            ts += 10;
+        }
+        // Back to bottom and drill on to 5029.5 ft at 120 ft/hr
+        while depth < 5029.5 {
+            log.record(&packet(ts, depth, depth, 120.0), RigState::Drilling, &cfg);
+            ts += 10;
+            depth += 1.0 / 3.0;
+        }
+
+        let td = log.time_depth(&cfg);
+        assert_eq!(td.resolution_secs, 60);
+        assert!(td.points.len() > 20);
+        assert!(td
+            .points
+            .windows(2)
+            .all(|w| w[1].hole_depth_ft >= w[0].hole_depth_ft && w[1].timestamp > w[0].timestamp));
+        assert!(td
+            .points
+            .iter()
+            .any(|p| p.bit_depth_ft < p.hole_depth_ft - 50.0));
+
+        let rd = log.rop_depth(&cfg);
+        assert_eq!(rd.resolution_ft, 10.0);
+        let depths: Vec<f64> = rd.points.iter().map(|p| p.depth_ft).collect();
+        assert_eq!(depths, vec![5000.0, 5010.0, 5020.0]);
+        assert!((rd.points[0].rop_ft_hr - 60.0).abs() < 1e-9);
+        assert!((rd.points[1].rop_ft_hr - 60.0).abs() < 1e-9);
+        assert!((rd.points[2].rop_ft_hr - 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reload_with_rounding_noise_keeps_rop_buckets() {
+        let mut cfg = ChartsConfig {
+            depth_resolution_ft: 3.3,
+            ..ChartsConfig::default()
+        };
+        let mut log = ChartLog::new();
+        log.record(&packet(0, 100.0, 100.0, 60.0), RigState::Drilling, &cfg);
+
+        // Same resolution up to float rounding (e.g. after a unit conversion)
+        cfg.depth_resolution_ft = 3.3 / 0.0254 * 0.0254;
+        assert_ne!(cfg.depth_resolution_ft, 3.3);
+        log.record(&packet(10, 100.1, 100.1, 60.0), RigState::Drilling, &cfg);
+        assert_eq!(log.rop_depth(&cfg).points[0].samples, 2);
+
+        // A real change still restarts the series
+        cfg.depth_resolution_ft = 10.0;
+        log.record(&packet(20, 100.2, 100.2, 60.0), RigState::Drilling, &cfg);
+        assert_eq!(log.rop_depth(&cfg).points[0].samples, 1);
+    }
+
+    #[test]
+    fn test_mse_log_has_values_in_valid_intervals_and_gaps_elsewhere() {
+        let cfg = ChartsConfig {
+            depth_resolution_ft: 10.0,
+            ..ChartsConfig::default()
+        };
+        let min_rop = 5.0;
+        let mut log = ChartLog::new();
+        let metrics = |state: RigState, mse: f64, efficiency: f64| DrillingMetrics {
+            state,
+            mse,
+            mse_efficiency: efficiency,
+            ..DrillingMetrics::default()
+        };
+
+        // (interval top, state, ROP, MSE, efficiency) — 20 packets per interval
+        let sequence = [
+            (5000.0, RigState::Drilling, 60.0, 30_000.0, 80.0),
+            (5010.0, RigState::Sliding, 30.0, 0.0, 0.0),
+            (5020.0, RigState::Drilling, 2.0, 90_000.0, 20.0), // ROP below guard
+            (5030.0, RigState::Drilling, 40.0, 50_000.0, 50.0),
+        ];
+        let mut ts = 0;
+        for (top, state, rop, mse, efficiency) in sequence {
+            for i in 0..20 {
+                let depth = top + i as f64 * 0.5;
+                let p = packet(ts, depth, depth, rop);
+                log.record_mse(&p, &metrics(state, mse, efficiency), min_rop, &cfg);
+                ts += 1;
+            }
+        }
+        // Reaming back through the first interval adds no MSE samples
+        let p = packet(ts, 5005.0, 5040.0, 0.0);
+        log.record_mse(&p, &metrics(RigState::Reaming, 0.0, 0.0), min_rop, &cfg);
+        // Tripping is not at the bit at all
+        let p = packet(ts + 1, 4000.0, 5040.0, 0.0);
+        log.record_mse(&p, &metrics(RigState::TrippingOut, 0.0, 0.0), min_rop, &cfg);
+
+        let mse_log = log.mse_log(&cfg);
+        assert_eq!(mse_log.resolution_ft, 10.0);
+        let depths: Vec<f64> = mse_log.points.iter().map(|p| p.depth_ft).collect();
+        assert_eq!(depths, vec![5000.0, 5010.0, 5020.0, 5030.0]);
+
+        let first = &mse_log.points[0];
+        assert_eq!(first.mse_psi, Some(30_000.0));
+        assert_eq!(first.mse_efficiency_pct, Some(80.0));
+        assert_eq!((first.samples, first.excluded), (20, 1));
+
+        for gap in &mse_log.points[1..3] {
+            assert_eq!(gap.mse_psi, None);
+            assert_eq!(gap.mse_efficiency_pct, None);
+            assert_eq!((gap.samples, gap.excluded), (0, 20));
+        }
+
+        let last = &mse_log.points[3];
+        assert_eq!(last.mse_psi, Some(50_000.0));
+        assert_eq!(last.mse_efficiency_pct, Some(50.0));
+    }
+
+    #[test]
+    fn test_series_are_thinned_to_max_points() {
+        let cfg = ChartsConfig {
+            time_resolution_secs: 1,
+            depth_resolution_ft: 1.0,
+            max_points: 10,
+            ..ChartsConfig::default()
+        };
+        let mut log = ChartLog::new();
+        for i in 0..100u64 {
+            let depth = 1000.0 + i as f64;
+            log.record(&packet(i, depth, depth, 50.0), RigState::Drilling, &cfg);
+        }
+
+        let td = log.time_depth(&cfg);
+        assert!(td.points.len() <= 11);
+        assert_eq!(td.points.last().map(|p| p.timestamp), Some(99));
+        assert_eq!(td.resolution_secs, 10);
+
+        let rd = log.rop_depth(&cfg);
+        assert_eq!(rd.points.len(), 10);
+        assert_eq!(rd.resolution_ft, 10.0);
+        assert_eq!(rd.points.iter().map(|p| p.samples).sum::<usize>(), 100);
+    }
+}
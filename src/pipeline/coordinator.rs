@@ -38,6 +38,16 @@ use crate::config::defaults::{
 /// Cycle-time warning threshold (ms). Template-based advisories are fast.
 const CYCLE_TARGET_MS: u128 = 100;
 
+/// Provisional (learning-phase) advisories held for promotion at baseline lock.
+const MAX_PROVISIONAL_ADVISORIES: usize = 100;
+
+/// Longest the persisted bit run may lag behind the live one (packet time)
+const BIT_RUN_SAVE_INTERVAL_SECS: u64 = 300;
+
+/// Consecutive pumping evaluations without an SPP decline before a reported
+/// washout re-arms
+const WASHOUT_REARM_EVALS: u32 = 30;
+
 /// Trend components computed from the history buffer with zero heap allocation.
 struct TrendComponents {
     avg_mse: f64,
@@ -47,6 +57,17 @@ struct TrendComponents {
     avg_pit_rate: f64,
 }
 
+/// A WellControl advisory raised on the loss side of flow balance or pit
+/// rate (not a kick or pit gain).
+fn is_loss_advisory(advisory: &StrategicAdvisory) -> bool {
+    advisory.category == AnomalyCategory::WellControl
+        && matches!(
+            advisory.trigger_parameter.as_str(),
+            "flow_balance" | "pit_rate"
+        )
+        && advisory.trigger_value < 0.0
+}
+
 /// Compute trend components from history using iterator sums (no `collect()`).
 fn compute_trends(history: &[HistoryEntry], fallback_mse: f64) -> TrendComponents {
     let len = history.len();
@@ -145,6 +166,67 @@ fn compute_torque_cv(torques: &[f64]) -> Option<f64> {
     Some(variance.sqrt() / mean)
 }
 
+/// Check on-bottom ROP history for a drilling break and build a Formation
+/// advisory recommending a flow check.
+fn check_drilling_break(
+    packet: &WitsPacket,
+    metrics: &DrillingMetrics,
+    history: &[HistoryEntry],
+) -> Option<StrategicAdvisory> {
+    use crate::types::{FinalSeverity, RigState, RiskLevel};
+
+    if metrics.state != RigState::Drilling {
+        return None;
+    }
+    let rops: Vec<f64> = history
+        .iter()
+        .filter(|e| e.metrics.state == RigState::Drilling)
+        .map(|e| e.packet.rop)
+        .collect();
+    let brk = physics_engine::detect_drilling_break(&rops)?;
+    warn!(
+        depth = packet.bit_depth,
+        baseline_rop = brk.baseline_rop,
+        current_rop = brk.current_rop,
+        magnitude = brk.magnitude,
+        "Drilling break detected"
+    );
+
+    Some(StrategicAdvisory {
+        timestamp: packet.timestamp,
+        efficiency_score: 80,
+        risk_level: RiskLevel::Elevated,
+        severity: FinalSeverity::High,
+        recommendation: format!(
+            "DRILLING BREAK at {:.0} ft: ROP up {:.1}x ({:.0} → {:.0} ft/hr). \
+             Pick up off bottom and flow check — possible entry into an overpressured \
+             or productive formation.",
+            packet.bit_depth, brk.magnitude, brk.baseline_rop, brk.current_rop
+        ),
+        expected_benefit: "Early kick detection at a formation change".to_string(),
+        reasoning: format!(
+            "Sustained ROP of {:.0} ft/hr against a {:.0} ft/hr baseline over the \
+             preceding drilling packets.",
+            brk.current_rop, brk.baseline_rop
+        ),
+        votes: Vec::new(),
+        physics_report: DrillingPhysicsReport::default(),
+        context_used: Vec::new(),
+        trace_log: Vec::new(),
+        category: AnomalyCategory::Formation,
+        trigger_parameter: "rop_break_ratio".to_string(),
+        trigger_value: brk.magnitude,
+        threshold_value: crate::config::get()
+            .thresholds
+            .drilling_break
+            .rop_increase_factor,
+        provisional_during_learning: false,
+        sparkline: None,
+        gas_lag: None,
+        causal_leads: Vec::new(),
+    })
+}
+
 /// Damping feedback monitor — tracks recommendation effectiveness.
 enum DampingMonitorState {
     Idle {
@@ -178,12 +260,18 @@ pub struct PipelineCoordinator {
     latest_advisory: Option<StrategicAdvisory>,
     /// Latest verification result (for monitoring verification system)
     latest_verification: Option<VerificationResult>,
+    /// Stage durations measured during the latest cycle
+    stage_timings: super::stage_latency::StageTimings,
+    /// Ticket raised by the tactical agent during the latest cycle
+    cycle_ticket: Option<AdvisoryTicket>,
     /// Statistics
     packets_processed: u64,
     tickets_created: u64,
     tickets_verified: u64,
     tickets_rejected: u64,
     strategic_analyses: u64,
+    /// Channel samples flagged by the rate-of-change glitch filter
+    sensor_glitches: u64,
     /// Timestamp of last periodic summary (Unix timestamp)
     last_periodic_summary_time: u64,
     /// Latest drilling metrics (from tactical agent)
@@ -200,12 +288,50 @@ pub struct PipelineCoordinator {
     prev_pit_volume: Option<f64>,
     /// Phase 1.1: Depth continuity validation (stateful across packets)
     depth_tracker: crate::acquisition::wits_parser::DepthContinuityTracker,
+    /// Phase 1.1: Per-channel rate-of-change glitch filter
+    rate_filter: crate::acquisition::wits_parser::RateOfChangeFilter,
     /// Formation boundaries already alerted (prevents repeat lookahead advisories)
     alerted_boundaries: HashSet<String>,
     /// Active damping feedback monitor state
     damping_monitor: DampingMonitorState,
     /// Proactive damping recipe (set on formation transition, cleared on next transition)
     proactive_damping: Option<super::state::ProactiveDamping>,
+    /// Post-connection trapped-pressure detector
+    trapped_pressure: crate::physics_engine::trapped_pressure::TrappedPressureTracker,
+    /// Lost-returns severity staging
+    lost_returns: crate::physics_engine::lost_returns::LostReturnsTracker,
+    /// Pumps-off casing/annular pressure build-up (gas migration)
+    gas_migration: crate::physics_engine::gas_migration::GasMigrationTracker,
+    /// Slide / rotate / survey tracking for directional wells
+    directional: crate::physics_engine::directional::DirectionalTracker,
+    /// Packet timestamp of the last mud-program deviation advisory
+    last_mud_program_alert: Option<u64>,
+    /// Equipment rating checks (reported level per parameter)
+    equipment_limits: crate::physics_engine::equipment_limits::EquipmentLimitTracker,
+    /// Flow-in vs pump-derived flow (discrepancy episode)
+    flow_meter: crate::physics_engine::flow_meter::FlowMeterCheckTracker,
+    /// Depth / flow history for bottoms-up lag correction of gas advisories
+    gas_lag: super::gas_lag::GasLagTracker,
+    /// Stick-slip severity classification and trend
+    stick_slip_trend: crate::physics_engine::stick_slip_trend::StickSlipTrendTracker,
+    /// Washout currently reported (re-arms when SPP stops declining)
+    washout_active: bool,
+    /// Consecutive pumping evaluations without a decline since the report
+    washout_clear_evals: u32,
+    /// Current bit run: hours and run-start baseline (persisted)
+    bit_run: crate::physics_engine::bit_run::BitRunTracker,
+    /// Bit run ended by a trip out, not yet taken by the processing loop
+    ended_bit_run: Option<crate::physics_engine::bit_run::BitRunBaseline>,
+    /// Packet timestamp of the last bit-run save
+    last_bit_run_save: u64,
+    /// Active wellbore branch (sidetracks / multi-laterals)
+    wellbore: super::wellbore::WellboreTracker,
+    /// Preview-mode advisories raised before baselines locked
+    provisional_advisories: Vec<StrategicAdvisory>,
+    /// Provisional advisories promoted at baseline lock, awaiting pickup
+    promoted_advisories: Vec<StrategicAdvisory>,
+    /// Event advisories raised on packets whose slot was taken, awaiting dispatch
+    pending_advisories: super::advisory_queue::PendingAdvisories,
 }
 
 impl PipelineCoordinator {
@@ -229,11 +355,14 @@ impl PipelineCoordinator {
             history_buffer: VecDeque::with_capacity(HISTORY_BUFFER_SIZE),
             latest_advisory: None,
             latest_verification: None,
+            stage_timings: Default::default(),
+            cycle_ticket: None,
             packets_processed: 0,
             tickets_created: 0,
             tickets_verified: 0,
             tickets_rejected: 0,
             strategic_analyses: 0,
+            sensor_glitches: 0,
             last_periodic_summary_time: 0,
             latest_metrics: None,
             formation_prognosis,
@@ -242,9 +371,31 @@ impl PipelineCoordinator {
             knowledge_base,
             prev_pit_volume: None,
             depth_tracker: crate::acquisition::wits_parser::DepthContinuityTracker::new(),
+            rate_filter: crate::acquisition::wits_parser::RateOfChangeFilter::new(),
             alerted_boundaries: HashSet::new(),
             damping_monitor: DampingMonitorState::Idle { last_outcome: None },
             proactive_damping: None,
+            trapped_pressure:
+                crate::physics_engine::trapped_pressure::TrappedPressureTracker::new(),
+            lost_returns: crate::physics_engine::lost_returns::LostReturnsTracker::new(),
+            gas_migration: crate::physics_engine::gas_migration::GasMigrationTracker::new(),
+            directional: crate::physics_engine::directional::DirectionalTracker::new(),
+            last_mud_program_alert: None,
+            equipment_limits:
+                crate::physics_engine::equipment_limits::EquipmentLimitTracker::new(),
+            flow_meter: crate::physics_engine::flow_meter::FlowMeterCheckTracker::new(),
+            gas_lag: super::gas_lag::GasLagTracker::new(),
+            stick_slip_trend:
+                crate::physics_engine::stick_slip_trend::StickSlipTrendTracker::new(),
+            washout_active: false,
+            washout_clear_evals: 0,
+            bit_run: crate::physics_engine::bit_run::BitRunTracker::new(),
+            ended_bit_run: None,
+            last_bit_run_save: 0,
+            wellbore: super::wellbore::WellboreTracker::new(),
+            provisional_advisories: Vec::new(),
+            promoted_advisories: Vec::new(),
+            pending_advisories: super::advisory_queue::PendingAdvisories::new(),
         }
     }
 
@@ -279,11 +430,14 @@ impl PipelineCoordinator {
             history_buffer: VecDeque::with_capacity(HISTORY_BUFFER_SIZE),
             latest_advisory: None,
             latest_verification: None,
+            stage_timings: Default::default(),
+            cycle_ticket: None,
             packets_processed: 0,
             tickets_created: 0,
             tickets_verified: 0,
             tickets_rejected: 0,
             strategic_analyses: 0,
+            sensor_glitches: 0,
             last_periodic_summary_time: 0,
             latest_metrics: None,
             formation_prognosis,
@@ -292,9 +446,31 @@ impl PipelineCoordinator {
             knowledge_base,
             prev_pit_volume: None,
             depth_tracker: crate::acquisition::wits_parser::DepthContinuityTracker::new(),
+            rate_filter: crate::acquisition::wits_parser::RateOfChangeFilter::new(),
             alerted_boundaries: HashSet::new(),
             damping_monitor: DampingMonitorState::Idle { last_outcome: None },
             proactive_damping: None,
+            trapped_pressure:
+                crate::physics_engine::trapped_pressure::TrappedPressureTracker::new(),
+            lost_returns: crate::physics_engine::lost_returns::LostReturnsTracker::new(),
+            gas_migration: crate::physics_engine::gas_migration::GasMigrationTracker::new(),
+            directional: crate::physics_engine::directional::DirectionalTracker::new(),
+            last_mud_program_alert: None,
+            equipment_limits:
+                crate::physics_engine::equipment_limits::EquipmentLimitTracker::new(),
+            flow_meter: crate::physics_engine::flow_meter::FlowMeterCheckTracker::new(),
+            gas_lag: super::gas_lag::GasLagTracker::new(),
+            stick_slip_trend:
+                crate::physics_engine::stick_slip_trend::StickSlipTrendTracker::new(),
+            washout_active: false,
+            washout_clear_evals: 0,
+            bit_run: crate::physics_engine::bit_run::BitRunTracker::new(),
+            ended_bit_run: None,
+            last_bit_run_save: 0,
+            wellbore: super::wellbore::WellboreTracker::new(),
+            provisional_advisories: Vec::new(),
+            promoted_advisories: Vec::new(),
+            pending_advisories: super::advisory_queue::PendingAdvisories::new(),
         }
     }
 
@@ -302,16 +478,66 @@ impl PipelineCoordinator {
     ///
     /// Returns a StrategicAdvisory if:
     /// 1. Any advisory ticket was created (immediate processing), OR
-    /// 2. No ticket was created but 10 minutes have elapsed (periodic summary)
+    /// 2. No ticket was created but 10 minutes have elapsed (periodic summary), OR
+    /// 3. No ticket was created and an event advisory is pending — event
+    ///    advisories raised on busy packets are queued, and well-control ones
+    ///    go ahead of the periodic summary
     ///
     /// Spam protection is provided upstream by the tactical agent's per-severity
     /// cooldown (`default_cooldown_seconds` in `well_config.toml`), so all
     /// confirmed tickets reach the orchestrator immediately regardless of severity.
     /// Periodic summaries represent the last 10 minutes of drilling activity.
+    ///
+    /// In learning preview mode, advisories raised before the baselines lock
+    /// are flagged `provisional_during_learning` and left out of the stats;
+    /// they are promoted once the baselines lock (see
+    /// [`take_promoted_advisories`](Self::take_promoted_advisories)).
     pub async fn process_packet(
         &mut self,
         packet: &mut WitsPacket,
         campaign: Campaign,
+    ) -> Option<StrategicAdvisory> {
+        let analyses_before = self.strategic_analyses;
+        self.stage_timings = Default::default();
+        self.cycle_ticket = None;
+        let mut advisory = self.run_pipeline_cycle(packet, campaign).await;
+        if crate::config::is_initialized() {
+            self.gas_lag.record(packet, &crate::config::get().gas_lag);
+        }
+        if let Some(adv) = advisory.as_mut() {
+            super::sparkline::enrich_advisory(adv, self.history_buffer.make_contiguous());
+            self.enrich_gas_lag(adv, packet);
+        }
+        self.apply_learning_preview(advisory, analyses_before)
+    }
+
+    /// Tag a gas advisory with the depth the gas came from, using the
+    /// `trip_parameters` pipe OD and hole size (bit size when not set).
+    fn enrich_gas_lag(&self, advisory: &mut StrategicAdvisory, packet: &WitsPacket) {
+        if !crate::config::is_initialized() {
+            return;
+        }
+        let cfg = crate::config::get();
+        let tp = &cfg.trip_parameters;
+        let hole_diameter = if tp.hole_diameter_inches > 0.0 {
+            tp.hole_diameter_inches
+        } else {
+            packet.bit_diameter
+        };
+        super::gas_lag::enrich_advisory(
+            advisory,
+            &self.gas_lag,
+            hole_diameter,
+            tp.pipe_od_inches,
+            &cfg.gas_lag,
+        );
+    }
+
+    /// Phases 1-10 for a single packet (see [`process_packet`](Self::process_packet)).
+    async fn run_pipeline_cycle(
+        &mut self,
+        packet: &mut WitsPacket,
+        campaign: Campaign,
     ) -> Option<StrategicAdvisory> {
         use crate::types::TicketSeverity;
 
@@ -340,6 +566,15 @@ impl PipelineCoordinator {
             return None;
         }
 
+        // Phase 1.1c: Rate-of-change limits (hold single-sample electrical glitches)
+        let glitches = self
+            .rate_filter
+            .apply(packet, &crate::config::get().sensor_rate_limits);
+        self.sensor_glitches += glitches.len() as u64;
+        for issue in &glitches {
+            debug!(field = %issue.field, "{}", issue.message);
+        }
+
         // Compute pit_volume_change from consecutive packets (WITS has no item
         // code for pit volume *change* — only absolute pit_volume via item 0123).
         packet.pit_volume_change = match self.prev_pit_volume {
@@ -367,9 +602,23 @@ impl PipelineCoordinator {
 
         // PHASE 2-3: Tactical Agent (Basic Physics + Decision)
         let has_active_advisory = self.latest_advisory.is_some();
+        let tactical_start = Instant::now();
         let (ticket_opt, mut metrics, history_entry) =
             self.tactical_agent
                 .process(packet, has_active_advisory, formation_ctx);
+        let cfc_time = self.tactical_agent.cfc_update_time();
+        let tactical_time = tactical_start.elapsed();
+        self.stage_timings.tactical =
+            Some(tactical_time.saturating_sub(cfc_time.unwrap_or_default()));
+        self.stage_timings.cfc = cfc_time;
+
+        // Phase 1.2a: Wellbore branch tracking (auto-detected sidetracks)
+        if let Some(event) =
+            self.wellbore
+                .update(packet, metrics.state, &crate::config::get().sidetrack)
+        {
+            self.on_sidetrack(&event);
+        }
 
         // Phase 1.2: Enrich metrics with formation context
         if let Some(formation) = self.current_formation_context(packet.bit_depth) {
@@ -413,11 +662,7 @@ impl PipelineCoordinator {
         self.update_history_buffer(history_entry.clone());
 
         // Refresh prognosis from knowledge base if available (hot reload)
-        let dynamic_prognosis = if let Some(ref kb) = self.knowledge_base {
-            kb.prognosis()
-        } else {
-            self.formation_prognosis.clone()
-        };
+        let dynamic_prognosis = self.active_prognosis();
 
         // PHASE LOOKAHEAD: Standalone formation lookahead (independent of optimizer).
         // Runs before make_contiguous() because it needs &mut self for cooldown tracking.
@@ -437,6 +682,62 @@ impl PipelineCoordinator {
         // PHASE DAMPING-MONITOR: Check effectiveness of active damping recommendation
         let damping_monitor_text = self.check_damping_monitor(history_slice);
 
+        // PHASE TRAPPED-PRESSURE: Post-connection pump-resume SPP transient
+        let trapped_pressure_advisory = self.check_trapped_pressure(packet, &metrics);
+
+        // PHASE DIRECTIONAL: Slide / rotate / survey sequence
+        self.update_directional(packet, &metrics);
+
+        // PHASE GAS-MIGRATION: Pressure build-up while pumps are off
+        let gas_migration_advisory = self.check_gas_migration(packet);
+
+        // PHASE LOST-RETURNS: Stage losses and flag escalations
+        let lost_returns_advisory = self.check_lost_returns(packet, &metrics, history_slice);
+
+        // PHASE DRILLING-BREAK: Sudden sustained ROP increase → flow check
+        let drilling_break_advisory = check_drilling_break(packet, &metrics, history_slice);
+
+        // PHASE WASHOUT: Gradual SPP decline at steady pumps → drillstring leak
+        let washout_advisory = self.check_washout(packet, history_slice);
+
+        // PHASE MUD-PROGRAM: Actual mud weight vs planned program at depth
+        let mud_program_advisory = self.check_mud_program(packet);
+
+        // PHASE EQUIPMENT-LIMITS: WOB / RPM / flow against equipment ratings
+        let equipment_limit_advisory =
+            self.check_equipment_limits(packet, &crate::config::get().equipment_limits);
+
+        // PHASE FLOW-METER: Reported flow-in vs pump strokes × displacement
+        let flow_meter_advisory =
+            self.check_flow_meter(packet, &crate::config::get().flow_meter_check);
+
+        // PHASE STICK-SLIP-TREND: Stick-slip severity class and trend
+        let stick_slip_advisory =
+            self.check_stick_slip_trend(packet, &metrics, history_slice, &crate::config::get());
+
+        // Event advisories are edge-triggered: queue them so one raised on a
+        // packet with a ticket or a periodic summary is dispatched later
+        // instead of being lost for the whole episode.
+        {
+            use super::advisory_queue::AdvisorySource;
+            for (source, advisory) in [
+                (AdvisorySource::GasMigration, gas_migration_advisory),
+                (AdvisorySource::LostReturns, lost_returns_advisory),
+                (AdvisorySource::DrillingBreak, drilling_break_advisory),
+                (AdvisorySource::TrappedPressure, trapped_pressure_advisory),
+                (AdvisorySource::Washout, washout_advisory),
+                (AdvisorySource::StickSlip, stick_slip_advisory),
+                (AdvisorySource::EquipmentLimits, equipment_limit_advisory),
+                (AdvisorySource::FlowMeter, flow_meter_advisory),
+                (AdvisorySource::MudProgram, mud_program_advisory),
+                (AdvisorySource::Lookahead, lookahead_advisory),
+            ] {
+                if let Some(advisory) = advisory {
+                    self.pending_advisories.push(source, advisory);
+                }
+            }
+        }
+
         // PHASE OPT: Proactive Optimization (every N packets, independent of tickets)
         let opt_advisory = if let Some(ref prognosis) = dynamic_prognosis {
             if let Some(formation) = prognosis.formation_at_depth(packet.bit_depth).cloned() {
@@ -504,6 +805,7 @@ impl PipelineCoordinator {
         let mut ticket = match ticket_opt {
             Some(t) => {
                 self.tickets_created += 1;
+                self.cycle_ticket = Some(t.clone());
                 debug!(
                     category = ?t.category,
                     severity = ?t.severity,
@@ -513,6 +815,10 @@ impl PipelineCoordinator {
                 t
             }
             None => {
+                // Safety event advisories go ahead of the periodic summary
+                if let Some(adv) = self.pending_advisories.pop_safety() {
+                    return Some(adv);
+                }
                 // No ticket - check if we should generate a periodic summary
                 if should_generate_periodic {
                     return self
@@ -523,12 +829,14 @@ impl PipelineCoordinator {
                 if let Some(text) = damping_monitor_text {
                     return Some(self.make_damping_monitor_advisory(packet, &text));
                 }
-                // Return optimization advisory if one was generated this cycle
-                if let Some(adv) = opt_advisory {
+                // Return the highest-priority pending event advisory (trapped
+                // pressure, washout, stick-slip, equipment limits, flow meter,
+                // mud program, lookahead)
+                if let Some(adv) = self.pending_advisories.pop() {
                     return Some(adv);
                 }
-                // Return lookahead advisory if one was generated this cycle
-                if let Some(adv) = lookahead_advisory {
+                // Return optimization advisory if one was generated this cycle
+                if let Some(adv) = opt_advisory {
                     return Some(adv);
                 }
                 debug!("Phase 3: No ticket, pipeline ends");
@@ -577,7 +885,9 @@ impl PipelineCoordinator {
         let context = self.lookup_context(&ticket);
 
         // Run strategic verification
+        let strategic_start = Instant::now();
         let verification_result = self.strategic_agent.verify_ticket(&ticket, history_slice);
+        self.stage_timings.strategic = Some(strategic_start.elapsed());
 
         self.latest_verification = Some(verification_result.clone());
 
@@ -629,6 +939,22 @@ impl PipelineCoordinator {
             }
         };
 
+        // Loss advisories during confirmed losses carry the stage response
+        let mut advisory = advisory;
+        if is_loss_advisory(&advisory) {
+            if let Some(stage) = self.lost_returns.stage() {
+                advisory.recommendation = format!(
+                    "{} LOSSES: {} {}",
+                    stage.label(),
+                    stage.recommendation(),
+                    advisory.recommendation
+                );
+            }
+        }
+
+        // Trend preview of the triggering metric
+        super::sparkline::enrich_advisory(&mut advisory, history_slice);
+
         // PHASE 10: Storage (store in latest_advisory for dashboard)
         self.latest_advisory = Some(advisory.clone());
         self.strategic_analyses += 1;
@@ -657,14 +983,85 @@ impl PipelineCoordinator {
         Some(advisory)
     }
 
+    /// Flag advisories raised during learning preview as provisional, and
+    /// promote the held ones once the baselines have locked.
+    fn apply_learning_preview(
+        &mut self,
+        advisory: Option<StrategicAdvisory>,
+        analyses_before: u64,
+    ) -> Option<StrategicAdvisory> {
+        if self.tactical_agent.in_learning_preview() {
+            // Provisional advisories don't count toward stats
+            self.strategic_analyses = analyses_before;
+            return advisory.map(|mut adv| {
+                adv.provisional_during_learning = true;
+                if self.provisional_advisories.len() >= MAX_PROVISIONAL_ADVISORIES {
+                    self.provisional_advisories.remove(0);
+                }
+                self.provisional_advisories.push(adv.clone());
+                adv
+            });
+        }
+
+        if self.tactical_agent.is_baseline_locked() && !self.provisional_advisories.is_empty() {
+            info!(
+                count = self.provisional_advisories.len(),
+                "Baselines locked — promoting provisional advisories"
+            );
+            for mut adv in self.provisional_advisories.drain(..) {
+                adv.provisional_during_learning = false;
+                self.strategic_analyses += 1;
+                self.promoted_advisories.push(adv);
+            }
+        }
+        advisory
+    }
+
+    /// Take provisional advisories promoted since the last call.
+    pub fn take_promoted_advisories(&mut self) -> Vec<StrategicAdvisory> {
+        std::mem::take(&mut self.promoted_advisories)
+    }
+
     /// Phase 4: Update history buffer (circular, 60 packets)
     fn update_history_buffer(&mut self, entry: HistoryEntry) {
+        self.update_bit_run(&entry);
         if self.history_buffer.len() >= HISTORY_BUFFER_SIZE {
             self.history_buffer.pop_front();
         }
         self.history_buffer.push_back(entry);
     }
 
+    /// Track on-bottom hours and the run-start baseline, starting a new run
+    /// when the bit is pulled to surface. The run is saved when it starts,
+    /// when its baseline completes, and every `BIT_RUN_SAVE_INTERVAL_SECS`.
+    fn update_bit_run(&mut self, entry: &HistoryEntry) {
+        let started = self.bit_run.run().samples > 0;
+        let baseline_complete = self.bit_run.run().is_complete();
+        let ended = self
+            .bit_run
+            .update(entry, crate::config::get().history_gaps.max_gap_secs);
+        let run = self.bit_run.run();
+        let ts = entry.packet.timestamp;
+        let save = if let Some(ended) = ended {
+            info!(
+                bit_hours = format!("{:.1}", ended.bit_hours),
+                "Bit at surface — bit run ended, next run starts a new baseline"
+            );
+            self.ended_bit_run = Some(ended);
+            true
+        } else {
+            (!started && run.samples > 0)
+                || (!baseline_complete && run.is_complete())
+                || ts.saturating_sub(self.last_bit_run_save) >= BIT_RUN_SAVE_INTERVAL_SECS
+        };
+        if save {
+            self.last_bit_run_save = ts;
+            if let Err(e) = crate::storage::bit_runs::save_active_run(self.bit_run.run()) {
+                debug!("Bit run not saved: {}", e);
+            }
+        }
+    }
+
     /// Generate a periodic 10-minute summary advisory
     ///
     /// This creates a summary of the last 10 minutes of drilling activity,
@@ -945,6 +1342,7 @@ impl PipelineCoordinator {
             current_ecd: packet.ecd,
             current_gas: packet.gas_units,
             current_pit_volume: packet.pit_volume,
+            bit_wear: physics_engine::estimate_bit_wear(history, Some(self.bit_run.run())),
         }
     }
 
@@ -995,6 +1393,7 @@ impl PipelineCoordinator {
             current_ecd: packet.ecd,
             current_gas: packet.gas_units,
             current_pit_volume: packet.pit_volume,
+            bit_wear: physics_engine::estimate_bit_wear(history, Some(self.bit_run.run())),
         }
     }
 
@@ -1006,6 +1405,7 @@ impl PipelineCoordinator {
             AnomalyCategory::Hydraulics => "standpipe pressure ECD flow rate hydraulics",
             AnomalyCategory::Mechanical => "torque pack-off stick-slip mechanical",
             AnomalyCategory::Formation => "d-exponent formation change pore pressure",
+            AnomalyCategory::Equipment => "washpipe seal surface equipment leak",
             AnomalyCategory::None => "normal drilling operations",
         };
 
@@ -1390,7 +1790,664 @@ impl PipelineCoordinator {
             trigger_parameter: "torque_cv_monitor".to_string(),
             trigger_value: 0.0,
             threshold_value: 0.0,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        }
+    }
+
+    /// Feed the trapped-pressure detector and build a Hydraulics advisory
+    /// when an abnormal post-connection transient completes.
+    fn check_trapped_pressure(
+        &mut self,
+        packet: &WitsPacket,
+        metrics: &DrillingMetrics,
+    ) -> Option<StrategicAdvisory> {
+        use crate::types::{FinalSeverity, RiskLevel};
+
+        let config = &crate::config::get().trapped_pressure;
+        if !config.enabled {
+            return None;
+        }
+
+        let event = self
+            .trapped_pressure
+            .update(packet, metrics.state, config)
+            .filter(|e| e.abnormal)?;
+        let reason = event.reason.clone().unwrap_or_default();
+        warn!(
+            depth = event.depth_ft,
+            overshoot_psi = event.overshoot_psi,
+            bleed_secs = ?event.bleed_secs,
+            reason = %reason,
+            "Abnormal post-connection trapped pressure"
+        );
+
+        // Both an oversized spike and a failure to bleed off = likely restriction
+        let severity = if event.overshoot_exceeded && event.bleed_secs.is_none() {
+            FinalSeverity::High
+        } else {
+            FinalSeverity::Medium
+        };
+
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score: 70,
+            risk_level: RiskLevel::Elevated,
+            severity,
+            recommendation: format!(
+                "TRAPPED PRESSURE: Abnormal SPP transient after connection at {:.0} ft — {}. \
+                 Check for plugged jets or a restriction in the string before resuming drilling.",
+                event.depth_ft, reason
+            ),
+            expected_benefit: "Early detection of plugged nozzles / string restriction".to_string(),
+            reasoning: format!(
+                "Pre-connection SPP {:.0} psi, peak {:.0} psi after pump start. \
+                 Compared against {} learned normal connections.",
+                event.pre_spp_psi, event.peak_spp_psi, event.learned_connections
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::Hydraulics,
+            trigger_parameter: "trapped_pressure_psi".to_string(),
+            trigger_value: event.overshoot_psi,
+            threshold_value: 0.0,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Follow the slide / rotate / survey sequence and log completed slides
+    /// and survey stations.
+    fn update_directional(&mut self, packet: &WitsPacket, metrics: &DrillingMetrics) {
+        use crate::physics_engine::directional::DirectionalEvent;
+
+        let config = &crate::config::get().directional;
+        for event in self.directional.update(packet, metrics.state, config) {
+            match event {
+                DirectionalEvent::SlideCompleted(slide) => info!(
+                    start_depth = slide.start_depth_ft,
+                    footage = slide.footage_ft,
+                    rop = slide.rop_ft_hr,
+                    expected_rop = ?slide.expected_rop_ft_hr,
+                    below_expectation = slide.below_expectation,
+                    "Slide completed"
+                ),
+                DirectionalEvent::SurveyStation(survey) => info!(
+                    depth = survey.bit_depth_ft,
+                    duration_secs = survey.duration_secs,
+                    "Survey station detected"
+                ),
+            }
+        }
+    }
+
+    /// Watch pumps-off periods for rising casing/annular pressure and build a
+    /// WellControl advisory for possible gas migration.
+    fn check_gas_migration(&mut self, packet: &WitsPacket) -> Option<StrategicAdvisory> {
+        use crate::types::{FinalSeverity, RiskLevel};
+
+        let config = &crate::config::get().gas_migration;
+        if !config.enabled {
+            return None;
+        }
+
+        let event = self.gas_migration.update(packet, config)?;
+        let static_mins = event.timestamp.saturating_sub(event.static_since) as f64 / 60.0;
+        let label = if event.pressure_source == "casing" {
+            "Casing"
+        } else {
+            "Annular"
+        };
+        warn!(
+            depth = event.depth_ft,
+            source = %event.pressure_source,
+            rise_psi = event.rise_psi,
+            rate_psi_per_min = event.rate_psi_per_min,
+            "Pressure building with pumps off — possible gas migration"
+        );
+
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score: 50,
+            risk_level: RiskLevel::High,
+            severity: FinalSeverity::High,
+            recommendation: format!(
+                "GAS MIGRATION: {} pressure up {:.0} psi ({:.1} psi/min) over {:.1} min with pumps off \
+                 at {:.0} ft. Possible gas migrating up the annulus — monitor the well, \
+                 verify the trip tank and be prepared to shut in.",
+                label,
+                event.rise_psi,
+                event.rate_psi_per_min,
+                static_mins,
+                event.depth_ft
+            ),
+            expected_benefit: "Early detection of gas migration before it reaches surface"
+                .to_string(),
+            reasoning: format!(
+                "{} pressure rose from {:.0} to {:.0} psi during a static period with no \
+                 pumping and no pipe movement.",
+                label,
+                event.start_pressure_psi,
+                event.pressure_psi
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::WellControl,
+            trigger_parameter: "static_pressure_rise_psi".to_string(),
+            trigger_value: event.rise_psi,
+            threshold_value: config.min_rise_psi,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Feed the lost-returns stager and build a WellControl advisory when
+    /// losses escalate to a more severe stage.
+    fn check_lost_returns(
+        &mut self,
+        packet: &WitsPacket,
+        metrics: &DrillingMetrics,
+        history: &[HistoryEntry],
+    ) -> Option<StrategicAdvisory> {
+        use crate::physics_engine::lost_returns::{classify_loss_source, LossSeverity, LossSource};
+        use crate::types::{FinalSeverity, RiskLevel};
+
+        let config = &crate::config::get().lost_returns;
+        if !config.enabled {
+            return None;
+        }
+        // Pump trip/changeover: the flow-out deficit is the pumps, not the hole
+        if self.tactical_agent.pump_event_suppressing() {
+            return None;
+        }
+
+        let event = self.lost_returns.update(
+            packet.timestamp,
+            packet.flow_in,
+            packet.flow_out,
+            metrics.pit_rate,
+            config,
+        )?;
+        warn!(
+            from = ?event.from,
+            to = ?event.to,
+            deficit_pct = event.deficit_pct,
+            pit_loss_bbl_hr = event.pit_loss_bbl_hr,
+            depth = packet.bit_depth,
+            "Lost returns escalated"
+        );
+
+        if config.detect_surface_losses {
+            let pumping: Vec<&HistoryEntry> = history
+                .iter()
+                .filter(|e| e.packet.flow_in >= config.min_flow_in_gpm)
+                .collect();
+            let window = &pumping[pumping.len().saturating_sub(config.surface_window_packets)..];
+            let (spp, ecd): (Vec<f64>, Vec<f64>) = window
+                .iter()
+                .map(|e| (e.packet.spp, e.packet.ecd))
+                .unzip();
+            if classify_loss_source(&spp, &ecd, config) == LossSource::SurfaceEquipment {
+                let spp_drop = spp.first().copied().unwrap_or(0.0) - packet.spp;
+                warn!(
+                    deficit_pct = event.deficit_pct,
+                    spp_drop_psi = spp_drop,
+                    "Lost returns have a surface-equipment signature"
+                );
+                return Some(StrategicAdvisory {
+                    timestamp: packet.timestamp,
+                    efficiency_score: 60,
+                    risk_level: RiskLevel::Elevated,
+                    severity: FinalSeverity::High,
+                    recommendation: format!(
+                        "SURFACE EQUIPMENT LOSSES at {:.0} ft: {:.1}% of flow-in not returning \
+                         with SPP down {:.0} psi and ECD stable at {:.2} ppg. Inspect the \
+                         washpipe, rotating head seals and surface lines before treating as \
+                         lost circulation.",
+                        packet.bit_depth, event.deficit_pct, spp_drop, packet.ecd
+                    ),
+                    expected_benefit: "Repair the surface leak without an unnecessary LCM \
+                                       treatment"
+                        .to_string(),
+                    reasoning: format!(
+                        "Flow-out deficit {:.1}% of {:.0} gpm flow-in, pit loss {:.1} bbl/hr. \
+                         SPP bled off while downhole ECD held, so the mud is leaving at surface.",
+                        event.deficit_pct, packet.flow_in, event.pit_loss_bbl_hr
+                    ),
+                    votes: Vec::new(),
+                    physics_report: DrillingPhysicsReport::default(),
+                    context_used: Vec::new(),
+                    trace_log: Vec::new(),
+                    category: AnomalyCategory::Equipment,
+                    trigger_parameter: "loss_deficit_pct".to_string(),
+                    trigger_value: event.deficit_pct,
+                    threshold_value: 0.0,
+                    provisional_during_learning: false,
+                    sparkline: None,
+                    gas_lag: None,
+                    causal_leads: Vec::new(),
+                });
+            }
+        }
+
+        let (severity, risk_level) = match event.to {
+            LossSeverity::Seepage => (FinalSeverity::Medium, RiskLevel::Elevated),
+            LossSeverity::Partial => (FinalSeverity::High, RiskLevel::High),
+            LossSeverity::Total | LossSeverity::Complete => {
+                (FinalSeverity::Critical, RiskLevel::Critical)
+            }
+        };
+        let escalated_from = event
+            .from
+            .map(|s| format!("escalated from {}", s.label().to_lowercase()))
+            .unwrap_or_else(|| "new losses".to_string());
+
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score: 50,
+            risk_level,
+            severity,
+            recommendation: format!(
+                "{} LOSSES at {:.0} ft ({}): {}",
+                event.to.label(),
+                packet.bit_depth,
+                escalated_from,
+                event.to.recommendation()
+            ),
+            expected_benefit: "Stage-appropriate loss response before losses worsen".to_string(),
+            reasoning: format!(
+                "Flow-out deficit {:.1}% of {:.0} gpm flow-in, pit loss {:.1} bbl/hr.",
+                event.deficit_pct, packet.flow_in, event.pit_loss_bbl_hr
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::WellControl,
+            trigger_parameter: "loss_deficit_pct".to_string(),
+            trigger_value: event.deficit_pct,
+            threshold_value: 0.0,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Run washout detection over the history window and build a Mechanical
+    /// advisory when a washout first appears. Re-arms once SPP has stopped
+    /// declining for `WASHOUT_REARM_EVALS` consecutive pumping evaluations;
+    /// pumps-off packets (connections) neither count nor reset the streak.
+    fn check_washout(
+        &mut self,
+        packet: &WitsPacket,
+        history: &[HistoryEntry],
+    ) -> Option<StrategicAdvisory> {
+        use crate::types::{FinalSeverity, RigState, RiskLevel};
+
+        let Some((severity, description)) = physics_engine::detect_washout(history) else {
+            let pumping = history.last().is_some_and(|e| {
+                matches!(
+                    e.metrics.state,
+                    RigState::Drilling | RigState::Sliding | RigState::Circulating
+                )
+            });
+            if self.washout_active && pumping {
+                self.washout_clear_evals += 1;
+                if self.washout_clear_evals >= WASHOUT_REARM_EVALS {
+                    self.washout_active = false;
+                }
+            }
+            return None;
+        };
+        self.washout_clear_evals = 0;
+        if self.washout_active {
+            return None;
+        }
+        self.washout_active = true;
+        warn!(depth = packet.bit_depth, severity, "{}", description);
+
+        let (final_severity, risk_level) = if severity >= 1.0 {
+            (FinalSeverity::High, RiskLevel::High)
+        } else {
+            (FinalSeverity::Medium, RiskLevel::Elevated)
+        };
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score: 70,
+            risk_level,
+            severity: final_severity,
+            recommendation: format!(
+                "POSSIBLE WASHOUT at {:.0} ft: {}. Check surface lines, then consider a \
+                 pump pressure test or a trip to inspect the string before it parts.",
+                packet.bit_depth, description
+            ),
+            expected_benefit: "Catch a drillstring leak before a twist-off".to_string(),
+            reasoning: "SPP normalized by flow-in trending down while pump SPM and flow-in \
+                        held steady over the history window."
+                .to_string(),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::Mechanical,
+            trigger_parameter: "washout_severity".to_string(),
+            trigger_value: severity,
+            // Severity reaches 0.5 at the configured decline threshold
+            threshold_value: 0.5,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Compare `mud_weight_in` against the planned mud program at the current
+    /// depth and build an informational advisory when it is out of tolerance.
+    fn check_mud_program(&mut self, packet: &WitsPacket) -> Option<StrategicAdvisory> {
+        use crate::types::{FinalSeverity, RiskLevel};
+
+        let config = &crate::config::get().mud_program;
+        if !config.enabled || packet.mud_weight_in <= 0.0 {
+            return None;
+        }
+        if self
+            .last_mud_program_alert
+            .is_some_and(|t| packet.timestamp.saturating_sub(t) < config.alert_cooldown_secs)
+        {
+            return None;
+        }
+
+        let deviation = self.active_prognosis()?.mud_weight_deviation(
+            packet.bit_depth,
+            packet.mud_weight_in,
+            config.tolerance_ppg,
+        )?;
+        self.last_mud_program_alert = Some(packet.timestamp);
+        info!(
+            depth = deviation.depth_ft,
+            planned_ppg = deviation.planned_ppg,
+            actual_ppg = deviation.actual_ppg,
+            "Mud weight off planned program"
+        );
+
+        let direction = if deviation.deviation_ppg < 0.0 {
+            "below"
+        } else {
+            "above"
+        };
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score: 90,
+            risk_level: RiskLevel::Low,
+            severity: FinalSeverity::Low,
+            recommendation: format!(
+                "MUD PROGRAM: Mud weight in {:.2} ppg is {:.2} ppg {} the planned {:.2} ppg \
+                 for {:.0}-{:.0} ft. Confirm the weight-up/cut-back schedule with the mud engineer.",
+                deviation.actual_ppg,
+                deviation.deviation_ppg.abs(),
+                direction,
+                deviation.planned_ppg,
+                deviation.interval_top_ft,
+                deviation.interval_base_ft
+            ),
+            expected_benefit: "Mud weight kept within the planned pore/fracture window"
+                .to_string(),
+            reasoning: format!(
+                "Planned {:.2} ppg ± {:.2} at {:.0} ft; measured {:.2} ppg.",
+                deviation.planned_ppg,
+                deviation.tolerance_ppg,
+                deviation.depth_ft,
+                deviation.actual_ppg
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::Hydraulics,
+            trigger_parameter: "mud_weight_deviation_ppg".to_string(),
+            trigger_value: deviation.deviation_ppg,
+            threshold_value: deviation.tolerance_ppg,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Raise a Mechanical advisory when stick-slip escalates to a more
+    /// severe class (mild / moderate / severe / damaging).
+    ///
+    /// Samples torque CV over the recent drilling packets; off-bottom
+    /// packets neither add samples nor reset the trend.
+    fn check_stick_slip_trend(
+        &mut self,
+        packet: &WitsPacket,
+        metrics: &DrillingMetrics,
+        history: &[HistoryEntry],
+        well_config: &crate::config::WellConfig,
+    ) -> Option<StrategicAdvisory> {
+        use crate::physics_engine::stick_slip_trend::{torque_cv, StickSlipClass};
+        use crate::types::{FinalSeverity, RigState, RiskLevel};
+
+        let config = &well_config.stick_slip_trend;
+        let mech = &well_config.thresholds.mechanical;
+        if !config.enabled || metrics.state != RigState::Drilling {
+            return None;
+        }
+        let torques: Vec<f64> = history
+            .iter()
+            .rev()
+            .filter(|e| e.metrics.state == RigState::Drilling)
+            .take(config.cv_window_packets)
+            .map(|e| e.packet.torque)
+            .collect();
+        if torques.len() < config.cv_window_packets {
+            return None;
+        }
+        let cv = torque_cv(&torques)?;
+        let report = self.stick_slip_trend.update(cv, mech, config)?;
+        warn!(
+            from = ?report.from,
+            to = ?report.to,
+            trend = ?report.trend,
+            cv = report.cv,
+            cv_slope = report.cv_slope,
+            "Stick-slip escalated"
+        );
+
+        let (risk_level, severity, efficiency_score) = match report.to {
+            StickSlipClass::Mild => (RiskLevel::Low, FinalSeverity::Low, 85),
+            StickSlipClass::Moderate => (RiskLevel::Elevated, FinalSeverity::Medium, 70),
+            StickSlipClass::Severe => (RiskLevel::High, FinalSeverity::High, 55),
+            StickSlipClass::Damaging => (RiskLevel::Critical, FinalSeverity::Critical, 40),
+        };
+        let persisted = if report.to == StickSlipClass::Damaging {
+            format!(" Severe for {} consecutive samples.", report.severe_samples)
+        } else {
+            String::new()
+        };
+
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score,
+            risk_level,
+            severity,
+            recommendation: format!(
+                "{} STICK-SLIP at {:.0} ft ({}, torque CV {:.0}%): {}",
+                report.to.label(),
+                packet.bit_depth,
+                report.trend.label(),
+                report.cv * 100.0,
+                report.to.recommendation()
+            ),
+            expected_benefit: "Protect RSS / motor from torsional damage".to_string(),
+            reasoning: format!(
+                "Torque CV {:.1}% over {} drilling packets at {:.0} RPM / {:.1} klbs WOB, \
+                 trend {:+.2}% per sample.{}",
+                report.cv * 100.0,
+                config.cv_window_packets,
+                packet.rpm,
+                packet.wob,
+                report.cv_slope * 100.0,
+                persisted
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::Mechanical,
+            trigger_parameter: "stick_slip_cv".to_string(),
+            trigger_value: report.cv,
+            threshold_value: mech.stick_slip_cv_critical,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Raise an Equipment advisory when WOB, RPM or flow approaches or
+    /// exceeds its equipment rating (once per escalation).
+    fn check_equipment_limits(
+        &mut self,
+        packet: &WitsPacket,
+        config: &crate::config::EquipmentLimitsConfig,
+    ) -> Option<StrategicAdvisory> {
+        use crate::physics_engine::equipment_limits::LimitLevel;
+        use crate::types::{FinalSeverity, RiskLevel};
+
+        if !config.enabled {
+            return None;
         }
+        let check = self.equipment_limits.update(packet, config)?;
+        let description = check.describe();
+        warn!(
+            parameter = check.parameter,
+            value = check.value,
+            limit = check.limit,
+            level = ?check.level,
+            "Equipment limit: {}",
+            description
+        );
+
+        let (risk_level, severity, efficiency_score, action) = match check.level {
+            LimitLevel::Exceeded => (
+                RiskLevel::High,
+                FinalSeverity::High,
+                50,
+                format!(
+                    "Reduce {} below the {} rating now and log the exceedance \
+                     for the equipment report.",
+                    check.parameter, check.equipment
+                ),
+            ),
+            _ => (
+                RiskLevel::Elevated,
+                FinalSeverity::Medium,
+                75,
+                format!(
+                    "Hold {} at or below the current value; further increases \
+                     will exceed the {} rating.",
+                    check.parameter, check.equipment
+                ),
+            ),
+        };
+        let label = if check.level == LimitLevel::Exceeded {
+            "EQUIPMENT LIMIT EXCEEDED"
+        } else {
+            "EQUIPMENT LIMIT"
+        };
+
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score,
+            risk_level,
+            severity,
+            recommendation: format!("{}: {}. {}", label, description, action),
+            expected_benefit: "Drilling parameters kept within equipment ratings".to_string(),
+            reasoning: format!(
+                "{} rating {:.0} {}; approach margin {:.0}% of rating.",
+                check.equipment,
+                check.limit,
+                check.unit,
+                config.approach_fraction * 100.0
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::Equipment,
+            trigger_parameter: format!("{}_limit", check.parameter),
+            trigger_value: check.value,
+            threshold_value: check.limit,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
+    }
+
+    /// Raise an Equipment advisory when reported flow-in has disagreed with
+    /// pump-derived flow for `sustain_secs` (once per episode).
+    fn check_flow_meter(
+        &mut self,
+        packet: &WitsPacket,
+        config: &crate::config::FlowMeterCheckConfig,
+    ) -> Option<StrategicAdvisory> {
+        use crate::types::{FinalSeverity, RiskLevel};
+
+        if !config.enabled {
+            return None;
+        }
+        let d = self.flow_meter.update(packet, config)?;
+        warn!(
+            pump_spm = d.pump_spm,
+            expected_gpm = d.expected_gpm,
+            flow_in = d.flow_in_gpm,
+            discrepancy_pct = d.discrepancy_pct,
+            "Flow meter discrepancy"
+        );
+
+        Some(StrategicAdvisory {
+            timestamp: packet.timestamp,
+            efficiency_score: 60,
+            risk_level: RiskLevel::High,
+            severity: FinalSeverity::High,
+            recommendation: format!(
+                "FLOW METER DISCREPANCY: flow-in {:.0} gpm vs {:.0} gpm expected from \
+                 {:.0} spm ({:.0}% off for {} s). Check the flow-in meter and pump suction; \
+                 flow-balance based kick and loss detection is unreliable until resolved.",
+                d.flow_in_gpm, d.expected_gpm, d.pump_spm, d.discrepancy_pct, d.duration_secs
+            ),
+            expected_benefit: "Trustworthy flow-in for kick and loss detection".to_string(),
+            reasoning: format!(
+                "Pump output {:.2} gal/stroke; discrepancy threshold {:.0}% sustained {} s.",
+                config.pump_output_gal_per_stroke, config.max_discrepancy_pct, config.sustain_secs
+            ),
+            votes: Vec::new(),
+            physics_report: DrillingPhysicsReport::default(),
+            context_used: Vec::new(),
+            trace_log: Vec::new(),
+            category: AnomalyCategory::Equipment,
+            trigger_parameter: "flow_in_discrepancy_pct".to_string(),
+            trigger_value: d.discrepancy_pct,
+            threshold_value: config.max_discrepancy_pct,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
+        })
     }
 
     /// Get a snapshot of the current damping monitor state for API visibility.
@@ -1437,11 +2494,92 @@ impl PipelineCoordinator {
         }
     }
 
+    /// Start a new wellbore branch from an operator sidetrack marker.
+    pub fn mark_sidetrack(
+        &mut self,
+        marker: crate::types::SidetrackMarker,
+        packet: &WitsPacket,
+    ) -> crate::types::SidetrackEvent {
+        let event = self.wellbore.mark(marker, packet);
+        self.on_sidetrack(&event);
+        event
+    }
+
+    /// Attribute pressure changes from this packet on to an operator-declared
+    /// MPD choke adjustment.
+    pub fn mark_choke_adjustment(
+        &mut self,
+        declaration: &crate::physics_engine::choke::ChokeAdjustmentDeclaration,
+        packet: &WitsPacket,
+    ) {
+        if let Some(note) = &declaration.note {
+            info!(depth = packet.bit_depth, note = %note, "Operator choke adjustment");
+        }
+        self.tactical_agent.mark_choke_adjustment(packet.timestamp);
+    }
+
+    /// Slide / rotate / survey snapshot for the API.
+    pub fn directional_summary(&self) -> crate::physics_engine::directional::DirectionalSummary {
+        self.directional.summary(&crate::config::get().directional)
+    }
+
+    /// Event that started the active wellbore branch (`None` on the original hole).
+    pub fn active_wellbore(&self) -> Option<&crate::types::SidetrackEvent> {
+        self.wellbore.active()
+    }
+
+    /// Reset depth-keyed state when the active wellbore changes.
+    fn on_sidetrack(&mut self, event: &crate::types::SidetrackEvent) {
+        info!(
+            branch = %event.branch,
+            parent = ?event.parent,
+            kickoff_ft = event.kickoff_depth_ft,
+            source = ?event.source,
+            "Sidetrack — switching active wellbore"
+        );
+        // Formations on the new branch are entered afresh
+        self.last_formation_name = None;
+        self.alerted_boundaries.clear();
+    }
+
+    /// Prognosis for the active wellbore branch.
+    ///
+    /// Reads from the knowledge base when active (hot reload), otherwise the
+    /// static prognosis. On a sidetrack the matching `[[sidetrack]]` branch is
+    /// applied; a branch with no prognosis entry keeps the parent formations.
+    fn active_prognosis(&self) -> Option<FormationPrognosis> {
+        let prognosis = if let Some(ref kb) = self.knowledge_base {
+            kb.prognosis()
+        } else {
+            self.formation_prognosis.clone()
+        }?;
+        match self.wellbore.active_branch() {
+            Some(branch) => Some(prognosis.for_branch(branch).unwrap_or(prognosis)),
+            None => Some(prognosis),
+        }
+    }
+
+    /// Feed an ML analysis report to the tactical agent's formation-hardness
+    /// calibration, attributed to the formation at the report's mid-depth.
+    pub fn ingest_ml_report(&mut self, report: &crate::types::MLInsightsReport) {
+        let mid_depth = (report.depth_range.0 + report.depth_range.1) / 2.0;
+        let formation = self.current_formation_context(mid_depth).map(|f| f.name);
+        self.tactical_agent
+            .ingest_ml_report(report, formation.as_deref());
+    }
+
     /// Look up the formation at the current bit depth.
     ///
     /// When the knowledge base is active, it dynamically reads from the KB
     /// (which may have been updated by the watcher). Falls back to the static prognosis.
+    /// On a sidetrack, lookup follows the active branch.
     fn current_formation_context(&self, depth_ft: f64) -> Option<crate::types::FormationInterval> {
+        if self.wellbore.active_branch().is_some() {
+            return self
+                .active_prognosis()?
+                .formation_at_depth(depth_ft)
+                .cloned();
+        }
         if let Some(ref kb) = self.knowledge_base {
             return kb.formation_at_depth(depth_ft);
         }
@@ -1451,6 +2589,21 @@ impl PipelineCoordinator {
             .cloned()
     }
 
+    /// Drilling / sliding / reaming hours accumulated on the current bit.
+    pub fn bit_hours(&self) -> f64 {
+        self.bit_run.run().bit_hours
+    }
+
+    /// Resume the bit run persisted before a restart.
+    pub fn restore_bit_run(&mut self, run: crate::physics_engine::bit_run::BitRunBaseline) {
+        self.bit_run.restore(run);
+    }
+
+    /// Take the bit run ended by a trip out since the last call.
+    pub fn take_ended_bit_run(&mut self) -> Option<crate::physics_engine::bit_run::BitRunBaseline> {
+        self.ended_bit_run.take()
+    }
+
     /// Get formation hardness at a given depth, if available.
     pub fn formation_hardness_at_depth(&self, depth_ft: f64) -> Option<f64> {
         self.current_formation_context(depth_ft)
@@ -1468,6 +2621,16 @@ impl PipelineCoordinator {
         self.proactive_damping.as_ref()
     }
 
+    /// Advisory ticket raised during the latest `process_packet()` call.
+    pub fn cycle_ticket(&self) -> Option<&AdvisoryTicket> {
+        self.cycle_ticket.as_ref()
+    }
+
+    /// Stage durations measured during the latest `process_packet()` call.
+    pub fn stage_timings(&self) -> &super::stage_latency::StageTimings {
+        &self.stage_timings
+    }
+
     /// Get a reference to the tactical agent
     pub fn tactical_agent(&self) -> &TacticalAgent {
         &self.tactical_agent
@@ -1488,6 +2651,7 @@ impl PipelineCoordinator {
             tickets_rejected: self.tickets_rejected,
             strategic_analyses: self.strategic_analyses,
             history_buffer_size: self.history_buffer.len(),
+            sensor_glitches: self.sensor_glitches,
         }
     }
 
@@ -1506,10 +2670,31 @@ impl PipelineCoordinator {
         self.tactical_agent.regime_centroids()
     }
 
+    /// Get the regime stability (fraction of recent packets in one regime)
+    pub fn regime_stability(&self) -> f64 {
+        self.tactical_agent.regime_stability()
+    }
+
+    /// Merge near-duplicate regime centroids; returns how many were removed
+    pub fn merge_regimes(&mut self, min_distance: f64) -> usize {
+        self.tactical_agent.merge_regimes(min_distance)
+    }
+
     /// Start the knowledge base watcher (if KB is active)
     pub fn start_kb_watcher(&self) -> Option<tokio::task::JoinHandle<()>> {
         self.knowledge_base.as_ref().map(|kb| kb.start_watcher())
     }
+
+    /// Knowledge base for this well (if KB is active)
+    pub fn knowledge_base(&self) -> Option<&crate::knowledge_base::KnowledgeBase> {
+        self.knowledge_base.as_ref()
+    }
+
+    /// Replace the knowledge base (e.g. to point at a different KB root)
+    #[cfg(test)]
+    pub fn set_knowledge_base(&mut self, kb: Option<crate::knowledge_base::KnowledgeBase>) {
+        self.knowledge_base = kb;
+    }
 }
 
 impl Default for PipelineCoordinator {
@@ -1527,6 +2712,8 @@ pub struct PipelineStats {
     pub tickets_rejected: u64,
     pub strategic_analyses: u64,
     pub history_buffer_size: usize,
+    /// Channel samples flagged by the rate-of-change glitch filter
+    pub sensor_glitches: u64,
 }
 
 impl std::fmt::Display for PipelineStats {
@@ -1577,6 +2764,7 @@ mod tests {
             co2: 0.1,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.0,
             mse: 35000.0,
@@ -1611,6 +2799,80 @@ mod tests {
         assert_eq!(stats.packets_processed, 20);
     }
 
+    #[tokio::test]
+    async fn test_wob_glitch_counted_in_stats() {
+        ensure_config();
+        let mut coordinator = PipelineCoordinator::new();
+        for (i, wob) in [25.0, 25.0, 75.0, 25.0, 25.0].into_iter().enumerate() {
+            let mut packet = create_test_packet(50.0, 2.0);
+            packet.timestamp += i as u64;
+            packet.wob = wob;
+            coordinator
+                .process_packet(&mut packet, Campaign::Production)
+                .await;
+        }
+        assert_eq!(coordinator.get_stats().sensor_glitches, 1);
+    }
+
+    #[tokio::test]
+    async fn test_learning_preview_advisories_promoted_at_lock() {
+        ensure_config();
+        use crate::baseline::wits_metrics;
+
+        // Every metric locked except MSE, which has enough samples to lock
+        let equip = "preview-rig";
+        let mut manager = ThresholdManager::new();
+        for sensor in [
+            wits_metrics::D_EXPONENT,
+            wits_metrics::DXC,
+            wits_metrics::FLOW_BALANCE,
+            wits_metrics::SPP,
+            wits_metrics::TORQUE,
+            wits_metrics::ROP,
+            wits_metrics::WOB,
+            wits_metrics::RPM,
+            wits_metrics::ECD,
+            wits_metrics::PIT_VOLUME,
+            wits_metrics::GAS_UNITS,
+        ] {
+            for i in 0..150 {
+                manager.add_sample(equip, sensor, 100.0 + (i as f64 * 0.01), i as u64);
+            }
+            manager.lock_baseline(equip, sensor, 150).expect("should lock");
+        }
+        for i in 0..150 {
+            manager.add_sample(equip, wits_metrics::MSE, 20_000.0 + (i as f64), i as u64);
+        }
+
+        let tm = Arc::new(RwLock::new(manager));
+        let mut coordinator =
+            PipelineCoordinator::new_with_thresholds(tm, equip.to_string(), true);
+        coordinator.tactical_agent_mut().set_learning_preview(true);
+        assert!(coordinator.tactical_agent().in_learning_preview());
+
+        // An advisory raised during learning is provisional and not counted
+        coordinator.strategic_analyses = 1;
+        let adv = coordinator
+            .apply_learning_preview(Some(StrategicAdvisory::default()), 0)
+            .expect("advisory still shown");
+        assert!(adv.provisional_during_learning);
+        assert_eq!(coordinator.get_stats().strategic_analyses, 0);
+        assert!(coordinator.take_promoted_advisories().is_empty());
+
+        // Next drilling packet locks the baselines and promotes it
+        let mut packet = create_test_packet(50.0, 0.0);
+        coordinator
+            .process_packet(&mut packet, Campaign::Production)
+            .await;
+        assert!(coordinator.tactical_agent().is_baseline_locked());
+        assert!(!coordinator.tactical_agent().in_learning_preview());
+
+        let promoted = coordinator.take_promoted_advisories();
+        assert_eq!(promoted.len(), 1);
+        assert!(!promoted[0].provisional_during_learning);
+        assert!(coordinator.get_stats().strategic_analyses >= 1);
+    }
+
     #[tokio::test]
     async fn test_well_control_advisory() {
         let mut coordinator = PipelineCoordinator::new();
@@ -1884,4 +3146,497 @@ mod tests {
             }
         ));
     }
+
+    // ============================================================================
+    // Sidetrack Tests
+    // ============================================================================
+
+    fn make_formation(
+        name: &str,
+        top: f64,
+        base: f64,
+        hardness: f64,
+    ) -> crate::types::FormationInterval {
+        use crate::types::{
+            BestParams, FormationInterval, FormationParameters, OffsetPerformance, ParameterRange,
+        };
+        let range = |optimal: f64| ParameterRange {
+            min: optimal * 0.8,
+            optimal,
+            max: optimal * 1.2,
+        };
+        FormationInterval {
+            name: name.to_string(),
+            depth_top_ft: top,
+            depth_base_ft: base,
+            lithology: "Sandstone".to_string(),
+            hardness,
+            drillability: "Medium".to_string(),
+            pore_pressure_ppg: 9.0,
+            fracture_gradient_ppg: 14.0,
+            hazards: Vec::new(),
+            parameters: FormationParameters {
+                wob_klbs: range(20.0),
+                rpm: range(120.0),
+                flow_gpm: range(500.0),
+                mud_weight_ppg: 9.5,
+                bit_type: "PDC".to_string(),
+            },
+            offset_performance: OffsetPerformance {
+                wells: Vec::new(),
+                avg_rop_ft_hr: 50.0,
+                best_rop_ft_hr: 80.0,
+                avg_mse_psi: 15000.0,
+                best_params: BestParams {
+                    wob_klbs: 20.0,
+                    rpm: 120.0,
+                },
+                notes: String::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_sidetrack_marker_switches_formation_lookup_to_branch() {
+        use crate::types::{PrognosisWellInfo, SidetrackBranch, SidetrackMarker};
+
+        ensure_config();
+        let mut coordinator = PipelineCoordinator::new();
+        coordinator.knowledge_base = None;
+        coordinator.formation_prognosis = Some(FormationPrognosis {
+            well: PrognosisWellInfo {
+                name: "Well-A".to_string(),
+                field: "TestField".to_string(),
+                spud_date: String::new(),
+                target_depth_ft: 12000.0,
+                coordinate_system: String::new(),
+            },
+            formations: vec![
+                make_formation("Overburden", 0.0, 8000.0, 3.0),
+                make_formation("Reservoir", 8000.0, 12000.0, 6.0),
+            ],
+            casings: Vec::new(),
+            sidetracks: vec![SidetrackBranch {
+                name: "ST1".to_string(),
+                kickoff_depth_ft: 7500.0,
+                formations: vec![
+                    make_formation("ST1 Shale", 7500.0, 9000.0, 4.0),
+                    make_formation("ST1 Target", 9000.0, 11000.0, 8.0),
+                ],
+            }],
+            mud_program: Vec::new(),
+        });
+
+        // Original hole
+        assert_eq!(coordinator.formation_hardness_at_depth(9500.0), Some(6.0));
+        assert!(coordinator.active_wellbore().is_none());
+
+        let mut packet = create_test_packet(50.0, 0.0);
+        packet.bit_depth = 7500.0;
+        let event = coordinator.mark_sidetrack(
+            SidetrackMarker {
+                name: Some("ST1".to_string()),
+                kickoff_depth_ft: Some(7500.0),
+            },
+            &packet,
+        );
+        assert_eq!(event.branch, "ST1");
+        assert_eq!(
+            coordinator.active_wellbore().map(|w| w.branch.as_str()),
+            Some("ST1")
+        );
+
+        // Same measured depths now resolve to the branch's formations
+        assert_eq!(coordinator.formation_hardness_at_depth(9500.0), Some(8.0));
+        assert_eq!(coordinator.formation_hardness_at_depth(8500.0), Some(4.0));
+        // Above kickoff the parent hole is shared
+        assert_eq!(coordinator.formation_hardness_at_depth(5000.0), Some(3.0));
+    }
+
+    #[test]
+    fn test_surface_leak_losses_raise_equipment_advisory() {
+        ensure_config();
+
+        // Washpipe leak: returns drop 12% while SPP bleeds off and ECD holds
+        let mut coordinator = PipelineCoordinator::new();
+        let mut history = Vec::new();
+        let mut advisories = Vec::new();
+        for i in 0..30 {
+            let mut packet = create_test_packet(50.0, 0.0);
+            packet.timestamp += i * 10;
+            packet.flow_in = 600.0;
+            packet.flow_out = if i < 20 { 600.0 } else { 528.0 };
+            packet.spp = if i < 20 { 3000.0 } else { 2800.0 };
+            packet.ecd = 10.8;
+            let metrics = DrillingMetrics::default();
+            history.push(HistoryEntry {
+                packet: packet.clone(),
+                metrics: metrics.clone(),
+            });
+            advisories.extend(coordinator.check_lost_returns(&packet, &metrics, &history));
+        }
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0].category, AnomalyCategory::Equipment);
+        assert!(advisories[0].recommendation.contains("washpipe"));
+
+        // Same deficit with SPP holding and ECD falling is lost circulation
+        let mut coordinator = PipelineCoordinator::new();
+        let mut history = Vec::new();
+        let mut advisories = Vec::new();
+        for i in 0..30 {
+            let mut packet = create_test_packet(50.0, 0.0);
+            packet.timestamp += i * 10;
+            packet.flow_in = 600.0;
+            packet.flow_out = if i < 20 { 600.0 } else { 528.0 };
+            packet.spp = 3000.0;
+            packet.ecd = if i < 20 { 10.8 } else { 10.6 };
+            let metrics = DrillingMetrics::default();
+            history.push(HistoryEntry {
+                packet: packet.clone(),
+                metrics: metrics.clone(),
+            });
+            advisories.extend(coordinator.check_lost_returns(&packet, &metrics, &history));
+        }
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0].category, AnomalyCategory::WellControl);
+    }
+
+    #[test]
+    fn test_loss_stage_prefix_applies_to_loss_advisories_only() {
+        let advisory = |trigger: &str, value: f64| StrategicAdvisory {
+            category: AnomalyCategory::WellControl,
+            trigger_parameter: trigger.to_string(),
+            trigger_value: value,
+            ..StrategicAdvisory::default()
+        };
+        assert!(is_loss_advisory(&advisory("flow_balance", -40.0)));
+        assert!(is_loss_advisory(&advisory("pit_rate", -15.0)));
+        // Kick / pit gain during losses keeps its own response
+        assert!(!is_loss_advisory(&advisory("flow_balance", 40.0)));
+        assert!(!is_loss_advisory(&advisory("pit_rate", 15.0)));
+    }
+
+    #[test]
+    fn test_flow_near_motor_limit_approaches_then_escalates() {
+        ensure_config();
+
+        let config = crate::config::EquipmentLimitsConfig {
+            max_flow_gpm: 700.0,
+            ..crate::config::EquipmentLimitsConfig::default()
+        };
+        let mut coordinator = PipelineCoordinator::new();
+        let mut packet = create_test_packet(50.0, 0.0);
+
+        packet.flow_in = 650.0;
+        let approaching = coordinator
+            .check_equipment_limits(&packet, &config)
+            .expect("approaching-limit advisory");
+        assert_eq!(approaching.category, AnomalyCategory::Equipment);
+        assert_eq!(approaching.severity, crate::types::FinalSeverity::Medium);
+        assert!(approaching
+            .recommendation
+            .contains("flow 650 gpm approaching motor max 700 gpm"));
+
+        // Holding near the limit doesn't repeat the advisory
+        packet.flow_in = 660.0;
+        assert!(coordinator.check_equipment_limits(&packet, &config).is_none());
+
+        packet.flow_in = 720.0;
+        let exceeded = coordinator
+            .check_equipment_limits(&packet, &config)
+            .expect("exceeded-limit advisory");
+        assert_eq!(exceeded.severity, crate::types::FinalSeverity::High);
+        assert!(exceeded.recommendation.contains("EXCEEDED"));
+        assert!(exceeded.recommendation.contains("flow 720 gpm exceeds motor max 700 gpm"));
+    }
+
+    #[test]
+    fn test_pumps_running_with_dead_flow_meter_raises_discrepancy() {
+        ensure_config();
+
+        let config = crate::config::FlowMeterCheckConfig {
+            pump_output_gal_per_stroke: 4.0,
+            ..crate::config::FlowMeterCheckConfig::default()
+        };
+        let mut coordinator = PipelineCoordinator::new();
+        let mut advisories = Vec::new();
+        for i in 0..=config.sustain_secs {
+            // 120 spm x 4 gal/stroke = 480 gpm expected, meter reads ~0
+            let mut packet = create_test_packet(50.0, 0.0);
+            packet.timestamp += i;
+            packet.flow_in = 2.0;
+            advisories.extend(coordinator.check_flow_meter(&packet, &config));
+        }
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0].category, AnomalyCategory::Equipment);
+        assert_eq!(advisories[0].trigger_parameter, "flow_in_discrepancy_pct");
+        assert!(advisories[0]
+            .recommendation
+            .starts_with("FLOW METER DISCREPANCY"));
+
+        // Meter agreeing with the pumps stays quiet
+        let mut coordinator = PipelineCoordinator::new();
+        for i in 0..=config.sustain_secs {
+            let mut packet = create_test_packet(50.0, 0.0);
+            packet.timestamp += i;
+            packet.flow_in = 470.0;
+            assert!(coordinator.check_flow_meter(&packet, &config).is_none());
+        }
+    }
+
+    #[test]
+    fn test_drilling_break_raises_formation_flow_check_advisory() {
+        ensure_config();
+
+        let entry = |rop: f64| HistoryEntry {
+            packet: create_test_packet(rop, 0.0),
+            metrics: DrillingMetrics {
+                state: RigState::Drilling,
+                ..DrillingMetrics::default()
+            },
+        };
+
+        // 2x sustained jump after 30 packets at 40 ft/hr
+        let mut history: Vec<HistoryEntry> = (0..30).map(|_| entry(40.0)).collect();
+        history.extend((0..5).map(|_| entry(80.0)));
+        let last = history.last().unwrap();
+        let adv = check_drilling_break(&last.packet, &last.metrics, &history)
+            .expect("drilling break advisory");
+        assert_eq!(adv.category, AnomalyCategory::Formation);
+        assert!(adv.recommendation.contains("flow check"));
+        assert!(adv.recommendation.contains("10000 ft"));
+        assert!((adv.trigger_value - 2.0).abs() < 1e-9);
+
+        // Gradual climb to the same ROP
+        let gradual: Vec<HistoryEntry> = (0..60)
+            .map(|i| entry(40.0 + i as f64 * 40.0 / 59.0))
+            .collect();
+        let last = gradual.last().unwrap();
+        assert!(check_drilling_break(&last.packet, &last.metrics, &gradual).is_none());
+    }
+
+    #[test]
+    fn test_washout_advisory_raised_once_per_episode() {
+        ensure_config();
+
+        let entry = |spp: f64| {
+            let mut packet = create_test_packet(50.0, 0.0);
+            packet.spp = spp;
+            HistoryEntry {
+                packet,
+                metrics: DrillingMetrics {
+                    state: RigState::Drilling,
+                    ..DrillingMetrics::default()
+                },
+            }
+        };
+        let history: Vec<HistoryEntry> = (0..60).map(|i| entry(2800.0 - 8.0 * i as f64)).collect();
+        let last = &history.last().unwrap().packet;
+
+        let mut coordinator = PipelineCoordinator::new();
+        let adv = coordinator
+            .check_washout(last, &history)
+            .expect("washout advisory");
+        assert_eq!(adv.category, AnomalyCategory::Mechanical);
+        assert!(adv.recommendation.starts_with("POSSIBLE WASHOUT"));
+        assert!(coordinator.check_washout(last, &history).is_none());
+
+        // A connection (pumps off) does not re-arm it
+        let mut connection = history.clone();
+        connection.push(HistoryEntry {
+            metrics: DrillingMetrics {
+                state: RigState::Connection,
+                ..DrillingMetrics::default()
+            },
+            ..history[0].clone()
+        });
+        for _ in 0..2 * WASHOUT_REARM_EVALS {
+            assert!(coordinator.check_washout(last, &connection).is_none());
+        }
+        assert!(coordinator.check_washout(last, &history).is_none());
+
+        // SPP recovers (e.g. after a trip to replace the joint) and stays
+        // steady while pumping: re-armed
+        let steady: Vec<HistoryEntry> = (0..60).map(|_| entry(2800.0)).collect();
+        for _ in 0..WASHOUT_REARM_EVALS - 1 {
+            assert!(coordinator.check_washout(last, &steady).is_none());
+        }
+        assert!(coordinator.check_washout(last, &history).is_none());
+        for _ in 0..WASHOUT_REARM_EVALS {
+            assert!(coordinator.check_washout(last, &steady).is_none());
+        }
+        assert!(coordinator.check_washout(last, &history).is_some());
+    }
+
+    #[test]
+    fn test_underweight_mud_raises_mud_program_deviation_advisory() {
+        use crate::types::{FinalSeverity, MudProgramInterval, PrognosisWellInfo};
+
+        ensure_config();
+        let mut coordinator = PipelineCoordinator::new();
+        coordinator.knowledge_base = None;
+        coordinator.formation_prognosis = Some(FormationPrognosis {
+            well: PrognosisWellInfo {
+                name: "Well-A".to_string(),
+                field: "TestField".to_string(),
+                spud_date: String::new(),
+                target_depth_ft: 12000.0,
+                coordinate_system: String::new(),
+            },
+            formations: vec![make_formation("Overburden", 0.0, 12000.0, 3.0)],
+            casings: Vec::new(),
+            sidetracks: Vec::new(),
+            mud_program: vec![
+                MudProgramInterval {
+                    depth_top_ft: 0.0,
+                    depth_base_ft: 8000.0,
+                    mud_weight_ppg: 9.5,
+                    tolerance_ppg: None,
+                    mud_type: String::new(),
+                },
+                MudProgramInterval {
+                    depth_top_ft: 8000.0,
+                    depth_base_ft: 12000.0,
+                    mud_weight_ppg: 11.0,
+                    tolerance_ppg: None,
+                    mud_type: String::new(),
+                },
+            ],
+        });
+
+        // On plan in the upper interval
+        let mut packet = create_test_packet(50.0, 0.0);
+        packet.bit_depth = 5000.0;
+        packet.mud_weight_in = 9.6;
+        assert!(coordinator.check_mud_program(&packet).is_none());
+
+        // Deeper interval planned at 11.0 ppg, still circulating 10.2 ppg
+        packet.bit_depth = 9000.0;
+        packet.mud_weight_in = 10.2;
+        let adv = coordinator
+            .check_mud_program(&packet)
+            .expect("mud program deviation advisory");
+        assert_eq!(adv.severity, FinalSeverity::Low);
+        assert_eq!(adv.trigger_parameter, "mud_weight_deviation_ppg");
+        assert!((adv.trigger_value + 0.8).abs() < 1e-9);
+        assert!(adv.recommendation.contains("below the planned 11.00 ppg"));
+
+        // Cooldown suppresses a repeat on the next packet
+        packet.timestamp += 1;
+        assert!(coordinator.check_mud_program(&packet).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slide_sequence_classified_without_efficiency_advisories() {
+        use crate::physics_engine::directional::DrillingMode;
+
+        ensure_config();
+        let mut coordinator = PipelineCoordinator::new();
+        let mut depth = 10000.0;
+        let mut slide_advisories = Vec::new();
+
+        // Rotate 300 s, slide 600 s, rotate 60 s
+        for t in 0..960u64 {
+            let sliding = (300..900).contains(&t);
+            let mut packet = create_test_packet(if sliding { 30.0 } else { 100.0 }, 0.0);
+            depth += packet.rop / 3600.0;
+            packet.timestamp = 1705564800 + t;
+            packet.bit_depth = depth;
+            packet.hole_depth = depth;
+            if sliding {
+                packet.rpm = 0.0;
+                packet.torque = 2.0;
+            }
+            let advisory = coordinator
+                .process_packet(&mut packet, Campaign::Production)
+                .await;
+            // The rate-of-change filter holds the RPM step for one sample, then
+            // the rig state debounce needs 3 consecutive sliding packets
+            if sliding && t > 302 {
+                let metrics = coordinator.get_latest_metrics().expect("metrics");
+                assert_eq!(metrics.state, RigState::Sliding);
+                assert_eq!(metrics.mse, 0.0);
+                assert_ne!(metrics.anomaly_category, AnomalyCategory::DrillingEfficiency);
+                slide_advisories.extend(advisory);
+            }
+        }
+
+        assert!(slide_advisories
+            .iter()
+            .all(|a| a.category != AnomalyCategory::DrillingEfficiency));
+        let summary = coordinator.directional_summary();
+        assert_eq!(summary.mode, DrillingMode::Rotating);
+        assert_eq!(summary.slides.len(), 1);
+        assert!(summary.slides[0].below_expectation);
+        // ±1 sample at each transition from the rate-of-change filter, plus
+        // 2 samples of rig state debounce (rotating at 100 ft/hr at the end)
+        assert!((summary.slide_footage_ft - 5.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_rising_casing_pressure_while_static_raises_gas_migration_advisory() {
+        use crate::types::FinalSeverity;
+
+        ensure_config();
+        let static_packet = |ts: u64, casing_pressure: f64| {
+            let mut packet = create_test_packet(0.0, 0.0);
+            packet.timestamp = ts;
+            packet.flow_in = 0.0;
+            packet.flow_out = 0.0;
+            packet.pump_spm = 0.0;
+            packet.casing_pressure = casing_pressure;
+            packet
+        };
+
+        // Connection with the casing pressure building 15 psi/min
+        let mut coordinator = PipelineCoordinator::new();
+        let advisories: Vec<StrategicAdvisory> = (0..600u64)
+            .filter_map(|t| {
+                coordinator.check_gas_migration(&static_packet(1000 + t, 200.0 + t as f64 / 4.0))
+            })
+            .collect();
+        assert_eq!(advisories.len(), 1);
+        let adv = &advisories[0];
+        assert_eq!(adv.category, AnomalyCategory::WellControl);
+        assert_eq!(adv.severity, FinalSeverity::High);
+        assert_eq!(adv.trigger_parameter, "static_pressure_rise_psi");
+        assert!(adv.recommendation.contains("GAS MIGRATION"));
+
+        // Same connection with a stable casing pressure
+        let mut coordinator = PipelineCoordinator::new();
+        for t in 0..600u64 {
+            assert!(coordinator
+                .check_gas_migration(&static_packet(1000 + t, 200.0))
+                .is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gas_migration_advisory_not_lost_to_periodic_summary() {
+        ensure_config();
+        let mut coordinator = PipelineCoordinator::new();
+        let mut gas_migration = 0;
+        for t in 0..600u64 {
+            let mut packet = create_test_packet(0.0, 0.0);
+            packet.timestamp = 1000 + t;
+            packet.flow_in = 0.0;
+            packet.flow_out = 0.0;
+            packet.pump_spm = 0.0;
+            packet.casing_pressure = 200.0 + t as f64 / 4.0;
+            // Keep a periodic summary due on every packet
+            if t > 0 {
+                coordinator.last_periodic_summary_time = 1;
+            }
+            let advisory = coordinator
+                .process_packet(&mut packet, Campaign::Production)
+                .await;
+            if advisory.is_some_and(|a| a.trigger_parameter == "static_pressure_rise_psi") {
+                gas_migration += 1;
+            }
+        }
+        assert_eq!(
+            gas_migration, 1,
+            "gas migration dispatched ahead of the summary"
+        );
+    }
 }
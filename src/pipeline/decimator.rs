@@ -0,0 +1,183 @@
+//! Input Decimation
+//!
+//! At 10 Hz, running the dual CfC network and strategic verification on
+//! every packet is more than the trend needs and can put the pipeline behind
+//! the feed. `Decimator` forwards at most one packet per
+//! `1 / pipeline.decimate_to_hz` seconds of packet time to the full
+//! coordinator cycle.
+//!
+//! Naive sampling would drop the one packet that shows a kick, so every
+//! packet is still compared against the last forwarded one: a step in flow
+//! imbalance or pit volume (either direction), or a rise in gas, of at least
+//! half the well-control warning threshold is forwarded immediately
+//! regardless of the interval. Packets
+//! that are thinned out still feed baseline learning (see
+//! `TacticalAgent::observe_decimated`).
+
+use crate::config::{PipelineConfig, WellControlThresholds};
+use crate::types::WitsPacket;
+
+/// Fraction of a well-control warning threshold that counts as a step
+const STEP_FRACTION: f64 = 0.5;
+
+/// What to do with one incoming packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimateDecision {
+    /// Interval elapsed (or decimation off): run the full pipeline
+    Forward,
+    /// Inside the interval, but a well-control step change was seen
+    ForwardStepChange,
+    /// Inside the interval: baseline learning only
+    Skip,
+}
+
+impl DecimateDecision {
+    pub fn is_forwarded(self) -> bool {
+        self != DecimateDecision::Skip
+    }
+}
+
+/// Pre-stage in front of the coordinator that thins high-rate input.
+#[derive(Debug, Default)]
+pub struct Decimator {
+    /// Timestamp of the last packet forwarded on the interval
+    last_interval_ts: Option<u64>,
+    /// Last packet forwarded for any reason; step changes are measured from it
+    reference: Option<WitsPacket>,
+}
+
+impl Decimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decide(
+        &mut self,
+        packet: &WitsPacket,
+        cfg: &PipelineConfig,
+        wc: &WellControlThresholds,
+    ) -> DecimateDecision {
+        let decision = if cfg.decimate_to_hz <= 0.0 || self.interval_elapsed(packet, cfg) {
+            self.last_interval_ts = Some(packet.timestamp);
+            DecimateDecision::Forward
+        } else if self.is_step_change(packet, wc) {
+            DecimateDecision::ForwardStepChange
+        } else {
+            return DecimateDecision::Skip;
+        };
+        self.reference = Some(packet.clone());
+        decision
+    }
+
+    fn interval_elapsed(&self, packet: &WitsPacket, cfg: &PipelineConfig) -> bool {
+        match self.last_interval_ts {
+            None => true,
+            Some(last) => {
+                // A timestamp going backwards (replay restart) starts over
+                packet.timestamp < last
+                    || (packet.timestamp - last) as f64 >= 1.0 / cfg.decimate_to_hz
+            }
+        }
+    }
+
+    fn is_step_change(&self, packet: &WitsPacket, wc: &WellControlThresholds) -> bool {
+        let Some(prev) = self.reference.as_ref() else {
+            return true;
+        };
+        let imbalance = |p: &WitsPacket| p.flow_out - p.flow_in;
+        let flow_step = (imbalance(packet) - imbalance(prev)).abs();
+        let pit_step = (packet.pit_volume - prev.pit_volume).abs();
+        // Flow and pit count both ways: a sudden loss matters as much as a
+        // gain. Gas only counts rising; gas falling back to background after
+        // a peak is the event clearing, and forwarding it would defeat
+        // thinning every time connection gas decays.
+        let gas_rise = packet.gas_units - prev.gas_units;
+        flow_step >= wc.flow_imbalance_warning_gpm * STEP_FRACTION
+            || pit_step >= wc.pit_gain_warning_bbl * STEP_FRACTION
+            || gas_rise >= wc.gas_units_warning * STEP_FRACTION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steady(timestamp: u64) -> WitsPacket {
+        WitsPacket {
+            timestamp,
+            flow_in: 500.0,
+            flow_out: 500.0,
+            pit_volume: 800.0,
+            gas_units: 10.0,
+            ..WitsPacket::default()
+        }
+    }
+
+    /// Ten packets per second of timestamp, the way a 10 Hz feed arrives
+    fn ten_hz(seconds: u64) -> Vec<WitsPacket> {
+        (0..seconds)
+            .flat_map(|s| (0..10).map(move |_| steady(1_700_000_000 + s)))
+            .collect()
+    }
+
+    #[test]
+    fn test_steady_packets_are_thinned() {
+        let cfg = PipelineConfig {
+            decimate_to_hz: 0.5,
+        };
+        let wc = WellControlThresholds::default();
+        let mut decimator = Decimator::new();
+
+        let forwarded = ten_hz(10)
+            .iter()
+            .filter(|p| decimator.decide(p, &cfg, &wc).is_forwarded())
+            .count();
+        // One every two seconds out of 100 packets
+        assert_eq!(forwarded, 5);
+
+        // Decimation off forwards everything
+        let off = PipelineConfig::default();
+        let mut decimator = Decimator::new();
+        assert!(ten_hz(2)
+            .iter()
+            .all(|p| decimator.decide(p, &off, &wc) == DecimateDecision::Forward));
+    }
+
+    #[test]
+    fn test_kick_in_dropped_packet_is_forwarded() {
+        let cfg = PipelineConfig {
+            decimate_to_hz: 1.0,
+        };
+        let wc = WellControlThresholds::default();
+        let mut decimator = Decimator::new();
+        let mut packets = ten_hz(3);
+
+        // Mid-second packet (would be thinned) carries a flow-out surge and
+        // pit gain; the packets after it stay at the new level
+        for p in packets.iter_mut().skip(14) {
+            p.flow_out += wc.flow_imbalance_warning_gpm;
+            p.pit_volume += wc.pit_gain_warning_bbl;
+        }
+
+        let decisions: Vec<_> = packets
+            .iter()
+            .map(|p| decimator.decide(p, &cfg, &wc))
+            .collect();
+        assert_eq!(decisions[10], DecimateDecision::Forward);
+        assert_eq!(decisions[13], DecimateDecision::Skip);
+        assert_eq!(decisions[14], DecimateDecision::ForwardStepChange);
+        // The new level becomes the reference, so it does not re-fire
+        assert_eq!(decisions[15], DecimateDecision::Skip);
+        assert_eq!(decisions[20], DecimateDecision::Forward);
+
+        // A gas spike in a thinned packet is forwarded too
+        let mut gas = steady(1_700_000_002);
+        gas.flow_out = packets[20].flow_out;
+        gas.pit_volume = packets[20].pit_volume;
+        gas.gas_units += wc.gas_units_warning;
+        assert_eq!(
+            decimator.decide(&gas, &cfg, &wc),
+            DecimateDecision::ForwardStepChange
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Detection latency against injected ground-truth faults
+//!
+//! When the pipeline runs on synthetic data, the generator knows when each
+//! fault starts (`sensors::generate_fault_scenario`). Every advisory is
+//! matched against those faults: the first advisory of the fault's expected
+//! category within `max_latency_secs` of its onset counts as the detection.
+//! The result is served at `GET /api/v2/benchmark/detection`.
+
+use serde::Serialize;
+
+use crate::config::DetectionBenchmarkConfig;
+use crate::sensors::{FaultKind, InjectedFault};
+use crate::types::AnomalyCategory;
+
+/// Detection outcome for one injected fault
+#[derive(Debug, Clone, Serialize)]
+pub struct FaultDetection {
+    pub kind: FaultKind,
+    pub onset_timestamp: u64,
+    pub end_timestamp: u64,
+    pub detected: bool,
+    /// Timestamp of the first matching advisory
+    pub first_advisory_timestamp: Option<u64>,
+    /// Onset to first matching advisory (seconds)
+    pub latency_secs: Option<u64>,
+}
+
+/// Detection performance for one fault type
+#[derive(Debug, Clone, Serialize)]
+pub struct FaultTypePerformance {
+    pub kind: FaultKind,
+    pub injected: usize,
+    pub detected: usize,
+    pub detection_rate: f64,
+    pub mean_latency_secs: Option<f64>,
+    pub max_latency_secs: Option<u64>,
+}
+
+/// Response body for `GET /api/v2/benchmark/detection`
+#[derive(Debug, Clone, Serialize)]
+pub struct DetectionPerformanceReport {
+    /// Advisories later than this after onset count as a miss
+    pub max_latency_secs: u64,
+    pub faults: Vec<FaultDetection>,
+    pub by_type: Vec<FaultTypePerformance>,
+}
+
+/// Matches advisories against injected faults
+#[derive(Debug, Clone)]
+pub struct DetectionBenchmark {
+    faults: Vec<InjectedFault>,
+    /// First matching advisory timestamp, per fault
+    detections: Vec<Option<u64>>,
+}
+
+impl DetectionBenchmark {
+    pub fn new(faults: Vec<InjectedFault>) -> Self {
+        let detections = vec![None; faults.len()];
+        Self { faults, detections }
+    }
+
+    /// Record an advisory. Returns the faults it detected for the first
+    /// time, with their latency, so the caller can log them.
+    pub fn observe_advisory(
+        &mut self,
+        timestamp: u64,
+        category: AnomalyCategory,
+        cfg: &DetectionBenchmarkConfig,
+    ) -> Vec<(FaultKind, u64)> {
+        let mut newly_detected = Vec::new();
+        for (fault, detection) in self.faults.iter().zip(self.detections.iter_mut()) {
+            if detection.is_some()
+                || category != fault.kind.expected_category()
+                || timestamp < fault.onset_timestamp
+            {
+                continue;
+            }
+            let latency = timestamp - fault.onset_timestamp;
+            if latency > cfg.max_latency_secs {
+                continue;
+            }
+            *detection = Some(timestamp);
+            newly_detected.push((fault.kind, latency));
+        }
+        newly_detected
+    }
+
+    pub fn report(&self, cfg: &DetectionBenchmarkConfig) -> DetectionPerformanceReport {
+        let faults: Vec<FaultDetection> = self
+            .faults
+            .iter()
+            .zip(&self.detections)
+            .map(|(fault, detection)| FaultDetection {
+                kind: fault.kind,
+                onset_timestamp: fault.onset_timestamp,
+                end_timestamp: fault.end_timestamp,
+                detected: detection.is_some(),
+                first_advisory_timestamp: *detection,
+                latency_secs: detection.map(|ts| ts - fault.onset_timestamp),
+            })
+            .collect();
+
+        let mut kinds: Vec<FaultKind> = Vec::new();
+        for f in &faults {
+            if !kinds.contains(&f.kind) {
+                kinds.push(f.kind);
+            }
+        }
+        let by_type = kinds
+            .into_iter()
+            .map(|kind| {
+                let of_kind: Vec<&FaultDetection> =
+                    faults.iter().filter(|f| f.kind == kind).collect();
+                let latencies: Vec<u64> = of_kind.iter().filter_map(|f| f.latency_secs).collect();
+                FaultTypePerformance {
+                    kind,
+                    injected: of_kind.len(),
+                    detected: latencies.len(),
+                    detection_rate: latencies.len() as f64 / of_kind.len() as f64,
+                    mean_latency_secs: (!latencies.is_empty())
+                        .then(|| latencies.iter().sum::<u64>() as f64 / latencies.len() as f64),
+                    max_latency_secs: latencies.iter().copied().max(),
+                }
+            })
+            .collect();
+
+        DetectionPerformanceReport {
+            max_latency_secs: cfg.max_latency_secs,
+            faults,
+            by_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{self, WellConfig};
+    use crate::pipeline::PipelineCoordinator;
+    use crate::sensors::generate_fault_scenario;
+    use crate::types::Campaign;
+
+    fn ensure_config() {
+        if !config::is_initialized() {
+            config::init(WellConfig::default(), config::ConfigProvenance::default());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_injected_kick_detected_within_latency_bound() {
+        ensure_config();
+        let cfg = DetectionBenchmarkConfig::default();
+        let scenario = generate_fault_scenario();
+        let kick_onset = scenario
+            .faults
+            .iter()
+            .find(|f| f.kind == FaultKind::Kick)
+            .expect("kick injected")
+            .onset_timestamp;
+
+        let mut benchmark = DetectionBenchmark::new(scenario.faults.clone());
+        let mut coordinator = PipelineCoordinator::new();
+        for mut packet in scenario.packets {
+            if let Some(adv) = coordinator
+                .process_packet(&mut packet, Campaign::Production)
+                .await
+            {
+                benchmark.observe_advisory(adv.timestamp, adv.category, &cfg);
+            }
+        }
+
+        let report = benchmark.report(&cfg);
+        let kick = report
+            .faults
+            .iter()
+            .find(|f| f.kind == FaultKind::Kick)
+            .expect("kick in report");
+        assert!(kick.detected, "kick not detected: {:?}", report);
+        assert_eq!(kick.onset_timestamp, kick_onset);
+        // Synthetic packets are 60 s apart: flagged within two packets of onset
+        let latency = kick.latency_secs.expect("latency");
+        assert!(latency <= 120, "kick detection latency {} s", latency);
+
+        let kick_type = report
+            .by_type
+            .iter()
+            .find(|t| t.kind == FaultKind::Kick)
+            .expect("kick summary");
+        assert_eq!((kick_type.injected, kick_type.detected), (1, 1));
+        assert_eq!(kick_type.max_latency_secs, Some(latency));
+    }
+
+    #[test]
+    fn test_late_or_wrong_category_advisory_is_not_a_detection() {
+        let cfg = DetectionBenchmarkConfig {
+            max_latency_secs: 300,
+            ..Default::default()
+        };
+        let mut benchmark = DetectionBenchmark::new(vec![InjectedFault {
+            kind: FaultKind::Kick,
+            onset_timestamp: 1000,
+            end_timestamp: 1600,
+        }]);
+
+        // Before onset, wrong category, then too late
+        assert!(benchmark
+            .observe_advisory(900, AnomalyCategory::WellControl, &cfg)
+            .is_empty());
+        assert!(benchmark
+            .observe_advisory(1100, AnomalyCategory::Mechanical, &cfg)
+            .is_empty());
+        assert!(benchmark
+            .observe_advisory(1400, AnomalyCategory::WellControl, &cfg)
+            .is_empty());
+
+        let report = benchmark.report(&cfg);
+        assert!(!report.faults[0].detected);
+        assert_eq!(report.by_type[0].detection_rate, 0.0);
+        assert_eq!(report.by_type[0].mean_latency_secs, None);
+    }
+}
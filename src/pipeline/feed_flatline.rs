@@ -0,0 +1,208 @@
+//! Whole-Feed Flatline Detection
+//!
+//! An acquisition system can hang while the WITS connection stays open: the
+//! rig keeps sending records, but every channel repeats its last value. A
+//! clean disconnect ends the source; this does not, so the pipeline would
+//! keep analysing stale data without any sign that something is wrong.
+//!
+//! `FeedFlatlineDetector` compares the measured channels of each packet with
+//! the previous one. When none of them has changed for `window_secs` of
+//! arrival time it raises one high-priority system advisory per episode; the
+//! first changing packet ends the episode. Arrival time is used rather than
+//! the packet timestamp because a hung system may also freeze the timestamp.
+
+use crate::config::FeedFlatlineConfig;
+use crate::types::{
+    AnomalyCategory, DrillingPhysicsReport, FinalSeverity, RiskLevel, StrategicAdvisory, WitsPacket,
+};
+
+/// Measured channels compared between packets
+fn channels(packet: &WitsPacket) -> [f64; 18] {
+    [
+        packet.bit_depth,
+        packet.hole_depth,
+        packet.rop,
+        packet.hook_load,
+        packet.wob,
+        packet.rpm,
+        packet.torque,
+        packet.spp,
+        packet.pump_spm,
+        packet.flow_in,
+        packet.flow_out,
+        packet.pit_volume,
+        packet.mud_weight_in,
+        packet.mud_weight_out,
+        packet.mud_temp_in,
+        packet.mud_temp_out,
+        packet.gas_units,
+        packet.casing_pressure,
+    ]
+}
+
+/// Raised when every channel has held its value for the window
+#[derive(Debug, Clone)]
+pub struct FeedFlatlineAlert {
+    /// Timestamp of the packet that completed the window
+    pub timestamp: u64,
+    /// Time all channels have been unchanged (s)
+    pub frozen_secs: f64,
+    /// Packets received while frozen
+    pub frozen_packets: u64,
+}
+
+/// Tracks how long the whole feed has been unchanged
+#[derive(Debug, Clone, Default)]
+pub struct FeedFlatlineDetector {
+    last_channels: Option<[f64; 18]>,
+    /// Arrival time of the first packet with the current values (s)
+    frozen_since_secs: f64,
+    frozen_packets: u64,
+    /// Advisory already raised for the current episode
+    alerted: bool,
+}
+
+impl FeedFlatlineDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one packet arriving at `arrival_secs` (Unix time, s).
+    ///
+    /// Returns an alert the first time all channels have been unchanged for
+    /// `window_secs`.
+    pub fn observe(
+        &mut self,
+        packet: &WitsPacket,
+        arrival_secs: f64,
+        cfg: &FeedFlatlineConfig,
+    ) -> Option<FeedFlatlineAlert> {
+        let current = channels(packet);
+        let unchanged = self.last_channels.is_some_and(|last| {
+            last.iter()
+                .zip(&current)
+                .all(|(a, b)| (a - b).abs() <= cfg.tolerance)
+        });
+        self.last_channels = Some(current);
+
+        if !unchanged {
+            self.frozen_since_secs = arrival_secs;
+            self.frozen_packets = 0;
+            self.alerted = false;
+            return None;
+        }
+        self.frozen_packets += 1;
+
+        let frozen_secs = arrival_secs - self.frozen_since_secs;
+        if !cfg.enabled || self.alerted || frozen_secs < cfg.window_secs as f64 {
+            return None;
+        }
+        self.alerted = true;
+
+        Some(FeedFlatlineAlert {
+            timestamp: packet.timestamp,
+            frozen_secs,
+            frozen_packets: self.frozen_packets,
+        })
+    }
+}
+
+/// Build the system advisory for a frozen feed.
+pub fn feed_frozen_advisory(alert: &FeedFlatlineAlert) -> StrategicAdvisory {
+    StrategicAdvisory {
+        timestamp: alert.timestamp,
+        efficiency_score: 100,
+        risk_level: RiskLevel::High,
+        severity: FinalSeverity::High,
+        recommendation: "WITS feed appears frozen — verify acquisition. All channels have held \
+                         the same value while the connection stays open; advisories are based \
+                         on stale data until the feed recovers."
+            .to_string(),
+        expected_benefit: "Detection resumes on live rig data".to_string(),
+        reasoning: format!(
+            "No channel has changed for {:.0} s ({} packets received). A clean disconnect \
+             would close the source; a hung acquisition system keeps repeating the last record.",
+            alert.frozen_secs, alert.frozen_packets
+        ),
+        votes: Vec::new(),
+        physics_report: DrillingPhysicsReport::default(),
+        context_used: Vec::new(),
+        trace_log: Vec::new(),
+        category: AnomalyCategory::None,
+        trigger_parameter: "feed_frozen_secs".to_string(),
+        trigger_value: alert.frozen_secs,
+        threshold_value: 0.0,
+        provisional_during_learning: false,
+        sparkline: None,
+        gas_lag: None,
+        causal_leads: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(ts: u64, hook_load: f64) -> WitsPacket {
+        WitsPacket {
+            timestamp: ts,
+            bit_depth: 9000.0,
+            hole_depth: 9000.0,
+            hook_load,
+            spp: 3000.0,
+            flow_in: 500.0,
+            flow_out: 500.0,
+            pit_volume: 800.0,
+            ..WitsPacket::default()
+        }
+    }
+
+    #[test]
+    fn test_constant_feed_raises_frozen_advisory_once() {
+        let cfg = FeedFlatlineConfig::default();
+        let mut detector = FeedFlatlineDetector::new();
+        let start = 1_700_000_000u64;
+
+        let mut alerts = Vec::new();
+        for i in 0..(cfg.window_secs * 2) {
+            if let Some(a) = detector.observe(&packet(start + i, 250.0), (start + i) as f64, &cfg) {
+                alerts.push(a);
+            }
+        }
+        assert_eq!(alerts.len(), 1);
+        assert!(alerts[0].frozen_secs >= cfg.window_secs as f64);
+
+        let adv = feed_frozen_advisory(&alerts[0]);
+        assert!(adv
+            .recommendation
+            .starts_with("WITS feed appears frozen — verify acquisition"));
+        assert_eq!(adv.severity, FinalSeverity::High);
+
+        // Live data ends the episode; a second freeze alerts again
+        let restart = start + cfg.window_secs * 2;
+        let mut second = 0;
+        for i in 0..=cfg.window_secs {
+            let t = restart + i;
+            if detector
+                .observe(&packet(t, 251.0), t as f64, &cfg)
+                .is_some()
+            {
+                second += 1;
+            }
+        }
+        assert_eq!(second, 1);
+    }
+
+    #[test]
+    fn test_varying_feed_never_frozen() {
+        let cfg = FeedFlatlineConfig::default();
+        let mut detector = FeedFlatlineDetector::new();
+        let start = 1_700_000_000u64;
+        for i in 0..(cfg.window_secs * 3) {
+            // Hook load noise; every other channel constant
+            let hook_load = 250.0 + (i % 3) as f64 * 0.4;
+            let alert = detector.observe(&packet(start + i, hook_load), (start + i) as f64, &cfg);
+            assert!(alert.is_none());
+        }
+    }
+}
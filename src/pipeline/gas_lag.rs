@@ -0,0 +1,216 @@
+//! Gas Lag Depth Correlation
+//!
+//! Gas read at the surface detector entered the annulus at the bit one
+//! bottoms-up earlier, while the bit may since have drilled tens of feet.
+//! Correlating gas with formations needs the depth it came from, not the
+//! depth at which it was read.
+//!
+//! `GasLagTracker` keeps recent (timestamp, bit depth, flow-in) samples and
+//! walks back through them until the pumped volume equals the annular volume
+//! (bit depth × annular capacity) plus the configured surface volume. The bit
+//! depth at that point is the source depth. Walking the pumped volume rather
+//! than dividing by the current flow rate keeps pumps-off time (connections,
+//! flow checks) from advancing the gas.
+
+use std::collections::VecDeque;
+
+use crate::config::GasLagConfig;
+use crate::types::{GasLagCorrection, StrategicAdvisory, WitsPacket};
+
+/// bbl per ft of annulus = (D² − d²) / 1029.4, diameters in inches
+const ANNULAR_CAPACITY_DIVISOR: f64 = 1029.4;
+
+/// gal per bbl
+const GAL_PER_BBL: f64 = 42.0;
+
+/// Trigger parameters read from the gas detector
+fn is_gas_trigger(trigger_parameter: &str) -> bool {
+    matches!(trigger_parameter, "gas_units" | "h2s" | "co2")
+}
+
+/// Annular capacity (bbl/ft) between hole and pipe.
+pub fn annular_capacity_bbl_per_ft(hole_diameter_in: f64, pipe_od_in: f64) -> f64 {
+    ((hole_diameter_in * hole_diameter_in - pipe_od_in * pipe_od_in) / ANNULAR_CAPACITY_DIVISOR)
+        .max(0.0)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LagSample {
+    timestamp: u64,
+    bit_depth: f64,
+    flow_gpm: f64,
+}
+
+/// Depth and flow history for bottoms-up lag correction
+#[derive(Debug, Clone, Default)]
+pub struct GasLagTracker {
+    samples: VecDeque<LagSample>,
+}
+
+impl GasLagTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one packet; samples older than `max_lag_secs` are dropped.
+    pub fn record(&mut self, packet: &WitsPacket, cfg: &GasLagConfig) {
+        if let Some(last) = self.samples.back() {
+            if packet.timestamp < last.timestamp {
+                // Clock went backwards (replay restart): start over
+                self.samples.clear();
+            }
+        }
+        self.samples.push_back(LagSample {
+            timestamp: packet.timestamp,
+            bit_depth: packet.bit_depth,
+            flow_gpm: packet.flow_in.max(0.0),
+        });
+        let cutoff = packet.timestamp.saturating_sub(cfg.max_lag_secs);
+        while self.samples.front().is_some_and(|s| s.timestamp < cutoff) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Source depth of gas read at surface now.
+    ///
+    /// `None` until enough volume has been pumped within the tracked history
+    /// to cover one bottoms-up, or when the geometry gives no annulus.
+    pub fn correction(
+        &self,
+        hole_diameter_in: f64,
+        pipe_od_in: f64,
+        cfg: &GasLagConfig,
+    ) -> Option<GasLagCorrection> {
+        let newest = *self.samples.back()?;
+        let lag_volume_bbl = newest.bit_depth.max(0.0)
+            * annular_capacity_bbl_per_ft(hole_diameter_in, pipe_od_in)
+            + cfg.surface_volume_bbl;
+        if lag_volume_bbl <= 0.0 {
+            return None;
+        }
+
+        let mut pumped_bbl = 0.0;
+        for (prev, cur) in self
+            .samples
+            .iter()
+            .rev()
+            .skip(1)
+            .zip(self.samples.iter().rev())
+        {
+            let dt = cur.timestamp.saturating_sub(prev.timestamp) as f64;
+            let rate_bbl_per_sec = (prev.flow_gpm + cur.flow_gpm) / 2.0 / GAL_PER_BBL / 60.0;
+            let step_bbl = rate_bbl_per_sec * dt;
+            if step_bbl > 0.0 && pumped_bbl + step_bbl >= lag_volume_bbl {
+                // Interpolate within the interval
+                let frac = (lag_volume_bbl - pumped_bbl) / step_bbl;
+                let source_time = cur.timestamp as f64 - frac * dt;
+                return Some(GasLagCorrection {
+                    detected_at_depth_ft: newest.bit_depth,
+                    source_depth_ft: cur.bit_depth - frac * (cur.bit_depth - prev.bit_depth),
+                    lag_secs: newest.timestamp as f64 - source_time,
+                    lag_volume_bbl,
+                });
+            }
+            pumped_bbl += step_bbl;
+        }
+        None
+    }
+}
+
+/// Tag a gas advisory with its lag-corrected source depth.
+pub fn enrich_advisory(
+    advisory: &mut StrategicAdvisory,
+    tracker: &GasLagTracker,
+    hole_diameter_in: f64,
+    pipe_od_in: f64,
+    cfg: &GasLagConfig,
+) {
+    if !cfg.enabled || advisory.gas_lag.is_some() || !is_gas_trigger(&advisory.trigger_parameter) {
+        return;
+    }
+    if let Some(lag) = tracker.correction(hole_diameter_in, pipe_od_in, cfg) {
+        advisory.reasoning = format!(
+            "{} Gas source depth {:.0} ft (read at surface with bit at {:.0} ft; \
+             bottoms-up lag {:.0} min).",
+            advisory.reasoning,
+            lag.source_depth_ft,
+            lag.detected_at_depth_ft,
+            lag.lag_secs / 60.0
+        )
+        .trim_start()
+        .to_string();
+        advisory.gas_lag = Some(lag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drill at 60 ft/hr from 9000 ft with constant flow, 10 s packets
+    fn drilled_tracker(flow_in: f64, secs: u64) -> GasLagTracker {
+        let cfg = GasLagConfig::default();
+        let mut tracker = GasLagTracker::new();
+        for t in (0..=secs).step_by(10) {
+            tracker.record(
+                &WitsPacket {
+                    timestamp: 1_700_000_000 + t,
+                    bit_depth: 9000.0 + t as f64 / 60.0,
+                    flow_in,
+                    ..WitsPacket::default()
+                },
+                &cfg,
+            );
+        }
+        tracker
+    }
+
+    #[test]
+    fn test_gas_advisory_tagged_with_lag_corrected_depth() {
+        let cfg = GasLagConfig::default();
+        let tracker = drilled_tracker(500.0, 3600);
+
+        // 8.5" hole, 5" pipe: 0.0459 bbl/ft x 9060 ft = 415.9 bbl.
+        // 500 gpm = 0.1984 bbl/s -> 2096 s lag, 34.9 ft drilled meanwhile.
+        let capacity = (8.5_f64 * 8.5 - 5.0 * 5.0) / 1029.4;
+        let lag_volume = 9060.0 * capacity;
+        let expected_lag = lag_volume / (500.0 / 42.0 / 60.0);
+        let expected_source = 9060.0 - expected_lag / 60.0;
+
+        let mut adv = StrategicAdvisory {
+            trigger_parameter: "gas_units".to_string(),
+            ..StrategicAdvisory::default()
+        };
+        enrich_advisory(&mut adv, &tracker, 8.5, 5.0, &cfg);
+        let lag = adv.gas_lag.expect("gas advisory tagged");
+        assert!((lag.detected_at_depth_ft - 9060.0).abs() < 1e-6);
+        assert!((lag.lag_volume_bbl - lag_volume).abs() < 1e-6);
+        assert!(
+            (lag.lag_secs - expected_lag).abs() < 1.0,
+            "{}",
+            lag.lag_secs
+        );
+        assert!(
+            (lag.source_depth_ft - expected_source).abs() < 0.1,
+            "{}",
+            lag.source_depth_ft
+        );
+        assert!(adv.reasoning.contains("Gas source depth 9025 ft"));
+
+        // Non-gas advisories are left alone
+        let mut other = StrategicAdvisory {
+            trigger_parameter: "flow_balance".to_string(),
+            ..StrategicAdvisory::default()
+        };
+        enrich_advisory(&mut other, &tracker, 8.5, 5.0, &cfg);
+        assert!(other.gas_lag.is_none());
+    }
+
+    #[test]
+    fn test_no_correction_until_one_bottoms_up_pumped() {
+        let cfg = GasLagConfig::default();
+        // 20 minutes at 500 gpm is short of the ~35 minute lag
+        let tracker = drilled_tracker(500.0, 1200);
+        assert!(tracker.correction(8.5, 5.0, &cfg).is_none());
+    }
+}
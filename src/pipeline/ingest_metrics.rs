@@ -0,0 +1,317 @@
+//! Streaming Ingestion Metrics
+//!
+//! Tracks whether the pipeline keeps up with the WITS feed in real time.
+//!
+//! - **Ingestion lag**: arrival time minus packet timestamp
+//! - **Processing lag**: growth of ingestion lag above the lowest lag seen.
+//!   The lowest lag absorbs any fixed offset (rig/host clock skew, replayed
+//!   historical timestamps), so only lag that accumulates because packets
+//!   arrive faster than they are processed counts.
+//! - **Processing time** per packet and throughput over a rolling window
+//!
+//! When processing lag exceeds `max_lag_secs` a system advisory recommends
+//! decimating the feed or moving to a faster host.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::config::PipelineMetricsConfig;
+use crate::types::{
+    AnomalyCategory, DrillingPhysicsReport, FinalSeverity, RiskLevel, StrategicAdvisory,
+};
+
+/// One processed packet
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    packet_ts: u64,
+    arrival_secs: f64,
+    processing_ms: f64,
+}
+
+/// Snapshot exposed at `GET /api/v2/pipeline/metrics`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PipelineMetricsSnapshot {
+    pub packets_observed: u64,
+    /// Arrival time minus packet timestamp for the latest packet (s)
+    pub ingestion_lag_secs: f64,
+    /// Lowest ingestion lag seen — the fixed clock / replay offset (s)
+    pub lag_baseline_secs: f64,
+    /// Ingestion lag accumulated above the baseline (s)
+    pub processing_lag_secs: f64,
+    pub processing_ms_last: f64,
+    pub processing_ms_avg: f64,
+    pub processing_ms_max: f64,
+    /// Packets processed per second of wall-clock time over the window
+    pub throughput_pps: f64,
+    /// Packets buffered at the source, when the source buffers
+    pub queue_depth: Option<usize>,
+    /// Processing lag is above `max_lag_secs`
+    pub falling_behind: bool,
+    pub max_lag_secs: f64,
+}
+
+/// Raised when processing lag crosses the configured threshold
+#[derive(Debug, Clone)]
+pub struct LagAlert {
+    /// Timestamp of the packet that crossed the threshold
+    pub timestamp: u64,
+    pub processing_lag_secs: f64,
+    pub processing_ms_avg: f64,
+    /// Mean spacing of packet timestamps over the window (s)
+    pub packet_interval_secs: f64,
+}
+
+/// Rolling ingestion / processing metrics
+#[derive(Debug, Clone, Default)]
+pub struct IngestMetricsTracker {
+    window: VecDeque<Sample>,
+    packets_observed: u64,
+    lag_baseline_secs: Option<f64>,
+    queue_depth: Option<usize>,
+    /// Arrival time of the last lag advisory (s)
+    last_alert_secs: Option<f64>,
+}
+
+impl IngestMetricsTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one processed packet.
+    ///
+    /// `arrival_secs` is the Unix time (s) the packet was read from the
+    /// source. Returns a `LagAlert` when processing lag exceeds the
+    /// threshold, at most once per `alert_cooldown_secs`.
+    pub fn observe(
+        &mut self,
+        packet_ts: u64,
+        arrival_secs: f64,
+        processing: Duration,
+        queue_depth: Option<usize>,
+        cfg: &PipelineMetricsConfig,
+    ) -> Option<LagAlert> {
+        self.packets_observed += 1;
+        self.queue_depth = queue_depth;
+        if self.window.len() >= cfg.window_packets.max(2) {
+            self.window.pop_front();
+        }
+        self.window.push_back(Sample {
+            packet_ts,
+            arrival_secs,
+            processing_ms: processing.as_secs_f64() * 1000.0,
+        });
+
+        let lag = arrival_secs - packet_ts as f64;
+        let baseline = self.lag_baseline_secs.map_or(lag, |b| b.min(lag));
+        self.lag_baseline_secs = Some(baseline);
+
+        let processing_lag = lag - baseline;
+        if !cfg.enabled || processing_lag <= cfg.max_lag_secs {
+            return None;
+        }
+        if self
+            .last_alert_secs
+            .is_some_and(|t| arrival_secs - t < cfg.alert_cooldown_secs as f64)
+        {
+            return None;
+        }
+        self.last_alert_secs = Some(arrival_secs);
+
+        Some(LagAlert {
+            timestamp: packet_ts,
+            processing_lag_secs: processing_lag,
+            processing_ms_avg: self.processing_ms_avg(),
+            packet_interval_secs: self.packet_interval_secs(),
+        })
+    }
+
+    fn processing_ms_avg(&self) -> f64 {
+        if self.window.is_empty() {
+            return 0.0;
+        }
+        self.window.iter().map(|s| s.processing_ms).sum::<f64>() / self.window.len() as f64
+    }
+
+    fn packet_interval_secs(&self) -> f64 {
+        match (self.window.front(), self.window.back()) {
+            (Some(first), Some(last)) if self.window.len() > 1 => {
+                last.packet_ts.saturating_sub(first.packet_ts) as f64
+                    / (self.window.len() - 1) as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Current metrics snapshot.
+    pub fn snapshot(&self, cfg: &PipelineMetricsConfig) -> PipelineMetricsSnapshot {
+        let Some(last) = self.window.back() else {
+            return PipelineMetricsSnapshot {
+                max_lag_secs: cfg.max_lag_secs,
+                ..PipelineMetricsSnapshot::default()
+            };
+        };
+
+        let ingestion_lag = last.arrival_secs - last.packet_ts as f64;
+        let baseline = self.lag_baseline_secs.unwrap_or(ingestion_lag);
+        let processing_lag = ingestion_lag - baseline;
+        let elapsed = self
+            .window
+            .front()
+            .map_or(0.0, |first| last.arrival_secs - first.arrival_secs);
+
+        PipelineMetricsSnapshot {
+            packets_observed: self.packets_observed,
+            ingestion_lag_secs: ingestion_lag,
+            lag_baseline_secs: baseline,
+            processing_lag_secs: processing_lag,
+            processing_ms_last: last.processing_ms,
+            processing_ms_avg: self.processing_ms_avg(),
+            processing_ms_max: self
+                .window
+                .iter()
+                .map(|s| s.processing_ms)
+                .fold(0.0, f64::max),
+            throughput_pps: if elapsed > 0.0 {
+                (self.window.len() - 1) as f64 / elapsed
+            } else {
+                0.0
+            },
+            queue_depth: self.queue_depth,
+            falling_behind: processing_lag > cfg.max_lag_secs,
+            max_lag_secs: cfg.max_lag_secs,
+        }
+    }
+}
+
+/// Build the system advisory for a lag alert.
+pub fn lag_advisory(alert: &LagAlert) -> StrategicAdvisory {
+    // Keep every Nth packet so processing fits inside the packet interval
+    let decimation = if alert.packet_interval_secs > 0.0 {
+        ((alert.processing_ms_avg / 1000.0) / alert.packet_interval_secs)
+            .ceil()
+            .max(2.0)
+    } else {
+        2.0
+    };
+
+    StrategicAdvisory {
+        timestamp: alert.timestamp,
+        efficiency_score: 100,
+        risk_level: RiskLevel::Elevated,
+        severity: FinalSeverity::Medium,
+        recommendation: format!(
+            "PIPELINE LAG: Analysis is running {:.0} s behind the WITS feed. \
+             Decimate the feed (process every {:.0}th packet) or move SAIREN to a faster host \
+             so advisories stay real-time.",
+            alert.processing_lag_secs, decimation
+        ),
+        expected_benefit: "Advisories based on current rather than stale rig data".to_string(),
+        reasoning: format!(
+            "Processing averages {:.0} ms per packet against a {:.1} s packet interval; \
+             ingestion lag has grown {:.0} s above its baseline.",
+            alert.processing_ms_avg, alert.packet_interval_secs, alert.processing_lag_secs
+        ),
+        votes: Vec::new(),
+        physics_report: DrillingPhysicsReport::default(),
+        context_used: Vec::new(),
+        trace_log: Vec::new(),
+        category: AnomalyCategory::None,
+        trigger_parameter: "processing_lag_secs".to_string(),
+        trigger_value: alert.processing_lag_secs,
+        threshold_value: 0.0,
+        provisional_during_learning: false,
+        sparkline: None,
+        gas_lag: None,
+        causal_leads: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cfg() -> PipelineMetricsConfig {
+        PipelineMetricsConfig {
+            enabled: true,
+            max_lag_secs: 10.0,
+            alert_cooldown_secs: 600,
+            window_packets: 60,
+        }
+    }
+
+    #[test]
+    fn test_slow_processing_raises_lag_and_system_advisory() {
+        let cfg = cfg();
+        let mut tracker = IngestMetricsTracker::new();
+        // Rig clock 3 s behind the host: a fixed offset, not lag
+        let offset = 3.0;
+        let start_ts = 1_700_000_000u64;
+
+        // Keeping up: 1 Hz feed, 50 ms processing
+        for i in 0..30 {
+            let ts = start_ts + i;
+            let alert = tracker.observe(
+                ts,
+                ts as f64 + offset,
+                Duration::from_millis(50),
+                None,
+                &cfg,
+            );
+            assert!(alert.is_none());
+        }
+        assert!(tracker.snapshot(&cfg).processing_lag_secs.abs() < 1e-6);
+
+        // Processing slows to 1.5 s per 1 Hz packet: each packet is read
+        // 0.5 s later than the one before relative to its timestamp
+        let mut alerts = Vec::new();
+        let mut arrival = (start_ts + 29) as f64 + offset;
+        for i in 30..60 {
+            arrival += 1.5;
+            if let Some(a) = tracker.observe(
+                start_ts + i,
+                arrival,
+                Duration::from_millis(1500),
+                Some(12),
+                &cfg,
+            ) {
+                alerts.push(a);
+            }
+        }
+
+        let snap = tracker.snapshot(&cfg);
+        assert!(snap.processing_lag_secs > cfg.max_lag_secs);
+        assert!((snap.lag_baseline_secs - offset).abs() < 1e-6);
+        assert!(snap.falling_behind);
+        assert_eq!(snap.queue_depth, Some(12));
+        assert!(snap.processing_ms_max >= 1500.0);
+
+        // One advisory within the cooldown
+        assert_eq!(alerts.len(), 1);
+        let adv = lag_advisory(&alerts[0]);
+        assert_eq!(adv.trigger_parameter, "processing_lag_secs");
+        assert!(adv.trigger_value > cfg.max_lag_secs);
+        assert!(adv.recommendation.contains("Decimate"));
+        assert!(adv.recommendation.contains("faster host"));
+    }
+
+    #[test]
+    fn test_fast_replay_never_lags() {
+        let cfg = cfg();
+        let mut tracker = IngestMetricsTracker::new();
+        // Historical replay: packets from years ago, read 100 per second
+        for i in 0..200u64 {
+            let alert = tracker.observe(
+                1_400_000_000 + i,
+                1_700_000_000.0 + i as f64 * 0.01,
+                Duration::from_millis(5),
+                None,
+                &cfg,
+            );
+            assert!(alert.is_none());
+        }
+        let snap = tracker.snapshot(&cfg);
+        assert!(!snap.falling_behind);
+        assert!(snap.throughput_pps > 50.0);
+    }
+}
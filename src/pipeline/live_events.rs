@@ -0,0 +1,130 @@
+//! Live Event Bus
+//!
+//! Push feed for dashboards and control-room wall displays that should not
+//! poll `/api/v2/live`. The processing loop publishes advisory tickets,
+//! strategic advisories and rig state changes to a `tokio::sync::broadcast`
+//! channel; `GET /api/v2/stream` relays them as Server-Sent Events and
+//! `GET /api/v2/ws` over a WebSocket.
+//!
+//! Per-packet drilling metrics go to a second, small channel that only the
+//! WebSocket reads, so a metrics event every packet never pushes tickets or
+//! advisories out of a slow subscriber's buffer.
+//!
+//! Broadcast semantics handle slow clients: a subscriber that falls more
+//! than `LIVE_EVENT_CAPACITY` events behind loses the oldest ones rather
+//! than holding the pipeline back.
+
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use crate::types::{AdvisoryTicket, DrillingMetrics, RigState, StrategicAdvisory};
+
+/// Events buffered per subscriber before the oldest are dropped
+pub const LIVE_EVENT_CAPACITY: usize = 256;
+
+/// Metrics events buffered per subscriber; only the newest matter
+pub const LIVE_METRICS_CAPACITY: usize = 16;
+
+/// One event on the live stream
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum LiveEvent {
+    /// Tactical agent raised an advisory ticket
+    Ticket(Box<AdvisoryTicket>),
+    /// Advisory surfaced on the dashboard (muted advisories are not sent)
+    Advisory(Box<StrategicAdvisory>),
+    /// Drilling metrics for the latest processed packet (metrics channel)
+    Metrics(Box<DrillingMetrics>),
+    /// Classified rig state changed
+    RigState {
+        timestamp: u64,
+        previous: RigState,
+        current: RigState,
+    },
+}
+
+impl LiveEvent {
+    /// Event name on the SSE stream and in WebSocket frames
+    pub fn name(&self) -> &'static str {
+        match self {
+            LiveEvent::Ticket(_) => "ticket",
+            LiveEvent::Advisory(_) => "advisory",
+            LiveEvent::Metrics(_) => "metrics",
+            LiveEvent::RigState { .. } => "rig_state",
+        }
+    }
+}
+
+/// Cloneable handle to the live event and metrics channels.
+#[derive(Debug, Clone)]
+pub struct LiveEventBus {
+    tx: broadcast::Sender<LiveEvent>,
+    metrics_tx: broadcast::Sender<LiveEvent>,
+}
+
+impl Default for LiveEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiveEventBus {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(LIVE_EVENT_CAPACITY);
+        let (metrics_tx, _) = broadcast::channel(LIVE_METRICS_CAPACITY);
+        Self { tx, metrics_tx }
+    }
+
+    /// Whether any client is listening; lets callers skip building events.
+    pub fn has_subscribers(&self) -> bool {
+        self.tx.receiver_count() > 0
+    }
+
+    /// Whether any client is listening for metrics
+    pub fn has_metrics_subscribers(&self) -> bool {
+        self.metrics_tx.receiver_count() > 0
+    }
+
+    /// Publish to every current subscriber (a no-op with none connected).
+    /// `Metrics` events go to the metrics channel.
+    pub fn publish(&self, event: LiveEvent) {
+        let tx = match event {
+            LiveEvent::Metrics(_) => &self.metrics_tx,
+            _ => &self.tx,
+        };
+        let _ = tx.send(event);
+    }
+
+    /// Tickets, advisories and rig state changes
+    pub fn subscribe(&self) -> broadcast::Receiver<LiveEvent> {
+        self.tx.subscribe()
+    }
+
+    /// Per-packet drilling metrics
+    pub fn subscribe_metrics(&self) -> broadcast::Receiver<LiveEvent> {
+        self.metrics_tx.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_do_not_share_the_event_channel() {
+        let bus = LiveEventBus::new();
+        let mut events = bus.subscribe();
+        let mut metrics = bus.subscribe_metrics();
+
+        for _ in 0..LIVE_EVENT_CAPACITY * 2 {
+            bus.publish(LiveEvent::Metrics(Box::default()));
+        }
+        bus.publish(LiveEvent::Advisory(Box::default()));
+
+        assert_eq!(events.try_recv().unwrap().name(), "advisory");
+        assert!(events.try_recv().is_err());
+        // The metrics subscriber lagged, then reads the newest metrics
+        assert!(metrics.try_recv().is_err());
+        assert_eq!(metrics.try_recv().unwrap().name(), "metrics");
+    }
+}
@@ -17,10 +17,22 @@
 //!
 //! CRITICAL GUARANTEE: Phases 5-9 ONLY execute if Tactical Agent created a ticket.
 
+pub mod acknowledgments;
+mod advisory_queue;
+pub mod chart_log;
 mod coordinator;
+pub mod decimator;
+pub mod detection_benchmark;
+pub mod feed_flatline;
+pub mod gas_lag;
+pub mod ingest_metrics;
+pub mod live_events;
 pub mod processing_loop;
 pub mod source;
+pub mod sparkline;
+pub mod stage_latency;
 mod state;
+mod wellbore;
 
 pub use coordinator::{PipelineCoordinator, PipelineStats};
 pub use state::*;
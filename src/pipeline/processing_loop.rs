@@ -9,6 +9,7 @@ use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
+use super::live_events::LiveEvent;
 use super::source::{PacketEvent, PacketSource};
 use super::{AppState, PipelineCoordinator, PipelineStats, SystemStatus};
 use crate::config::defaults::ML_HISTORY_BUFFER_SIZE;
@@ -61,6 +62,19 @@ pub struct ProcessingLoop<H: PostProcessHooks> {
     cancel_token: CancellationToken,
     /// Tracks WOB/RPM changes to stamp `seconds_since_param_change` on every packet.
     param_tracker: crate::ml_engine::param_change_tracker::ParamChangeTracker,
+    /// Ingestion lag / processing time across packets.
+    ingest_metrics: super::ingest_metrics::IngestMetricsTracker,
+    /// Whole-feed flatline (hung acquisition) detection.
+    feed_flatline: super::feed_flatline::FeedFlatlineDetector,
+    /// Thins high-rate input before the full pipeline (`pipeline.decimate_to_hz`).
+    decimator: super::decimator::Decimator,
+    /// `[pipeline]` settings pinned by tests in place of the global config
+    #[cfg(test)]
+    pipeline_override: Option<crate::config::PipelineConfig>,
+    /// Push feed behind `GET /api/v2/stream` and `GET /api/v2/ws`.
+    live_events: super::live_events::LiveEventBus,
+    /// Rig state of the previous packet, for change events.
+    last_rig_state: Option<crate::types::RigState>,
 }
 
 impl<H: PostProcessHooks> ProcessingLoop<H> {
@@ -76,15 +90,30 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
             hooks,
             cancel_token,
             param_tracker: crate::ml_engine::param_change_tracker::ParamChangeTracker::new(),
+            ingest_metrics: super::ingest_metrics::IngestMetricsTracker::new(),
+            feed_flatline: super::feed_flatline::FeedFlatlineDetector::new(),
+            decimator: super::decimator::Decimator::new(),
+            #[cfg(test)]
+            pipeline_override: None,
+            live_events: super::live_events::LiveEventBus::new(),
+            last_rig_state: None,
         }
     }
 
+    /// Publish tickets, advisories, metrics and rig state changes to
+    /// `live_events` (shared with the dashboard's push endpoints).
+    pub fn with_live_events(mut self, live_events: super::live_events::LiveEventBus) -> Self {
+        self.live_events = live_events;
+        self
+    }
+
     /// Run the processing loop until the source is exhausted or cancellation.
     ///
     /// Returns final pipeline statistics.
     pub async fn run<S: PacketSource>(mut self, source: &mut S) -> PipelineStats {
         let mut packets_processed = 0u64;
         let mut advisories_generated = 0u64;
+        let mut last_ml_report_ts = 0u64;
 
         info!(
             "📊 Processing WITS packets from {}...",
@@ -118,12 +147,59 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
                     );
                     break;
                 }
+                PacketEvent::ReplayComplete { complete_well } => {
+                    info!(
+                        "[PacketProcessor] Replay complete ({} packets processed)",
+                        packets_processed
+                    );
+                    let debrief = if complete_well {
+                        let (rig_states, data_loss) = {
+                            let state = self.app_state.read().await;
+                            let data_loss = state
+                                .mesh_evictions
+                                .as_ref()
+                                .map(crate::gossip::store::EvictionLog::windows)
+                                .unwrap_or_default();
+                            (state.rig_state_log.clone(), data_loss)
+                        };
+                        self.complete_well(&rig_states, &data_loss)
+                    } else {
+                        None
+                    };
+                    let mut state = self.app_state.write().await;
+                    state.status = SystemStatus::ReplayComplete;
+                    state.well_debrief = debrief;
+                    break;
+                }
             };
 
+            // Input decimation: thinned packets only feed baseline learning
+            let decision = {
+                let cfg = crate::config::get();
+                #[cfg(test)]
+                let pipeline = self.pipeline_override.as_ref().unwrap_or(&cfg.pipeline);
+                #[cfg(not(test))]
+                let pipeline = &cfg.pipeline;
+                self.decimator
+                    .decide(&packet, pipeline, &cfg.thresholds.well_control)
+            };
+            if !decision.is_forwarded() {
+                self.coordinator
+                    .tactical_agent_mut()
+                    .observe_decimated(&packet);
+                self.app_state.read().await.stage_latency.note_decimated();
+                continue;
+            }
+
             packets_processed += 1;
+            let arrival_secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64();
+            let processing_start = std::time::Instant::now();
 
             // Update app state with incoming data and read current campaign
-            let campaign = {
+            let (campaign, sidetrack_marker, choke_declaration, new_ml_report) = {
                 let mut state = self.app_state.write().await;
                 state.current_rpm = packet.rpm;
                 state.samples_collected = packets_processed as usize;
@@ -131,12 +207,41 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
                 state.last_analysis_time = Some(chrono::Utc::now());
                 state.status = SystemStatus::Monitoring;
                 state.latest_wits_packet = Some(packet.clone());
-                state.campaign
+                let new_ml_report = state
+                    .latest_ml_report
+                    .as_ref()
+                    .filter(|r| r.timestamp > last_ml_report_ts)
+                    .cloned();
+                (
+                    state.campaign,
+                    state.pending_sidetrack.take(),
+                    state.pending_choke_adjustment.take(),
+                    new_ml_report,
+                )
             };
 
+            if let Some(marker) = sidetrack_marker {
+                self.coordinator.mark_sidetrack(marker, &packet);
+            }
+            if let Some(declaration) = choke_declaration {
+                self.coordinator.mark_choke_adjustment(&declaration, &packet);
+            }
+
+            // Calibrate formation hardness from each new ML analysis
+            if let Some(report) = new_ml_report {
+                last_ml_report_ts = report.timestamp;
+                self.coordinator.ingest_ml_report(&report);
+            }
+
             // Process through the 10-phase pipeline
             let mut packet = packet;
             let advisory = self.coordinator.process_packet(&mut packet, campaign).await;
+            if let Some(ticket) = self.coordinator.cycle_ticket() {
+                if self.live_events.has_subscribers() {
+                    self.live_events
+                        .publish(LiveEvent::Ticket(Box::new(ticket.clone())));
+                }
+            }
 
             // Per-packet post-processing — runs for ALL input modes.
             {
@@ -155,14 +260,22 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
                 }
                 state.wits_history.push_back(packet.clone());
                 state.regime_centroids = self.coordinator.regime_centroids();
+                state.regime_stability = self.coordinator.regime_stability();
 
                 // Store latest drilling metrics (includes operation classification)
                 if let Some(metrics) = self.coordinator.get_latest_metrics() {
                     state.latest_drilling_metrics = Some(metrics.clone());
+                    if self.live_events.has_metrics_subscribers() {
+                        self.live_events
+                            .publish(LiveEvent::Metrics(Box::new(metrics.clone())));
+                    }
                 }
 
                 // Store damping monitor snapshot for API visibility
                 state.damping_monitor_snapshot = Some(self.coordinator.damping_monitor_snapshot());
+                state.cfc_health = self.coordinator.tactical_agent().cfc_health_report();
+                state.sensor_glitches = self.coordinator.get_stats().sensor_glitches;
+                state.directional = Some(self.coordinator.directional_summary());
 
                 // Update connection gas tracker with rig state from latest metrics
                 let rig_state = state
@@ -170,7 +283,72 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
                     .as_ref()
                     .map(|m| m.state)
                     .unwrap_or_default();
-                state.connection_gas_tracker.update(&packet, rig_state);
+                if let Some(change) = state.rig_state_log.record(packet.timestamp, rig_state) {
+                    if let Err(e) = crate::storage::rig_state_log::append(&change) {
+                        warn!("Failed to persist rig state change: {}", e);
+                    }
+                }
+                if let Some(previous) = self.last_rig_state.filter(|&s| s != rig_state) {
+                    self.live_events.publish(LiveEvent::RigState {
+                        timestamp: packet.timestamp,
+                        previous,
+                        current: rig_state,
+                    });
+                }
+                self.last_rig_state = Some(rig_state);
+
+                // Expire stale operator acknowledgments
+                let now = arrival_secs as u64;
+                let advisory_cfg = &crate::config::get().advisory;
+                for record in super::acknowledgments::sweep_expired(
+                    &mut state.acknowledgments,
+                    now,
+                    advisory_cfg,
+                ) {
+                    if let Err(e) = crate::storage::acks::remove(
+                        record.acknowledged_at,
+                        record.ticket_timestamp,
+                    ) {
+                        warn!("Failed to remove expired acknowledgment: {}", e);
+                    }
+                    info!(
+                        ticket_ts = record.ticket_timestamp,
+                        category = ?record.category,
+                        "Acknowledgment expired"
+                    );
+                }
+                if let Some(event) = state.connection_gas_tracker.update(&packet, rig_state) {
+                    let scorecard = &crate::config::get().connection_scorecard;
+                    // Only breaks that went through a connection are scored
+                    if scorecard.enabled && event.rig_connection {
+                        state.connection_log.push_back(event);
+                        while state.connection_log.len() > scorecard.max_connections {
+                            state.connection_log.pop_front();
+                        }
+                    }
+                }
+
+                // Whole-well drilling performance charts
+                let cfg = crate::config::get();
+                if let Some(warnings) = state.config_sanity.observe(&packet, &cfg) {
+                    for w in warnings {
+                        warn!("⚠️  CONFIG MISMATCH ({}): {}", w.check, w.message);
+                    }
+                }
+                let charts = &cfg.charts;
+                if charts.enabled {
+                    state.chart_log.record(&packet, rig_state, charts);
+                    if charts.mse_log {
+                        if let Some(metrics) = self.coordinator.get_latest_metrics() {
+                            state.chart_log.record_mse(
+                                &packet,
+                                metrics,
+                                cfg.physics.min_rop_for_mse,
+                                charts,
+                            );
+                        }
+                    }
+                }
 
                 // Update swab/surge estimation during tripping
                 if rig_state == crate::types::RigState::TrippingIn
@@ -230,20 +408,38 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
                 }
 
                 // Update bit wear tracker during active drilling
-                if rig_state == crate::types::RigState::Drilling
-                    || rig_state == crate::types::RigState::Reaming
-                {
+                // Trip out: the footage and hours belong to the pulled bit
+                if self.coordinator.take_ended_bit_run().is_some() {
+                    state.bit_wear_tracker.reset();
+                    state.bit_depth_drilled = 0.0;
+                    state.bit_hours = 0.0;
+                }
+                if matches!(
+                    rig_state,
+                    crate::types::RigState::Drilling
+                        | crate::types::RigState::Sliding
+                        | crate::types::RigState::Reaming
+                ) {
                     let hardness = self
                         .coordinator
                         .formation_hardness_at_depth(packet.bit_depth)
                         .unwrap_or(5.0);
+                    // Hole depth drives the new-hole high-water mark; fall back
+                    // to bit depth when the feed doesn't populate it
+                    let hole_depth = if packet.hole_depth > 0.0 {
+                        packet.hole_depth
+                    } else {
+                        packet.bit_depth
+                    };
                     state.bit_wear_tracker.update(
-                        packet.bit_depth,
+                        hole_depth,
                         packet.mse,
                         packet.wob,
                         packet.rpm,
                         hardness,
                     );
+                    state.bit_depth_drilled = state.bit_wear_tracker.footage_drilled();
+                    state.bit_hours = self.coordinator.bit_hours();
                 }
 
                 // Store CfC formation transition event (if any)
@@ -259,36 +455,138 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
                         state.formation_transition_timestamps.drain(..excess);
                     }
                     state.latest_formation_transition = Some(event.clone());
+                    state
+                        .candidate_tops
+                        .record(event, &crate::config::get().candidate_tops);
 
                     // Notify bit wear tracker of formation change so it restarts
                     // its comparison window with new-formation hardness
                     state.bit_wear_tracker.notify_formation_change();
                 }
 
+                // Learning-to-operational transition (fires once)
+                if let Some(event) = self.coordinator.tactical_agent_mut().take_mode_transition() {
+                    info!("✅ {}", event.message);
+                    state.mode_transition = Some(event);
+                }
+                state.learning_stall = self.coordinator.tactical_agent().learning_stall().cloned();
+
                 // Proactive damping recipe (set on formation transition by coordinator)
                 state.proactive_damping = self.coordinator.proactive_damping().cloned();
 
+                // Active wellbore branch (sidetrack marker or auto-detected)
+                state.active_wellbore = self.coordinator.active_wellbore().cloned();
+
                 // Any remaining mode-specific hooks (no-op for () — see PostProcessHooks)
+                let hooks_start = std::time::Instant::now();
                 self.hooks
                     .on_packet(&mut packet, &self.coordinator, &mut state);
+                state.stage_latency.record(
+                    super::stage_latency::PipelineStage::PostProcess,
+                    hooks_start.elapsed(),
+                );
+                state
+                    .stage_latency
+                    .record_cycle(self.coordinator.stage_timings());
             }
 
-            if let Some(ref adv) = advisory {
-                advisories_generated += 1;
+            // Ingestion lag / processing time; a lag advisory joins this cycle's output
+            let lag_advisory = {
+                let cfg = &crate::config::get().pipeline_metrics;
+                let alert = self.ingest_metrics.observe(
+                    packet.timestamp,
+                    arrival_secs,
+                    processing_start.elapsed(),
+                    source.queue_depth(),
+                    cfg,
+                );
+                let snapshot = self.ingest_metrics.snapshot(cfg);
+                let mut state = self.app_state.write().await;
+                if snapshot.falling_behind {
+                    state.stage_latency.note_lagged();
+                }
+                state.pipeline_metrics = snapshot;
+                drop(state);
+                alert.map(|a| {
+                    warn!(
+                        lag_secs = a.processing_lag_secs,
+                        processing_ms = a.processing_ms_avg,
+                        "Pipeline falling behind the WITS feed"
+                    );
+                    super::ingest_metrics::lag_advisory(&a)
+                })
+            };
 
+            // All channels unchanged while the connection stays open
+            let frozen_advisory = self
+                .feed_flatline
+                .observe(&packet, arrival_secs, &crate::config::get().feed_flatline)
+                .map(|a| {
+                    warn!(
+                        frozen_secs = a.frozen_secs,
+                        packets = a.frozen_packets,
+                        "WITS feed appears frozen — verify acquisition"
+                    );
+                    super::feed_flatline::feed_frozen_advisory(&a)
+                });
+
+            for adv in advisory
+                .iter()
+                .chain(lag_advisory.iter())
+                .chain(frozen_advisory.iter())
+            {
                 // Update dashboard state
                 {
                     let mut state = self.app_state.write().await;
                     state.latest_advisory = Some(adv.clone());
+                    if self.live_events.has_subscribers() {
+                        self.live_events
+                            .publish(LiveEvent::Advisory(Box::new(adv.clone())));
+                    }
+                    if let Some(benchmark) = state.detection_benchmark.as_mut() {
+                        let cfg = &crate::config::get().detection_benchmark;
+                        for (kind, latency_secs) in
+                            benchmark.observe_advisory(adv.timestamp, adv.category, cfg)
+                        {
+                            info!(
+                                fault = ?kind,
+                                latency_secs,
+                                "🎯 Injected fault detected"
+                            );
+                        }
+                    }
                 }
 
-                // Persist to history storage
-                if let Err(e) = crate::storage::history::store_report(adv) {
-                    warn!("Failed to persist advisory to history: {}", e);
+                // Learning preview: shown on the dashboard, but kept out of
+                // history and the advisory count until baselines lock
+                if adv.provisional_during_learning {
+                    info!(
+                        "🔍 PROVISIONAL ADVISORY (baselines learning): {:?} | {}",
+                        adv.risk_level,
+                        truncate_str(&adv.recommendation, 70)
+                    );
+                    continue;
                 }
 
-                // Log advisory summary
-                log_advisory(advisories_generated, adv);
+                advisories_generated += 1;
+                record_advisory(advisories_generated, adv);
+            }
+
+            // Provisional advisories promoted now that baselines have locked
+            for adv in self.coordinator.take_promoted_advisories() {
+                advisories_generated += 1;
+                record_advisory(advisories_generated, &adv);
+            }
+
+            // Collapse near-duplicate regime centroids so the timeline doesn't fragment
+            let cfc_cfg = &crate::config::get().cfc;
+            if cfc_cfg.regime_merge_distance > 0.0
+                && packets_processed.is_multiple_of(cfc_cfg.regime_merge_interval_packets)
+            {
+                let merged = self.coordinator.merge_regimes(cfc_cfg.regime_merge_distance);
+                if merged > 0 {
+                    info!("🔀 Merged {} near-duplicate CfC regime(s)", merged);
+                }
             }
 
             // Progress indicator every 10 packets
@@ -313,16 +611,54 @@ impl<H: PostProcessHooks> ProcessingLoop<H> {
         info!("   Tickets Rejected:     {}", stats.tickets_rejected);
         info!("   Advisories Generated: {}", stats.strategic_analyses);
         info!("   History Buffer Size:  {}/60", stats.history_buffer_size);
+        info!("   Sensor Glitches:      {}", stats.sensor_glitches);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
 
         stats
     }
+
+    /// Treat the end of a replay as well complete: post-well summary + debrief.
+    fn complete_well(
+        &self,
+        rig_states: &crate::types::RigStateLog,
+        data_loss: &[crate::gossip::store::Eviction],
+    ) -> Option<crate::types::WellDebrief> {
+        let Some(kb) = self.coordinator.knowledge_base() else {
+            warn!(
+                "[PacketProcessor] Replay complete but knowledge base not configured \
+                 (set SAIREN_KB and SAIREN_KB_FIELD); skipping debrief"
+            );
+            return None;
+        };
+        match crate::debrief::complete_well(kb, rig_states, data_loss) {
+            Ok(debrief) => {
+                info!(
+                    well = %debrief.well_id,
+                    depth_ft = debrief.total_depth_ft,
+                    "🏁 Well complete: post-well summary and debrief written"
+                );
+                Some(debrief)
+            }
+            Err(e) => {
+                warn!("[PacketProcessor] Failed to complete well at end of replay: {}", e);
+                None
+            }
+        }
+    }
 }
 
 // ============================================================================
 // Helpers
 // ============================================================================
 
+/// Persist an advisory to history storage and log its summary.
+fn record_advisory(count: u64, adv: &StrategicAdvisory) {
+    if let Err(e) = crate::storage::history::store_report(adv) {
+        warn!("Failed to persist advisory to history: {}", e);
+    }
+    log_advisory(count, adv);
+}
+
 /// Log an advisory summary to tracing output.
 fn log_advisory(count: u64, adv: &StrategicAdvisory) {
     info!(
@@ -356,3 +692,274 @@ fn truncate_str(s: &str, max_len: usize) -> String {
         format!("{}...", &s[..boundary])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::source::{CsvSource, ReplayEofBehavior};
+    use crate::types::{
+        AnalysisInsights, AnalysisResult, Campaign, ConfidenceLevel, KnowledgeBaseConfig,
+        MLInsightsReport, OptimalParams,
+    };
+
+    fn make_report(ts: u64, formation: &str) -> MLInsightsReport {
+        MLInsightsReport {
+            timestamp: ts,
+            campaign: Campaign::Production,
+            depth_range: (1000.0, 2000.0),
+            well_id: "Well-A".to_string(),
+            field_name: "TestField".to_string(),
+            wellbore: None,
+            bit_hours: 10.0,
+            bit_depth: 500.0,
+            formation_type: formation.to_string(),
+            result: AnalysisResult::Success(AnalysisInsights {
+                optimal_params: OptimalParams {
+                    achieved_rop: 100.0,
+                    achieved_mse: 15000.0,
+                    best_wob: 25.0,
+                    best_rpm: 110.0,
+                    best_flow: 500.0,
+                    ..Default::default()
+                },
+                correlations: Vec::new(),
+                summary_text: "test".to_string(),
+                confidence: ConfidenceLevel::Medium,
+                sample_count: 1000,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_eof_with_completion_writes_debrief() {
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let kb_config = KnowledgeBaseConfig {
+            root: tmp.path().to_path_buf(),
+            field: "TestField".to_string(),
+            well: "Well-A".to_string(),
+            ..Default::default()
+        };
+        let kb = crate::knowledge_base::KnowledgeBase::from_config(kb_config.clone())
+            .expect("knowledge base");
+        let report = make_report(1700000000, "Shallow");
+        crate::knowledge_base::mid_well::write_snapshot(&kb_config, &report).expect("snapshot");
+
+        let mut coordinator = PipelineCoordinator::new();
+        coordinator.set_knowledge_base(Some(kb));
+        let app_state = Arc::new(RwLock::new(AppState::default()));
+
+        let packets: Vec<WitsPacket> = (0..5)
+            .map(|i| WitsPacket {
+                timestamp: 1700000000 + i,
+                ..WitsPacket::default()
+            })
+            .collect();
+        let mut source =
+            CsvSource::new(packets, 0).with_eof_behavior(ReplayEofBehavior::Complete);
+
+        ProcessingLoop::new(coordinator, Arc::clone(&app_state), (), CancellationToken::new())
+            .run(&mut source)
+            .await;
+
+        let state = app_state.read().await;
+        assert_eq!(state.status, SystemStatus::ReplayComplete);
+        let debrief = state.well_debrief.as_ref().expect("debrief generated at EOF");
+        assert_eq!(debrief.well_id, "Well-A");
+
+        let post_dir = kb_config.post_well_dir("Well-A");
+        assert!(post_dir.join("summary.toml").exists());
+        assert!(post_dir.join("debrief.json").exists());
+    }
+
+    /// Steady drilling at 1 Hz; from `kick_at` on, returns exceed flow-in by
+    /// 30 gpm with a gas rise — a kick.
+    fn drilling_with_kick(len: u64, kick_at: u64) -> Vec<WitsPacket> {
+        (0..len)
+            .map(|i| {
+                let kick = i >= kick_at;
+                WitsPacket {
+                    timestamp: 1_700_000_000 + i,
+                    bit_depth: 10_000.0 + i as f64 * 0.01,
+                    hole_depth: 10_000.0 + i as f64 * 0.01,
+                    rop: 50.0,
+                    hook_load: 200.0,
+                    wob: 25.0,
+                    rpm: 120.0,
+                    torque: 15.0,
+                    bit_diameter: 8.5,
+                    spp: 2800.0,
+                    pump_spm: 120.0,
+                    flow_in: 500.0,
+                    flow_out: if kick { 530.0 } else { 500.0 },
+                    pit_volume: 500.0,
+                    mud_weight_in: 12.0,
+                    mud_weight_out: 12.0,
+                    ecd: 12.4,
+                    gas_units: if kick { 200.0 } else { 50.0 },
+                    background_gas: 40.0,
+                    rig_state: crate::types::RigState::Drilling,
+                    ..WitsPacket::default()
+                }
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_well_control_critical_after_ack_reaches_dashboard() {
+        use crate::api::handlers::AcknowledgmentRecord;
+        use crate::types::{AnomalyCategory, RiskLevel};
+
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+
+        // The crew acknowledged an earlier kick moments ago
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let mut state = AppState::default();
+        state.acknowledgments.push_back(AcknowledgmentRecord {
+            ticket_timestamp: 1_699_999_000,
+            acknowledged_by: "driller".to_string(),
+            acknowledged_at: now,
+            notes: String::new(),
+            action_taken: "flow check".to_string(),
+            category: Some(AnomalyCategory::WellControl),
+            critical: true,
+            reason_code: None,
+        });
+        let app_state = Arc::new(RwLock::new(state));
+
+        let mut source = CsvSource::new(drilling_with_kick(160, 150), 0);
+        ProcessingLoop::new(
+            PipelineCoordinator::new(),
+            Arc::clone(&app_state),
+            (),
+            CancellationToken::new(),
+        )
+        .run(&mut source)
+        .await;
+
+        let state = app_state.read().await;
+        let adv = state
+            .latest_advisory
+            .as_ref()
+            .expect("re-raised kick reaches the dashboard");
+        assert_eq!(adv.category, AnomalyCategory::WellControl);
+        assert_eq!(adv.risk_level, RiskLevel::Critical);
+        // The acknowledgment itself is still within its TTL
+        assert_eq!(state.acknowledgments.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_kick_in_thinned_stretch_raises_well_control_ticket() {
+        use crate::types::AnomalyCategory;
+
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+
+        // One packet per 5 s goes through on the interval (…, 145, 150). The
+        // kick starts at 152 and the feed ends at 154, before the next
+        // interval packet, so only step-change forwarding can catch it.
+        let app_state = Arc::new(RwLock::new(AppState::default()));
+        let live_events = crate::pipeline::live_events::LiveEventBus::new();
+        let mut events = live_events.subscribe();
+        let mut processing = ProcessingLoop::new(
+            PipelineCoordinator::new(),
+            Arc::clone(&app_state),
+            (),
+            CancellationToken::new(),
+        )
+        .with_live_events(live_events);
+        processing.pipeline_override = Some(crate::config::PipelineConfig {
+            decimate_to_hz: 0.2,
+        });
+        let mut source = CsvSource::new(drilling_with_kick(155, 152), 0);
+        processing.run(&mut source).await;
+
+        let mut tickets = Vec::new();
+        loop {
+            match events.try_recv() {
+                Ok(LiveEvent::Ticket(ticket)) => tickets.push(ticket),
+                Ok(_) | Err(tokio::sync::broadcast::error::TryRecvError::Lagged(_)) => {}
+                Err(_) => break,
+            }
+        }
+        let kick = tickets
+            .iter()
+            .find(|t| t.category == AnomalyCategory::WellControl)
+            .expect("kick in a thinned stretch raises a well-control ticket");
+        assert_eq!(kick.timestamp, 1_700_000_152);
+
+        let state = app_state.read().await;
+        // 31 interval packets plus the step change; the rest were thinned
+        assert_eq!(state.total_analyses, 32);
+        let adv = state.latest_advisory.as_ref().expect("advisory");
+        assert_eq!(adv.category, AnomalyCategory::WellControl);
+    }
+
+    /// Hook that burns a fixed amount of wall time on every packet
+    struct SlowHook(std::time::Duration);
+
+    impl PostProcessHooks for SlowHook {
+        fn on_packet(
+            &mut self,
+            _packet: &mut WitsPacket,
+            _coordinator: &PipelineCoordinator,
+            _state: &mut AppState,
+        ) {
+            std::thread::sleep(self.0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_hook_shows_in_post_process_latency() {
+        if !crate::config::is_initialized() {
+            crate::config::init(
+                crate::config::WellConfig::default(),
+                crate::config::ConfigProvenance::default(),
+            );
+        }
+
+        let app_state = Arc::new(RwLock::new(AppState::default()));
+        let packets: Vec<WitsPacket> = (0..5)
+            .map(|i| WitsPacket {
+                timestamp: 1700000000 + i,
+                ..WitsPacket::default()
+            })
+            .collect();
+        let mut source = CsvSource::new(packets, 0);
+
+        let hook = SlowHook(std::time::Duration::from_millis(30));
+        ProcessingLoop::new(
+            PipelineCoordinator::new(),
+            Arc::clone(&app_state),
+            hook,
+            CancellationToken::new(),
+        )
+        .run(&mut source)
+        .await;
+
+        let stats = app_state.read().await.stage_latency.snapshot();
+        assert_eq!(stats.post_process.samples, 5);
+        assert!(stats.post_process.p50_ms >= 25.0);
+        assert!(stats.post_process.max_ms >= 30.0);
+        // No tickets from idle packets, so strategic verification never ran
+        assert_eq!(stats.strategic.samples, 0);
+    }
+}
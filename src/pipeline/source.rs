@@ -6,6 +6,9 @@
 use crate::types::WitsPacket;
 use anyhow::Result;
 use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 /// Events produced by a packet source.
 pub enum PacketEvent {
@@ -13,6 +16,12 @@ pub enum PacketEvent {
     Packet(WitsPacket),
     /// Source reached end of data (EOF for files/stdin, permanent disconnect for TCP).
     Eof,
+    /// A replay ran out of recorded packets. Unlike [`Eof`](PacketEvent::Eof)
+    /// this is the expected end of the data, not a dead feed.
+    ReplayComplete {
+        /// Treat the end of the replay as well complete (post-well summary + debrief).
+        complete_well: bool,
+    },
 }
 
 /// Trait abstracting where WITS packets come from.
@@ -23,33 +32,72 @@ pub enum PacketEvent {
 pub trait PacketSource: Send + 'static {
     /// Read the next packet from the source.
     ///
-    /// Returns `PacketEvent::Eof` when no more data is available.
+    /// Returns `PacketEvent::Eof` when no more data is available, or
+    /// `PacketEvent::ReplayComplete` when a replay finishes its recording.
     /// Returns `Err` on unrecoverable errors (e.g. failed reconnection).
     async fn next_packet(&mut self) -> Result<PacketEvent>;
 
     /// Human-readable name for logging (e.g. "CSV", "stdin", "WITS-TCP").
     fn source_name(&self) -> &str;
+
+    /// Packets buffered and waiting to be read, for sources that buffer.
+    fn queue_depth(&self) -> Option<usize> {
+        None
+    }
 }
 
 // ============================================================================
 // CSV Source (file / synthetic replay)
 // ============================================================================
 
+/// What a replay does once its packets are exhausted (`[replay] eof_behavior`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplayEofBehavior {
+    /// Stop and report the replay complete.
+    #[default]
+    Halt,
+    /// Restart from the first packet.
+    Loop,
+    /// Report the replay complete and treat it as well complete.
+    Complete,
+}
+
+impl ReplayEofBehavior {
+    /// Parse a `replay.eof_behavior` config value (unknown values halt).
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "loop" => Self::Loop,
+            "complete" => Self::Complete,
+            _ => Self::Halt,
+        }
+    }
+}
+
 /// Replays pre-loaded WITS packets with optional inter-packet delay.
 pub struct CsvSource {
-    packets: std::vec::IntoIter<WitsPacket>,
+    packets: Vec<WitsPacket>,
+    cursor: usize,
     delay_ms: u64,
     yielded_first: bool,
+    eof_behavior: ReplayEofBehavior,
 }
 
 impl CsvSource {
     pub fn new(packets: Vec<WitsPacket>, delay_ms: u64) -> Self {
         Self {
-            packets: packets.into_iter(),
+            packets,
+            cursor: 0,
             delay_ms,
             yielded_first: false,
+            eof_behavior: ReplayEofBehavior::Halt,
         }
     }
+
+    /// Set what happens when the packets run out.
+    pub fn with_eof_behavior(mut self, eof_behavior: ReplayEofBehavior) -> Self {
+        self.eof_behavior = eof_behavior;
+        self
+    }
 }
 
 #[async_trait]
@@ -60,12 +108,25 @@ impl PacketSource for CsvSource {
         if self.yielded_first && self.delay_ms > 0 {
             tokio::time::sleep(tokio::time::Duration::from_millis(self.delay_ms)).await;
         }
-        match self.packets.next() {
+        if self.cursor >= self.packets.len()
+            && self.eof_behavior == ReplayEofBehavior::Loop
+            && !self.packets.is_empty()
+        {
+            tracing::info!(
+                "[CsvSource] Replay exhausted ({} packets), looping",
+                self.packets.len()
+            );
+            self.cursor = 0;
+        }
+        match self.packets.get(self.cursor) {
             Some(p) => {
+                self.cursor += 1;
                 self.yielded_first = true;
-                Ok(PacketEvent::Packet(p))
+                Ok(PacketEvent::Packet(p.clone()))
             }
-            None => Ok(PacketEvent::Eof),
+            None => Ok(PacketEvent::ReplayComplete {
+                complete_well: self.eof_behavior == ReplayEofBehavior::Complete,
+            }),
         }
     }
 
@@ -74,6 +135,182 @@ impl PacketSource for CsvSource {
     }
 }
 
+// ============================================================================
+// Streaming CSV Source (large files, parsed row by row)
+// ============================================================================
+
+/// Row format of a streamed CSV, detected once from its first line.
+enum CsvRowFormat {
+    /// Volve WITSML export (Kaggle or Tunkiel column names).
+    Volve(crate::volve::VolveRowParser),
+    /// Generic SAIREN CSV (`timestamp,bit_depth,hole_depth,...`).
+    Generic,
+}
+
+/// Replays a CSV file row by row without loading it into memory.
+///
+/// Same pacing as [`CsvSource`], but each `next_packet` reads and parses one
+/// line, so multi-GB historical exports replay in constant memory. Volve
+/// whole-file passes (gap interpolation, quality filters, depth windows) are
+/// not available when streaming.
+pub struct StreamingCsvSource {
+    path: std::path::PathBuf,
+    reader: tokio::io::BufReader<tokio::fs::File>,
+    line_buffer: String,
+    line_num: usize,
+    format: CsvRowFormat,
+    /// First line of a headerless generic file, yielded before reading on.
+    pending_line: Option<String>,
+    delay_ms: u64,
+    yielded_first: bool,
+    yielded_this_pass: usize,
+    eof_behavior: ReplayEofBehavior,
+}
+
+impl StreamingCsvSource {
+    /// Open a CSV file and detect Volve vs generic format from its header.
+    pub async fn open(path: impl AsRef<std::path::Path>, delay_ms: u64) -> Result<Self> {
+        use tokio::io::AsyncBufReadExt;
+        let path = path.as_ref().to_path_buf();
+        let file = tokio::fs::File::open(&path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to open {}: {}", path.display(), e))?;
+        let mut reader = tokio::io::BufReader::new(file);
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 {
+            return Err(anyhow::anyhow!("Empty file: {}", path.display()));
+        }
+        let header = header.trim_end_matches(['\r', '\n']).to_string();
+
+        let is_generic_header = header.trim_start().starts_with("timestamp,bit_depth");
+        let (format, pending_line) = if is_generic_header {
+            (CsvRowFormat::Generic, None)
+        } else {
+            match crate::volve::VolveRowParser::from_header(
+                &header,
+                crate::volve::VolveConfig::default(),
+            ) {
+                Ok(parser) => (CsvRowFormat::Volve(parser), None),
+                // No recognisable header: treat the first line as generic data
+                Err(_) => (CsvRowFormat::Generic, Some(header)),
+            }
+        };
+
+        Ok(Self {
+            path,
+            reader,
+            line_buffer: String::with_capacity(2048),
+            line_num: 1,
+            format,
+            pending_line,
+            delay_ms,
+            yielded_first: false,
+            yielded_this_pass: 0,
+            eof_behavior: ReplayEofBehavior::Halt,
+        })
+    }
+
+    /// Set what happens when the file runs out.
+    pub fn with_eof_behavior(mut self, eof_behavior: ReplayEofBehavior) -> Self {
+        self.eof_behavior = eof_behavior;
+        self
+    }
+
+    /// Detected format for logging ("Volve Kaggle", "Volve Tunkiel", "generic").
+    pub fn format_name(&self) -> &'static str {
+        match &self.format {
+            CsvRowFormat::Volve(parser) => match parser.format_name() {
+                "Tunkiel" => "Volve Tunkiel",
+                _ => "Volve Kaggle",
+            },
+            CsvRowFormat::Generic => "generic",
+        }
+    }
+
+    /// Read and parse the next valid row without pacing; `None` at end of file.
+    ///
+    /// Blank, null and malformed rows are skipped, as in the batch loaders.
+    pub async fn next_row(&mut self) -> Result<Option<WitsPacket>> {
+        use tokio::io::AsyncBufReadExt;
+        loop {
+            let line = match self.pending_line.take() {
+                Some(line) => line,
+                None => {
+                    self.line_buffer.clear();
+                    if self.reader.read_line(&mut self.line_buffer).await? == 0 {
+                        return Ok(None);
+                    }
+                    self.line_num += 1;
+                    self.line_buffer.trim_end_matches(['\r', '\n']).to_string()
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parsed = match &self.format {
+                CsvRowFormat::Volve(parser) => parser.parse_line(&line, self.line_num),
+                CsvRowFormat::Generic => {
+                    crate::sensors::parse_csv_line(&line, self.line_num).map(Some)
+                }
+            };
+            match parsed {
+                Ok(Some(packet)) => {
+                    self.yielded_this_pass += 1;
+                    return Ok(Some(packet));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(line = self.line_num, error = %e, "[StreamingCsvSource] Skipping row");
+                }
+            }
+        }
+    }
+
+    /// Reopen the file from the top for another replay pass.
+    async fn rewind(&mut self) -> Result<()> {
+        let reopened = Self::open(&self.path, self.delay_ms).await?;
+        self.reader = reopened.reader;
+        self.line_num = reopened.line_num;
+        self.pending_line = reopened.pending_line;
+        self.yielded_this_pass = 0;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PacketSource for StreamingCsvSource {
+    async fn next_packet(&mut self) -> Result<PacketEvent> {
+        if self.yielded_first && self.delay_ms > 0 {
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.delay_ms)).await;
+        }
+        let mut packet = self.next_row().await?;
+        if packet.is_none()
+            && self.eof_behavior == ReplayEofBehavior::Loop
+            && self.yielded_this_pass > 0
+        {
+            tracing::info!(
+                "[StreamingCsvSource] Replay exhausted ({} packets), looping",
+                self.yielded_this_pass
+            );
+            self.rewind().await?;
+            packet = self.next_row().await?;
+        }
+        match packet {
+            Some(p) => {
+                self.yielded_first = true;
+                Ok(PacketEvent::Packet(p))
+            }
+            None => Ok(PacketEvent::ReplayComplete {
+                complete_well: self.eof_behavior == ReplayEofBehavior::Complete,
+            }),
+        }
+    }
+
+    fn source_name(&self) -> &str {
+        "CSV-stream"
+    }
+}
+
 // ============================================================================
 // Stdin Source (JSON WITS packets, one per line)
 // ============================================================================
@@ -131,10 +368,17 @@ impl PacketSource for StdinSource {
 
 /// Reads WITS Level 0 packets from a TCP connection.
 ///
-/// Wraps [`WitsClient`](crate::acquisition::WitsClient) which handles
-/// reconnection and timeouts internally.
+/// When the link drops (read error, timeout or EOF) the source reconnects
+/// with exponential backoff (`[acquisition]` reconnect delays) and resumes
+/// yielding packets. Retries continue until the link is re-established or
+/// the cancellation token fires, which ends the source with `Eof`.
 pub struct TcpSource {
     client: crate::acquisition::WitsClient,
+    reconnect_base_ms: u64,
+    reconnect_max_ms: u64,
+    cancel_token: CancellationToken,
+    /// Successful reconnections, shared so the supervisor can log it.
+    reconnects: Arc<AtomicU64>,
 }
 
 impl TcpSource {
@@ -145,19 +389,91 @@ impl TcpSource {
             .connect()
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))?;
-        Ok(Self { client })
+        let backoff = crate::config::AcquisitionConfig::default();
+        Ok(Self {
+            client,
+            reconnect_base_ms: backoff.reconnect_base_ms,
+            reconnect_max_ms: backoff.reconnect_max_ms,
+            cancel_token: CancellationToken::new(),
+            reconnects: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Set the reconnect backoff from `[acquisition]`.
+    pub fn with_reconnect(mut self, cfg: &crate::config::AcquisitionConfig) -> Self {
+        self.reconnect_base_ms = cfg.reconnect_base_ms;
+        self.reconnect_max_ms = cfg.reconnect_max_ms;
+        self
+    }
+
+    /// Abort the reconnect loop when this token is cancelled.
+    pub fn with_cancel_token(mut self, cancel_token: CancellationToken) -> Self {
+        self.cancel_token = cancel_token;
+        self
+    }
+
+    /// Shared count of successful reconnections.
+    pub fn reconnect_counter(&self) -> Arc<AtomicU64> {
+        Arc::clone(&self.reconnects)
+    }
+
+    /// Reconnect with exponential backoff until connected or cancelled.
+    ///
+    /// Returns `false` if cancelled before the link came back.
+    async fn reconnect(&mut self) -> bool {
+        let mut attempt: u32 = 0;
+        loop {
+            attempt += 1;
+            let delay_ms = self
+                .reconnect_base_ms
+                .saturating_mul(2u64.saturating_pow(attempt - 1))
+                .min(self.reconnect_max_ms);
+            tracing::warn!(
+                attempt = attempt,
+                delay_ms = delay_ms,
+                "WITS link down — reconnecting"
+            );
+
+            let client = &mut self.client;
+            let result = tokio::select! {
+                _ = self.cancel_token.cancelled() => return false,
+                result = async move {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+                    client.connect().await
+                } => result,
+            };
+
+            match result {
+                Ok(()) => {
+                    let total = self.reconnects.fetch_add(1, Ordering::Relaxed) + 1;
+                    tracing::info!(
+                        attempt = attempt,
+                        total_reconnects = total,
+                        "WITS link re-established"
+                    );
+                    return true;
+                }
+                Err(e) => {
+                    tracing::warn!(attempt = attempt, error = %e, "WITS reconnect attempt failed");
+                }
+            }
+        }
     }
 }
 
 #[async_trait]
 impl PacketSource for TcpSource {
     async fn next_packet(&mut self) -> Result<PacketEvent> {
-        // WitsClient::read_packet() handles reconnection internally.
-        // If it returns an error, reconnection has already been exhausted.
-        match self.client.read_packet().await {
-            Ok(packet) => Ok(PacketEvent::Packet(packet)),
-            Err(crate::acquisition::WitsError::ConnectionClosed) => Ok(PacketEvent::Eof),
-            Err(e) => Err(anyhow::anyhow!("WITS TCP error: {}", e)),
+        loop {
+            match self.client.read_packet_once().await {
+                Ok(packet) => return Ok(PacketEvent::Packet(packet)),
+                Err(e) => {
+                    tracing::warn!(error = %e, "WITS link dropped");
+                    if !self.reconnect().await {
+                        return Ok(PacketEvent::Eof);
+                    }
+                }
+            }
         }
     }
 
@@ -165,3 +481,145 @@ impl PacketSource for TcpSource {
         "WITS-TCP"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[tokio::test]
+    async fn test_streaming_csv_source_yields_rows_in_order() {
+        let mut file = tempfile::NamedTempFile::new().expect("temp csv");
+        writeln!(
+            file,
+            "timestamp,bit_depth,hole_depth,rop,hook_load,wob,rpm,torque,spp,pump_spm,\
+             flow_in,flow_out,pit_volume,mud_weight_in,mud_weight_out,ecd,gas_units"
+        )
+        .unwrap();
+        let rows = 250;
+        for i in 0..rows {
+            writeln!(
+                file,
+                "{},{},{},50,200,20,120,15,3000,100,500,500,800,10.5,10.6,10.8,20",
+                1_700_000_000 + i,
+                5000 + i,
+                5000 + i
+            )
+            .unwrap();
+            if i == 100 {
+                // Blank and malformed rows are skipped, not fatal
+                writeln!(file).unwrap();
+                writeln!(file, "not,a,packet").unwrap();
+            }
+        }
+        file.flush().unwrap();
+
+        let mut source = StreamingCsvSource::open(file.path(), 0)
+            .await
+            .expect("open stream");
+        assert_eq!(source.format_name(), "generic");
+
+        let mut count = 0u64;
+        loop {
+            match source.next_packet().await.expect("next packet") {
+                PacketEvent::Packet(p) => {
+                    assert_eq!(p.timestamp, 1_700_000_000 + count);
+                    count += 1;
+                    // Parsed lazily: nothing beyond the current row is held
+                    assert!(source.pending_line.is_none());
+                }
+                PacketEvent::ReplayComplete { complete_well } => {
+                    assert!(!complete_well);
+                    break;
+                }
+                PacketEvent::Eof => panic!("replay should complete, not hit a dead feed"),
+            }
+        }
+        assert_eq!(count, rows);
+    }
+
+    #[tokio::test]
+    async fn test_tcp_source_reconnects_after_link_drop() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock WITS server");
+        let port = listener.local_addr().unwrap().port();
+        let frame = |depth: u32| format!("&&\r\n0108{}.0\r\n0110{}.0\r\n!!\r\n", depth, depth);
+
+        // First connection sends one frame then drops; the second keeps going
+        let server = tokio::spawn(async move {
+            let (mut first, _) = listener.accept().await.unwrap();
+            first.write_all(frame(1000).as_bytes()).await.unwrap();
+            drop(first);
+            let (mut second, _) = listener.accept().await.unwrap();
+            for depth in [1001, 1002] {
+                second.write_all(frame(depth).as_bytes()).await.unwrap();
+            }
+            second.flush().await.unwrap();
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+        });
+
+        let mut source = TcpSource::connect("127.0.0.1", port)
+            .await
+            .expect("connect to mock server")
+            .with_reconnect(&crate::config::AcquisitionConfig {
+                reconnect_base_ms: 10,
+                reconnect_max_ms: 50,
+                ..Default::default()
+            });
+        let reconnects = source.reconnect_counter();
+
+        let mut depths = Vec::new();
+        while depths.len() < 3 {
+            match source.next_packet().await.expect("next packet") {
+                PacketEvent::Packet(p) => depths.push(p.bit_depth),
+                _ => panic!("source should recover, not end"),
+            }
+        }
+        assert_eq!(depths, vec![1000.0, 1001.0, 1002.0]);
+        assert_eq!(reconnects.load(Ordering::Relaxed), 1);
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_tcp_source_cancel_aborts_reconnect() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock WITS server");
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (first, _) = listener.accept().await.unwrap();
+            drop(first);
+            drop(listener);
+        });
+
+        let cancel = CancellationToken::new();
+        let mut source = TcpSource::connect("127.0.0.1", port)
+            .await
+            .expect("connect to mock server")
+            .with_reconnect(&crate::config::AcquisitionConfig {
+                reconnect_base_ms: 10,
+                reconnect_max_ms: 20,
+                ..Default::default()
+            })
+            .with_cancel_token(cancel.clone());
+        server.await.unwrap();
+
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            cancel.cancel();
+        });
+        let event = tokio::time::timeout(
+            tokio::time::Duration::from_secs(5),
+            source.next_packet(),
+        )
+        .await
+        .expect("cancellation should end the retry loop")
+        .expect("next packet");
+        assert!(matches!(event, PacketEvent::Eof));
+        assert_eq!(source.reconnect_counter().load(Ordering::Relaxed), 0);
+        canceller.await.unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+//! Advisory Trend Sparklines
+//!
+//! Each advisory carries the recent trajectory of the metric that raised it
+//! (the last `length` packets of the history buffer), so the dashboard can
+//! show how, say, torque moved into a pack-off without a second query.
+//!
+//! The trigger parameter is mapped to the series a driller would recognise:
+//! torque-based triggers plot raw torque rather than the percent delta,
+//! SPP triggers plot SPP, and so on. Triggers with no per-packet series
+//! (e.g. periodic summaries) get no sparkline.
+
+use crate::config::SparklineConfig;
+use crate::types::{HistoryEntry, Sparkline, SparklinePoint, StrategicAdvisory};
+
+/// Series plotted for a trigger parameter: (label, extractor)
+fn series_for(trigger_parameter: &str) -> Option<(&'static str, fn(&HistoryEntry) -> f64)> {
+    let series: (&'static str, fn(&HistoryEntry) -> f64) = match trigger_parameter {
+        "torque_delta_percent" | "torque_cv_monitor" | "stick_slip_cv" => {
+            ("torque", |e| e.packet.torque)
+        }
+        "spp_delta" | "trapped_pressure_psi" => ("spp", |e| e.packet.spp),
+        "static_pressure_rise_psi" => ("casing_pressure", |e| e.packet.casing_pressure),
+        "flow_balance" => ("flow_balance", |e| e.metrics.flow_balance),
+        "pit_rate" => ("pit_rate", |e| e.metrics.pit_rate),
+        "ecd_margin" => ("ecd_margin", |e| e.metrics.ecd_margin),
+        "mse_efficiency" => ("mse_efficiency", |e| e.metrics.mse_efficiency),
+        "d_exponent" => ("d_exponent", |e| e.metrics.d_exponent),
+        "rop_break_ratio" => ("rop", |e| e.packet.rop),
+        "loss_deficit_pct" => ("flow_out", |e| e.packet.flow_out),
+        "mud_weight_deviation_ppg" => ("mud_weight_in", |e| e.packet.mud_weight_in),
+        "wob_limit" => ("wob", |e| e.packet.wob),
+        "rpm_limit" => ("rpm", |e| e.packet.rpm),
+        "flow_limit" => ("flow_in", |e| e.packet.flow_in),
+        _ => return None,
+    };
+    Some(series)
+}
+
+/// Build the sparkline for a trigger parameter from the history buffer
+/// (oldest first, current packet last).
+pub fn build_sparkline(
+    trigger_parameter: &str,
+    history: &[HistoryEntry],
+    cfg: &SparklineConfig,
+) -> Option<Sparkline> {
+    let (parameter, value) = series_for(trigger_parameter)?;
+    let start = history.len().saturating_sub(cfg.length);
+    let points: Vec<SparklinePoint> = history[start..]
+        .iter()
+        .map(|e| SparklinePoint {
+            timestamp: e.packet.timestamp,
+            value: value(e),
+        })
+        .filter(|p| p.value.is_finite())
+        .collect();
+    if points.len() < 2 {
+        return None;
+    }
+    Some(Sparkline {
+        parameter: parameter.to_string(),
+        points,
+    })
+}
+
+/// Attach a sparkline to an advisory that does not have one yet.
+pub fn enrich_advisory(advisory: &mut StrategicAdvisory, history: &[HistoryEntry]) {
+    if advisory.sparkline.is_some() || !crate::config::is_initialized() {
+        return;
+    }
+    let cfg = &crate::config::get().sparklines;
+    if cfg.enabled {
+        advisory.sparkline = build_sparkline(&advisory.trigger_parameter, history, cfg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DrillingMetrics, WitsPacket};
+
+    fn entry(ts: u64, torque: f64) -> HistoryEntry {
+        HistoryEntry {
+            packet: WitsPacket {
+                timestamp: ts,
+                torque,
+                ..WitsPacket::default()
+            },
+            metrics: DrillingMetrics::default(),
+        }
+    }
+
+    #[test]
+    fn test_torque_advisory_sparkline_shows_rising_torque() {
+        let cfg = SparklineConfig {
+            length: 20,
+            ..SparklineConfig::default()
+        };
+        // 40 s flat at 15 kft-lbs, then 20 s climbing into a pack-off
+        let mut history: Vec<HistoryEntry> = (0..40).map(|i| entry(1000 + i, 15.0)).collect();
+        history.extend((0..20).map(|i| entry(1040 + i, 15.0 + 0.5 * i as f64)));
+
+        let spark = build_sparkline("torque_delta_percent", &history, &cfg).expect("sparkline");
+        assert_eq!(spark.parameter, "torque");
+        assert_eq!(spark.points.len(), 20);
+        assert_eq!(spark.points.first().map(|p| p.timestamp), Some(1040));
+        assert_eq!(spark.points.last().map(|p| p.timestamp), Some(1059));
+        assert!(spark.points.windows(2).all(|w| w[1].value > w[0].value));
+        assert!((spark.points.last().unwrap().value - 24.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unknown_trigger_has_no_sparkline() {
+        let cfg = SparklineConfig::default();
+        let history: Vec<HistoryEntry> = (0..10).map(|i| entry(i, 15.0)).collect();
+        assert!(build_sparkline("periodic_summary", &history, &cfg).is_none());
+    }
+}
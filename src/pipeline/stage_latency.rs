@@ -0,0 +1,249 @@
+//! Per-Stage Processing Latency
+//!
+//! Rolling latency histograms for the expensive stages of the processing
+//! loop, so a pipeline that falls behind the WITS feed can be diagnosed
+//! down to the stage that is eating the packet budget:
+//!
+//! - **tactical**: `TacticalAgent::process`, excluding the CfC update
+//! - **cfc**: dual CfC network update (drilling/reaming only)
+//! - **strategic**: strategic ticket verification (only when a ticket fires)
+//! - **post_process**: mode-specific `PostProcessHooks`
+//!
+//! Samples land in fixed log-spaced buckets held in atomic counters, so
+//! recording is a bucket lookup plus one `fetch_add` and the API reads the
+//! histogram without taking the app-state write lock. Once a stage holds
+//! `WINDOW` samples every bucket is halved, which keeps the histogram
+//! weighted toward recent packets. Percentiles are reported as the upper
+//! bound of the bucket they fall in (the observed max for the last bucket).
+//!
+//! Served at `GET /api/v2/pipeline/stats`.
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Bucket upper bounds in microseconds; the last bucket is unbounded.
+const BUCKET_BOUNDS_US: [u64; 14] = [
+    50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000,
+    1_000_000,
+];
+const NUM_BUCKETS: usize = BUCKET_BOUNDS_US.len() + 1;
+
+/// Samples per stage before the histogram is halved.
+const WINDOW: u64 = 1024;
+
+/// Timed stage of the processing loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineStage {
+    Tactical,
+    Cfc,
+    Strategic,
+    PostProcess,
+}
+
+impl PipelineStage {
+    const ALL: [PipelineStage; 4] = [
+        PipelineStage::Tactical,
+        PipelineStage::Cfc,
+        PipelineStage::Strategic,
+        PipelineStage::PostProcess,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            PipelineStage::Tactical => 0,
+            PipelineStage::Cfc => 1,
+            PipelineStage::Strategic => 2,
+            PipelineStage::PostProcess => 3,
+        }
+    }
+}
+
+/// Stage durations measured during one coordinator cycle.
+///
+/// A stage that did not run this cycle (no drilling, no ticket) is `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StageTimings {
+    pub tactical: Option<Duration>,
+    pub cfc: Option<Duration>,
+    pub strategic: Option<Duration>,
+}
+
+/// Rolling bucketed histogram for one stage
+#[derive(Debug, Default)]
+struct StageHistogram {
+    buckets: [AtomicU64; NUM_BUCKETS],
+    count: AtomicU64,
+    total: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl StageHistogram {
+    fn record(&self, elapsed: Duration) {
+        let us = elapsed.as_micros().min(u64::MAX as u128) as u64;
+        let idx = BUCKET_BOUNDS_US
+            .iter()
+            .position(|&bound| us <= bound)
+            .unwrap_or(NUM_BUCKETS - 1);
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.total.fetch_add(1, Ordering::Relaxed);
+        self.max_us.fetch_max(us, Ordering::Relaxed);
+        if self.count.fetch_add(1, Ordering::Relaxed) + 1 >= WINDOW {
+            // Single writer (the processing loop), so halving bucket by
+            // bucket cannot race another record.
+            let mut remaining = 0;
+            for bucket in &self.buckets {
+                let halved = bucket.load(Ordering::Relaxed) / 2;
+                bucket.store(halved, Ordering::Relaxed);
+                remaining += halved;
+            }
+            self.count.store(remaining, Ordering::Relaxed);
+        }
+    }
+
+    /// Latency (ms) at quantile `q` in [0, 1]; 0 when empty.
+    fn percentile_ms(&self, counts: &[u64; NUM_BUCKETS], q: f64) -> f64 {
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let rank = ((total as f64) * q).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (idx, &n) in counts.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                let us = BUCKET_BOUNDS_US
+                    .get(idx)
+                    .copied()
+                    .unwrap_or_else(|| self.max_us.load(Ordering::Relaxed));
+                return us as f64 / 1000.0;
+            }
+        }
+        self.max_us.load(Ordering::Relaxed) as f64 / 1000.0
+    }
+
+    fn snapshot(&self) -> StageLatencySnapshot {
+        let counts: [u64; NUM_BUCKETS] =
+            std::array::from_fn(|i| self.buckets[i].load(Ordering::Relaxed));
+        StageLatencySnapshot {
+            samples: self.total.load(Ordering::Relaxed),
+            p50_ms: self.percentile_ms(&counts, 0.50),
+            p95_ms: self.percentile_ms(&counts, 0.95),
+            p99_ms: self.percentile_ms(&counts, 0.99),
+            max_ms: self.max_us.load(Ordering::Relaxed) as f64 / 1000.0,
+        }
+    }
+}
+
+/// Latency percentiles for one stage
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StageLatencySnapshot {
+    /// Samples recorded since startup
+    pub samples: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Snapshot exposed at `GET /api/v2/pipeline/stats`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PipelineStageStats {
+    pub tactical: StageLatencySnapshot,
+    pub cfc: StageLatencySnapshot,
+    pub strategic: StageLatencySnapshot,
+    pub post_process: StageLatencySnapshot,
+    /// Packets processed while the pipeline was falling behind the feed
+    pub lagged_packets: u64,
+    /// Packets thinned out by input decimation (`pipeline.decimate_to_hz`)
+    pub decimated_packets: u64,
+}
+
+/// Shared per-stage latency histograms.
+///
+/// Held in `AppState` behind an `Arc`; the processing loop records, the API
+/// snapshots.
+#[derive(Debug, Default)]
+pub struct StageLatency {
+    stages: [StageHistogram; 4],
+    lagged_packets: AtomicU64,
+    decimated_packets: AtomicU64,
+}
+
+impl StageLatency {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, stage: PipelineStage, elapsed: Duration) {
+        self.stages[stage.index()].record(elapsed);
+    }
+
+    /// Record every stage that ran during a coordinator cycle.
+    pub fn record_cycle(&self, timings: &StageTimings) {
+        let stages = [
+            (PipelineStage::Tactical, timings.tactical),
+            (PipelineStage::Cfc, timings.cfc),
+            (PipelineStage::Strategic, timings.strategic),
+        ];
+        for (stage, elapsed) in stages {
+            if let Some(elapsed) = elapsed {
+                self.record(stage, elapsed);
+            }
+        }
+    }
+
+    /// Count a packet processed while ingestion lag was over the limit.
+    pub fn note_lagged(&self) {
+        self.lagged_packets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a packet that skipped the full pipeline.
+    pub fn note_decimated(&self) {
+        self.decimated_packets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> PipelineStageStats {
+        let [tactical, cfc, strategic, post_process] =
+            PipelineStage::ALL.map(|stage| self.stages[stage.index()].snapshot());
+        PipelineStageStats {
+            tactical,
+            cfc,
+            strategic,
+            post_process,
+            lagged_packets: self.lagged_packets.load(Ordering::Relaxed),
+            decimated_packets: self.decimated_packets.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_follow_buckets_and_window_rolls() {
+        let latency = StageLatency::new();
+        for _ in 0..90 {
+            latency.record(PipelineStage::Tactical, Duration::from_micros(400));
+        }
+        for _ in 0..10 {
+            latency.record(PipelineStage::Tactical, Duration::from_millis(40));
+        }
+
+        let stats = latency.snapshot();
+        assert_eq!(stats.tactical.samples, 100);
+        assert_eq!(stats.tactical.p50_ms, 0.5);
+        assert_eq!(stats.tactical.p95_ms, 50.0);
+        assert_eq!(stats.tactical.max_ms, 40.0);
+        assert_eq!(stats.cfc.samples, 0);
+        assert_eq!(stats.cfc.p99_ms, 0.0);
+
+        // A long run of fast samples pushes the old slow ones out of p99
+        for _ in 0..(WINDOW * 4) {
+            latency.record(PipelineStage::Tactical, Duration::from_micros(20));
+        }
+        let stats = latency.snapshot();
+        assert_eq!(stats.tactical.p99_ms, 0.05);
+    }
+}
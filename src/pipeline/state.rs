@@ -103,6 +103,9 @@ pub struct AppState {
     /// Total tickets rejected as transient (for shift summary)
     pub tickets_rejected: u64,
 
+    /// WITS channel samples flagged as single-sample glitches
+    pub sensor_glitches: u64,
+
     /// Peak severity observed during current session
     #[serde(skip)]
     pub peak_severity: crate::types::TicketSeverity,
@@ -113,6 +116,28 @@ pub struct AppState {
     /// Latest CfC formation transition event
     pub latest_formation_transition: Option<crate::types::FormationTransitionEvent>,
 
+    /// Learning-to-operational transition (set once when baselines lock)
+    pub mode_transition: Option<crate::types::ModeTransitionEvent>,
+
+    /// Baseline learning stalled with no progress toward locking
+    pub learning_stall: Option<crate::baseline::watchdog::LearningStallEvent>,
+
+    /// Active wellbore branch (`None` while on the original hole)
+    pub active_wellbore: Option<crate::types::SidetrackEvent>,
+
+    /// Operator sidetrack marker awaiting the next packet
+    #[serde(skip)]
+    pub pending_sidetrack: Option<crate::types::SidetrackMarker>,
+
+    /// Operator-declared MPD choke adjustment awaiting the next packet
+    #[serde(skip)]
+    pub pending_choke_adjustment:
+        Option<crate::physics_engine::choke::ChokeAdjustmentDeclaration>,
+
+    /// Detection latency benchmark (synthetic data with injected faults)
+    #[serde(skip)]
+    pub detection_benchmark: Option<super::detection_benchmark::DetectionBenchmark>,
+
     /// CfC formation transition timestamps (for ML dual-source segmentation)
     #[serde(skip)]
     pub formation_transition_timestamps: Vec<u64>,
@@ -121,6 +146,10 @@ pub struct AppState {
     #[serde(skip)]
     pub regime_centroids: [[f64; 8]; 4],
 
+    /// Fraction of recent packets that stayed in one regime (1.0 = steady state)
+    #[serde(skip)]
+    pub regime_stability: f64,
+
     /// Latest damping monitor snapshot (updated every packet by coordinator)
     #[serde(skip)]
     pub damping_monitor_snapshot: Option<crate::types::DampingMonitorSnapshot>,
@@ -129,10 +158,57 @@ pub struct AppState {
     #[serde(skip)]
     pub connection_gas_tracker: crate::physics_engine::connection_gas::ConnectionGasTracker,
 
+    /// Detected connections for the connection-practices scorecard
+    #[serde(skip)]
+    pub connection_log: std::collections::VecDeque<crate::physics_engine::connection_gas::ConnectionGasEvent>,
+
+    /// Whole-well time-depth / ROP-depth chart series (in memory only;
+    /// they start over when the process restarts)
+    #[serde(skip)]
+    pub chart_log: super::chart_log::ChartLog,
+
+    /// Whole-well rig state transitions for debrief NPT accounting
+    /// (persisted in `storage::rig_state_log`, capped at
+    /// `MAX_RIG_STATE_CHANGES`)
+    #[serde(skip)]
+    pub rig_state_log: crate::types::RigStateLog,
+
+    /// Latest CfC network health (drift / degradation)
+    #[serde(skip)]
+    pub cfc_health: Option<crate::cfc::health::CfcHealthReport>,
+
+    /// Latest slide / rotate / survey summary (directional wells)
+    #[serde(skip)]
+    pub directional: Option<crate::physics_engine::directional::DirectionalSummary>,
+
+    /// Knowledge-base snapshot write health (retries, queued failures)
+    #[serde(skip)]
+    pub kb_snapshot_status: Option<crate::knowledge_base::snapshot_queue::SnapshotWriteStatus>,
+
+    /// Mesh event store size-cap evictions (None when mesh is disabled)
+    #[serde(skip)]
+    pub mesh_evictions: Option<crate::gossip::store::EvictionLog>,
+
+    /// Configured-vs-observed check for a config from another well
+    #[serde(skip)]
+    pub config_sanity: crate::config::sanity::ConfigSanityChecker,
+
     /// Bit wear tracker (v6: Phase 4)
     #[serde(skip)]
     pub bit_wear_tracker: crate::optimization::bit_wear::BitWearTracker,
 
+    /// Candidate formation tops from CfC formation transitions
+    #[serde(skip)]
+    pub candidate_tops: crate::cfc::candidate_tops::CandidateTopTracker,
+
+    /// Streaming ingestion lag / processing time (updated every packet)
+    #[serde(skip)]
+    pub pipeline_metrics: super::ingest_metrics::PipelineMetricsSnapshot,
+
+    /// Per-stage processing latency histograms (recorded every packet)
+    #[serde(skip)]
+    pub stage_latency: std::sync::Arc<super::stage_latency::StageLatency>,
+
     /// Latest swab/surge estimate (v6: Phase 5, only during tripping)
     #[serde(skip)]
     pub latest_swab_surge: Option<crate::physics_engine::swab_surge::SwabSurgeEstimate>,
@@ -140,6 +216,10 @@ pub struct AppState {
     /// Proactive damping recipe recommendation (v6: Phase 7, set on formation transition)
     #[serde(skip)]
     pub proactive_damping: Option<ProactiveDamping>,
+
+    /// Debrief generated when a replay ended with `eof_behavior = "complete"`
+    #[serde(skip)]
+    pub well_debrief: Option<crate::types::WellDebrief>,
 }
 
 /// A proactive damping recommendation based on a proven recipe from a prior run
@@ -191,16 +271,36 @@ impl Default for AppState {
             tickets_created: 0,
             tickets_verified: 0,
             tickets_rejected: 0,
+            sensor_glitches: 0,
             peak_severity: crate::types::TicketSeverity::Low,
             avg_mse_efficiency: None,
             latest_formation_transition: None,
+            mode_transition: None,
+            learning_stall: None,
+            active_wellbore: None,
+            pending_sidetrack: None,
+            pending_choke_adjustment: None,
+            detection_benchmark: None,
             formation_transition_timestamps: Vec::new(),
             regime_centroids: [[0.0; 8]; 4],
+            regime_stability: 0.0,
             damping_monitor_snapshot: None,
             connection_gas_tracker: crate::physics_engine::connection_gas::ConnectionGasTracker::new(),
+            connection_log: std::collections::VecDeque::new(),
+            chart_log: super::chart_log::ChartLog::new(),
+            rig_state_log: crate::types::RigStateLog::default(),
+            cfc_health: None,
+            directional: None,
+            kb_snapshot_status: None,
+            mesh_evictions: None,
+            config_sanity: crate::config::sanity::ConfigSanityChecker::new(),
             bit_wear_tracker: crate::optimization::bit_wear::BitWearTracker::new(),
+            candidate_tops: crate::cfc::candidate_tops::CandidateTopTracker::new(),
+            pipeline_metrics: super::ingest_metrics::PipelineMetricsSnapshot::default(),
+            stage_latency: std::sync::Arc::new(super::stage_latency::StageLatency::new()),
             latest_swab_surge: None,
             proactive_damping: None,
+            well_debrief: None,
         }
     }
 }
@@ -279,6 +379,8 @@ pub enum SystemStatus {
     Alert,
     /// System error or degraded operation
     Error,
+    /// Replay reached the end of its recorded data
+    ReplayComplete,
 }
 
 impl std::fmt::Display for SystemStatus {
@@ -289,6 +391,7 @@ impl std::fmt::Display for SystemStatus {
             SystemStatus::Monitoring => write!(f, "Monitoring"),
             SystemStatus::Alert => write!(f, "Alert"),
             SystemStatus::Error => write!(f, "Error"),
+            SystemStatus::ReplayComplete => write!(f, "Replay Complete"),
         }
     }
 }
@@ -313,5 +416,6 @@ mod tests {
         assert_eq!(format!("{}", SystemStatus::Monitoring), "Monitoring");
         assert_eq!(format!("{}", SystemStatus::Alert), "Alert");
         assert_eq!(format!("{}", SystemStatus::Error), "Error");
+        assert_eq!(format!("{}", SystemStatus::ReplayComplete), "Replay Complete");
     }
 }
@@ -0,0 +1,212 @@
+//! Wellbore Branch Tracking (Sidetracks / Multi-laterals)
+//!
+//! On a sidetrack the bit is pulled back up the hole and new hole is drilled
+//! from a kickoff point, so `bit_depth` is no longer monotonic over the life
+//! of the well. This tracker keeps the name of the active wellbore branch so
+//! formation lookup and depth-indexed storage don't conflate the original
+//! interval with the sidetrack interval at the same measured depth.
+//!
+//! ## Detection
+//!
+//! - **Operator marker**: `POST /api/v2/sidetrack` starts a branch immediately
+//! - **Auto-detect**: `auto_detect_packets` Drilling packets more than
+//!   `redrill_margin_ft` above the deepest drilled depth of the active branch,
+//!   with the bit making new hole over the run. Drilling back down near the
+//!   drilled depth (i.e. deepening the existing hole) resets the count.
+
+use crate::config::SidetrackConfig;
+use crate::types::{RigState, SidetrackEvent, SidetrackMarker, SidetrackSource, WitsPacket};
+
+/// Candidate sidetrack run: drilling above the drilled depth
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    start_depth_ft: f64,
+    packets: usize,
+}
+
+/// Tracks the active wellbore branch
+#[derive(Debug, Clone, Default)]
+pub struct WellboreTracker {
+    /// Deepest depth drilled on the active branch (ft)
+    drilled_depth_ft: f64,
+    candidate: Option<Candidate>,
+    /// Branches started so far, oldest first (last = active)
+    branches: Vec<SidetrackEvent>,
+}
+
+impl WellboreTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name of the active branch (`None` while on the original hole).
+    pub fn active_branch(&self) -> Option<&str> {
+        self.branches.last().map(|b| b.branch.as_str())
+    }
+
+    /// Event that started the active branch.
+    pub fn active(&self) -> Option<&SidetrackEvent> {
+        self.branches.last()
+    }
+
+    /// Update with a new packet.
+    ///
+    /// Returns a `SidetrackEvent` when a sidetrack is auto-detected.
+    pub fn update(
+        &mut self,
+        packet: &WitsPacket,
+        rig_state: RigState,
+        cfg: &SidetrackConfig,
+    ) -> Option<SidetrackEvent> {
+        let depth = packet.bit_depth;
+        if rig_state != RigState::Drilling || depth <= 0.0 {
+            return None;
+        }
+
+        if depth > self.drilled_depth_ft - cfg.redrill_margin_ft {
+            // Deepening the active hole
+            self.drilled_depth_ft = self.drilled_depth_ft.max(depth);
+            self.candidate = None;
+            return None;
+        }
+
+        if !cfg.auto_detect {
+            return None;
+        }
+
+        let candidate = self.candidate.get_or_insert(Candidate {
+            start_depth_ft: depth,
+            packets: 0,
+        });
+        candidate.packets += 1;
+        if candidate.packets < cfg.auto_detect_packets || depth <= candidate.start_depth_ft {
+            return None;
+        }
+
+        let kickoff = candidate.start_depth_ft;
+        Some(self.start_branch(
+            None,
+            kickoff,
+            depth,
+            packet.timestamp,
+            SidetrackSource::AutoDetected,
+        ))
+    }
+
+    /// Start a branch from an operator marker.
+    ///
+    /// Kickoff defaults to the current bit depth.
+    pub fn mark(&mut self, marker: SidetrackMarker, packet: &WitsPacket) -> SidetrackEvent {
+        let kickoff = marker.kickoff_depth_ft.unwrap_or(packet.bit_depth);
+        self.start_branch(
+            marker.name,
+            kickoff,
+            packet.bit_depth.max(kickoff),
+            packet.timestamp,
+            SidetrackSource::Operator,
+        )
+    }
+
+    fn start_branch(
+        &mut self,
+        name: Option<String>,
+        kickoff_depth_ft: f64,
+        drilled_depth_ft: f64,
+        timestamp: u64,
+        source: SidetrackSource,
+    ) -> SidetrackEvent {
+        let branch = name
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| format!("ST{}", self.branches.len() + 1));
+        let event = SidetrackEvent {
+            branch,
+            parent: self.active_branch().map(str::to_string),
+            kickoff_depth_ft,
+            timestamp,
+            source,
+        };
+        self.branches.push(event.clone());
+        self.drilled_depth_ft = drilled_depth_ft;
+        self.candidate = None;
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drilling(ts: u64, depth: f64) -> WitsPacket {
+        WitsPacket {
+            timestamp: ts,
+            bit_depth: depth,
+            ..WitsPacket::default()
+        }
+    }
+
+    fn cfg() -> SidetrackConfig {
+        SidetrackConfig {
+            auto_detect: true,
+            redrill_margin_ft: 100.0,
+            auto_detect_packets: 10,
+        }
+    }
+
+    #[test]
+    fn test_sustained_drilling_above_td_starts_branch() {
+        let cfg = cfg();
+        let mut tracker = WellboreTracker::new();
+        for i in 0..20 {
+            assert!(tracker
+                .update(&drilling(i, 10_000.0 + i as f64), RigState::Drilling, &cfg)
+                .is_none());
+        }
+
+        // Tripping back up the hole is not drilling
+        assert!(tracker
+            .update(&drilling(100, 8_000.0), RigState::TrippingOut, &cfg)
+            .is_none());
+
+        let mut event = None;
+        for i in 0..10 {
+            event = tracker.update(
+                &drilling(200 + i, 8_000.0 + i as f64),
+                RigState::Drilling,
+                &cfg,
+            );
+        }
+        let event = event.expect("sidetrack detected");
+        assert_eq!(event.branch, "ST1");
+        assert_eq!(event.parent, None);
+        assert_eq!(event.source, SidetrackSource::AutoDetected);
+        assert!((event.kickoff_depth_ft - 8_000.0).abs() < 1e-9);
+        assert_eq!(tracker.active_branch(), Some("ST1"));
+
+        // Further drilling on the branch does not re-trigger
+        for i in 10..40 {
+            assert!(tracker
+                .update(
+                    &drilling(200 + i, 8_000.0 + i as f64),
+                    RigState::Drilling,
+                    &cfg
+                )
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_operator_marker_names_branch() {
+        let mut tracker = WellboreTracker::new();
+        let marker = SidetrackMarker {
+            name: Some("Lateral-A".to_string()),
+            kickoff_depth_ft: Some(7_500.0),
+        };
+        let event = tracker.mark(marker, &drilling(1, 7_600.0));
+        assert_eq!(event.branch, "Lateral-A");
+        assert_eq!(event.source, SidetrackSource::Operator);
+
+        let second = tracker.mark(SidetrackMarker::default(), &drilling(2, 7_700.0));
+        assert_eq!(second.branch, "ST2");
+        assert_eq!(second.parent.as_deref(), Some("Lateral-A"));
+    }
+}
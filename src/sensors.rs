@@ -1,7 +1,8 @@
 //! Sensor data ingestion from CSV files (Legacy TDS + WITS support)
 
-use crate::types::{RigState, WitsPacket};
+use crate::types::{AnomalyCategory, RigState, WitsPacket};
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
@@ -57,7 +58,7 @@ pub fn read_csv_data(path: &str) -> Vec<WitsPacket> {
 }
 
 /// Parse a single CSV line into a WitsPacket
-fn parse_csv_line(line: &str, line_num: usize) -> Result<WitsPacket, String> {
+pub(crate) fn parse_csv_line(line: &str, line_num: usize) -> Result<WitsPacket, String> {
     let fields: Vec<&str> = line.split(',').collect();
 
     if fields.len() < 17 {
@@ -151,6 +152,7 @@ fn parse_csv_line(line: &str, line_num: usize) -> Result<WitsPacket, String> {
         co2,
         casing_pressure,
         annular_pressure: 0.0,
+        choke_position_pct: 0.0,
         pore_pressure: 0.0,
         fracture_gradient: 0.0,
         mse: 0.0,        // Calculated later
@@ -207,11 +209,54 @@ fn parse_f64(s: &str, field: &str) -> Result<f64, String> {
         .map_err(|_| format!("Cannot parse {} as f64: '{}'", field, s))
 }
 
+/// Fault injected into the synthetic drilling data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultKind {
+    /// Poor drilling efficiency (bit wear / wrong parameters)
+    MseInefficiency,
+    /// Influx: flow-out gain, pit gain and rising gas
+    Kick,
+}
+
+impl FaultKind {
+    /// Advisory category that counts as detecting this fault
+    pub fn expected_category(&self) -> AnomalyCategory {
+        match self {
+            Self::MseInefficiency => AnomalyCategory::DrillingEfficiency,
+            Self::Kick => AnomalyCategory::WellControl,
+        }
+    }
+}
+
+/// Ground truth for one injected fault
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InjectedFault {
+    pub kind: FaultKind,
+    /// Timestamp of the first faulted packet
+    pub onset_timestamp: u64,
+    /// Timestamp of the last faulted packet
+    pub end_timestamp: u64,
+}
+
+/// Synthetic packets plus the faults injected into them
+#[derive(Debug, Clone)]
+pub struct FaultScenario {
+    pub packets: Vec<WitsPacket>,
+    pub faults: Vec<InjectedFault>,
+}
+
 /// Generate synthetic drilling test data
 ///
 /// Creates drilling scenarios with normal operation, MSE inefficiency,
 /// well control events, and formation changes to test the tactical agent.
 pub fn generate_fault_test_data() -> Vec<WitsPacket> {
+    generate_fault_scenario().packets
+}
+
+/// Generate synthetic drilling test data with the onset and end of each
+/// injected fault, for measuring detection latency.
+pub fn generate_fault_scenario() -> FaultScenario {
     let mut packets = Vec::new();
     let base_timestamp = 1705564800u64;
 
@@ -245,6 +290,7 @@ pub fn generate_fault_test_data() -> Vec<WitsPacket> {
             co2: 0.1,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.0,
             mse: 35000.0,
@@ -289,6 +335,7 @@ pub fn generate_fault_test_data() -> Vec<WitsPacket> {
             co2: 0.1,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.0,
             mse: 55000.0,
@@ -334,6 +381,7 @@ pub fn generate_fault_test_data() -> Vec<WitsPacket> {
             co2: 0.2,
             casing_pressure: 50.0 + i as f64 * 10.0,
             annular_pressure: 30.0 + i as f64 * 5.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.8,
             fracture_gradient: 14.0,
             mse: 38000.0,
@@ -378,6 +426,7 @@ pub fn generate_fault_test_data() -> Vec<WitsPacket> {
             co2: 0.1,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 10.5,
             fracture_gradient: 14.5,
             mse: 36000.0,
@@ -392,11 +441,25 @@ pub fn generate_fault_test_data() -> Vec<WitsPacket> {
         });
     }
 
+    let faults = vec![
+        InjectedFault {
+            kind: FaultKind::MseInefficiency,
+            onset_timestamp: base_timestamp + 40 * 60,
+            end_timestamp: base_timestamp + 59 * 60,
+        },
+        InjectedFault {
+            kind: FaultKind::Kick,
+            onset_timestamp: base_timestamp + 60 * 60,
+            end_timestamp: base_timestamp + 74 * 60,
+        },
+    ];
+
     tracing::debug!(
         count = packets.len(),
+        faults = faults.len(),
         "Generated synthetic drilling test packets"
     );
-    packets
+    FaultScenario { packets, faults }
 }
 
 #[cfg(test)]
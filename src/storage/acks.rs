@@ -3,11 +3,13 @@
 //! Stores acknowledgment records in a named sled tree ("acknowledgments")
 //! within the global history DB.  The storage layer is type-agnostic —
 //! records are serialized to JSON at the call site and stored as raw bytes
-//! keyed by `acknowledged_at` timestamp (big-endian u64, so they sort
-//! chronologically).
+//! keyed by `acknowledged_at` then `ticket_timestamp` (big-endian u64s, so
+//! they sort chronologically and a bulk acknowledgment made within one
+//! second keeps every record).
 //!
-//! Call `persist()` from the API handler and `load_all_raw()` at startup
-//! to restore the in-memory acknowledgment list.
+//! Call `persist()` / `persist_batch()` from the API handlers and
+//! `load_all_raw()` at startup to restore the in-memory acknowledgment list;
+//! `remove()` drops records once they expire.
 
 use super::history::get_db;
 use super::history::StorageError;
@@ -39,16 +41,44 @@ fn get_tree() -> Result<&'static Tree, StorageError> {
     ACKS_TREE.get().ok_or(StorageError::NotInitialized)
 }
 
+/// Tree key: `acknowledged_at` then `ticket_timestamp`, both big-endian.
+fn key(acknowledged_at: u64, ticket_timestamp: u64) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    key[..8].copy_from_slice(&acknowledged_at.to_be_bytes());
+    key[8..].copy_from_slice(&ticket_timestamp.to_be_bytes());
+    key
+}
+
+fn encode<T: Serialize>(record: &T) -> Result<Vec<u8>, StorageError> {
+    serde_json::to_vec(record).map_err(|e| StorageError::SerializationError(e.to_string()))
+}
+
 /// Persist an acknowledgment record.
 ///
-/// `key` should be the record's timestamp (seconds) so records are ordered
-/// chronologically.  If two records share the same second, the later one
-/// overwrites the earlier — acceptable for an audit trail.
-pub fn persist<T: Serialize>(key: u64, record: &T) -> Result<(), StorageError> {
+/// Re-acknowledging the same ticket within the same second overwrites the
+/// earlier record — acceptable for an audit trail.
+pub fn persist<T: Serialize>(
+    acknowledged_at: u64,
+    ticket_timestamp: u64,
+    record: &T,
+) -> Result<(), StorageError> {
     let tree = get_tree()?;
-    let bytes =
-        serde_json::to_vec(record).map_err(|e| StorageError::SerializationError(e.to_string()))?;
-    tree.insert(key.to_be_bytes(), bytes)?;
+    tree.insert(key(acknowledged_at, ticket_timestamp), encode(record)?)?;
+    Ok(())
+}
+
+/// Persist several records atomically: either all are written or none.
+///
+/// Each entry is `(acknowledged_at, ticket_timestamp, record)`.
+pub fn persist_batch<'a, T: Serialize + 'a>(
+    entries: impl IntoIterator<Item = (u64, u64, &'a T)>,
+) -> Result<(), StorageError> {
+    let tree = get_tree()?;
+    let mut batch = sled::Batch::default();
+    for (acknowledged_at, ticket_timestamp, record) in entries {
+        batch.insert(&key(acknowledged_at, ticket_timestamp)[..], encode(record)?);
+    }
+    tree.apply_batch(batch)?;
     Ok(())
 }
 
@@ -67,3 +97,14 @@ pub fn load_all_raw() -> Vec<Vec<u8>> {
         .filter_map(|item| item.ok().map(|(_, v)| v.to_vec()))
         .collect()
 }
+
+/// Remove the acknowledgment of `ticket_timestamp` made at `acknowledged_at`.
+///
+/// Also removes a record stored under the older `acknowledged_at`-only key.
+/// Removing a key that is not present is not an error.
+pub fn remove(acknowledged_at: u64, ticket_timestamp: u64) -> Result<(), StorageError> {
+    let tree = get_tree()?;
+    tree.remove(key(acknowledged_at, ticket_timestamp))?;
+    tree.remove(acknowledged_at.to_be_bytes())?;
+    Ok(())
+}
@@ -0,0 +1,451 @@
+//! Bit-run archive and dull-grade correlation
+//!
+//! Stores one record per bit run in a named sled tree ("bit_runs") within the
+//! global history DB, keyed by the operator's run id. After the bit is pulled
+//! the engineer enters the IADC dull grade (and any failure mode such as a
+//! washout); the run's **Mechanical** advisories are then cross-referenced
+//! with the reported outcome: confirmed when the dull shows damage, false
+//! positives when the bit came out clean. Other categories are left to the
+//! operator — a clean bit says nothing about, say, a hydraulics advisory
+//! raised for a surface leak. Whether the dull is hydraulic (washed out bit,
+//! lost or plugged nozzle) is still recorded on the run.
+//!
+//! Each cross-referenced advisory produces a `FeedbackRecord` tagged
+//! `FeedbackSource::DullGrade`, so the outcome feeds the same per-category
+//! confirmation stats and threshold suggestions as operator feedback while
+//! staying distinguishable from it. Advisories already rated by an operator
+//! are left alone.
+//!
+//! The bit run in progress (hours and run-start baseline, see
+//! `physics_engine::bit_run`) is kept in a second tree ("bit_run_active") so
+//! a restart mid-run doesn't reset the bit.
+//!
+//! Call `init()` after `storage::history::init()`.
+
+use super::feedback::{FeedbackOutcome, FeedbackRecord, FeedbackSource};
+use super::history::{get_db, StorageError};
+use crate::config::BitRunConfig;
+use crate::physics_engine::bit_run::BitRunBaseline;
+use crate::types::{AnomalyCategory, StrategicAdvisory};
+use serde::{Deserialize, Serialize};
+use sled::Tree;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+static BIT_RUNS_TREE: OnceLock<Tree> = OnceLock::new();
+static ACTIVE_RUN_TREE: OnceLock<Tree> = OnceLock::new();
+
+/// Key of the bit run in progress within "bit_run_active".
+const ACTIVE_RUN_KEY: &[u8] = b"current";
+
+/// `submitted_by` on feedback derived from a dull grade.
+pub const DULL_GRADE_SOURCE: &str = "dull_grade";
+
+/// IADC dull characteristics that do not indicate a downhole problem.
+const BENIGN_DULL_CHARS: &[&str] = &["NO", "WT", "SS"];
+
+/// IADC dull characteristics caused by hydraulics (washed out bit,
+/// lost nozzle, plugged nozzle).
+const HYDRAULIC_DULL_CHARS: &[&str] = &["WO", "LN", "PN"];
+
+/// IADC dull grade as reported after the bit is pulled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DullGrade {
+    /// Inner cutting structure wear (0-8)
+    pub inner: u8,
+    /// Outer cutting structure wear (0-8)
+    pub outer: u8,
+    /// Major dull characteristic (IADC code, e.g. "BT", "WT", "NO")
+    pub dull_char: String,
+    /// Location on the bit (e.g. "N", "S", "A")
+    #[serde(default)]
+    pub location: String,
+    /// Bearings / seals ("X" for fixed cutter)
+    #[serde(default)]
+    pub bearings: String,
+    /// Gauge ("I" = in gauge, otherwise 1/16ths under)
+    #[serde(default)]
+    pub gauge: String,
+    /// Other dull characteristic (IADC code)
+    #[serde(default)]
+    pub other_char: String,
+    /// Reason pulled (IADC code, e.g. "TD", "PR", "HP")
+    #[serde(default)]
+    pub reason_pulled: String,
+    /// Free-text failure mode (e.g. "washout", "cone lost")
+    #[serde(default)]
+    pub failure_mode: Option<String>,
+}
+
+impl DullGrade {
+    fn code(value: &str) -> String {
+        value.trim().to_uppercase()
+    }
+
+    fn has_char(&self, codes: &[&str]) -> bool {
+        [&self.dull_char, &self.other_char]
+            .iter()
+            .any(|c| codes.contains(&Self::code(c).as_str()))
+    }
+
+    fn failure_mode(&self) -> Option<&str> {
+        self.failure_mode
+            .as_deref()
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+    }
+
+    /// Whether the dull points to a hydraulic problem.
+    pub fn is_hydraulic(&self) -> bool {
+        self.has_char(HYDRAULIC_DULL_CHARS)
+            || self
+                .failure_mode()
+                .is_some_and(|m| m.to_lowercase().contains("washout"))
+    }
+
+    /// Whether the dull shows a downhole problem worth having been warned about.
+    pub fn indicates_problem(&self, cfg: &BitRunConfig) -> bool {
+        let dull_char = Self::code(&self.dull_char);
+        let gauge = Self::code(&self.gauge);
+        self.inner.max(self.outer) >= cfg.wear_problem_threshold
+            || (!dull_char.is_empty() && !BENIGN_DULL_CHARS.contains(&dull_char.as_str()))
+            || (!gauge.is_empty() && gauge != "I")
+            || self.failure_mode().is_some()
+    }
+
+    /// Compact IADC string, e.g. "2-4-BT-S-X-1-WT-PR".
+    pub fn iadc(&self) -> String {
+        let field = |v: &str| {
+            let v = Self::code(v);
+            if v.is_empty() {
+                "-".to_string()
+            } else {
+                v
+            }
+        };
+        format!(
+            "{}-{}-{}-{}-{}-{}-{}-{}",
+            self.inner,
+            self.outer,
+            field(&self.dull_char),
+            field(&self.location),
+            field(&self.bearings),
+            field(&self.gauge),
+            field(&self.other_char),
+            field(&self.reason_pulled)
+        )
+    }
+}
+
+/// Post-run dull grade as submitted through the API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DullGradeSubmission {
+    /// Unix timestamp the bit went in the hole
+    pub start_ts: u64,
+    /// Unix timestamp the bit was pulled
+    pub end_ts: u64,
+    #[serde(default)]
+    pub depth_in_ft: Option<f64>,
+    #[serde(default)]
+    pub depth_out_ft: Option<f64>,
+    pub dull: DullGrade,
+    #[serde(default)]
+    pub submitted_by: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// How a run's advisories aligned with its dull grade.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DullCorrelation {
+    /// Whether the dull grade indicates a downhole problem
+    pub problem_found: bool,
+    /// Whether the dull grade indicates a hydraulic problem
+    pub hydraulic_problem: bool,
+    /// Advisories issued during the run
+    pub advisories_in_run: usize,
+    /// Mechanical advisories issued during the run
+    pub mechanical_advisories: usize,
+    /// Advisories turned into dull-grade feedback
+    pub correlated: usize,
+    pub confirmed: usize,
+    pub false_positives: usize,
+    /// Advisories skipped because an operator already rated them
+    pub already_rated: usize,
+}
+
+/// An archived bit run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitRunRecord {
+    pub id: String,
+    pub start_ts: u64,
+    pub end_ts: u64,
+    #[serde(default)]
+    pub depth_in_ft: Option<f64>,
+    #[serde(default)]
+    pub depth_out_ft: Option<f64>,
+    pub dull: DullGrade,
+    pub submitted_by: String,
+    pub submitted_at: u64,
+    #[serde(default)]
+    pub notes: String,
+    pub correlation: DullCorrelation,
+}
+
+/// Cross-reference a run's advisories with its dull grade.
+///
+/// Returns the correlation summary and the feedback records to persist.
+/// Advisories whose timestamp is in `already_rated` are skipped.
+pub fn correlate(
+    run_id: &str,
+    well_id: &str,
+    dull: &DullGrade,
+    advisories: &[StrategicAdvisory],
+    already_rated: &HashSet<u64>,
+    cfg: &BitRunConfig,
+    submitted_at: u64,
+) -> (DullCorrelation, Vec<FeedbackRecord>) {
+    let problem_found = dull.indicates_problem(cfg);
+    let hydraulic_problem = dull.is_hydraulic();
+    let mut correlation = DullCorrelation {
+        problem_found,
+        hydraulic_problem,
+        advisories_in_run: advisories.len(),
+        mechanical_advisories: advisories
+            .iter()
+            .filter(|a| a.category == AnomalyCategory::Mechanical)
+            .count(),
+        ..DullCorrelation::default()
+    };
+    if !cfg.correlate_advisories {
+        return (correlation, Vec::new());
+    }
+
+    let mut records = Vec::new();
+    for advisory in advisories {
+        if advisory.category != AnomalyCategory::Mechanical {
+            continue;
+        }
+        let confirmed = problem_found;
+        if already_rated.contains(&advisory.timestamp) {
+            correlation.already_rated += 1;
+            continue;
+        }
+
+        let outcome = if confirmed {
+            correlation.confirmed += 1;
+            FeedbackOutcome::Confirmed
+        } else {
+            correlation.false_positives += 1;
+            FeedbackOutcome::FalsePositive
+        };
+        records.push(FeedbackRecord {
+            advisory_timestamp: advisory.timestamp,
+            well_id: well_id.to_string(),
+            outcome,
+            category: advisory.category,
+            trigger_parameter: advisory.trigger_parameter.clone(),
+            trigger_value: advisory.trigger_value,
+            threshold_value: advisory.threshold_value,
+            submitted_by: DULL_GRADE_SOURCE.to_string(),
+            source: FeedbackSource::DullGrade,
+            submitted_at,
+            notes: format!("Bit run {}: dull {}", run_id, dull.iadc()),
+            actual_cause: dull
+                .failure_mode()
+                .map(str::to_string)
+                .or_else(|| confirmed.then(|| format!("dull {}", dull.iadc()))),
+            action_taken: None,
+        });
+    }
+    correlation.correlated = records.len();
+    (correlation, records)
+}
+
+/// Initialise the bit-run sled trees.
+///
+/// Must be called after `storage::history::init()`.
+pub fn init() -> Result<(), StorageError> {
+    if BIT_RUNS_TREE.get().is_some() {
+        return Ok(());
+    }
+    let db = get_db()?;
+    let active = db
+        .open_tree("bit_run_active")
+        .map_err(|e: sled::Error| StorageError::DatabaseError(e.to_string()))?;
+    let _ = ACTIVE_RUN_TREE.set(active);
+    let tree = db
+        .open_tree("bit_runs")
+        .map_err(|e: sled::Error| StorageError::DatabaseError(e.to_string()))?;
+    let _ = BIT_RUNS_TREE.set(tree);
+    Ok(())
+}
+
+fn get_tree() -> Result<&'static Tree, StorageError> {
+    BIT_RUNS_TREE.get().ok_or(StorageError::NotInitialized)
+}
+
+/// Save the bit run in progress (overwrites the previous save).
+pub fn save_active_run(run: &BitRunBaseline) -> Result<(), StorageError> {
+    let tree = ACTIVE_RUN_TREE.get().ok_or(StorageError::NotInitialized)?;
+    let bytes =
+        serde_json::to_vec(run).map_err(|e| StorageError::SerializationError(e.to_string()))?;
+    tree.insert(ACTIVE_RUN_KEY, bytes)?;
+    Ok(())
+}
+
+/// Load the bit run saved before a restart, if any.
+pub fn load_active_run() -> Option<BitRunBaseline> {
+    let bytes = ACTIVE_RUN_TREE.get()?.get(ACTIVE_RUN_KEY).ok()??;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// Persist a bit-run record keyed by run id (last write wins).
+pub fn persist(record: &BitRunRecord) -> Result<(), StorageError> {
+    let tree = get_tree()?;
+    let bytes =
+        serde_json::to_vec(record).map_err(|e| StorageError::SerializationError(e.to_string()))?;
+    tree.insert(record.id.as_bytes(), bytes)?;
+    Ok(())
+}
+
+/// Load all archived bit runs, ordered by start time.
+pub fn load_all() -> Vec<BitRunRecord> {
+    let tree = match get_tree() {
+        Ok(t) => t,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut runs: Vec<BitRunRecord> = tree
+        .iter()
+        .filter_map(|item| item.ok().and_then(|(_, v)| serde_json::from_slice(&v).ok()))
+        .collect();
+    runs.sort_by_key(|r| r.start_ts);
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::suggestions::compute_stats;
+
+    fn advisory(ts: u64, category: AnomalyCategory) -> StrategicAdvisory {
+        StrategicAdvisory {
+            timestamp: ts,
+            category,
+            trigger_parameter: "torque_cv".to_string(),
+            trigger_value: 0.2,
+            threshold_value: 0.12,
+            ..StrategicAdvisory::default()
+        }
+    }
+
+    fn dull(inner: u8, outer: u8, dull_char: &str) -> DullGrade {
+        DullGrade {
+            inner,
+            outer,
+            dull_char: dull_char.to_string(),
+            location: "S".to_string(),
+            bearings: "X".to_string(),
+            gauge: "I".to_string(),
+            other_char: String::new(),
+            reason_pulled: "PR".to_string(),
+            failure_mode: None,
+        }
+    }
+
+    #[test]
+    fn test_dull_grade_cross_references_mechanical_advisories_into_stats() {
+        let cfg = BitRunConfig::default();
+        let advisories = vec![
+            advisory(1000, AnomalyCategory::Mechanical),
+            advisory(1600, AnomalyCategory::Mechanical),
+            advisory(2000, AnomalyCategory::Hydraulics),
+            advisory(2500, AnomalyCategory::WellControl),
+            advisory(3000, AnomalyCategory::Mechanical),
+        ];
+        // Operator already rated one mechanical advisory
+        let rated: HashSet<u64> = [3000].into_iter().collect();
+
+        // Broken cutters: the mechanical warnings were right
+        let (corr, records) = correlate(
+            "RUN-3",
+            "Well-A",
+            &dull(2, 5, "BT"),
+            &advisories,
+            &rated,
+            &cfg,
+            9000,
+        );
+        assert!(corr.problem_found);
+        assert!(!corr.hydraulic_problem);
+        assert_eq!(corr.mechanical_advisories, 3);
+        assert_eq!(corr.already_rated, 1);
+        assert_eq!(corr.confirmed, 2);
+        // The hydraulics and well-control advisories are left to the operator
+        assert_eq!(corr.false_positives, 0);
+        assert_eq!(corr.correlated, 2);
+        assert!(records.iter().all(|r| r.is_automatic()));
+
+        let stats = compute_stats(&records);
+        let mech = stats
+            .iter()
+            .find(|s| s.category == AnomalyCategory::Mechanical)
+            .expect("mechanical stats");
+        assert_eq!(mech.confirmed, 2);
+        assert_eq!(mech.confirmation_rate, 1.0);
+        assert!(stats
+            .iter()
+            .all(|s| s.category == AnomalyCategory::Mechanical));
+
+        // A clean bit turns the same mechanical warnings into false positives
+        let (clean, records) = correlate(
+            "RUN-4",
+            "Well-A",
+            &dull(1, 1, "WT"),
+            &advisories,
+            &HashSet::new(),
+            &cfg,
+            9000,
+        );
+        assert!(!clean.problem_found);
+        assert_eq!(clean.false_positives, 3);
+        let stats = compute_stats(&records);
+        let mech = stats
+            .iter()
+            .find(|s| s.category == AnomalyCategory::Mechanical)
+            .unwrap();
+        assert_eq!(mech.false_positives, 3);
+        assert_eq!(mech.confirmation_rate, 0.0);
+    }
+
+    #[test]
+    fn test_washout_confirms_mechanical_only() {
+        let cfg = BitRunConfig::default();
+        let mut grade = dull(1, 1, "WT");
+        grade.failure_mode = Some("Washout in BHA".to_string());
+        assert!(grade.is_hydraulic());
+        assert!(grade.indicates_problem(&cfg));
+        assert_eq!(grade.iadc(), "1-1-WT-S-X-I---PR");
+
+        let advisories = vec![
+            advisory(1000, AnomalyCategory::Hydraulics),
+            advisory(1500, AnomalyCategory::Mechanical),
+        ];
+        let (corr, records) = correlate(
+            "RUN-5",
+            "Well-A",
+            &grade,
+            &advisories,
+            &HashSet::new(),
+            &cfg,
+            1,
+        );
+        assert!(corr.hydraulic_problem);
+        assert_eq!(corr.confirmed, 1);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].category, AnomalyCategory::Mechanical);
+        assert_eq!(records[0].source, FeedbackSource::DullGrade);
+        assert_eq!(records[0].actual_cause.as_deref(), Some("Washout in BHA"));
+    }
+}
@@ -0,0 +1,386 @@
+//! Well Export Bundle
+//!
+//! Everything SAIREN learned about one well — config, baselines, strategic
+//! reports, advisory history, operator feedback and the debrief — as a
+//! single versioned JSON document that can be archived or handed to another
+//! rig's team:
+//!
+//! ```text
+//! {"payload":{"format":"sairen-well-export","version":1,...},
+//!  "sha256":"<hex>","hmac_sha256":"<hex>"|null}
+//! ```
+//!
+//! `sha256` is computed over the exact bytes of `payload`, so any edit to the
+//! bundle is detected. When a fleet passphrase is configured
+//! (`FLEET_PASSPHRASE`), `hmac_sha256` is an HMAC-SHA256 of the same bytes
+//! keyed by it, proving the bundle came from a rig of the same fleet.
+//!
+//! The writer is synchronous and streams each section straight to its
+//! output; advisory history is read from sled one entry at a time so memory
+//! stays bounded by the largest single record. `verify_bundle` is the
+//! matching import-side check, served by `POST /api/v2/export/verify`.
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use sha2::{Digest, Sha256};
+use std::io::{self, Write};
+use thiserror::Error;
+
+use crate::baseline::ThresholdManager;
+use crate::config::WellConfig;
+use crate::storage::feedback::FeedbackRecord;
+use crate::strategic::{DailyReport, HourlyReport};
+use crate::types::{StrategicAdvisory, WellDebrief};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// `format` marker of an export bundle
+pub const BUNDLE_FORMAT: &str = "sairen-well-export";
+
+/// Bundle layout version; bumped on incompatible changes
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Everything exported except advisory history, which is streamed.
+#[derive(Debug, Clone)]
+pub struct ExportSources {
+    pub exported_at: u64,
+    pub well_id: String,
+    pub field: String,
+    pub config: WellConfig,
+    /// `None` when baseline learning has not started
+    pub baselines: Option<ThresholdManager>,
+    pub hourly_reports: Vec<HourlyReport>,
+    pub daily_reports: Vec<DailyReport>,
+    pub feedback: Vec<FeedbackRecord>,
+    pub debrief: Option<WellDebrief>,
+}
+
+/// Digests written at the end of a bundle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleDigest {
+    pub sha256: String,
+    pub hmac_sha256: Option<String>,
+    /// Advisories written to the bundle
+    pub advisories: usize,
+}
+
+/// Strategic reports section
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportedReports {
+    pub hourly: Vec<HourlyReport>,
+    pub daily: Vec<DailyReport>,
+}
+
+/// A verified bundle payload
+#[derive(Debug, Clone, Deserialize)]
+pub struct WellExport {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: u64,
+    pub well_id: String,
+    pub field: String,
+    pub config: WellConfig,
+    pub baselines: Option<ThresholdManager>,
+    pub strategic_reports: ExportedReports,
+    pub advisories: Vec<StrategicAdvisory>,
+    pub feedback: Vec<FeedbackRecord>,
+    pub debrief: Option<WellDebrief>,
+}
+
+/// Why a bundle was rejected on import
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error("malformed export bundle: {0}")]
+    Malformed(#[from] serde_json::Error),
+
+    #[error("unsupported export bundle {format} v{version}")]
+    Unsupported { format: String, version: u32 },
+
+    #[error("content hash mismatch: bundle was modified")]
+    HashMismatch,
+
+    #[error("bundle is not signed but a fleet passphrase was given")]
+    Unsigned,
+
+    #[error("signature mismatch: wrong passphrase or bundle was modified")]
+    SignatureMismatch,
+}
+
+/// Bundle as read for verification; `payload` keeps its exact bytes.
+#[derive(Deserialize)]
+struct RawBundle<'a> {
+    #[serde(borrow)]
+    payload: &'a RawValue,
+    sha256: String,
+    #[serde(default)]
+    hmac_sha256: Option<String>,
+}
+
+/// Passes bytes through while hashing (and optionally MACing) them.
+struct SigningWriter<W> {
+    inner: W,
+    digest: Sha256,
+    mac: Option<HmacSha256>,
+}
+
+impl<W: Write> SigningWriter<W> {
+    fn new(inner: W, key: Option<&[u8]>) -> Self {
+        Self {
+            inner,
+            digest: Sha256::new(),
+            // HMAC accepts keys of any length
+            mac: key.map(|k| HmacSha256::new_from_slice(k).expect("any key length")),
+        }
+    }
+
+    fn finish(self) -> (W, String, Option<String>) {
+        let sha256 = format!("{:x}", self.digest.finalize());
+        let hmac = self.mac.map(|m| format!("{:x}", m.finalize().into_bytes()));
+        (self.inner, sha256, hmac)
+    }
+}
+
+impl<W: Write> Write for SigningWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.digest.update(&buf[..n]);
+        if let Some(mac) = self.mac.as_mut() {
+            mac.update(&buf[..n]);
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Write `"name":value`.
+fn entry<W: Write, T: Serialize + ?Sized>(w: &mut W, name: &str, value: &T) -> io::Result<()> {
+    serde_json::to_writer(&mut *w, name)?;
+    w.write_all(b":")?;
+    serde_json::to_writer(&mut *w, value)?;
+    Ok(())
+}
+
+/// Write `"name":[...]` one item at a time; returns the item count.
+fn array<W: Write, T: Serialize>(
+    w: &mut W,
+    name: &str,
+    items: impl IntoIterator<Item = T>,
+) -> io::Result<usize> {
+    serde_json::to_writer(&mut *w, name)?;
+    w.write_all(b":[")?;
+    let mut count = 0;
+    for item in items {
+        if count > 0 {
+            w.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut *w, &item)?;
+        count += 1;
+    }
+    w.write_all(b"]")?;
+    Ok(count)
+}
+
+fn write_payload<W: Write>(
+    w: &mut W,
+    sources: &ExportSources,
+    advisories: impl IntoIterator<Item = StrategicAdvisory>,
+) -> io::Result<usize> {
+    w.write_all(b"{")?;
+    entry(w, "format", BUNDLE_FORMAT)?;
+    w.write_all(b",")?;
+    entry(w, "version", &BUNDLE_VERSION)?;
+    w.write_all(b",")?;
+    entry(w, "exported_at", &sources.exported_at)?;
+    w.write_all(b",")?;
+    entry(w, "well_id", &sources.well_id)?;
+    w.write_all(b",")?;
+    entry(w, "field", &sources.field)?;
+    w.write_all(b",")?;
+    entry(w, "config", &sources.config)?;
+    w.write_all(b",")?;
+    entry(w, "baselines", &sources.baselines)?;
+    w.write_all(b",\"strategic_reports\":{")?;
+    array(w, "hourly", &sources.hourly_reports)?;
+    w.write_all(b",")?;
+    array(w, "daily", &sources.daily_reports)?;
+    w.write_all(b"},")?;
+    let advisories = array(w, "advisories", advisories)?;
+    w.write_all(b",")?;
+    array(w, "feedback", &sources.feedback)?;
+    w.write_all(b",")?;
+    entry(w, "debrief", &sources.debrief)?;
+    w.write_all(b"}")?;
+    Ok(advisories)
+}
+
+/// Stream a complete bundle to `out`, signing it when `key` is given.
+pub fn write_bundle<W: Write>(
+    mut out: W,
+    sources: &ExportSources,
+    advisories: impl IntoIterator<Item = StrategicAdvisory>,
+    key: Option<&[u8]>,
+) -> io::Result<BundleDigest> {
+    out.write_all(b"{\"payload\":")?;
+    let mut signer = SigningWriter::new(out, key);
+    let count = write_payload(&mut signer, sources, advisories)?;
+    let (mut out, sha256, hmac_sha256) = signer.finish();
+
+    out.write_all(b",")?;
+    entry(&mut out, "sha256", &sha256)?;
+    out.write_all(b",")?;
+    entry(&mut out, "hmac_sha256", &hmac_sha256)?;
+    out.write_all(b"}")?;
+    out.flush()?;
+
+    Ok(BundleDigest {
+        sha256,
+        hmac_sha256,
+        advisories: count,
+    })
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Check a bundle's content hash (and signature, when `key` is given) and
+/// decode its payload.
+pub fn verify_bundle(bundle: &[u8], key: Option<&[u8]>) -> Result<WellExport, ExportError> {
+    let raw: RawBundle = serde_json::from_slice(bundle)?;
+    let payload = raw.payload.get().as_bytes();
+
+    if format!("{:x}", Sha256::digest(payload)) != raw.sha256 {
+        return Err(ExportError::HashMismatch);
+    }
+    if let Some(key) = key {
+        let signature = raw.hmac_sha256.as_deref().ok_or(ExportError::Unsigned)?;
+        let signature = from_hex(signature).ok_or(ExportError::SignatureMismatch)?;
+        let mut mac = HmacSha256::new_from_slice(key).expect("any key length");
+        mac.update(payload);
+        mac.verify_slice(&signature)
+            .map_err(|_| ExportError::SignatureMismatch)?;
+    }
+
+    let export: WellExport = serde_json::from_slice(payload)?;
+    if export.format != BUNDLE_FORMAT || export.version != BUNDLE_VERSION {
+        return Err(ExportError::Unsupported {
+            format: export.format,
+            version: export.version,
+        });
+    }
+    Ok(export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::feedback::FeedbackOutcome;
+    use crate::types::AnomalyCategory;
+
+    fn populated() -> (ExportSources, Vec<StrategicAdvisory>) {
+        let mut baselines = ThresholdManager::new();
+        baselines.start_learning("mud", "flow_out", 1_700_000_000);
+
+        let advisories: Vec<StrategicAdvisory> = (0..3)
+            .map(|i| StrategicAdvisory {
+                timestamp: 1_700_000_000 + i * 60,
+                recommendation: format!("advisory {i}"),
+                ..StrategicAdvisory::default()
+            })
+            .collect();
+
+        let feedback = vec![FeedbackRecord {
+            advisory_timestamp: 1_700_000_060,
+            well_id: String::new(),
+            outcome: FeedbackOutcome::Confirmed,
+            category: AnomalyCategory::WellControl,
+            trigger_parameter: "flow_balance".to_string(),
+            trigger_value: 12.0,
+            threshold_value: 10.0,
+            submitted_by: "driller".to_string(),
+            source: crate::storage::feedback::FeedbackSource::Operator,
+            submitted_at: 1_700_000_100,
+            notes: String::new(),
+            actual_cause: None,
+            action_taken: None,
+        }];
+
+        let sources = ExportSources {
+            exported_at: 1_700_003_600,
+            well_id: "Well-A".to_string(),
+            field: "North".to_string(),
+            config: WellConfig::default(),
+            baselines: Some(baselines),
+            hourly_reports: vec![HourlyReport {
+                health_score: 82.0,
+                severity: "Low".to_string(),
+                diagnosis: "Steady drilling".to_string(),
+                action: "Continue".to_string(),
+                raw: String::new(),
+            }],
+            daily_reports: Vec::new(),
+            feedback,
+            debrief: None,
+        };
+        (sources, advisories)
+    }
+
+    #[test]
+    fn test_signed_export_round_trips_and_detects_tampering() {
+        let (sources, advisories) = populated();
+        let key = b"fleet-passphrase".as_slice();
+
+        let mut bundle = Vec::new();
+        let digest = write_bundle(&mut bundle, &sources, advisories, Some(key)).unwrap();
+        assert_eq!(digest.advisories, 3);
+        assert!(digest.hmac_sha256.is_some());
+
+        let export = verify_bundle(&bundle, Some(key)).unwrap();
+        assert_eq!(export.exported_at, 1_700_003_600);
+        assert_eq!(export.well_id, "Well-A");
+        assert_eq!(export.field, "North");
+        assert_eq!(export.config.well.name, sources.config.well.name);
+        assert!(export.strategic_reports.daily.is_empty());
+        assert!(export.debrief.is_none());
+        assert_eq!(export.advisories.len(), 3);
+        assert_eq!(export.advisories[1].recommendation, "advisory 1");
+        assert_eq!(export.feedback[0].outcome, FeedbackOutcome::Confirmed);
+        assert_eq!(export.strategic_reports.hourly[0].health_score, 82.0);
+        assert!(export.baselines.is_some());
+
+        // Hash alone still verifies without the passphrase
+        assert!(verify_bundle(&bundle, None).is_ok());
+        assert!(matches!(
+            verify_bundle(&bundle, Some(b"other-fleet")),
+            Err(ExportError::SignatureMismatch)
+        ));
+
+        // Any edit to the payload breaks the content hash
+        let text = String::from_utf8(bundle).unwrap();
+        let tampered = text.replacen("advisory 1", "advisory 9", 1);
+        assert!(matches!(
+            verify_bundle(tampered.as_bytes(), Some(key)),
+            Err(ExportError::HashMismatch)
+        ));
+
+        // An unsigned bundle is rejected when a signature is expected
+        let (sources, advisories) = populated();
+        let mut unsigned = Vec::new();
+        write_bundle(&mut unsigned, &sources, advisories, None).unwrap();
+        assert!(verify_bundle(&unsigned, None).is_ok());
+        assert!(matches!(
+            verify_bundle(&unsigned, Some(key)),
+            Err(ExportError::Unsigned)
+        ));
+    }
+}
@@ -5,16 +5,24 @@
 //! positive) to a specific advisory by timestamp. Category and trigger fields
 //! are denormalized from the advisory for fast statistical queries.
 //!
+//! Each category also has a tailored feedback prompt telling the operator
+//! what to assess, with structured follow-up fields (actual cause, action
+//! taken) captured alongside the bare outcome.
+//!
 //! Call `init()` after `storage::history::init()`.
 
 use super::history::{get_db, StorageError};
-use crate::types::AnomalyCategory;
+use crate::types::{AnomalyCategory, StrategicAdvisory};
 use serde::{Deserialize, Serialize};
 use sled::Tree;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::OnceLock;
 
 static FEEDBACK_TREE: OnceLock<Tree> = OnceLock::new();
 
+/// Bumped on every persisted record so readers can cache derived statistics.
+static FEEDBACK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 /// Operator assessment of an advisory.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -24,11 +32,26 @@ pub enum FeedbackOutcome {
     Unclear,
 }
 
+/// Where a feedback record came from.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackSource {
+    /// Rated by an operator through the API
+    #[default]
+    Operator,
+    /// Inferred from a bit-run dull grade (`storage::bit_runs`)
+    DullGrade,
+}
+
 /// A single feedback record linking an operator assessment to an advisory.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeedbackRecord {
     /// Timestamp of the advisory being rated (also used as key).
     pub advisory_timestamp: u64,
+    /// Well the advisory was raised on (empty for records stored before
+    /// feedback was tagged with its well).
+    #[serde(default)]
+    pub well_id: String,
     /// Operator's assessment.
     pub outcome: FeedbackOutcome,
     /// Anomaly category (denormalized from advisory).
@@ -41,11 +64,147 @@ pub struct FeedbackRecord {
     pub threshold_value: f64,
     /// Who submitted the feedback (e.g. "driller", "toolpusher").
     pub submitted_by: String,
+    /// Operator rating or automatic inference.
+    #[serde(default)]
+    pub source: FeedbackSource,
     /// Unix timestamp when feedback was submitted.
     pub submitted_at: u64,
     /// Optional free-text notes from the operator.
     #[serde(default)]
     pub notes: String,
+    /// Operator's assessment of the true cause (structured follow-up).
+    #[serde(default)]
+    pub actual_cause: Option<String>,
+    /// Action the crew took in response (structured follow-up).
+    #[serde(default)]
+    pub action_taken: Option<String>,
+}
+
+impl FeedbackRecord {
+    /// Build a record from an operator submission, denormalizing the
+    /// advisory's category and trigger fields.
+    pub fn from_submission(
+        advisory: &StrategicAdvisory,
+        submission: FeedbackSubmission,
+        well_id: &str,
+        submitted_at: u64,
+    ) -> Self {
+        Self {
+            advisory_timestamp: advisory.timestamp,
+            well_id: well_id.to_string(),
+            outcome: submission.outcome,
+            category: advisory.category,
+            trigger_parameter: advisory.trigger_parameter.clone(),
+            trigger_value: advisory.trigger_value,
+            threshold_value: advisory.threshold_value,
+            submitted_by: if submission.submitted_by.is_empty() {
+                "anonymous".to_string()
+            } else {
+                submission.submitted_by
+            },
+            source: FeedbackSource::Operator,
+            submitted_at,
+            notes: submission.notes,
+            actual_cause: non_empty(submission.actual_cause),
+            action_taken: non_empty(submission.action_taken),
+        }
+    }
+
+    /// Whether the record was inferred automatically rather than rated by an
+    /// operator.
+    pub fn is_automatic(&self) -> bool {
+        self.source != FeedbackSource::Operator
+    }
+}
+
+/// Operator feedback as submitted through the API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeedbackSubmission {
+    pub outcome: FeedbackOutcome,
+    #[serde(default)]
+    pub submitted_by: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub actual_cause: Option<String>,
+    #[serde(default)]
+    pub action_taken: Option<String>,
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Category-specific guidance shown to the operator when rating an advisory.
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedbackPrompt {
+    pub category: AnomalyCategory,
+    /// What the operator should assess before rating.
+    pub question: String,
+    /// Suggested answers for the actual-cause field.
+    pub cause_options: Vec<&'static str>,
+    /// Structured follow-up fields accepted with the submission.
+    pub follow_up_fields: Vec<&'static str>,
+}
+
+/// Build the feedback prompt for an advisory category.
+///
+/// Questions can be overridden per category in `[feedback]`; returns `None`
+/// when structured prompts are disabled or the category has no advisories.
+pub fn feedback_prompt(category: AnomalyCategory) -> Option<FeedbackPrompt> {
+    let cfg = crate::config::is_initialized().then(crate::config::get);
+    let feedback_cfg = cfg.as_ref().map(|c| &c.feedback);
+    if feedback_cfg.is_some_and(|f| !f.structured_prompts) {
+        return None;
+    }
+
+    let (default_question, cause_options, override_question) = match category {
+        AnomalyCategory::WellControl => (
+            "Was there an actual influx or loss? What was the true cause?",
+            vec!["influx", "losses", "pump or surface transient", "sensor error"],
+            feedback_cfg.map(|f| f.well_control_prompt.as_str()),
+        ),
+        AnomalyCategory::DrillingEfficiency => (
+            "Was drilling efficiency actually degraded? What limited it?",
+            vec!["bit wear", "formation change", "dysfunction", "parameter choice"],
+            feedback_cfg.map(|f| f.drilling_efficiency_prompt.as_str()),
+        ),
+        AnomalyCategory::Hydraulics => (
+            "Was there a real pressure or ECD problem? What caused it?",
+            vec!["pack-off", "washout", "plugged nozzle", "mud properties", "sensor error"],
+            feedback_cfg.map(|f| f.hydraulics_prompt.as_str()),
+        ),
+        AnomalyCategory::Mechanical => (
+            "Was there real downhole dysfunction? What was the source?",
+            vec!["stick-slip", "whirl", "tight hole", "bit balling", "sensor error"],
+            feedback_cfg.map(|f| f.mechanical_prompt.as_str()),
+        ),
+        AnomalyCategory::Formation => (
+            "Was this a real formation change or pressure transition?",
+            vec!["formation top", "pore pressure change", "lithology stringer", "none"],
+            feedback_cfg.map(|f| f.formation_prompt.as_str()),
+        ),
+        AnomalyCategory::Equipment => (
+            "Was there a real surface equipment problem? What failed?",
+            vec!["washpipe", "rotating head seal", "pump or line leak", "sensor error"],
+            None,
+        ),
+        AnomalyCategory::None => return None,
+    };
+
+    let question = override_question
+        .filter(|q| !q.trim().is_empty())
+        .unwrap_or(default_question)
+        .to_string();
+
+    Some(FeedbackPrompt {
+        category,
+        question,
+        cause_options,
+        follow_up_fields: vec!["actual_cause", "action_taken"],
+    })
 }
 
 /// Initialise the feedback sled tree.
@@ -75,9 +234,15 @@ pub fn persist(record: &FeedbackRecord) -> Result<(), StorageError> {
     let bytes =
         serde_json::to_vec(record).map_err(|e| StorageError::SerializationError(e.to_string()))?;
     tree.insert(record.advisory_timestamp.to_be_bytes(), bytes)?;
+    FEEDBACK_GENERATION.fetch_add(1, Ordering::Relaxed);
     Ok(())
 }
 
+/// Changes whenever a feedback record is persisted.
+pub fn generation() -> u64 {
+    FEEDBACK_GENERATION.load(Ordering::Relaxed)
+}
+
 /// Load all feedback records (oldest first).
 pub fn load_all() -> Vec<FeedbackRecord> {
     let tree = match get_tree() {
@@ -105,14 +270,18 @@ mod tests {
     fn make_record(ts: u64, outcome: FeedbackOutcome, category: AnomalyCategory) -> FeedbackRecord {
         FeedbackRecord {
             advisory_timestamp: ts,
+            well_id: String::new(),
             outcome,
             category,
             trigger_parameter: "test_param".to_string(),
             trigger_value: 42.0,
             threshold_value: 50.0,
             submitted_by: "driller".to_string(),
+            source: FeedbackSource::Operator,
             submitted_at: ts + 100,
             notes: String::new(),
+            actual_cause: None,
+            action_taken: None,
         }
     }
 
@@ -128,4 +297,45 @@ mod tests {
         assert_eq!(decoded.advisory_timestamp, 1000);
         assert_eq!(decoded.outcome, FeedbackOutcome::Confirmed);
     }
+
+    #[test]
+    fn test_structured_kick_feedback_flows_into_summary() {
+        let advisory = StrategicAdvisory {
+            timestamp: 5000,
+            category: AnomalyCategory::WellControl,
+            trigger_parameter: "flow_balance".to_string(),
+            trigger_value: 18.0,
+            threshold_value: 10.0,
+            ..StrategicAdvisory::default()
+        };
+
+        let prompt = feedback_prompt(advisory.category).expect("well control prompt");
+        assert!(prompt.question.contains("influx"));
+        assert!(prompt.follow_up_fields.contains(&"actual_cause"));
+
+        let submission: FeedbackSubmission = serde_json::from_value(serde_json::json!({
+            "outcome": "confirmed",
+            "submitted_by": "driller",
+            "actual_cause": " Gas influx after swabbing ",
+            "action_taken": "shut in, circulated out"
+        }))
+        .unwrap();
+        let record = FeedbackRecord::from_submission(&advisory, submission, "Well-A", 5100);
+
+        // Stored form keeps the structured fields
+        let json = serde_json::to_vec(&record).unwrap();
+        let stored: FeedbackRecord = serde_json::from_slice(&json).unwrap();
+        assert_eq!(stored.actual_cause.as_deref(), Some("Gas influx after swabbing"));
+        assert_eq!(stored.action_taken.as_deref(), Some("shut in, circulated out"));
+
+        let stats = crate::storage::suggestions::compute_stats(&[stored]);
+        let wc = stats
+            .iter()
+            .find(|s| s.category == AnomalyCategory::WellControl)
+            .expect("well control stats");
+        assert_eq!(wc.confirmed, 1);
+        assert_eq!(wc.actual_causes.len(), 1);
+        assert_eq!(wc.actual_causes[0].cause, "gas influx after swabbing");
+        assert_eq!(wc.actual_causes[0].count, 1);
+    }
 }
@@ -10,6 +10,19 @@ use std::sync::{Arc, OnceLock};
 /// Global database instance for the history storage
 static HISTORY_DB: OnceLock<Arc<sled::Db>> = OnceLock::new();
 
+/// Maximum reports a depth query examines, newest first.
+///
+/// Depth is not monotonic in time (reaming, tripping, sidetracks), so a depth
+/// window cannot be served by a key range: every report in the scan is
+/// deserialized and its depth checked. The cap bounds that cost on long wells.
+pub const MAX_DEPTH_SCAN_REPORTS: usize = 50_000;
+
+/// Maximum reports a depth query returns.
+pub const MAX_DEPTH_RESULTS: usize = 1_000;
+
+/// Maximum advisories a recommendation search returns.
+pub const MAX_SEARCH_RESULTS: usize = 500;
+
 /// Error type for storage operations
 #[derive(Debug)]
 pub enum StorageError {
@@ -117,6 +130,20 @@ impl HistoryStorage {
         reports
     }
 
+    /// Get the newest `limit` reports whose bit depth lies within
+    /// `[min_ft, max_ft]` (oldest first, capped at `MAX_DEPTH_RESULTS`)
+    ///
+    /// Full scan bounded by `MAX_DEPTH_SCAN_REPORTS`; see that constant.
+    pub fn query_by_depth(&self, min_ft: f64, max_ft: f64, limit: usize) -> Vec<StrategicReport> {
+        scan_depth_range(&self.db, min_ft, max_ft, limit)
+    }
+
+    /// Find reports whose recommendation contains `query`, case-insensitively
+    /// (newest first, at most `limit`, capped at `MAX_SEARCH_RESULTS`)
+    pub fn search_recommendations(&self, query: &str, limit: usize) -> Vec<StrategicReport> {
+        scan_recommendations(&self.db, query, limit)
+    }
+
     /// Get total number of stored reports
     pub fn count(&self) -> usize {
         self.db.len()
@@ -285,16 +312,86 @@ pub fn get_by_timestamp(timestamp: u64) -> Result<Option<StrategicReport>, Stora
 
 /// Get all reports from the global database (oldest first).
 pub fn get_all_reports() -> Vec<StrategicReport> {
-    let db = match get_db() {
-        Ok(db) => db,
-        Err(_) => return Vec::new(),
-    };
+    iter_reports().collect()
+}
+
+/// Lazily iterate reports in the global database (oldest first), decoding
+/// one at a time. Entries that fail to read or decode are skipped.
+pub fn iter_reports() -> impl Iterator<Item = StrategicReport> {
+    get_db().ok().into_iter().flat_map(|db| {
+        db.iter().filter_map(|item| {
+            item.ok()
+                .and_then(|(_, v)| serde_json::from_slice::<StrategicReport>(&v).ok())
+        })
+    })
+}
+
+/// Get the newest `limit` reports whose bit depth lies within
+/// `[min_ft, max_ft]` (oldest first, capped at `MAX_DEPTH_RESULTS`).
+///
+/// Filters on `physics_report.current_depth`. Only the newest
+/// `MAX_DEPTH_SCAN_REPORTS` reports are examined, each one deserialized, so
+/// the cost is linear in history size rather than in the result size; the
+/// scan stops early once `limit` matches are found. Blocking — call from
+/// `spawn_blocking` in async code.
+pub fn query_by_depth(
+    min_ft: f64,
+    max_ft: f64,
+    limit: usize,
+) -> Result<Vec<StrategicReport>, StorageError> {
+    let db = get_db()?;
+    Ok(scan_depth_range(db, min_ft, max_ft, limit))
+}
+
+/// Scan the newest reports for a depth window, returning them oldest first
+fn scan_depth_range(db: &sled::Db, min_ft: f64, max_ft: f64, limit: usize) -> Vec<StrategicReport> {
+    let mut reports: Vec<StrategicReport> = db
+        .iter()
+        .rev()
+        .take(MAX_DEPTH_SCAN_REPORTS)
+        .filter_map(|item| {
+            item.ok()
+                .and_then(|(_, v)| serde_json::from_slice::<StrategicReport>(&v).ok())
+        })
+        .filter(|report| {
+            let depth = report.physics_report.current_depth;
+            depth >= min_ft && depth <= max_ft
+        })
+        .take(limit.min(MAX_DEPTH_RESULTS))
+        .collect();
+    reports.reverse();
+    reports
+}
+
+/// Find advisories whose recommendation contains `query`, ignoring case.
+///
+/// Returns newest first, at most `limit` (capped at `MAX_SEARCH_RESULTS`).
+/// This is a linear scan, not an index: every report is deserialized until
+/// enough matches are found, so a rare term costs a pass over the whole
+/// history. Fine for an operator query; not for a per-packet path.
+pub fn search_recommendations(
+    query: &str,
+    limit: usize,
+) -> Result<Vec<StrategicReport>, StorageError> {
+    let db = get_db()?;
+    Ok(scan_recommendations(db, query, limit))
+}
+
+/// Case-insensitive substring scan over recommendations, newest first
+fn scan_recommendations(db: &sled::Db, query: &str, limit: usize) -> Vec<StrategicReport> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
 
     db.iter()
+        .rev()
         .filter_map(|item| {
             item.ok()
                 .and_then(|(_, v)| serde_json::from_slice::<StrategicReport>(&v).ok())
         })
+        .filter(|report| report.recommendation.to_lowercase().contains(&needle))
+        .take(limit.min(MAX_SEARCH_RESULTS))
         .collect()
 }
 
@@ -347,6 +444,10 @@ mod tests {
             trigger_parameter: String::new(),
             trigger_value: 0.0,
             threshold_value: 0.0,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
         }
     }
 
@@ -461,6 +562,77 @@ mod tests {
         assert_eq!(range.len(), 5); // 200, 300, 400, 500, 600
     }
 
+    #[test]
+    fn test_depth_range_query() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("test.db");
+        let storage = HistoryStorage::open(&path).unwrap();
+
+        // Drill down, then ream back up through the window
+        let depths = [3000.0, 3200.0, 3450.0, 3600.0, 3800.0, 3500.0, 3100.0];
+        for (i, depth) in depths.iter().enumerate() {
+            let mut report = create_test_report(i as u64 * 100, 50.0);
+            report.physics_report.current_depth = *depth;
+            storage.store_report(&report).unwrap();
+        }
+
+        let window = storage.query_by_depth(3200.0, 3600.0, 100);
+        let found: Vec<(u64, f64)> = window
+            .iter()
+            .map(|r| (r.timestamp, r.physics_report.current_depth))
+            .collect();
+        assert_eq!(
+            found,
+            vec![(100, 3200.0), (200, 3450.0), (300, 3600.0), (500, 3500.0)]
+        );
+
+        assert!(storage.query_by_depth(4000.0, 5000.0, 100).is_empty());
+
+        // A limit keeps the newest matches
+        let newest: Vec<u64> = storage
+            .query_by_depth(3200.0, 3600.0, 2)
+            .iter()
+            .map(|r| r.timestamp)
+            .collect();
+        assert_eq!(newest, vec![300, 500]);
+    }
+
+    #[test]
+    fn test_search_recommendations() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("test.db");
+        let storage = HistoryStorage::open(&path).unwrap();
+
+        let texts = [
+            "Pack-off suspected: reduce flow and work pipe",
+            "Optimize WOB for formation change",
+            "Possible PACK-OFF building, monitor torque and SPP",
+            "Kick indicators: flow check",
+            "Hole cleaning poor, risk of pack-off on connection",
+        ];
+        for (i, text) in texts.iter().enumerate() {
+            let mut report = create_test_report(i as u64 * 100, 50.0);
+            report.recommendation = text.to_string();
+            storage.store_report(&report).unwrap();
+        }
+
+        // Case-insensitive, newest first
+        let hits = storage.search_recommendations("pack-off", 10);
+        let ts: Vec<u64> = hits.iter().map(|r| r.timestamp).collect();
+        assert_eq!(ts, vec![400, 200, 0]);
+
+        // Limit keeps the newest matches
+        let ts: Vec<u64> = storage
+            .search_recommendations("Pack-Off", 2)
+            .iter()
+            .map(|r| r.timestamp)
+            .collect();
+        assert_eq!(ts, vec![400, 200]);
+
+        assert!(storage.search_recommendations("stick-slip", 10).is_empty());
+        assert!(storage.search_recommendations("  ", 10).is_empty());
+    }
+
     #[test]
     fn test_stats() {
         let temp_dir = tempfile::tempdir().unwrap();
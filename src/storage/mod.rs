@@ -3,10 +3,14 @@
 //! This module provides persistent storage for strategic reports and process locking.
 
 pub mod acks;
+pub mod bit_runs;
 pub mod damping_recipes;
+pub mod export;
 pub mod feedback;
 pub mod history;
 pub mod lockfile;
+pub mod reset;
+pub mod rig_state_log;
 mod strategic;
 pub mod suggestions;
 
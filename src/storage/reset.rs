@@ -0,0 +1,181 @@
+//! Scoped reset of the data directory (`--reset-db[=SCOPE]`)
+//!
+//! A full reset also throws away learned baselines, which can take hours of
+//! drilling to relearn. Narrower scopes clear transient data only:
+//!
+//! | Scope            | Removes                                            |
+//! |------------------|----------------------------------------------------|
+//! | `all`            | the whole data directory                           |
+//! | `reports`        | advisory history (incl. feedback, bit runs, acks, rig state log) and strategic reports |
+//! | `ml`             | ML insights                                        |
+//! | `keep-baselines` | everything except baseline state                   |
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Advisory history (shared by feedback, bit runs, acks, damping recipes)
+/// and the strategic report store.
+const REPORT_STORES: &[&str] = &["strategic_history.db", "strategic_reports.db"];
+
+/// ML insights store.
+const ML_STORES: &[&str] = &["ml_insights.db"];
+
+/// Learned state kept by `keep-baselines`.
+pub const PRESERVED_BY_KEEP_BASELINES: &[&str] = &["baseline_state.json"];
+
+/// What `--reset-db` removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ResetScope {
+    /// Wipe the whole data directory
+    All,
+    /// Advisory history, feedback and strategic reports
+    Reports,
+    /// ML insights
+    Ml,
+    /// Everything except learned baselines
+    KeepBaselines,
+}
+
+impl ResetScope {
+    /// Parse the `RESET_DB` environment value: a scope name, or a truthy
+    /// value (`true` / `1` / `yes`) meaning `all`. `None` for anything else.
+    pub fn from_env_value(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "true" | "1" | "yes" | "all" => Some(Self::All),
+            "reports" => Some(Self::Reports),
+            "ml" => Some(Self::Ml),
+            "keep-baselines" | "keep_baselines" => Some(Self::KeepBaselines),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Reports => "reports",
+            Self::Ml => "ml",
+            Self::KeepBaselines => "keep-baselines",
+        }
+    }
+}
+
+/// Entries a reset would remove and keep
+#[derive(Debug, Default)]
+pub struct ResetPlan {
+    pub remove: Vec<PathBuf>,
+    pub keep: Vec<PathBuf>,
+}
+
+/// Work out which entries of `data_dir` a reset with `scope` removes.
+pub fn plan_reset(data_dir: &Path, scope: ResetScope) -> io::Result<ResetPlan> {
+    let mut plan = ResetPlan::default();
+    if !data_dir.exists() {
+        return Ok(plan);
+    }
+
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(data_dir)?
+        .flatten()
+        .map(|e| e.path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        let remove = match scope {
+            ResetScope::All => true,
+            ResetScope::Reports => REPORT_STORES.contains(&name),
+            ResetScope::Ml => ML_STORES.contains(&name),
+            ResetScope::KeepBaselines => !PRESERVED_BY_KEEP_BASELINES.contains(&name),
+        };
+        if remove {
+            plan.remove.push(path);
+        } else {
+            plan.keep.push(path);
+        }
+    }
+    Ok(plan)
+}
+
+/// Remove everything in `plan.remove`.
+///
+/// `all` removes `data_dir` itself so the next start creates it fresh.
+pub fn apply_reset(data_dir: &Path, scope: ResetScope, plan: &ResetPlan) -> io::Result<()> {
+    if scope == ResetScope::All {
+        if data_dir.exists() {
+            std::fs::remove_dir_all(data_dir)?;
+        }
+        return Ok(());
+    }
+    for path in &plan.remove {
+        if path.is_dir() {
+            std::fs::remove_dir_all(path)?;
+        } else if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_baselines_removes_reports_and_keeps_baseline_state() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let data = tmp.path();
+        for db in [
+            "strategic_history.db",
+            "strategic_reports.db",
+            "ml_insights.db",
+        ] {
+            std::fs::create_dir_all(data.join(db)).expect("db dir");
+            std::fs::write(data.join(db).join("db"), b"sled").expect("db file");
+        }
+        std::fs::write(data.join("baseline_state.json"), b"{\"version\":3}").expect("write");
+        std::fs::write(data.join("auto_detected.json"), b"{}").expect("write");
+
+        let plan = plan_reset(data, ResetScope::KeepBaselines).expect("plan");
+        apply_reset(data, ResetScope::KeepBaselines, &plan).expect("reset");
+
+        assert!(!data.join("strategic_history.db").exists());
+        assert!(!data.join("strategic_reports.db").exists());
+        assert!(!data.join("ml_insights.db").exists());
+        assert!(!data.join("auto_detected.json").exists());
+        assert_eq!(
+            std::fs::read(data.join("baseline_state.json")).expect("baseline kept"),
+            b"{\"version\":3}"
+        );
+    }
+
+    #[test]
+    fn test_reports_scope_leaves_ml_and_baselines() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let data = tmp.path();
+        for name in ["strategic_history.db", "ml_insights.db"] {
+            std::fs::create_dir_all(data.join(name)).expect("db dir");
+        }
+        std::fs::write(data.join("baseline_state.json"), b"{}").expect("write");
+
+        let plan = plan_reset(data, ResetScope::Reports).expect("plan");
+        assert_eq!(plan.remove, vec![data.join("strategic_history.db")]);
+        apply_reset(data, ResetScope::Reports, &plan).expect("reset");
+
+        assert!(!data.join("strategic_history.db").exists());
+        assert!(data.join("ml_insights.db").exists());
+        assert!(data.join("baseline_state.json").exists());
+    }
+
+    #[test]
+    fn test_env_value_parsing() {
+        assert_eq!(ResetScope::from_env_value("TRUE"), Some(ResetScope::All));
+        assert_eq!(
+            ResetScope::from_env_value("keep-baselines"),
+            Some(ResetScope::KeepBaselines)
+        );
+        assert_eq!(ResetScope::from_env_value("false"), None);
+    }
+}
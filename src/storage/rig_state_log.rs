@@ -0,0 +1,68 @@
+//! Rig state log persistence
+//!
+//! Stores each rig state transition in a named sled tree ("rig_state_log")
+//! within the global history DB so the debrief's NPT accounting still covers
+//! the whole well after a restart. Keys are sled-generated ids (big-endian),
+//! so records sort in the order they were recorded.
+//!
+//! Call `append()` when `RigStateLog::record` returns a transition and
+//! `load()` at startup; `load()` also prunes records beyond
+//! `MAX_RIG_STATE_CHANGES`.
+
+use super::history::{get_db, StorageError};
+use crate::types::{RigStateChange, RigStateLog, MAX_RIG_STATE_CHANGES};
+use sled::Tree;
+use std::sync::OnceLock;
+
+static RIG_STATE_TREE: OnceLock<Tree> = OnceLock::new();
+
+/// Initialise the rig state log sled tree.
+///
+/// Must be called after `storage::history::init()` so the global DB is ready.
+pub fn init() -> Result<(), StorageError> {
+    if RIG_STATE_TREE.get().is_some() {
+        return Ok(());
+    }
+    let db = get_db()?;
+    let tree = db
+        .open_tree("rig_state_log")
+        .map_err(|e: sled::Error| StorageError::DatabaseError(e.to_string()))?;
+    let _ = RIG_STATE_TREE.set(tree);
+    Ok(())
+}
+
+fn get_tree() -> Result<&'static Tree, StorageError> {
+    RIG_STATE_TREE.get().ok_or(StorageError::NotInitialized)
+}
+
+/// Persist one rig state transition.
+pub fn append(change: &RigStateChange) -> Result<(), StorageError> {
+    let tree = get_tree()?;
+    let id = get_db()?.generate_id()?;
+    let bytes =
+        serde_json::to_vec(change).map_err(|e| StorageError::SerializationError(e.to_string()))?;
+    tree.insert(id.to_be_bytes(), bytes)?;
+    Ok(())
+}
+
+/// Load the persisted log, dropping records beyond the newest
+/// `MAX_RIG_STATE_CHANGES`. Empty when storage is unavailable.
+pub fn load() -> RigStateLog {
+    let tree = match get_tree() {
+        Ok(t) => t,
+        Err(_) => return RigStateLog::default(),
+    };
+
+    let records: Vec<(sled::IVec, RigStateChange)> = tree
+        .iter()
+        .filter_map(|item| {
+            let (k, v) = item.ok()?;
+            Some((k, serde_json::from_slice(&v).ok()?))
+        })
+        .collect();
+    let stale = records.len().saturating_sub(MAX_RIG_STATE_CHANGES);
+    for (key, _) in &records[..stale] {
+        let _ = tree.remove(key);
+    }
+    RigStateLog::from_changes(records.into_iter().skip(stale).map(|(_, c)| c).collect())
+}
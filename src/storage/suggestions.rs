@@ -3,6 +3,11 @@
 //! Pure computation module — no sled storage. Analyses operator feedback
 //! records to compute per-category confirmation rates and recommend threshold
 //! adjustments when false positive rates are high.
+//!
+//! False positives are also broken down by the specific trigger parameter
+//! (`compute_false_positive_sources`), ranking the thresholds that cause the
+//! most false alarms and suggesting a value from the trigger readings the
+//! operators rejected.
 
 use super::feedback::{FeedbackOutcome, FeedbackRecord};
 use crate::config::WellConfig;
@@ -19,6 +24,15 @@ pub struct CategoryStats {
     pub unclear: usize,
     /// confirmed / (confirmed + false_positives), NaN-safe.
     pub confirmation_rate: f64,
+    /// Operator-reported actual causes, most frequent first.
+    pub actual_causes: Vec<CauseCount>,
+}
+
+/// How often operators reported a given actual cause.
+#[derive(Debug, Clone, Serialize)]
+pub struct CauseCount {
+    pub cause: String,
+    pub count: usize,
 }
 
 /// A suggested threshold adjustment based on feedback data.
@@ -39,6 +53,7 @@ pub fn compute_stats(records: &[FeedbackRecord]) -> Vec<CategoryStats> {
     use std::collections::HashMap;
 
     let mut buckets: HashMap<AnomalyCategory, (usize, usize, usize)> = HashMap::new();
+    let mut causes: HashMap<AnomalyCategory, HashMap<String, usize>> = HashMap::new();
 
     for rec in records {
         let entry = buckets.entry(rec.category).or_insert((0, 0, 0));
@@ -47,6 +62,13 @@ pub fn compute_stats(records: &[FeedbackRecord]) -> Vec<CategoryStats> {
             FeedbackOutcome::FalsePositive => entry.1 += 1,
             FeedbackOutcome::Unclear => entry.2 += 1,
         }
+        if let Some(cause) = &rec.actual_cause {
+            *causes
+                .entry(rec.category)
+                .or_default()
+                .entry(cause.trim().to_lowercase())
+                .or_insert(0) += 1;
+        }
     }
 
     let mut stats: Vec<CategoryStats> = buckets
@@ -60,6 +82,13 @@ pub fn compute_stats(records: &[FeedbackRecord]) -> Vec<CategoryStats> {
             } else {
                 0.0
             };
+            let mut actual_causes: Vec<CauseCount> = causes
+                .remove(&category)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(cause, count)| CauseCount { cause, count })
+                .collect();
+            actual_causes.sort_by(|a, b| b.count.cmp(&a.count).then(a.cause.cmp(&b.cause)));
             CategoryStats {
                 category,
                 total,
@@ -67,6 +96,7 @@ pub fn compute_stats(records: &[FeedbackRecord]) -> Vec<CategoryStats> {
                 false_positives,
                 unclear,
                 confirmation_rate,
+                actual_causes,
             }
         })
         .collect();
@@ -105,7 +135,7 @@ fn category_threshold_mapping(cat: AnomalyCategory) -> Option<(&'static str, boo
             // This is a negative threshold; treat as "lower = more sensitive"
             Some(("thresholds.formation.dexp_decrease_warning", false))
         }
-        AnomalyCategory::None => None,
+        AnomalyCategory::Equipment | AnomalyCategory::None => None,
     }
 }
 
@@ -121,7 +151,7 @@ fn current_threshold_value(config: &WellConfig, cat: AnomalyCategory) -> Option<
         }
         AnomalyCategory::Mechanical => Some(config.thresholds.mechanical.stick_slip_cv_warning),
         AnomalyCategory::Formation => Some(config.thresholds.formation.dexp_decrease_warning),
-        AnomalyCategory::None => None,
+        AnomalyCategory::Equipment | AnomalyCategory::None => None,
     }
 }
 
@@ -220,6 +250,207 @@ pub fn compute_suggestions(
     suggestions
 }
 
+/// False-positive statistics for one trigger parameter.
+#[derive(Debug, Clone, Serialize)]
+pub struct FalsePositiveSource {
+    pub trigger_parameter: String,
+    pub category: AnomalyCategory,
+    pub confirmed: usize,
+    pub false_positives: usize,
+    /// false_positives / (confirmed + false_positives).
+    pub false_positive_rate: f64,
+    /// Median trigger reading on advisories rated false positive.
+    pub median_false_positive_value: f64,
+    /// Suggested adjustment (present when the FP rate is high enough).
+    pub suggestion: Option<ThresholdSuggestion>,
+}
+
+/// Map a trigger parameter to its warning threshold key, its current value,
+/// and whether "higher value = more sensitive" (see
+/// `category_threshold_mapping`).
+fn trigger_threshold(config: &WellConfig, trigger: &str) -> Option<(&'static str, f64, bool)> {
+    let t = &config.thresholds;
+    match trigger {
+        "mse_efficiency" => Some((
+            "thresholds.mse.efficiency_warning_percent",
+            t.mse.efficiency_warning_percent,
+            true,
+        )),
+        "ecd_margin" => Some((
+            "thresholds.hydraulics.ecd_margin_warning_ppg",
+            t.hydraulics.ecd_margin_warning_ppg,
+            true,
+        )),
+        "flow_balance" => Some((
+            "thresholds.well_control.flow_imbalance_warning_gpm",
+            t.well_control.flow_imbalance_warning_gpm,
+            false,
+        )),
+        "pit_rate" => Some((
+            "thresholds.well_control.pit_rate_warning_bbl_hr",
+            t.well_control.pit_rate_warning_bbl_hr,
+            false,
+        )),
+        "spp_delta" => Some((
+            "thresholds.hydraulics.spp_deviation_warning_psi",
+            t.hydraulics.spp_deviation_warning_psi,
+            false,
+        )),
+        "torque_delta_percent" => Some((
+            "thresholds.mechanical.torque_increase_warning",
+            t.mechanical.torque_increase_warning,
+            false,
+        )),
+        _ => None,
+    }
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let n = values.len();
+    if n == 0 {
+        0.0
+    } else if n % 2 == 1 {
+        values[n / 2]
+    } else {
+        (values[n / 2 - 1] + values[n / 2]) / 2.0
+    }
+}
+
+/// Threshold that would silence the median false positive, kept on the
+/// alarming side of every confirmed reading and within ±25% of `current`.
+/// `None` when that would not make the threshold less sensitive.
+fn fp_suggested_value(
+    current: f64,
+    median_fp: f64,
+    confirmed: &[f64],
+    higher_is_more_sensitive: bool,
+) -> Option<f64> {
+    let (a, b) = (current * 0.75, current * 1.25);
+    let suggested = median_fp.clamp(a.min(b), a.max(b));
+    if higher_is_more_sensitive {
+        // Alarms when value < threshold: confirmed readings must stay below
+        let suggested = match confirmed.iter().copied().reduce(f64::max) {
+            Some(max_conf) => suggested.max(max_conf + (current - max_conf).abs() * 0.1),
+            None => suggested,
+        };
+        (suggested < current).then_some(suggested)
+    } else {
+        // Alarms when value > threshold: confirmed readings must stay above
+        let suggested = match confirmed.iter().copied().reduce(f64::min) {
+            Some(min_conf) => suggested.min(min_conf - (min_conf - current).abs() * 0.1),
+            None => suggested,
+        };
+        (suggested > current).then_some(suggested)
+    }
+}
+
+/// Rank trigger parameters by false-positive rate.
+///
+/// Triggers with at least `fp_source_min_rated` rated advisories are
+/// ranked by FP rate (then FP count). When the rate reaches
+/// `fp_source_suggest_rate` and the trigger maps to a threshold, the
+/// suggested value is the median false-positive reading, which would have
+/// silenced half of them. It stays on the alarming side of every confirmed
+/// reading and within ±25% of the current value; a suggestion that would
+/// not make the threshold less sensitive is dropped.
+pub fn compute_false_positive_sources(
+    records: &[FeedbackRecord],
+    config: &WellConfig,
+) -> Vec<FalsePositiveSource> {
+    use std::collections::HashMap;
+
+    let fb = &config.feedback;
+
+    struct Bucket {
+        category: AnomalyCategory,
+        confirmed: Vec<f64>,
+        false_positives: Vec<f64>,
+    }
+    let mut buckets: HashMap<&str, Bucket> = HashMap::new();
+    for rec in records {
+        if rec.category == AnomalyCategory::None || rec.outcome == FeedbackOutcome::Unclear {
+            continue;
+        }
+        let bucket = buckets
+            .entry(rec.trigger_parameter.as_str())
+            .or_insert_with(|| Bucket {
+                category: rec.category,
+                confirmed: Vec::new(),
+                false_positives: Vec::new(),
+            });
+        match rec.outcome {
+            FeedbackOutcome::Confirmed => bucket.confirmed.push(rec.trigger_value),
+            FeedbackOutcome::FalsePositive => bucket.false_positives.push(rec.trigger_value),
+            FeedbackOutcome::Unclear => {}
+        }
+    }
+
+    let mut sources: Vec<FalsePositiveSource> = buckets
+        .into_iter()
+        .filter(|(_, b)| b.confirmed.len() + b.false_positives.len() >= fb.fp_source_min_rated)
+        .map(|(trigger, mut b)| {
+            let rated = b.confirmed.len() + b.false_positives.len();
+            let fp_rate = b.false_positives.len() as f64 / rated as f64;
+            let threshold = trigger_threshold(config, trigger);
+
+            // Magnitude thresholds alarm on |value| (gain or loss)
+            if matches!(threshold, Some((_, _, false))) {
+                b.confirmed.iter_mut().for_each(|v| *v = v.abs());
+                b.false_positives.iter_mut().for_each(|v| *v = v.abs());
+            }
+            let median_fp = median(&mut b.false_positives);
+
+            let suggestion = threshold
+                .filter(|_| fp_rate >= fb.fp_source_suggest_rate)
+                .and_then(|(key, current, higher_is_more_sensitive)| {
+                    let suggested = fp_suggested_value(
+                        current,
+                        median_fp,
+                        &b.confirmed,
+                        higher_is_more_sensitive,
+                    )?;
+                    Some(ThresholdSuggestion {
+                        category: b.category,
+                        threshold_key: key.to_string(),
+                        current_value: current,
+                        suggested_value: (suggested * 1000.0).round() / 1000.0,
+                        rationale: format!(
+                            "{} of {} rated `{}` advisories were false positives \
+                             (median reading {:.3} vs threshold {:.3}). Moving the \
+                             threshold toward the false-positive readings keeps \
+                             every confirmed advisory.",
+                            b.false_positives.len(),
+                            rated,
+                            trigger,
+                            median_fp,
+                            current
+                        ),
+                        confidence: ((rated as f64 - 10.0) / 40.0).clamp(0.0, 1.0) * 0.5 + 0.5,
+                    })
+                });
+
+            FalsePositiveSource {
+                trigger_parameter: trigger.to_string(),
+                category: b.category,
+                confirmed: b.confirmed.len(),
+                false_positives: b.false_positives.len(),
+                false_positive_rate: fp_rate,
+                median_false_positive_value: median_fp,
+                suggestion,
+            }
+        })
+        .collect();
+
+    sources.sort_by(|a, b| {
+        b.false_positive_rate
+            .total_cmp(&a.false_positive_rate)
+            .then(b.false_positives.cmp(&a.false_positives))
+            .then(a.trigger_parameter.cmp(&b.trigger_parameter))
+    });
+    sources
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,14 +459,18 @@ mod tests {
     fn make_feedback(cat: AnomalyCategory, outcome: FeedbackOutcome, ts: u64) -> FeedbackRecord {
         FeedbackRecord {
             advisory_timestamp: ts,
+            well_id: String::new(),
             outcome,
             category: cat,
             trigger_parameter: "test".to_string(),
             trigger_value: 0.0,
             threshold_value: 0.0,
             submitted_by: "driller".to_string(),
+            source: crate::storage::feedback::FeedbackSource::Operator,
             submitted_at: ts + 100,
             notes: String::new(),
+            actual_cause: None,
+            action_taken: None,
         }
     }
 
@@ -336,6 +571,69 @@ mod tests {
         assert!(s.suggested_value < s.current_value);
     }
 
+    #[test]
+    fn test_repeated_mse_efficiency_false_positives_rank_first() {
+        let config = WellConfig::default();
+        let threshold = config.thresholds.mse.efficiency_warning_percent;
+        let rec = |trigger: &str, cat, outcome, value: f64, ts| FeedbackRecord {
+            trigger_parameter: trigger.to_string(),
+            trigger_value: value,
+            ..make_feedback(cat, outcome, ts)
+        };
+
+        let mut records = Vec::new();
+        // MSE efficiency: 8 false alarms just under the threshold, 2 real ones well below
+        for i in 0..8 {
+            records.push(rec(
+                "mse_efficiency",
+                AnomalyCategory::DrillingEfficiency,
+                FeedbackOutcome::FalsePositive,
+                threshold - 3.0 - i as f64 * 0.5,
+                i,
+            ));
+        }
+        for i in 8..10 {
+            records.push(rec(
+                "mse_efficiency",
+                AnomalyCategory::DrillingEfficiency,
+                FeedbackOutcome::Confirmed,
+                threshold - 30.0,
+                i,
+            ));
+        }
+        // Flow balance: mostly confirmed
+        for i in 10..16 {
+            let outcome = if i == 10 {
+                FeedbackOutcome::FalsePositive
+            } else {
+                FeedbackOutcome::Confirmed
+            };
+            records.push(rec(
+                "flow_balance",
+                AnomalyCategory::WellControl,
+                outcome,
+                -25.0,
+                i,
+            ));
+        }
+
+        let sources = compute_false_positive_sources(&records, &config);
+        assert_eq!(sources.len(), 2);
+        let top = &sources[0];
+        assert_eq!(top.trigger_parameter, "mse_efficiency");
+        assert_eq!(top.false_positives, 8);
+        assert!((top.false_positive_rate - 0.8).abs() < 1e-9);
+
+        let s = top.suggestion.as_ref().expect("adjustment suggested");
+        assert_eq!(s.threshold_key, "thresholds.mse.efficiency_warning_percent");
+        // Alarms when efficiency is below the threshold: lower it toward the
+        // false alarms, but not past the confirmed readings
+        assert!(s.suggested_value < threshold);
+        assert!(s.suggested_value > threshold - 30.0);
+
+        assert!(sources[1].suggestion.is_none());
+    }
+
     #[test]
     fn test_no_suggestion_with_insufficient_data() {
         let records: Vec<FeedbackRecord> = (0..5)
@@ -99,6 +99,10 @@ impl AdvisoryComposer {
             trigger_parameter: ticket.trigger_parameter.clone(),
             trigger_value: ticket.trigger_value,
             threshold_value: ticket.threshold_value,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: ticket.causal_leads.clone(),
         })
     }
 }
@@ -123,6 +127,7 @@ pub fn calculate_efficiency_score(
             AnomalyCategory::Mechanical => 15.0,
             AnomalyCategory::DrillingEfficiency => 10.0,
             AnomalyCategory::Formation => 5.0,
+            AnomalyCategory::Equipment => 10.0,
             AnomalyCategory::None => 0.0,
         }
     } else {
@@ -0,0 +1,293 @@
+//! Compact History Summaries
+//!
+//! Reduces a rolling packet history (typically 60 entries) to per-metric
+//! statistics plus a short list of notable events. Used in place of the raw
+//! history when an advisory is shared with other rigs or fed to a prompt,
+//! keeping the salient context at a fraction of the token cost.
+//!
+//! Nothing in this binary produces fleet events yet; peers attach summaries
+//! to the events they share and the fleet brief reads them.
+
+use crate::physics_engine::calculate_trend;
+use crate::types::{HistoryEntry, RigState};
+use serde::{Deserialize, Serialize};
+
+/// Relative change over the window (percent of mean) below which a metric
+/// is reported as flat.
+const FLAT_TREND_PCT: f64 = 5.0;
+
+/// Maximum notable events retained in a summary.
+const MAX_NOTABLE_EVENTS: usize = 10;
+
+/// Direction of a metric over the summarized window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrendDirection {
+    Rising,
+    Falling,
+    Flat,
+}
+
+impl std::fmt::Display for TrendDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrendDirection::Rising => write!(f, "rising"),
+            TrendDirection::Falling => write!(f, "falling"),
+            TrendDirection::Flat => write!(f, "flat"),
+        }
+    }
+}
+
+/// Statistics for a single metric across the history window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricSummary {
+    /// Metric name (e.g. "mse", "rop")
+    pub name: String,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub first: f64,
+    pub last: f64,
+    /// Linear-regression slope per sample
+    pub slope: f64,
+    /// Fitted change across the whole window as a percent of the mean
+    pub trend_pct: f64,
+    pub direction: TrendDirection,
+}
+
+/// Compact statistical summary of a packet history window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistorySummary {
+    /// Number of history entries summarized
+    pub sample_count: usize,
+    /// Timestamp of the first entry (unix seconds)
+    pub start_timestamp: u64,
+    /// Timestamp of the last entry (unix seconds)
+    pub end_timestamp: u64,
+    /// Bit depth at the start of the window (ft)
+    pub start_depth_ft: f64,
+    /// Bit depth at the end of the window (ft)
+    pub end_depth_ft: f64,
+    /// Per-metric statistics
+    pub metrics: Vec<MetricSummary>,
+    /// Rig-state changes and anomaly onsets, oldest first
+    pub notable_events: Vec<String>,
+}
+
+impl HistorySummary {
+    /// Look up a metric summary by name.
+    pub fn metric(&self, name: &str) -> Option<&MetricSummary> {
+        self.metrics.iter().find(|m| m.name == name)
+    }
+
+    /// Format the summary for inclusion in an advisory prompt.
+    pub fn to_prompt_section(&self) -> String {
+        let mut out = format!(
+            "HISTORY SUMMARY ({} samples, {}s, depth {:.0}-{:.0} ft):\n",
+            self.sample_count,
+            self.end_timestamp.saturating_sub(self.start_timestamp),
+            self.start_depth_ft,
+            self.end_depth_ft,
+        );
+        for m in &self.metrics {
+            out.push_str(&format!(
+                "- {}: mean {:.2}, std {:.2}, range {:.2}-{:.2}, {} ({:+.1}%)\n",
+                m.name, m.mean, m.std_dev, m.min, m.max, m.direction, m.trend_pct
+            ));
+        }
+        if !self.notable_events.is_empty() {
+            out.push_str("Notable events:\n");
+            for event in &self.notable_events {
+                out.push_str(&format!("- {}\n", event));
+            }
+        }
+        out
+    }
+}
+
+/// Summarize a packet history into per-metric statistics and notable events.
+///
+/// Returns `None` for an empty history.
+#[allow(dead_code)]
+pub fn summarize_history(history: &[HistoryEntry]) -> Option<HistorySummary> {
+    let first = history.first()?;
+    let last = history.last()?;
+
+    type Extractor = fn(&HistoryEntry) -> f64;
+    let extractors: [(&str, Extractor); 11] = [
+        ("mse", |e| e.metrics.mse),
+        ("mse_efficiency", |e| e.metrics.mse_efficiency),
+        ("rop", |e| e.packet.rop),
+        ("wob", |e| e.packet.wob),
+        ("rpm", |e| e.packet.rpm),
+        ("torque", |e| e.packet.torque),
+        ("spp", |e| e.packet.spp),
+        ("flow_balance", |e| e.metrics.flow_balance),
+        ("pit_rate", |e| e.metrics.pit_rate),
+        ("ecd_margin", |e| e.metrics.ecd_margin),
+        ("gas_units", |e| e.packet.gas_units),
+    ];
+
+    let metrics = extractors
+        .iter()
+        .filter_map(|(name, extract)| {
+            let values: Vec<f64> = history.iter().map(extract).collect();
+            summarize_metric(name, &values)
+        })
+        .collect();
+
+    Some(HistorySummary {
+        sample_count: history.len(),
+        start_timestamp: first.packet.timestamp,
+        end_timestamp: last.packet.timestamp,
+        start_depth_ft: first.packet.bit_depth,
+        end_depth_ft: last.packet.bit_depth,
+        metrics,
+        notable_events: notable_events(history),
+    })
+}
+
+fn summarize_metric(name: &str, values: &[f64]) -> Option<MetricSummary> {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let (&first, &last) = (finite.first()?, finite.last()?);
+
+    let n = finite.len() as f64;
+    let mean = finite.iter().sum::<f64>() / n;
+    let variance = finite.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let slope = calculate_trend(&finite);
+    let trend_pct = if mean.abs() > f64::EPSILON {
+        slope * (n - 1.0) / mean.abs() * 100.0
+    } else {
+        0.0
+    };
+    let direction = if trend_pct >= FLAT_TREND_PCT {
+        TrendDirection::Rising
+    } else if trend_pct <= -FLAT_TREND_PCT {
+        TrendDirection::Falling
+    } else {
+        TrendDirection::Flat
+    };
+
+    Some(MetricSummary {
+        name: name.to_string(),
+        mean,
+        std_dev: variance.sqrt(),
+        min,
+        max,
+        first,
+        last,
+        slope,
+        trend_pct,
+        direction,
+    })
+}
+
+/// Rig-state transitions and anomaly onsets, capped at [`MAX_NOTABLE_EVENTS`]
+/// (most recent kept).
+fn notable_events(history: &[HistoryEntry]) -> Vec<String> {
+    let mut events = Vec::new();
+    let mut prev_state: Option<RigState> = None;
+    let mut prev_anomaly = false;
+
+    for entry in history {
+        let ts = entry.packet.timestamp;
+        let state = entry.metrics.state;
+        if let Some(prev) = prev_state {
+            if prev != state {
+                events.push(format!(
+                    "t={} depth {:.0} ft: rig state {:?} -> {:?}",
+                    ts, entry.packet.bit_depth, prev, state
+                ));
+            }
+        }
+        prev_state = Some(state);
+
+        if entry.metrics.is_anomaly && !prev_anomaly {
+            let description = entry
+                .metrics
+                .anomaly_description
+                .as_deref()
+                .unwrap_or("no description");
+            events.push(format!(
+                "t={} depth {:.0} ft: {:?} anomaly ({})",
+                ts, entry.packet.bit_depth, entry.metrics.anomaly_category, description
+            ));
+        }
+        prev_anomaly = entry.metrics.is_anomaly;
+    }
+
+    if events.len() > MAX_NOTABLE_EVENTS {
+        events.drain(..events.len() - MAX_NOTABLE_EVENTS);
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DrillingMetrics, WitsPacket};
+
+    fn entry(i: u64, mse: f64) -> HistoryEntry {
+        let packet = WitsPacket {
+            timestamp: 1_000 + i,
+            bit_depth: 10_000.0 + i as f64,
+            rop: 60.0,
+            wob: 25.0,
+            rpm: 120.0,
+            ..WitsPacket::default()
+        };
+        let metrics = DrillingMetrics {
+            state: RigState::Drilling,
+            mse,
+            ..DrillingMetrics::default()
+        };
+        HistoryEntry { packet, metrics }
+    }
+
+    #[test]
+    fn test_summary_captures_rising_mse_and_ranges() {
+        // MSE climbs linearly 20_000 -> 29_900 over 100 samples
+        let history: Vec<HistoryEntry> =
+            (0..100).map(|i| entry(i, 20_000.0 + i as f64 * 100.0)).collect();
+
+        let summary = summarize_history(&history).expect("non-empty history");
+        assert_eq!(summary.sample_count, 100);
+        assert_eq!(summary.end_timestamp - summary.start_timestamp, 99);
+
+        let mse = summary.metric("mse").expect("mse summarized");
+        assert_eq!(mse.direction, TrendDirection::Rising);
+        assert!((mse.slope - 100.0).abs() < 1e-6);
+        assert!((mse.min - 20_000.0).abs() < 1e-6);
+        assert!((mse.max - 29_900.0).abs() < 1e-6);
+        assert!((mse.mean - 24_950.0).abs() < 1e-6);
+        assert!(mse.std_dev > 0.0);
+
+        let rop = summary.metric("rop").expect("rop summarized");
+        assert_eq!(rop.direction, TrendDirection::Flat);
+        assert!(rop.std_dev.abs() < 1e-9);
+
+        let prompt = summary.to_prompt_section();
+        assert!(prompt.contains("mse: mean 24950.00"));
+        assert!(prompt.contains("rising"));
+    }
+
+    #[test]
+    fn test_summary_records_state_changes_and_anomalies() {
+        let mut history: Vec<HistoryEntry> = (0..10).map(|i| entry(i, 20_000.0)).collect();
+        history[4].metrics.state = RigState::Connection;
+        history[7].metrics.is_anomaly = true;
+        history[8].metrics.is_anomaly = true;
+
+        let summary = summarize_history(&history).expect("non-empty history");
+        // Drilling -> Connection, Connection -> Drilling, one anomaly onset
+        assert_eq!(summary.notable_events.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_history() {
+        assert!(summarize_history(&[]).is_none());
+    }
+}
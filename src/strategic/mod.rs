@@ -1,12 +1,14 @@
 //! Strategic Analysis Module
 //!
 //! Aggregates tactical analyses and generates strategic reports.
-//! Also provides advisory composition.
+//! Also provides advisory composition and compact history summaries.
 
 pub mod advisory;
 mod aggregation;
+pub mod history_summary;
 pub(crate) mod parsing;
 pub mod templates;
 
 pub use advisory::AdvisoryComposer;
+pub use history_summary::HistorySummary;
 pub use parsing::{DailyReport, HourlyReport};
@@ -39,6 +39,7 @@ pub fn template_advisory(
         AnomalyCategory::Hydraulics => hydraulics_template(ticket, physics),
         AnomalyCategory::Mechanical => mechanical_template(ticket, physics),
         AnomalyCategory::Formation => formation_template(ticket, physics),
+        AnomalyCategory::Equipment => equipment_template(physics),
         AnomalyCategory::None => normal_template(physics),
     };
 
@@ -348,6 +349,27 @@ fn formation_template(
     )
 }
 
+fn equipment_template(physics: &DrillingPhysicsReport) -> (String, String, String) {
+    let deficit = physics.current_flow_in - physics.current_flow_out;
+    (
+        format!(
+            "Surface equipment fault suspected — {:.0} gpm of {:.0} gpm flow-in not returning \
+             with downhole indicators stable. Inspect washpipe, rotating head seals and \
+             surface lines before treating as downhole losses.",
+            deficit, physics.current_flow_in
+        ),
+        "Avoid unnecessary LCM treatment; repair surface leak before it worsens".to_string(),
+        format!(
+            "Flow in {:.0} gpm, flow out {:.0} gpm, SPP {:.0} psi, ECD {:.2} ppg at {:.0} ft.",
+            physics.current_flow_in,
+            physics.current_flow_out,
+            physics.current_spp,
+            physics.current_ecd,
+            physics.current_depth
+        ),
+    )
+}
+
 fn normal_template(physics: &DrillingPhysicsReport) -> (String, String, String) {
     (
         format!(
@@ -435,6 +457,7 @@ mod tests {
             AnomalyCategory::Hydraulics,
             AnomalyCategory::Mechanical,
             AnomalyCategory::Formation,
+            AnomalyCategory::Equipment,
             AnomalyCategory::None,
         ] {
             let ticket = make_ticket(cat.clone());
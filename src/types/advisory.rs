@@ -3,7 +3,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{AnomalyCategory, DrillingMetrics, RiskLevel, TicketEvent, TicketSeverity, WitsPacket};
+use super::{
+    AnomalyCategory, CausalLead, DrillingMetrics, RiskLevel, TicketEvent, TicketSeverity,
+    WitsPacket,
+};
 
 // ============================================================================
 // Phase 4: History Buffer
@@ -93,6 +96,30 @@ pub struct DrillingPhysicsReport {
     pub current_spp: f64,
     /// Current casing pressure (psi)
     pub current_casing_pressure: f64,
+    /// Bit wear estimate from MSE and d-exponent drift over the bit run
+    #[serde(default)]
+    pub bit_wear: BitWearEstimate,
+}
+
+/// IADC-style bit dull estimate from drilling response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitWearEstimate {
+    /// Estimated dull grade (0 = sharp, 8 = fully worn)
+    pub dull_grade_estimate: f64,
+    /// Confidence in the estimate (0.0-1.0, 0 = not enough drilling data)
+    pub confidence: f64,
+    /// What the estimate is based on
+    pub basis: String,
+}
+
+impl Default for BitWearEstimate {
+    fn default() -> Self {
+        Self {
+            dull_grade_estimate: 0.0,
+            confidence: 0.0,
+            basis: "No drilling data".to_string(),
+        }
+    }
 }
 
 impl Default for DrillingPhysicsReport {
@@ -126,6 +153,7 @@ impl Default for DrillingPhysicsReport {
             current_pit_volume: 0.0,
             current_spp: 0.0,
             current_casing_pressure: 0.0,
+            bit_wear: BitWearEstimate::default(),
         }
     }
 }
@@ -298,6 +326,35 @@ impl From<f64> for FinalSeverity {
     }
 }
 
+/// One sample of an advisory sparkline
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SparklinePoint {
+    pub timestamp: u64,
+    pub value: f64,
+}
+
+/// Compact trend preview of the metric behind an advisory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sparkline {
+    /// Metric plotted (e.g. "torque", "flow_balance")
+    pub parameter: String,
+    /// Oldest first, ending at the packet that raised the advisory
+    pub points: Vec<SparklinePoint>,
+}
+
+/// Mud-logging lag correction of a gas reading to the depth it came from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GasLagCorrection {
+    /// Bit depth when the gas was read at surface (ft)
+    pub detected_at_depth_ft: f64,
+    /// Bit depth when the gas entered the annulus (ft)
+    pub source_depth_ft: f64,
+    /// Bottoms-up transit time (s)
+    pub lag_secs: f64,
+    /// Annular plus surface volume pumped during the lag (bbl)
+    pub lag_volume_bbl: f64,
+}
+
 // ============================================================================
 // Phase 8-9: Strategic Advisory (Final Output)
 // ============================================================================
@@ -347,6 +404,19 @@ pub struct StrategicAdvisory {
     /// Threshold value that was exceeded
     #[serde(default)]
     pub threshold_value: f64,
+    /// Raised in preview mode before baselines locked (static thresholds);
+    /// excluded from history and stats until promoted
+    #[serde(default)]
+    pub provisional_during_learning: bool,
+    /// Recent trajectory of the triggering metric, captured at generation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparkline: Option<Sparkline>,
+    /// Gas advisories: source depth corrected for the bottoms-up lag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_lag: Option<GasLagCorrection>,
+    /// Causal leading indicators carried over from the originating ticket
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causal_leads: Vec<CausalLead>,
 }
 
 impl Default for StrategicAdvisory {
@@ -367,6 +437,10 @@ impl Default for StrategicAdvisory {
             trigger_parameter: String::new(),
             trigger_value: 0.0,
             threshold_value: 0.0,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
         }
     }
 }
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::AnomalyCategory;
+use super::{AnomalyCategory, CausalLead, RigState};
 
 /// Complete post-well debrief report
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +20,25 @@ pub struct WellDebrief {
     pub timeline: Vec<TimelineEvent>,
     pub formation_comparisons: Vec<FormationComparison>,
     pub feedback_summary: FeedbackSummary,
+    /// Recurring advisories grouped by category and depth proximity
+    #[serde(default)]
+    pub clusters: Vec<AdvisoryCluster>,
+    /// Causal leads aggregated across advisories, most frequent first
+    #[serde(default)]
+    pub causal_summary: Vec<CausalLeadSummary>,
+    /// Non-productive time attributed to advisory categories, most hours first
+    #[serde(default)]
+    pub npt_breakdown: Vec<NptEntry>,
+    /// Threshold changes suggested by the well's operator feedback
+    #[serde(default)]
+    pub threshold_recommendations: Vec<ThresholdRecommendation>,
+    /// `threshold_recommendations` as a TOML patch for the next well's config
+    #[serde(default)]
+    pub config_patch: String,
+    /// Mesh store evictions during the well: fleet events dropped to stay
+    /// under `gossip.store_max_bytes`
+    #[serde(default)]
+    pub mesh_data_loss: Vec<crate::gossip::store::Eviction>,
     pub narrative: String,
 }
 
@@ -34,6 +53,116 @@ pub struct TimelineEvent {
     pub recommendation: String,
     /// "confirmed" / "false_positive" / "unclear" / None
     pub feedback: Option<String>,
+    /// Strongest causal lead recorded on the advisory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_lead: Option<CausalLead>,
+}
+
+/// How often a parameter led the advisories on this well
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausalLeadSummary {
+    pub parameter: String,
+    /// Advisories that recorded this parameter as a lead
+    pub occurrences: usize,
+    /// Advisories where it was the strongest lead
+    pub top_lead_count: usize,
+    pub avg_lag_seconds: f64,
+    pub avg_abs_r: f64,
+    /// Occurrences where the parameter moved with the target (positive r)
+    pub co_moving: usize,
+    /// Advisory categories it preceded
+    pub categories: Vec<AnomalyCategory>,
+}
+
+/// Hours lost after advisories of one category before drilling resumed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NptEntry {
+    pub category: AnomalyCategory,
+    pub hours: f64,
+    /// Advisories that opened an NPT window
+    pub events: usize,
+    /// Windows still open at well end (capped there)
+    pub open_ended: usize,
+}
+
+/// Rig state transition recorded for NPT accounting
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct RigStateChange {
+    pub timestamp: u64,
+    pub state: RigState,
+}
+
+/// Rig state transitions kept at most; the oldest are dropped first.
+pub const MAX_RIG_STATE_CHANGES: usize = 50_000;
+
+/// Whole-well rig state history, kept as transitions only
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RigStateLog {
+    pub changes: Vec<RigStateChange>,
+    /// Timestamp of the last packet observed (well end proxy)
+    pub last_timestamp: u64,
+}
+
+impl RigStateLog {
+    /// Rebuild a log from persisted transitions (oldest first).
+    pub fn from_changes(mut changes: Vec<RigStateChange>) -> Self {
+        if changes.len() > MAX_RIG_STATE_CHANGES {
+            changes.drain(..changes.len() - MAX_RIG_STATE_CHANGES);
+        }
+        let last_timestamp = changes.last().map_or(0, |c| c.timestamp);
+        Self {
+            changes,
+            last_timestamp,
+        }
+    }
+
+    /// Record the rig state for one packet; only changes are stored.
+    /// Returns the new transition, if any, so the caller can persist it.
+    pub fn record(&mut self, timestamp: u64, state: RigState) -> Option<RigStateChange> {
+        self.last_timestamp = self.last_timestamp.max(timestamp);
+        if self.changes.last().is_some_and(|c| c.state == state) {
+            return None;
+        }
+        if self.changes.len() >= MAX_RIG_STATE_CHANGES {
+            // Drop a tenth at a time so a full log isn't shifted on every change
+            self.changes.drain(..MAX_RIG_STATE_CHANGES / 10);
+        }
+        let change = RigStateChange { timestamp, state };
+        self.changes.push(change);
+        Some(change)
+    }
+}
+
+/// Same-category advisories grouped by depth proximity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryCluster {
+    pub category: AnomalyCategory,
+    pub depth_top_ft: f64,
+    pub depth_base_ft: f64,
+    pub count: usize,
+    pub critical_count: usize,
+    pub first_timestamp: u64,
+    pub last_timestamp: u64,
+    /// Formations the clustered advisories fell in, shallowest first
+    pub formations: Vec<String>,
+}
+
+/// A threshold adjustment recommended from the well's feedback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdRecommendation {
+    pub category: AnomalyCategory,
+    /// Trigger parameter the feedback was rated on (None for category-level)
+    pub trigger_parameter: Option<String>,
+    /// Dot-path config key (e.g. "thresholds.mechanical.torque_increase_warning")
+    pub threshold_key: String,
+    pub current_value: f64,
+    pub recommended_value: f64,
+    /// True when the change makes the threshold less sensitive (fewer alerts)
+    pub widen: bool,
+    /// Rated (confirmed + false positive) advisories behind the recommendation
+    pub rated: usize,
+    pub false_positive_rate: f64,
+    pub rationale: String,
 }
 
 /// Planned vs actual comparison for a single formation
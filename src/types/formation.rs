@@ -64,6 +64,32 @@ pub struct CasingPoint {
     pub cement_top_ft: f64,
 }
 
+/// Planned mud weight over a depth interval of the mud program
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MudProgramInterval {
+    pub depth_top_ft: f64,
+    pub depth_base_ft: f64,
+    pub mud_weight_ppg: f64,
+    /// Allowed deviation for this interval (ppg); defaults to `[mud_program]`
+    #[serde(default)]
+    pub tolerance_ppg: Option<f64>,
+    #[serde(default)]
+    pub mud_type: String,
+}
+
+/// Actual mud weight outside the planned program at the current depth
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MudWeightDeviation {
+    pub depth_ft: f64,
+    pub interval_top_ft: f64,
+    pub interval_base_ft: f64,
+    pub planned_ppg: f64,
+    pub actual_ppg: f64,
+    /// actual − planned (ppg); negative = underweight
+    pub deviation_ppg: f64,
+    pub tolerance_ppg: f64,
+}
+
 /// Well-level metadata in the prognosis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PrognosisWellInfo {
@@ -76,6 +102,52 @@ pub struct PrognosisWellInfo {
     pub coordinate_system: String,
 }
 
+/// A planned sidetrack / lateral branch leaving the original hole
+///
+/// Formation depths are measured depth along the branch. Above the kickoff
+/// depth the branch shares the parent hole's formations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SidetrackBranch {
+    /// Branch name (e.g. "ST1"); auto-detected sidetracks are named ST1, ST2, ...
+    pub name: String,
+    pub kickoff_depth_ft: f64,
+    #[serde(default, rename = "formation")]
+    pub formations: Vec<FormationInterval>,
+}
+
+/// How a sidetrack branch was started
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SidetrackSource {
+    /// Operator marker via `POST /api/v2/sidetrack`
+    Operator,
+    /// Sustained drilling above the deepest drilled depth
+    AutoDetected,
+}
+
+/// Operator request to start a new wellbore branch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SidetrackMarker {
+    /// Branch name; defaults to the next ST<n>
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Kickoff depth (ft); defaults to the current bit depth
+    #[serde(default)]
+    pub kickoff_depth_ft: Option<f64>,
+}
+
+/// A wellbore branch becoming active
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SidetrackEvent {
+    /// Name of the new active branch
+    pub branch: String,
+    /// Branch it left (`None` for the original hole)
+    pub parent: Option<String>,
+    pub kickoff_depth_ft: f64,
+    /// Unix timestamp of the packet that started the branch
+    pub timestamp: u64,
+    pub source: SidetrackSource,
+}
+
 /// Complete well formation prognosis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FormationPrognosis {
@@ -84,4 +156,9 @@ pub struct FormationPrognosis {
     pub formations: Vec<FormationInterval>,
     #[serde(default, rename = "casing")]
     pub casings: Vec<CasingPoint>,
+    #[serde(default, rename = "sidetrack")]
+    pub sidetracks: Vec<SidetrackBranch>,
+    /// Planned mud weight by depth interval
+    #[serde(default)]
+    pub mud_program: Vec<MudProgramInterval>,
 }
@@ -4,8 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use super::{
-    BestParams, Campaign, CasingPoint, ConfidenceLevel, FormationParameters, OptimalParams,
-    ParameterRange, PrognosisWellInfo,
+    BestParams, Campaign, CasingPoint, ConfidenceLevel, FormationParameters, MudProgramInterval,
+    OptimalParams, ParameterRange, PrognosisWellInfo, SidetrackBranch,
 };
 
 /// Field-level geology (shared across all wells in a field)
@@ -39,6 +39,12 @@ pub struct PreSpudPrognosis {
     pub formations: Vec<PreSpudFormation>,
     #[serde(default)]
     pub casings: Vec<CasingPoint>,
+    /// Planned sidetrack branches (fully specified, not merged with geology)
+    #[serde(default, rename = "sidetrack")]
+    pub sidetracks: Vec<SidetrackBranch>,
+    /// Planned mud weight by depth interval
+    #[serde(default)]
+    pub mud_program: Vec<MudProgramInterval>,
 }
 
 /// Per-formation engineering overrides
@@ -82,6 +88,22 @@ pub struct MidWellSnapshot {
     pub sustained_stats: Option<SustainedStats>,
 }
 
+/// Mid-well snapshot stored as the fields changed since a base snapshot
+///
+/// `changes` holds the changed `MidWellSnapshot` fields (nested tables
+/// carry only their changed keys); `removed` lists dotted paths of fields
+/// the new snapshot no longer has. The base may itself be a delta.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MidWellSnapshotDelta {
+    pub timestamp: u64,
+    /// Timestamp of the snapshot this delta applies to
+    pub base_timestamp: u64,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub changes: toml::Table,
+}
+
 /// Per-snapshot aggregate stats from sustained-only samples (seconds_since_param_change > 120)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SustainedStats {
@@ -153,6 +175,9 @@ pub struct KnowledgeBaseConfig {
     pub max_mid_well_snapshots: usize,
     /// Days to retain compressed cold files before deletion (default 30)
     pub cold_retention_days: u32,
+    /// Write a full mid-well snapshot every N snapshots, deltas in between
+    /// (default 24; 1 = always full)
+    pub full_snapshot_every: usize,
 }
 
 impl Default for KnowledgeBaseConfig {
@@ -163,6 +188,7 @@ impl Default for KnowledgeBaseConfig {
             well: String::new(),
             max_mid_well_snapshots: 168,
             cold_retention_days: 30,
+            full_snapshot_every: 24,
         }
     }
 }
@@ -31,6 +31,9 @@ pub mod ml_quality_thresholds {
     pub const MIN_ANALYSIS_SAMPLES: usize = 360;
     /// P-value threshold for statistical significance
     pub const SIGNIFICANCE_THRESHOLD: f64 = 0.05;
+    /// Minimum |r| for a correlation to count as significant. With enough
+    /// samples even a negligible r clears the p-value bar.
+    pub const MIN_CORRELATION_R: f64 = 0.3;
 }
 
 /// Dataset for ML analysis over a time window
@@ -56,6 +59,9 @@ pub struct HourlyDataset {
     pub well_id: String,
     /// Field/asset name for cross-well queries
     pub field_name: String,
+    /// Active wellbore branch (`None` = original hole)
+    #[serde(default)]
+    pub wellbore: Option<String>,
     /// Cumulative bit hours at window start
     pub bit_hours: f64,
     /// Depth drilled on current bit (ft)
@@ -84,6 +90,7 @@ impl Default for HourlyDataset {
             rig_states_breakdown: HashMap::new(),
             well_id: String::new(),
             field_name: String::new(),
+            wellbore: None,
             bit_hours: 0.0,
             bit_depth: 0.0,
             rejected_sample_count: 0,
@@ -130,6 +137,10 @@ pub struct MLInsightsReport {
     // === V2: Multi-well identification ===
     pub well_id: String,
     pub field_name: String,
+    /// Wellbore branch the window was drilled on (`None` = original hole).
+    /// Keeps sidetrack intervals separate from the original hole at the same depth.
+    #[serde(default)]
+    pub wellbore: Option<String>,
 
     // === V2: Bit wear context ===
     pub bit_hours: f64,
@@ -23,6 +23,31 @@ impl std::fmt::Display for DrillingParameter {
     }
 }
 
+/// Direction of a recommended parameter step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StepAction {
+    Increase,
+    Decrease,
+    Hold,
+}
+
+/// Next WOB step from the local ROP-vs-WOB response (RPM is held)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterStep {
+    /// Whether to raise, lower or hold WOB
+    pub action: StepAction,
+    /// Current WOB (klbs)
+    pub current_wob: f64,
+    /// WOB after the step (klbs)
+    pub recommended_wob: f64,
+    /// Signed change (klbs), bounded by `optimization.max_wob_step_klbs`
+    pub step_klbs: f64,
+    /// ROP elasticity to WOB: % ROP change per % WOB change (0 if not fitted)
+    pub rop_response: f64,
+    /// Advisory text explaining the step
+    pub rationale: String,
+}
+
 /// A single parameter recommendation with current/recommended values and bounds
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParameterRecommendation {
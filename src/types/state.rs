@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, Hash)]
 pub enum RigState {
     Drilling,
+    /// Directional slide: on bottom, pumps driving the motor, no surface rotation
+    Sliding,
     Reaming,
     Circulating,
     Connection,
@@ -23,6 +25,7 @@ impl std::fmt::Display for RigState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RigState::Drilling => write!(f, "Drilling"),
+            RigState::Sliding => write!(f, "Sliding"),
             RigState::Reaming => write!(f, "Reaming"),
             RigState::Circulating => write!(f, "Circulating"),
             RigState::Connection => write!(f, "Connection"),
@@ -1,4 +1,5 @@
-//! Tactical agent types: AnomalyCategory, DrillingMetrics, TicketSeverity, TicketType
+//! Tactical agent types: AnomalyCategory, DrillingMetrics, TicketSeverity, TicketType,
+//! ModeTransitionEvent
 
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +24,8 @@ pub enum AnomalyCategory {
     Mechanical,
     /// D-exponent trends, hard/soft stringers
     Formation,
+    /// Surface equipment faults (washpipe, rotating head seals, rated limits)
+    Equipment,
 }
 
 impl std::fmt::Display for AnomalyCategory {
@@ -34,6 +37,7 @@ impl std::fmt::Display for AnomalyCategory {
             AnomalyCategory::WellControl => write!(f, "Well Control"),
             AnomalyCategory::Mechanical => write!(f, "Mechanical"),
             AnomalyCategory::Formation => write!(f, "Formation"),
+            AnomalyCategory::Equipment => write!(f, "Equipment"),
         }
     }
 }
@@ -116,6 +120,26 @@ impl Default for DrillingMetrics {
     }
 }
 
+/// Emitted once when baseline learning completes and the tactical agent goes
+/// live on dynamic (learned) anomaly detection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModeTransitionEvent {
+    /// Unix timestamp of the transition
+    pub timestamp: u64,
+    /// WITS metrics with locked baselines at transition time
+    pub locked_metrics: usize,
+    /// Total WITS metrics tracked
+    pub total_metrics: usize,
+    /// Operator-facing banner text
+    pub message: String,
+}
+
+impl ModeTransitionEvent {
+    /// Banner shown on the dashboard once learning completes.
+    pub const BANNER: &'static str =
+        "Baseline learning complete — dynamic anomaly detection active";
+}
+
 /// Severity level for advisory tickets
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub enum TicketSeverity {
@@ -238,13 +238,19 @@ pub struct VerificationResult {
 pub struct EnhancedPhysicsReport {
     /// Base physics calculations
     pub base: DrillingPhysicsReport,
-    /// Trend consistency from regression analysis (0.0 to 1.0)
-    /// Higher values indicate consistent trend
+    /// Trend consistency from regression analysis (0.0 to 1.0) over the
+    /// latest continuous segment. Higher values indicate consistent trend
     pub trend_consistency: f64,
     /// Confidence factor based on data quality and history depth
     pub confidence_factor: f64,
-    /// Hours of history used for analysis
+    /// Hours of continuous history in the window (gaps excluded)
     pub history_hours: f64,
+    /// Continuous time as a fraction of the window span (1.0 = no gaps)
+    #[serde(default)]
+    pub data_coverage: f64,
+    /// Feed gaps found in the history window
+    #[serde(default)]
+    pub gap_count: u32,
     /// Whether the anomaly is sustained (not transient)
     pub is_sustained: bool,
     /// Number of consecutive packets showing anomaly
@@ -258,6 +264,8 @@ impl Default for EnhancedPhysicsReport {
             trend_consistency: 0.0,
             confidence_factor: 0.0,
             history_hours: 0.0,
+            data_coverage: 0.0,
+            gap_count: 0,
             is_sustained: false,
             consecutive_anomaly_count: 0,
         }
@@ -290,9 +298,9 @@ pub mod verification_thresholds {
 pub struct CausalLead {
     /// Drilling parameter name (e.g. "WOB", "RPM", "Torque", "SPP", "ROP")
     pub parameter: String,
-    /// Number of seconds this parameter leads the MSE response
+    /// Number of seconds this parameter leads the target (MSE by default) response
     pub lag_seconds: u32,
-    /// Pearson correlation coefficient between lagged parameter and MSE
+    /// Pearson correlation coefficient between lagged parameter and the target
     /// Positive = co-movement (parameter increase → MSE increase)
     /// Negative = inverse (parameter increase → MSE decrease)
     pub pearson_r: f64,
@@ -300,6 +308,10 @@ pub struct CausalLead {
     /// -1 when they move inversely. Use this to generate natural-language
     /// descriptions rather than interpreting it as a drilling prescription.
     pub correlation_sign: i8,
+    /// Partial correlation controlling for the stronger leads, populated only
+    /// by `causal::detect_leads_partial`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_r: Option<f64>,
 }
 
 // ============================================================================
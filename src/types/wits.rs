@@ -77,6 +77,9 @@ pub struct WitsPacket {
     /// Annular pressure (psi)
     #[serde(default)]
     pub annular_pressure: f64,
+    /// MPD choke position (% open); 0 = not reported
+    #[serde(default)]
+    pub choke_position_pct: f64,
 
     // === Formation Parameters ===
     /// Formation pore pressure estimate (ppg)
@@ -151,6 +154,7 @@ impl Default for WitsPacket {
             co2: 0.0,
             casing_pressure: 0.0,
             annular_pressure: 0.0,
+            choke_position_pct: 0.0,
             pore_pressure: 0.0,
             fracture_gradient: 0.0,
             mse: 0.0,
@@ -184,6 +188,46 @@ impl WitsPacket {
         }
     }
 
+    /// Whether both mud temperature sensors are reporting
+    pub fn has_mud_temps(&self) -> bool {
+        self.mud_temp_in > 0.0 && self.mud_temp_out > 0.0
+    }
+
+    /// ECD corrected for the in/out mud temperature delta
+    ///
+    /// Applies `thresholds.hydraulics.mud_thermal_expansion_ppg_per_degf`
+    /// (0.0 = off, the default) to `mud_temp_delta()`. Returns the raw ECD
+    /// when temperatures are absent.
+    pub fn corrected_ecd(&self) -> f64 {
+        let coefficient = if crate::config::is_initialized() {
+            crate::config::get()
+                .thresholds
+                .hydraulics
+                .mud_thermal_expansion_ppg_per_degf
+        } else {
+            crate::config::default_mud_thermal_expansion()
+        };
+        self.corrected_ecd_with(coefficient)
+    }
+
+    /// ECD corrected by `ppg_per_degf` per °F of `mud_temp_delta()`
+    pub fn corrected_ecd_with(&self, ppg_per_degf: f64) -> f64 {
+        if self.ecd <= 0.0 || !self.has_mud_temps() {
+            return self.ecd;
+        }
+        self.ecd + ppg_per_degf * self.mud_temp_delta()
+    }
+
+    /// ECD margin to fracture gradient using `corrected_ecd()`
+    /// Same 1.5 ppg fallback as `ecd_margin()` when fracture gradient unavailable
+    pub fn corrected_ecd_margin(&self) -> f64 {
+        if self.fracture_gradient > 0.0 && self.ecd > 0.0 {
+            self.fracture_gradient - self.corrected_ecd()
+        } else {
+            1.5
+        }
+    }
+
     /// Check if drilling (RPM > 0 and WOB > 0)
     pub fn is_drilling(&self) -> bool {
         self.rpm > 5.0 && self.wob > 1.0
@@ -137,6 +137,22 @@ pub struct VolveConfig {
     pub skip_null_rows: bool,
     /// Replace NaN values with 0.0 instead of skipping the row
     pub nan_to_zero: bool,
+    /// Apply the data-quality filters below after parsing
+    pub quality_filters: bool,
+    /// Known-bad time intervals to drop (documented sensor faults, logging
+    /// restarts, ...)
+    pub bad_intervals: Vec<VolveBadInterval>,
+    /// Gaps up to this long (seconds) are filled by linear interpolation;
+    /// longer gaps are left open and reported
+    pub max_interpolate_gap_secs: u64,
+    /// Only keep packets with `bit_depth` (ft) in `[min, max]`, both ends
+    /// inclusive. Applied after the quality filters.
+    pub depth_window: Option<(f64, f64)>,
+    /// Fill missing samples of channels present in the file instead of
+    /// zero-filling them: linear interpolation between the nearest valid
+    /// samples, nearest valid value for leading/trailing gaps. Channels
+    /// absent from the file stay zero. Overrides `nan_to_zero` for channels.
+    pub interpolate_gaps: bool,
 }
 
 impl Default for VolveConfig {
@@ -146,10 +162,66 @@ impl Default for VolveConfig {
             well_id: None,
             skip_null_rows: true,
             nan_to_zero: true,
+            quality_filters: false,
+            bad_intervals: Vec::new(),
+            max_interpolate_gap_secs: 30,
+            depth_window: None,
+            interpolate_gaps: false,
         }
     }
 }
 
+/// Parse a `MIN:MAX` depth window in feet (e.g. `3200:3600`)
+pub fn parse_depth_window(s: &str) -> Result<(f64, f64), String> {
+    let (min, max) = s
+        .split_once(':')
+        .ok_or_else(|| format!("Depth window '{}' must be MIN:MAX in feet", s))?;
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<f64>()
+            .map_err(|e| format!("Invalid depth '{}' in window '{}': {}", v, s, e))
+    };
+    Ok((parse(min)?, parse(max)?))
+}
+
+/// A known-bad stretch of a Volve log, dropped when `quality_filters` is on
+#[derive(Debug, Clone)]
+pub struct VolveBadInterval {
+    /// First bad timestamp (Unix seconds, inclusive)
+    pub start_ts: u64,
+    /// Last bad timestamp (Unix seconds, inclusive)
+    pub end_ts: u64,
+    /// Why the interval is excluded
+    pub reason: String,
+}
+
+/// A gap in the time index
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolveGap {
+    /// Timestamp of the last packet before the gap
+    pub start_ts: u64,
+    /// Timestamp of the first packet after the gap
+    pub end_ts: u64,
+    pub duration_secs: u64,
+}
+
+/// Corrections applied by the quality filters
+#[derive(Debug, Clone, Default)]
+pub struct VolveCorrections {
+    /// Packets removed by `bad_intervals`
+    pub dropped_packets: usize,
+    /// Bad intervals that matched at least one packet
+    pub bad_intervals_applied: usize,
+    /// Nominal sample interval (median spacing, seconds)
+    pub sample_interval_secs: u64,
+    /// Short gaps filled by interpolation
+    pub interpolated_gaps: Vec<VolveGap>,
+    /// Packets synthesised to fill short gaps
+    pub interpolated_packets: usize,
+    /// Gaps too long to interpolate (left open)
+    pub long_gaps: Vec<VolveGap>,
+}
+
 // ============================================================================
 // CSV Format Detection
 // ============================================================================
@@ -484,6 +556,11 @@ pub struct VolveWellInfo {
     pub depth_range_ft: (f64, f64),
     /// Time range as Unix timestamps (first, last)
     pub time_range: (u64, u64),
+    /// Quality-filter corrections (`None` when filters are off)
+    pub corrections: Option<VolveCorrections>,
+    /// Missing samples filled per channel by `interpolate_gaps` (channels
+    /// with no fills are omitted)
+    pub interpolated_values: Vec<(&'static str, usize)>,
 }
 
 /// Loaded Volve well ready for replay through SAIREN-OS
@@ -498,6 +575,15 @@ impl VolveReplay {
         let path = path.as_ref();
         let path_str = path.display().to_string();
 
+        if let Some((min, max)) = config.depth_window {
+            if !min.is_finite() || !max.is_finite() || min >= max {
+                return Err(format!(
+                    "Invalid depth window {}-{} ft: min must be below max",
+                    min, max
+                ));
+            }
+        }
+
         let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path_str, e))?;
 
         let reader = BufReader::new(file);
@@ -557,6 +643,29 @@ impl VolveReplay {
             }
         }
 
+        // Fill missing channel samples before the quality filters interpolate
+        // across time gaps, so those never blend in a NaN
+        let interpolated_values = if config.interpolate_gaps {
+            interpolate_missing_channels(&mut packets)
+        } else {
+            Vec::new()
+        };
+
+        let corrections = config
+            .quality_filters
+            .then(|| apply_quality_filters(&mut packets, &config));
+
+        if let Some((min, max)) = config.depth_window {
+            let before = packets.len();
+            packets.retain(|p| p.bit_depth >= min && p.bit_depth <= max);
+            if packets.is_empty() && before > 0 {
+                return Err(format!(
+                    "No packets from {} within depth window {:.0}-{:.0} ft ({} outside)",
+                    path_str, min, max, before
+                ));
+            }
+        }
+
         if packets.is_empty() {
             return Err(format!(
                 "No valid packets from {}. {} errors, {} skipped.",
@@ -590,6 +699,8 @@ impl VolveReplay {
             columns_found: columns_summary,
             depth_range_ft: (depth_min, depth_max),
             time_range: (time_first, time_last),
+            corrections,
+            interpolated_values,
         };
 
         tracing::info!(
@@ -661,6 +772,25 @@ impl VolveReplay {
             duration_hrs / 24.0
         );
         println!("  Columns:    {}", self.info.columns_found);
+        if let Some(c) = &self.info.corrections {
+            println!(
+                "  Quality:    {} dropped ({} bad intervals), {} gaps interpolated (+{} pkts), {} long gaps",
+                c.dropped_packets,
+                c.bad_intervals_applied,
+                c.interpolated_gaps.len(),
+                c.interpolated_packets,
+                c.long_gaps.len()
+            );
+        }
+        if !self.info.interpolated_values.is_empty() {
+            let fills: Vec<String> = self
+                .info
+                .interpolated_values
+                .iter()
+                .map(|(name, n)| format!("{}={}", name, n))
+                .collect();
+            println!("  Filled:     {}", fills.join(", "));
+        }
 
         if let Some(first) = self.packets.first() {
             println!(
@@ -681,6 +811,46 @@ impl VolveReplay {
 // Row Parsing
 // ============================================================================
 
+/// Row-at-a-time parser for streaming a Volve CSV without loading it
+///
+/// Built from the header line; the whole-file passes of [`VolveReplay::load`]
+/// (`interpolate_gaps`, `quality_filters`, `depth_window`) need every row at
+/// once and are not applied.
+pub struct VolveRowParser {
+    col_map: ColumnMap,
+    format: CsvFormat,
+    config: VolveConfig,
+}
+
+impl VolveRowParser {
+    /// Detect the format from a header line, failing if it isn't a Volve CSV
+    pub fn from_header(header: &str, mut config: VolveConfig) -> Result<Self, String> {
+        let col_map = ColumnMap::from_header(header);
+        col_map.validate()?;
+        // No later pass fills NaN samples, so zero-fill as without interpolation
+        config.interpolate_gaps = false;
+        let format = col_map.format.unwrap_or(CsvFormat::Kaggle);
+        Ok(Self {
+            col_map,
+            format,
+            config,
+        })
+    }
+
+    /// Detected format name ("Kaggle" or "Tunkiel")
+    pub fn format_name(&self) -> &'static str {
+        match self.format {
+            CsvFormat::Kaggle => "Kaggle",
+            CsvFormat::Tunkiel => "Tunkiel",
+        }
+    }
+
+    /// Parse one data row; `Ok(None)` for rows skipped as null
+    pub fn parse_line(&self, line: &str, line_num: usize) -> Result<Option<WitsPacket>, String> {
+        parse_row(line, &self.col_map, self.format, &self.config, line_num)
+    }
+}
+
 /// Parse a CSV row into a WitsPacket, handling both formats
 fn parse_row(
     line: &str,
@@ -696,26 +866,38 @@ fn parse_row(
     let timestamp = parse_timestamp_from_row(&fields, col_map)?;
 
     // --- Read raw values ---
-    let raw_depth = get_f64(&fields, col_map.depth, config.nan_to_zero).unwrap_or(0.0);
-    let raw_hole_depth =
-        get_f64(&fields, col_map.hole_depth, config.nan_to_zero).unwrap_or(raw_depth);
-    let raw_wob = get_f64(&fields, col_map.wob, config.nan_to_zero).unwrap_or(0.0);
-    let raw_torque = get_f64(&fields, col_map.torque, config.nan_to_zero).unwrap_or(0.0);
-    let raw_rpm = get_f64(&fields, col_map.rpm, config.nan_to_zero).unwrap_or(0.0);
-    let raw_rop = get_f64(&fields, col_map.rop, config.nan_to_zero).unwrap_or(0.0);
-    let raw_hookload = get_f64(&fields, col_map.hook_load, config.nan_to_zero).unwrap_or(0.0);
-    let raw_spp = get_f64(&fields, col_map.spp, config.nan_to_zero).unwrap_or(0.0);
-    let raw_flow_in = get_f64(&fields, col_map.flow_in, config.nan_to_zero).unwrap_or(0.0);
-    let raw_flow_out = get_f64(&fields, col_map.flow_out, config.nan_to_zero).unwrap_or(0.0);
-    let raw_mw_in = get_f64(&fields, col_map.mw_in, config.nan_to_zero).unwrap_or(0.0);
-    let raw_mw_out = get_f64(&fields, col_map.mw_out, config.nan_to_zero).unwrap_or(0.0);
-    let raw_ecd = get_f64(&fields, col_map.ecd, config.nan_to_zero).unwrap_or(0.0);
-    let raw_temp_in = get_f64(&fields, col_map.temp_in, config.nan_to_zero).unwrap_or(0.0);
-    let raw_temp_out = get_f64(&fields, col_map.temp_out, config.nan_to_zero).unwrap_or(0.0);
-    let raw_gas = get_f64(&fields, col_map.gas, config.nan_to_zero).unwrap_or(0.0);
-    let raw_dxc = get_f64(&fields, col_map.dxc, config.nan_to_zero).unwrap_or(0.0);
-    let raw_pump_spm = get_f64(&fields, col_map.pump_spm, config.nan_to_zero).unwrap_or(0.0);
-    let raw_pit_vol = get_f64(&fields, col_map.pit_volume, config.nan_to_zero).unwrap_or(0.0);
+    // With `interpolate_gaps`, a missing sample in a column the file has is
+    // NaN until `interpolate_missing_channels`; an absent column is zero
+    let read = |idx: Option<usize>| -> f64 {
+        if config.interpolate_gaps {
+            idx.map_or(0.0, |_| get_f64(&fields, idx, false).unwrap_or(f64::NAN))
+        } else {
+            get_f64(&fields, idx, config.nan_to_zero).unwrap_or(0.0)
+        }
+    };
+    let raw_depth = read(col_map.depth);
+    let raw_hole_depth = if config.interpolate_gaps && col_map.hole_depth.is_some() {
+        read(col_map.hole_depth)
+    } else {
+        get_f64(&fields, col_map.hole_depth, config.nan_to_zero).unwrap_or(raw_depth)
+    };
+    let raw_wob = read(col_map.wob);
+    let raw_torque = read(col_map.torque);
+    let raw_rpm = read(col_map.rpm);
+    let raw_rop = read(col_map.rop);
+    let raw_hookload = read(col_map.hook_load);
+    let raw_spp = read(col_map.spp);
+    let raw_flow_in = read(col_map.flow_in);
+    let raw_flow_out = read(col_map.flow_out);
+    let raw_mw_in = read(col_map.mw_in);
+    let raw_mw_out = read(col_map.mw_out);
+    let raw_ecd = read(col_map.ecd);
+    let raw_temp_in = read(col_map.temp_in);
+    let raw_temp_out = read(col_map.temp_out);
+    let raw_gas = read(col_map.gas);
+    let raw_dxc = read(col_map.dxc);
+    let raw_pump_spm = read(col_map.pump_spm);
+    let raw_pit_vol = read(col_map.pit_volume);
 
     // --- Convert to oilfield units based on format ---
     let (
@@ -786,12 +968,14 @@ fn parse_row(
     };
 
     // --- Skip null rows ---
+    // (a missing sample counts as zero here, as it would without interpolation)
+    let or_zero = |v: f64| if v.is_nan() { 0.0 } else { v };
     if config.skip_null_rows {
-        let all_zero = wob_klbs.abs() < 1e-10
-            && rpm.abs() < 1e-10
-            && rop_fthr.abs() < 1e-10
-            && spp_psi.abs() < 1e-10
-            && depth_ft.abs() < 1e-10;
+        let all_zero = or_zero(wob_klbs).abs() < 1e-10
+            && or_zero(rpm).abs() < 1e-10
+            && or_zero(rop_fthr).abs() < 1e-10
+            && or_zero(spp_psi).abs() < 1e-10
+            && or_zero(depth_ft).abs() < 1e-10;
 
         if all_zero {
             return Ok(None);
@@ -799,7 +983,13 @@ fn parse_row(
     }
 
     // --- Rig state ---
-    let rig_state = parse_rig_state(&fields, col_map, rpm, wob_klbs, rop_fthr);
+    let rig_state = parse_rig_state(
+        &fields,
+        col_map,
+        or_zero(rpm),
+        or_zero(wob_klbs),
+        or_zero(rop_fthr),
+    );
 
     Ok(Some(WitsPacket {
         timestamp,
@@ -829,6 +1019,7 @@ fn parse_row(
         co2: 0.0,
         casing_pressure: 0.0,
         annular_pressure: 0.0,
+        choke_position_pct: 0.0,
         pore_pressure: 0.0,
         fracture_gradient: 0.0,
         mse: 0.0,        // Physics engine calculates
@@ -968,6 +1159,7 @@ fn parse_rig_state(
         if let Some(s) = fields.get(idx).map(|s| s.trim().to_lowercase()) {
             if !s.is_empty() && s != "nan" {
                 return match s.as_str() {
+                    s if s.contains("slid") => RigState::Sliding,
                     s if s.contains("drill") => RigState::Drilling,
                     s if s.contains("ream") => RigState::Reaming,
                     s if s.contains("circ") => RigState::Circulating,
@@ -1012,6 +1204,201 @@ fn classify_from_params(rpm: f64, wob_klbs: f64, rop_fthr: f64) -> RigState {
     }
 }
 
+// ============================================================================
+// Quality Filters
+// ============================================================================
+
+/// Drop known-bad intervals, interpolate short gaps and report long ones.
+///
+/// A gap is any spacing above twice the nominal (median) sample interval.
+/// Mutable accessor for one numeric channel of a packet
+type ChannelField = fn(&mut WitsPacket) -> &mut f64;
+
+/// Numeric channels filled by `interpolate_missing_channels`
+const INTERPOLATED_CHANNELS: &[(&str, ChannelField)] = &[
+    ("bit_depth", |p| &mut p.bit_depth),
+    ("hole_depth", |p| &mut p.hole_depth),
+    ("rop", |p| &mut p.rop),
+    ("hook_load", |p| &mut p.hook_load),
+    ("wob", |p| &mut p.wob),
+    ("rpm", |p| &mut p.rpm),
+    ("torque", |p| &mut p.torque),
+    ("spp", |p| &mut p.spp),
+    ("pump_spm", |p| &mut p.pump_spm),
+    ("flow_in", |p| &mut p.flow_in),
+    ("flow_out", |p| &mut p.flow_out),
+    ("pit_volume", |p| &mut p.pit_volume),
+    ("mud_weight_in", |p| &mut p.mud_weight_in),
+    ("mud_weight_out", |p| &mut p.mud_weight_out),
+    ("ecd", |p| &mut p.ecd),
+    ("mud_temp_in", |p| &mut p.mud_temp_in),
+    ("mud_temp_out", |p| &mut p.mud_temp_out),
+    ("gas_units", |p| &mut p.gas_units),
+    ("dxc", |p| &mut p.dxc),
+];
+
+/// Replace NaN (missing) channel samples in place.
+///
+/// Interior runs are interpolated linearly in time between the bracketing
+/// valid samples; leading and trailing runs take the nearest valid value. A
+/// channel with no valid sample at all is zeroed and not counted. Returns the
+/// number of values filled per channel.
+fn interpolate_missing_channels(packets: &mut [WitsPacket]) -> Vec<(&'static str, usize)> {
+    let mut filled = Vec::new();
+
+    for &(name, field) in INTERPOLATED_CHANNELS {
+        let valid: Vec<usize> = (0..packets.len())
+            .filter(|&i| !field(&mut packets[i]).is_nan())
+            .collect();
+        let (Some(&first), Some(&last)) = (valid.first(), valid.last()) else {
+            for p in packets.iter_mut() {
+                *field(p) = 0.0;
+            }
+            continue;
+        };
+
+        let missing = packets.len() - valid.len();
+        if missing == 0 {
+            continue;
+        }
+
+        // Leading / trailing runs: nearest valid sample
+        let head = *field(&mut packets[first]);
+        let tail = *field(&mut packets[last]);
+        for p in &mut packets[..first] {
+            *field(p) = head;
+        }
+        for p in &mut packets[last + 1..] {
+            *field(p) = tail;
+        }
+
+        // Interior runs: linear in time between the bracketing samples
+        for pair in valid.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if b - a < 2 {
+                continue;
+            }
+            let (ta, tb) = (packets[a].timestamp, packets[b].timestamp);
+            let (va, vb) = (*field(&mut packets[a]), *field(&mut packets[b]));
+            for (k, p) in packets[a + 1..b].iter_mut().enumerate() {
+                let frac = if tb > ta {
+                    p.timestamp.saturating_sub(ta) as f64 / (tb - ta) as f64
+                } else {
+                    (k + 1) as f64 / (b - a) as f64
+                };
+                *field(p) = va + (vb - va) * frac;
+            }
+        }
+
+        filled.push((name, missing));
+    }
+
+    if !filled.is_empty() {
+        tracing::info!(channels = ?filled, "Volve missing samples interpolated");
+    }
+    filled
+}
+
+fn apply_quality_filters(packets: &mut Vec<WitsPacket>, config: &VolveConfig) -> VolveCorrections {
+    let mut corrections = VolveCorrections::default();
+
+    // --- Known-bad intervals ---
+    for interval in &config.bad_intervals {
+        let before = packets.len();
+        packets.retain(|p| p.timestamp < interval.start_ts || p.timestamp > interval.end_ts);
+        let dropped = before - packets.len();
+        if dropped > 0 {
+            tracing::info!(
+                start = interval.start_ts,
+                end = interval.end_ts,
+                dropped,
+                reason = %interval.reason,
+                "Volve bad interval dropped"
+            );
+            corrections.dropped_packets += dropped;
+            corrections.bad_intervals_applied += 1;
+        }
+    }
+    if packets.len() < 2 {
+        return corrections;
+    }
+
+    // --- Nominal sample interval ---
+    let mut deltas: Vec<u64> = packets
+        .windows(2)
+        .map(|w| w[1].timestamp.saturating_sub(w[0].timestamp))
+        .filter(|d| *d > 0)
+        .collect();
+    if deltas.is_empty() {
+        return corrections;
+    }
+    deltas.sort_unstable();
+    let step = deltas[deltas.len() / 2];
+    corrections.sample_interval_secs = step;
+
+    // --- Gaps ---
+    let mut filled = Vec::with_capacity(packets.len());
+    for (i, packet) in packets.iter().enumerate() {
+        if let Some(prev) = i.checked_sub(1).map(|j| &packets[j]) {
+            let duration = packet.timestamp.saturating_sub(prev.timestamp);
+            if duration > step * 2 {
+                let gap = VolveGap {
+                    start_ts: prev.timestamp,
+                    end_ts: packet.timestamp,
+                    duration_secs: duration,
+                };
+                if duration <= config.max_interpolate_gap_secs {
+                    let mut ts = prev.timestamp + step;
+                    while ts < packet.timestamp {
+                        let frac = (ts - prev.timestamp) as f64 / duration as f64;
+                        filled.push(interpolate_packet(prev, packet, ts, frac));
+                        corrections.interpolated_packets += 1;
+                        ts += step;
+                    }
+                    corrections.interpolated_gaps.push(gap);
+                } else {
+                    tracing::warn!(
+                        start = gap.start_ts,
+                        duration_secs = gap.duration_secs,
+                        "Volve data gap too long to interpolate"
+                    );
+                    corrections.long_gaps.push(gap);
+                }
+            }
+        }
+        filled.push(packet.clone());
+    }
+    *packets = filled;
+    corrections
+}
+
+/// Linear interpolation of the measured channels between two packets
+fn interpolate_packet(a: &WitsPacket, b: &WitsPacket, timestamp: u64, frac: f64) -> WitsPacket {
+    let lerp = |x: f64, y: f64| x + (y - x) * frac;
+    WitsPacket {
+        timestamp,
+        bit_depth: lerp(a.bit_depth, b.bit_depth),
+        hole_depth: lerp(a.hole_depth, b.hole_depth),
+        rop: lerp(a.rop, b.rop),
+        hook_load: lerp(a.hook_load, b.hook_load),
+        wob: lerp(a.wob, b.wob),
+        rpm: lerp(a.rpm, b.rpm),
+        torque: lerp(a.torque, b.torque),
+        spp: lerp(a.spp, b.spp),
+        pump_spm: lerp(a.pump_spm, b.pump_spm),
+        flow_in: lerp(a.flow_in, b.flow_in),
+        flow_out: lerp(a.flow_out, b.flow_out),
+        pit_volume: lerp(a.pit_volume, b.pit_volume),
+        mud_weight_in: lerp(a.mud_weight_in, b.mud_weight_in),
+        mud_weight_out: lerp(a.mud_weight_out, b.mud_weight_out),
+        ecd: lerp(a.ecd, b.ecd),
+        mud_temp_in: lerp(a.mud_temp_in, b.mud_temp_in),
+        mud_temp_out: lerp(a.mud_temp_out, b.mud_temp_out),
+        gas_units: lerp(a.gas_units, b.gas_units),
+        ..a.clone()
+    }
+}
+
 /// Load all Volve CSV files from a directory
 pub fn load_volve_directory(dir: impl AsRef<Path>, config: VolveConfig) -> Vec<VolveReplay> {
     let dir = dir.as_ref();
@@ -1117,6 +1504,165 @@ mod tests {
         assert!(map.rpm.is_some());
     }
 
+    #[test]
+    fn test_quality_filters_interpolate_short_gap_and_report_long_gap() {
+        use std::io::Write;
+
+        // Tunkiel format, epoch timestamps, 5 s sampling
+        let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+        writeln!(file, "Time,Depth,WOB,SURF_RPM,ROP_AVG,PUMP").unwrap();
+        let t0 = 1_250_000_000u64;
+        let mut row = |ts: u64, depth_m: f64| {
+            writeln!(file, "{},{},100000,2,0.005,20000000", ts, depth_m).unwrap();
+        };
+        for i in 0..10 {
+            row(t0 + i * 5, 1000.0 + i as f64);
+        }
+        // 20 s dropout: short, interpolated
+        for i in 0..10 {
+            row(t0 + 65 + i * 5, 1013.0 + i as f64);
+        }
+        // Logging stopped for 10 minutes: long, reported
+        for i in 0..10 {
+            row(t0 + 710 + i * 5, 1030.0 + i as f64);
+        }
+        // Known-bad stretch at the end
+        for i in 0..4 {
+            row(t0 + 760 + i * 5, 0.5);
+        }
+        file.flush().unwrap();
+
+        let config = VolveConfig {
+            quality_filters: true,
+            bad_intervals: vec![VolveBadInterval {
+                start_ts: t0 + 760,
+                end_ts: t0 + 775,
+                reason: "depth sensor reset".to_string(),
+            }],
+            ..Default::default()
+        };
+        let replay = VolveReplay::load(file.path(), config).expect("load");
+        let c = replay
+            .info
+            .corrections
+            .as_ref()
+            .expect("corrections reported");
+
+        assert_eq!(c.dropped_packets, 4);
+        assert_eq!(c.bad_intervals_applied, 1);
+        assert_eq!(c.sample_interval_secs, 5);
+        assert_eq!(
+            c.interpolated_gaps,
+            vec![VolveGap {
+                start_ts: t0 + 45,
+                end_ts: t0 + 65,
+                duration_secs: 20
+            }]
+        );
+        assert_eq!(c.interpolated_packets, 3);
+        assert_eq!(c.long_gaps.len(), 1);
+        assert_eq!(c.long_gaps[0].duration_secs, 600);
+        assert_eq!(replay.info.packet_count, 33);
+
+        // Interpolated depth lies between the bracketing samples
+        let filled = replay
+            .packets()
+            .iter()
+            .find(|p| p.timestamp == t0 + 55)
+            .expect("interpolated packet");
+        let (lo, hi) = (1009.0 * M_TO_FT, 1013.0 * M_TO_FT);
+        assert!((filled.bit_depth - (lo + hi) / 2.0).abs() < 1e-6);
+
+        // Filters off: nothing corrected or reported
+        let plain = VolveReplay::load(file.path(), VolveConfig::default()).expect("load");
+        assert!(plain.info.corrections.is_none());
+        assert_eq!(plain.info.packet_count, 34);
+    }
+
+    #[test]
+    fn test_depth_window_keeps_only_inclusive_interval() {
+        use std::io::Write;
+
+        let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+        writeln!(file, "Time,Depth,WOB,SURF_RPM,ROP_AVG,PUMP").unwrap();
+        let t0 = 1_250_000_000u64;
+        for i in 0..30u64 {
+            writeln!(file, "{},{},100000,2,0.005,20000000", t0 + i * 5, 1000 + i).unwrap();
+        }
+        file.flush().unwrap();
+
+        let (min, max) = (1005.0 * M_TO_FT, 1015.0 * M_TO_FT);
+        let config = VolveConfig {
+            depth_window: Some((min, max)),
+            ..Default::default()
+        };
+        let replay = VolveReplay::load(file.path(), config).expect("load");
+
+        // 1005..=1015 m: both ends kept
+        assert_eq!(replay.info.packet_count, 11);
+        assert!((replay.info.depth_range_ft.0 - min).abs() < 1e-6);
+        assert!((replay.info.depth_range_ft.1 - max).abs() < 1e-6);
+        let packets = replay.into_packets();
+        assert_eq!(packets.len(), 11);
+        assert!(packets.iter().all(|p| p.bit_depth >= min && p.bit_depth <= max));
+
+        // Inverted window is rejected before reading the file
+        let inverted = VolveConfig {
+            depth_window: Some((max, min)),
+            ..Default::default()
+        };
+        let err = VolveReplay::load(file.path(), inverted).err().expect("error");
+        assert!(err.contains("min must be below max"), "{}", err);
+
+        assert_eq!(parse_depth_window("3200:3600"), Ok((3200.0, 3600.0)));
+        assert!(parse_depth_window("3200").is_err());
+    }
+
+    #[test]
+    fn test_interpolate_gaps_fills_present_channels_only() {
+        use std::io::Write;
+
+        // Tunkiel format with mud weight in; no ECD column at all
+        let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+        writeln!(file, "Time,Depth,WOB,SURF_RPM,ROP_AVG,PUMP,MWIN").unwrap();
+        let t0 = 1_250_000_000u64;
+        for i in 0..10u64 {
+            // Missing: leading sample 0, interior 3..=5, trailing 9
+            let mw = if matches!(i, 0 | 3..=5 | 9) {
+                String::new()
+            } else {
+                (1200 + 10 * i).to_string()
+            };
+            writeln!(file, "{},{},100000,2,0.005,20000000,{}", t0 + i * 5, 1000 + i, mw).unwrap();
+        }
+        file.flush().unwrap();
+
+        let config = VolveConfig {
+            interpolate_gaps: true,
+            ..Default::default()
+        };
+        let replay = VolveReplay::load(file.path(), config).expect("load");
+        assert_eq!(replay.info.interpolated_values, vec![("mud_weight_in", 5)]);
+
+        let packets = replay.packets();
+        assert_eq!(packets.len(), 10);
+        let mw = |i: u64| (1200 + 10 * i) as f64 * KGM3_TO_PPG;
+        // Interior gap interpolated in time between samples 2 and 6
+        for i in 3..=5 {
+            assert!((packets[i as usize].mud_weight_in - mw(i)).abs() < 1e-9);
+        }
+        // Leading / trailing gaps take the nearest valid sample
+        assert!((packets[0].mud_weight_in - mw(1)).abs() < 1e-9);
+        assert!((packets[9].mud_weight_in - mw(8)).abs() < 1e-9);
+        // Channel never in the file stays zero
+        assert!(packets.iter().all(|p| p.ecd == 0.0));
+
+        // Without the option, missing samples are zero-filled
+        let plain = VolveReplay::load(file.path(), VolveConfig::default()).expect("load");
+        assert_eq!(plain.packets()[4].mud_weight_in, 0.0);
+        assert!(plain.info.interpolated_values.is_empty());
+    }
+
     #[test]
     fn test_parse_kaggle_timestamp() {
         let ts = parse_datetime_string("2009-06-27 16:50:29+00:00").unwrap();
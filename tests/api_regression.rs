@@ -17,7 +17,11 @@ use tower::ServiceExt;
 
 fn ensure_config() {
     if !config::is_initialized() {
-        config::init(WellConfig::default(), config::ConfigProvenance::default());
+        // These tests exercise the v1 handlers, not the v1 sunset (covered
+        // in `api::middleware`), so keep v1 open regardless of today's date.
+        let mut cfg = WellConfig::default();
+        cfg.api.v1_sunset = chrono::NaiveDate::from_ymd_opt(2099, 12, 31).unwrap();
+        config::init(cfg, config::ConfigProvenance::default());
     }
 }
 
@@ -28,6 +32,7 @@ fn create_test_state() -> DashboardState {
         threshold_manager: None,
         equipment_id: "TEST-RIG".to_string(),
         ml_storage: None,
+        live_events: Default::default(),
     }
 }
 
@@ -187,3 +192,102 @@ async fn test_legacy_health_returns_200() {
 
     assert_eq!(resp.status(), StatusCode::OK);
 }
+
+/// /api/v2/stream relays a published advisory as a named SSE event.
+#[tokio::test]
+async fn test_v2_stream_receives_published_advisory() {
+    use futures::StreamExt;
+    use sairen_os::pipeline::live_events::LiveEvent;
+    use sairen_os::types::StrategicAdvisory;
+
+    ensure_config();
+    let state = create_test_state();
+    let live_events = state.live_events.clone();
+    let app = create_app(state);
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v2/stream")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(live_events.has_subscribers());
+
+    live_events.publish(LiveEvent::Advisory(Box::new(StrategicAdvisory {
+        timestamp: 1_700_000_000,
+        recommendation: "Flow check".to_string(),
+        ..Default::default()
+    })));
+
+    let mut body = resp.into_body().into_data_stream();
+    let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+        .await
+        .expect("event within timeout")
+        .expect("stream open")
+        .unwrap();
+    let text = String::from_utf8(chunk.to_vec()).unwrap();
+    assert!(text.contains("event: advisory"), "{text}");
+    assert!(text.contains("Flow check"), "{text}");
+}
+
+/// Next WebSocket text message, parsed as JSON.
+async fn next_json<S>(socket: &mut S) -> serde_json::Value
+where
+    S: futures::Stream<
+            Item = Result<
+                tokio_tungstenite::tungstenite::Message,
+                tokio_tungstenite::tungstenite::Error,
+            >,
+        > + Unpin,
+{
+    use futures::StreamExt;
+    use tokio_tungstenite::tungstenite::Message;
+
+    let msg = tokio::time::timeout(std::time::Duration::from_secs(5), socket.next())
+        .await
+        .expect("message within timeout")
+        .expect("socket open")
+        .unwrap();
+    match msg {
+        Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        other => panic!("unexpected message {other:?}"),
+    }
+}
+
+/// /api/v2/ws sends the live snapshot on connect, then pushes live events.
+#[tokio::test]
+async fn test_v2_ws_sends_snapshot_then_update() {
+    use sairen_os::pipeline::live_events::LiveEvent;
+    use sairen_os::types::RigState;
+
+    ensure_config();
+    let state = create_test_state();
+    let live_events = state.live_events.clone();
+    let app = create_app(state);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/api/v2/ws"))
+        .await
+        .expect("websocket handshake");
+
+    let snapshot = next_json(&mut socket).await;
+    assert_eq!(snapshot["data"]["event"], "snapshot");
+    assert!(snapshot["data"]["payload"]["health"].is_object());
+    assert_eq!(snapshot["meta"]["version"], "2");
+
+    live_events.publish(LiveEvent::RigState {
+        timestamp: 1_700_000_000,
+        previous: RigState::Idle,
+        current: RigState::Drilling,
+    });
+    let update = next_json(&mut socket).await;
+    assert_eq!(update["data"]["event"], "rig_state");
+    assert_eq!(update["data"]["payload"]["current"], "Drilling");
+}
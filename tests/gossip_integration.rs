@@ -38,8 +38,13 @@ fn make_test_event(id: &str, rig_id: &str, depth: f64, timestamp: u64) -> FleetE
             trigger_parameter: "torque_cv".to_string(),
             trigger_value: 0.25,
             threshold_value: 0.15,
+            provisional_during_learning: false,
+            sparkline: None,
+            gas_lag: None,
+            causal_leads: Vec::new(),
         },
         history_window: Vec::new(),
+        history_summary: None,
         outcome: EventOutcome::Pending,
         notes: None,
         depth,
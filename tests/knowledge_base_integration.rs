@@ -21,7 +21,7 @@ fn test_migrate_and_assemble_volve() {
     let kb_root = tmp.path();
 
     // Migrate
-    migration::migrate_flat_to_kb(&volve_prognosis_path(), kb_root).unwrap();
+    migration::migrate_flat_to_kb(&volve_prognosis_path(), kb_root, false).unwrap();
 
     // Verify directory structure exists
     assert!(kb_root.join("Volve/geology.toml").exists());
@@ -36,6 +36,7 @@ fn test_migrate_and_assemble_volve() {
         well: "F-15B".to_string(),
         max_mid_well_snapshots: 168,
         cold_retention_days: 30,
+        full_snapshot_every: 24,
     };
 
     let prognosis = assembler::assemble_prognosis(&config)
@@ -82,7 +83,7 @@ fn test_offset_well_assembly() {
     let kb_root = tmp.path();
 
     // Migrate Volve data
-    migration::migrate_flat_to_kb(&volve_prognosis_path(), kb_root).unwrap();
+    migration::migrate_flat_to_kb(&volve_prognosis_path(), kb_root, false).unwrap();
 
     // Simulate an offset well by writing post-well performance data
     let offset_well_dir = kb_root.join("Volve/wells/F-14/post-well");
@@ -135,6 +136,7 @@ fn test_offset_well_assembly() {
         well: "F-15B".to_string(),
         max_mid_well_snapshots: 168,
         cold_retention_days: 30,
+        full_snapshot_every: 24,
     };
 
     let prognosis = assembler::assemble_prognosis(&config).unwrap();
@@ -169,7 +171,7 @@ fn test_full_knowledge_base_lifecycle() {
     let kb_root = tmp.path();
 
     // Migrate Volve data for well F-15B
-    migration::migrate_flat_to_kb(&volve_prognosis_path(), kb_root).unwrap();
+    migration::migrate_flat_to_kb(&volve_prognosis_path(), kb_root, false).unwrap();
 
     let config = KnowledgeBaseConfig {
         root: kb_root.to_path_buf(),
@@ -177,6 +179,7 @@ fn test_full_knowledge_base_lifecycle() {
         well: "F-15B".to_string(),
         max_mid_well_snapshots: 168,
         cold_retention_days: 30,
+        full_snapshot_every: 24,
     };
 
     // Ensure dirs
@@ -235,6 +238,7 @@ fn test_full_knowledge_base_lifecycle() {
         well: "F-16".to_string(),
         max_mid_well_snapshots: 168,
         cold_retention_days: 30,
+        full_snapshot_every: 24,
     };
 
     // Create pre-spud dir for F-16 (minimal — just needs to exist for assembly)